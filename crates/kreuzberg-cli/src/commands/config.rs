@@ -1,11 +1,16 @@
 //! Config command - Configuration loading and discovery
 //!
 //! This module provides utilities for loading extraction configuration from files
-//! or discovering them automatically in the project directory.
+//! or discovering them automatically in the project directory, and the
+//! `config validate` and `config merge` commands for checking the effective config
+//! a deployment will use.
 
 use anyhow::{Context, Result};
 use kreuzberg::ExtractionConfig;
-use std::path::PathBuf;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+
+use crate::{ConfigFileFormat, OutputFormat};
 
 /// Loads extraction configuration from a file or discovers it automatically.
 ///
@@ -29,27 +34,112 @@ use std::path::PathBuf;
 /// - Config file contains invalid extraction settings
 pub fn load_config(config_path: Option<PathBuf>) -> Result<ExtractionConfig> {
     if let Some(path) = config_path {
-        let path_str = path.to_string_lossy();
-        let path_lower = path_str.to_lowercase();
-        let config = if path_lower.ends_with(".toml") {
-            ExtractionConfig::from_toml_file(&path)
-        } else if path_lower.ends_with(".yaml") || path_lower.ends_with(".yml") {
-            ExtractionConfig::from_yaml_file(&path)
-        } else if path_lower.ends_with(".json") {
-            ExtractionConfig::from_json_file(&path)
-        } else {
-            anyhow::bail!("Config file must have .toml, .yaml, .yml, or .json extension (case-insensitive)");
-        };
+        let config = load_config_file(&path)?;
         config.with_context(|| format!("Failed to load configuration from '{}'. Ensure the file exists, is readable, and contains valid configuration.", path.display()))
     } else {
-        match ExtractionConfig::discover() {
-            Ok(Some(config)) => Ok(config),
+        match ExtractionConfig::discover_with_source() {
+            Ok(Some((config, source))) => {
+                tracing::debug!("Using config: {}", source.display());
+                Ok(config)
+            }
             Ok(None) => Ok(ExtractionConfig::default()),
             Err(e) => Err(e).context("Failed to auto-discover configuration file. Searched for kreuzberg.{toml,yaml,json} in current and parent directories. Use --config to specify an explicit path."),
         }
     }
 }
 
+/// Load a config file with the loader matching its extension.
+///
+/// The outer error covers an unsupported extension; the inner result is the loader's.
+fn load_config_file(path: &Path) -> Result<kreuzberg::Result<ExtractionConfig>> {
+    let path_lower = path.to_string_lossy().to_lowercase();
+    if path_lower.ends_with(".toml") {
+        Ok(ExtractionConfig::from_toml_file(path))
+    } else if path_lower.ends_with(".yaml") || path_lower.ends_with(".yml") {
+        Ok(ExtractionConfig::from_yaml_file(path))
+    } else if path_lower.ends_with(".json") {
+        Ok(ExtractionConfig::from_json_file(path))
+    } else {
+        anyhow::bail!("Config file must have .toml, .yaml, .yml, or .json extension (case-insensitive)");
+    }
+}
+
+/// Execute config validate command
+///
+/// Loads `config_path` and runs [`ExtractionConfig::validate`]. Prints "valid", or each
+/// problem on its own line, and returns an error (nonzero exit) when the file fails to
+/// load or validate.
+pub fn validate_command(config_path: PathBuf, format: OutputFormat) -> Result<()> {
+    let problems = match load_config_file(&config_path)? {
+        Ok(config) => config.validation_problems(),
+        Err(e) => vec![e.to_string()],
+    };
+
+    match format {
+        OutputFormat::Text => {
+            if problems.is_empty() {
+                println!("valid");
+            } else {
+                for problem in &problems {
+                    println!("{}", problem);
+                }
+            }
+        }
+        OutputFormat::Json => {
+            let output = json!({
+                "path": config_path.to_string_lossy(),
+                "valid": problems.is_empty(),
+                "problems": problems,
+            });
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&output).context("Failed to serialize validation result to JSON")?
+            );
+        }
+    }
+
+    if !problems.is_empty() {
+        anyhow::bail!(
+            "Configuration '{}' is invalid ({} problem{})",
+            config_path.display(),
+            problems.len(),
+            if problems.len() == 1 { "" } else { "s" }
+        );
+    }
+
+    Ok(())
+}
+
+/// Execute config merge command
+///
+/// Loads both files, layers `override_path` on top of `base_path` with
+/// [`ExtractionConfig::merge`], validates the merged config, and prints it in `format`.
+pub fn merge_command(base_path: PathBuf, override_path: PathBuf, format: ConfigFileFormat) -> Result<()> {
+    let mut merged = load_config(Some(base_path))?;
+    let override_config = load_config(Some(override_path))?;
+    merged.merge(&override_config);
+
+    let problems = merged.validation_problems();
+    if !problems.is_empty() {
+        anyhow::bail!("Merged configuration is invalid:\n{}", problems.join("\n"));
+    }
+
+    let output = match format {
+        ConfigFileFormat::Toml => {
+            toml::to_string_pretty(&merged).context("Failed to serialize merged configuration to TOML")?
+        }
+        ConfigFileFormat::Json => {
+            serde_json::to_string_pretty(&merged).context("Failed to serialize merged configuration to JSON")?
+        }
+        ConfigFileFormat::Yaml => {
+            serde_yaml_ng::to_string(&merged).context("Failed to serialize merged configuration to YAML")?
+        }
+    };
+    println!("{}", output.trim_end());
+
+    Ok(())
+}
+
 /// Loads extraction configuration from a JSON string.
 ///
 /// This function parses a JSON string into an ExtractionConfig struct.
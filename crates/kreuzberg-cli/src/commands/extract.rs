@@ -5,31 +5,129 @@
 
 use anyhow::{Context, Result};
 use kreuzberg::{
-    ChunkingConfig, ExtractionConfig, LanguageDetectionConfig, OcrConfig, batch_extract_file_sync, extract_file_sync,
+    ChunkingConfig, ExtractionConfig, LanguageDetectionConfig, Metadata, OcrConfig, ProgressEvent, Table,
+    batch_extract_file_lenient_sync, extract_file_sync, extract_file_with_progress_sync, extract_tables_only_sync,
+    extract_thumbnail,
 };
+use std::io::Write;
 use std::path::PathBuf;
 
-use crate::{ContentOutputFormatArg, OutputFormat};
+use crate::{ContentOutputFormatArg, OutputFormat, TextEncoding};
+
+/// Width of the stderr progress bar in characters
+const PROGRESS_BAR_WIDTH: usize = 30;
+
+/// Render a progress event as a single, continuously rewritten stderr line
+fn render_progress(event: ProgressEvent) {
+    let line = match event {
+        ProgressEvent::ExtractionStarted { mime_type } => format!("Extracting ({})", mime_type),
+        ProgressEvent::PageStarted { page, total } => {
+            let filled = (page * PROGRESS_BAR_WIDTH / total.max(1)).min(PROGRESS_BAR_WIDTH);
+            format!(
+                "[{}{}] page {}/{}",
+                "#".repeat(filled),
+                "-".repeat(PROGRESS_BAR_WIDTH - filled),
+                page,
+                total
+            )
+        }
+        ProgressEvent::OcrStarted => "Running OCR".to_string(),
+        ProgressEvent::PostProcessingStarted => "Post-processing".to_string(),
+        ProgressEvent::ChunkingStarted => "Chunking".to_string(),
+        ProgressEvent::ExtractionFinished => {
+            let _ = writeln!(std::io::stderr(), "\r\x1b[2KDone");
+            return;
+        }
+        _ => return,
+    };
+
+    let _ = write!(std::io::stderr(), "\r\x1b[2K{}", line);
+}
+
+/// Select the requested fields from the serialized metadata.
+///
+/// Uses the same serialization as the full JSON output; absent fields map to null.
+fn select_metadata_fields(metadata: &Metadata, fields: &[String]) -> Result<serde_json::Value> {
+    let serialized = serde_json::to_value(metadata).context("Failed to serialize metadata to JSON")?;
+
+    let selected = fields
+        .iter()
+        .map(|field| {
+            let value = serialized.get(field).cloned().unwrap_or(serde_json::Value::Null);
+            (field.clone(), value)
+        })
+        .collect();
+
+    Ok(serde_json::Value::Object(selected))
+}
 
 /// Execute single document extraction command
+///
+/// When `metadata_fields` is set, only those metadata fields are printed as a JSON object,
+/// regardless of `format`. `encoding` only applies to text output. When `schema` is set, JSON
+/// output is wrapped as `{"schema": ..., "result": ...}` with the result's JSON Schema. When
+/// `stable` is set, JSON output has the keys of every object sorted.
+#[allow(clippy::too_many_arguments)]
 pub fn extract_command(
     path: PathBuf,
     config: ExtractionConfig,
     mime_type: Option<String>,
     format: OutputFormat,
+    encoding: TextEncoding,
+    progress: bool,
+    metadata_fields: Option<Vec<String>>,
+    schema: bool,
+    stable: bool,
 ) -> Result<()> {
     let path_str = path.to_string_lossy().to_string();
 
-    let result = extract_file_sync(&path_str, mime_type.as_deref(), &config).with_context(|| {
+    let result = if progress {
+        extract_file_with_progress_sync(&path_str, mime_type.as_deref(), &config, render_progress)
+    } else {
+        extract_file_sync(&path_str, mime_type.as_deref(), &config)
+    };
+
+    let result = result.with_context(|| {
         format!(
             "Failed to extract file '{}'. Ensure the file is readable and the format is supported.",
             path.display()
         )
     })?;
 
+    if let Some(fields) = metadata_fields {
+        let selected = select_metadata_fields(&result.metadata, &fields)?;
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&selected).context("Failed to serialize metadata fields to JSON")?
+        );
+        return Ok(());
+    }
+
     match format {
         OutputFormat::Text => {
-            println!("{}", result.content);
+            let mut stdout = std::io::stdout().lock();
+            stdout
+                .write_all(&encoding.encode(&format!("{}\n", result.content)))
+                .and_then(|()| stdout.flush())
+                .context("Failed to write extraction result to stdout")?;
+        }
+        OutputFormat::Json if schema => {
+            let envelope = serde_json::json!({
+                "schema": result_schema()?,
+                "result": result,
+            });
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&envelope).context("Failed to serialize extraction result to JSON")?
+            );
+        }
+        OutputFormat::Json if stable => {
+            println!(
+                "{}",
+                result
+                    .to_stable_json()
+                    .context("Failed to serialize extraction result to JSON")?
+            );
         }
         OutputFormat::Json => {
             // Serialize the full ExtractionResult including chunks, images, elements, etc.
@@ -43,36 +141,172 @@ pub fn extract_command(
     Ok(())
 }
 
-/// Execute batch extraction command
-pub fn batch_command(paths: Vec<PathBuf>, config: ExtractionConfig, format: OutputFormat) -> Result<()> {
-    let path_strs: Vec<String> = paths.iter().map(|p| p.to_string_lossy().to_string()).collect();
+#[cfg(feature = "api")]
+fn result_schema() -> Result<serde_json::Value> {
+    Ok(kreuzberg::result_json_schema())
+}
 
-    let results = batch_extract_file_sync(path_strs, &config).with_context(|| {
+#[cfg(not(feature = "api"))]
+fn result_schema() -> Result<serde_json::Value> {
+    anyhow::bail!("--schema requires kreuzberg-cli to be built with the 'api' feature.")
+}
+
+/// Quote a CSV field when it contains a delimiter, quote or line break (RFC 4180).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render tables as CSV blocks separated by a blank line.
+///
+/// CSV readers take the first line as the header, so tables without a header row
+/// (`has_header == false`) get a generated `column_1,column_2,...` line first.
+fn tables_to_csv(tables: &[Table]) -> String {
+    tables
+        .iter()
+        .map(|table| {
+            let mut csv = String::new();
+            if !table.has_header {
+                let columns = table.cells.iter().map(Vec::len).max().unwrap_or(0);
+                if columns > 0 {
+                    let header: Vec<String> = (1..=columns).map(|col| format!("column_{col}")).collect();
+                    csv.push_str(&header.join(","));
+                    csv.push('\n');
+                }
+            }
+            for row in &table.cells {
+                csv.push_str(&row.iter().map(|cell| csv_field(cell)).collect::<Vec<_>>().join(","));
+                csv.push('\n');
+            }
+            csv
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Execute table-only extraction
+///
+/// Prints the tables as CSV (or HTML with `html`) for text output and as a JSON array
+/// for JSON output. With `html`, every JSON table also gets an `html` field.
+pub fn tables_only_command(
+    path: PathBuf,
+    config: ExtractionConfig,
+    mime_type: Option<String>,
+    format: OutputFormat,
+    encoding: TextEncoding,
+    html: bool,
+) -> Result<()> {
+    let tables = extract_tables_only_sync(&path, mime_type.as_deref(), &config).with_context(|| {
         format!(
-            "Failed to batch extract {} documents. Check that all files are readable and formats are supported.",
-            paths.len()
+            "Failed to extract tables from '{}'. Ensure the file is readable and the format is supported.",
+            path.display()
         )
     })?;
 
     match format {
         OutputFormat::Text => {
-            for (i, result) in results.iter().enumerate() {
+            let rendered = if html {
+                tables.iter().map(Table::to_html).collect::<Vec<_>>().join("\n")
+            } else {
+                tables_to_csv(&tables)
+            };
+            let mut stdout = std::io::stdout().lock();
+            stdout
+                .write_all(&encoding.encode(&rendered))
+                .and_then(|()| stdout.flush())
+                .context("Failed to write tables to stdout")?;
+        }
+        OutputFormat::Json => {
+            let mut value = serde_json::to_value(&tables).context("Failed to serialize tables to JSON")?;
+            if html && let serde_json::Value::Array(items) = &mut value {
+                for (item, table) in items.iter_mut().zip(&tables) {
+                    if let serde_json::Value::Object(object) = item {
+                        object.insert("html".to_string(), serde_json::Value::String(table.to_html()));
+                    }
+                }
+            }
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&value).context("Failed to serialize tables to JSON")?
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Execute thumbnail command
+///
+/// Renders the first page (or the image itself) as a PNG `width` pixels wide and
+/// writes it to `output`.
+pub fn thumbnail_command(path: PathBuf, output: PathBuf, width: u32, config: ExtractionConfig) -> Result<()> {
+    let png = extract_thumbnail(&path, &config, width)
+        .with_context(|| format!("Failed to render a thumbnail of '{}'", path.display()))?;
+
+    std::fs::write(&output, png).with_context(|| format!("Failed to write thumbnail to '{}'", output.display()))?;
+
+    Ok(())
+}
+
+/// Execute batch extraction command
+///
+/// Every document is reported, including the ones that failed; the command exits with an
+/// error after printing when any document failed.
+pub fn batch_command(paths: Vec<PathBuf>, config: ExtractionConfig, format: OutputFormat) -> Result<()> {
+    let path_strs: Vec<String> = paths.iter().map(|p| p.to_string_lossy().to_string()).collect();
+
+    let outcomes = batch_extract_file_lenient_sync(path_strs, &config);
+    let failed = outcomes.iter().filter(|outcome| outcome.is_err()).count();
+
+    match format {
+        OutputFormat::Text => {
+            for (i, (path, outcome)) in paths.iter().zip(&outcomes).enumerate() {
                 println!("=== Document {} ===", i + 1);
-                println!("MIME Type: {}", result.mime_type);
-                println!("Content:\n{}", result.content);
+                match outcome {
+                    Ok(result) => {
+                        println!("MIME Type: {}", result.mime_type);
+                        println!("Content:\n{}", result.content);
+                    }
+                    Err(e) => {
+                        println!("Error: {}", e);
+                        eprintln!("Failed to extract {}: {}", path.display(), e);
+                    }
+                }
                 println!();
             }
         }
         OutputFormat::Json => {
-            // Serialize the full ExtractionResult for each document
+            // Serialize the full ExtractionResult for each document, or the error for failed ones
+            let documents = paths
+                .iter()
+                .zip(&outcomes)
+                .map(|(path, outcome)| match outcome {
+                    Ok(result) => serde_json::to_value(result),
+                    Err(e) => Ok(serde_json::json!({
+                        "path": path.to_string_lossy(),
+                        "error": {
+                            "error_type": format!("{:?}", e),
+                            "message": e.to_string(),
+                        },
+                    })),
+                })
+                .collect::<serde_json::Result<Vec<_>>>()
+                .context("Failed to serialize batch extraction results to JSON")?;
             println!(
                 "{}",
-                serde_json::to_string_pretty(&results)
+                serde_json::to_string_pretty(&documents)
                     .context("Failed to serialize batch extraction results to JSON")?
             );
         }
     }
 
+    if failed > 0 {
+        anyhow::bail!("Failed to extract {} of {} documents", failed, outcomes.len());
+    }
+
     Ok(())
 }
 
@@ -107,13 +341,33 @@ pub fn apply_extraction_overrides(
             // Preserve existing paddle_ocr_config and element_config from config file/inline JSON
             let existing_paddle_config = config.ocr.as_ref().and_then(|o| o.paddle_ocr_config.clone());
             let existing_element_config = config.ocr.as_ref().and_then(|o| o.element_config.clone());
+            let existing_max_retries = config.ocr.as_ref().map_or(0, |o| o.max_retries);
+            let existing_auto_language = config.ocr.as_ref().is_some_and(|o| o.auto_language);
+            let existing_fallback_backends = config
+                .ocr
+                .as_ref()
+                .map(|o| o.fallback_backends.clone())
+                .unwrap_or_default();
+            let existing_user_words = config.ocr.as_ref().and_then(|o| o.user_words.clone());
+            let existing_render_dpi = config.ocr.as_ref().and_then(|o| o.render_dpi);
+            let existing_empty_page_confidence = config.ocr.as_ref().and_then(|o| o.empty_page_confidence);
+            let existing_page_filter = config.ocr.as_ref().and_then(|o| o.page_filter.clone());
+            let existing_region = config.ocr.as_ref().and_then(|o| o.region);
             config.ocr = Some(OcrConfig {
                 backend: backend.to_string(),
+                fallback_backends: existing_fallback_backends,
                 language: language.to_string(),
                 tesseract_config: None,
                 output_format: None,
                 paddle_ocr_config: existing_paddle_config,
                 element_config: existing_element_config,
+                max_retries: existing_max_retries,
+                auto_language: existing_auto_language,
+                user_words: existing_user_words,
+                render_dpi: existing_render_dpi,
+                empty_page_confidence: existing_empty_page_confidence,
+                page_filter: existing_page_filter,
+                region: existing_region,
             });
         } else {
             config.ocr = None;
@@ -136,6 +390,9 @@ pub fn apply_extraction_overrides(
                 chunker_type: kreuzberg::chunking::ChunkerType::Text,
                 embedding: None,
                 preset: None,
+                overlap_unit: kreuzberg::chunking::OverlapUnit::Characters,
+                max_chunks: None,
+                balance: false,
             });
         } else {
             config.chunking = None;
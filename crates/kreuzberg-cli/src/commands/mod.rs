@@ -1,10 +1,10 @@
 //! Command modules for Kreuzberg CLI
 //!
 //! This module organizes the CLI commands into focused submodules:
-//! - `extract` - Document extraction commands
+//! - `extract` - Document extraction and thumbnail commands
 //! - `cache` - Cache management operations
 //! - `server` - API and MCP server commands
-//! - `config` - Configuration loading and discovery
+//! - `config` - Configuration loading, discovery and validation
 
 pub mod cache;
 pub mod config;
@@ -13,8 +13,8 @@ pub mod server;
 
 // Re-export command functions for convenience
 pub use cache::{clear_command, stats_command};
-pub use config::load_config;
-pub use extract::{apply_extraction_overrides, batch_command, extract_command};
+pub use config::{load_config, merge_command, validate_command};
+pub use extract::{apply_extraction_overrides, batch_command, extract_command, tables_only_command, thumbnail_command};
 #[cfg(feature = "mcp")]
 pub use server::mcp_command;
 #[cfg(feature = "api")]
@@ -9,6 +9,7 @@
 //! - `extract`: Extract text/data from a single document
 //! - `batch`: Process multiple documents in parallel
 //! - `detect`: Identify MIME type of a file
+//! - `thumbnail`: Render a PNG thumbnail of a document
 //! - `cache`: Manage cache (clear, stats)
 //! - `serve`: Start API server (requires `api` feature)
 //! - `version`: Show version information
@@ -49,6 +50,12 @@
 //!
 //! # Detect MIME type
 //! kreuzberg detect unknown-file.bin
+//!
+//! # Check PDF/A conformance
+//! kreuzberg detect archive.pdf --pdfa
+//!
+//! # Render a thumbnail of the first page
+//! kreuzberg thumbnail doc.pdf -o thumb.png
 //! ```
 
 #![deny(unsafe_code)]
@@ -62,8 +69,11 @@ use clap::{Parser, Subcommand};
 use commands::mcp_command;
 #[cfg(feature = "api")]
 use commands::serve_command;
-use commands::{apply_extraction_overrides, batch_command, clear_command, extract_command, load_config, stats_command};
-use kreuzberg::{OutputFormat as ContentOutputFormat, detect_mime_type};
+use commands::{
+    apply_extraction_overrides, batch_command, clear_command, extract_command, load_config, merge_command,
+    stats_command, tables_only_command, thumbnail_command, validate_command,
+};
+use kreuzberg::{KNOWN_FORMATS, OutputFormat as ContentOutputFormat, detect_mime_type, is_valid_format_field};
 use serde_json::json;
 use std::path::{Path, PathBuf};
 use tracing_subscriber::EnvFilter;
@@ -158,6 +168,52 @@ enum Commands {
         /// This flag is maintained for backward compatibility. Use --output-format for new code.
         #[arg(long, value_enum, hide = true)]
         content_format: Option<ContentOutputFormatArg>,
+
+        /// Render extraction progress (pages, OCR, post-processing) to stderr
+        #[arg(long)]
+        progress: bool,
+
+        /// Print only these metadata fields as a JSON object (comma-separated, e.g. title,page_count)
+        ///
+        /// Field names must be known format fields. Fields missing from the document are printed as null.
+        #[arg(long, value_delimiter = ',')]
+        metadata_fields: Option<Vec<String>>,
+
+        /// Extract only this PDF page range (1-indexed, inclusive), e.g. 5-10 or 3
+        #[arg(long, value_parser = parse_page_range)]
+        pages: Option<(usize, usize)>,
+
+        /// Extract only tables, skipping prose, OCR of non-image formats, and post-processing
+        ///
+        /// Prints the tables as CSV (--format text, one block per table separated by a blank
+        /// line) or as a JSON array (--format json).
+        #[arg(long, conflicts_with = "metadata_fields")]
+        tables_only: bool,
+
+        /// With --tables-only, render tables as HTML, keeping merged cells as rowspan/colspan
+        ///
+        /// Prints one <table> per table instead of CSV (--format text), or adds an `html`
+        /// field to every table (--format json).
+        #[arg(long, requires = "tables_only")]
+        tables_html: bool,
+
+        /// Encoding for text output (utf-8 or utf-16le). Only applies to --format text.
+        ///
+        /// utf-16le output starts with a byte order mark for tools that expect one.
+        #[arg(long, default_value = "utf-8")]
+        encoding: TextEncoding,
+
+        /// Print the result together with its JSON Schema as {"schema": ..., "result": ...}
+        ///
+        /// Requires --format json and a build with the `api` feature.
+        #[arg(long, conflicts_with_all = ["metadata_fields", "tables_only"])]
+        schema: bool,
+
+        /// Print JSON with the keys of every object sorted, for diffs and snapshot tests
+        ///
+        /// Requires --format json. Equal results always print identically.
+        #[arg(long, conflicts_with_all = ["metadata_fields", "tables_only", "schema"])]
+        stable: bool,
     },
 
     /// Batch extract from multiple documents
@@ -229,6 +285,28 @@ enum Commands {
         /// Output format (text or json)
         #[arg(short, long, default_value = "text")]
         format: OutputFormat,
+
+        /// Check PDF/A conformance (PDF files only)
+        #[arg(long)]
+        pdfa: bool,
+    },
+
+    /// Render a PNG thumbnail of a document
+    Thumbnail {
+        /// Path to the document
+        path: PathBuf,
+
+        /// Path to write the PNG thumbnail to
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Thumbnail width in pixels; the height follows the document's aspect ratio
+        #[arg(short, long, default_value_t = 256)]
+        width: u32,
+
+        /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
     },
 
     /// Show version information
@@ -236,6 +314,10 @@ enum Commands {
         /// Output format (text or json)
         #[arg(short, long, default_value = "text")]
         format: OutputFormat,
+
+        /// Also list enabled features, extractors and OCR backends
+        #[arg(short, long)]
+        verbose: bool,
     },
 
     /// Cache management operations
@@ -244,6 +326,12 @@ enum Commands {
         command: CacheCommands,
     },
 
+    /// Configuration file operations
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
     /// Start the API server
     ///
     /// Configuration is loaded with the following precedence (highest to lowest):
@@ -314,6 +402,62 @@ enum CacheCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Check a config file for problems without running an extraction
+    ///
+    /// Prints "valid" or one problem per line and exits nonzero if the file
+    /// cannot be loaded or fails validation.
+    Validate {
+        /// Path to config file (TOML, YAML, or JSON)
+        #[arg(short, long)]
+        config: PathBuf,
+
+        /// Output format (text or json)
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
+    },
+
+    /// Merge an override config on top of a base config and print the result
+    ///
+    /// Uses the same shallow merge as the FFI `kreuzberg_config_merge`: scalar
+    /// settings come from the override, and sections set in the override replace
+    /// the base section wholesale. The merged config is validated before printing.
+    Merge {
+        /// Base config file (TOML, YAML, or JSON)
+        base: PathBuf,
+
+        /// Override config file (TOML, YAML, or JSON)
+        #[arg(value_name = "OVERRIDE")]
+        override_config: PathBuf,
+
+        /// Output format (toml, json, or yaml)
+        #[arg(short, long, default_value = "toml")]
+        format: ConfigFileFormat,
+    },
+}
+
+/// Serialization format for printed config files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ConfigFileFormat {
+    Toml,
+    Json,
+    Yaml,
+}
+
+impl std::str::FromStr for ConfigFileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "toml" => Ok(ConfigFileFormat::Toml),
+            "json" => Ok(ConfigFileFormat::Json),
+            "yaml" | "yml" => Ok(ConfigFileFormat::Yaml),
+            _ => Err(format!("Invalid format: {}. Use 'toml', 'json', or 'yaml'", s)),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum OutputFormat {
     Text,
@@ -332,6 +476,39 @@ impl std::str::FromStr for OutputFormat {
     }
 }
 
+/// Character encoding for `--format text` output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TextEncoding {
+    Utf8,
+    Utf16Le,
+}
+
+impl TextEncoding {
+    /// Encode `text` for writing to stdout. UTF-16LE output is prefixed with a byte order mark.
+    fn encode(self, text: &str) -> Vec<u8> {
+        match self {
+            TextEncoding::Utf8 => text.as_bytes().to_vec(),
+            TextEncoding::Utf16Le => [0xFEFF_u16]
+                .into_iter()
+                .chain(text.encode_utf16())
+                .flat_map(u16::to_le_bytes)
+                .collect(),
+        }
+    }
+}
+
+impl std::str::FromStr for TextEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "utf-8" | "utf8" => Ok(TextEncoding::Utf8),
+            "utf-16le" | "utf16le" => Ok(TextEncoding::Utf16Le),
+            _ => Err(format!("Unsupported encoding: {}. Use 'utf-8' or 'utf-16le'", s)),
+        }
+    }
+}
+
 /// Content output format for extraction results.
 ///
 /// Controls the format of the extracted content (not the CLI output format).
@@ -384,6 +561,20 @@ fn validate_file_exists(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Validates that `--schema` is only combined with JSON output on builds that can generate it.
+fn validate_schema_output(schema: bool, format: OutputFormat) -> Result<()> {
+    if !schema {
+        return Ok(());
+    }
+    if format != OutputFormat::Json {
+        anyhow::bail!("--schema requires --format json.");
+    }
+    if cfg!(not(feature = "api")) {
+        anyhow::bail!("--schema requires kreuzberg-cli to be built with the 'api' feature.");
+    }
+    Ok(())
+}
+
 /// Validates chunking parameters for correctness.
 ///
 /// Ensures that chunking configuration makes sense: size must be positive and reasonable,
@@ -424,6 +615,59 @@ fn validate_chunk_params(chunk_size: Option<usize>, chunk_overlap: Option<usize>
     Ok(())
 }
 
+/// Validates `--metadata-fields` names against the known format fields.
+///
+/// # Errors
+///
+/// Returns an error listing every field name that is not a known format field.
+fn validate_metadata_fields(fields: Option<&[String]>) -> Result<()> {
+    let Some(fields) = fields else {
+        return Ok(());
+    };
+
+    let unknown: Vec<&str> = fields
+        .iter()
+        .map(String::as_str)
+        .filter(|field| !is_valid_format_field(field))
+        .collect();
+    if !unknown.is_empty() {
+        anyhow::bail!(
+            "Unknown metadata field(s): {}. Valid fields: {}",
+            unknown.join(", "),
+            KNOWN_FORMATS.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+/// Parses a `--pages` value: a single page (`3`) or an inclusive range (`5-10`).
+///
+/// # Errors
+///
+/// Returns an error if the value is not a page or range, a page is 0, or start > end.
+fn parse_page_range(value: &str) -> std::result::Result<(usize, usize), String> {
+    let (start, end) = value.split_once('-').unwrap_or((value, value));
+    let parse = |page: &str| {
+        page.trim()
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid page number '{}' in page range '{}'", page.trim(), value))
+    };
+    let (start, end) = (parse(start)?, parse(end)?);
+
+    if start == 0 {
+        return Err("Page numbers are 1-indexed and must be at least 1".to_string());
+    }
+    if start > end {
+        return Err(format!(
+            "Page range start ({}) must not be greater than end ({})",
+            start, end
+        ));
+    }
+
+    Ok((start, end))
+}
+
 /// Validates batch extraction paths for correctness.
 ///
 /// Ensures that at least one file path is provided and that all paths point to valid,
@@ -510,9 +754,22 @@ fn main() -> Result<()> {
             detect_language,
             output_format,
             content_format,
+            progress,
+            metadata_fields,
+            pages,
+            tables_only,
+            tables_html,
+            encoding,
+            schema,
+            stable,
         } => {
             validate_file_exists(&path)?;
+            validate_schema_output(schema, format)?;
+            if stable && format != OutputFormat::Json {
+                anyhow::bail!("--stable requires --format json.");
+            }
             validate_chunk_params(chunk_size, chunk_overlap)?;
+            validate_metadata_fields(metadata_fields.as_deref())?;
 
             let mut config = load_config(config_path)?;
 
@@ -550,7 +807,25 @@ fn main() -> Result<()> {
                 content_format,
             );
 
-            extract_command(path, config, mime_type, format)?;
+            if let Some(range) = pages {
+                config.pdf_options.get_or_insert_with(Default::default).page_range = Some(range);
+            }
+
+            if tables_only {
+                tables_only_command(path, config, mime_type, format, encoding, tables_html)?;
+            } else {
+                extract_command(
+                    path,
+                    config,
+                    mime_type,
+                    format,
+                    encoding,
+                    progress,
+                    metadata_fields,
+                    schema,
+                    stable,
+                )?;
+            }
         }
 
         Commands::Batch {
@@ -608,7 +883,7 @@ fn main() -> Result<()> {
             batch_command(paths, config, format)?;
         }
 
-        Commands::Detect { path, format } => {
+        Commands::Detect { path, format, pdfa } => {
             validate_file_exists(&path)?;
 
             let path_str = path.to_string_lossy().to_string();
@@ -619,15 +894,52 @@ fn main() -> Result<()> {
                 )
             })?;
 
+            let pdfa_report = if pdfa {
+                if mime_type != "application/pdf" {
+                    anyhow::bail!(
+                        "--pdfa requires a PDF file, but '{}' was detected as {}",
+                        path.display(),
+                        mime_type
+                    );
+                }
+                let bytes =
+                    std::fs::read(&path).with_context(|| format!("Failed to read file '{}'", path.display()))?;
+                Some(
+                    kreuzberg::pdf::check_pdfa_conformance(&bytes)
+                        .with_context(|| format!("Failed to check PDF/A conformance of '{}'", path.display()))?,
+                )
+            } else {
+                None
+            };
+
             match format {
                 OutputFormat::Text => {
                     println!("{}", mime_type);
+                    if let Some(report) = &pdfa_report {
+                        match report.claimed_level() {
+                            Some(level) if report.is_conformant() => println!("PDF/A: {} (no violations found)", level),
+                            Some(level) => {
+                                println!("PDF/A: {} claimed, {} violation(s)", level, report.violations.len())
+                            }
+                            None => println!("PDF/A: not claimed, {} violation(s)", report.violations.len()),
+                        }
+                        for violation in &report.violations {
+                            println!("  - {}", violation);
+                        }
+                    }
                 }
                 OutputFormat::Json => {
-                    let output = json!({
+                    let mut output = json!({
                         "path": path_str,
                         "mime_type": mime_type,
                     });
+                    if let Some(report) = &pdfa_report {
+                        output["pdfa"] = json!({
+                            "claimed_level": report.claimed_level(),
+                            "conformant": report.is_conformant(),
+                            "report": report,
+                        });
+                    }
                     println!(
                         "{}",
                         serde_json::to_string_pretty(&output)
@@ -637,19 +949,30 @@ fn main() -> Result<()> {
             }
         }
 
-        Commands::Version { format } => {
+        Commands::Version { format, verbose } => {
             let version = env!("CARGO_PKG_VERSION");
             let name = env!("CARGO_PKG_NAME");
 
             match format {
                 OutputFormat::Text => {
                     println!("{} {}", name, version);
+                    if verbose {
+                        let build = kreuzberg::build_info();
+                        println!("library: {}", build.version);
+                        println!("features: {}", build.features.join(", "));
+                        println!("extractors: {}", build.extractors.join(", "));
+                        println!("ocr backends: {}", build.ocr_backends.join(", "));
+                    }
                 }
                 OutputFormat::Json => {
-                    let output = json!({
+                    let mut output = json!({
                         "name": name,
                         "version": version,
                     });
+                    if verbose {
+                        output["build"] = serde_json::to_value(kreuzberg::build_info())
+                            .context("Failed to serialize build information")?;
+                    }
                     println!(
                         "{}",
                         serde_json::to_string_pretty(&output)
@@ -659,6 +982,17 @@ fn main() -> Result<()> {
             }
         }
 
+        Commands::Thumbnail {
+            path,
+            output,
+            width,
+            config: config_path,
+        } => {
+            validate_file_exists(&path)?;
+            let config = load_config(config_path)?;
+            thumbnail_command(path, output, width, config)?;
+        }
+
         #[cfg(feature = "api")]
         Commands::Serve {
             host: cli_host,
@@ -694,6 +1028,19 @@ fn main() -> Result<()> {
                 clear_command(cache_dir, format)?;
             }
         },
+
+        Commands::Config { command } => match command {
+            ConfigCommands::Validate { config, format } => {
+                validate_command(config, format)?;
+            }
+            ConfigCommands::Merge {
+                base,
+                override_config,
+                format,
+            } => {
+                merge_command(base, override_config, format)?;
+            }
+        },
     }
 
     Ok(())
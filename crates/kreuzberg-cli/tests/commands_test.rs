@@ -1,4 +1,4 @@
-//! Integration tests for CLI commands (extract, detect, batch).
+//! Integration tests for CLI commands (extract, detect, thumbnail, batch).
 //!
 //! These tests verify that the CLI commands work correctly end-to-end,
 //! including input validation, file processing, and output formatting.
@@ -93,6 +93,56 @@ fn test_extract_with_json_output() {
     assert!(json.get("mime_type").is_some(), "JSON should have 'mime_type' field");
 }
 
+#[test]
+fn test_extract_with_stable_json_output() {
+    build_binary();
+
+    let test_file = get_test_file("text/simple.txt");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let run = || {
+        Command::new(get_binary_path())
+            .args([
+                "extract",
+                test_file.as_str(),
+                "--format",
+                "json",
+                "--stable",
+                "--no-cache",
+                "true",
+            ])
+            .output()
+            .expect("Failed to execute extract command")
+    };
+    let first = run();
+    let second = run();
+
+    assert!(
+        first.status.success(),
+        "Extract --stable failed: {}",
+        String::from_utf8_lossy(&first.stderr)
+    );
+    assert_eq!(first.stdout, second.stdout, "Stable JSON output should be identical");
+
+    let stdout = String::from_utf8_lossy(&first.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("Output should be valid JSON");
+    assert!(json.get("content").is_some(), "JSON should have 'content' field");
+    assert!(
+        stdout.find("\"content\"") < stdout.find("\"mime_type\""),
+        "Keys should be sorted, got: {}",
+        stdout
+    );
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", test_file.as_str(), "--stable"])
+        .output()
+        .expect("Failed to execute extract command");
+    assert!(!output.status.success(), "--stable requires --format json");
+}
+
 #[test]
 fn test_extract_with_chunking() {
     build_binary();
@@ -319,6 +369,63 @@ fn test_detect_with_json_output() {
     assert!(json.get("path").is_some(), "JSON should have 'path' field");
 }
 
+#[test]
+fn test_thumbnail_writes_png() {
+    build_binary();
+
+    let test_file = get_test_file("pdf/blank_page.pdf");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let dir = tempdir().expect("Failed to create temp dir");
+    let thumbnail = dir.path().join("thumb.png");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "thumbnail",
+            test_file.as_str(),
+            "-o",
+            thumbnail.to_str().unwrap(),
+            "--width",
+            "128",
+        ])
+        .output()
+        .expect("Failed to execute thumbnail command");
+
+    assert!(
+        output.status.success(),
+        "Thumbnail command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let png = std::fs::read(&thumbnail).expect("Thumbnail file should be written");
+    assert!(png.starts_with(b"\x89PNG"), "Thumbnail should be a PNG file");
+}
+
+#[test]
+fn test_thumbnail_rejects_plain_text() {
+    build_binary();
+
+    let test_file = get_test_file("text/simple.txt");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let dir = tempdir().expect("Failed to create temp dir");
+    let thumbnail = dir.path().join("thumb.png");
+
+    let output = Command::new(get_binary_path())
+        .args(["thumbnail", test_file.as_str(), "-o", thumbnail.to_str().unwrap()])
+        .output()
+        .expect("Failed to execute thumbnail command");
+
+    assert!(!output.status.success(), "Plain text has no thumbnail");
+    assert!(!thumbnail.exists(), "No file should be written on failure");
+}
+
 #[test]
 fn test_detect_file_not_found() {
     build_binary();
@@ -443,3 +550,326 @@ fn test_batch_help() {
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(stdout.contains("Batch extract from multiple documents"));
 }
+
+#[test]
+fn test_extract_metadata_fields() {
+    build_binary();
+
+    let test_file = get_test_file("text/simple.txt");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", test_file.as_str(), "--metadata-fields", "title,line_count"])
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(
+        output.status.success(),
+        "Extract command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("Output should be valid JSON");
+    let object = json.as_object().expect("Output should be a JSON object");
+    assert_eq!(
+        object.len(),
+        2,
+        "Only requested fields should be printed, got: {}",
+        stdout
+    );
+    assert!(object.contains_key("title"));
+    assert!(
+        object["line_count"].is_number(),
+        "line_count should be present, got: {}",
+        stdout
+    );
+}
+
+#[test]
+fn test_extract_metadata_fields_unknown_field() {
+    build_binary();
+
+    let test_file = get_test_file("text/simple.txt");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", test_file.as_str(), "--metadata-fields", "title,not_a_field"])
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(
+        !output.status.success(),
+        "Extract should fail for unknown metadata fields"
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Unknown metadata field") && stderr.contains("not_a_field"),
+        "Error should name the unknown field, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_extract_pages_invalid_range() {
+    build_binary();
+
+    let test_file = get_test_file("text/simple.txt");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", test_file.as_str(), "--pages", "10-5"])
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(
+        !output.status.success(),
+        "Extract should fail for a reversed page range"
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("must not be greater than end"),
+        "Error should explain the invalid range, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_extract_utf16le_encoding() {
+    build_binary();
+
+    let test_file = get_test_file("text/simple.txt");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let utf8_output = Command::new(get_binary_path())
+        .args(["extract", test_file.as_str()])
+        .output()
+        .expect("Failed to execute extract command");
+    let utf16_output = Command::new(get_binary_path())
+        .args(["extract", test_file.as_str(), "--encoding", "utf-16le"])
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(
+        utf16_output.status.success(),
+        "Extract with --encoding utf-16le failed: {}",
+        String::from_utf8_lossy(&utf16_output.stderr)
+    );
+
+    let (bom, body) = utf16_output.stdout.split_at(2);
+    assert_eq!(bom, [0xFF, 0xFE], "UTF-16LE output should start with a byte order mark");
+    let units: Vec<u16> = body
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    let decoded = String::from_utf16(&units).expect("Output should be valid UTF-16LE");
+    assert_eq!(decoded.as_bytes(), utf8_output.stdout.as_slice());
+}
+
+#[test]
+fn test_extract_unsupported_encoding() {
+    build_binary();
+
+    let test_file = get_test_file("text/simple.txt");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", test_file.as_str(), "--encoding", "latin-1"])
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(
+        !output.status.success(),
+        "Extract should fail for an unsupported encoding"
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Unsupported encoding"),
+        "Error should name the unsupported encoding, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_config_validate_reports_problems() {
+    build_binary();
+
+    let dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let valid = dir.path().join("valid.toml");
+    std::fs::write(&valid, "[chunking]\nmax_characters = 1000\noverlap = 100\n").unwrap();
+
+    let output = Command::new(get_binary_path())
+        .args(["config", "validate", "--config", valid.to_str().unwrap()])
+        .output()
+        .expect("Failed to execute config validate command");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "valid");
+
+    let invalid = dir.path().join("invalid.toml");
+    std::fs::write(
+        &invalid,
+        "[ocr]\nbackend = \"tesseract\"\nlanguage = \"englsh\"\n\n[chunking]\nmax_characters = 100\noverlap = 100\n",
+    )
+    .unwrap();
+
+    let output = Command::new(get_binary_path())
+        .args(["config", "validate", "--config", invalid.to_str().unwrap()])
+        .output()
+        .expect("Failed to execute config validate command");
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("ocr.language:"), "stdout: {}", stdout);
+    assert!(stdout.contains("chunking:"), "stdout: {}", stdout);
+}
+
+#[test]
+fn test_config_merge_prints_merged_config() {
+    build_binary();
+
+    let dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let base = dir.path().join("base.toml");
+    std::fs::write(&base, "[ocr]\nbackend = \"tesseract\"\nlanguage = \"deu\"\n").unwrap();
+    let override_path = dir.path().join("override.json");
+    std::fs::write(
+        &override_path,
+        r#"{"use_cache": false, "chunking": {"max_characters": 500, "overlap": 50}}"#,
+    )
+    .unwrap();
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "config",
+            "merge",
+            base.to_str().unwrap(),
+            override_path.to_str().unwrap(),
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("Failed to execute config merge command");
+    assert!(
+        output.status.success(),
+        "Merge command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let merged: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Merged config should be JSON");
+    assert_eq!(merged["use_cache"], false);
+    assert_eq!(merged["ocr"]["language"], "deu");
+    assert_eq!(merged["chunking"]["max_characters"], 500);
+}
+
+#[test]
+fn test_version_verbose_json_reports_build_info() {
+    build_binary();
+
+    let output = Command::new(get_binary_path())
+        .args(["version", "--format", "json", "--verbose"])
+        .output()
+        .expect("Failed to execute version command");
+
+    assert!(
+        output.status.success(),
+        "Version command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Version output should be valid JSON");
+    let build = &json["build"];
+    assert!(build["version"].is_string());
+    assert!(
+        build["features"]
+            .as_array()
+            .is_some_and(|features| features.iter().any(|f| f == "pdf")),
+        "CLI builds enable the pdf feature: {build}"
+    );
+    assert!(
+        build["extractors"]
+            .as_array()
+            .is_some_and(|extractors| extractors.iter().any(|e| e == "pdf-extractor"))
+    );
+    assert!(build["ocr_backends"].is_array());
+}
+
+#[test]
+fn test_extract_tables_only_csv_and_json() {
+    build_binary();
+
+    let dir = tempdir().expect("Failed to create temp dir");
+    let csv_path = dir.path().join("people.csv");
+    std::fs::write(&csv_path, "Name,City\nAlice,\"Paris, FR\"\n").expect("Failed to write CSV");
+    let csv_path = csv_path.to_string_lossy().to_string();
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", csv_path.as_str(), "--tables-only"])
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(
+        output.status.success(),
+        "Extract --tables-only failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Name,City\nAlice,\"Paris, FR\"\n"
+    );
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", csv_path.as_str(), "--tables-only", "--format", "json"])
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(output.status.success());
+    let tables: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(tables.as_array().map(Vec::len), Some(1));
+    assert_eq!(tables[0]["cells"][1][1], "Paris, FR");
+}
+
+#[test]
+fn test_extract_tables_html() {
+    build_binary();
+
+    let dir = tempdir().expect("Failed to create temp dir");
+    let csv_path = dir.path().join("tags.csv");
+    std::fs::write(&csv_path, "Tag,Meaning\n<b>,Bold & strong\n").expect("Failed to write CSV");
+    let csv_path = csv_path.to_string_lossy().to_string();
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", csv_path.as_str(), "--tables-only", "--tables-html"])
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(
+        output.status.success(),
+        "Extract --tables-html failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let html = String::from_utf8_lossy(&output.stdout);
+    assert!(html.starts_with("<table>\n<thead>\n<tr><th>Tag</th><th>Meaning</th></tr>"));
+    assert!(html.contains("<tr><td>&lt;b&gt;</td><td>Bold &amp; strong</td></tr>"));
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", csv_path.as_str(), "--tables-html"])
+        .output()
+        .expect("Failed to execute extract command");
+    assert!(!output.status.success(), "--tables-html requires --tables-only");
+}
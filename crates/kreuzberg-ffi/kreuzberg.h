@@ -223,7 +223,7 @@ typedef struct CErrorDetails {
  * # Memory Layout
  *
  * Must be kept in sync with the Java side's MemoryLayout definition in KreuzbergFFI.java
- * Field order: 15 pointers (8 bytes each) + 1 bool + 7 bytes padding = 128 bytes total
+ * Field order: 17 pointers (8 bytes each) + 1 bool + 7 bytes padding = 144 bytes total
  *
  * The `#[repr(C)]` attribute ensures the struct follows C's memory layout rules:
  * - Fields are laid out in order
@@ -296,6 +296,14 @@ typedef struct CExtractionResult {
    * Document structure as JSON object (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
    */
   char *document_json;
+  /**
+   * Document outline as JSON array (null-terminated string, or NULL if not requested, must be freed with kreuzberg_free_string)
+   */
+  char *outline_json;
+  /**
+   * Positioned text blocks as JSON array (null-terminated string, or NULL if not requested, must be freed with kreuzberg_free_string)
+   */
+  char *text_blocks_json;
   /**
    * Whether extraction was successful
    */
@@ -443,6 +451,26 @@ typedef char *(*OcrBackendCallback)(const uint8_t *image_bytes,
  */
 typedef char *(*PostProcessorCallback)(const char *result_json);
 
+/**
+ * Type alias for the view-based PostProcessor callback function.
+ *
+ * # Parameters
+ *
+ * - `view`: Zero-copy view of the ExtractionResult (see `kreuzberg_get_result_view`)
+ *
+ * # Returns
+ *
+ * Null-terminated UTF-8 string with the new content of the result (must be freed by Rust
+ * via kreuzberg_free_string), or NULL to leave the result unchanged.
+ *
+ * # Safety
+ *
+ * The callback must:
+ * - Not store the view or any pointer in it (they are only valid for the duration of the call)
+ * - Return a valid null-terminated UTF-8 string or NULL
+ */
+typedef char *(*PostProcessorViewCallback)(const struct CExtractionResultView *view);
+
 /**
  * Validator callback function type for FFI.
  *
@@ -458,6 +486,23 @@ typedef char *(*PostProcessorCallback)(const char *result_json);
  */
 typedef char *(*ValidatorCallback)(const char *result_json);
 
+/**
+ * MIME detector callback function type for FFI.
+ *
+ * This is a C function pointer that detects the MIME type of a document from its
+ * first bytes. It is called synchronously, possibly from several threads at once.
+ *
+ * # Safety
+ *
+ * The callback must:
+ * - Not store the header pointer (it's only valid for the duration of the call)
+ * - Read at most `header_len` bytes from `header`
+ * - Return a valid null-terminated UTF-8 string (the MIME type) if it recognizes the document
+ * - Return NULL to leave the document to the other detectors
+ * - The returned string must be freeable by kreuzberg_free_string
+ */
+typedef char *(*MimeDetectorCallback)(const uint8_t *header, uintptr_t header_len);
+
 /**
  * Metadata field accessor structure
  *
@@ -1462,6 +1507,27 @@ struct CBatchResult *kreuzberg_batch_extract_files_sync(const char *const *file_
                                                         uintptr_t count,
                                                         const char *config_json);
 
+/**
+ * Batch extract text and metadata from multiple files, each with its own configuration (synchronous).
+ *
+ * # Safety
+ *
+ * - `file_paths` must be a valid pointer to an array of `count` null-terminated C strings
+ * - `config_jsons` must be a valid pointer to an array of `count` C strings containing JSON;
+ *   individual entries may be NULL to use the default config for that file
+ * - The returned pointer must be freed with `kreuzberg_free_batch_result`
+ * - Returns NULL on error (check `kreuzberg_last_error` for details)
+ *
+ * # Critical Memory Management
+ *
+ * This function shares the same critical memory management pattern as
+ * `kreuzberg_batch_extract_files_sync`. See that function's documentation
+ * for details on the Box/Vec/slice allocation pattern.
+ */
+struct CBatchResult *kreuzberg_batch_extract_files_with_configs_sync(const char *const *file_paths,
+                                                                     const char *const *config_jsons,
+                                                                     uintptr_t count);
+
 /**
  * Batch extract text and metadata from multiple byte arrays (synchronous).
  *
@@ -1673,7 +1739,7 @@ char *kreuzberg_clone_string(const char *s);
  *
  * # Memory Layout
  *
- * This function frees all 13 string fields in CExtractionResult:
+ * This function frees all 17 string fields in CExtractionResult:
  * 1. content
  * 2. mime_type
  * 3. language
@@ -1688,6 +1754,9 @@ char *kreuzberg_clone_string(const char *s);
  * 12. pages_json (FIXED: was missing before PR #3)
  * 13. elements_json (ADDED: for element-based extraction support)
  * 14. ocr_elements_json (ADDED: for OCR element output)
+ * 15. document_json
+ * 16. outline_json
+ * 17. text_blocks_json
  *
  * # Example (C)
  *
@@ -2114,6 +2183,42 @@ bool kreuzberg_register_post_processor_with_stage(const char *name,
                                                   int32_t priority,
                                                   const char *stage);
 
+/**
+ * Register a view-based PostProcessor.
+ *
+ * The callback receives a zero-copy view of the result instead of a JSON copy, so the
+ * result is neither serialized nor cloned while it runs. It can only replace the content.
+ *
+ * # Safety
+ *
+ * - `name` must be a valid null-terminated C string
+ * - `stage` must be NULL (middle) or a valid null-terminated C string containing "early",
+ *   "middle", or "late"
+ * - `callback` must be a valid function pointer that:
+ *   - Does not store the view or any pointer in it
+ *   - Returns a null-terminated UTF-8 string with the new content, or NULL to keep it
+ *   - The returned string must be freeable by kreuzberg_free_string
+ * - `priority` determines the order of execution within the stage (higher priority runs first)
+ * - Returns true on success, false on error (check kreuzberg_last_error)
+ *
+ * # Example (C)
+ *
+ * ```c
+ * char* trim_processor(const CExtractionResultView* view) {
+ *     if (view->content_len < 1000000) {
+ *         return NULL; // keep content
+ *     }
+ *     return strndup((const char*)view->content_ptr, 1000000);
+ * }
+ *
+ * bool success = kreuzberg_register_post_processor_view("trim", trim_processor, 100, NULL);
+ * ```
+ */
+bool kreuzberg_register_post_processor_view(const char *name,
+                                            PostProcessorViewCallback callback,
+                                            int32_t priority,
+                                            const char *stage);
+
 /**
  * Unregister a PostProcessor by name.
  *
@@ -2154,6 +2259,18 @@ bool kreuzberg_clear_post_processors(void);
  */
 char *kreuzberg_list_post_processors(void);
 
+/**
+ * Clear every plugin registry in one call.
+ *
+ * # Safety
+ *
+ * - Removes all registered OCR backends, document extractors, post-processors, and validators.
+ * - The default OCR backends are registered again as part of the reset.
+ * - Built-in document extractors are re-registered automatically on the next extraction.
+ * - Returns true on success, false on error (check `kreuzberg_last_error`).
+ */
+bool kreuzberg_reset_all_registries(void);
+
 /**
  * Register a custom Validator via FFI callback.
  *
@@ -2229,6 +2346,70 @@ bool kreuzberg_clear_validators(void);
  */
 char *kreuzberg_list_validators(void);
 
+/**
+ * Register a custom MIME detector via FFI callback.
+ *
+ * Registered detectors are asked before the built-in MIME detection.
+ *
+ * # Safety
+ *
+ * - `name` must be a valid null-terminated C string
+ * - `callback` must be a valid function pointer that:
+ *   - Does not store the header pointer
+ *   - Returns a null-terminated UTF-8 MIME type if it recognizes the document
+ *   - Returns NULL otherwise
+ *   - The returned string must be freeable by kreuzberg_free_string
+ * - Returns true on success, false on error (check kreuzberg_last_error)
+ *
+ * # Example (C)
+ *
+ * ```c
+ * char* ledger_detector(const uint8_t* header, size_t header_len) {
+ *     if (header_len >= 5 && memcmp(header, "LDGR\x01", 5) == 0) {
+ *         return strdup("application/x-ledger");
+ *     }
+ *     return NULL;
+ * }
+ *
+ * bool success = kreuzberg_register_mime_detector("ledger-detector", ledger_detector);
+ * if (!success) {
+ *     const char* error = kreuzberg_last_error();
+ *     printf("Failed to register: %s\n", error);
+ * }
+ * ```
+ */
+bool kreuzberg_register_mime_detector(const char *name, MimeDetectorCallback callback);
+
+/**
+ * Unregister a MIME detector by name.
+ *
+ * # Safety
+ *
+ * - `name` must be a valid null-terminated C string
+ * - Returns true on success, false on error (check kreuzberg_last_error)
+ */
+bool kreuzberg_unregister_mime_detector(const char *name);
+
+/**
+ * Clear all registered MIME detectors.
+ *
+ * # Safety
+ *
+ * - Removes all detectors. Subsequent detection only uses the built-in rules.
+ * - Returns true on success, false on error.
+ */
+bool kreuzberg_clear_mime_detectors(void);
+
+/**
+ * List all registered MIME detectors as a JSON array of names.
+ *
+ * # Safety
+ *
+ * - Returned string must be freed with `kreuzberg_free_string`.
+ * - Returns NULL on error (check `kreuzberg_last_error`).
+ */
+char *kreuzberg_list_mime_detectors(void);
+
 /**
  * Get page count from extraction result.
  *
@@ -8,58 +8,14 @@ use kreuzberg::core::config::ExtractionConfig;
 ///
 /// Performs a shallow merge where fields from `override_config` take
 /// precedence over fields in `base`. The `base` config is modified in-place.
+/// Delegates to [`ExtractionConfig::merge`] so the CLI shares the same semantics.
 ///
 /// # Arguments
 ///
 /// * `base` - Mutable reference to the base config (will be modified)
 /// * `override_config` - Reference to the override config (read-only)
 pub fn merge_configs(base: &mut ExtractionConfig, override_config: &ExtractionConfig) {
-    base.use_cache = override_config.use_cache;
-    base.enable_quality_processing = override_config.enable_quality_processing;
-    base.force_ocr = override_config.force_ocr;
-    base.max_concurrent_extractions = override_config.max_concurrent_extractions;
-
-    if override_config.ocr.is_some() {
-        base.ocr = override_config.ocr.clone();
-    }
-
-    if override_config.chunking.is_some() {
-        base.chunking = override_config.chunking.clone();
-    }
-
-    if override_config.images.is_some() {
-        base.images = override_config.images.clone();
-    }
-
-    #[cfg(feature = "pdf")]
-    if override_config.pdf_options.is_some() {
-        base.pdf_options = override_config.pdf_options.clone();
-    }
-
-    if override_config.token_reduction.is_some() {
-        base.token_reduction = override_config.token_reduction.clone();
-    }
-
-    if override_config.language_detection.is_some() {
-        base.language_detection = override_config.language_detection.clone();
-    }
-
-    if override_config.pages.is_some() {
-        base.pages = override_config.pages.clone();
-    }
-
-    #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
-    if override_config.keywords.is_some() {
-        base.keywords = override_config.keywords.clone();
-    }
-
-    if override_config.postprocessor.is_some() {
-        base.postprocessor = override_config.postprocessor.clone();
-    }
-
-    if override_config.html_options.is_some() {
-        base.html_options = override_config.html_options.clone();
-    }
+    base.merge(override_config);
 }
 
 #[cfg(test)]
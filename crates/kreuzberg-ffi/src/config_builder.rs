@@ -32,7 +32,7 @@ impl ConfigBuilder {
     }
 
     fn set_use_cache(&mut self, use_cache: bool) {
-        self.config.use_cache = use_cache;
+        self.config.use_cache = use_cache && !kreuzberg::cache::cache_disabled_by_env();
     }
 
     fn set_include_document_structure(&mut self, include: bool) {
@@ -194,6 +194,26 @@ impl ErrorCode {
     }
 }
 
+impl From<&kreuzberg::KreuzbergError> for ErrorCode {
+    /// Maps a native error to its FFI error code.
+    fn from(error: &kreuzberg::KreuzbergError) -> Self {
+        use kreuzberg::KreuzbergError;
+
+        match error {
+            KreuzbergError::Validation { .. } => ErrorCode::Validation,
+            KreuzbergError::Parsing { .. } | KreuzbergError::Serialization { .. } => ErrorCode::Parsing,
+            KreuzbergError::Ocr { .. } => ErrorCode::Ocr,
+            KreuzbergError::MissingDependency(_) => ErrorCode::MissingDependency,
+            KreuzbergError::Io(_) | KreuzbergError::Cache { .. } => ErrorCode::Io,
+            KreuzbergError::Plugin { .. } => ErrorCode::Plugin,
+            KreuzbergError::UnsupportedFormat { .. } => ErrorCode::UnsupportedFormat,
+            KreuzbergError::ImageProcessing { .. } | KreuzbergError::LockPoisoned(_) | KreuzbergError::Other(_) => {
+                ErrorCode::Internal
+            }
+        }
+    }
+}
+
 /// Returns the validation error code (0).
 ///
 /// # C Signature
@@ -721,6 +741,16 @@ pub unsafe extern "C" fn kreuzberg_classify_error(error_message: *const c_char)
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_error_code_from_kreuzberg_error() {
+        let error = kreuzberg::KreuzbergError::unsupported_format_with_extension("image/vnd.adobe.photoshop", "psd");
+        assert_eq!(ErrorCode::from(&error), ErrorCode::UnsupportedFormat);
+        assert_eq!(
+            ErrorCode::from(&kreuzberg::KreuzbergError::parsing("corrupt")),
+            ErrorCode::Parsing
+        );
+    }
+
     #[test]
     fn test_error_code_values() {
         assert_eq!(ErrorCode::Validation as u32, 0);
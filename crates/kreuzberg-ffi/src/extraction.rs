@@ -443,6 +443,115 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_files_sync(
     })
 }
 
+/// Batch extract text and metadata from multiple files, each with its own configuration (synchronous).
+///
+/// # Safety
+///
+/// - `file_paths` must be a valid pointer to an array of `count` null-terminated C strings
+/// - `config_jsons` must be a valid pointer to an array of `count` C strings containing JSON;
+///   individual entries may be NULL to use the default config for that file
+/// - The returned pointer must be freed with `kreuzberg_free_batch_result`
+/// - Returns NULL on error (check `kreuzberg_last_error` for details)
+///
+/// # Critical Memory Management
+///
+/// This function shares the same critical memory management pattern as
+/// `kreuzberg_batch_extract_files_sync`. See that function's documentation
+/// for details on the Box/Vec/slice allocation pattern.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_batch_extract_files_with_configs_sync(
+    file_paths: *const *const c_char,
+    config_jsons: *const *const c_char,
+    count: usize,
+) -> *mut CBatchResult {
+    ffi_panic_guard!("kreuzberg_batch_extract_files_with_configs_sync", {
+        clear_last_error();
+
+        if file_paths.is_null() {
+            set_last_error("file_paths cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        if config_jsons.is_null() {
+            set_last_error("config_jsons cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        let mut items: Vec<(std::path::PathBuf, ExtractionConfig)> = Vec::with_capacity(count);
+        for i in 0..count {
+            let path_ptr = unsafe { *file_paths.add(i) };
+            if path_ptr.is_null() {
+                set_last_error(format!("File path at index {} is NULL", i));
+                return ptr::null_mut();
+            }
+
+            let path_str = match unsafe { CStr::from_ptr(path_ptr) }.to_str() {
+                Ok(s) => s,
+                Err(e) => {
+                    set_last_error(format!("Invalid UTF-8 in file path at index {}: {}", i, e));
+                    return ptr::null_mut();
+                }
+            };
+
+            let config_ptr = unsafe { *config_jsons.add(i) };
+            let config = if config_ptr.is_null() {
+                ExtractionConfig::default()
+            } else {
+                let config_str = match unsafe { CStr::from_ptr(config_ptr) }.to_str() {
+                    Ok(s) => s,
+                    Err(e) => {
+                        set_last_error(format!("Invalid UTF-8 in config JSON at index {}: {}", i, e));
+                        return ptr::null_mut();
+                    }
+                };
+
+                match parse_extraction_config_from_json(config_str) {
+                    Ok(cfg) => cfg,
+                    Err(e) => {
+                        set_last_error(format!("Invalid config at index {}: {}", i, e));
+                        return ptr::null_mut();
+                    }
+                }
+            };
+
+            items.push((std::path::PathBuf::from(path_str), config));
+        }
+
+        match kreuzberg::batch_extract_file_with_configs_sync(items) {
+            Ok(results) => {
+                let mut c_results = Vec::with_capacity(results.len());
+                for result in results {
+                    match to_c_extraction_result(result) {
+                        Ok(ptr) => c_results.push(ptr),
+                        Err(e) => {
+                            for c_res in c_results {
+                                unsafe { kreuzberg_free_result(c_res) };
+                            }
+                            set_last_error(e);
+                            return ptr::null_mut();
+                        }
+                    }
+                }
+
+                let actual_count = c_results.len();
+                let results_array = c_results.into_boxed_slice();
+                let results_ptr = Box::into_raw(results_array) as *mut *mut CExtractionResult;
+
+                Box::into_raw(Box::new(CBatchResult {
+                    results: results_ptr,
+                    count: actual_count,
+                    success: true,
+                    _padding2: [0u8; 7],
+                }))
+            }
+            Err(e) => {
+                set_last_error(e.to_string());
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
 /// Batch extract text and metadata from multiple byte arrays (synchronous).
 ///
 /// # Safety
@@ -70,6 +70,9 @@ pub fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*
         elements,
         ocr_elements,
         document,
+        outline,
+        formulas: _,
+        text_blocks,
     } = result;
 
     let sanitized_content = if content.contains('\0') {
@@ -214,6 +217,28 @@ pub fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*
         _ => None,
     };
 
+    let outline_json_guard = match outline {
+        Some(outline) => {
+            let json =
+                serde_json::to_string(&outline).map_err(|e| format!("Failed to serialize outline to JSON: {}", e))?;
+            Some(CStringGuard::new(CString::new(json).map_err(|e| {
+                format!("Failed to convert outline JSON to C string: {}", e)
+            })?))
+        }
+        _ => None,
+    };
+
+    let text_blocks_json_guard = match text_blocks {
+        Some(text_blocks) => {
+            let json = serde_json::to_string(&text_blocks)
+                .map_err(|e| format!("Failed to serialize text blocks to JSON: {}", e))?;
+            Some(CStringGuard::new(CString::new(json).map_err(|e| {
+                format!("Failed to convert text blocks JSON to C string: {}", e)
+            })?))
+        }
+        _ => None,
+    };
+
     Ok(Box::into_raw(Box::new(CExtractionResult {
         content: content_guard.into_raw(),
         mime_type: mime_type_guard.into_raw(),
@@ -230,6 +255,8 @@ pub fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*
         elements_json: elements_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
         ocr_elements_json: ocr_elements_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
         document_json: document_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
+        outline_json: outline_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
+        text_blocks_json: text_blocks_json_guard.map_or(ptr::null_mut(), |g| g.into_raw()),
         success: true,
         _padding1: [0u8; 7],
     })))
@@ -390,6 +417,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let c_result = to_c_extraction_result(result);
@@ -431,6 +463,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let c_result = to_c_extraction_result(result);
@@ -482,6 +519,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let c_result = to_c_extraction_result(result);
@@ -534,6 +576,9 @@ mod tests {
             ],
             markdown: "| Col1 | Col2 |\n|------|------|\n| A1 | A2 |\n| B1 | B2 |".to_string(),
             page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         };
 
         let chunk = Chunk {
@@ -547,6 +592,7 @@ mod tests {
                 total_chunks: 1,
                 first_page: Some(1),
                 last_page: Some(1),
+                heading_path: None,
             },
         };
 
@@ -563,6 +609,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let c_result = to_c_extraction_result(result);
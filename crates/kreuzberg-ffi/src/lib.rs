@@ -45,7 +45,8 @@ pub use error::{
     kreuzberg_error_code_unsupported_format, kreuzberg_error_code_validation, kreuzberg_get_error_details,
 };
 pub use extraction::{
-    kreuzberg_batch_extract_bytes_sync, kreuzberg_batch_extract_files_sync, kreuzberg_extract_bytes_sync,
+    kreuzberg_batch_extract_bytes_sync, kreuzberg_batch_extract_files_sync,
+    kreuzberg_batch_extract_files_with_configs_sync, kreuzberg_extract_bytes_sync,
     kreuzberg_extract_bytes_sync_with_config, kreuzberg_extract_file_sync, kreuzberg_extract_file_sync_with_config,
 };
 pub use helpers::*;
@@ -139,8 +140,8 @@ mod tests {
         // Test size
         assert_eq!(
             std::mem::size_of::<CExtractionResult>(),
-            128,
-            "CExtractionResult must be exactly 128 bytes"
+            144,
+            "CExtractionResult must be exactly 144 bytes"
         );
 
         // Test alignment
@@ -205,6 +206,8 @@ mod tests {
             elements_json: ptr::null_mut(),
             ocr_elements_json: ptr::null_mut(),
             document_json: ptr::null_mut(),
+            outline_json: ptr::null_mut(),
+            text_blocks_json: ptr::null_mut(),
             success: true,
             _padding1: [0u8; 7],
         }))
@@ -501,6 +504,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_batch_extract_files_with_configs() {
+        unsafe {
+            let dir = tempfile::tempdir().unwrap();
+            let file1 = dir.path().join("first.txt");
+            let file2 = dir.path().join("second.txt");
+            std::fs::write(&file1, "first document").unwrap();
+            std::fs::write(&file2, "second document").unwrap();
+
+            let path1 = CString::new(file1.to_str().unwrap()).unwrap();
+            let path2 = CString::new(file2.to_str().unwrap()).unwrap();
+            let paths = [path1.as_ptr(), path2.as_ptr()];
+            let truncating = CString::new(r#"{"max_content_chars": 6}"#).unwrap();
+            let configs = [ptr::null(), truncating.as_ptr()];
+
+            let result = kreuzberg_batch_extract_files_with_configs_sync(paths.as_ptr(), configs.as_ptr(), 2);
+            assert!(!result.is_null(), "Batch extraction should succeed");
+
+            let batch = &*result;
+            assert_eq!(batch.count, 2);
+            let results = std::slice::from_raw_parts(batch.results, batch.count);
+            let first = CStr::from_ptr((*results[0]).content).to_str().unwrap();
+            let second = CStr::from_ptr((*results[1]).content).to_str().unwrap();
+            assert!(first.contains("first document"));
+            assert_eq!(second, "second");
+
+            kreuzberg_free_batch_result(result);
+        }
+    }
+
+    #[test]
+    fn test_batch_extract_files_with_configs_null_configs() {
+        unsafe {
+            let path = CString::new("/tmp/test1.txt").unwrap();
+            let paths = [path.as_ptr()];
+
+            let result = kreuzberg_batch_extract_files_with_configs_sync(paths.as_ptr(), ptr::null(), 1);
+            assert!(result.is_null());
+        }
+    }
+
     #[test]
     fn test_extraction_result_free_with_null_fields() {
         unsafe {
@@ -521,6 +565,8 @@ mod tests {
                 elements_json: ptr::null_mut(),
                 ocr_elements_json: ptr::null_mut(),
                 document_json: ptr::null_mut(),
+                outline_json: ptr::null_mut(),
+                text_blocks_json: ptr::null_mut(),
                 success: true,
                 _padding1: [0u8; 7],
             }));
@@ -551,6 +597,8 @@ mod tests {
                 elements_json: CString::new("[]").unwrap().into_raw(),
                 ocr_elements_json: ptr::null_mut(),
                 document_json: ptr::null_mut(),
+                outline_json: ptr::null_mut(),
+                text_blocks_json: ptr::null_mut(),
                 success: true,
                 _padding1: [0u8; 7],
             }));
@@ -635,7 +683,7 @@ mod tests {
     /// Test CExtractionResult size exactly matches FFI contract
     #[test]
     fn test_c_extraction_result_size() {
-        assert_eq!(std::mem::size_of::<CExtractionResult>(), 128);
+        assert_eq!(std::mem::size_of::<CExtractionResult>(), 144);
         assert_eq!(std::mem::align_of::<CExtractionResult>(), 8);
     }
 
@@ -745,6 +793,30 @@ mod tests {
         }
     }
 
+    /// Test that a registered MIME detector callback is used for detection
+    #[test]
+    fn test_register_mime_detector_detects_bytes() {
+        unsafe extern "C" fn ledger_detector(header: *const u8, header_len: usize) -> *mut c_char {
+            let header = unsafe { std::slice::from_raw_parts(header, header_len) };
+            if header.starts_with(b"LDGR\x01") {
+                CString::new("application/x-ffi-ledger").unwrap().into_raw()
+            } else {
+                ptr::null_mut()
+            }
+        }
+
+        unsafe {
+            assert!(!kreuzberg_register_mime_detector(ptr::null(), ledger_detector));
+
+            let name = CString::new("ffi-ledger-detector").unwrap();
+            assert!(kreuzberg_register_mime_detector(name.as_ptr(), ledger_detector));
+            let detected = kreuzberg::core::mime::detect_mime_type_from_bytes(b"LDGR\x01balance");
+            assert!(kreuzberg_unregister_mime_detector(name.as_ptr()));
+
+            assert_eq!(detected.unwrap(), "application/x-ffi-ledger");
+        }
+    }
+
     /// Test that kreuzberg_unregister_validator handles NULL name
     #[test]
     fn test_unregister_validator_null_name() {
@@ -890,6 +962,16 @@ mod tests {
         }
     }
 
+    /// Test resetting all registries doesn't crash
+    #[test]
+    fn test_reset_all_registries_doesnt_crash() {
+        unsafe {
+            // This should not crash even if called multiple times
+            assert!(kreuzberg_reset_all_registries());
+            assert!(kreuzberg_reset_all_registries());
+        }
+    }
+
     /// Test clearing document extractors doesn't crash
     #[test]
     fn test_clear_document_extractors_doesnt_crash() {
@@ -917,6 +999,10 @@ mod tests {
             assert!(!validators.is_null(), "list_validators should return non-NULL");
             kreuzberg_free_string(validators);
 
+            let detectors = kreuzberg_list_mime_detectors();
+            assert!(!detectors.is_null(), "list_mime_detectors should return non-NULL");
+            kreuzberg_free_string(detectors);
+
             let extractors = kreuzberg_list_document_extractors();
             assert!(!extractors.is_null(), "list_document_extractors should return non-NULL");
             kreuzberg_free_string(extractors);
@@ -146,7 +146,7 @@ pub unsafe extern "C" fn kreuzberg_clone_string(s: *const c_char) -> *mut c_char
 ///
 /// # Memory Layout
 ///
-/// This function frees all 13 string fields in CExtractionResult:
+/// This function frees all 17 string fields in CExtractionResult:
 /// 1. content
 /// 2. mime_type
 /// 3. language
@@ -161,6 +161,9 @@ pub unsafe extern "C" fn kreuzberg_clone_string(s: *const c_char) -> *mut c_char
 /// 12. pages_json (FIXED: was missing before PR #3)
 /// 13. elements_json (ADDED: for element-based extraction support)
 /// 14. ocr_elements_json (ADDED: for OCR element output)
+/// 15. document_json
+/// 16. outline_json
+/// 17. text_blocks_json
 ///
 /// # Example (C)
 ///
@@ -220,6 +223,12 @@ pub unsafe extern "C" fn kreuzberg_free_result(result: *mut CExtractionResult) {
         if !result_box.document_json.is_null() {
             unsafe { drop(CString::from_raw(result_box.document_json)) };
         }
+        if !result_box.outline_json.is_null() {
+            unsafe { drop(CString::from_raw(result_box.outline_json)) };
+        }
+        if !result_box.text_blocks_json.is_null() {
+            unsafe { drop(CString::from_raw(result_box.text_blocks_json)) };
+        }
     }
 }
 
@@ -246,6 +255,8 @@ mod tests {
             elements_json: CString::new("[]").unwrap().into_raw(),
             ocr_elements_json: ptr::null_mut(),
             document_json: ptr::null_mut(),
+            outline_json: ptr::null_mut(),
+            text_blocks_json: ptr::null_mut(),
             success: true,
             _padding1: [0u8; 7],
         }))
@@ -269,6 +280,8 @@ mod tests {
             elements_json: ptr::null_mut(),
             ocr_elements_json: ptr::null_mut(),
             document_json: ptr::null_mut(),
+            outline_json: ptr::null_mut(),
+            text_blocks_json: ptr::null_mut(),
             success: true,
             _padding1: [0u8; 7],
         }))
@@ -363,6 +376,8 @@ mod tests {
             elements_json: ptr::null_mut(),
             ocr_elements_json: ptr::null_mut(),
             document_json: ptr::null_mut(),
+            outline_json: ptr::null_mut(),
+            text_blocks_json: ptr::null_mut(),
             success: true,
             _padding1: [0u8; 7],
         }));
@@ -392,6 +407,8 @@ mod tests {
                 .into_raw(),
             ocr_elements_json: ptr::null_mut(),
             document_json: ptr::null_mut(),
+            outline_json: ptr::null_mut(),
+            text_blocks_json: ptr::null_mut(),
             success: true,
             _padding1: [0u8; 7],
         }));
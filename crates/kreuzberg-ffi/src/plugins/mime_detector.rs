@@ -0,0 +1,225 @@
+//! MIME detector plugin FFI bindings
+//!
+//! Provides FFI bindings for registering and managing custom MIME detectors.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr;
+use std::sync::Arc;
+
+use kreuzberg::Result;
+use kreuzberg::plugins::{MimeDetector, Plugin};
+
+use crate::helpers::{clear_last_error, set_last_error};
+use crate::{ffi_panic_guard, ffi_panic_guard_bool};
+
+/// MIME detector callback function type for FFI.
+///
+/// This is a C function pointer that detects the MIME type of a document from its
+/// first bytes. It is called synchronously, possibly from several threads at once.
+///
+/// # Safety
+///
+/// The callback must:
+/// - Not store the header pointer (it's only valid for the duration of the call)
+/// - Read at most `header_len` bytes from `header`
+/// - Return a valid null-terminated UTF-8 string (the MIME type) if it recognizes the document
+/// - Return NULL to leave the document to the other detectors
+/// - The returned string must be freeable by kreuzberg_free_string
+type MimeDetectorCallback = unsafe extern "C" fn(header: *const u8, header_len: usize) -> *mut c_char;
+
+/// FFI wrapper for custom MIME detectors registered from Java/C.
+///
+/// This struct wraps a C function pointer and implements the MimeDetector trait,
+/// allowing custom detection implementations from FFI languages to be registered
+/// and used by the Rust MIME detection.
+struct FfiMimeDetector {
+    name: String,
+    callback: MimeDetectorCallback,
+}
+
+impl Plugin for FfiMimeDetector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> String {
+        "ffi-1.0.0".to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl MimeDetector for FfiMimeDetector {
+    fn detect(&self, header: &[u8]) -> Option<String> {
+        let mime_ptr = unsafe { (self.callback)(header.as_ptr(), header.len()) };
+        if mime_ptr.is_null() {
+            return None;
+        }
+
+        // A MIME type that is not valid UTF-8 is treated as not recognizing the document ~keep
+        let mime_type = unsafe { CStr::from_ptr(mime_ptr) }.to_str().map(str::to_string).ok();
+        unsafe { crate::memory::kreuzberg_free_string(mime_ptr) };
+
+        mime_type
+    }
+}
+
+/// Register a custom MIME detector via FFI callback.
+///
+/// Registered detectors are asked before the built-in MIME detection.
+///
+/// # Safety
+///
+/// - `name` must be a valid null-terminated C string
+/// - `callback` must be a valid function pointer that:
+///   - Does not store the header pointer
+///   - Returns a null-terminated UTF-8 MIME type if it recognizes the document
+///   - Returns NULL otherwise
+///   - The returned string must be freeable by kreuzberg_free_string
+/// - Returns true on success, false on error (check kreuzberg_last_error)
+///
+/// # Example (C)
+///
+/// ```c
+/// char* ledger_detector(const uint8_t* header, size_t header_len) {
+///     if (header_len >= 5 && memcmp(header, "LDGR\x01", 5) == 0) {
+///         return strdup("application/x-ledger");
+///     }
+///     return NULL;
+/// }
+///
+/// bool success = kreuzberg_register_mime_detector("ledger-detector", ledger_detector);
+/// if (!success) {
+///     const char* error = kreuzberg_last_error();
+///     printf("Failed to register: %s\n", error);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_register_mime_detector(name: *const c_char, callback: MimeDetectorCallback) -> bool {
+    ffi_panic_guard_bool!("kreuzberg_register_mime_detector", {
+        clear_last_error();
+
+        if name.is_null() {
+            set_last_error("MIME detector name cannot be NULL".to_string());
+            return false;
+        }
+
+        let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in MIME detector name: {}", e));
+                return false;
+            }
+        };
+
+        let detector = Arc::new(FfiMimeDetector {
+            name: name_str.to_string(),
+            callback,
+        });
+
+        match kreuzberg::plugins::register_mime_detector(detector) {
+            Ok(()) => true,
+            Err(e) => {
+                set_last_error(format!("Failed to register MIME detector: {}", e));
+                false
+            }
+        }
+    })
+}
+
+/// Unregister a MIME detector by name.
+///
+/// # Safety
+///
+/// - `name` must be a valid null-terminated C string
+/// - Returns true on success, false on error (check kreuzberg_last_error)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_unregister_mime_detector(name: *const c_char) -> bool {
+    ffi_panic_guard_bool!("kreuzberg_unregister_mime_detector", {
+        clear_last_error();
+
+        if name.is_null() {
+            set_last_error("MIME detector name cannot be NULL".to_string());
+            return false;
+        }
+
+        let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in MIME detector name: {}", e));
+                return false;
+            }
+        };
+
+        match kreuzberg::plugins::unregister_mime_detector(name_str) {
+            Ok(()) => true,
+            Err(e) => {
+                set_last_error(format!("Failed to remove MIME detector: {}", e));
+                false
+            }
+        }
+    })
+}
+
+/// Clear all registered MIME detectors.
+///
+/// # Safety
+///
+/// - Removes all detectors. Subsequent detection only uses the built-in rules.
+/// - Returns true on success, false on error.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_clear_mime_detectors() -> bool {
+    ffi_panic_guard_bool!("kreuzberg_clear_mime_detectors", {
+        clear_last_error();
+
+        match kreuzberg::plugins::clear_mime_detectors() {
+            Ok(()) => true,
+            Err(e) => {
+                set_last_error(format!("Failed to clear MIME detectors: {}", e));
+                false
+            }
+        }
+    })
+}
+
+/// List all registered MIME detectors as a JSON array of names.
+///
+/// # Safety
+///
+/// - Returned string must be freed with `kreuzberg_free_string`.
+/// - Returns NULL on error (check `kreuzberg_last_error`).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_list_mime_detectors() -> *mut c_char {
+    ffi_panic_guard!("kreuzberg_list_mime_detectors", {
+        clear_last_error();
+
+        let names = match kreuzberg::plugins::list_mime_detectors() {
+            Ok(names) => names,
+            Err(e) => {
+                set_last_error(format!("Failed to list MIME detectors: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        match serde_json::to_string(&names) {
+            Ok(json) => match CString::new(json) {
+                Ok(cstr) => cstr.into_raw(),
+                Err(e) => {
+                    set_last_error(format!("Failed to create C string: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_last_error(format!("Failed to serialize MIME detector list: {}", e));
+                ptr::null_mut()
+            }
+        }
+    })
+}
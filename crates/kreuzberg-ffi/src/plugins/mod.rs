@@ -3,12 +3,16 @@
 //! Provides FFI bindings for registering and managing plugins.
 
 pub mod document_extractor;
+pub mod mime_detector;
 pub mod ocr_backend;
 pub mod post_processor;
+pub mod registry;
 pub mod validator;
 
 // Re-export all public items
 pub use document_extractor::*;
+pub use mime_detector::*;
 pub use ocr_backend::*;
 pub use post_processor::*;
+pub use registry::*;
 pub use validator::*;
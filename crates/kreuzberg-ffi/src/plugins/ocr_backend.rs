@@ -171,6 +171,11 @@ impl OcrBackend for FfiOcrBackend {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
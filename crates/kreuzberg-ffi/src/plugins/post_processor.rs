@@ -16,6 +16,7 @@ use kreuzberg::{KreuzbergError, Result};
 
 use crate::helpers::{clear_last_error, set_last_error};
 use crate::memory::kreuzberg_free_string;
+use crate::result_view::{CExtractionResultView, create_result_view};
 use crate::{ffi_panic_guard, ffi_panic_guard_bool};
 
 /// Type alias for the PostProcessor callback function.
@@ -37,6 +38,24 @@ use crate::{ffi_panic_guard, ffi_panic_guard_bool};
 /// - Return NULL on error (error message should be retrievable separately)
 pub type PostProcessorCallback = unsafe extern "C" fn(result_json: *const c_char) -> *mut c_char;
 
+/// Type alias for the view-based PostProcessor callback function.
+///
+/// # Parameters
+///
+/// - `view`: Zero-copy view of the ExtractionResult (see `kreuzberg_get_result_view`)
+///
+/// # Returns
+///
+/// Null-terminated UTF-8 string with the new content of the result (must be freed by Rust
+/// via kreuzberg_free_string), or NULL to leave the result unchanged.
+///
+/// # Safety
+///
+/// The callback must:
+/// - Not store the view or any pointer in it (they are only valid for the duration of the call)
+/// - Return a valid null-terminated UTF-8 string or NULL
+pub type PostProcessorViewCallback = unsafe extern "C" fn(view: *const CExtractionResultView) -> *mut c_char;
+
 /// FFI wrapper for custom PostProcessors registered from Java/C.
 ///
 /// This struct wraps a C function pointer and implements the PostProcessor trait,
@@ -74,22 +93,27 @@ impl Plugin for FfiPostProcessor {
 
 #[async_trait]
 impl kreuzberg::plugins::PostProcessor for FfiPostProcessor {
-    async fn process(&self, result: &mut ExtractionResult, _config: &ExtractionConfig) -> Result<()> {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
         let result_json = serde_json::to_string(&*result).map_err(|e| KreuzbergError::Validation {
             message: format!("Failed to serialize ExtractionResult: {}", e),
             source: Some(Box::new(e)),
         })?;
+        let result_cstring = CString::new(result_json).map_err(|e| KreuzbergError::Validation {
+            message: format!("Failed to create C string from result JSON: {}", e),
+            source: Some(Box::new(e)),
+        })?;
+
+        // The callback only sees the JSON, so in low-memory mode the result itself is released
+        // until the processed result replaces it. Every failure past this point is a Plugin
+        // error, which aborts the extraction instead of leaving the emptied result behind.
+        if config.low_memory {
+            drop(result.take());
+        }
 
         let callback = self.callback;
         let processor_name = self.name.clone();
-        let result_json_owned = result_json.clone();
 
         let processed_json = tokio::task::spawn_blocking(move || {
-            let result_cstring = CString::new(result_json_owned).map_err(|e| KreuzbergError::Validation {
-                message: format!("Failed to create C string from result JSON: {}", e),
-                source: Some(Box::new(e)),
-            })?;
-
             let processed_ptr = unsafe { callback(result_cstring.as_ptr()) };
 
             if processed_ptr.is_null() {
@@ -134,6 +158,79 @@ impl kreuzberg::plugins::PostProcessor for FfiPostProcessor {
     }
 }
 
+/// FFI wrapper for view-based PostProcessors.
+///
+/// The callback reads the result through a borrowed view instead of a JSON copy, so
+/// nothing is serialized or cloned; it can only replace the content.
+struct FfiViewPostProcessor {
+    name: String,
+    callback: PostProcessorViewCallback,
+    stage: ProcessingStage,
+}
+
+impl Plugin for FfiViewPostProcessor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> String {
+        "ffi-1.0.0".to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl kreuzberg::plugins::PostProcessor for FfiViewPostProcessor {
+    async fn process(&self, result: &mut ExtractionResult, _config: &ExtractionConfig) -> Result<()> {
+        let callback = self.callback;
+
+        let call = || {
+            let view = create_result_view(result);
+            let content_ptr = unsafe { callback(&view) };
+
+            if content_ptr.is_null() {
+                return Ok(None);
+            }
+
+            let content = unsafe { CStr::from_ptr(content_ptr) }
+                .to_str()
+                .map(str::to_string)
+                .map_err(|e| KreuzbergError::Plugin {
+                    message: format!("PostProcessor returned invalid UTF-8: {}", e),
+                    plugin_name: self.name.clone(),
+                });
+
+            unsafe { kreuzberg_free_string(content_ptr) };
+
+            content.map(Some)
+        };
+
+        // `block_in_place` panics on a current-thread runtime, where the callback is
+        // called directly instead.
+        let new_content = match tokio::runtime::Handle::try_current().map(|handle| handle.runtime_flavor()) {
+            Ok(tokio::runtime::RuntimeFlavor::CurrentThread) => call(),
+            _ => tokio::task::block_in_place(call),
+        }?;
+
+        if let Some(content) = new_content {
+            result.content = content;
+        }
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> kreuzberg::plugins::ProcessingStage {
+        self.stage
+    }
+}
+
 fn parse_processing_stage(stage: Option<&str>) -> std::result::Result<ProcessingStage, String> {
     match stage {
         Some(value) => match value.to_lowercase().as_str() {
@@ -320,6 +417,114 @@ pub unsafe extern "C" fn kreuzberg_register_post_processor_with_stage(
     })
 }
 
+/// Register a view-based PostProcessor.
+///
+/// The callback receives a zero-copy view of the result instead of a JSON copy, so the
+/// result is neither serialized nor cloned while it runs. It can only replace the content.
+///
+/// # Safety
+///
+/// - `name` must be a valid null-terminated C string
+/// - `stage` must be NULL (middle) or a valid null-terminated C string containing "early",
+///   "middle", or "late"
+/// - `callback` must be a valid function pointer that:
+///   - Does not store the view or any pointer in it
+///   - Returns a null-terminated UTF-8 string with the new content, or NULL to keep it
+///   - The returned string must be freeable by kreuzberg_free_string
+/// - `priority` determines the order of execution within the stage (higher priority runs first)
+/// - Returns true on success, false on error (check kreuzberg_last_error)
+///
+/// # Example (C)
+///
+/// ```c
+/// char* trim_processor(const CExtractionResultView* view) {
+///     if (view->content_len < 1000000) {
+///         return NULL; // keep content
+///     }
+///     return strndup((const char*)view->content_ptr, 1000000);
+/// }
+///
+/// bool success = kreuzberg_register_post_processor_view("trim", trim_processor, 100, NULL);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_register_post_processor_view(
+    name: *const c_char,
+    callback: PostProcessorViewCallback,
+    priority: i32,
+    stage: *const c_char,
+) -> bool {
+    ffi_panic_guard_bool!("kreuzberg_register_post_processor_view", {
+        clear_last_error();
+
+        if name.is_null() {
+            set_last_error("PostProcessor name cannot be NULL".to_string());
+            return false;
+        }
+
+        let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in PostProcessor name: {}", e));
+                return false;
+            }
+        };
+
+        if name_str.is_empty() {
+            set_last_error("Plugin name cannot be empty".to_string());
+            return false;
+        }
+
+        if name_str.chars().any(|c| c.is_whitespace()) {
+            set_last_error("Plugin name cannot contain whitespace".to_string());
+            return false;
+        }
+
+        let stage_str = if stage.is_null() {
+            None
+        } else {
+            match unsafe { CStr::from_ptr(stage) }.to_str() {
+                Ok(s) => Some(s),
+                Err(e) => {
+                    set_last_error(format!("Invalid UTF-8 in processing stage: {}", e));
+                    return false;
+                }
+            }
+        };
+
+        let stage = match parse_processing_stage(stage_str) {
+            Ok(stage) => stage,
+            Err(e) => {
+                set_last_error(e);
+                return false;
+            }
+        };
+
+        let processor = Arc::new(FfiViewPostProcessor {
+            name: name_str.to_string(),
+            callback,
+            stage,
+        });
+
+        let registry = kreuzberg::plugins::registry::get_post_processor_registry();
+        let mut registry_guard = match registry.write() {
+            Ok(guard) => guard,
+            Err(e) => {
+                // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+                set_last_error(format!("Failed to acquire registry write lock: {}", e));
+                return false;
+            }
+        };
+
+        match registry_guard.register(processor, priority) {
+            Ok(()) => true,
+            Err(e) => {
+                set_last_error(format!("Failed to register PostProcessor: {}", e));
+                false
+            }
+        }
+    })
+}
+
 /// Unregister a PostProcessor by name.
 ///
 /// # Safety
@@ -0,0 +1,29 @@
+//! Registry-wide FFI bindings
+//!
+//! Provides FFI functions that operate on all plugin registries at once.
+
+use crate::ffi_panic_guard_bool;
+use crate::helpers::{clear_last_error, set_last_error};
+
+/// Clear every plugin registry in one call.
+///
+/// # Safety
+///
+/// - Removes all registered OCR backends, document extractors, post-processors, and validators.
+/// - The default OCR backends are registered again as part of the reset.
+/// - Built-in document extractors are re-registered automatically on the next extraction.
+/// - Returns true on success, false on error (check `kreuzberg_last_error`).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_reset_all_registries() -> bool {
+    ffi_panic_guard_bool!("kreuzberg_reset_all_registries", {
+        clear_last_error();
+
+        match kreuzberg::plugins::reset_all_registries() {
+            Ok(()) => true,
+            Err(e) => {
+                set_last_error(format!("Failed to reset plugin registries: {}", e));
+                false
+            }
+        }
+    })
+}
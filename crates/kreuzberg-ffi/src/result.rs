@@ -406,6 +406,7 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        heading_path: None,
                     },
                 },
                 kreuzberg::types::Chunk {
@@ -419,6 +420,7 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        heading_path: None,
                     },
                 },
             ]),
@@ -428,6 +430,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         }
     }
 
@@ -436,6 +436,7 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        heading_path: None,
                     },
                 },
                 kreuzberg::types::Chunk {
@@ -449,6 +450,7 @@ mod tests {
                         total_chunks: 2,
                         first_page: None,
                         last_page: None,
+                        heading_path: None,
                     },
                 },
             ]),
@@ -458,6 +460,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         }
     }
 
@@ -726,6 +733,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let result_ptr = &result as *const ExtractionResult;
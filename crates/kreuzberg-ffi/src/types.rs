@@ -51,7 +51,7 @@ impl Drop for CStringGuard {
 /// # Memory Layout
 ///
 /// Must be kept in sync with the Java side's MemoryLayout definition in KreuzbergFFI.java
-/// Field order: 15 pointers (8 bytes each) + 1 bool + 7 bytes padding = 128 bytes total
+/// Field order: 17 pointers (8 bytes each) + 1 bool + 7 bytes padding = 144 bytes total
 ///
 /// The `#[repr(C)]` attribute ensures the struct follows C's memory layout rules:
 /// - Fields are laid out in order
@@ -94,6 +94,10 @@ pub struct CExtractionResult {
     pub ocr_elements_json: *mut c_char,
     /// Document structure as JSON object (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
     pub document_json: *mut c_char,
+    /// Document outline as JSON array (null-terminated string, or NULL if not requested, must be freed with kreuzberg_free_string)
+    pub outline_json: *mut c_char,
+    /// Positioned text blocks as JSON array (null-terminated string, or NULL if not requested, must be freed with kreuzberg_free_string)
+    pub text_blocks_json: *mut c_char,
     /// Whether extraction was successful
     pub success: bool,
     /// Padding to match Java MemoryLayout (7 bytes padding to align to 8-byte boundary)
@@ -156,7 +160,7 @@ pub struct CBatchResult {
 const _: () = {
     const fn assert_c_extraction_result_size() {
         const SIZE: usize = std::mem::size_of::<CExtractionResult>();
-        const _: () = assert!(SIZE == 128, "CExtractionResult size must be 128 bytes");
+        const _: () = assert!(SIZE == 144, "CExtractionResult size must be 144 bytes");
     }
 
     const fn assert_c_extraction_result_alignment() {
@@ -201,8 +205,8 @@ mod tests {
     fn test_c_extraction_result_size() {
         assert_eq!(
             std::mem::size_of::<CExtractionResult>(),
-            128,
-            "CExtractionResult must be exactly 128 bytes"
+            144,
+            "CExtractionResult must be exactly 144 bytes"
         );
     }
 
@@ -336,7 +340,9 @@ mod tests {
         assert_eq!(offset_of!(CExtractionResult, elements_json), 96);
         assert_eq!(offset_of!(CExtractionResult, ocr_elements_json), 104);
         assert_eq!(offset_of!(CExtractionResult, document_json), 112);
-        assert_eq!(offset_of!(CExtractionResult, success), 120);
+        assert_eq!(offset_of!(CExtractionResult, outline_json), 120);
+        assert_eq!(offset_of!(CExtractionResult, text_blocks_json), 128);
+        assert_eq!(offset_of!(CExtractionResult, success), 136);
     }
 
     /// Verify field offsets in CBatchResult match expectations
@@ -143,6 +143,7 @@ export interface PdfMetadata {
 export interface ExcelMetadata {
 	sheetCount: number;
 	sheetNames: string[];
+	hiddenSheets?: string[];
 }
 
 export interface EmailMetadata {
@@ -324,6 +325,7 @@ export interface BaseMetadata {
 
 	sheetCount?: number;
 	sheetNames?: string[];
+	hiddenSheets?: string[];
 
 	fromEmail?: string;
 	fromName?: string;
@@ -461,6 +463,10 @@ export interface ExtractedTable {
 	markdown: string;
 	/** Page number where table was found (1-indexed) */
 	pageNumber: number;
+	/** Table name, e.g. the sheet name for spreadsheets */
+	name?: string | null;
+	/** Whether the first row of cells is a header row */
+	hasHeader: boolean;
 }
 
 /**
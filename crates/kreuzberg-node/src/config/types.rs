@@ -13,9 +13,9 @@ use kreuzberg::pdf::HierarchyConfig as RustHierarchyConfig;
 use kreuzberg::{
     ChunkerType, ChunkingConfig as RustChunkingConfig, EmbeddingConfig as RustEmbeddingConfig,
     EmbeddingModelType as RustEmbeddingModelType, ExtractionConfig, ImageExtractionConfig as RustImageExtractionConfig,
-    LanguageDetectionConfig as RustLanguageDetectionConfig, OcrConfig as RustOcrConfig, PdfConfig as RustPdfConfig,
-    PostProcessorConfig as RustPostProcessorConfig, TesseractConfig as RustTesseractConfig,
-    TokenReductionConfig as RustTokenReductionConfig,
+    LanguageDetectionConfig as RustLanguageDetectionConfig, OcrConfig as RustOcrConfig, OverlapUnit,
+    PdfConfig as RustPdfConfig, PostProcessorConfig as RustPostProcessorConfig, ReadingOrder as RustReadingOrder,
+    TesseractConfig as RustTesseractConfig, TokenReductionConfig as RustTokenReductionConfig,
 };
 use std::ffi::c_char;
 
@@ -50,6 +50,21 @@ pub struct JsOcrConfig {
     pub tesseract_config: Option<JsTesseractConfig>,
     pub paddle_ocr_config: Option<JsPaddleOcrConfig>,
     pub element_config: Option<JsOcrElementConfig>,
+    pub auto_language: Option<bool>,
+    pub fallback_backends: Option<Vec<String>>,
+    pub user_words: Option<Vec<String>>,
+    pub render_dpi: Option<i32>,
+    pub empty_page_confidence: Option<f64>,
+    pub page_filter: Option<Vec<u32>>,
+    pub region: Option<JsRect>,
+}
+
+#[napi(object)]
+pub struct JsRect {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
 }
 
 #[napi(object)]
@@ -78,6 +93,7 @@ impl From<JsOcrConfig> for RustOcrConfig {
     fn from(val: JsOcrConfig) -> Self {
         RustOcrConfig {
             backend: val.backend,
+            fallback_backends: val.fallback_backends.unwrap_or_default(),
             language: val.language.unwrap_or_else(|| "eng".to_string()),
             tesseract_config: val.tesseract_config.map(Into::into),
             output_format: None,
@@ -151,6 +167,20 @@ impl From<JsOcrConfig> for RustOcrConfig {
                 min_confidence: ec.min_confidence.unwrap_or(0.0),
                 build_hierarchy: ec.build_hierarchy.unwrap_or(false),
             }),
+            max_retries: 0,
+            auto_language: val.auto_language.unwrap_or(false),
+            user_words: val.user_words,
+            render_dpi: val.render_dpi,
+            empty_page_confidence: val.empty_page_confidence,
+            page_filter: val
+                .page_filter
+                .map(|pages| pages.into_iter().map(|page| page as usize).collect()),
+            region: val.region.map(|r| kreuzberg::core::config::Rect {
+                left: r.left,
+                top: r.top,
+                width: r.width,
+                height: r.height,
+            }),
         }
     }
 }
@@ -250,18 +280,38 @@ pub struct JsChunkingConfig {
     pub embedding: Option<JsEmbeddingConfig>,
     /// Optional preset name for chunking parameters
     pub preset: Option<String>,
+    /// Unit of `maxOverlap`: "characters" | "sentences"
+    pub overlap_unit: Option<String>,
+    /// Stop after this many chunks
+    pub max_chunks: Option<u32>,
 }
 
-impl From<JsChunkingConfig> for RustChunkingConfig {
-    fn from(val: JsChunkingConfig) -> Self {
-        RustChunkingConfig {
+impl TryFrom<JsChunkingConfig> for RustChunkingConfig {
+    type Error = Error;
+
+    fn try_from(val: JsChunkingConfig) -> Result<Self> {
+        let overlap_unit = match val.overlap_unit.as_deref() {
+            None | Some("characters") => OverlapUnit::Characters,
+            Some("sentences") => OverlapUnit::Sentences,
+            Some(other) => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!("Invalid overlap_unit: {}. Expected 'characters' or 'sentences'", other),
+                ));
+            }
+        };
+
+        Ok(RustChunkingConfig {
             max_characters: val.max_chars.unwrap_or(1000) as usize,
             overlap: val.max_overlap.unwrap_or(200) as usize,
             trim: true,
             chunker_type: ChunkerType::Text,
             embedding: val.embedding.map(Into::into),
             preset: val.preset,
-        }
+            overlap_unit,
+            max_chunks: val.max_chunks.map(|v| v as usize),
+            balance: false,
+        })
     }
 }
 
@@ -286,6 +336,8 @@ impl From<JsLanguageDetectionConfig> for RustLanguageDetectionConfig {
 pub struct JsTokenReductionConfig {
     pub mode: Option<String>,
     pub preserve_important_words: Option<bool>,
+    /// Stopword language as an ISO 639-1 or 639-3 code; defaults to the detected language
+    pub language: Option<String>,
 }
 
 impl From<JsTokenReductionConfig> for RustTokenReductionConfig {
@@ -293,6 +345,7 @@ impl From<JsTokenReductionConfig> for RustTokenReductionConfig {
         RustTokenReductionConfig {
             mode: val.mode.unwrap_or_else(|| "off".to_string()),
             preserve_important_words: val.preserve_important_words.unwrap_or(true),
+            language: val.language,
         }
     }
 }
@@ -322,16 +375,45 @@ pub struct JsPdfConfig {
     pub passwords: Option<Vec<String>>,
     pub extract_metadata: Option<bool>,
     pub hierarchy: Option<JsHierarchyConfig>,
+    /// Reading order: "raw" (default) or "column_aware"
+    pub reading_order: Option<String>,
+    /// Extract text from PDFs whose owner permissions forbid copying (default: true)
+    pub allow_owner_locked: Option<bool>,
+    /// Text inserted between pages (default: "\n\n"); `{page}` becomes the following page number
+    pub page_separator: Option<String>,
+    /// Recognize formulas as LaTeX into `formulas` (requires the math-ocr feature, default: false)
+    pub extract_math: Option<bool>,
+    /// Insert `[image N]` markers into the content where images appear (default: false)
+    pub insert_image_markers: Option<bool>,
 }
 
-impl From<JsPdfConfig> for RustPdfConfig {
-    fn from(val: JsPdfConfig) -> Self {
-        RustPdfConfig {
+impl TryFrom<JsPdfConfig> for RustPdfConfig {
+    type Error = Error;
+
+    fn try_from(val: JsPdfConfig) -> Result<Self> {
+        let reading_order = match val.reading_order.as_deref() {
+            None | Some("raw") => RustReadingOrder::Raw,
+            Some("column_aware") => RustReadingOrder::ColumnAware,
+            Some(other) => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!("Invalid reading_order: {}. Expected 'raw' or 'column_aware'", other),
+                ));
+            }
+        };
+
+        Ok(RustPdfConfig {
             extract_images: val.extract_images.unwrap_or(false),
             passwords: val.passwords,
+            allow_owner_locked: val.allow_owner_locked.unwrap_or(true),
             extract_metadata: val.extract_metadata.unwrap_or(true),
             hierarchy: val.hierarchy.map(|h| h.into()),
-        }
+            reading_order,
+            page_range: None,
+            page_separator: val.page_separator,
+            extract_math: val.extract_math.unwrap_or(false),
+            insert_image_markers: val.insert_image_markers.unwrap_or(false),
+        })
     }
 }
 
@@ -340,9 +422,15 @@ pub struct JsImageExtractionConfig {
     pub extract_images: Option<bool>,
     pub target_dpi: Option<i32>,
     pub max_image_dimension: Option<i32>,
+    pub max_decode_dimension: Option<u32>,
     pub auto_adjust_dpi: Option<bool>,
     pub min_dpi: Option<i32>,
     pub max_dpi: Option<i32>,
+    pub max_images: Option<u32>,
+    pub max_total_image_bytes: Option<u32>,
+    pub min_width: Option<u32>,
+    pub min_height: Option<u32>,
+    pub output_format: Option<String>,
 }
 
 impl From<JsImageExtractionConfig> for RustImageExtractionConfig {
@@ -351,9 +439,18 @@ impl From<JsImageExtractionConfig> for RustImageExtractionConfig {
             extract_images: val.extract_images.unwrap_or(true),
             target_dpi: val.target_dpi.unwrap_or(300),
             max_image_dimension: val.max_image_dimension.unwrap_or(4096),
+            max_decode_dimension: val.max_decode_dimension.unwrap_or(32768),
             auto_adjust_dpi: val.auto_adjust_dpi.unwrap_or(true),
             min_dpi: val.min_dpi.unwrap_or(72),
             max_dpi: val.max_dpi.unwrap_or(600),
+            max_images: val.max_images.map(|v| v as usize),
+            max_total_image_bytes: val.max_total_image_bytes.map(|v| v as usize),
+            min_width: val.min_width.unwrap_or(16),
+            min_height: val.min_height.unwrap_or(16),
+            output_format: val
+                .output_format
+                .and_then(|format| format.parse().ok())
+                .unwrap_or_default(),
         }
     }
 }
@@ -382,6 +479,7 @@ impl From<JsPostProcessorConfig> for RustPostProcessorConfig {
             disabled_processors: val.disabled_processors,
             enabled_set,
             disabled_set,
+            ..Default::default()
         }
     }
 }
@@ -928,6 +1026,69 @@ pub struct JsPageConfig {
     pub marker_format: Option<String>,
 }
 
+#[napi(object)]
+pub struct JsDocxConfig {
+    /// Estimate page boundaries from content length and explicit page breaks
+    pub estimate_pages: Option<bool>,
+}
+
+impl From<JsDocxConfig> for kreuzberg::DocxConfig {
+    fn from(val: JsDocxConfig) -> Self {
+        kreuzberg::DocxConfig {
+            estimate_pages: val.estimate_pages.unwrap_or(false),
+        }
+    }
+}
+
+impl From<kreuzberg::DocxConfig> for JsDocxConfig {
+    fn from(config: kreuzberg::DocxConfig) -> Self {
+        Self {
+            estimate_pages: Some(config.estimate_pages),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct JsJupyterConfig {
+    /// Code cell outputs to include: "all" | "text" | "none"
+    pub outputs: Option<String>,
+}
+
+impl TryFrom<JsJupyterConfig> for kreuzberg::JupyterConfig {
+    type Error = Error;
+
+    fn try_from(val: JsJupyterConfig) -> Result<Self> {
+        let outputs = match val.outputs.as_deref() {
+            None | Some("all") => kreuzberg::NotebookOutputs::All,
+            Some("text") => kreuzberg::NotebookOutputs::Text,
+            Some("none") => kreuzberg::NotebookOutputs::None,
+            Some(other) => {
+                return Err(Error::new(
+                    Status::InvalidArg,
+                    format!(
+                        "Invalid jupyter_options.outputs: {}. Expected 'all', 'text' or 'none'",
+                        other
+                    ),
+                ));
+            }
+        };
+        Ok(kreuzberg::JupyterConfig { outputs })
+    }
+}
+
+impl From<kreuzberg::JupyterConfig> for JsJupyterConfig {
+    fn from(config: kreuzberg::JupyterConfig) -> Self {
+        let outputs = match config.outputs {
+            kreuzberg::NotebookOutputs::All => "all",
+            kreuzberg::NotebookOutputs::Text => "text",
+            kreuzberg::NotebookOutputs::None => "none",
+        };
+        Self {
+            outputs: Some(outputs.to_string()),
+        }
+    }
+}
+
 #[napi(object)]
 pub struct JsExtractionConfig {
     pub use_cache: Option<bool>,
@@ -946,10 +1107,25 @@ pub struct JsExtractionConfig {
     pub pages: Option<JsPageConfig>,
     /// Output text format: "plain" | "markdown" | "djot" | "html"
     pub output_format: Option<String>,
+    /// Markdown decoration of HTML and DOCX content: "markdown" | "plain_text"
+    pub output_mode: Option<String>,
     /// Result structure format: "unified" | "element_based"
     pub result_format: Option<String>,
     /// Include document structure in extraction result
     pub include_document_structure: Option<bool>,
+    pub max_content_chars: Option<u32>,
+    pub preserve_list_markers: Option<bool>,
+    pub extract_outline: Option<bool>,
+    pub extract_references: Option<bool>,
+    pub normalize_bidi: Option<bool>,
+    pub html_include_alt_text: Option<bool>,
+    pub emit_positions: Option<bool>,
+    pub emit_source_offsets: Option<bool>,
+    pub low_memory: Option<bool>,
+    /// Line ending of the extracted text: "lf" | "crlf" | "keep"
+    pub normalize_newlines: Option<String>,
+    pub docx_options: Option<JsDocxConfig>,
+    pub jupyter_options: Option<JsJupyterConfig>,
 }
 
 impl TryFrom<JsPageConfig> for kreuzberg::core::config::PageConfig {
@@ -991,13 +1167,13 @@ impl TryFrom<JsExtractionConfig> for ExtractionConfig {
         };
 
         Ok(ExtractionConfig {
-            use_cache: val.use_cache.unwrap_or(true),
+            use_cache: val.use_cache.unwrap_or(true) && !kreuzberg::cache::cache_disabled_by_env(),
             enable_quality_processing: val.enable_quality_processing.unwrap_or(true),
             ocr: val.ocr.map(Into::into),
             force_ocr: val.force_ocr.unwrap_or(false),
-            chunking: val.chunking.map(Into::into),
+            chunking: val.chunking.map(|c| c.try_into()).transpose()?,
             images: val.images.map(Into::into),
-            pdf_options: val.pdf_options.map(Into::into),
+            pdf_options: val.pdf_options.map(RustPdfConfig::try_from).transpose()?,
             token_reduction: val.token_reduction.map(Into::into),
             language_detection: val.language_detection.map(Into::into),
             keywords,
@@ -1011,6 +1187,12 @@ impl TryFrom<JsExtractionConfig> for ExtractionConfig {
                 .transpose()
                 .map_err(|e: String| Error::new(Status::InvalidArg, e))?
                 .unwrap_or_default(),
+            output_mode: val
+                .output_mode
+                .map(|s| s.parse())
+                .transpose()
+                .map_err(|e: String| Error::new(Status::InvalidArg, e))?
+                .unwrap_or_default(),
             result_format: val
                 .result_format
                 .map(|s| match s.as_str() {
@@ -1027,11 +1209,42 @@ impl TryFrom<JsExtractionConfig> for ExtractionConfig {
                 .transpose()?
                 .unwrap_or_default(),
             include_document_structure: val.include_document_structure.unwrap_or(false),
+            max_content_chars: val.max_content_chars.map(|v| v as usize),
+            preserve_list_markers: val.preserve_list_markers.unwrap_or(true),
+            extract_outline: val.extract_outline.unwrap_or(false),
+            extract_references: val.extract_references.unwrap_or(false),
+            normalize_bidi: val.normalize_bidi.unwrap_or(false),
+            html_include_alt_text: val.html_include_alt_text.unwrap_or(true),
+            emit_positions: val.emit_positions.unwrap_or(false),
+            emit_source_offsets: val.emit_source_offsets.unwrap_or(false),
+            low_memory: val.low_memory.unwrap_or(false),
+            normalize_newlines: val
+                .normalize_newlines
+                .map(|s| s.parse())
+                .transpose()
+                .map_err(|e: String| Error::new(Status::InvalidArg, e))?,
             security_limits: None,
+            xlsx_options: None,
+            docx_options: val.docx_options.map(Into::into),
+            jupyter_options: val.jupyter_options.map(|j| j.try_into()).transpose()?,
+            archive_options: None,
+            table_options: None,
         })
     }
 }
 
+/// Convert a `usize` limit to the `u32` exposed to JavaScript, rejecting values that do not fit.
+fn limit_to_u32(value: usize, field: &str) -> Result<u32> {
+    u32::try_from(value).map_err(|_| {
+        crate::error_handling::convert_error(kreuzberg::KreuzbergError::validation(format!(
+            "{} ({}) exceeds the largest value supported by the Node bindings ({})",
+            field,
+            value,
+            u32::MAX
+        )))
+    })
+}
+
 impl TryFrom<ExtractionConfig> for JsExtractionConfig {
     type Error = napi::Error;
 
@@ -1077,44 +1290,91 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                     min_confidence: Some(ec.min_confidence),
                     build_hierarchy: Some(ec.build_hierarchy),
                 }),
-            }),
-            force_ocr: Some(val.force_ocr),
-            chunking: val.chunking.map(|chunk| JsChunkingConfig {
-                max_chars: Some(chunk.max_characters as u32),
-                max_overlap: Some(chunk.overlap as u32),
-                embedding: chunk.embedding.map(|emb| JsEmbeddingConfig {
-                    model: Some(JsEmbeddingModelType {
-                        model_type: match emb.model {
-                            RustEmbeddingModelType::Preset { .. } => "preset".to_string(),
-                            RustEmbeddingModelType::FastEmbed { .. } => "fastembed".to_string(),
-                            RustEmbeddingModelType::Custom { .. } => "custom".to_string(),
-                        },
-                        value: match &emb.model {
-                            RustEmbeddingModelType::Preset { name } => name.clone(),
-                            RustEmbeddingModelType::FastEmbed { model, .. } => model.clone(),
-                            RustEmbeddingModelType::Custom { model_id, .. } => model_id.clone(),
-                        },
-                        dimensions: match emb.model {
-                            RustEmbeddingModelType::FastEmbed { dimensions, .. } => Some(dimensions as u32),
-                            RustEmbeddingModelType::Custom { dimensions, .. } => Some(dimensions as u32),
-                            _ => None,
-                        },
-                    }),
-                    normalize: Some(emb.normalize),
-                    batch_size: Some(emb.batch_size as u32),
-                    show_download_progress: Some(emb.show_download_progress),
-                    cache_dir: emb.cache_dir.and_then(|p| p.to_str().map(String::from)),
+                auto_language: Some(ocr.auto_language),
+                fallback_backends: Some(ocr.fallback_backends),
+                user_words: ocr.user_words,
+                render_dpi: ocr.render_dpi,
+                empty_page_confidence: ocr.empty_page_confidence,
+                page_filter: ocr
+                    .page_filter
+                    .map(|pages| pages.into_iter().map(|page| page as u32).collect()),
+                region: ocr.region.map(|r| JsRect {
+                    left: r.left,
+                    top: r.top,
+                    width: r.width,
+                    height: r.height,
                 }),
-                preset: chunk.preset,
-            }),
-            images: val.images.map(|img| JsImageExtractionConfig {
-                extract_images: Some(img.extract_images),
-                target_dpi: Some(img.target_dpi),
-                max_image_dimension: Some(img.max_image_dimension),
-                auto_adjust_dpi: Some(img.auto_adjust_dpi),
-                min_dpi: Some(img.min_dpi),
-                max_dpi: Some(img.max_dpi),
             }),
+            force_ocr: Some(val.force_ocr),
+            chunking: val
+                .chunking
+                .map(|chunk| -> Result<JsChunkingConfig> {
+                    Ok(JsChunkingConfig {
+                        max_chars: Some(chunk.max_characters as u32),
+                        max_overlap: Some(chunk.overlap as u32),
+                        embedding: chunk.embedding.map(|emb| JsEmbeddingConfig {
+                            model: Some(JsEmbeddingModelType {
+                                model_type: match emb.model {
+                                    RustEmbeddingModelType::Preset { .. } => "preset".to_string(),
+                                    RustEmbeddingModelType::FastEmbed { .. } => "fastembed".to_string(),
+                                    RustEmbeddingModelType::Custom { .. } => "custom".to_string(),
+                                },
+                                value: match &emb.model {
+                                    RustEmbeddingModelType::Preset { name } => name.clone(),
+                                    RustEmbeddingModelType::FastEmbed { model, .. } => model.clone(),
+                                    RustEmbeddingModelType::Custom { model_id, .. } => model_id.clone(),
+                                },
+                                dimensions: match emb.model {
+                                    RustEmbeddingModelType::FastEmbed { dimensions, .. } => Some(dimensions as u32),
+                                    RustEmbeddingModelType::Custom { dimensions, .. } => Some(dimensions as u32),
+                                    _ => None,
+                                },
+                            }),
+                            normalize: Some(emb.normalize),
+                            batch_size: Some(emb.batch_size as u32),
+                            show_download_progress: Some(emb.show_download_progress),
+                            cache_dir: emb.cache_dir.and_then(|p| p.to_str().map(String::from)),
+                        }),
+                        preset: chunk.preset,
+                        overlap_unit: Some(
+                            match chunk.overlap_unit {
+                                OverlapUnit::Characters => "characters",
+                                OverlapUnit::Sentences => "sentences",
+                            }
+                            .to_string(),
+                        ),
+                        max_chunks: chunk
+                            .max_chunks
+                            .map(|v| limit_to_u32(v, "chunking.max_chunks"))
+                            .transpose()?,
+                    })
+                })
+                .transpose()?,
+            images: val
+                .images
+                .map(|img| -> Result<JsImageExtractionConfig> {
+                    Ok(JsImageExtractionConfig {
+                        extract_images: Some(img.extract_images),
+                        target_dpi: Some(img.target_dpi),
+                        max_image_dimension: Some(img.max_image_dimension),
+                        max_decode_dimension: Some(img.max_decode_dimension),
+                        auto_adjust_dpi: Some(img.auto_adjust_dpi),
+                        min_dpi: Some(img.min_dpi),
+                        max_dpi: Some(img.max_dpi),
+                        max_images: img
+                            .max_images
+                            .map(|v| limit_to_u32(v, "images.max_images"))
+                            .transpose()?,
+                        max_total_image_bytes: img
+                            .max_total_image_bytes
+                            .map(|v| limit_to_u32(v, "images.max_total_image_bytes"))
+                            .transpose()?,
+                        min_width: Some(img.min_width),
+                        min_height: Some(img.min_height),
+                        output_format: Some(img.output_format.to_string()),
+                    })
+                })
+                .transpose()?,
             pdf_options: val.pdf_options.map(|pdf| JsPdfConfig {
                 extract_images: Some(pdf.extract_images),
                 passwords: pdf.passwords,
@@ -1125,10 +1385,22 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                     include_bbox: Some(h.include_bbox),
                     ocr_coverage_threshold: h.ocr_coverage_threshold.map(|v| v as f64),
                 }),
+                reading_order: Some(
+                    match pdf.reading_order {
+                        RustReadingOrder::Raw => "raw",
+                        RustReadingOrder::ColumnAware => "column_aware",
+                    }
+                    .to_string(),
+                ),
+                allow_owner_locked: Some(pdf.allow_owner_locked),
+                page_separator: pdf.page_separator,
+                extract_math: Some(pdf.extract_math),
+                insert_image_markers: Some(pdf.insert_image_markers),
             }),
             token_reduction: val.token_reduction.map(|tr| JsTokenReductionConfig {
                 mode: Some(tr.mode),
                 preserve_important_words: Some(tr.preserve_important_words),
+                language: tr.language,
             }),
             language_detection: val.language_detection.map(|ld| JsLanguageDetectionConfig {
                 enabled: Some(ld.enabled),
@@ -1145,11 +1417,24 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
             max_concurrent_extractions: val.max_concurrent_extractions.map(|v| v as u32),
             pages: val.pages.map(JsPageConfig::from),
             output_format: Some(val.output_format.to_string()),
+            output_mode: Some(val.output_mode.to_string()),
             result_format: Some(match val.result_format {
                 kreuzberg::types::OutputFormat::Unified => "unified".to_string(),
                 kreuzberg::types::OutputFormat::ElementBased => "element_based".to_string(),
             }),
             include_document_structure: Some(val.include_document_structure),
+            max_content_chars: val.max_content_chars.map(|v| v as u32),
+            preserve_list_markers: Some(val.preserve_list_markers),
+            extract_outline: Some(val.extract_outline),
+            extract_references: Some(val.extract_references),
+            normalize_bidi: Some(val.normalize_bidi),
+            html_include_alt_text: Some(val.html_include_alt_text),
+            emit_positions: Some(val.emit_positions),
+            emit_source_offsets: Some(val.emit_source_offsets),
+            low_memory: Some(val.low_memory),
+            normalize_newlines: val.normalize_newlines.map(|ending| ending.to_string()),
+            docx_options: val.docx_options.map(JsDocxConfig::from),
+            jupyter_options: val.jupyter_options.map(JsJupyterConfig::from),
         })
     }
 }
@@ -69,9 +69,7 @@ pub(crate) fn convert_error(err: kreuzberg::KreuzbergError) -> napi::Error {
 
         KreuzbergError::LockPoisoned(msg) => Error::new(Status::GenericFailure, format!("Lock poisoned: {}", msg)),
 
-        KreuzbergError::UnsupportedFormat(format) => {
-            Error::new(Status::InvalidArg, format!("Unsupported format: {}", format))
-        }
+        error @ KreuzbergError::UnsupportedFormat { .. } => Error::new(Status::InvalidArg, error.to_string()),
 
         KreuzbergError::Other(msg) => Error::new(Status::GenericFailure, msg),
     }
@@ -24,10 +24,11 @@ mod validation;
 pub use error_handling::{ErrorClassification, classify_error, get_error_code_description, get_error_code_name};
 
 pub use config::{
-    JsChunkingConfig, JsEmbeddingConfig, JsEmbeddingModelType, JsExtractionConfig, JsHierarchyConfig, JsHtmlOptions,
-    JsHtmlPreprocessingOptions, JsImageExtractionConfig, JsKeywordConfig, JsLanguageDetectionConfig, JsOcrConfig,
-    JsPageConfig, JsPdfConfig, JsPostProcessorConfig, JsRakeParams, JsTesseractConfig, JsTokenReductionConfig,
-    JsYakeParams, discover_extraction_config, load_extraction_config_from_file,
+    JsChunkingConfig, JsDocxConfig, JsEmbeddingConfig, JsEmbeddingModelType, JsExtractionConfig, JsHierarchyConfig,
+    JsHtmlOptions, JsHtmlPreprocessingOptions, JsImageExtractionConfig, JsJupyterConfig, JsKeywordConfig,
+    JsLanguageDetectionConfig, JsOcrConfig, JsPageConfig, JsPdfConfig, JsPostProcessorConfig, JsRakeParams,
+    JsTesseractConfig, JsTokenReductionConfig, JsYakeParams, discover_extraction_config,
+    load_extraction_config_from_file,
 };
 
 pub use result::{
@@ -55,8 +56,10 @@ pub use metadata::{
 pub use embedding_presets::{EmbeddingPreset, get_embedding_preset, list_embedding_presets};
 
 pub use plugins::{
-    clear_ocr_backends, clear_post_processors, clear_validators, list_ocr_backends, list_post_processors,
-    list_validators, register_ocr_backend, register_post_processor, register_validator, unregister_ocr_backend,
+    clear_image_captioners, clear_mime_detectors, clear_ocr_backends, clear_post_processors, clear_validators,
+    list_image_captioners, list_mime_detectors, list_ocr_backends, list_post_processors, list_validators,
+    register_image_captioner, register_mime_detector, register_ocr_backend, register_post_processor,
+    register_validator, unregister_image_captioner, unregister_mime_detector, unregister_ocr_backend,
     unregister_post_processor, unregister_validator,
 };
 
@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use base64::Engine;
+use napi::bindgen_prelude::*;
+use napi::threadsafe_function::ThreadsafeFunction;
+use napi_derive::napi;
+use std::sync::Arc;
+
+use kreuzberg::plugins::ImageCaptioner as RustImageCaptioner;
+use kreuzberg::plugins::Plugin;
+
+use crate::error_handling::convert_error;
+
+/// Wrapper that makes a JavaScript image captioner usable from Rust.
+///
+/// The caption_fn is an async JavaScript function that:
+/// - Takes: (String, String) - Base64 encoded image bytes and image format
+/// - Returns: Promise<String> - The caption text
+type CaptionFn = Arc<ThreadsafeFunction<(String, String), Promise<String>, Vec<(String, String)>, napi::Status, false>>;
+
+struct JsImageCaptioner {
+    name: String,
+    caption_fn: CaptionFn,
+}
+
+unsafe impl Send for JsImageCaptioner {}
+unsafe impl Sync for JsImageCaptioner {}
+
+impl Plugin for JsImageCaptioner {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> String {
+        "1.0.0".to_string()
+    }
+
+    fn initialize(&self) -> std::result::Result<(), kreuzberg::KreuzbergError> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> std::result::Result<(), kreuzberg::KreuzbergError> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RustImageCaptioner for JsImageCaptioner {
+    async fn caption(
+        &self,
+        image: &kreuzberg::ExtractedImage,
+    ) -> std::result::Result<String, kreuzberg::KreuzbergError> {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&image.data);
+        let format = image.format.to_string();
+        let captioner_name = self.name.clone();
+
+        self.caption_fn
+            .call_async((encoded, format))
+            .await
+            .map_err(|e| kreuzberg::KreuzbergError::Plugin {
+                message: format!("JavaScript image captioner '{}' failed: {}", captioner_name, e),
+                plugin_name: captioner_name.clone(),
+            })?
+            .await
+            .map_err(|e| kreuzberg::KreuzbergError::Plugin {
+                message: format!("JavaScript image captioner '{}' failed: {}", captioner_name, e),
+                plugin_name: captioner_name.clone(),
+            })
+    }
+}
+
+/// Register a custom image captioner
+///
+/// Registers a JavaScript image captioner. When a captioner is registered, every
+/// extracted image without a caption is passed to it and the returned text is stored
+/// in `ExtractedImage.caption`. The most recently registered captioner is used.
+///
+/// # Arguments
+///
+/// * `captioner` - JavaScript object with the following interface:
+///   - `name(): string` - Unique captioner name
+///   - `caption(imageBytes: string, format: string): Promise<string>` - Caption an image
+///
+/// # Implementation Notes
+///
+/// Due to NAPI ThreadsafeFunction limitations, the caption function receives:
+/// - `imageBytes` as a Base64 string (first argument)
+/// - `format` as string (second argument), e.g. "png" or "jpeg"
+///
+/// # Example
+///
+/// ```typescript
+/// import { registerImageCaptioner } from '@kreuzberg/node';
+///
+/// registerImageCaptioner({
+///   name: () => "my-captioner",
+///   caption: async (imageBytes, format) => {
+///     const buffer = Buffer.from(imageBytes, "base64");
+///     return await myVisionModel.describe(buffer, format);
+///   }
+/// });
+/// ```
+#[napi]
+pub fn register_image_captioner(_env: Env, captioner: Object) -> Result<()> {
+    use super::validate_plugin_object;
+
+    validate_plugin_object(&captioner, "Image Captioner", &["name", "caption"])?;
+
+    let name: String = captioner.get_named_property::<String>("name").or_else(|_| {
+        let name_fn: Function<(), String> = captioner.get_named_property("name")?;
+        name_fn.call(())
+    })?;
+
+    if name.is_empty() {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "Image captioner name cannot be empty".to_string(),
+        ));
+    }
+
+    let caption_fn: Function<(String, String), Promise<String>> = captioner.get_named_property("caption")?;
+
+    let tsfn = caption_fn
+        .build_threadsafe_function()
+        .build_callback(|ctx| Ok(vec![ctx.value]))?;
+
+    let js_captioner = JsImageCaptioner {
+        name: name.clone(),
+        caption_fn: Arc::new(tsfn),
+    };
+
+    kreuzberg::plugins::register_image_captioner(Arc::new(js_captioner)).map_err(|e| {
+        napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("Failed to register image captioner '{}': {}", name, e),
+        )
+    })
+}
+
+/// Unregister an image captioner by name
+#[napi]
+pub fn unregister_image_captioner(name: String) -> Result<()> {
+    kreuzberg::plugins::unregister_image_captioner(&name).map_err(convert_error)
+}
+
+/// List all registered image captioners
+#[napi]
+pub fn list_image_captioners() -> Result<Vec<String>> {
+    kreuzberg::plugins::list_image_captioners().map_err(convert_error)
+}
+
+/// Clear all registered image captioners
+#[napi]
+pub fn clear_image_captioners() -> Result<()> {
+    kreuzberg::plugins::clear_image_captioners().map_err(convert_error)
+}
@@ -0,0 +1,140 @@
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use std::sync::Arc;
+
+use kreuzberg::plugins::MimeDetector as RustMimeDetector;
+use kreuzberg::plugins::Plugin;
+
+/// MIME detector that recognizes a magic number declared from JavaScript.
+///
+/// Detection runs synchronously, including on the JavaScript thread for the sync
+/// APIs, so the signature is matched in Rust rather than by calling back into
+/// JavaScript.
+struct JsMimeDetector {
+    name: String,
+    mime_type: String,
+    magic: Vec<u8>,
+    offset: usize,
+}
+
+impl Plugin for JsMimeDetector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> String {
+        "1.0.0".to_string()
+    }
+
+    fn initialize(&self) -> std::result::Result<(), kreuzberg::KreuzbergError> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> std::result::Result<(), kreuzberg::KreuzbergError> {
+        Ok(())
+    }
+}
+
+impl RustMimeDetector for JsMimeDetector {
+    fn detect(&self, header: &[u8]) -> Option<String> {
+        header
+            .get(self.offset..)
+            .is_some_and(|bytes| bytes.starts_with(&self.magic))
+            .then(|| self.mime_type.clone())
+    }
+}
+
+/// Register a custom MIME detector
+///
+/// Registers a magic-number signature that is checked before the built-in MIME detection.
+///
+/// # Arguments
+///
+/// * `detector` - JavaScript object with the following interface:
+///   - `name: string` - Unique detector name
+///   - `mimeType: string` - MIME type reported for matching documents
+///   - `magic: Buffer` - Bytes the document must contain at `offset`
+///   - `offset: number` - Optional position of the magic bytes (defaults to 0)
+///
+/// # Example
+///
+/// ```typescript
+/// import { registerMimeDetector } from '@kreuzberg/node';
+///
+/// registerMimeDetector({
+///   name: "ledger-detector",
+///   mimeType: "application/x-ledger",
+///   magic: Buffer.from("LDGR\x01", "latin1"),
+/// });
+/// ```
+#[napi]
+pub fn register_mime_detector(detector: Object) -> Result<()> {
+    use super::validate_plugin_object;
+
+    validate_plugin_object(&detector, "MimeDetector", &["name", "mimeType", "magic"])?;
+
+    let name: String = detector.get_named_property("name")?;
+    if name.is_empty() {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "MimeDetector name cannot be empty".to_string(),
+        ));
+    }
+
+    let mime_type: String = detector.get_named_property("mimeType")?;
+    let magic: Buffer = detector.get_named_property("magic")?;
+    if magic.is_empty() {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "MimeDetector magic cannot be empty".to_string(),
+        ));
+    }
+    let offset: Option<u32> = detector.get_named_property("offset")?;
+
+    let js_detector = JsMimeDetector {
+        name: name.clone(),
+        mime_type,
+        magic: magic.to_vec(),
+        offset: offset.unwrap_or(0) as usize,
+    };
+
+    kreuzberg::plugins::register_mime_detector(Arc::new(js_detector)).map_err(|e| {
+        napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("Failed to register MimeDetector '{}': {}", name, e),
+        )
+    })
+}
+
+/// Unregister a MIME detector by name
+#[napi]
+pub fn unregister_mime_detector(name: String) -> Result<()> {
+    kreuzberg::plugins::unregister_mime_detector(&name).map_err(|e| {
+        napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("Failed to unregister MimeDetector '{}': {}", name, e),
+        )
+    })
+}
+
+/// Clear all registered MIME detectors
+#[napi]
+pub fn clear_mime_detectors() -> Result<()> {
+    kreuzberg::plugins::clear_mime_detectors().map_err(|e| {
+        napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("Failed to clear MimeDetector registry: {}", e),
+        )
+    })
+}
+
+/// List all registered MIME detectors
+#[napi]
+pub fn list_mime_detectors() -> Result<Vec<String>> {
+    kreuzberg::plugins::list_mime_detectors().map_err(|e| {
+        napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("Failed to list MimeDetectors: {}", e),
+        )
+    })
+}
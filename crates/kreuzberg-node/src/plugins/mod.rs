@@ -1,5 +1,7 @@
 use napi::bindgen_prelude::*;
 
+mod image_captioner;
+mod mime_detector;
 mod ocr_backend;
 /// Plugin system implementations for Kreuzberg
 ///
@@ -7,9 +9,13 @@ mod ocr_backend;
 /// - **PostProcessor**: Custom document post-processing
 /// - **Validator**: Custom validation logic
 /// - **OcrBackend**: Custom OCR implementations
+/// - **ImageCaptioner**: Custom captions for extracted images
+/// - **MimeDetector**: Custom magic-number MIME detection
 mod post_processor;
 mod validator;
 
+pub use image_captioner::*;
+pub use mime_detector::*;
 pub use ocr_backend::*;
 pub use post_processor::*;
 pub use validator::*;
@@ -143,6 +143,11 @@ impl RustOcrBackend for JsOcrBackend {
             djot_content: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
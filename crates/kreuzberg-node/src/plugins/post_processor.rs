@@ -55,19 +55,43 @@ impl RustPostProcessor for JsPostProcessor {
     async fn process(
         &self,
         result: &mut kreuzberg::ExtractionResult,
-        _config: &kreuzberg::ExtractionConfig,
+        config: &kreuzberg::ExtractionConfig,
     ) -> std::result::Result<(), kreuzberg::KreuzbergError> {
-        let js_result =
-            JsExtractionResult::try_from(result.clone()).map_err(|e| kreuzberg::KreuzbergError::Plugin {
+        // In low-memory mode the result is moved into the conversion instead of cloned, so
+        // only its JSON form is alive while the JavaScript callback runs. A failing plugin
+        // aborts the extraction, so the emptied result is never observed.
+        let source = if config.low_memory {
+            result.take()
+        } else {
+            result.clone()
+        };
+        let json_string = {
+            let js_result = JsExtractionResult::try_from(source).map_err(|e| kreuzberg::KreuzbergError::Plugin {
                 message: format!("Failed to convert result for JavaScript PostProcessor: {}", e),
                 plugin_name: self.name.clone(),
             })?;
 
-        let json_string = serde_json::to_string(&js_result).map_err(|e| kreuzberg::KreuzbergError::Plugin {
-            message: format!("Failed to serialize result to JSON for JavaScript PostProcessor: {}", e),
-            plugin_name: self.name.clone(),
-        })?;
+            serde_json::to_string(&js_result).map_err(|e| kreuzberg::KreuzbergError::Plugin {
+                message: format!("Failed to serialize result to JSON for JavaScript PostProcessor: {}", e),
+                plugin_name: self.name.clone(),
+            })?
+        };
+
+        *result = self.call_process(json_string).await?;
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        self.stage
+    }
+}
 
+impl JsPostProcessor {
+    /// Run the JavaScript process function on a JSON-serialized result.
+    async fn call_process(
+        &self,
+        json_string: String,
+    ) -> std::result::Result<kreuzberg::ExtractionResult, kreuzberg::KreuzbergError> {
         let output_json = self
             .process_fn
             .call_async(json_string)
@@ -91,19 +115,10 @@ impl RustPostProcessor for JsPostProcessor {
                 plugin_name: self.name.clone(),
             })?;
 
-        let rust_result =
-            kreuzberg::ExtractionResult::try_from(updated).map_err(|e| kreuzberg::KreuzbergError::Plugin {
-                message: format!("Failed to convert result from JavaScript PostProcessor: {}", e),
-                plugin_name: self.name.clone(),
-            })?;
-
-        *result = rust_result;
-
-        Ok(())
-    }
-
-    fn processing_stage(&self) -> ProcessingStage {
-        self.stage
+        kreuzberg::ExtractionResult::try_from(updated).map_err(|e| kreuzberg::KreuzbergError::Plugin {
+            message: format!("Failed to convert result from JavaScript PostProcessor: {}", e),
+            plugin_name: self.name.clone(),
+        })
     }
 }
 
@@ -42,6 +42,8 @@ pub struct JsTable {
     pub cells: Vec<Vec<String>>,
     pub markdown: String,
     pub page_number: u32,
+    pub name: Option<String>,
+    pub has_header: bool,
 }
 
 #[napi(object)]
@@ -56,6 +58,10 @@ pub struct JsExtractedImage {
     pub bits_per_component: Option<u32>,
     pub is_mask: bool,
     pub description: Option<String>,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    #[napi(ts_type = "[number, number] | undefined")]
+    pub page_position: Option<Vec<f64>>,
     #[napi(ts_type = "JsExtractionResult | undefined")]
     pub ocr_result: Option<serde_json::Value>,
 }
@@ -70,6 +76,7 @@ pub struct JsChunkMetadata {
     pub total_chunks: u32,
     pub first_page: Option<u32>,
     pub last_page: Option<u32>,
+    pub heading_path: Option<Vec<String>>,
 }
 
 #[napi(object)]
@@ -145,6 +152,16 @@ pub struct JsExtractionResult {
     pub document: Option<serde_json::Value>,
     #[napi(ts_type = "OcrElement[] | null")]
     pub ocr_elements: Option<serde_json::Value>,
+    #[napi(ts_type = "OutlineNode[] | null")]
+    pub outline: Option<serde_json::Value>,
+    #[napi(ts_type = "Formula[] | null")]
+    pub formulas: Option<serde_json::Value>,
+    #[napi(ts_type = "TextBlock[] | null")]
+    pub text_blocks: Option<serde_json::Value>,
+    #[napi(ts_type = "SourceSpan[] | null")]
+    pub source_map: Option<serde_json::Value>,
+    #[napi(ts_type = "Reference[] | null")]
+    pub references: Option<serde_json::Value>,
 }
 
 impl TryFrom<RustExtractionResult> for JsExtractionResult {
@@ -181,6 +198,9 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                     bits_per_component: img.bits_per_component,
                     is_mask: img.is_mask,
                     description: img.description,
+                    alt_text: img.alt_text,
+                    caption: img.caption,
+                    page_position: img.page_position.map(|(x, y)| vec![x as f64, y as f64]),
                     ocr_result,
                 });
             }
@@ -199,6 +219,8 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                         cells: t.cells.clone(),
                         markdown: t.markdown.clone(),
                         page_number: t.page_number as u32,
+                        name: t.name.clone(),
+                        has_header: t.has_header,
                     })
                     .collect();
 
@@ -232,6 +254,9 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                             bits_per_component: img.bits_per_component,
                             is_mask: img.is_mask,
                             description: img.description.clone(),
+                            alt_text: img.alt_text.clone(),
+                            caption: img.caption.clone(),
+                            page_position: img.page_position.map(|(x, y)| vec![x as f64, y as f64]),
                             ocr_result,
                         }
                     })
@@ -316,6 +341,46 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                 )
             })?;
 
+        let outline = val
+            .outline
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("Failed to serialize outline: {}", e)))?;
+
+        let formulas = val
+            .formulas
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("Failed to serialize formulas: {}", e)))?;
+
+        let text_blocks = val
+            .text_blocks
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| {
+                Error::new(
+                    Status::GenericFailure,
+                    format!("Failed to serialize text blocks: {}", e),
+                )
+            })?;
+
+        let source_map = val
+            .source_map
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("Failed to serialize source map: {}", e)))?;
+
+        let references = val
+            .references
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Error::new(Status::GenericFailure, format!("Failed to serialize references: {}", e)))?;
+
         let ocr_elements = val
             .ocr_elements
             .map(|elems| serde_json::to_value(&elems))
@@ -338,6 +403,8 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number as u32,
+                    name: t.name,
+                    has_header: t.has_header,
                 })
                 .collect(),
             detected_languages: val.detected_languages,
@@ -355,6 +422,7 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                         total_chunks: usize_to_u32(chunk.metadata.total_chunks, "chunks[].metadata.total_chunks")?,
                         first_page: chunk.metadata.first_page.map(|p| p as u32),
                         last_page: chunk.metadata.last_page.map(|p| p as u32),
+                        heading_path: chunk.metadata.heading_path.clone(),
                     };
 
                     let embedding = chunk
@@ -376,6 +444,11 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
             elements,
             document,
             ocr_elements,
+            outline,
+            formulas,
+            text_blocks,
+            source_map,
+            references,
         })
     }
 }
@@ -466,6 +539,12 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                     bits_per_component: img.bits_per_component,
                     is_mask: img.is_mask,
                     description: img.description,
+                    alt_text: img.alt_text,
+                    caption: img.caption,
+                    page_position: img.page_position.and_then(|position| match position.as_slice() {
+                        [x, y] => Some((*x as f32, *y as f32)),
+                        _ => None,
+                    }),
                     ocr_result,
                 });
             }
@@ -510,6 +589,7 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                         total_chunks: chunk.metadata.total_chunks as usize,
                         first_page: chunk.metadata.first_page.map(|v| v as usize),
                         last_page: chunk.metadata.last_page.map(|v| v as usize),
+                        heading_path: chunk.metadata.heading_path,
                     },
                 });
             }
@@ -531,6 +611,9 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number as usize,
+                    name: t.name,
+                    has_header: t.has_header,
+                    cell_spans: Vec::new(),
                 })
                 .collect(),
             detected_languages: val.detected_languages,
@@ -572,6 +655,11 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
             document,
             djot_content: None,
             ocr_elements: val.ocr_elements.and_then(|v| serde_json::from_value(v).ok()),
+            outline: val.outline.and_then(|v| serde_json::from_value(v).ok()),
+            formulas: val.formulas.and_then(|v| serde_json::from_value(v).ok()),
+            text_blocks: val.text_blocks.and_then(|v| serde_json::from_value(v).ok()),
+            source_map: val.source_map.and_then(|v| serde_json::from_value(v).ok()),
+            references: val.references.and_then(|v| serde_json::from_value(v).ok()),
         })
     }
 }
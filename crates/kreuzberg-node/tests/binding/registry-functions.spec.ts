@@ -13,6 +13,7 @@
 import { beforeEach, describe, expect, it } from "vitest";
 import {
 	clearDocumentExtractors,
+	clearMimeDetectors,
 	clearOcrBackends,
 	clearPostProcessors,
 	clearValidators,
@@ -23,15 +24,18 @@ import {
 	getExtensionsForMime,
 	listDocumentExtractors,
 	listEmbeddingPresets,
+	listMimeDetectors,
 	listOcrBackends,
 	listPostProcessors,
 	listValidators,
 	type OcrBackendProtocol,
 	type PostProcessorProtocol,
+	registerMimeDetector,
 	registerOcrBackend,
 	registerPostProcessor,
 	registerValidator,
 	unregisterDocumentExtractor,
+	unregisterMimeDetector,
 	unregisterOcrBackend,
 	unregisterPostProcessor,
 	unregisterValidator,
@@ -373,6 +377,38 @@ describe("detectMimeType", () => {
 	});
 });
 
+describe("registerMimeDetector", () => {
+	beforeEach(() => {
+		clearMimeDetectors();
+	});
+
+	it("should detect documents matching the registered signature", () => {
+		registerMimeDetector({
+			name: "ledger-detector",
+			mimeType: "application/x-ledger",
+			magic: Buffer.from("LDGR\x01", "latin1"),
+		});
+
+		expect(listMimeDetectors()).toContain("ledger-detector");
+		expect(detectMimeType(Buffer.from("LDGR\x01opening balance", "latin1"))).toBe("application/x-ledger");
+		expect(detectMimeType(Buffer.from([0x25, 0x50, 0x44, 0x46]))).toContain("pdf");
+
+		unregisterMimeDetector("ledger-detector");
+		expect(listMimeDetectors()).not.toContain("ledger-detector");
+	});
+
+	it("should match the magic bytes at the given offset", () => {
+		registerMimeDetector({
+			name: "offset-detector",
+			mimeType: "application/x-offset",
+			magic: Buffer.from("MAGIC"),
+			offset: 4,
+		});
+
+		expect(detectMimeType(Buffer.from("\0\0\0\0MAGIC rest"))).toBe("application/x-offset");
+	});
+});
+
 describe("detectMimeTypeFromPath", () => {
 	it("should detect MIME type from .pdf extension with checkExists false", () => {
 		try {
@@ -57,6 +57,14 @@ export interface NativeBinding {
 	unregisterOcrBackend(name: string): void;
 	clearOcrBackends(): void;
 	listOcrBackends(): string[];
+	registerImageCaptioner(captioner: Record<string, unknown>): void;
+	unregisterImageCaptioner(name: string): void;
+	clearImageCaptioners(): void;
+	listImageCaptioners(): string[];
+	registerMimeDetector(detector: Record<string, unknown>): void;
+	unregisterMimeDetector(name: string): void;
+	clearMimeDetectors(): void;
+	listMimeDetectors(): string[];
 	registerDocumentExtractor(extractor: Record<string, unknown>): void;
 	unregisterDocumentExtractor(name: string): void;
 	clearDocumentExtractors(): void;
@@ -11,10 +11,12 @@
 
 import type {
 	ChunkingConfig,
+	DocxConfig,
 	ExtractionConfig,
 	HtmlConversionOptions,
 	HtmlPreprocessingOptions,
 	ImageExtractionConfig,
+	JupyterConfig,
 	KeywordConfig,
 	LanguageDetectionConfig,
 	OcrConfig,
@@ -89,6 +91,13 @@ function normalizeOcrConfig(ocr?: OcrConfig): NativeExtractionConfig | undefined
 
 	setIfDefined(normalized, "paddleOcrConfig", ocr.paddleOcrConfig);
 	setIfDefined(normalized, "elementConfig", ocr.elementConfig);
+	setIfDefined(normalized, "autoLanguage", ocr.autoLanguage);
+	setIfDefined(normalized, "fallbackBackends", ocr.fallbackBackends);
+	setIfDefined(normalized, "userWords", ocr.userWords);
+	setIfDefined(normalized, "renderDpi", ocr.renderDpi);
+	setIfDefined(normalized, "emptyPageConfidence", ocr.emptyPageConfidence);
+	setIfDefined(normalized, "pageFilter", ocr.pageFilter);
+	setIfDefined(normalized, "region", ocr.region);
 
 	return normalized;
 }
@@ -108,6 +117,8 @@ function normalizeChunkingConfig(chunking?: ChunkingConfig): NativeExtractionCon
 	const normalized: NativeExtractionConfig = {};
 	setIfDefined(normalized, "maxChars", chunking.maxChars);
 	setIfDefined(normalized, "maxOverlap", chunking.maxOverlap);
+	setIfDefined(normalized, "overlapUnit", chunking.overlapUnit);
+	setIfDefined(normalized, "maxChunks", chunking.maxChunks);
 	setIfDefined(normalized, "preset", chunking.preset);
 	setIfDefined(normalized, "embedding", chunking.embedding);
 	setIfDefined(normalized, "enabled", chunking.enabled);
@@ -130,9 +141,15 @@ function normalizeImageExtractionConfig(images?: ImageExtractionConfig): NativeE
 	setIfDefined(normalized, "extractImages", images.extractImages);
 	setIfDefined(normalized, "targetDpi", images.targetDpi);
 	setIfDefined(normalized, "maxImageDimension", images.maxImageDimension);
+	setIfDefined(normalized, "maxDecodeDimension", images.maxDecodeDimension);
 	setIfDefined(normalized, "autoAdjustDpi", images.autoAdjustDpi);
 	setIfDefined(normalized, "minDpi", images.minDpi);
 	setIfDefined(normalized, "maxDpi", images.maxDpi);
+	setIfDefined(normalized, "maxImages", images.maxImages);
+	setIfDefined(normalized, "maxTotalImageBytes", images.maxTotalImageBytes);
+	setIfDefined(normalized, "minWidth", images.minWidth);
+	setIfDefined(normalized, "minHeight", images.minHeight);
+	setIfDefined(normalized, "outputFormat", images.outputFormat);
 	return normalized;
 }
 
@@ -152,6 +169,11 @@ function normalizePdfConfig(pdf?: PdfConfig): NativeExtractionConfig | undefined
 	setIfDefined(normalized, "extractImages", pdf.extractImages);
 	setIfDefined(normalized, "passwords", pdf.passwords);
 	setIfDefined(normalized, "extractMetadata", pdf.extractMetadata);
+	setIfDefined(normalized, "readingOrder", pdf.readingOrder);
+	setIfDefined(normalized, "allowOwnerLocked", pdf.allowOwnerLocked);
+	setIfDefined(normalized, "pageSeparator", pdf.pageSeparator);
+	setIfDefined(normalized, "extractMath", pdf.extractMath);
+	setIfDefined(normalized, "insertImageMarkers", pdf.insertImageMarkers);
 	return normalized;
 }
 
@@ -170,6 +192,7 @@ function normalizeTokenReductionConfig(tokenReduction?: TokenReductionConfig): N
 	const normalized: NativeExtractionConfig = {};
 	setIfDefined(normalized, "mode", tokenReduction.mode);
 	setIfDefined(normalized, "preserveImportantWords", tokenReduction.preserveImportantWords);
+	setIfDefined(normalized, "language", tokenReduction.language);
 	return normalized;
 }
 
@@ -326,6 +349,40 @@ function normalizePageConfig(pages?: PageExtractionConfig): NativeExtractionConf
 	return normalized;
 }
 
+/**
+ * Normalize DOCX extraction configuration.
+ *
+ * @param docx - DOCX extraction configuration
+ * @returns Normalized config object or undefined
+ * @internal
+ */
+function normalizeDocxConfig(docx?: DocxConfig): NativeExtractionConfig | undefined {
+	if (!docx) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "estimatePages", docx.estimatePages);
+	return normalized;
+}
+
+/**
+ * Normalize Jupyter notebook extraction configuration.
+ *
+ * @param jupyter - Jupyter notebook extraction configuration
+ * @returns Normalized config object or undefined
+ * @internal
+ */
+function normalizeJupyterConfig(jupyter?: JupyterConfig): NativeExtractionConfig | undefined {
+	if (!jupyter) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "outputs", jupyter.outputs);
+	return normalized;
+}
+
 /**
  * Master orchestrator for normalizing the complete extraction configuration.
  * Calls all specific normalizers and aggregates results into a single config object
@@ -378,7 +435,24 @@ function normalizeExtractionConfig(config: ExtractionConfig | null): NativeExtra
 	setIfDefined(normalized, "htmlOptions", htmlOptions);
 
 	setIfDefined(normalized, "outputFormat", config.outputFormat);
+	setIfDefined(normalized, "outputMode", config.outputMode);
 	setIfDefined(normalized, "resultFormat", config.resultFormat);
+	setIfDefined(normalized, "maxContentChars", config.maxContentChars);
+	setIfDefined(normalized, "preserveListMarkers", config.preserveListMarkers);
+	setIfDefined(normalized, "extractOutline", config.extractOutline);
+	setIfDefined(normalized, "extractReferences", config.extractReferences);
+	setIfDefined(normalized, "normalizeBidi", config.normalizeBidi);
+	setIfDefined(normalized, "htmlIncludeAltText", config.htmlIncludeAltText);
+	setIfDefined(normalized, "emitPositions", config.emitPositions);
+	setIfDefined(normalized, "emitSourceOffsets", config.emitSourceOffsets);
+	setIfDefined(normalized, "lowMemory", config.lowMemory);
+	setIfDefined(normalized, "normalizeNewlines", config.normalizeNewlines);
+
+	const docxOptions = normalizeDocxConfig(config.docxOptions);
+	setIfDefined(normalized, "docxOptions", docxOptions);
+
+	const jupyterOptions = normalizeJupyterConfig(config.jupyterOptions);
+	setIfDefined(normalized, "jupyterOptions", jupyterOptions);
 
 	return normalized;
 }
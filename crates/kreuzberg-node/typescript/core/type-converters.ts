@@ -14,7 +14,10 @@ import type {
 	ElementType,
 	ExtractedImage,
 	ExtractionResult,
+	Formula,
+	OutlineNode,
 	PageContent,
+	SourceSpan,
 	Table,
 } from "../types.js";
 
@@ -104,6 +107,8 @@ function convertChunk(rawChunk: unknown): Chunk {
 			firstPage: ((metadata["first_page"] ?? metadata["firstPage"]) as number | null) ?? null,
 			// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
 			lastPage: ((metadata["last_page"] ?? metadata["lastPage"]) as number | null) ?? null,
+			// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+			headingPath: ((metadata["heading_path"] ?? metadata["headingPath"]) as string[] | null) ?? null,
 		},
 	};
 }
@@ -171,6 +176,9 @@ function convertImage(rawImage: unknown): ExtractedImage {
 			bitsPerComponent: null,
 			isMask: false,
 			description: null,
+			altText: null,
+			caption: null,
+			pagePosition: null,
 			ocrResult: null,
 		};
 	}
@@ -198,6 +206,12 @@ function convertImage(rawImage: unknown): ExtractedImage {
 		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
 		description: (image["description"] as string | null) ?? null,
 		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		altText: (image["altText"] as string | null) ?? null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		caption: (image["caption"] as string | null) ?? null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		pagePosition: (image["pagePosition"] as [number, number] | null) ?? null,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
 		ocrResult: image["ocrResult"] ? convertResult(image["ocrResult"]) : null,
 	};
 }
@@ -234,6 +248,82 @@ function convertPageContent(rawPage: unknown): PageContent {
 	};
 }
 
+/**
+ * Convert raw outline entry from native binding to typed OutlineNode.
+ *
+ * @param rawNode - Raw outline entry from native binding (snake_case keys)
+ * @returns Typed OutlineNode object
+ * @internal
+ */
+function convertOutlineNode(rawNode: unknown): OutlineNode {
+	const node = (rawNode && typeof rawNode === "object" ? rawNode : {}) as Record<string, unknown>;
+	// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+	const pageNumber = (node["page_number"] ?? node["pageNumber"]) as number | undefined;
+	// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+	const children = node["children"];
+
+	return {
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		title: (node["title"] as string) ?? "",
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		level: (node["level"] as number) ?? 1,
+		...(pageNumber !== undefined && pageNumber !== null ? { pageNumber } : {}),
+		...(Array.isArray(children) && children.length > 0
+			? { children: (children as unknown[]).map((child) => convertOutlineNode(child)) }
+			: {}),
+	};
+}
+
+/**
+ * Convert raw formula from native binding to typed Formula.
+ *
+ * @param rawFormula - Raw formula from native binding (snake_case keys)
+ * @returns Typed Formula object
+ * @internal
+ */
+function convertFormula(rawFormula: unknown): Formula {
+	const formula = (rawFormula && typeof rawFormula === "object" ? rawFormula : {}) as Record<string, unknown>;
+	// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+	const boundingBox = (formula["bounding_box"] ?? formula["boundingBox"]) as BoundingBox | undefined;
+
+	return {
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		latex: (formula["latex"] as string) ?? "",
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		kind: formula["kind"] === "display" ? "display" : "inline",
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		pageNumber: ((formula["page_number"] ?? formula["pageNumber"]) as number) ?? 0,
+		...(boundingBox ? { boundingBox } : {}),
+	};
+}
+
+/**
+ * Convert raw source span from native binding to typed SourceSpan.
+ *
+ * @param rawSpan - Raw source span from native binding (snake_case keys)
+ * @returns Typed SourceSpan object
+ * @internal
+ */
+function convertSourceSpan(rawSpan: unknown): SourceSpan {
+	const span = (rawSpan && typeof rawSpan === "object" ? rawSpan : {}) as Record<string, unknown>;
+	// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+	const page = span["page"] as number | null | undefined;
+
+	return {
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		contentStart: ((span["content_start"] ?? span["contentStart"]) as number) ?? 0,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		contentEnd: ((span["content_end"] ?? span["contentEnd"]) as number) ?? 0,
+		...(page !== undefined && page !== null ? { page } : {}),
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		sourceStart: ((span["source_start"] ?? span["sourceStart"]) as number) ?? 0,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		sourceEnd: ((span["source_end"] ?? span["sourceEnd"]) as number) ?? 0,
+		// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+		unit: span["unit"] === "page_char" ? "page_char" : "byte",
+	};
+}
+
 /**
  * Convert raw result object from native binding to typed ExtractionResult.
  * Handles metadata parsing, array conversions, and nested structure conversion.
@@ -312,6 +402,36 @@ function convertResult(rawResult: unknown): ExtractionResult {
 		returnObj.ocrElements = ocrElementsData as import("../types.js").OcrElement[];
 	}
 
+	// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+	const outlineData = result["outline"];
+	if (Array.isArray(outlineData)) {
+		returnObj.outline = (outlineData as unknown[]).map((node) => convertOutlineNode(node));
+	}
+
+	// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+	const formulasData = result["formulas"];
+	if (Array.isArray(formulasData)) {
+		returnObj.formulas = (formulasData as unknown[]).map((formula) => convertFormula(formula));
+	}
+
+	// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+	const textBlocksData = result["textBlocks"];
+	if (Array.isArray(textBlocksData)) {
+		returnObj.textBlocks = textBlocksData as import("../types.js").TextBlock[];
+	}
+
+	// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+	const sourceMapData = result["sourceMap"];
+	if (Array.isArray(sourceMapData)) {
+		returnObj.sourceMap = (sourceMapData as unknown[]).map((span) => convertSourceSpan(span));
+	}
+
+	// biome-ignore lint/complexity/useLiteralKeys: required for strict TypeScript noPropertyAccessFromIndexSignature
+	const referencesData = result["references"];
+	if (Array.isArray(referencesData)) {
+		returnObj.references = referencesData as import("../types.js").Reference[];
+	}
+
 	return returnObj;
 }
 
@@ -323,7 +443,9 @@ export {
 	ensureUint8Array,
 	convertChunk,
 	convertElement,
+	convertFormula,
 	convertImage,
+	convertOutlineNode,
 	convertPageContent,
 	convertResult,
 };
@@ -67,15 +67,19 @@
 export type {
 	Chunk,
 	ChunkingConfig,
+	DocxConfig,
 	ErrorClassification,
 	ExtractedImage,
 	ExtractionConfig,
 	ExtractionResult,
 	HtmlConversionOptions,
 	HtmlPreprocessingOptions,
+	ImageCaptionerProtocol,
 	ImageExtractionConfig,
+	JupyterConfig,
 	KeywordConfig,
 	LanguageDetectionConfig,
+	MimeDetectorSignature,
 	OcrBackendProtocol,
 	OcrConfig,
 	PageContent,
@@ -83,6 +87,7 @@ export type {
 	PdfConfig,
 	PostProcessorConfig,
 	PostProcessorProtocol,
+	Rect,
 	Table,
 	TesseractConfig,
 	TokenReductionConfig,
@@ -177,6 +182,28 @@ export {
 	unregisterOcrBackend,
 } from "./plugins/ocr-backends.js";
 
+// ============================================================================
+// Plugin System: Image Captioners
+// ============================================================================
+
+export {
+	clearImageCaptioners,
+	listImageCaptioners,
+	registerImageCaptioner,
+	unregisterImageCaptioner,
+} from "./plugins/image-captioners.js";
+
+// ============================================================================
+// Plugin System: MIME Detectors
+// ============================================================================
+
+export {
+	clearMimeDetectors,
+	listMimeDetectors,
+	registerMimeDetector,
+	unregisterMimeDetector,
+} from "./plugins/mime-detectors.js";
+
 // ============================================================================
 // Registry: Document Extractors
 // ============================================================================
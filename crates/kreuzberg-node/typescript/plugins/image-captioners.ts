@@ -0,0 +1,118 @@
+import { getBinding } from "../core/binding.js";
+import type { ImageCaptionerProtocol } from "../types.js";
+
+/**
+ * Arguments as delivered by the native binding: either spread or packed in a tuple
+ */
+type CaptionTuple = [string, string];
+
+/**
+ * Type guard for the packed caption tuple
+ */
+function isCaptionTuple(value: unknown): value is CaptionTuple {
+	return Array.isArray(value) && value.length === 2 && typeof value[0] === "string" && typeof value[1] === "string";
+}
+
+/**
+ * Register a custom image captioner.
+ *
+ * When a captioner is registered, Kreuzberg passes every extracted image without a
+ * caption to it and stores the returned text in `ExtractedImage.caption`. Images are
+ * only extracted when `images.extractImages` is enabled. If several captioners are
+ * registered, the most recently registered one is used.
+ *
+ * @param captioner - ImageCaptionerProtocol implementation with name() and caption()
+ * @throws {Error} If captioner is missing required methods (name or caption)
+ * @throws {Error} If captioner name is empty string or contains invalid characters
+ * @throws {Error} If registration fails due to FFI issues
+ *
+ * @example
+ * ```typescript
+ * import { registerImageCaptioner, extractFile } from '@kreuzberg/node';
+ *
+ * registerImageCaptioner({
+ *   name: () => 'vision-captioner',
+ *   caption: async (imageBytes, format) => myVisionModel.describe(imageBytes, format),
+ * });
+ *
+ * const result = await extractFile('report.pdf', null, { images: { extractImages: true } });
+ * ```
+ */
+export function registerImageCaptioner(captioner: ImageCaptionerProtocol): void {
+	const binding = getBinding();
+
+	const wrappedCaptioner = {
+		name: typeof captioner.name === "function" ? captioner.name() : captioner.name,
+		async caption(...captionArgs: [string | CaptionTuple | [CaptionTuple], string?]): Promise<string> {
+			let [encoded, format] = captionArgs;
+
+			if (Array.isArray(encoded) && encoded.length === 1 && isCaptionTuple(encoded[0])) {
+				[encoded, format] = encoded[0];
+			} else if (isCaptionTuple(encoded)) {
+				[encoded, format] = encoded;
+			}
+
+			if (typeof encoded !== "string" || typeof format !== "string") {
+				throw new Error("Image captioner did not receive image bytes and format");
+			}
+
+			const caption = await captioner.caption(new Uint8Array(Buffer.from(encoded, "base64")), format);
+			return String(caption);
+		},
+	};
+
+	binding.registerImageCaptioner(wrappedCaptioner);
+}
+
+/**
+ * List all registered image captioners.
+ *
+ * @returns Array of captioner names in registration order (empty array if none registered)
+ *
+ * @example
+ * ```typescript
+ * import { listImageCaptioners } from '@kreuzberg/node';
+ *
+ * console.log(listImageCaptioners()); // ['vision-captioner']
+ * ```
+ */
+export function listImageCaptioners(): string[] {
+	const binding = getBinding();
+	return binding.listImageCaptioners();
+}
+
+/**
+ * Unregister an image captioner by name.
+ *
+ * If the captioner doesn't exist, this operation is a no-op.
+ *
+ * @param name - Name of the captioner to unregister
+ *
+ * @example
+ * ```typescript
+ * import { unregisterImageCaptioner } from '@kreuzberg/node';
+ *
+ * unregisterImageCaptioner('vision-captioner');
+ * ```
+ */
+export function unregisterImageCaptioner(name: string): void {
+	const binding = getBinding();
+	binding.unregisterImageCaptioner(name);
+}
+
+/**
+ * Clear all registered image captioners.
+ *
+ * After clearing, extracted images are no longer captioned.
+ *
+ * @example
+ * ```typescript
+ * import { clearImageCaptioners } from '@kreuzberg/node';
+ *
+ * clearImageCaptioners();
+ * ```
+ */
+export function clearImageCaptioners(): void {
+	const binding = getBinding();
+	binding.clearImageCaptioners();
+}
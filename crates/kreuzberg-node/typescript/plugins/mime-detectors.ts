@@ -0,0 +1,81 @@
+import { getBinding } from "../core/binding.js";
+import type { MimeDetectorSignature } from "../types.js";
+
+/**
+ * Register a custom MIME detector.
+ *
+ * The detector recognizes documents starting with (or containing at `offset`) the given
+ * magic bytes, and is asked before the built-in MIME detection. Detection runs
+ * synchronously inside the native library, so detectors are declared as signatures
+ * rather than JavaScript callbacks.
+ *
+ * @param detector - Signature describing the format
+ *
+ * @example
+ * ```typescript
+ * import { registerMimeDetector, detectMimeType } from '@kreuzberg/node';
+ *
+ * registerMimeDetector({
+ *   name: 'ledger-detector',
+ *   mimeType: 'application/x-ledger',
+ *   magic: Buffer.from('LDGR\x01', 'latin1'),
+ * });
+ *
+ * detectMimeType(Buffer.from('LDGR\x01...', 'latin1')); // 'application/x-ledger'
+ * ```
+ */
+export function registerMimeDetector(detector: MimeDetectorSignature): void {
+	const binding = getBinding();
+	binding.registerMimeDetector({ ...detector });
+}
+
+/**
+ * Unregister a MIME detector by name.
+ *
+ * If the detector doesn't exist, this is a no-op (does not throw).
+ *
+ * @param name - Detector name to unregister (case-sensitive)
+ *
+ * @example
+ * ```typescript
+ * import { unregisterMimeDetector } from '@kreuzberg/node';
+ *
+ * unregisterMimeDetector('ledger-detector');
+ * ```
+ */
+export function unregisterMimeDetector(name: string): void {
+	const binding = getBinding();
+	binding.unregisterMimeDetector(name);
+}
+
+/**
+ * Clear all registered MIME detectors.
+ *
+ * @example
+ * ```typescript
+ * import { clearMimeDetectors } from '@kreuzberg/node';
+ *
+ * clearMimeDetectors();
+ * ```
+ */
+export function clearMimeDetectors(): void {
+	const binding = getBinding();
+	binding.clearMimeDetectors();
+}
+
+/**
+ * List all registered MIME detectors.
+ *
+ * @returns Array of detector names (empty array if none registered)
+ *
+ * @example
+ * ```typescript
+ * import { listMimeDetectors } from '@kreuzberg/node';
+ *
+ * const names = listMimeDetectors();
+ * ```
+ */
+export function listMimeDetectors(): string[] {
+	const binding = getBinding();
+	return binding.listMimeDetectors();
+}
@@ -212,6 +212,39 @@ export interface OcrConfig {
 
 	/** OCR element extraction configuration. */
 	elementConfig?: OcrElementConfig;
+
+	/** Pick the OCR language from the script detected on the first page (Tesseract only). Default: false. */
+	autoLanguage?: boolean;
+
+	/** Backends tried in order when `backend` is unavailable or fails (e.g. ['paddle-ocr']). Default: []. */
+	fallbackBackends?: string[];
+
+	/** Domain-specific words to bias recognition towards (Tesseract user-words; PaddleOCR post-correction). */
+	userWords?: string[];
+
+	/** DPI at which PDF pages are rendered before OCR; higher for dense small text, lower for speed. Default: 300. */
+	renderDpi?: number;
+
+	/** Report pages where OCR found no text (blank, or normalized confidence 0-1 below this floor) in `metadata.ocr_empty_pages`. */
+	emptyPageConfidence?: number;
+
+	/** Only OCR these 1-indexed PDF pages, keeping the native text of the rest, instead of deciding automatically. */
+	pageFilter?: number[];
+
+	/** Only OCR this region of each page, in coordinates normalized to the page size (0-1, origin top-left). */
+	region?: Rect;
+}
+
+/**
+ * A rectangle in coordinates normalized to the page size.
+ *
+ * The origin is the top-left corner of the page; all values lie within 0-1.
+ */
+export interface Rect {
+	left: number;
+	top: number;
+	width: number;
+	height: number;
 }
 
 /**
@@ -227,6 +260,12 @@ export interface ChunkingConfig {
 	/** Maximum overlapping characters between consecutive chunks for context preservation. Default: 512. */
 	maxOverlap?: number;
 
+	/** Unit of maxOverlap: 'characters' or trailing 'sentences' carried into the next chunk. Default: 'characters'. */
+	overlapUnit?: "characters" | "sentences";
+
+	/** Stop after this many chunks; the result is marked with `chunks_truncated` metadata. Default: no limit. */
+	maxChunks?: number;
+
 	/**
 	 * Alternative to maxChars: chunk size using different unit.
 	 * Mutually exclusive with maxChars.
@@ -280,6 +319,9 @@ export interface TokenReductionConfig {
 
 	/** Preserve tokens for semantically important words even in aggressive mode. Default: true. */
 	preserveImportantWords?: boolean;
+
+	/** Stopword language as an ISO 639-1 or 639-3 code (e.g. "de", "deu"). Defaults to the detected language, then English. */
+	language?: string;
 }
 
 /**
@@ -318,6 +360,21 @@ export interface PdfConfig {
 
 	/** Hierarchy extraction configuration. */
 	hierarchy?: HierarchyConfig;
+
+	/** Reading order: "raw" keeps content stream order, "column_aware" reads multi-column pages column by column. Default: "raw". */
+	readingOrder?: "raw" | "column_aware";
+
+	/** Extract text from PDFs whose owner permissions forbid copying. A warning is logged when restrictions are bypassed. Default: true. */
+	allowOwnerLocked?: boolean;
+
+	/** Text inserted between pages, e.g. "\n\n<!-- page {page} -->\n\n"; `{page}` is replaced with the following page number. Default: "\n\n". */
+	pageSeparator?: string;
+
+	/** Recognize mathematical formulas as LaTeX into `formulas`. Requires the math-ocr feature and its ONNX model. Default: false */
+	extractMath?: boolean;
+
+	/** Insert `[image N]` markers into the content where extracted images appear (N = imageIndex + 1). Requires image extraction. Default: false */
+	insertImageMarkers?: boolean;
 }
 
 /**
@@ -335,6 +392,9 @@ export interface ImageExtractionConfig {
 	/** Maximum image dimension (width or height) in pixels. Images larger than this are downscaled. Default: 2000. */
 	maxImageDimension?: number;
 
+	/** Largest width or height an image may declare before it is decoded; larger image files are rejected and larger embedded images are left undecoded. 0 disables the limit. Default: 32768. */
+	maxDecodeDimension?: number;
+
 	/** Automatically adjust DPI based on image content and quality. Default: true. */
 	autoAdjustDpi?: boolean;
 
@@ -343,6 +403,21 @@ export interface ImageExtractionConfig {
 
 	/** Maximum DPI to avoid excessive file sizes. Default: 300. */
 	maxDpi?: number;
+
+	/** Maximum number of images to extract per document; further images are skipped and `images_truncated` is set in metadata. */
+	maxImages?: number;
+
+	/** Maximum total bytes of image data to extract per document; extraction stops at the first image that would exceed it. */
+	maxTotalImageBytes?: number;
+
+	/** Minimum image width in pixels; narrower images (icons, tracking pixels) are skipped. 0 disables the check. Default: 16. */
+	minWidth?: number;
+
+	/** Minimum image height in pixels; shorter images are skipped. 0 disables the check. Default: 16. */
+	minHeight?: number;
+
+	/** Encoding for extracted images: "original", "png", "webp", "jpeg" or "jpeg:<quality>". Default: "original". */
+	outputFormat?: string;
 }
 
 /**
@@ -572,6 +647,32 @@ export interface PageExtractionConfig {
 	markerFormat?: string;
 }
 
+/**
+ * DOCX extraction configuration.
+ */
+export interface DocxConfig {
+	/**
+	 * Estimate page boundaries from content length and explicit page breaks. DOCX files do not
+	 * store Word's pagination, so the result is approximate and `page_boundaries_estimated` is
+	 * set in the metadata. Default: false
+	 */
+	estimatePages?: boolean;
+}
+
+/**
+ * Jupyter notebook extraction configuration.
+ */
+export interface JupyterConfig {
+	/**
+	 * Which code cell outputs are included. Default: 'all'
+	 *
+	 * - "all": Every output, each representation of rich outputs labelled by MIME type
+	 * - "text": Stream output, errors and the text/plain representation of rich outputs
+	 * - "none": No outputs
+	 */
+	outputs?: "all" | "text" | "none";
+}
+
 /**
  * Main extraction configuration interface.
  *
@@ -634,6 +735,14 @@ export interface ExtractionConfig {
 	 */
 	outputFormat?: "plain" | "markdown" | "djot" | "html";
 
+	/**
+	 * Markdown decoration of HTML and DOCX content. Default: "markdown".
+	 *
+	 * - "markdown": Keep headings, emphasis, links, list markers and table pipes
+	 * - "plain_text": Strip Markdown decoration and emit clean prose
+	 */
+	outputMode?: "markdown" | "plain_text";
+
 	/**
 	 * Result structure format. Default: "unified".
 	 *
@@ -641,6 +750,49 @@ export interface ExtractionConfig {
 	 * - "element_based": Semantic element extraction (Unstructured-compatible)
 	 */
 	resultFormat?: "unified" | "element_based";
+
+	/** Maximum number of characters of extracted content. Longer content is truncated and `metadata.truncated` is set. */
+	maxContentChars?: number;
+
+	/** Keep `- ` / `1. ` list markers in Markdown and DOCX content, indenting nested items two spaces per level. Default: true */
+	preserveListMarkers?: boolean;
+
+	/** Populate `outline` on the result from PDF bookmarks or DOCX heading styles. Default: false */
+	extractOutline?: boolean;
+
+	/** Populate `references` on the result by parsing the references section of the content (requires the references feature). Default: false */
+	extractReferences?: boolean;
+
+	/** Reorder Arabic/Hebrew lines stored in visual order (common in PDFs) into logical order with the Unicode Bidirectional Algorithm. Default: false */
+	normalizeBidi?: boolean;
+
+	/** Keep image alt text (`[alt: ...]`) and `aria-label`s of links, buttons and form controls in HTML content. Default: true */
+	htmlIncludeAltText?: boolean;
+
+	/** Populate `textBlocks` on the result with each text block's page and bounding box, from the PDF text layer or OCR elements. Default: false */
+	emitPositions?: boolean;
+
+	/** Populate `sourceMap` on the result with content ranges mapped to source positions (PDF page character indices, HTML byte offsets). Default: false */
+	emitSourceOffsets?: boolean;
+
+	/** Let post-processors update the result in place instead of a full copy, reducing peak memory for large documents. Default: false */
+	lowMemory?: boolean;
+
+	/**
+	 * Line ending of the extracted text, applied as the final processing step to content,
+	 * pages and chunks. Default: unset (line endings are left unchanged).
+	 *
+	 * - "lf": Unix line endings
+	 * - "crlf": Windows line endings
+	 * - "keep": Leave line endings unchanged
+	 */
+	normalizeNewlines?: "lf" | "crlf" | "keep";
+
+	/** DOCX-specific options. */
+	docxOptions?: DocxConfig;
+
+	/** Jupyter notebook options. */
+	jupyterOptions?: JupyterConfig;
 }
 
 /**
@@ -655,8 +807,14 @@ export interface Table {
 	/** Markdown representation of the table for display or parsing */
 	markdown: string;
 
-	/** Page number where this table was found (1-indexed) */
+	/** Page number where this table was found (1-indexed; sheet position for spreadsheets) */
 	pageNumber: number;
+
+	/** Table name, e.g. the sheet name for spreadsheets */
+	name?: string | null;
+
+	/** Whether the first row of cells is a header row */
+	hasHeader: boolean;
 }
 
 export interface ExcelMetadata {
@@ -825,8 +983,9 @@ export interface PageBoundary {
  * - "page": Standard document pages (PDF, DOCX, images)
  * - "slide": Presentation slides (PPTX, ODP)
  * - "sheet": Spreadsheet sheets (XLSX, ODS)
+ * - "chapter": Book chapters (EPUB)
  */
-export type PageUnitType = "page" | "slide" | "sheet";
+export type PageUnitType = "page" | "slide" | "sheet" | "chapter";
 
 /**
  * Detailed per-page metadata.
@@ -889,6 +1048,8 @@ export interface ChunkMetadata {
 	firstPage?: number | null;
 	/** Last page number this chunk spans (1-indexed, only when page tracking enabled) */
 	lastPage?: number | null;
+	/** Titles of the Markdown headings this chunk falls under, outermost first (Markdown chunker only) */
+	headingPath?: string[] | null;
 }
 
 /**
@@ -943,6 +1104,15 @@ export interface ExtractedImage {
 	/** Image description or caption if available */
 	description?: string | null;
 
+	/** Alternative text authored for the image (e.g. PPTX picture descriptions) */
+	altText?: string | null;
+
+	/** Caption generated by a registered image captioner, null otherwise */
+	caption?: string | null;
+
+	/** Top-left position on the page as [x, y] fractions (0..1, origin top-left), PDF only */
+	pagePosition?: [number, number] | null;
+
 	/** OCR extraction result if OCR was run on this image, null otherwise */
 	ocrResult?: ExtractionResult | null;
 }
@@ -997,6 +1167,7 @@ export interface Metadata {
 
 	sheet_count?: number;
 	sheet_names?: string[];
+	hidden_sheets?: string[];
 
 	from_email?: string | null;
 	from_name?: string | null;
@@ -1175,6 +1346,85 @@ export interface ExtractionResult {
 
 	/** Structured document tree when include_document_structure is enabled, null otherwise */
 	document?: Record<string, unknown> | null;
+
+	/** Document outline (PDF bookmarks or DOCX headings) when extractOutline is enabled, null otherwise */
+	outline?: OutlineNode[] | null;
+
+	/** Formulas recognized as LaTeX when pdfOptions.extractMath is enabled (requires the math-ocr feature), null otherwise */
+	formulas?: Formula[] | null;
+
+	/** Positioned text blocks when emitPositions is enabled, null otherwise */
+	textBlocks?: TextBlock[] | null;
+
+	/** Content ranges mapped to positions in the source when emitSourceOffsets is enabled, null otherwise */
+	sourceMap?: SourceSpan[] | null;
+
+	/** Entries parsed from the references section when extractReferences is enabled, null otherwise */
+	references?: Reference[] | null;
+}
+
+/** An entry of a document's references section, parsed heuristically. */
+export interface Reference {
+	/** The entry text with line breaks joined and its list marker removed */
+	raw: string;
+	/** Author names in the order listed, as written */
+	authors?: string[];
+	/** Title of the cited work */
+	title?: string;
+	/** Publication year */
+	year?: number;
+	/** DOI without a resolver prefix, e.g. "10.1000/xyz123" */
+	doi?: string;
+}
+
+/** A range of `content` mapped to where its text came from in the source document. */
+export interface SourceSpan {
+	/** Byte offset in `content` where the range starts */
+	contentStart: number;
+	/** Byte offset in `content` where the range ends (exclusive) */
+	contentEnd: number;
+	/** 1-indexed page of the source, for paged formats */
+	page?: number;
+	/** Start of the text in the source, counted in `unit` */
+	sourceStart: number;
+	/** End of the text in the source (exclusive), counted in `unit` */
+	sourceEnd: number;
+	/** "byte" for byte offsets into the source (HTML), "page_char" for character indices into the page text layer (PDF) */
+	unit: "byte" | "page_char";
+}
+
+/** A run of text with the page it appears on and its bounding box. */
+export interface TextBlock {
+	/** Text content of the block */
+	text: string;
+	/** 1-indexed page the block appears on */
+	page: number;
+	/** Bounding box on the page: PDF points (origin bottom-left) for PDFs, image pixels (origin top-left) for OCR */
+	bbox: BoundingBox;
+}
+
+/** An entry in a document outline. */
+export interface OutlineNode {
+	/** Entry title as shown in the document's navigation pane */
+	title: string;
+	/** Nesting level, starting at 1 for top-level entries */
+	level: number;
+	/** 1-indexed page the entry points to, when known */
+	pageNumber?: number;
+	/** Nested entries */
+	children?: OutlineNode[];
+}
+
+/** A mathematical formula recognized as LaTeX. */
+export interface Formula {
+	/** LaTeX source, without surrounding `$` delimiters */
+	latex: string;
+	/** "inline" for formulas in running text (`$...$`), "display" for formulas set apart (`$$...$$`) */
+	kind: "inline" | "display";
+	/** 1-indexed page the formula is on */
+	pageNumber: number;
+	/** Region of the page the formula was recognized from, in PDF points */
+	boundingBox?: BoundingBox;
 }
 
 /** Post-processor execution stage in the extraction pipeline. */
@@ -1295,6 +1545,26 @@ export interface ValidatorProtocol {
 	shutdown?(): void | Promise<void>;
 }
 
+/**
+ * Magic-number signature for recognizing a custom format during MIME detection.
+ *
+ * Registered signatures are checked before the built-in detection, so a document is
+ * recognized by its content even when its extension says otherwise.
+ */
+export interface MimeDetectorSignature {
+	/** Unique detector name */
+	name: string;
+
+	/** MIME type reported for documents that match */
+	mimeType: string;
+
+	/** Bytes the document must contain at `offset` */
+	magic: Buffer;
+
+	/** Position of the magic bytes from the start of the document (default: 0) */
+	offset?: number;
+}
+
 /**
  * OCR backend protocol for implementing custom OCR engines.
  *
@@ -1431,6 +1701,47 @@ export interface OcrBackendProtocol {
 	shutdown?(): void | Promise<void>;
 }
 
+/**
+ * Image captioner protocol for attaching captions to extracted images.
+ *
+ * When a captioner is registered, every extracted image without a caption is
+ * passed to `caption()` and the returned text is stored in `ExtractedImage.caption`.
+ * Images are only extracted when `images.extractImages` is enabled. If several
+ * captioners are registered, the most recently registered one is used.
+ *
+ * @example
+ * ```typescript
+ * import { registerImageCaptioner, extractFile } from '@kreuzberg/node';
+ *
+ * registerImageCaptioner({
+ *   name: () => 'vision-captioner',
+ *   caption: async (imageBytes, format) => myVisionModel.describe(imageBytes, format),
+ * });
+ *
+ * const result = await extractFile('report.pdf', null, { images: { extractImages: true } });
+ * console.log(result.images?.map((image) => image.caption));
+ * ```
+ */
+export interface ImageCaptionerProtocol {
+	/**
+	 * Return the unique name of this captioner.
+	 *
+	 * @returns Unique captioner identifier (e.g., "vision-captioner")
+	 */
+	name(): string;
+
+	/**
+	 * Generate a caption for an extracted image.
+	 *
+	 * A thrown error aborts the extraction.
+	 *
+	 * @param imageBytes - Encoded image data
+	 * @param format - Image format (e.g., "png", "jpeg")
+	 * @returns Promise resolving to the caption text
+	 */
+	caption(imageBytes: Uint8Array, format: string): Promise<string>;
+}
+
 /**
  * Result of error message classification into error codes.
  *
@@ -15,7 +15,7 @@ pub fn to_php_exception(error: kreuzberg::KreuzbergError) -> PhpException {
 
     match error {
         KreuzbergError::Validation { .. } => PhpException::default(format!("[Validation] {}", message)),
-        KreuzbergError::UnsupportedFormat(_) => PhpException::default(format!("[UnsupportedFormat] {}", message)),
+        KreuzbergError::UnsupportedFormat { .. } => PhpException::default(format!("[UnsupportedFormat] {}", message)),
         KreuzbergError::Parsing { .. } => PhpException::default(format!("[Parsing] {}", message)),
         KreuzbergError::Io(_) => PhpException::default(format!("[IO] {}", message)),
         KreuzbergError::Ocr { .. } => PhpException::default(format!("[OCR] {}", message)),
@@ -41,7 +41,10 @@ fn format_error_message(error: &kreuzberg::KreuzbergError) -> String {
                 message.clone()
             }
         }
-        KreuzbergError::UnsupportedFormat(msg) => msg.clone(),
+        KreuzbergError::UnsupportedFormat { mime_type, reason, .. } => match reason {
+            Some(reason) => format!("{}: {}", mime_type, reason),
+            None => mime_type.clone(),
+        },
         KreuzbergError::Parsing { message, source } => {
             if let Some(src) = source {
                 format!("{}: {}", message, src)
@@ -179,6 +179,11 @@ pub fn kreuzberg_extract_bytes(
                         djot_content: None,
                         ocr_elements: None,
                         document: None,
+                        outline: None,
+                        formulas: None,
+                        text_blocks: None,
+                        source_map: None,
+                        references: None,
                     };
 
                     return ExtractionResult::from_rust(rust_result);
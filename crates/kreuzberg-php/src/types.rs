@@ -513,7 +513,9 @@ impl ExtractionResult {
 ///
 /// - `cells` (array): Table data as nested arrays (rows of columns)
 /// - `markdown` (string): Markdown representation of the table
-/// - `page_number` (int): Page number where table was found
+/// - `page_number` (int): Page number where table was found (sheet position for spreadsheets)
+/// - `name` (string|null): Table name, e.g. the sheet name for spreadsheets
+/// - `has_header` (bool): Whether the first row of `cells` is a header row
 ///
 /// # Example
 ///
@@ -539,6 +541,14 @@ pub struct ExtractedTable {
     /// Page number
     #[php(prop)]
     pub page_number: usize,
+
+    /// Table name (sheet name for spreadsheets)
+    #[php(prop)]
+    pub name: Option<String>,
+
+    /// Whether the first row is a header row
+    #[php(prop)]
+    pub has_header: bool,
 }
 
 #[php_impl]
@@ -557,6 +567,8 @@ impl ExtractedTable {
             cells: table.cells,
             markdown: table.markdown,
             page_number: table.page_number,
+            name: table.name,
+            has_header: table.has_header,
         })
     }
 }
@@ -594,6 +606,12 @@ pub struct ExtractedImage {
     #[php(prop)]
     pub description: Option<String>,
     #[php(prop)]
+    pub alt_text: Option<String>,
+    #[php(prop)]
+    pub caption: Option<String>,
+    #[php(prop)]
+    pub page_position: Option<Vec<f64>>,
+    #[php(prop)]
     pub is_mask: bool,
 }
 
@@ -618,6 +636,9 @@ impl ExtractedImage {
             colorspace: img.colorspace,
             bits_per_component: img.bits_per_component.map(|b| b as i32),
             description: img.description,
+            alt_text: img.alt_text,
+            caption: img.caption,
+            page_position: img.page_position.map(|(x, y)| vec![x as f64, y as f64]),
             is_mask: img.is_mask,
         })
     }
@@ -634,6 +655,7 @@ impl ExtractedImage {
 /// - `total_chunks` (int): Total number of chunks
 /// - `first_page` (int|null): First page number in chunk
 /// - `last_page` (int|null): Last page number in chunk
+/// - `heading_path` (array|null): Titles of the enclosing Markdown headings, outermost first
 #[php_class]
 #[php(name = "Kreuzberg\\Types\\ChunkMetadata")]
 #[derive(Clone)]
@@ -652,6 +674,8 @@ pub struct ChunkMetadata {
     pub first_page: Option<usize>,
     #[php(prop)]
     pub last_page: Option<usize>,
+    #[php(prop)]
+    pub heading_path: Option<Vec<String>>,
 }
 
 #[php_impl]
@@ -667,6 +691,7 @@ impl ChunkMetadata {
             total_chunks: metadata.total_chunks,
             first_page: metadata.first_page,
             last_page: metadata.last_page,
+            heading_path: metadata.heading_path,
         })
     }
 }
@@ -1044,9 +1069,15 @@ pub(crate) fn php_array_to_table(arr: &ext_php_rs::types::ZendHashTable) -> PhpR
         .map(|v| v as usize)
         .unwrap_or(1);
 
+    let name = arr.get("name").and_then(|v| v.str()).map(str::to_string);
+
+    let has_header = arr.get("has_header").and_then(|v| v.bool()).unwrap_or(false);
+
     Ok(kreuzberg::types::Table {
         cells,
         markdown,
         page_number,
+        name,
+        has_header,
     })
 }
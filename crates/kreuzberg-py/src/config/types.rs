@@ -57,7 +57,20 @@ impl ExtractionConfig {
         pages=None,
         result_format=None,
         output_format=None,
-        include_document_structure=None
+        output_mode=None,
+        include_document_structure=None,
+        max_content_chars=None,
+        preserve_list_markers=None,
+        extract_outline=None,
+        extract_references=None,
+        normalize_bidi=None,
+        html_include_alt_text=None,
+        emit_positions=None,
+        emit_source_offsets=None,
+        low_memory=None,
+        normalize_newlines=None,
+        docx_options=None,
+        jupyter_options=None
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -77,12 +90,25 @@ impl ExtractionConfig {
         pages: Option<PageConfig>,
         result_format: Option<String>,
         output_format: Option<String>,
+        output_mode: Option<String>,
         include_document_structure: Option<bool>,
+        max_content_chars: Option<usize>,
+        preserve_list_markers: Option<bool>,
+        extract_outline: Option<bool>,
+        extract_references: Option<bool>,
+        normalize_bidi: Option<bool>,
+        html_include_alt_text: Option<bool>,
+        emit_positions: Option<bool>,
+        emit_source_offsets: Option<bool>,
+        low_memory: Option<bool>,
+        normalize_newlines: Option<String>,
+        docx_options: Option<DocxConfig>,
+        jupyter_options: Option<JupyterConfig>,
     ) -> PyResult<Self> {
         let (html_options_inner, html_options_dict) = parse_html_options_dict(html_options)?;
         Ok(Self {
             inner: kreuzberg::ExtractionConfig {
-                use_cache: use_cache.unwrap_or(true),
+                use_cache: use_cache.unwrap_or(true) && !kreuzberg::cache::cache_disabled_by_env(),
                 enable_quality_processing: enable_quality_processing.unwrap_or(true),
                 ocr: ocr.map(Into::into),
                 force_ocr: force_ocr.unwrap_or(false),
@@ -127,7 +153,29 @@ impl ExtractionConfig {
                 } else {
                     kreuzberg::core::config::formats::OutputFormat::Plain
                 },
+                output_mode: match output_mode {
+                    Some(mode) => mode.parse().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?,
+                    None => kreuzberg::OutputMode::Markdown,
+                },
+                max_content_chars,
+                preserve_list_markers: preserve_list_markers.unwrap_or(true),
+                extract_outline: extract_outline.unwrap_or(false),
+                extract_references: extract_references.unwrap_or(false),
+                normalize_bidi: normalize_bidi.unwrap_or(false),
+                html_include_alt_text: html_include_alt_text.unwrap_or(true),
+                emit_positions: emit_positions.unwrap_or(false),
+                emit_source_offsets: emit_source_offsets.unwrap_or(false),
+                low_memory: low_memory.unwrap_or(false),
+                normalize_newlines: normalize_newlines
+                    .map(|ending| ending.parse())
+                    .transpose()
+                    .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?,
                 security_limits: None,
+                xlsx_options: None,
+                docx_options: docx_options.map(|docx| docx.inner),
+                jupyter_options: jupyter_options.map(|jupyter| jupyter.inner),
+                archive_options: None,
+                table_options: None,
             },
             html_options_dict,
         })
@@ -140,7 +188,7 @@ impl ExtractionConfig {
 
     #[setter]
     fn set_use_cache(&mut self, value: bool) {
-        self.inner.use_cache = value;
+        self.inner.use_cache = value && !kreuzberg::cache::cache_disabled_by_env();
     }
 
     #[getter]
@@ -183,6 +231,96 @@ impl ExtractionConfig {
         self.inner.include_document_structure = value;
     }
 
+    #[getter]
+    fn max_content_chars(&self) -> Option<usize> {
+        self.inner.max_content_chars
+    }
+
+    #[setter]
+    fn set_max_content_chars(&mut self, value: Option<usize>) {
+        self.inner.max_content_chars = value;
+    }
+
+    #[getter]
+    fn preserve_list_markers(&self) -> bool {
+        self.inner.preserve_list_markers
+    }
+
+    #[setter]
+    fn set_preserve_list_markers(&mut self, value: bool) {
+        self.inner.preserve_list_markers = value;
+    }
+
+    #[getter]
+    fn extract_outline(&self) -> bool {
+        self.inner.extract_outline
+    }
+
+    #[setter]
+    fn set_extract_outline(&mut self, value: bool) {
+        self.inner.extract_outline = value;
+    }
+
+    #[getter]
+    fn extract_references(&self) -> bool {
+        self.inner.extract_references
+    }
+
+    #[setter]
+    fn set_extract_references(&mut self, value: bool) {
+        self.inner.extract_references = value;
+    }
+
+    #[getter]
+    fn normalize_bidi(&self) -> bool {
+        self.inner.normalize_bidi
+    }
+
+    #[setter]
+    fn set_normalize_bidi(&mut self, value: bool) {
+        self.inner.normalize_bidi = value;
+    }
+
+    #[getter]
+    fn html_include_alt_text(&self) -> bool {
+        self.inner.html_include_alt_text
+    }
+
+    #[setter]
+    fn set_html_include_alt_text(&mut self, value: bool) {
+        self.inner.html_include_alt_text = value;
+    }
+
+    #[getter]
+    fn emit_positions(&self) -> bool {
+        self.inner.emit_positions
+    }
+
+    #[setter]
+    fn set_emit_positions(&mut self, value: bool) {
+        self.inner.emit_positions = value;
+    }
+
+    #[getter]
+    fn emit_source_offsets(&self) -> bool {
+        self.inner.emit_source_offsets
+    }
+
+    #[setter]
+    fn set_emit_source_offsets(&mut self, value: bool) {
+        self.inner.emit_source_offsets = value;
+    }
+
+    #[getter]
+    fn low_memory(&self) -> bool {
+        self.inner.low_memory
+    }
+
+    #[setter]
+    fn set_low_memory(&mut self, value: bool) {
+        self.inner.low_memory = value;
+    }
+
     #[getter]
     fn chunking(&self) -> Option<ChunkingConfig> {
         self.inner.chunking.clone().map(Into::into)
@@ -326,6 +464,51 @@ impl ExtractionConfig {
         };
     }
 
+    #[getter]
+    fn output_mode(&self) -> String {
+        self.inner.output_mode.to_string()
+    }
+
+    #[setter]
+    fn set_output_mode(&mut self, value: String) -> PyResult<()> {
+        self.inner.output_mode = value.parse().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn normalize_newlines(&self) -> Option<String> {
+        self.inner.normalize_newlines.map(|ending| ending.to_string())
+    }
+
+    #[setter]
+    fn set_normalize_newlines(&mut self, value: Option<String>) -> PyResult<()> {
+        self.inner.normalize_newlines = value
+            .map(|ending| ending.parse())
+            .transpose()
+            .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn docx_options(&self) -> Option<DocxConfig> {
+        self.inner.docx_options.clone().map(|inner| DocxConfig { inner })
+    }
+
+    #[setter]
+    fn set_docx_options(&mut self, value: Option<DocxConfig>) {
+        self.inner.docx_options = value.map(|docx| docx.inner);
+    }
+
+    #[getter]
+    fn jupyter_options(&self) -> Option<JupyterConfig> {
+        self.inner.jupyter_options.clone().map(|inner| JupyterConfig { inner })
+    }
+
+    #[setter]
+    fn set_jupyter_options(&mut self, value: Option<JupyterConfig>) {
+        self.inner.jupyter_options = value.map(|jupyter| jupyter.inner);
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ExtractionConfig(use_cache={}, enable_quality_processing={}, ocr={}, force_ocr={})",
@@ -413,7 +596,7 @@ pub struct OcrConfig {
 #[pymethods]
 impl OcrConfig {
     #[new]
-    #[pyo3(signature = (backend=None, language=None, tesseract_config=None, paddle_ocr_config=None, element_config=None))]
+    #[pyo3(signature = (backend=None, language=None, tesseract_config=None, paddle_ocr_config=None, element_config=None, auto_language=false, fallback_backends=None, user_words=None, render_dpi=None, empty_page_confidence=None, page_filter=None, region=None))]
     fn new(
         py: Python<'_>,
         backend: Option<String>,
@@ -421,6 +604,13 @@ impl OcrConfig {
         tesseract_config: Option<TesseractConfig>,
         paddle_ocr_config: Option<Bound<'_, pyo3::types::PyAny>>,
         element_config: Option<Bound<'_, pyo3::types::PyAny>>,
+        auto_language: bool,
+        fallback_backends: Option<Vec<String>>,
+        user_words: Option<Vec<String>>,
+        render_dpi: Option<i32>,
+        empty_page_confidence: Option<f64>,
+        page_filter: Option<Vec<usize>>,
+        region: Option<(f64, f64, f64, f64)>,
     ) -> PyResult<Self> {
         let paddle_ocr_json = if let Some(obj) = paddle_ocr_config {
             let json_mod = py.import("json")?;
@@ -445,11 +635,19 @@ impl OcrConfig {
         Ok(Self {
             inner: kreuzberg::OcrConfig {
                 backend: backend.unwrap_or_else(|| "tesseract".to_string()),
+                fallback_backends: fallback_backends.unwrap_or_default(),
                 language: language.unwrap_or_else(|| "eng".to_string()),
                 tesseract_config: tesseract_config.map(Into::into),
                 output_format: None,
                 paddle_ocr_config: paddle_ocr_json,
                 element_config: element_cfg,
+                max_retries: 0,
+                auto_language,
+                user_words,
+                render_dpi,
+                empty_page_confidence,
+                page_filter,
+                region: region.map(rect_from_tuple),
             },
         })
     }
@@ -484,6 +682,76 @@ impl OcrConfig {
         self.inner.tesseract_config = value.map(Into::into);
     }
 
+    #[getter]
+    fn auto_language(&self) -> bool {
+        self.inner.auto_language
+    }
+
+    #[setter]
+    fn set_auto_language(&mut self, value: bool) {
+        self.inner.auto_language = value;
+    }
+
+    #[getter]
+    fn fallback_backends(&self) -> Vec<String> {
+        self.inner.fallback_backends.clone()
+    }
+
+    #[setter]
+    fn set_fallback_backends(&mut self, value: Vec<String>) {
+        self.inner.fallback_backends = value;
+    }
+
+    #[getter]
+    fn user_words(&self) -> Option<Vec<String>> {
+        self.inner.user_words.clone()
+    }
+
+    #[setter]
+    fn set_user_words(&mut self, value: Option<Vec<String>>) {
+        self.inner.user_words = value;
+    }
+
+    #[getter]
+    fn render_dpi(&self) -> Option<i32> {
+        self.inner.render_dpi
+    }
+
+    #[setter]
+    fn set_render_dpi(&mut self, value: Option<i32>) {
+        self.inner.render_dpi = value;
+    }
+
+    #[getter]
+    fn empty_page_confidence(&self) -> Option<f64> {
+        self.inner.empty_page_confidence
+    }
+
+    #[setter]
+    fn set_empty_page_confidence(&mut self, value: Option<f64>) {
+        self.inner.empty_page_confidence = value;
+    }
+
+    #[getter]
+    fn page_filter(&self) -> Option<Vec<usize>> {
+        self.inner.page_filter.clone()
+    }
+
+    #[setter]
+    fn set_page_filter(&mut self, value: Option<Vec<usize>>) {
+        self.inner.page_filter = value;
+    }
+
+    #[getter]
+    fn region(&self) -> Option<(f64, f64, f64, f64)> {
+        self.inner.region.map(|r| (r.left, r.top, r.width, r.height))
+    }
+
+    #[setter]
+    fn set_region(&mut self, value: Option<(f64, f64, f64, f64)>) {
+        self.inner.region = value.map(rect_from_tuple);
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "OcrConfig(backend='{}', language='{}', tesseract_config={})",
@@ -498,6 +766,16 @@ impl OcrConfig {
     }
 }
 
+/// Build a normalized region from a `(left, top, width, height)` tuple.
+fn rect_from_tuple((left, top, width, height): (f64, f64, f64, f64)) -> kreuzberg::core::config::Rect {
+    kreuzberg::core::config::Rect {
+        left,
+        top,
+        width,
+        height,
+    }
+}
+
 /// Embedding model type.
 ///
 /// Specifies which model to use for embedding generation.
@@ -646,6 +924,8 @@ impl EmbeddingConfig {
 ///     max_overlap (int): Overlap between chunks in characters (default: 200, must be < max_chars)
 ///     embedding (EmbeddingConfig | None): Embedding configuration (default: None)
 ///     preset (str | None): Chunking preset to use (default: None)
+///     overlap_unit (str): Unit of max_overlap, "characters" or "sentences" (default: "characters")
+///     max_chunks (int | None): Stop after this many chunks (default: None, no limit)
 ///
 /// Important:
 ///     The max_overlap must be less than max_chars, otherwise a validation error will be raised.
@@ -674,14 +954,16 @@ pub struct ChunkingConfig {
 #[pymethods]
 impl ChunkingConfig {
     #[new]
-    #[pyo3(signature = (max_chars=None, max_overlap=None, embedding=None, preset=None))]
+    #[pyo3(signature = (max_chars=None, max_overlap=None, embedding=None, preset=None, overlap_unit=None, max_chunks=None))]
     fn new(
         max_chars: Option<usize>,
         max_overlap: Option<usize>,
         embedding: Option<EmbeddingConfig>,
         preset: Option<String>,
-    ) -> Self {
-        Self {
+        overlap_unit: Option<String>,
+        max_chunks: Option<usize>,
+    ) -> PyResult<Self> {
+        Ok(Self {
             inner: kreuzberg::ChunkingConfig {
                 max_characters: max_chars.unwrap_or(1000),
                 overlap: max_overlap.unwrap_or(200),
@@ -689,8 +971,15 @@ impl ChunkingConfig {
                 chunker_type: kreuzberg::ChunkerType::Text,
                 embedding: embedding.map(Into::into),
                 preset,
+                overlap_unit: overlap_unit
+                    .as_deref()
+                    .map(parse_overlap_unit)
+                    .transpose()?
+                    .unwrap_or_default(),
+                max_chunks,
+                balance: false,
             },
-        }
+        })
     }
 
     #[getter]
@@ -733,6 +1022,30 @@ impl ChunkingConfig {
         self.inner.preset = value;
     }
 
+    #[getter]
+    fn overlap_unit(&self) -> &'static str {
+        match self.inner.overlap_unit {
+            kreuzberg::OverlapUnit::Characters => "characters",
+            kreuzberg::OverlapUnit::Sentences => "sentences",
+        }
+    }
+
+    #[setter]
+    fn set_overlap_unit(&mut self, value: String) -> PyResult<()> {
+        self.inner.overlap_unit = parse_overlap_unit(&value)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn max_chunks(&self) -> Option<usize> {
+        self.inner.max_chunks
+    }
+
+    #[setter]
+    fn set_max_chunks(&mut self, value: Option<usize>) {
+        self.inner.max_chunks = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ChunkingConfig(max_chars={}, max_overlap={}, embedding={}, preset={})",
@@ -748,6 +1061,17 @@ impl ChunkingConfig {
     }
 }
 
+fn parse_overlap_unit(value: &str) -> PyResult<kreuzberg::OverlapUnit> {
+    match value.to_lowercase().as_str() {
+        "characters" => Ok(kreuzberg::OverlapUnit::Characters),
+        "sentences" => Ok(kreuzberg::OverlapUnit::Sentences),
+        other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid overlap_unit: {}. Must be 'characters' or 'sentences'",
+            other
+        ))),
+    }
+}
+
 /// Image extraction configuration.
 ///
 /// Example:
@@ -766,28 +1090,53 @@ impl ImageExtractionConfig {
         extract_images=None,
         target_dpi=None,
         max_image_dimension=None,
+        max_decode_dimension=None,
         auto_adjust_dpi=None,
         min_dpi=None,
-        max_dpi=None
+        max_dpi=None,
+        max_images=None,
+        max_total_image_bytes=None,
+        min_width=None,
+        min_height=None,
+        output_format=None
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         extract_images: Option<bool>,
         target_dpi: Option<i32>,
         max_image_dimension: Option<i32>,
+        max_decode_dimension: Option<u32>,
         auto_adjust_dpi: Option<bool>,
         min_dpi: Option<i32>,
         max_dpi: Option<i32>,
-    ) -> Self {
-        Self {
+        max_images: Option<usize>,
+        max_total_image_bytes: Option<usize>,
+        min_width: Option<u32>,
+        min_height: Option<u32>,
+        output_format: Option<String>,
+    ) -> PyResult<Self> {
+        let output_format = match output_format {
+            Some(format) => format
+                .parse::<kreuzberg::ImageFormat>()
+                .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?,
+            None => kreuzberg::ImageFormat::Original,
+        };
+        Ok(Self {
             inner: kreuzberg::ImageExtractionConfig {
                 extract_images: extract_images.unwrap_or(true),
                 target_dpi: target_dpi.unwrap_or(300),
                 max_image_dimension: max_image_dimension.unwrap_or(4096),
+                max_decode_dimension: max_decode_dimension.unwrap_or(32768),
                 auto_adjust_dpi: auto_adjust_dpi.unwrap_or(true),
                 min_dpi: min_dpi.unwrap_or(72),
                 max_dpi: max_dpi.unwrap_or(600),
+                max_images,
+                max_total_image_bytes,
+                min_width: min_width.unwrap_or(16),
+                min_height: min_height.unwrap_or(16),
+                output_format,
             },
-        }
+        })
     }
 
     #[getter]
@@ -820,6 +1169,16 @@ impl ImageExtractionConfig {
         self.inner.max_image_dimension = value;
     }
 
+    #[getter]
+    fn max_decode_dimension(&self) -> u32 {
+        self.inner.max_decode_dimension
+    }
+
+    #[setter]
+    fn set_max_decode_dimension(&mut self, value: u32) {
+        self.inner.max_decode_dimension = value;
+    }
+
     #[getter]
     fn auto_adjust_dpi(&self) -> bool {
         self.inner.auto_adjust_dpi
@@ -850,6 +1209,57 @@ impl ImageExtractionConfig {
         self.inner.max_dpi = value;
     }
 
+    #[getter]
+    fn max_images(&self) -> Option<usize> {
+        self.inner.max_images
+    }
+
+    #[setter]
+    fn set_max_images(&mut self, value: Option<usize>) {
+        self.inner.max_images = value;
+    }
+
+    #[getter]
+    fn max_total_image_bytes(&self) -> Option<usize> {
+        self.inner.max_total_image_bytes
+    }
+
+    #[setter]
+    fn set_max_total_image_bytes(&mut self, value: Option<usize>) {
+        self.inner.max_total_image_bytes = value;
+    }
+
+    #[getter]
+    fn min_width(&self) -> u32 {
+        self.inner.min_width
+    }
+
+    #[setter]
+    fn set_min_width(&mut self, value: u32) {
+        self.inner.min_width = value;
+    }
+
+    #[getter]
+    fn min_height(&self) -> u32 {
+        self.inner.min_height
+    }
+
+    #[setter]
+    fn set_min_height(&mut self, value: u32) {
+        self.inner.min_height = value;
+    }
+
+    #[getter]
+    fn output_format(&self) -> String {
+        self.inner.output_format.to_string()
+    }
+
+    #[setter]
+    fn set_output_format(&mut self, value: String) -> PyResult<()> {
+        self.inner.output_format = value.parse().map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+        Ok(())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ImageExtractionConfig(extract_images={}, target_dpi={}, max_image_dimension={})",
@@ -872,21 +1282,36 @@ pub struct PdfConfig {
 #[pymethods]
 impl PdfConfig {
     #[new]
-    #[pyo3(signature = (extract_images=None, passwords=None, extract_metadata=None, hierarchy=None))]
+    #[pyo3(signature = (extract_images=None, passwords=None, extract_metadata=None, hierarchy=None, reading_order=None, allow_owner_locked=None, page_separator=None, extract_math=None, insert_image_markers=None))]
     fn new(
         extract_images: Option<bool>,
         passwords: Option<Vec<String>>,
         extract_metadata: Option<bool>,
         hierarchy: Option<HierarchyConfig>,
-    ) -> Self {
-        Self {
+        reading_order: Option<String>,
+        allow_owner_locked: Option<bool>,
+        page_separator: Option<String>,
+        extract_math: Option<bool>,
+        insert_image_markers: Option<bool>,
+    ) -> PyResult<Self> {
+        Ok(Self {
             inner: kreuzberg::PdfConfig {
                 extract_images: extract_images.unwrap_or(false),
                 passwords,
+                allow_owner_locked: allow_owner_locked.unwrap_or(true),
                 extract_metadata: extract_metadata.unwrap_or(true),
                 hierarchy: hierarchy.map(|h| h.inner),
+                reading_order: reading_order
+                    .as_deref()
+                    .map(parse_reading_order)
+                    .transpose()?
+                    .unwrap_or_default(),
+                page_range: None,
+                page_separator,
+                extract_math: extract_math.unwrap_or(false),
+                insert_image_markers: insert_image_markers.unwrap_or(false),
             },
-        }
+        })
     }
 
     #[getter]
@@ -909,6 +1334,16 @@ impl PdfConfig {
         self.inner.passwords = value;
     }
 
+    #[getter]
+    fn allow_owner_locked(&self) -> bool {
+        self.inner.allow_owner_locked
+    }
+
+    #[setter]
+    fn set_allow_owner_locked(&mut self, value: bool) {
+        self.inner.allow_owner_locked = value;
+    }
+
     #[getter]
     fn extract_metadata(&self) -> bool {
         self.inner.extract_metadata
@@ -929,6 +1364,50 @@ impl PdfConfig {
         self.inner.hierarchy = value.map(|h| h.inner);
     }
 
+    #[getter]
+    fn reading_order(&self) -> &'static str {
+        match self.inner.reading_order {
+            kreuzberg::ReadingOrder::Raw => "raw",
+            kreuzberg::ReadingOrder::ColumnAware => "column_aware",
+        }
+    }
+
+    #[setter]
+    fn set_reading_order(&mut self, value: String) -> PyResult<()> {
+        self.inner.reading_order = parse_reading_order(&value)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn page_separator(&self) -> Option<String> {
+        self.inner.page_separator.clone()
+    }
+
+    #[setter]
+    fn set_page_separator(&mut self, value: Option<String>) {
+        self.inner.page_separator = value;
+    }
+
+    #[getter]
+    fn extract_math(&self) -> bool {
+        self.inner.extract_math
+    }
+
+    #[setter]
+    fn set_extract_math(&mut self, value: bool) {
+        self.inner.extract_math = value;
+    }
+
+    #[getter]
+    fn insert_image_markers(&self) -> bool {
+        self.inner.insert_image_markers
+    }
+
+    #[setter]
+    fn set_insert_image_markers(&mut self, value: bool) {
+        self.inner.insert_image_markers = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "PdfConfig(extract_images={}, extract_metadata={}, passwords={})",
@@ -943,6 +1422,17 @@ impl PdfConfig {
     }
 }
 
+fn parse_reading_order(value: &str) -> PyResult<kreuzberg::ReadingOrder> {
+    match value.to_lowercase().as_str() {
+        "raw" => Ok(kreuzberg::ReadingOrder::Raw),
+        "column_aware" => Ok(kreuzberg::ReadingOrder::ColumnAware),
+        other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid reading_order: {}. Must be 'raw' or 'column_aware'",
+            other
+        ))),
+    }
+}
+
 /// Token reduction configuration.
 ///
 /// Example:
@@ -957,12 +1447,13 @@ pub struct TokenReductionConfig {
 #[pymethods]
 impl TokenReductionConfig {
     #[new]
-    #[pyo3(signature = (mode=None, preserve_important_words=None))]
-    fn new(mode: Option<String>, preserve_important_words: Option<bool>) -> Self {
+    #[pyo3(signature = (mode=None, preserve_important_words=None, language=None))]
+    fn new(mode: Option<String>, preserve_important_words: Option<bool>, language: Option<String>) -> Self {
         Self {
             inner: kreuzberg::TokenReductionConfig {
                 mode: mode.unwrap_or_else(|| "off".to_string()),
                 preserve_important_words: preserve_important_words.unwrap_or(true),
+                language,
             },
         }
     }
@@ -987,10 +1478,20 @@ impl TokenReductionConfig {
         self.inner.preserve_important_words = value;
     }
 
+    #[getter]
+    fn language(&self) -> Option<String> {
+        self.inner.language.clone()
+    }
+
+    #[setter]
+    fn set_language(&mut self, value: Option<String>) {
+        self.inner.language = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
-            "TokenReductionConfig(mode='{}', preserve_important_words={})",
-            self.inner.mode, self.inner.preserve_important_words
+            "TokenReductionConfig(mode='{}', preserve_important_words={}, language={:?})",
+            self.inner.mode, self.inner.preserve_important_words, self.inner.language
         )
     }
 }
@@ -1090,6 +1591,7 @@ impl PostProcessorConfig {
                 disabled_processors,
                 enabled_set,
                 disabled_set,
+                ..Default::default()
             },
         }
     }
@@ -1342,7 +1844,7 @@ impl TesseractConfig {
                 table_min_confidence: table_min_confidence.unwrap_or(0.0),
                 table_column_threshold: table_column_threshold.unwrap_or(50),
                 table_row_threshold_ratio: table_row_threshold_ratio.unwrap_or(0.5),
-                use_cache: use_cache.unwrap_or(true),
+                use_cache: use_cache.unwrap_or(true) && !kreuzberg::cache::cache_disabled_by_env(),
                 classify_use_pre_adapted_templates: classify_use_pre_adapted_templates.unwrap_or(true),
                 language_model_ngram_on: language_model_ngram_on.unwrap_or(false),
                 tessedit_dont_blkrej_good_wds: tessedit_dont_blkrej_good_wds.unwrap_or(true),
@@ -1464,7 +1966,7 @@ impl TesseractConfig {
 
     #[setter]
     fn set_use_cache(&mut self, value: bool) {
-        self.inner.use_cache = value;
+        self.inner.use_cache = value && !kreuzberg::cache::cache_disabled_by_env();
     }
 
     #[getter]
@@ -1640,6 +2142,103 @@ impl PageConfig {
     }
 }
 
+/// DOCX extraction configuration.
+///
+/// Example:
+///     >>> from kreuzberg import DocxConfig, ExtractionConfig
+///     >>> config = ExtractionConfig(docx_options=DocxConfig(estimate_pages=True))
+#[pyclass(name = "DocxConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct DocxConfig {
+    pub inner: kreuzberg::DocxConfig,
+}
+
+#[pymethods]
+impl DocxConfig {
+    #[new]
+    #[pyo3(signature = (estimate_pages=None))]
+    fn new(estimate_pages: Option<bool>) -> Self {
+        Self {
+            inner: kreuzberg::DocxConfig {
+                estimate_pages: estimate_pages.unwrap_or(false),
+            },
+        }
+    }
+
+    #[getter]
+    fn estimate_pages(&self) -> bool {
+        self.inner.estimate_pages
+    }
+
+    #[setter]
+    fn set_estimate_pages(&mut self, value: bool) {
+        self.inner.estimate_pages = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DocxConfig(estimate_pages={})", self.inner.estimate_pages)
+    }
+}
+
+/// Jupyter notebook extraction configuration.
+///
+/// Example:
+///     >>> from kreuzberg import ExtractionConfig, JupyterConfig
+///     >>> config = ExtractionConfig(jupyter_options=JupyterConfig(outputs="text"))
+#[pyclass(name = "JupyterConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct JupyterConfig {
+    pub inner: kreuzberg::JupyterConfig,
+}
+
+#[pymethods]
+impl JupyterConfig {
+    #[new]
+    #[pyo3(signature = (outputs=None))]
+    fn new(outputs: Option<String>) -> PyResult<Self> {
+        Ok(Self {
+            inner: kreuzberg::JupyterConfig {
+                outputs: outputs
+                    .as_deref()
+                    .map(parse_notebook_outputs)
+                    .transpose()?
+                    .unwrap_or_default(),
+            },
+        })
+    }
+
+    #[getter]
+    fn outputs(&self) -> &'static str {
+        match self.inner.outputs {
+            kreuzberg::NotebookOutputs::All => "all",
+            kreuzberg::NotebookOutputs::Text => "text",
+            kreuzberg::NotebookOutputs::None => "none",
+        }
+    }
+
+    #[setter]
+    fn set_outputs(&mut self, value: String) -> PyResult<()> {
+        self.inner.outputs = parse_notebook_outputs(&value)?;
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("JupyterConfig(outputs='{}')", self.outputs())
+    }
+}
+
+fn parse_notebook_outputs(value: &str) -> PyResult<kreuzberg::NotebookOutputs> {
+    match value.to_lowercase().as_str() {
+        "all" => Ok(kreuzberg::NotebookOutputs::All),
+        "text" => Ok(kreuzberg::NotebookOutputs::Text),
+        "none" => Ok(kreuzberg::NotebookOutputs::None),
+        other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid outputs: {}. Must be 'all', 'text' or 'none'",
+            other
+        ))),
+    }
+}
+
 /// Hierarchy extraction configuration.
 ///
 /// Controls document hierarchy detection based on font size clustering.
@@ -110,7 +110,7 @@ pub fn to_py_err(error: kreuzberg::KreuzbergError) -> PyErr {
         KreuzbergError::Validation { message, source } => {
             exception_from_module("ValidationError", format_error_with_source(message, source))
         }
-        KreuzbergError::UnsupportedFormat(msg) => exception_from_module("ValidationError", msg),
+        error @ KreuzbergError::UnsupportedFormat { .. } => exception_from_module("ValidationError", error.to_string()),
         KreuzbergError::Parsing { message, source } => {
             exception_from_module("ParsingError", format_error_with_source(message, source))
         }
@@ -315,7 +315,7 @@ mod tests {
     #[test]
     fn test_unsupported_format_error() {
         with_gil(|_py| {
-            let error = KreuzbergError::UnsupportedFormat("application/unknown".to_string());
+            let error = KreuzbergError::unsupported_format("application/unknown");
             let py_err = to_py_err(error);
 
             let err_msg = format!("{}", py_err);
@@ -69,6 +69,8 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<config::OcrConfig>()?;
     m.add_class::<config::PdfConfig>()?;
     m.add_class::<config::PageConfig>()?;
+    m.add_class::<config::DocxConfig>()?;
+    m.add_class::<config::JupyterConfig>()?;
     m.add_class::<config::ChunkingConfig>()?;
     m.add_class::<config::EmbeddingConfig>()?;
     m.add_class::<config::EmbeddingModelType>()?;
@@ -110,6 +112,10 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(plugins::unregister_validator, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::clear_validators, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::list_validators, m)?)?;
+    m.add_function(wrap_pyfunction!(plugins::register_mime_detector, m)?)?;
+    m.add_function(wrap_pyfunction!(plugins::unregister_mime_detector, m)?)?;
+    m.add_function(wrap_pyfunction!(plugins::clear_mime_detectors, m)?)?;
+    m.add_function(wrap_pyfunction!(plugins::list_mime_detectors, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::list_document_extractors, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::unregister_document_extractor, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::clear_document_extractors, m)?)?;
@@ -0,0 +1,185 @@
+//! Python MimeDetector wrapper bridge with GIL management.
+//!
+//! Provides a Rust wrapper that makes Python MIME detectors usable from Rust by
+//! implementing the `MimeDetector` trait and managing the FFI boundary with proper
+//! GIL handling.
+
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use std::sync::Arc;
+
+use kreuzberg::plugins::{MimeDetector, Plugin};
+use kreuzberg::{KreuzbergError, Result};
+
+use super::common::validate_plugin_object;
+
+/// Wrapper that makes a Python MIME detector usable from Rust.
+///
+/// This struct implements the Rust `MimeDetector` trait by forwarding calls
+/// to a Python object via PyO3.
+pub struct PythonMimeDetector {
+    /// Python object implementing the MimeDetector protocol
+    python_obj: Py<PyAny>,
+    /// Cached detector name (to avoid repeated GIL acquisition)
+    name: String,
+}
+
+impl PythonMimeDetector {
+    /// Create a new Python MIME detector wrapper.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Python object doesn't have required methods
+    /// - Method calls fail during initialization
+    pub fn new(py: Python<'_>, python_obj: Py<PyAny>) -> PyResult<Self> {
+        let obj = python_obj.bind(py);
+
+        validate_plugin_object(obj, "MimeDetector", &["name", "detect"])?;
+
+        let name: String = obj.call_method0("name")?.extract()?;
+        if name.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "MimeDetector name cannot be empty",
+            ));
+        }
+
+        Ok(Self { python_obj, name })
+    }
+}
+
+impl Plugin for PythonMimeDetector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> String {
+        Python::attach(|py| {
+            self.python_obj
+                .bind(py)
+                .getattr("version")
+                .and_then(|v| v.call0())
+                .and_then(|v| v.extract::<String>())
+                .unwrap_or_else(|_| "1.0.0".to_string())
+        })
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Python::attach(|py| {
+            let obj = self.python_obj.bind(py);
+            if obj.hasattr("initialize")? {
+                obj.call_method0("initialize")?;
+            }
+            Ok(())
+        })
+        .map_err(|e: PyErr| KreuzbergError::Plugin {
+            message: format!("Failed to initialize Python MimeDetector '{}': {}", self.name, e),
+            plugin_name: self.name.clone(),
+        })
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Python::attach(|py| {
+            let obj = self.python_obj.bind(py);
+            if obj.hasattr("shutdown")? {
+                obj.call_method0("shutdown")?;
+            }
+            Ok(())
+        })
+        .map_err(|e: PyErr| KreuzbergError::Plugin {
+            message: format!("Failed to shutdown Python MimeDetector '{}': {}", self.name, e),
+            plugin_name: self.name.clone(),
+        })
+    }
+}
+
+impl MimeDetector for PythonMimeDetector {
+    fn detect(&self, header: &[u8]) -> Option<String> {
+        // A failing detector is treated as not recognizing the document ~keep
+        Python::attach(|py| {
+            let obj = self.python_obj.bind(py);
+            obj.call_method1("detect", (PyBytes::new(py, header),))
+                .and_then(|v| v.extract::<Option<String>>())
+                .map_err(|e| {
+                    tracing::debug!("Python MimeDetector '{}' failed during detect: {}", self.name, e);
+                    e
+                })
+                .ok()
+                .flatten()
+        })
+    }
+}
+
+/// Register a Python MIME detector with the Rust core.
+///
+/// Registered detectors are asked for the MIME type of a document before the
+/// built-in detection. Pair a detector with a document extractor for the returned
+/// type to extract the format.
+///
+/// # Required Methods on Python MimeDetector
+///
+/// - `name() -> str` - Return detector name
+/// - `detect(header: bytes) -> str | None` - Return the MIME type, or None if not recognized
+///
+/// # Optional Methods
+///
+/// - `initialize()` - Called when detector is registered
+/// - `shutdown()` - Called when detector is unregistered
+/// - `version() -> str` - Detector version (defaults to "1.0.0")
+///
+/// # Example
+///
+/// ```python
+/// from kreuzberg import register_mime_detector
+///
+/// class LedgerDetector:
+///     def name(self) -> str:
+///         return "ledger-detector"
+///
+///     def detect(self, header: bytes) -> str | None:
+///         return "application/x-ledger" if header.startswith(b"LDGR\x01") else None
+///
+/// register_mime_detector(LedgerDetector())
+/// ```
+#[pyfunction]
+pub fn register_mime_detector(py: Python<'_>, detector: Py<PyAny>) -> PyResult<()> {
+    let rust_detector = PythonMimeDetector::new(py, detector)?;
+    let detector_name = rust_detector.name().to_string();
+
+    let arc_detector: Arc<dyn MimeDetector> = Arc::new(rust_detector);
+
+    py.detach(|| {
+        kreuzberg::plugins::register_mime_detector(arc_detector).map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!(
+                "Failed to register MimeDetector '{}': {}",
+                detector_name, e
+            ))
+        })
+    })
+}
+
+/// Unregister a MIME detector by name.
+#[pyfunction]
+pub fn unregister_mime_detector(py: Python<'_>, name: &str) -> PyResult<()> {
+    py.detach(|| {
+        kreuzberg::plugins::unregister_mime_detector(name).map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to unregister MimeDetector '{}': {}", name, e))
+        })
+    })
+}
+
+/// Clear all registered MIME detectors.
+#[pyfunction]
+pub fn clear_mime_detectors(py: Python<'_>) -> PyResult<()> {
+    py.detach(|| {
+        kreuzberg::plugins::clear_mime_detectors().map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to clear MimeDetector registry: {}", e))
+        })
+    })
+}
+
+/// List all registered MIME detector names.
+#[pyfunction]
+pub fn list_mime_detectors() -> PyResult<Vec<String>> {
+    kreuzberg::plugins::list_mime_detectors().map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+}
@@ -1,6 +1,6 @@
 //! Plugin registration functions for Python-Rust FFI bridge.
 //!
-//! Allows Python-based plugins (OCR backends, PostProcessors, Validators) to register
+//! Allows Python-based plugins (OCR backends, PostProcessors, Validators, MIME detectors) to register
 //! with the Rust core and be used by the Rust CLI, API server, and MCP server.
 //!
 //! # Architecture
@@ -9,6 +9,7 @@
 //! - **Python OCR backends** (EasyOCR, PaddleOCR, custom backends) to be used by Rust extraction
 //! - **Python PostProcessors** (entity extraction, keyword extraction, metadata enrichment) to enrich results
 //! - **Python Validators** (content validation, quality checks) to validate extraction results
+//! - **Python MIME detectors** (proprietary formats) to recognize documents before the built-in detection
 //!
 //! # GIL (Global Interpreter Lock) Management
 //!
@@ -116,12 +117,16 @@
 //!    - Solution: Always use `spawn_blocking` for Python calls from async code
 
 pub mod common;
+pub mod mime_detector_bridge;
 pub mod ocr_bridge;
 pub mod processor_bridge;
 pub mod validator_bridge;
 
 // Re-export public APIs for backward compatibility
 pub use common::json_value_to_py;
+pub use mime_detector_bridge::{
+    clear_mime_detectors, list_mime_detectors, register_mime_detector, unregister_mime_detector,
+};
 pub use ocr_bridge::register_ocr_backend;
 pub use processor_bridge::{
     clear_post_processors, list_post_processors, register_post_processor, unregister_post_processor,
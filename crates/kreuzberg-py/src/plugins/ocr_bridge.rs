@@ -388,6 +388,11 @@ fn dict_to_extraction_result(_py: Python<'_>, dict: &Bound<'_, PyAny>) -> Result
         document: None,
         djot_content: None,
         ocr_elements: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     })
 }
 
@@ -543,6 +548,9 @@ fn extract_tables(obj: &Bound<'_, PyAny>) -> Result<Vec<kreuzberg::types::Table>
             cells,
             markdown,
             page_number,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         });
     }
 
@@ -126,8 +126,9 @@ impl Plugin for PythonPostProcessor {
 
 #[async_trait]
 impl PostProcessor for PythonPostProcessor {
-    async fn process(&self, result: &mut ExtractionResult, _config: &ExtractionConfig) -> Result<()> {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
         let processor_name = self.name.clone();
+        let low_memory = config.low_memory;
 
         let updated_result = tokio::task::block_in_place(|| {
             Python::attach(|py| {
@@ -151,14 +152,23 @@ impl PostProcessor for PythonPostProcessor {
                     plugin_name: processor_name.clone(),
                 })?;
 
+                // Low-memory mode merges into the result directly instead of into a copy, so an
+                // invalid entry in the returned dict can leave the result partially updated.
+                if low_memory {
+                    merge_dict_to_extraction_result(py, &processed_dict, result)?;
+                    return Ok::<Option<ExtractionResult>, KreuzbergError>(None);
+                }
+
                 let mut updated_result = result.clone();
                 merge_dict_to_extraction_result(py, &processed_dict, &mut updated_result)?;
 
-                Ok::<ExtractionResult, KreuzbergError>(updated_result)
+                Ok(Some(updated_result))
             })
         })?;
 
-        *result = updated_result;
+        if let Some(updated_result) = updated_result {
+            *result = updated_result;
+        }
         Ok(())
     }
 
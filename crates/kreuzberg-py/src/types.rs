@@ -20,6 +20,11 @@ use crate::plugins::json_value_to_py;
 ///     tables (list[ExtractedTable]): Extracted tables
 ///     detected_languages (list[dict] | None): Detected languages with confidence scores
 ///     document (DocumentStructure | None): Hierarchical document structure if extraction enabled
+///     outline (list[OutlineNode] | None): Document outline if outline extraction enabled
+///     formulas (list[Formula] | None): Formulas recognized as LaTeX if math extraction enabled
+///     text_blocks (list[TextBlock] | None): Positioned text blocks if emit_positions enabled
+///     source_map (list[SourceSpan] | None): Content ranges mapped to source positions if emit_source_offsets enabled
+///     references (list[Reference] | None): Parsed bibliography entries if extract_references enabled
 ///
 /// Example:
 ///     >>> from kreuzberg import extract_file_sync, ExtractionConfig
@@ -55,6 +60,16 @@ pub struct ExtractionResult {
 
     document: Option<Py<PyAny>>,
 
+    outline: Option<Py<PyAny>>,
+
+    formulas: Option<Py<PyAny>>,
+
+    text_blocks: Option<Py<PyAny>>,
+
+    source_map: Option<Py<PyAny>>,
+
+    references: Option<Py<PyAny>>,
+
     #[pyo3(get)]
     pub output_format: Option<String>,
 
@@ -109,6 +124,31 @@ impl ExtractionResult {
         self.document.as_ref().map(|d| d.bind(py).clone())
     }
 
+    #[getter]
+    fn outline<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyAny>> {
+        self.outline.as_ref().map(|o| o.bind(py).clone())
+    }
+
+    #[getter]
+    fn formulas<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyAny>> {
+        self.formulas.as_ref().map(|f| f.bind(py).clone())
+    }
+
+    #[getter]
+    fn text_blocks<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyAny>> {
+        self.text_blocks.as_ref().map(|t| t.bind(py).clone())
+    }
+
+    #[getter]
+    fn source_map<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyAny>> {
+        self.source_map.as_ref().map(|s| s.bind(py).clone())
+    }
+
+    #[getter]
+    fn references<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyAny>> {
+        self.references.as_ref().map(|r| r.bind(py).clone())
+    }
+
     #[getter]
     fn djot_content<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyAny>> {
         self.djot_content.as_ref().map(|d| d.bind(py).clone())
@@ -371,6 +411,15 @@ impl ExtractionResult {
                 if let Some(desc) = &img.description {
                     img_dict.set_item("description", desc)?;
                 }
+                if let Some(alt_text) = &img.alt_text {
+                    img_dict.set_item("alt_text", alt_text)?;
+                }
+                if let Some(caption) = &img.caption {
+                    img_dict.set_item("caption", caption)?;
+                }
+                if let Some(position) = img.page_position {
+                    img_dict.set_item("page_position", position)?;
+                }
 
                 if let Some(ocr) = img.ocr_result {
                     let ocr_py = Self::from_rust(*ocr, py, output_format.clone(), result_format.clone())?;
@@ -406,6 +455,9 @@ impl ExtractionResult {
                 if let Some(last_page) = chunk.metadata.last_page {
                     chunk_metadata_dict.set_item("last_page", last_page)?;
                 }
+                if let Some(heading_path) = chunk.metadata.heading_path {
+                    chunk_metadata_dict.set_item("heading_path", heading_path)?;
+                }
 
                 let py_chunk = PyChunk {
                     content: chunk.content,
@@ -459,6 +511,15 @@ impl ExtractionResult {
                     if let Some(desc) = &img.description {
                         img_dict.set_item("description", desc)?;
                     }
+                    if let Some(alt_text) = &img.alt_text {
+                        img_dict.set_item("alt_text", alt_text)?;
+                    }
+                    if let Some(caption) = &img.caption {
+                        img_dict.set_item("caption", caption)?;
+                    }
+                    if let Some(position) = img.page_position {
+                        img_dict.set_item("page_position", position)?;
+                    }
                     page_images.append(img_dict)?;
                 }
                 page_dict.set_item("images", page_images)?;
@@ -541,6 +602,51 @@ impl ExtractionResult {
             None
         };
 
+        let outline = if let Some(outline) = result.outline {
+            let outline_json = serde_json::to_value(&outline).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize outline: {}", e))
+            })?;
+            Some(json_value_to_py(py, &outline_json)?.unbind())
+        } else {
+            None
+        };
+
+        let formulas = if let Some(formulas) = result.formulas {
+            let formulas_json = serde_json::to_value(&formulas).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize formulas: {}", e))
+            })?;
+            Some(json_value_to_py(py, &formulas_json)?.unbind())
+        } else {
+            None
+        };
+
+        let text_blocks = if let Some(text_blocks) = result.text_blocks {
+            let text_blocks_json = serde_json::to_value(&text_blocks).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize text blocks: {}", e))
+            })?;
+            Some(json_value_to_py(py, &text_blocks_json)?.unbind())
+        } else {
+            None
+        };
+
+        let source_map = if let Some(source_map) = result.source_map {
+            let source_map_json = serde_json::to_value(&source_map).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize source map: {}", e))
+            })?;
+            Some(json_value_to_py(py, &source_map_json)?.unbind())
+        } else {
+            None
+        };
+
+        let references = if let Some(references) = result.references {
+            let references_json = serde_json::to_value(&references).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to serialize references: {}", e))
+            })?;
+            Some(json_value_to_py(py, &references_json)?.unbind())
+        } else {
+            None
+        };
+
         let ocr_elements = if let Some(elems) = result.ocr_elements {
             let elem_list = PyList::empty(py);
             for elem in elems {
@@ -565,6 +671,11 @@ impl ExtractionResult {
             pages,
             elements,
             document,
+            outline,
+            formulas,
+            text_blocks,
+            source_map,
+            references,
             output_format,
             result_format,
             djot_content,
@@ -608,6 +719,11 @@ mod tests {
                 document: None,
                 djot_content: None,
                 ocr_elements: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             };
 
             let py_result =
@@ -641,6 +757,11 @@ mod tests {
                 document: None,
                 djot_content: None,
                 ocr_elements: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             };
             rust_result
                 .metadata
@@ -728,7 +849,9 @@ impl PyChunk {
 /// Attributes:
 ///     cells (list[list[str]]): Table data as nested lists (rows of columns)
 ///     markdown (str): Markdown representation of the table
-///     page_number (int): Page number where table was found
+///     page_number (int): Page number where table was found (sheet position for spreadsheets)
+///     name (str | None): Table name, e.g. the sheet name for spreadsheets
+///     has_header (bool): Whether the first row of ``cells`` is a header row
 ///
 /// Example:
 ///     >>> result = extract_file_sync("document.pdf", None, ExtractionConfig())
@@ -745,6 +868,12 @@ pub struct ExtractedTable {
 
     #[pyo3(get)]
     pub page_number: usize,
+
+    #[pyo3(get)]
+    pub name: Option<String>,
+
+    #[pyo3(get)]
+    pub has_header: bool,
 }
 
 #[pymethods]
@@ -792,6 +921,8 @@ impl ExtractedTable {
             cells: cells.unbind(),
             markdown: table.markdown,
             page_number: table.page_number,
+            name: table.name,
+            has_header: table.has_header,
         })
     }
 }
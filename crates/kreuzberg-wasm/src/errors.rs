@@ -53,9 +53,7 @@ pub fn convert_error(err: KreuzbergError) -> JsValue {
 
         KreuzbergError::LockPoisoned(msg) => ("LockPoisonedError", format!("Lock poisoned: {}", msg)),
 
-        KreuzbergError::UnsupportedFormat(format) => {
-            ("UnsupportedFormatError", format!("Unsupported format: {}", format))
-        }
+        error @ KreuzbergError::UnsupportedFormat { .. } => ("UnsupportedFormatError", error.to_string()),
 
         KreuzbergError::Other(msg) => ("Error", msg),
     };
@@ -188,7 +186,7 @@ mod tests {
 
     #[wasm_bindgen_test]
     fn test_convert_error_unsupported_format_returns_jsvalue() {
-        let err = KreuzbergError::UnsupportedFormat("application/x-custom".to_string());
+        let err = KreuzbergError::unsupported_format("application/x-custom");
         let result = convert_error(err);
 
         assert!(!result.is_null());
@@ -168,6 +168,11 @@ impl OcrBackend for JsOcrBackendWrapper {
             djot_content: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -208,6 +213,11 @@ impl OcrBackend for JsOcrBackendWrapper {
             djot_content: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -225,6 +235,11 @@ impl OcrBackend for JsOcrBackendWrapper {
             djot_content: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
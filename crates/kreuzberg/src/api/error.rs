@@ -114,7 +114,7 @@ impl ApiError {
             KreuzbergError::MissingDependency(_) => "MissingDependencyError",
             KreuzbergError::Plugin { .. } => "PluginError",
             KreuzbergError::LockPoisoned(_) => "LockPoisonedError",
-            KreuzbergError::UnsupportedFormat(_) => "UnsupportedFormatError",
+            KreuzbergError::UnsupportedFormat { .. } => "UnsupportedFormatError",
             KreuzbergError::Other(_) => "Error",
         };
 
@@ -1,14 +1,20 @@
 //! API request handlers.
 
-use axum::{Json, extract::State};
+use std::borrow::Cow;
 
-use crate::{batch_extract_bytes, cache, extract_bytes};
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
+
+use crate::{ExtractionResult, batch_extract_bytes, cache, extract_bytes};
 
 use super::{
     error::{ApiError, JsonApi, MultipartApi},
     types::{
         ApiState, CacheClearResponse, CacheStatsResponse, ChunkRequest, ChunkResponse, EmbedRequest, EmbedResponse,
-        ExtractResponse, HealthResponse, InfoResponse,
+        ExtractQuery, ExtractResponse, HealthResponse, InfoResponse, ReadinessCheck, ReadinessResponse,
     },
 };
 
@@ -40,6 +46,86 @@ pub async fn health_handler() -> Json<HealthResponse> {
     })
 }
 
+/// Readiness check endpoint handler.
+///
+/// GET /ready
+///
+/// Verifies the dependencies required by the server's default configuration:
+/// - `ocr_backend`: the configured OCR backend is registered (only when OCR is configured)
+/// - `cache_directory`: the cache directory can be created and written to (only when caching is enabled)
+///
+/// Returns 200 when every check passes and 503 otherwise.
+#[utoipa::path(
+    get,
+    path = "/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Service is ready", body = ReadinessResponse),
+        (status = 503, description = "Service is not ready", body = ReadinessResponse),
+    )
+)]
+#[cfg_attr(feature = "otel", tracing::instrument(name = "api.ready", skip(state)))]
+pub async fn ready_handler(State(state): State<ApiState>) -> (StatusCode, Json<ReadinessResponse>) {
+    let mut checks = Vec::new();
+
+    if let Some(ocr_config) = &state.default_config.ocr {
+        checks.push(check_ocr_backend(&ocr_config.backend));
+    }
+
+    if state.default_config.use_cache && !crate::cache::cache_disabled_by_env() {
+        checks.push(check_cache_directory());
+    }
+
+    let ready = checks.iter().all(|check| check.ok);
+    let (status_code, status) = if ready {
+        (StatusCode::OK, "ready")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "not_ready")
+    };
+
+    (
+        status_code,
+        Json(ReadinessResponse {
+            status: status.to_string(),
+            checks,
+        }),
+    )
+}
+
+fn check_ocr_backend(backend: &str) -> ReadinessCheck {
+    let registry = crate::plugins::registry::get_ocr_backend_registry();
+    let result = match registry.read() {
+        Ok(registry) => registry.get(backend).map(|_| ()).map_err(|e| e.to_string()),
+        Err(e) => Err(format!("OCR backend registry lock poisoned: {}", e)),
+    };
+
+    ReadinessCheck {
+        name: "ocr_backend".to_string(),
+        ok: result.is_ok(),
+        message: Some(result.map_or_else(|e| e, |_| format!("OCR backend '{}' is available", backend))),
+    }
+}
+
+fn check_cache_directory() -> ReadinessCheck {
+    let result = std::env::current_dir().and_then(|cwd| {
+        let cache_dir = cwd.join(".kreuzberg");
+        std::fs::create_dir_all(&cache_dir)?;
+        let probe = cache_dir.join(".ready-probe");
+        std::fs::write(&probe, b"")?;
+        std::fs::remove_file(&probe)?;
+        Ok(cache_dir)
+    });
+
+    ReadinessCheck {
+        name: "cache_directory".to_string(),
+        ok: result.is_ok(),
+        message: Some(match result {
+            Ok(cache_dir) => format!("Cache directory {} is writable", cache_dir.display()),
+            Err(e) => format!("Cache directory is not writable: {}", e),
+        }),
+    }
+}
+
 /// Server info endpoint handler.
 ///
 /// GET /info
@@ -69,6 +155,14 @@ pub async fn info_handler() -> Json<InfoResponse> {
 ///
 /// Returns a list of extraction results, one per file.
 ///
+/// # Partial Results
+///
+/// Failures that do not lose the whole document, such as a post-processor error or a
+/// single failed file in a multi-file request, still produce status 200. Each affected
+/// result lists what went wrong in `metadata.warnings`; failed files carry
+/// `metadata.error`. Only when every file fails is an error status returned. With the
+/// `partial_ok=false` query parameter any failure is answered with 422 instead.
+///
 /// # Size Limits
 ///
 /// Request body size limits are enforced at the router layer via `DefaultBodyLimit` and `RequestBodyLimitLayer`.
@@ -85,11 +179,13 @@ pub async fn info_handler() -> Json<InfoResponse> {
     post,
     path = "/extract",
     tag = "extraction",
+    params(ExtractQuery),
     request_body(content_type = "multipart/form-data"),
     responses(
-        (status = 200, description = "Extraction successful", body = ExtractResponse),
+        (status = 200, description = "Extraction successful, possibly with warnings", body = ExtractResponse),
         (status = 400, description = "Bad request", body = crate::api::types::ErrorResponse),
         (status = 413, description = "Payload too large", body = crate::api::types::ErrorResponse),
+        (status = 422, description = "Extraction failed, or partially failed with partial_ok=false", body = crate::api::types::ErrorResponse),
         (status = 500, description = "Internal server error", body = crate::api::types::ErrorResponse),
     )
 )]
@@ -97,12 +193,13 @@ pub async fn info_handler() -> Json<InfoResponse> {
     feature = "otel",
     tracing::instrument(
         name = "api.extract",
-        skip(state, multipart),
+        skip(state, query, multipart),
         fields(files_count = tracing::field::Empty)
     )
 )]
 pub async fn extract_handler(
     State(state): State<ApiState>,
+    Query(query): Query<ExtractQuery>,
     MultipartApi(mut multipart): MultipartApi,
 ) -> Result<Json<ExtractResponse>, ApiError> {
     let mut files = Vec::new();
@@ -178,19 +275,118 @@ pub async fn extract_handler(
     // Use provided config or fall back to default from state
     let final_config = config.as_ref().unwrap_or(&state.default_config);
 
-    if files.len() == 1 {
+    let results = if files.len() == 1 {
         let (data, mime_type, _file_name) = files
             .into_iter()
             .next()
             .expect("files.len() == 1 guarantees one element exists");
-        let result = extract_bytes(&data, mime_type.as_str(), final_config).await?;
-        return Ok(Json(vec![result]));
+        vec![extract_bytes(&data, mime_type.as_str(), final_config).await?]
+    } else {
+        let files_data: Vec<(Vec<u8>, String)> = files.into_iter().map(|(data, mime, _name)| (data, mime)).collect();
+        batch_extract_bytes(files_data, final_config).await?
+    };
+
+    finish_extraction(results, query.partial_ok.unwrap_or(true)).map(Json)
+}
+
+/// Attach warnings to partially successful results and decide whether the request failed.
+///
+/// Returns 422 when every file failed, or when anything failed and `partial_ok` is false.
+fn finish_extraction(mut results: ExtractResponse, partial_ok: bool) -> Result<ExtractResponse, ApiError> {
+    let mut failed_files = 0;
+    let mut problems = Vec::new();
+
+    for (index, result) in results.iter_mut().enumerate() {
+        if let Some(error) = &result.metadata.error {
+            failed_files += 1;
+            problems.push(format!("file {}: {}", index, error.message));
+            continue;
+        }
+
+        let warnings = extraction_warnings(result);
+        if !warnings.is_empty() {
+            problems.extend(warnings.iter().map(|warning| format!("file {}: {}", index, warning)));
+            result
+                .metadata
+                .additional
+                .insert(Cow::Borrowed("warnings"), serde_json::json!(warnings));
+        }
     }
 
-    let files_data: Vec<(Vec<u8>, String)> = files.into_iter().map(|(data, mime, _name)| (data, mime)).collect();
+    if failed_files == results.len() {
+        return Err(ApiError::unprocessable(crate::error::KreuzbergError::parsing(format!(
+            "All files failed to extract: {}",
+            problems.join("; ")
+        ))));
+    }
+
+    if !partial_ok && !problems.is_empty() {
+        return Err(ApiError::unprocessable(crate::error::KreuzbergError::parsing(format!(
+            "Extraction only partially succeeded: {}",
+            problems.join("; ")
+        ))));
+    }
 
-    let results = batch_extract_bytes(files_data, final_config).await?;
-    Ok(Json(results))
+    Ok(results)
+}
+
+/// Keys of `metadata.additional` under which extraction records non-fatal failures.
+///
+/// Each holds an error string, or a list of errors or failed page numbers.
+const WARNING_KEYS: &[&str] = &[
+    "archive_errors",
+    "chunking_error",
+    "embedding_error",
+    "formula_extraction_error",
+    "image_caption_errors",
+    "language_detection_error",
+    "ocr_failed_pages",
+    "outline_error",
+    "output_format_error",
+    "reference_extraction_error",
+    "source_map_error",
+    "text_blocks_error",
+    "token_reduction_error",
+];
+
+/// Prefix of the keys recording a failed post-processor, followed by its name.
+const PROCESSOR_ERROR_PREFIX: &str = "processing_error_";
+
+/// Non-fatal failures the extraction recorded in `metadata.additional`.
+///
+/// These are the [`WARNING_KEYS`] and the per-processor [`PROCESSOR_ERROR_PREFIX`]
+/// entries written when a page fails OCR, or a feature such as chunking, a
+/// post-processor or image captioning fails without failing the extraction.
+fn extraction_warnings(result: &ExtractionResult) -> Vec<String> {
+    let mut entries: Vec<(&str, &serde_json::Value)> = result
+        .metadata
+        .additional
+        .iter()
+        .map(|(key, value)| (key.as_ref(), value))
+        .filter(|(key, _)| WARNING_KEYS.contains(key) || key.starts_with(PROCESSOR_ERROR_PREFIX))
+        .collect();
+    entries.sort_unstable_by_key(|(key, _)| *key);
+
+    let mut warnings = Vec::new();
+    for (key, value) in entries {
+        match value {
+            serde_json::Value::String(message) => warnings.push(format!("{}: {}", key, message)),
+            serde_json::Value::Array(entries) => {
+                for entry in entries {
+                    let message = match entry {
+                        serde_json::Value::String(message) => message.clone(),
+                        other => other
+                            .get("error")
+                            .and_then(serde_json::Value::as_str)
+                            .map_or_else(|| other.to_string(), str::to_string),
+                    };
+                    warnings.push(format!("{}: {}", key, message));
+                }
+            }
+            other => warnings.push(format!("{}: {}", key, other)),
+        }
+    }
+    warnings
 }
 
 /// Cache stats endpoint handler.
@@ -364,6 +560,7 @@ pub async fn embed_handler(JsonApi(request): JsonApi<EmbedRequest>) -> Result<Js
                 total_chunks: request.texts.len(),
                 first_page: None,
                 last_page: None,
+                heading_path: None,
             },
         })
         .collect();
@@ -454,7 +651,7 @@ pub async fn embed_handler(JsonApi(_request): JsonApi<EmbedRequest>) -> Result<J
 )]
 pub async fn chunk_handler(JsonApi(request): JsonApi<ChunkRequest>) -> Result<Json<ChunkResponse>, ApiError> {
     use super::types::{ChunkItem, ChunkingConfigResponse};
-    use crate::chunking::{ChunkerType, ChunkingConfig, chunk_text};
+    use crate::chunking::{ChunkerType, ChunkingConfig, OverlapUnit, chunk_text};
 
     // Validate input
     if request.text.is_empty() {
@@ -495,6 +692,9 @@ pub async fn chunk_handler(JsonApi(request): JsonApi<ChunkRequest>) -> Result<Js
         chunker_type,
         embedding: None,
         preset: None,
+        overlap_unit: OverlapUnit::Characters,
+        max_chunks: None,
+        balance: false,
     };
 
     // Perform chunking - convert any remaining errors to validation errors since they're likely config issues
@@ -9,6 +9,7 @@
 //! - `POST /embed` - Generate embeddings for text (JSON body with texts array)
 //! - `POST /chunk` - Chunk text into smaller pieces (JSON body with text and config)
 //! - `GET /health` - Health check endpoint
+//! - `GET /ready` - Readiness check (OCR backend and cache directory)
 //! - `GET /info` - Server information
 //! - `GET /cache/stats` - Get cache statistics
 //! - `DELETE /cache/clear` - Clear all cached files
@@ -99,5 +100,6 @@ pub use router::{create_router, create_router_with_limits, create_router_with_li
 pub use startup::{serve, serve_default, serve_with_config, serve_with_config_and_limits, serve_with_server_config};
 pub use types::{
     ApiSizeLimits, ApiState, CacheClearResponse, CacheStatsResponse, ChunkRequest, ChunkResponse, EmbedRequest,
-    EmbedResponse, ErrorResponse, ExtractResponse, HealthResponse, InfoResponse,
+    EmbedResponse, ErrorResponse, ExtractQuery, ExtractResponse, HealthResponse, InfoResponse, ReadinessCheck,
+    ReadinessResponse,
 };
@@ -31,6 +31,7 @@ use utoipa::OpenApi;
     ),
     paths(
         crate::api::handlers::health_handler,
+        crate::api::handlers::ready_handler,
         crate::api::handlers::info_handler,
         crate::api::handlers::extract_handler,
         crate::api::handlers::cache_stats_handler,
@@ -42,6 +43,8 @@ use utoipa::OpenApi;
         schemas(
             crate::api::types::HealthResponse,
             crate::api::types::PluginStatus,
+            crate::api::types::ReadinessCheck,
+            crate::api::types::ReadinessResponse,
             crate::api::types::InfoResponse,
             crate::api::types::ErrorResponse,
             crate::api::types::CacheStatsResponse,
@@ -64,8 +67,11 @@ use utoipa::OpenApi;
             crate::types::extraction::BoundingBox,
             crate::types::metadata::Metadata,
             crate::types::tables::Table,
+            crate::types::tables::CellSpan,
             crate::types::page::PageContent,
             crate::types::djot::DjotContent,
+            crate::types::outline::OutlineNode,
+            crate::types::text_block::TextBlock,
         )
     ),
     tags(
@@ -100,6 +106,98 @@ pub fn openapi_json() -> String {
     r#"{"error": "API feature not enabled"}"#.to_string()
 }
 
+/// JSON Schema (draft 2020-12) describing a serialized [`ExtractionResult`].
+///
+/// Derived from the same type definitions as the OpenAPI document, so it stays in sync with
+/// the result type. The root references `#/$defs/ExtractionResult`; `$defs` holds that schema
+/// and every schema it refers to.
+///
+/// [`ExtractionResult`]: crate::types::ExtractionResult
+///
+/// # Examples
+///
+/// ```no_run
+/// let schema = kreuzberg::result_json_schema();
+/// println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+/// ```
+#[cfg(feature = "api")]
+pub fn result_json_schema() -> serde_json::Value {
+    const ROOT: &str = "ExtractionResult";
+
+    let mut schemas = ApiDoc::openapi()
+        .components
+        .and_then(|components| serde_json::to_value(components.schemas).ok())
+        .and_then(|value| match value {
+            serde_json::Value::Object(map) => Some(map),
+            _ => None,
+        })
+        .unwrap_or_default();
+    for schema in schemas.values_mut() {
+        rewrite_component_refs(schema);
+    }
+
+    // Keep only the schemas reachable from the root, dropping API request/response types.
+    let mut defs = serde_json::Map::new();
+    let mut pending = vec![ROOT.to_string()];
+    while let Some(name) = pending.pop() {
+        if defs.contains_key(&name) {
+            continue;
+        }
+        let Some(schema) = schemas.remove(&name) else {
+            continue;
+        };
+        collect_def_refs(&schema, &mut pending);
+        defs.insert(name, schema);
+    }
+
+    serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": ROOT,
+        "$ref": format!("#/$defs/{ROOT}"),
+        "$defs": defs,
+    })
+}
+
+/// Point OpenAPI component references (`#/components/schemas/X`) at `#/$defs/X`.
+#[cfg(feature = "api")]
+fn rewrite_component_refs(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                if key == "$ref"
+                    && let Some(name) = child.as_str().and_then(|r| r.strip_prefix("#/components/schemas/"))
+                {
+                    *child = serde_json::Value::String(format!("#/$defs/{name}"));
+                } else {
+                    rewrite_component_refs(child);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(rewrite_component_refs),
+        _ => {}
+    }
+}
+
+/// Push the names of all `#/$defs/` schemas referenced from `value`.
+#[cfg(feature = "api")]
+fn collect_def_refs(value: &serde_json::Value, names: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map {
+                if key == "$ref"
+                    && let Some(name) = child.as_str().and_then(|r| r.strip_prefix("#/$defs/"))
+                {
+                    names.push(name.to_string());
+                } else {
+                    collect_def_refs(child, names);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter().for_each(|item| collect_def_refs(item, names)),
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "api")]
@@ -131,6 +229,7 @@ mod tests {
         // Health endpoints
         assert!(schema.contains("/health"));
         assert!(schema.contains("/info"));
+        assert!(schema.contains("/ready"));
         // Extraction
         assert!(schema.contains("/extract"));
         // Cache
@@ -151,4 +250,86 @@ mod tests {
         assert!(schema.contains("EmbedRequest"));
         assert!(schema.contains("ChunkRequest"));
     }
+
+    #[test]
+    #[cfg(feature = "api")]
+    fn test_result_json_schema_describes_serialized_result() {
+        use crate::types::{BoundingBox, Chunk, ChunkMetadata, ExtractionResult, Metadata, Table, TextBlock};
+        use std::borrow::Cow;
+
+        let result = ExtractionResult {
+            content: "Hello".to_string(),
+            mime_type: Cow::Borrowed("application/pdf"),
+            metadata: Metadata::default(),
+            tables: vec![Table {
+                cells: vec![vec!["a".to_string()]],
+                markdown: "| a |".to_string(),
+                page_number: 1,
+                name: None,
+                has_header: false,
+                cell_spans: Vec::new(),
+            }],
+            detected_languages: Some(vec!["eng".to_string()]),
+            chunks: Some(vec![Chunk {
+                content: "Hello".to_string(),
+                embedding: None,
+                metadata: ChunkMetadata {
+                    byte_start: 0,
+                    byte_end: 5,
+                    token_count: None,
+                    chunk_index: 0,
+                    total_chunks: 1,
+                    first_page: None,
+                    last_page: None,
+                    heading_path: None,
+                },
+            }]),
+            images: None,
+            pages: None,
+            elements: None,
+            djot_content: None,
+            ocr_elements: None,
+            document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: Some(vec![TextBlock {
+                text: "Hello".to_string(),
+                page: 1,
+                bbox: BoundingBox {
+                    x0: 0.0,
+                    y0: 0.0,
+                    x1: 10.0,
+                    y1: 10.0,
+                },
+            }]),
+            source_map: None,
+            references: None,
+        };
+        let serialized = serde_json::to_value(&result).expect("serialize result");
+
+        let schema = result_json_schema();
+        assert_eq!(schema["$ref"], "#/$defs/ExtractionResult");
+        let defs = schema["$defs"].as_object().expect("$defs object");
+        assert!(!defs.contains_key("HealthResponse"), "API-only schemas must be pruned");
+
+        let root = &defs["ExtractionResult"];
+        let properties = root["properties"].as_object().expect("properties object");
+        for key in serialized.as_object().expect("result object").keys() {
+            assert!(properties.contains_key(key), "schema is missing result field '{key}'");
+        }
+        for required in root["required"].as_array().expect("required array") {
+            let required = required.as_str().expect("required field name");
+            assert!(
+                serialized.get(required).is_some(),
+                "result is missing required field '{required}'"
+            );
+        }
+
+        let mut refs = Vec::new();
+        collect_def_refs(&schema["$defs"], &mut refs);
+        for name in refs {
+            assert!(defs.contains_key(&name), "dangling schema reference '{name}'");
+        }
+        assert!(!schema.to_string().contains("#/components/schemas/"));
+    }
 }
@@ -18,7 +18,7 @@ use crate::{ExtractionConfig, core::ServerConfig};
 use super::{
     handlers::{
         cache_clear_handler, cache_stats_handler, chunk_handler, embed_handler, extract_handler, health_handler,
-        info_handler,
+        info_handler, ready_handler,
     },
     types::{ApiSizeLimits, ApiState},
 };
@@ -158,6 +158,7 @@ pub fn create_router_with_limits_and_server_config(
         .route("/embed", post(embed_handler))
         .route("/chunk", post(chunk_handler))
         .route("/health", get(health_handler))
+        .route("/ready", get(ready_handler))
         .route("/info", get(info_handler))
         .route("/cache/stats", get(cache_stats_handler))
         .route("/cache/clear", delete(cache_clear_handler));
@@ -138,6 +138,31 @@ pub struct HealthResponse {
     pub plugins: Option<PluginStatus>,
 }
 
+/// Result of a single readiness check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct ReadinessCheck {
+    /// Check name
+    #[cfg_attr(feature = "api", schema(example = "cache_directory"))]
+    pub name: String,
+    /// Whether the check passed
+    pub ok: bool,
+    /// Details about the check outcome
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Readiness check response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct ReadinessResponse {
+    /// Readiness status ("ready" or "not_ready")
+    #[cfg_attr(feature = "api", schema(example = "ready"))]
+    pub status: String,
+    /// Individual dependency checks
+    pub checks: Vec<ReadinessCheck>,
+}
+
 /// Server information response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
@@ -152,6 +177,20 @@ pub struct InfoResponse {
 /// Extraction response (list of results).
 pub type ExtractResponse = Vec<ExtractionResult>;
 
+/// Query parameters for the extract endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "api", into_params(parameter_in = Query))]
+pub struct ExtractQuery {
+    /// Accept partially successful extractions (default: true).
+    ///
+    /// When true, results whose extraction partly failed are returned with status 200
+    /// and a `warnings` array in their metadata. When false, any failure is answered
+    /// with status 422.
+    #[serde(default)]
+    pub partial_ok: Option<bool>,
+}
+
 /// Error response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
@@ -0,0 +1,167 @@
+//! Compile-time build information.
+//!
+//! Most of Kreuzberg's formats and integrations sit behind Cargo features, so a missing
+//! extractor usually means the feature was not compiled in. [`build_info`] reports what
+//! the running build actually contains.
+
+use serde::Serialize;
+
+/// Cargo features that change what a build can do, paired with whether they are enabled.
+const FEATURES: &[(&str, bool)] = &[
+    ("tokio-runtime", cfg!(feature = "tokio-runtime")),
+    ("simd-utf8", cfg!(feature = "simd-utf8")),
+    ("pdf", cfg!(feature = "pdf")),
+    ("bundled-pdfium", cfg!(feature = "bundled-pdfium")),
+    ("static-pdfium", cfg!(feature = "static-pdfium")),
+    ("system-pdfium", cfg!(feature = "system-pdfium")),
+    ("einvoice", cfg!(feature = "einvoice")),
+    ("excel", cfg!(feature = "excel")),
+    ("office", cfg!(feature = "office")),
+    ("email", cfg!(feature = "email")),
+    ("html", cfg!(feature = "html")),
+    ("xml", cfg!(feature = "xml")),
+    ("archives", cfg!(feature = "archives")),
+    ("ocr", cfg!(feature = "ocr")),
+    ("paddle-ocr", cfg!(feature = "paddle-ocr")),
+    ("math-ocr", cfg!(feature = "math-ocr")),
+    ("references", cfg!(feature = "references")),
+    ("language-detection", cfg!(feature = "language-detection")),
+    ("chunking", cfg!(feature = "chunking")),
+    ("embeddings", cfg!(feature = "embeddings")),
+    ("tokenize", cfg!(feature = "tokenize")),
+    ("stopwords", cfg!(feature = "stopwords")),
+    ("quality", cfg!(feature = "quality")),
+    ("keywords-yake", cfg!(feature = "keywords-yake")),
+    ("keywords-rake", cfg!(feature = "keywords-rake")),
+    ("api", cfg!(feature = "api")),
+    ("mcp", cfg!(feature = "mcp")),
+    ("mcp-http", cfg!(feature = "mcp-http")),
+    ("otel", cfg!(feature = "otel")),
+    ("pool-metrics", cfg!(feature = "pool-metrics")),
+    ("profiling", cfg!(feature = "profiling")),
+];
+
+/// Built-in extractors, paired with whether their feature is enabled.
+///
+/// Mirrors [`register_default_extractors`](crate::extractors::register_default_extractors).
+const EXTRACTORS: &[(&str, bool)] = &[
+    ("plain-text-extractor", true),
+    ("markdown-extractor", true),
+    ("structured-extractor", true),
+    ("csv-extractor", true),
+    ("dxf-extractor", true),
+    ("djot-extractor", true),
+    ("image-extractor", cfg!(feature = "ocr")),
+    ("xml-extractor", cfg!(feature = "xml")),
+    ("svg-extractor", cfg!(feature = "xml")),
+    ("jats-extractor", cfg!(feature = "xml")),
+    ("docbook-extractor", cfg!(feature = "xml")),
+    ("pdf-extractor", cfg!(feature = "pdf")),
+    ("excel-extractor", cfg!(feature = "excel")),
+    ("bibtex-extractor", cfg!(feature = "office")),
+    ("citation-extractor", cfg!(feature = "office")),
+    ("epub-extractor", cfg!(feature = "office")),
+    ("fictionbook-extractor", cfg!(feature = "office")),
+    ("rtf-extractor", cfg!(feature = "office")),
+    ("rst-extractor", cfg!(feature = "office")),
+    ("latex-extractor", cfg!(feature = "office")),
+    ("jupyter-extractor", cfg!(feature = "office")),
+    ("orgmode-extractor", cfg!(feature = "office")),
+    ("opml-extractor", cfg!(feature = "office")),
+    ("typst-extractor", cfg!(feature = "office")),
+    ("doc-extractor", cfg!(feature = "office")),
+    ("docx-extractor", cfg!(feature = "office")),
+    ("ppt-extractor", cfg!(feature = "office")),
+    ("pptx-extractor", cfg!(feature = "office")),
+    ("odt-extractor", cfg!(feature = "office")),
+    ("iwork-extractor", cfg!(feature = "office")),
+    ("email-extractor", cfg!(feature = "email")),
+    ("html-extractor", cfg!(feature = "html")),
+    ("zip-extractor", cfg!(feature = "archives")),
+    ("tar-extractor", cfg!(feature = "archives")),
+    ("7z-extractor", cfg!(feature = "archives")),
+    ("gzip-extractor", cfg!(feature = "archives")),
+];
+
+/// Built-in OCR backends, paired with whether their feature is enabled.
+const OCR_BACKENDS: &[(&str, bool)] = &[
+    ("tesseract", cfg!(feature = "ocr")),
+    ("paddle-ocr", cfg!(feature = "paddle-ocr")),
+];
+
+/// What the running build of Kreuzberg was compiled with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    /// Crate version.
+    pub version: &'static str,
+    /// Enabled Cargo features.
+    pub features: Vec<&'static str>,
+    /// Names of the built-in document extractors compiled in.
+    pub extractors: Vec<&'static str>,
+    /// Names of the built-in OCR backends compiled in.
+    pub ocr_backends: Vec<&'static str>,
+}
+
+impl BuildInfo {
+    /// Whether the named Cargo feature was enabled at compile time.
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.contains(&feature)
+    }
+}
+
+/// Report the version, enabled features, extractors and OCR backends of this build.
+///
+/// Everything is decided at compile time; the call only filters a few static tables.
+/// Plugins registered at runtime are not included.
+///
+/// # Example
+///
+/// ```rust
+/// let info = kreuzberg::build_info();
+/// println!("kreuzberg {} ({})", info.version, info.features.join(", "));
+/// if !info.has_feature("pdf") {
+///     eprintln!("PDF support is not compiled in");
+/// }
+/// ```
+pub fn build_info() -> BuildInfo {
+    fn enabled(table: &[(&'static str, bool)]) -> Vec<&'static str> {
+        table.iter().filter(|(_, on)| *on).map(|(name, _)| *name).collect()
+    }
+
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        features: enabled(FEATURES),
+        extractors: enabled(EXTRACTORS),
+        ocr_backends: enabled(OCR_BACKENDS),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_info_reflects_compiled_features() {
+        let info = build_info();
+
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.has_feature("pdf"), cfg!(feature = "pdf"));
+        assert_eq!(info.extractors.contains(&"pdf-extractor"), cfg!(feature = "pdf"));
+        assert_eq!(info.ocr_backends.contains(&"tesseract"), cfg!(feature = "ocr"));
+        assert!(info.extractors.contains(&"plain-text-extractor"));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_build_info_extractors_match_registry() {
+        crate::extractors::ensure_initialized().unwrap();
+        let registered = crate::plugins::registry::get_document_extractor_registry()
+            .read()
+            .unwrap()
+            .list();
+
+        for name in build_info().extractors {
+            assert!(registered.iter().any(|r| r == name), "{name} is not registered");
+        }
+    }
+}
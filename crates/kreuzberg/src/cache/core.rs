@@ -257,8 +257,25 @@ impl GenericCache {
     pub fn set(&self, cache_key: &str, data: Vec<u8>, source_file: Option<&str>) -> Result<()> {
         let cache_path = self.get_cache_path(cache_key);
 
-        fs::write(&cache_path, &data)
+        // Write to a unique temp file and rename so concurrent readers never see a partial entry ~keep
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let temp_path = self.cache_dir.join(format!(
+            "{}.tmp.{}.{:?}.{}",
+            cache_key,
+            std::process::id(),
+            std::thread::current().id(),
+            timestamp
+        ));
+
+        fs::write(&temp_path, &data)
             .map_err(|e| KreuzbergError::cache(format!("Failed to write cache file: {}", e)))?;
+        fs::rename(&temp_path, &cache_path).map_err(|e| {
+            let _ = fs::remove_file(&temp_path);
+            KreuzbergError::cache(format!("Failed to rename cache file: {}", e))
+        })?;
 
         self.save_metadata(cache_key, source_file);
 
@@ -6,6 +6,8 @@
 mod cleanup;
 mod core;
 mod utilities;
+#[cfg(feature = "tokio-runtime")]
+mod warm;
 
 // Re-export all public types and functions for backward compatibility
 pub use cleanup::{
@@ -13,9 +15,16 @@ pub use cleanup::{
 };
 pub use core::{CacheStats, GenericCache};
 pub use utilities::{
-    fast_hash, filter_old_cache_entries, generate_cache_key, get_available_disk_space, sort_cache_by_access_time,
-    validate_cache_key,
+    NO_CACHE_ENV_VAR, cache_disabled_by_env, fast_hash, filter_old_cache_entries, generate_cache_key,
+    get_available_disk_space, sort_cache_by_access_time, validate_cache_key,
 };
+#[cfg(feature = "tokio-runtime")]
+pub use warm::{
+    CACHE_KEY_IGNORED_FIELDS, EXTRACTION_CACHE_TYPE, WarmError, WarmReport, extraction_cache, extraction_cache_key,
+    extraction_config_hash, get_cached_extraction, warm, warm_with_cache,
+};
+#[cfg(feature = "tokio-runtime")]
+pub(crate) use warm::{lookup_warmed_bytes, lookup_warmed_file};
 
 #[cfg(test)]
 mod tests {
@@ -46,6 +55,19 @@ mod tests {
         assert!(!validate_cache_key("0123456789abcdef0123456789abcdef0"));
     }
 
+    #[test]
+    fn test_no_cache_requested() {
+        use utilities::no_cache_requested;
+
+        assert!(no_cache_requested(Some("1")));
+        assert!(no_cache_requested(Some("true")));
+        assert!(no_cache_requested(Some("yes")));
+        assert!(!no_cache_requested(None));
+        assert!(!no_cache_requested(Some("")));
+        assert!(!no_cache_requested(Some("0")));
+        assert!(!no_cache_requested(Some("False")));
+    }
+
     #[test]
     fn test_fast_hash() {
         let data1 = b"test data";
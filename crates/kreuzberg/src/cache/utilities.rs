@@ -3,12 +3,33 @@
 use crate::error::Result;
 use ahash::AHasher;
 use std::hash::{Hash, Hasher};
+use std::sync::LazyLock;
 
 #[cfg(unix)]
 use crate::error::KreuzbergError;
 #[cfg(unix)]
 use std::path::Path;
 
+/// Environment variable that disables every cache regardless of configuration.
+pub const NO_CACHE_ENV_VAR: &str = "KREUZBERG_NO_CACHE";
+
+static CACHE_DISABLED_BY_ENV: LazyLock<bool> =
+    LazyLock::new(|| no_cache_requested(std::env::var(NO_CACHE_ENV_VAR).ok().as_deref()));
+
+/// Whether `KREUZBERG_NO_CACHE` disables caching for this process.
+///
+/// The variable is read once, on first use. Any non-empty value other than `0` or
+/// `false` (case-insensitive) turns caching off, overriding `use_cache` in every config.
+pub fn cache_disabled_by_env() -> bool {
+    *CACHE_DISABLED_BY_ENV
+}
+
+pub(crate) fn no_cache_requested(value: Option<&str>) -> bool {
+    value
+        .map(str::trim)
+        .is_some_and(|value| !value.is_empty() && value != "0" && !value.eq_ignore_ascii_case("false"))
+}
+
 /// Cache key hash format width (32 hex digits for u64 hash)
 const CACHE_KEY_HASH_WIDTH: usize = 32;
 
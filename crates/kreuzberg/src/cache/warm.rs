@@ -0,0 +1,595 @@
+//! Incremental cache warming for extraction results.
+//!
+//! Warming extracts a set of files ahead of time and stores the serialized results in
+//! the `extraction` cache. `extract_file` and `extract_bytes` look results up there before
+//! running an extractor, so warmed documents are served without being extracted again.
+//! Entries are keyed by a hash of the file content, its MIME type, the config fields
+//! that affect the result (see [`extraction_config_hash`]) and the registered plugins;
+//! files whose entry already exists are skipped. Files are also indexed by their path,
+//! modification time and size, so looking up an unchanged file doesn't read and hash it.
+//!
+//! Both warming and lookups are skipped when `use_cache` is off or `KREUZBERG_NO_CACHE`
+//! is set.
+//!
+//! Entries are written atomically (see [`GenericCache::set`]), so warming is safe to run
+//! concurrently with live extraction or another warm against the same cache directory.
+//! The worst case under contention is the same file being extracted twice.
+
+use crate::core::config::ExtractionConfig;
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use super::core::GenericCache;
+use super::utilities::{cache_disabled_by_env, fast_hash, generate_cache_key};
+
+/// Cache type (subdirectory) used for extraction results.
+pub const EXTRACTION_CACHE_TYPE: &str = "extraction";
+
+const DEFAULT_MAX_AGE_DAYS: f64 = 30.0;
+const DEFAULT_MAX_CACHE_SIZE_MB: f64 = 500.0;
+const DEFAULT_MIN_FREE_SPACE_MB: f64 = 1000.0;
+
+/// Config fields, as dotted paths into the serialized `ExtractionConfig`, that do not
+/// affect the extraction result and are left out of the cache key.
+pub const CACHE_KEY_IGNORED_FIELDS: &[&str] = &[
+    "use_cache",
+    "max_concurrent_extractions",
+    "low_memory",
+    "ocr.tesseract_config.use_cache",
+];
+
+/// Outcome of a [`warm`] run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WarmReport {
+    /// Files whose result was already cached and were skipped.
+    pub hits: usize,
+    /// Files that were extracted and added to the cache.
+    pub misses: usize,
+    /// Files that could not be read, extracted, or cached.
+    pub errors: Vec<WarmError>,
+}
+
+/// A file that failed to warm.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WarmError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+/// Whether extraction results may be read from or written to the cache for `config`.
+fn caching_enabled(config: &ExtractionConfig) -> bool {
+    config.use_cache && !cache_disabled_by_env()
+}
+
+/// Open the extraction result cache in the default location (`./.kreuzberg/extraction`).
+pub fn extraction_cache() -> Result<GenericCache> {
+    GenericCache::new(
+        EXTRACTION_CACHE_TYPE.to_string(),
+        None,
+        DEFAULT_MAX_AGE_DAYS,
+        DEFAULT_MAX_CACHE_SIZE_MB,
+        DEFAULT_MIN_FREE_SPACE_MB,
+    )
+}
+
+/// Hash the fields of `config` that affect the extraction result.
+///
+/// Every serialized `ExtractionConfig` field participates, including nested sections
+/// such as `ocr`, `chunking` and `pdf_options`, except the fields in
+/// [`CACHE_KEY_IGNORED_FIELDS`], which only control caching, concurrency and memory use.
+/// Fields left at their default hash the same whether they were set explicitly or not.
+pub fn extraction_config_hash(config: &ExtractionConfig) -> Result<String> {
+    let mut config_json = serde_json::to_value(config)?;
+    for path in CACHE_KEY_IGNORED_FIELDS {
+        remove_field(&mut config_json, path);
+    }
+    Ok(format!("{:016x}", fast_hash(&serde_json::to_vec(&config_json)?)))
+}
+
+/// Remove the field at dotted `path` from `value`, if present.
+fn remove_field(value: &mut serde_json::Value, path: &str) {
+    let (parents, field) = path.rsplit_once('.').map_or((None, path), |(p, f)| (Some(p), f));
+    let parent = match parents {
+        Some(parents) => parents.split('.').try_fold(value, |value, key| value.get_mut(key)),
+        None => Some(value),
+    };
+    if let Some(serde_json::Value::Object(map)) = parent {
+        map.remove(field);
+    }
+}
+
+/// Compute the extraction cache key for `content` of `mime_type` extracted with `config`.
+///
+/// Combines a hash of the content with the MIME type, [`extraction_config_hash`] and a
+/// fingerprint of the registered plugins, so the same file extracted with configs or
+/// plugins that produce different results gets different entries.
+pub fn extraction_cache_key(content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<String> {
+    let content_hash = format!("{:016x}", fast_hash(content));
+    let config_hash = extraction_config_hash(config)?;
+    let plugins = plugin_fingerprint();
+    Ok(generate_cache_key(&[
+        ("content", content_hash.as_str()),
+        ("mime_type", mime_type),
+        ("config", config_hash.as_str()),
+        ("plugins", plugins.as_str()),
+    ]))
+}
+
+/// Hash the crate version and the names of the registered plugins.
+///
+/// Built-in extractors and post-processors are registered first, so the fingerprint
+/// is the same before and after the first extraction.
+fn plugin_fingerprint() -> String {
+    use crate::plugins::registry::{
+        get_document_extractor_registry, get_ocr_backend_registry, get_post_processor_registry, get_validator_registry,
+    };
+
+    let _ = crate::extractors::ensure_initialized();
+    crate::core::pipeline::initialize_features();
+
+    let mut names = vec![format!("kreuzberg:{}", env!("CARGO_PKG_VERSION"))];
+    if let Ok(registry) = get_document_extractor_registry().read() {
+        names.extend(registry.list().into_iter().map(|name| format!("extractor:{name}")));
+    }
+    if let Ok(registry) = get_post_processor_registry().read() {
+        names.extend(registry.list().into_iter().map(|name| format!("post_processor:{name}")));
+    }
+    if let Ok(registry) = get_validator_registry().read() {
+        names.extend(registry.list().into_iter().map(|name| format!("validator:{name}")));
+    }
+    if let Ok(registry) = get_ocr_backend_registry().read() {
+        names.extend(registry.list().into_iter().map(|name| format!("ocr_backend:{name}")));
+    }
+    names.sort();
+    format!("{:016x}", fast_hash(names.join("\n").as_bytes()))
+}
+
+/// Compute the index key for the file at `path` from its metadata instead of its content.
+///
+/// Combines the canonical path, modification time and size with the MIME type,
+/// [`extraction_config_hash`] and the plugin fingerprint. Returns `None` if the
+/// metadata can't be read, in which case lookups fall back to hashing the content.
+async fn file_cache_key(path: &Path, mime_type: &str, config: &ExtractionConfig) -> Option<String> {
+    let path = tokio::fs::canonicalize(path).await.ok()?;
+    let metadata = tokio::fs::metadata(&path).await.ok()?;
+    let modified = metadata
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?
+        .as_nanos()
+        .to_string();
+    let size = metadata.len().to_string();
+    let config_hash = extraction_config_hash(config).ok()?;
+    let plugins = plugin_fingerprint();
+    Some(generate_cache_key(&[
+        ("path", path.to_string_lossy().as_ref()),
+        ("modified", modified.as_str()),
+        ("size", size.as_str()),
+        ("mime_type", mime_type),
+        ("config", config_hash.as_str()),
+        ("plugins", plugins.as_str()),
+    ]))
+}
+
+/// Outcome of looking up a file in the extraction cache.
+enum Warmed {
+    /// The serialized result.
+    Hit(Vec<u8>),
+    /// Keys to store a new result under.
+    Miss {
+        content_key: String,
+        file_key: Option<String>,
+    },
+}
+
+/// Look up the cached result for the file at `path`.
+///
+/// The file's metadata key is tried first; the file is only read and hashed when that
+/// misses. A hit by content is then indexed under the metadata key, so the next lookup
+/// of the unchanged file is cheap.
+async fn find_warmed(cache: &GenericCache, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<Warmed> {
+    let file_key = file_cache_key(path, mime_type, config).await;
+    if let Some(file_key) = &file_key
+        && let Some(content_key) = cache.get(file_key, None)?
+        && let Some(bytes) = cache.get(&String::from_utf8_lossy(&content_key), None)?
+    {
+        return Ok(Warmed::Hit(bytes));
+    }
+
+    let content = crate::core::io::read_file_async(path).await?;
+    let content_key = extraction_cache_key(&content, mime_type, config)?;
+    match cache.get(&content_key, None)? {
+        Some(bytes) => {
+            if let Some(file_key) = &file_key {
+                index_file(cache, file_key, &content_key);
+            }
+            Ok(Warmed::Hit(bytes))
+        }
+        None => Ok(Warmed::Miss { content_key, file_key }),
+    }
+}
+
+/// Point the metadata key of a file at the entry stored under its content key.
+///
+/// A missing index entry only costs a content hash on the next lookup, so failures are
+/// logged and ignored.
+fn index_file(cache: &GenericCache, file_key: &str, content_key: &str) {
+    if let Err(e) = cache.set(file_key, content_key.as_bytes().to_vec(), None) {
+        tracing::debug!("Failed to index extraction cache entry: {}", e);
+    }
+}
+
+/// Look up the cached extraction result for `path` extracted with `config`.
+///
+/// The MIME type is detected from the path, as `warm` does. Returns `Ok(None)` on a
+/// cache miss.
+pub fn get_cached_extraction(
+    cache: &GenericCache,
+    path: impl AsRef<Path>,
+    config: &ExtractionConfig,
+) -> Result<Option<ExtractionResult>> {
+    let path = path.as_ref();
+    let mime_type = crate::core::mime::detect_or_validate(Some(path), None)?;
+    let content = crate::core::io::read_file_sync(path)?;
+    cached_result(cache, &content, &mime_type, config)
+}
+
+fn cached_result(
+    cache: &GenericCache,
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> Result<Option<ExtractionResult>> {
+    let cache_key = extraction_cache_key(content, mime_type, config)?;
+    match cache.get(&cache_key, None)? {
+        Some(bytes) => Ok(Some(rmp_serde::from_slice(&bytes)?)),
+        None => Ok(None),
+    }
+}
+
+/// Open the default extraction cache for a lookup, if caching is enabled and the cache
+/// has been warmed in this directory.
+///
+/// Doesn't create the cache directory, so plain extraction leaves no trace on disk.
+fn warmed_cache(config: &ExtractionConfig) -> Option<GenericCache> {
+    if !caching_enabled(config) {
+        return None;
+    }
+    let cache_dir = std::env::current_dir()
+        .ok()?
+        .join(".kreuzberg")
+        .join(EXTRACTION_CACHE_TYPE);
+    if !cache_dir.is_dir() {
+        return None;
+    }
+    extraction_cache().ok()
+}
+
+/// Look up a warmed result for the file at `path` before extracting it.
+///
+/// Returns `None` when caching is disabled, the cache was never warmed, or on a miss.
+/// An unchanged file is found by its path, modification time and size without being
+/// read. Lookup failures are logged and treated as misses.
+pub(crate) async fn lookup_warmed_file(
+    path: &Path,
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> Option<ExtractionResult> {
+    let cache = warmed_cache(config)?;
+    let lookup = match find_warmed(&cache, path, mime_type, config).await {
+        Ok(Warmed::Hit(bytes)) => rmp_serde::from_slice(&bytes).map_err(KreuzbergError::from),
+        Ok(Warmed::Miss { .. }) => return None,
+        Err(e) => Err(e),
+    };
+    match lookup {
+        Ok(result) => Some(result),
+        Err(e) => {
+            tracing::debug!("Extraction cache lookup failed: {}", e);
+            None
+        }
+    }
+}
+
+/// Look up a warmed result for `content` before extracting it.
+///
+/// See [`lookup_warmed_file`].
+pub(crate) fn lookup_warmed_bytes(
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> Option<ExtractionResult> {
+    let cache = warmed_cache(config)?;
+    lookup(&cache, content, mime_type, config)
+}
+
+fn lookup(
+    cache: &GenericCache,
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> Option<ExtractionResult> {
+    match cached_result(cache, content, mime_type, config) {
+        Ok(result) => result,
+        Err(e) => {
+            tracing::debug!("Extraction cache lookup failed: {}", e);
+            None
+        }
+    }
+}
+
+/// Pre-populate the extraction cache for `paths` using the default cache location.
+///
+/// Each file is extracted with `config` and its result cached; results are discarded.
+/// Files that are already cached are skipped. Per-file failures are collected in
+/// [`WarmReport::errors`] rather than aborting the run.
+///
+/// Nothing is extracted or written, and an empty report is returned, when
+/// `config.use_cache` is `false` or `KREUZBERG_NO_CACHE` is set.
+///
+/// # Errors
+///
+/// Returns an error only if the cache directory cannot be created.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::cache;
+/// use kreuzberg::ExtractionConfig;
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let report = cache::warm(&["docs/a.pdf", "docs/b.docx"], &ExtractionConfig::default()).await?;
+/// println!("{} cached, {} already warm, {} failed", report.misses, report.hits, report.errors.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn warm<P: AsRef<Path>>(paths: &[P], config: &ExtractionConfig) -> Result<WarmReport> {
+    if !caching_enabled(config) {
+        return Ok(WarmReport::default());
+    }
+    let cache = extraction_cache()?;
+    warm_with_cache(&cache, paths, config).await
+}
+
+/// Pre-populate `cache` with extraction results for `paths`.
+///
+/// See [`warm`].
+pub async fn warm_with_cache<P: AsRef<Path>>(
+    cache: &GenericCache,
+    paths: &[P],
+    config: &ExtractionConfig,
+) -> Result<WarmReport> {
+    let mut report = WarmReport::default();
+    if !caching_enabled(config) {
+        return Ok(report);
+    }
+
+    for path in paths {
+        let path = path.as_ref();
+        match warm_file(cache, path, config).await {
+            Ok(true) => report.hits += 1,
+            Ok(false) => report.misses += 1,
+            Err(e) => report.errors.push(WarmError {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(report)
+}
+
+/// Warm a single file. Returns `true` if it was already cached.
+async fn warm_file(cache: &GenericCache, path: &Path, config: &ExtractionConfig) -> Result<bool> {
+    let mime_type = crate::core::mime::detect_or_validate(Some(path), None)?;
+    let (content_key, file_key) = match find_warmed(cache, path, &mime_type, config).await? {
+        Warmed::Hit(_) => return Ok(true),
+        Warmed::Miss { content_key, file_key } => (content_key, file_key),
+    };
+
+    let result = crate::core::extractor::extract_file(path, Some(&mime_type), config).await?;
+    let serialized = rmp_serde::to_vec_named(&result)?;
+    cache
+        .set(&content_key, serialized, None)
+        .map_err(|e| KreuzbergError::cache(format!("Failed to cache result for {}: {}", path.display(), e)))?;
+    if let Some(file_key) = file_key {
+        index_file(cache, &file_key, &content_key);
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::tempdir;
+
+    fn temp_cache(dir: &Path) -> GenericCache {
+        GenericCache::new(
+            EXTRACTION_CACHE_TYPE.to_string(),
+            Some(dir.to_str().unwrap().to_string()),
+            30.0,
+            500.0,
+            1000.0,
+        )
+        .unwrap()
+    }
+
+    fn write_file(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        let mut file = std::fs::File::create(&path).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_warm_is_incremental() {
+        let docs = tempdir().unwrap();
+        let cache_dir = tempdir().unwrap();
+        let cache = temp_cache(cache_dir.path());
+        let config = ExtractionConfig::default();
+
+        let first = write_file(docs.path(), "first.txt", "First document");
+        let second = write_file(docs.path(), "second.txt", "Second document");
+
+        let report = warm_with_cache(&cache, std::slice::from_ref(&first), &config)
+            .await
+            .unwrap();
+        assert_eq!((report.hits, report.misses), (0, 1));
+
+        let report = warm_with_cache(&cache, &[&first, &second], &config).await.unwrap();
+        assert_eq!((report.hits, report.misses), (1, 1));
+        assert!(report.errors.is_empty());
+
+        let cached = get_cached_extraction(&cache, &second, &config).unwrap().unwrap();
+        assert!(cached.content.contains("Second document"));
+    }
+
+    #[tokio::test]
+    async fn test_warm_reports_errors_per_file() {
+        let docs = tempdir().unwrap();
+        let cache_dir = tempdir().unwrap();
+        let cache = temp_cache(cache_dir.path());
+
+        let good = write_file(docs.path(), "good.txt", "Readable");
+        let missing = docs.path().join("missing.txt");
+
+        let report = warm_with_cache(&cache, &[&good, &missing], &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(report.misses, 1);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].path, missing);
+    }
+
+    #[tokio::test]
+    async fn test_warm_respects_use_cache() {
+        let docs = tempdir().unwrap();
+        let cache_dir = tempdir().unwrap();
+        let cache = temp_cache(cache_dir.path());
+        let path = write_file(docs.path(), "notes.txt", "Not cached");
+        let config = ExtractionConfig {
+            use_cache: false,
+            ..Default::default()
+        };
+
+        let report = warm_with_cache(&cache, std::slice::from_ref(&path), &config)
+            .await
+            .unwrap();
+
+        assert_eq!(report, WarmReport::default());
+        assert!(get_cached_extraction(&cache, &path, &config).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_warmed_file_is_found_by_metadata() {
+        let docs = tempdir().unwrap();
+        let cache_dir = tempdir().unwrap();
+        let cache = temp_cache(cache_dir.path());
+        let config = ExtractionConfig::default();
+        let path = write_file(docs.path(), "notes.txt", "Indexed by metadata");
+
+        warm_with_cache(&cache, std::slice::from_ref(&path), &config)
+            .await
+            .unwrap();
+        let file_key = file_cache_key(&path, "text/plain", &config).await.unwrap();
+        assert!(cache.get(&file_key, None).unwrap().is_some());
+        assert!(matches!(
+            find_warmed(&cache, &path, "text/plain", &config).await.unwrap(),
+            Warmed::Hit(_)
+        ));
+
+        write_file(docs.path(), "notes.txt", "Changed since warming");
+        assert_ne!(file_cache_key(&path, "text/plain", &config).await.unwrap(), file_key);
+        assert!(matches!(
+            find_warmed(&cache, &path, "text/plain", &config).await.unwrap(),
+            Warmed::Miss { .. }
+        ));
+    }
+
+    #[test]
+    fn test_extraction_cache_key_depends_on_config() {
+        let default_key = extraction_cache_key(b"content", "text/plain", &ExtractionConfig::default()).unwrap();
+        let other_key = extraction_cache_key(
+            b"content",
+            "text/plain",
+            &ExtractionConfig {
+                force_ocr: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(default_key.len(), 32);
+        assert_ne!(default_key, other_key);
+        assert_eq!(
+            default_key,
+            extraction_cache_key(b"content", "text/plain", &ExtractionConfig::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_extraction_cache_key_ignores_runtime_fields() {
+        let config = ExtractionConfig {
+            ocr: Some(crate::core::config::OcrConfig {
+                tesseract_config: Some(crate::types::TesseractConfig::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut runtime_only = config.clone();
+        runtime_only.use_cache = !config.use_cache;
+        runtime_only.max_concurrent_extractions = Some(2);
+        runtime_only.low_memory = true;
+        if let Some(tesseract) = runtime_only.ocr.as_mut().and_then(|ocr| ocr.tesseract_config.as_mut()) {
+            tesseract.use_cache = !tesseract.use_cache;
+        }
+        let mut output_changing = config.clone();
+        output_changing.ocr.as_mut().unwrap().language = "deu".to_string();
+
+        let key = extraction_cache_key(b"content", "text/plain", &config).unwrap();
+        assert_eq!(
+            key,
+            extraction_cache_key(b"content", "text/plain", &runtime_only).unwrap()
+        );
+        assert_ne!(
+            key,
+            extraction_cache_key(b"content", "text/plain", &output_changing).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_warm_keeps_separate_entries_per_config() {
+        let docs = tempdir().unwrap();
+        let cache_dir = tempdir().unwrap();
+        let cache = temp_cache(cache_dir.path());
+        let path = write_file(docs.path(), "notes.txt", "Twenty words of notes about caching.");
+
+        let full = ExtractionConfig::default();
+        let truncated = ExtractionConfig {
+            max_content_chars: Some(6),
+            ..Default::default()
+        };
+
+        let report = warm_with_cache(&cache, std::slice::from_ref(&path), &full)
+            .await
+            .unwrap();
+        assert_eq!((report.hits, report.misses), (0, 1));
+        assert!(get_cached_extraction(&cache, &path, &truncated).unwrap().is_none());
+
+        let report = warm_with_cache(&cache, std::slice::from_ref(&path), &truncated)
+            .await
+            .unwrap();
+        assert_eq!((report.hits, report.misses), (0, 1));
+
+        let full_result = get_cached_extraction(&cache, &path, &full).unwrap().unwrap();
+        let truncated_result = get_cached_extraction(&cache, &path, &truncated).unwrap().unwrap();
+        assert!(full_result.content.contains("about caching"));
+        assert!(!truncated_result.content.contains("about caching"));
+    }
+}
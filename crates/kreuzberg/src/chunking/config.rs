@@ -2,8 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
-// Re-export ChunkingConfig and ChunkerType from core config (canonical location)
-pub use crate::core::config::processing::{ChunkerType, ChunkingConfig};
+// Re-export ChunkingConfig, ChunkerType and OverlapUnit from core config (canonical location)
+pub use crate::core::config::processing::{ChunkerType, ChunkingConfig, OverlapUnit};
 
 /// Result of a text chunking operation.
 ///
@@ -14,4 +14,7 @@ pub struct ChunkingResult {
     pub chunks: Vec<crate::types::Chunk>,
     /// Total number of chunks generated
     pub chunk_count: usize,
+    /// Whether chunking stopped at `ChunkingConfig::max_chunks` before the end of the text
+    #[serde(default)]
+    pub truncated: bool,
 }
@@ -4,17 +4,19 @@
 //! public API functions for splitting text into chunks.
 
 use crate::error::Result;
-use crate::types::PageBoundary;
+use crate::types::{Chunk, PageBoundary};
 use text_splitter::{MarkdownSplitter, TextSplitter};
 
-use super::builder::{build_chunk_config, build_chunks};
-use super::config::{ChunkerType, ChunkingConfig, ChunkingResult};
+use super::builder::{build_chunk, build_chunk_config};
+use super::config::{ChunkerType, ChunkingConfig, ChunkingResult, OverlapUnit};
+use super::headings::heading_paths;
 use super::validation::validate_utf8_boundaries;
 
 /// Split text into chunks with optional page boundary tracking.
 ///
 /// This is the primary API function for chunking text. It supports both plain text
 /// and Markdown with configurable chunk size, overlap, and page boundary mapping.
+/// Markdown chunks carry the heading path they fall under in `heading_path`.
 ///
 /// # Arguments
 ///
@@ -48,34 +50,115 @@ pub fn chunk_text(
     config: &ChunkingConfig,
     page_boundaries: Option<&[PageBoundary]>,
 ) -> Result<ChunkingResult> {
+    let (spans, truncated) = chunk_spans(text, config, page_boundaries)?;
+    let total_chunks = spans.len();
+    let chunks = spans
+        .into_iter()
+        .enumerate()
+        .map(|(index, (content, byte_start, byte_end, heading_path))| {
+            let mut chunk = build_chunk(content, byte_start, byte_end, index, total_chunks, page_boundaries)?;
+            chunk.metadata.heading_path = heading_path;
+            Ok(chunk)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let chunk_count = chunks.len();
+
+    Ok(ChunkingResult {
+        chunks,
+        chunk_count,
+        truncated,
+    })
+}
+
+/// Split text into chunks lazily, yielding each chunk as it is built.
+///
+/// Produces the same chunks as [`chunk_text`], but only chunk boundaries are computed
+/// up front; each chunk's content is allocated when the iterator reaches it. This keeps
+/// memory flat for very large documents, e.g. when embedding chunks one at a time.
+/// No more than `config.max_chunks` chunks are produced.
+///
+/// Configuration and page boundary errors are yielded as the first item.
+///
+/// # Examples
+///
+/// ```rust
+/// use kreuzberg::chunking::{chunk_text_streaming, ChunkingConfig};
+///
+/// # fn example() -> kreuzberg::Result<()> {
+/// let config = ChunkingConfig::default();
+/// for chunk in chunk_text_streaming("Long text...", &config, None) {
+///     let chunk = chunk?;
+///     assert!(!chunk.content.is_empty());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn chunk_text_streaming<'a>(
+    text: &'a str,
+    config: &ChunkingConfig,
+    page_boundaries: Option<&'a [PageBoundary]>,
+) -> impl Iterator<Item = Result<Chunk>> + 'a {
+    let (spans, error) = match chunk_spans(text, config, page_boundaries) {
+        Ok((spans, _)) => (spans, None),
+        Err(e) => (Vec::new(), Some(e)),
+    };
+    let total_chunks = spans.len();
+
+    error.map(Err).into_iter().chain(spans.into_iter().enumerate().map(
+        move |(index, (content, byte_start, byte_end, heading_path))| {
+            let mut chunk = build_chunk(content, byte_start, byte_end, index, total_chunks, page_boundaries)?;
+            chunk.metadata.heading_path = heading_path;
+            Ok(chunk)
+        },
+    ))
+}
+
+/// A chunk's content slice, byte range and heading path.
+type ChunkSpan<'a> = (&'a str, usize, usize, Option<Vec<String>>);
+
+/// Content slice, byte range and heading path of each chunk, and whether `max_chunks`
+/// cut the list short.
+///
+/// Byte ranges are the offsets the splitter reports, so they stay exact when `trim`
+/// removes whitespace between chunks.
+fn chunk_spans<'a>(
+    text: &'a str,
+    config: &ChunkingConfig,
+    page_boundaries: Option<&[PageBoundary]>,
+) -> Result<(Vec<ChunkSpan<'a>>, bool)> {
     if text.is_empty() {
-        return Ok(ChunkingResult {
-            chunks: vec![],
-            chunk_count: 0,
-        });
+        return Ok((Vec::new(), false));
     }
 
     if let Some(boundaries) = page_boundaries {
         validate_utf8_boundaries(text, boundaries)?;
     }
 
-    let chunk_config = build_chunk_config(config.max_characters, config.overlap, config.trim)?;
-
-    let text_chunks: Vec<&str> = match config.chunker_type {
-        ChunkerType::Text => {
-            let splitter = TextSplitter::new(chunk_config);
-            splitter.chunks(text).collect()
-        }
-        ChunkerType::Markdown => {
-            let splitter = MarkdownSplitter::new(chunk_config);
-            splitter.chunks(text).collect()
-        }
+    let (split, truncated) = split_chunks(text, config)?;
+    let (starts, text_chunks): (Vec<usize>, Vec<&str>) = split.into_iter().unzip();
+    let paths = match config.chunker_type {
+        ChunkerType::Text => vec![None; text_chunks.len()],
+        ChunkerType::Markdown => heading_paths(text, &starts),
     };
 
-    let chunks = build_chunks(text_chunks.into_iter(), config.overlap, page_boundaries)?;
-    let chunk_count = chunks.len();
+    let spans: Vec<(&str, usize, usize)> = match config.overlap_unit {
+        OverlapUnit::Characters => starts
+            .into_iter()
+            .zip(text_chunks)
+            .map(|(start, chunk)| (chunk, start, start + chunk.len()))
+            .collect(),
+        OverlapUnit::Sentences => sentence_overlap_ranges(text, &text_chunks, config.overlap)
+            .into_iter()
+            .map(|(start, end)| (&text[start..end], start, end))
+            .collect(),
+    };
 
-    Ok(ChunkingResult { chunks, chunk_count })
+    let spans = spans
+        .into_iter()
+        .zip(paths)
+        .map(|((content, start, end), heading_path)| (content, start, end, heading_path))
+        .collect();
+    Ok((spans, truncated))
 }
 
 /// Chunk text with explicit type specification.
@@ -120,6 +203,9 @@ pub fn chunk_text_with_type(
         chunker_type,
         embedding: None,
         preset: None,
+        overlap_unit: OverlapUnit::Characters,
+        max_chunks: None,
+        balance: false,
     };
     chunk_text(text, &config, None)
 }
@@ -159,6 +245,147 @@ pub fn chunk_texts_batch(texts: &[&str], config: &ChunkingConfig) -> Result<Vec<
     texts.iter().map(|text| chunk_text(text, config, None)).collect()
 }
 
+/// Split `text` into chunks with the configured splitter.
+///
+/// Returns each chunk's byte offset and slice, capped at `config.max_chunks`, and whether
+/// the cap cut the text short. With `config.balance`, the greedy split is followed by a
+/// search for the smallest chunk size that yields as many chunks.
+fn split_chunks<'a>(text: &'a str, config: &ChunkingConfig) -> Result<(Vec<(usize, &'a str)>, bool)> {
+    // Sentence overlap is applied after splitting, so the splitter itself runs without overlap.
+    let splitter_overlap = match config.overlap_unit {
+        OverlapUnit::Characters => config.overlap,
+        OverlapUnit::Sentences => 0,
+    };
+
+    // One chunk past the limit is split only to tell whether the text was cut short.
+    let limit = config
+        .max_chunks
+        .map_or(usize::MAX, |max_chunks| max_chunks.saturating_add(1));
+    let mut chunks = split_with_capacity(text, config, config.max_characters, splitter_overlap, limit)?;
+    let truncated = config.max_chunks.is_some_and(|max_chunks| chunks.len() > max_chunks);
+
+    if let Some(max_chunks) = config.max_chunks {
+        chunks.truncate(max_chunks);
+    }
+    if config.balance && !truncated && chunks.len() > 1 {
+        chunks = balanced_chunks(text, config, splitter_overlap, chunks)?;
+    }
+
+    Ok((chunks, truncated))
+}
+
+/// Split `text` into at most `limit` chunks of at most `max_characters` characters.
+fn split_with_capacity<'a>(
+    text: &'a str,
+    config: &ChunkingConfig,
+    max_characters: usize,
+    overlap: usize,
+    limit: usize,
+) -> Result<Vec<(usize, &'a str)>> {
+    let chunk_config = build_chunk_config(max_characters, overlap, config.trim)?;
+    Ok(match config.chunker_type {
+        ChunkerType::Text => TextSplitter::new(chunk_config)
+            .chunk_indices(text)
+            .take(limit)
+            .collect(),
+        ChunkerType::Markdown => MarkdownSplitter::new(chunk_config)
+            .chunk_indices(text)
+            .take(limit)
+            .collect(),
+    })
+}
+
+/// Re-split `text` with the smallest chunk size that yields no more chunks than `greedy`.
+///
+/// The size is binary searched between the even share of the text per chunk and
+/// `config.max_characters`, so the remainder that greedy splitting leaves in the last
+/// chunk is spread over all of them.
+fn balanced_chunks<'a>(
+    text: &'a str,
+    config: &ChunkingConfig,
+    overlap: usize,
+    greedy: Vec<(usize, &'a str)>,
+) -> Result<Vec<(usize, &'a str)>> {
+    let count = greedy.len();
+    let total_characters = text.chars().count() + (count - 1) * overlap;
+
+    let mut low = total_characters.div_ceil(count).max(overlap + 1);
+    let mut high = config.max_characters;
+    let mut best = greedy;
+    while low < high {
+        let size = low + (high - low) / 2;
+        let chunks = split_with_capacity(text, config, size, overlap, count + 1)?;
+        if chunks.len() <= count {
+            best = chunks;
+            high = size;
+        } else {
+            low = size + 1;
+        }
+    }
+
+    Ok(best)
+}
+
+/// Byte ranges of the chunks with the last `sentences` sentences of each chunk carried into the next.
+///
+/// `text_chunks` must be slices of `text` in order, as produced by the splitters.
+fn sentence_overlap_ranges(text: &str, text_chunks: &[&str], sentences: usize) -> Vec<(usize, usize)> {
+    let base = text.as_ptr() as usize;
+    let spans: Vec<(usize, usize)> = text_chunks
+        .iter()
+        .map(|chunk| {
+            let start = chunk.as_ptr() as usize - base;
+            (start, start + chunk.len())
+        })
+        .collect();
+
+    spans
+        .iter()
+        .enumerate()
+        .map(|(index, &(start, end))| match index.checked_sub(1) {
+            Some(previous) if sentences > 0 => {
+                let (previous_start, previous_end) = spans[previous];
+                let starts = sentence_starts(&text[previous_start..previous_end]);
+                let carried = starts[starts.len().saturating_sub(sentences)];
+                ((previous_start + carried).min(start), end)
+            }
+            _ => (start, end),
+        })
+        .collect()
+}
+
+/// Byte offsets at which sentences begin in `text`, always including 0.
+///
+/// A sentence begins at the first non-whitespace character after `.`, `!` or `?` and any
+/// closing quotes or brackets that follow them.
+fn sentence_starts(text: &str) -> Vec<usize> {
+    let mut starts = vec![0];
+    let mut after_terminator = false;
+    let mut in_gap = false;
+
+    for (offset, ch) in text.char_indices() {
+        if in_gap {
+            if !ch.is_whitespace() {
+                starts.push(offset);
+                in_gap = false;
+                after_terminator = matches!(ch, '.' | '!' | '?');
+            }
+            continue;
+        }
+        match ch {
+            '.' | '!' | '?' => after_terminator = true,
+            '"' | '\'' | ')' | ']' | '”' | '’' if after_terminator => {}
+            c if c.is_whitespace() && after_terminator => {
+                in_gap = true;
+                after_terminator = false;
+            }
+            _ => after_terminator = false,
+        }
+    }
+
+    starts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,6 +399,93 @@ mod tests {
         assert_eq!(result.chunk_count, 0);
     }
 
+    #[test]
+    fn test_chunk_max_chunks_truncates() {
+        let text = "word ".repeat(200);
+        let config = ChunkingConfig {
+            max_characters: 50,
+            overlap: 0,
+            max_chunks: Some(4),
+            ..Default::default()
+        };
+
+        let unlimited = chunk_text(
+            &text,
+            &ChunkingConfig {
+                max_chunks: None,
+                ..config.clone()
+            },
+            None,
+        )
+        .unwrap();
+        assert!(unlimited.chunk_count > 4);
+        assert!(!unlimited.truncated);
+
+        let result = chunk_text(&text, &config, None).unwrap();
+        assert!(result.truncated);
+        assert_eq!(result.chunk_count, 4);
+        assert_eq!(result.chunks.len(), 4);
+        assert_eq!(result.chunks[3].metadata.total_chunks, 4);
+        assert_eq!(result.chunks[3].content, unlimited.chunks[3].content);
+
+        let streamed = chunk_text_streaming(&text, &config, None).count();
+        assert_eq!(streamed, 4);
+
+        let exact = ChunkingConfig {
+            max_chunks: Some(unlimited.chunk_count),
+            ..config
+        };
+        assert!(!chunk_text(&text, &exact, None).unwrap().truncated);
+    }
+
+    #[test]
+    fn test_chunk_balance_reduces_size_variance() {
+        let text = "word ".repeat(130);
+        let greedy_config = ChunkingConfig {
+            max_characters: 200,
+            overlap: 0,
+            ..Default::default()
+        };
+        let balanced_config = ChunkingConfig {
+            balance: true,
+            ..greedy_config.clone()
+        };
+
+        let greedy = chunk_text(&text, &greedy_config, None).unwrap();
+        let balanced = chunk_text(&text, &balanced_config, None).unwrap();
+
+        let variance = |result: &ChunkingResult| {
+            let sizes: Vec<f64> = result
+                .chunks
+                .iter()
+                .map(|chunk| chunk.content.chars().count() as f64)
+                .collect();
+            let mean = sizes.iter().sum::<f64>() / sizes.len() as f64;
+            sizes.iter().map(|size| (size - mean).powi(2)).sum::<f64>() / sizes.len() as f64
+        };
+
+        assert_eq!(greedy.chunk_count, 4);
+        assert!(greedy.chunks[3].content.len() < 60);
+        assert_eq!(balanced.chunk_count, greedy.chunk_count);
+        assert!(balanced.chunks.iter().all(|chunk| chunk.content.len() <= 200));
+        assert!(balanced.chunks[3].content.len() > 120);
+        assert!(variance(&balanced) < variance(&greedy) / 10.0);
+        assert_eq!(
+            balanced
+                .chunks
+                .iter()
+                .map(|chunk| chunk.content.split_whitespace().count())
+                .sum::<usize>(),
+            130
+        );
+
+        let streamed = chunk_text_streaming(&text, &balanced_config, None)
+            .collect::<Result<Vec<Chunk>>>()
+            .unwrap();
+        assert_eq!(streamed.len(), balanced.chunk_count);
+        assert_eq!(streamed[3].content, balanced.chunks[3].content);
+    }
+
     #[test]
     fn test_chunk_short_text_single_chunk() {
         let config = ChunkingConfig {
@@ -181,6 +495,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "This is a short text.";
         let result = chunk_text(text, &config, None).unwrap();
@@ -198,6 +515,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -206,6 +526,80 @@ mod tests {
         assert!(result.chunks.iter().all(|chunk| chunk.content.len() <= 20));
     }
 
+    #[test]
+    fn test_chunk_text_streaming_matches_chunk_text() {
+        let text = "First sentence here. Second sentence follows. Third one ends it. \
+                    A fourth sentence keeps going. And a fifth closes the text.";
+        for overlap_unit in [OverlapUnit::Characters, OverlapUnit::Sentences] {
+            let config = ChunkingConfig {
+                max_characters: 40,
+                overlap: 1,
+                trim: true,
+                chunker_type: ChunkerType::Text,
+                embedding: None,
+                preset: None,
+                overlap_unit,
+                max_chunks: None,
+                balance: false,
+            };
+            let eager = chunk_text(text, &config, None).unwrap();
+            let streamed: Vec<Chunk> = chunk_text_streaming(text, &config, None)
+                .collect::<Result<_>>()
+                .unwrap();
+
+            assert_eq!(streamed.len(), eager.chunk_count);
+            for (streamed, eager) in streamed.iter().zip(&eager.chunks) {
+                assert_eq!(streamed.content, eager.content);
+                assert_eq!(streamed.metadata.byte_start, eager.metadata.byte_start);
+                assert_eq!(streamed.metadata.byte_end, eager.metadata.byte_end);
+                assert_eq!(streamed.metadata.total_chunks, eager.metadata.total_chunks);
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunk_offsets_index_the_source_text() {
+        let text = "First   sentence here.\n\n   Second sentence follows.   Third one ends it.";
+        let config = ChunkingConfig {
+            max_characters: 24,
+            overlap: 6,
+            trim: true,
+            chunker_type: ChunkerType::Text,
+            embedding: None,
+            preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
+        };
+
+        let eager = chunk_text(text, &config, None).unwrap();
+        let streamed: Vec<Chunk> = chunk_text_streaming(text, &config, None)
+            .collect::<Result<_>>()
+            .unwrap();
+        assert!(eager.chunk_count > 2);
+        for chunk in eager.chunks.iter().chain(&streamed) {
+            assert_eq!(&text[chunk.metadata.byte_start..chunk.metadata.byte_end], chunk.content);
+        }
+    }
+
+    #[test]
+    fn test_chunk_text_streaming_yields_config_error() {
+        let config = ChunkingConfig {
+            max_characters: 10,
+            overlap: 20,
+            trim: true,
+            chunker_type: ChunkerType::Text,
+            embedding: None,
+            preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
+        };
+        let mut chunks = chunk_text_streaming("Some text to chunk", &config, None);
+        assert!(matches!(chunks.next(), Some(Err(KreuzbergError::Validation { .. }))));
+        assert!(chunks.next().is_none());
+    }
+
     #[test]
     fn test_chunk_text_with_overlap() {
         let config = ChunkingConfig {
@@ -215,6 +609,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "abcdefghijklmnopqrstuvwxyz0123456789";
         let result = chunk_text(text, &config, None).unwrap();
@@ -240,6 +637,9 @@ mod tests {
             chunker_type: ChunkerType::Markdown,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let markdown = "# Title\n\nParagraph one.\n\n## Section\n\nParagraph two.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -256,6 +656,9 @@ mod tests {
             chunker_type: ChunkerType::Markdown,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let markdown = "# Code Example\n\n```python\nprint('hello')\n```\n\nSome text after code.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -272,6 +675,9 @@ mod tests {
             chunker_type: ChunkerType::Markdown,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let markdown = "Check out [this link](https://example.com) for more info.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -279,6 +685,51 @@ mod tests {
         assert!(result.chunks[0].content.contains("[this link]"));
     }
 
+    #[test]
+    fn test_chunk_markdown_heading_paths() {
+        let config = ChunkingConfig {
+            max_characters: 60,
+            overlap: 0,
+            chunker_type: ChunkerType::Markdown,
+            ..Default::default()
+        };
+        let markdown = "# Chapter 2\n\nOverview of the chapter.\n\n## Installation\n\n\
+                        Run the installer and follow the prompts.\n\n### Linux\n\n\
+                        Use the package manager of your distribution.\n\n\
+                        ## Usage\n\nStart the application from the menu.";
+        let result = chunk_text(markdown, &config, None).unwrap();
+        assert!(result.chunk_count > 1);
+
+        let path_of = |needle: &str| {
+            result
+                .chunks
+                .iter()
+                .find(|chunk| chunk.content.contains(needle))
+                .and_then(|chunk| chunk.metadata.heading_path.clone())
+                .unwrap_or_default()
+        };
+        assert_eq!(path_of("Run the installer"), vec!["Chapter 2", "Installation"]);
+        assert_eq!(path_of("package manager"), vec!["Chapter 2", "Installation", "Linux"]);
+        assert_eq!(path_of("Start the application"), vec!["Chapter 2", "Usage"]);
+
+        let streamed: Vec<Option<Vec<String>>> = chunk_text_streaming(markdown, &config, None)
+            .map(|chunk| chunk.unwrap().metadata.heading_path)
+            .collect();
+        let collected: Vec<Option<Vec<String>>> = result
+            .chunks
+            .iter()
+            .map(|chunk| chunk.metadata.heading_path.clone())
+            .collect();
+        assert_eq!(streamed, collected);
+
+        let text_config = ChunkingConfig {
+            chunker_type: ChunkerType::Text,
+            ..config
+        };
+        let result = chunk_text(markdown, &text_config, None).unwrap();
+        assert!(result.chunks.iter().all(|chunk| chunk.metadata.heading_path.is_none()));
+    }
+
     #[test]
     fn test_chunk_text_with_trim() {
         let config = ChunkingConfig {
@@ -288,6 +739,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "  Leading and trailing spaces  should be trimmed  ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -304,6 +758,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "  Text with spaces  ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -320,6 +777,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let result = chunk_text("Some text", &config, None);
         assert!(result.is_err());
@@ -359,6 +819,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let texts = vec!["First text", "Second text", "Third text"];
         let results = chunk_texts_batch(&texts, &config).unwrap();
@@ -375,6 +838,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let texts = vec![
             "Short",
@@ -397,6 +863,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let texts = vec!["Text one", "Text two"];
         let result = chunk_texts_batch(&texts, &config);
@@ -421,6 +890,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "a".repeat(1000);
         let result = chunk_text(&text, &config, None).unwrap();
@@ -437,6 +909,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "Line one\nLine two\nLine three\nLine four\nLine five";
         let result = chunk_text(text, &config, None).unwrap();
@@ -452,6 +927,9 @@ mod tests {
             chunker_type: ChunkerType::Markdown,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let markdown = "# List Example\n\n- Item 1\n- Item 2\n- Item 3\n\nMore text.";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -468,6 +946,9 @@ mod tests {
             chunker_type: ChunkerType::Markdown,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let markdown = "# Table\n\n| Col1 | Col2 |\n|------|------|\n| A    | B    |\n| C    | D    |";
         let result = chunk_text(markdown, &config, None).unwrap();
@@ -484,6 +965,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "Special chars: @#$%^&*()[]{}|\\<>?/~`";
         let result = chunk_text(text, &config, None).unwrap();
@@ -500,6 +984,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "Unicode: 你好世界 🌍 café résumé";
         let result = chunk_text(text, &config, None).unwrap();
@@ -517,6 +1004,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "日本語のテキストです。これは長い文章で、複数のチャンクに分割されるべきです。";
         let result = chunk_text(text, &config, None).unwrap();
@@ -532,6 +1022,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "English text mixed with 中文文本 and some français";
         let result = chunk_text(text, &config, None).unwrap();
@@ -547,6 +1040,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "AAAAA BBBBB CCCCC DDDDD EEEEE FFFFF";
         let result = chunk_text(text, &config, None).unwrap();
@@ -601,6 +1097,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "AAAAA BBBBB CCCCC DDDDD EEEEE FFFFF";
         let result = chunk_text(text, &config, None).unwrap();
@@ -629,6 +1128,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "0123456789 ABCDEFGHIJ KLMNOPQRST UVWXYZ";
         let result = chunk_text(text, &config, None).unwrap();
@@ -665,6 +1167,9 @@ mod tests {
                 chunker_type: ChunkerType::Text,
                 embedding: None,
                 preset: None,
+                overlap_unit: OverlapUnit::Characters,
+                max_chunks: None,
+                balance: false,
             };
             let text = "Word ".repeat(30);
             let result = chunk_text(&text, &config, None).unwrap();
@@ -699,6 +1204,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "AAAAA BBBBB CCCCC DDDDD EEEEE";
         let result = chunk_text(text, &config, None).unwrap();
@@ -728,6 +1236,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "Page one content here. Page two starts here and continues.";
 
@@ -762,6 +1273,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "This is some test content that should be split into multiple chunks.";
 
@@ -783,6 +1297,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "Some text content here.";
         let boundaries: Vec<PageBoundary> = vec![];
@@ -803,6 +1320,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "0123456789 AAAAAAAAAA 1111111111 BBBBBBBBBB 2222222222";
 
@@ -841,6 +1361,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "Page one content here. Page two content.";
 
@@ -866,6 +1389,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "Page one content here. Page two content.";
 
@@ -898,6 +1424,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "Page one content here. Page two content.";
 
@@ -930,6 +1459,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "First page content here.Second page content here.Third page.";
 
@@ -967,6 +1499,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "All content on single page fits in one chunk.";
 
@@ -991,6 +1526,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "AAAAA BBBBB CCCCC DDDDD";
 
@@ -1026,6 +1564,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "Page One Content Here.Page Two.";
 
@@ -1058,6 +1599,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         let text = "0123456789ABCDEFGHIJ";
 
@@ -1090,4 +1634,74 @@ mod tests {
             }
         }
     }
+
+    fn sentence_config(max_characters: usize, sentences: usize) -> ChunkingConfig {
+        ChunkingConfig {
+            max_characters,
+            overlap: sentences,
+            trim: true,
+            chunker_type: ChunkerType::Text,
+            embedding: None,
+            preset: None,
+            overlap_unit: OverlapUnit::Sentences,
+            max_chunks: None,
+            balance: false,
+        }
+    }
+
+    #[test]
+    fn test_sentence_overlap_starts_at_sentence_boundary() {
+        let text = "The first sentence is here. The second one follows it. A third sentence appears. \
+                    Then comes the fourth. The fifth sentence ends the paragraph. Finally the sixth.";
+        let result = chunk_text(text, &sentence_config(60, 1), None).unwrap();
+
+        assert!(result.chunk_count > 1);
+        for chunk in &result.chunks[1..] {
+            let start = chunk.metadata.byte_start;
+            assert_eq!(chunk.content, &text[start..chunk.metadata.byte_end]);
+            let preceding = text[..start].trim_end();
+            assert!(
+                preceding.ends_with('.'),
+                "overlap should begin at a sentence boundary, got {:?}",
+                chunk.content
+            );
+        }
+    }
+
+    #[test]
+    fn test_sentence_overlap_carries_trailing_sentences() {
+        let text = "Alpha sentence one. Beta sentence two. Gamma sentence three. Delta sentence four.";
+        let result = chunk_text(text, &sentence_config(40, 1), None).unwrap();
+
+        assert!(result.chunk_count > 1);
+        for pair in result.chunks.windows(2) {
+            let previous_last = sentence_starts(&pair[0].content)
+                .last()
+                .map(|&start| pair[0].content[start..].to_string())
+                .unwrap();
+            assert!(
+                pair[1].content.starts_with(&previous_last),
+                "{:?} should start with {:?}",
+                pair[1].content,
+                previous_last
+            );
+        }
+    }
+
+    #[test]
+    fn test_sentence_overlap_zero_is_contiguous() {
+        let text = "One short sentence. Another short sentence. A third short sentence.";
+        let result = chunk_text(text, &sentence_config(30, 0), None).unwrap();
+
+        for pair in result.chunks.windows(2) {
+            assert!(pair[1].metadata.byte_start >= pair[0].metadata.byte_end);
+        }
+    }
+
+    #[test]
+    fn test_sentence_starts() {
+        assert_eq!(sentence_starts("One. Two! \"Three?\" Four"), vec![0, 5, 10, 19]);
+        assert_eq!(sentence_starts("No terminator here"), vec![0]);
+        assert_eq!(sentence_starts("Version 1.2 is out."), vec![0]);
+    }
 }
@@ -0,0 +1,178 @@
+//! Markdown heading hierarchy for chunk metadata.
+//!
+//! Headings are found with a line scan rather than a full Markdown parse: ATX headings
+//! (`## Title`) and setext headings (a line underlined with `=` or `-`) are recognized,
+//! and fenced code blocks are skipped so that `#` comments in code are not mistaken for
+//! headings.
+
+/// A heading of the text: where its first line starts, its level (1-6) and its title.
+struct Heading {
+    offset: usize,
+    level: usize,
+    title: String,
+}
+
+/// Heading path in effect at each of `offsets` (ascending byte offsets into `text`).
+///
+/// The path lists the titles of the enclosing headings, outermost first, including a
+/// heading that starts exactly at the offset. Offsets before the first heading get `None`.
+pub(super) fn heading_paths(text: &str, offsets: &[usize]) -> Vec<Option<Vec<String>>> {
+    let headings = markdown_headings(text);
+    let mut stack: Vec<&Heading> = Vec::new();
+    let mut next = 0;
+
+    offsets
+        .iter()
+        .map(|&offset| {
+            while let Some(heading) = headings.get(next).filter(|heading| heading.offset <= offset) {
+                while stack.last().is_some_and(|open| open.level >= heading.level) {
+                    stack.pop();
+                }
+                stack.push(heading);
+                next += 1;
+            }
+            (!stack.is_empty()).then(|| stack.iter().map(|heading| heading.title.clone()).collect())
+        })
+        .collect()
+}
+
+/// ATX and setext headings of `text`, in order.
+fn markdown_headings(text: &str) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut fence: Option<(char, usize)> = None;
+    // Start and text of the previous line while it can still become a setext heading.
+    let mut paragraph_line: Option<(usize, &str)> = None;
+    let mut offset = 0;
+
+    for raw_line in text.split_inclusive('\n') {
+        let line_start = offset;
+        offset += raw_line.len();
+        let line = raw_line.trim_end_matches(['\n', '\r']);
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        let content = line.trim();
+
+        if let Some((fence_char, fence_len)) = fence {
+            if indent < 4 && content.chars().take_while(|&c| c == fence_char).count() >= fence_len {
+                fence = None;
+            }
+            continue;
+        }
+        if indent >= 4 {
+            paragraph_line = None;
+            continue;
+        }
+        if let Some(opening) = code_fence(content) {
+            fence = Some(opening);
+            paragraph_line = None;
+            continue;
+        }
+
+        if let Some((level, title)) = atx_heading(content) {
+            if !title.is_empty() {
+                headings.push(Heading {
+                    offset: line_start,
+                    level,
+                    title: title.to_string(),
+                });
+            }
+            paragraph_line = None;
+            continue;
+        }
+
+        if let (Some((start, title)), Some(level)) = (paragraph_line, setext_level(content)) {
+            headings.push(Heading {
+                offset: start,
+                level,
+                title: title.to_string(),
+            });
+            paragraph_line = None;
+            continue;
+        }
+
+        paragraph_line = (!content.is_empty()).then_some((line_start, content));
+    }
+
+    headings
+}
+
+/// Level and title of an ATX heading line (`# Title`, `## Title ##`).
+fn atx_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+
+    let title = rest.trim();
+    let without_closing = title.trim_end_matches('#');
+    let title = if without_closing.is_empty() || without_closing.ends_with([' ', '\t']) {
+        without_closing.trim_end()
+    } else {
+        title
+    };
+    Some((level, title))
+}
+
+/// Level of a setext underline: `1` for `===`, `2` for `---`.
+fn setext_level(line: &str) -> Option<usize> {
+    let first = line.chars().next()?;
+    let level = match first {
+        '=' => 1,
+        '-' => 2,
+        _ => return None,
+    };
+    line.chars().all(|c| c == first).then_some(level)
+}
+
+/// Fence character and length of a code fence opening line.
+fn code_fence(line: &str) -> Option<(char, usize)> {
+    let first = line.chars().next().filter(|&c| c == '`' || c == '~')?;
+    let len = line.chars().take_while(|&c| c == first).count();
+    (len >= 3).then_some((first, len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(titles: &[&str]) -> Option<Vec<String>> {
+        Some(titles.iter().map(|title| title.to_string()).collect())
+    }
+
+    #[test]
+    fn test_heading_paths_follow_nesting() {
+        let text = "Intro\n\n# Guide\n\n## Setup\n\ntext\n\n### Linux ###\n\nmore\n\n## Usage\n\nend";
+        let offsets = [
+            0,
+            text.find("# Guide").unwrap(),
+            text.find("text").unwrap(),
+            text.find("more").unwrap(),
+            text.find("end").unwrap(),
+        ];
+
+        assert_eq!(
+            heading_paths(text, &offsets),
+            vec![
+                None,
+                path(&["Guide"]),
+                path(&["Guide", "Setup"]),
+                path(&["Guide", "Setup", "Linux"]),
+                path(&["Guide", "Usage"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_heading_paths_handle_setext_and_code_fences() {
+        let text = "Manual\n======\n\n```sh\n# not a heading\n```\n\nSection\n-------\n\nbody";
+        let offsets = [text.find("```").unwrap(), text.find("body").unwrap()];
+
+        assert_eq!(
+            heading_paths(text, &offsets),
+            vec![path(&["Manual"]), path(&["Manual", "Section"])]
+        );
+    }
+}
@@ -10,6 +10,7 @@
 //! - **Configurable overlap**: Overlap chunks to maintain context
 //! - **Unicode support**: Handles CJK characters and emojis correctly
 //! - **Batch processing**: Process multiple texts efficiently
+//! - **Streaming**: `chunk_text_streaming` yields chunks one at a time for large documents
 //!
 //! # Chunker Types
 //!
@@ -55,13 +56,14 @@ pub mod boundaries;
 mod builder;
 pub mod config;
 pub mod core;
+mod headings;
 pub mod processor;
 pub mod validation;
 
 // Re-export submodule types and functions
 pub use boundaries::{calculate_page_range, validate_page_boundaries};
-pub use config::{ChunkerType, ChunkingConfig, ChunkingResult}; // ChunkingConfig re-exported from core::config::processing
-pub use core::{chunk_text, chunk_text_with_type, chunk_texts_batch};
+pub use config::{ChunkerType, ChunkingConfig, ChunkingResult, OverlapUnit}; // ChunkingConfig re-exported from core::config::processing
+pub use core::{chunk_text, chunk_text_streaming, chunk_text_with_type, chunk_texts_batch};
 pub use processor::ChunkingProcessor;
 pub use validation::{ADAPTIVE_VALIDATION_THRESHOLD, precompute_utf8_boundaries, validate_utf8_boundaries};
 
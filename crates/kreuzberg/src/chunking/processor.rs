@@ -57,6 +57,12 @@ impl PostProcessor for ChunkingProcessor {
         let chunking_result = crate::chunking::chunk_text(&result.content, chunking_config, None)
             .map_err(|e| KreuzbergError::Other(format!("Chunking failed: {}", e)))?;
         result.chunks = Some(chunking_result.chunks);
+        if chunking_result.truncated {
+            result.metadata.additional.insert(
+                std::borrow::Cow::Borrowed("chunks_truncated"),
+                serde_json::Value::Bool(true),
+            );
+        }
 
         Ok(())
     }
@@ -93,6 +99,9 @@ mod tests {
                 chunker_type: crate::chunking::ChunkerType::Text,
                 embedding: None,
                 preset: None,
+                overlap_unit: crate::chunking::OverlapUnit::Characters,
+                max_chunks: None,
+                balance: false,
             }),
             ..Default::default()
         };
@@ -110,6 +119,11 @@ mod tests {
 	            elements: None,
 	            ocr_elements: None,
 	            document: None,
+	            outline: None,
+	            formulas: None,
+	            text_blocks: None,
+	            source_map: None,
+	            references: None,
 	        };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -137,6 +151,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -176,6 +195,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let config_with_chunking = ExtractionConfig {
@@ -186,6 +210,9 @@ mod tests {
                 chunker_type: crate::chunking::ChunkerType::Text,
                 embedding: None,
                 preset: None,
+                overlap_unit: crate::chunking::OverlapUnit::Characters,
+                max_chunks: None,
+                balance: false,
             }),
             ..Default::default()
         };
@@ -212,6 +239,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let long_result = ExtractionResult {
@@ -227,6 +259,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -0,0 +1,62 @@
+//! Archive configuration.
+//!
+//! Defines how ZIP archives of documents are extracted: whether their members are
+//! combined into one result or returned separately, and the password for encrypted
+//! archives.
+
+use serde::{Deserialize, Serialize};
+
+/// ZIP archive extraction configuration.
+///
+/// When set, every member of a ZIP archive is extracted with the extractor for its
+/// format, instead of only listing the archive and including the contents of plain
+/// text members. Nested ZIP archives are extracted too, up to
+/// `security_limits.max_nesting_depth` levels; other nested archives are listed but not
+/// extracted. The total uncompressed size and number of entries are bounded by
+/// `ExtractionConfig.security_limits`.
+#[cfg(feature = "archives")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchiveConfig {
+    /// How member results are returned
+    #[serde(default)]
+    pub mode: ArchiveMode,
+
+    /// Password for encrypted members (ZipCrypto or AES)
+    ///
+    /// Extracting an encrypted member without a password, or with the wrong one, is a
+    /// parsing error.
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+/// How the results of an archive's members are returned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveMode {
+    /// Merge all members into one result, in archive order.
+    ///
+    /// Content is joined with blank lines; tables, images and pages are concatenated and
+    /// page numbers continue across members.
+    #[default]
+    Combined,
+    /// Keep each member's result separate.
+    ///
+    /// The archive result lists the members in its content, and the full member results
+    /// are returned in `metadata.additional["documents"]`.
+    Separate,
+}
+
+#[cfg(all(test, feature = "archives"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive_config_deserialize() {
+        let config: ArchiveConfig = serde_json::from_str(r#"{"mode": "separate", "password": "secret"}"#).unwrap();
+        assert_eq!(config.mode, ArchiveMode::Separate);
+        assert_eq!(config.password.as_deref(), Some("secret"));
+
+        let config: ArchiveConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, ArchiveConfig::default());
+    }
+}
@@ -0,0 +1,37 @@
+//! Word document configuration.
+//!
+//! Defines options for DOCX extraction, such as estimating page boundaries that
+//! Word only determines when rendering the document.
+
+#[cfg(feature = "office")]
+use serde::{Deserialize, Serialize};
+
+/// DOCX extraction configuration.
+#[cfg(feature = "office")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocxConfig {
+    /// Estimate page boundaries from content length and explicit page breaks
+    ///
+    /// DOCX files only store explicit page breaks; all other breaks depend on how Word
+    /// lays out the document. When enabled, `metadata.pages.boundaries` and table page
+    /// numbers are derived from an approximate page size, calibrated against the page
+    /// count Word last saved when available, and
+    /// `metadata.additional["page_boundaries_estimated"]` is set to `true`. The
+    /// result is a rough page reference, not Word's pagination.
+    #[serde(default)]
+    pub estimate_pages: bool,
+}
+
+#[cfg(all(test, feature = "office"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_docx_config_deserialize() {
+        let config: DocxConfig = serde_json::from_str(r#"{"estimate_pages": true}"#).unwrap();
+        assert!(config.estimate_pages);
+
+        let config: DocxConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, DocxConfig::default());
+    }
+}
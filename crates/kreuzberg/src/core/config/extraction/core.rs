@@ -5,12 +5,25 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::super::formats::OutputFormat;
+use super::super::formats::{LineEnding, OutputFormat, OutputMode};
 use super::super::ocr::OcrConfig;
 use super::super::page::PageConfig;
 use super::super::processing::{ChunkingConfig, PostProcessorConfig};
+use super::super::tables::TableExtractionConfig;
 use super::types::{ImageExtractionConfig, LanguageDetectionConfig, TokenReductionConfig};
 
+/// Default limit on how deeply HTML elements and archives may nest.
+#[cfg(any(feature = "html", feature = "archives"))]
+pub(crate) const DEFAULT_MAX_NESTING_DEPTH: usize = 100;
+
+/// Default limit on the uncompressed size of archives and zipped bundles (500 MB).
+#[cfg(any(feature = "office", feature = "archives"))]
+pub(crate) const DEFAULT_MAX_ARCHIVE_SIZE: usize = 500 * 1024 * 1024;
+
+/// Default limit on the content decompressed from a single document (100 MB).
+#[cfg(any(feature = "einvoice", feature = "archives"))]
+pub(crate) const DEFAULT_MAX_CONTENT_SIZE: usize = 100 * 1024 * 1024;
+
 /// Main extraction configuration.
 ///
 /// This struct contains all configuration options for the extraction process.
@@ -30,7 +43,10 @@ use super::types::{ImageExtractionConfig, LanguageDetectionConfig, TokenReductio
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractionConfig {
     /// Enable caching of extraction results
-    #[serde(default = "default_true")]
+    ///
+    /// Always `false` when the `KREUZBERG_NO_CACHE` environment variable is set,
+    /// whatever the config says.
+    #[serde(default = "default_use_cache", deserialize_with = "deserialize_use_cache")]
     pub use_cache: bool,
 
     /// Enable quality post-processing
@@ -58,6 +74,30 @@ pub struct ExtractionConfig {
     #[serde(default)]
     pub pdf_options: Option<super::super::pdf::PdfConfig>,
 
+    /// Spreadsheet-specific options (None = use defaults)
+    #[cfg(feature = "excel")]
+    #[serde(default)]
+    pub xlsx_options: Option<super::super::xlsx::XlsxConfig>,
+
+    /// Word document options (None = use defaults)
+    #[cfg(feature = "office")]
+    #[serde(default)]
+    pub docx_options: Option<super::super::docx::DocxConfig>,
+
+    /// Jupyter notebook options (None = include all cell outputs)
+    #[cfg(feature = "office")]
+    #[serde(default)]
+    pub jupyter_options: Option<super::super::jupyter::JupyterConfig>,
+
+    /// ZIP archive options (None = list the archive and include plain text members)
+    #[cfg(feature = "archives")]
+    #[serde(default)]
+    pub archive_options: Option<super::super::archive::ArchiveConfig>,
+
+    /// Table cleanup options (None = tables are returned as detected)
+    #[serde(default)]
+    pub table_options: Option<TableExtractionConfig>,
+
     /// Token reduction configuration (None = no token reduction)
     #[serde(default)]
     pub token_reduction: Option<TokenReductionConfig>,
@@ -87,11 +127,14 @@ pub struct ExtractionConfig {
     #[serde(default)]
     pub html_options: Option<html_to_markdown_rs::ConversionOptions>,
 
-    /// Maximum concurrent extractions in batch operations (None = num_cpus * 2).
+    /// Maximum concurrent extractions in batch operations (None = number of CPU cores).
     ///
-    /// Limits parallelism to prevent resource exhaustion when processing
-    /// large batches. Defaults to twice the number of CPU cores.
-    #[serde(default)]
+    /// The async batch functions hold a semaphore permit per document, which bounds
+    /// memory use and the number of OCR processes running at once. OCR inside a single
+    /// document (e.g. Tesseract's own threads) is not covered, so with OCR-heavy batches
+    /// the effective thread count is up to this limit times the per-document OCR threads.
+    /// Also accepted as `max_concurrent` in configuration files.
+    #[serde(default, alias = "max_concurrent")]
     pub max_concurrent_extractions: Option<usize>,
 
     /// Result structure format
@@ -125,6 +168,14 @@ pub struct ExtractionConfig {
     #[serde(default)]
     pub output_format: OutputFormat,
 
+    /// Markdown decoration of extractor output (default: Markdown).
+    ///
+    /// With `PlainText`, extractors that render Markdown (HTML, DOCX) strip heading
+    /// markers, emphasis, link syntax, list markers and table pipes from `content`.
+    /// Tables keep their Markdown rendering in `tables`.
+    #[serde(default)]
+    pub output_mode: OutputMode,
+
     /// Enable structured document tree output.
     ///
     /// When true, populates the `document` field on `ExtractionResult` with a
@@ -134,12 +185,100 @@ pub struct ExtractionConfig {
     /// Independent of `result_format` — can be combined with Unified or ElementBased.
     #[serde(default)]
     pub include_document_structure: bool,
+
+    /// Maximum number of characters kept in the extracted content (None = unlimited).
+    ///
+    /// Content past the limit is dropped before post-processing, preferring a
+    /// paragraph break shortly before the limit. `metadata.truncated` is set
+    /// when truncation occurs.
+    #[serde(default)]
+    pub max_content_chars: Option<usize>,
+
+    /// Keep list item markers in the extracted content (default: true).
+    ///
+    /// When true, Markdown and DOCX list items keep their `- ` / `1. ` prefixes and
+    /// nested items are indented two spaces per level. When false, each item is
+    /// emitted on its own line without a marker.
+    #[serde(default = "default_true")]
+    pub preserve_list_markers: bool,
+
+    /// Populate `ExtractionResult.outline` with the document outline (default: false).
+    ///
+    /// PDFs provide the outline from their bookmarks, DOCX documents from their
+    /// heading styles. A PDF outline that cannot be read is recorded as `outline_error`
+    /// in the metadata. Other formats leave the outline unset.
+    #[serde(default)]
+    pub extract_outline: bool,
+
+    /// Populate `ExtractionResult.references` with the entries of the references section
+    /// (default: false).
+    ///
+    /// Requires the `references` feature. Meant for academic papers: the section is found by
+    /// its heading ("References", "Bibliography", ...) and each entry is parsed heuristically
+    /// into authors, title, year and DOI. Runs on the extracted content of any format.
+    #[serde(default)]
+    pub extract_references: bool,
+
+    /// Reorder right-to-left text from visual into logical order (default: false).
+    ///
+    /// Some sources, PDFs in particular, store Arabic and Hebrew text in display order,
+    /// so it extracts reversed. When set, every line containing right-to-left characters
+    /// is reordered with the Unicode Bidirectional Algorithm, keeping embedded numbers and
+    /// Latin runs readable. Lines without right-to-left characters are left unchanged.
+    /// Only enable it for sources that are known to store visual order.
+    #[serde(default)]
+    pub normalize_bidi: bool,
+
+    /// Keep image alt text and `aria-label`s in HTML content (default: true).
+    ///
+    /// Images with alt text are followed by `[alt: ...]` (Markdown and Djot output keep
+    /// alt text in their image syntax instead), and links, buttons and form controls with
+    /// an `aria-label` get `[aria-label: ...]` unless their text already matches it.
+    #[serde(default = "default_true")]
+    pub html_include_alt_text: bool,
+
+    /// Populate `ExtractionResult.text_blocks` with positioned text (default: false).
+    ///
+    /// PDFs provide blocks from their text layer; OCR results derive them from the
+    /// recognized OCR elements, and PDFs whose text came from OCR get no text-layer
+    /// blocks. A failure is recorded as `text_blocks_error` in the metadata. Other
+    /// formats leave the blocks unset.
+    #[serde(default)]
+    pub emit_positions: bool,
+
+    /// Populate `ExtractionResult.source_map` with content ranges mapped to source
+    /// positions (default: false).
+    ///
+    /// PDFs map lines of their text layer to page character indices; HTML maps text nodes
+    /// to byte offsets in the document. Positions are approximate, and text that post-
+    /// processing rewrites beyond recognition is not mapped. PDFs whose text came from OCR
+    /// get no map, and a failure is recorded as `source_map_error` in the metadata. Other
+    /// formats leave the map unset.
+    #[serde(default)]
+    pub emit_source_offsets: bool,
+
+    /// Reduce peak memory when post-processor plugins run (default: false).
+    ///
+    /// Language bindings hand plugins a copy of the `ExtractionResult`. With this flag set
+    /// they convert the result without keeping a second full copy alive and apply the
+    /// plugin's changes in place, at the cost of leaving the result partially updated if
+    /// the plugin's output cannot be applied.
+    #[serde(default)]
+    pub low_memory: bool,
+
+    /// Line ending applied to the extracted text as the final pipeline step (None = unchanged).
+    ///
+    /// Rewrites `\r\n`, `\n` and lone `\r` in `content`, page contents and chunk contents
+    /// to the chosen ending, shifting page boundaries and chunk byte offsets to match.
+    /// `Keep` behaves like `None`.
+    #[serde(default)]
+    pub normalize_newlines: Option<LineEnding>,
 }
 
 impl Default for ExtractionConfig {
     fn default() -> Self {
         Self {
-            use_cache: true,
+            use_cache: default_use_cache(),
             enable_quality_processing: true,
             ocr: None,
             force_ocr: false,
@@ -147,6 +286,15 @@ impl Default for ExtractionConfig {
             images: None,
             #[cfg(feature = "pdf")]
             pdf_options: None,
+            #[cfg(feature = "excel")]
+            xlsx_options: None,
+            #[cfg(feature = "office")]
+            docx_options: None,
+            #[cfg(feature = "office")]
+            jupyter_options: None,
+            #[cfg(feature = "archives")]
+            archive_options: None,
+            table_options: None,
             token_reduction: None,
             language_detection: None,
             pages: None,
@@ -160,7 +308,18 @@ impl Default for ExtractionConfig {
             security_limits: None,
             result_format: crate::types::OutputFormat::Unified,
             output_format: OutputFormat::Plain,
+            output_mode: OutputMode::Markdown,
             include_document_structure: false,
+            max_content_chars: None,
+            preserve_list_markers: true,
+            extract_outline: false,
+            extract_references: false,
+            normalize_bidi: false,
+            html_include_alt_text: true,
+            emit_positions: false,
+            emit_source_offsets: false,
+            low_memory: false,
+            normalize_newlines: None,
         }
     }
 }
@@ -184,8 +343,328 @@ impl ExtractionConfig {
 
         ocr_enabled || image_extraction_enabled
     }
+
+    /// Maximum nesting depth accepted for HTML elements and archives within archives.
+    ///
+    /// Taken from `security_limits.max_nesting_depth`; without security limits (or
+    /// without the `archives` feature, which provides them) the default applies.
+    #[cfg(feature = "html")]
+    pub(crate) fn max_nesting_depth(&self) -> usize {
+        #[cfg(feature = "archives")]
+        if let Some(limits) = self.security_limits.as_ref() {
+            return limits.max_nesting_depth;
+        }
+        DEFAULT_MAX_NESTING_DEPTH
+    }
+
+    /// Largest width or height an image may have to be decoded.
+    ///
+    /// Taken from `images.max_decode_dimension`, so the limit also applies when image
+    /// extraction is not configured; 0 means no limit.
+    #[cfg(any(feature = "pdf", feature = "ocr"))]
+    pub(crate) fn max_decode_dimension(&self) -> u32 {
+        self.images
+            .as_ref()
+            .map_or(super::DEFAULT_MAX_DECODE_DIMENSION, |images| {
+                images.max_decode_dimension
+            })
+    }
+
+    /// Maximum uncompressed size accepted for archives and zipped bundles.
+    ///
+    /// Taken from `security_limits.max_archive_size`; without security limits (or
+    /// without the `archives` feature, which provides them) the default applies.
+    #[cfg(feature = "office")]
+    pub(crate) fn max_archive_size(&self) -> usize {
+        #[cfg(feature = "archives")]
+        if let Some(limits) = self.security_limits.as_ref() {
+            return limits.max_archive_size;
+        }
+        DEFAULT_MAX_ARCHIVE_SIZE
+    }
+
+    /// Maximum size of content decompressed from a single document, such as a PDF attachment.
+    ///
+    /// Taken from `security_limits.max_content_size`; without security limits (or
+    /// without the `archives` feature, which provides them) the default applies.
+    #[cfg(feature = "einvoice")]
+    pub(crate) fn max_content_size(&self) -> usize {
+        #[cfg(feature = "archives")]
+        if let Some(limits) = self.security_limits.as_ref() {
+            return limits.max_content_size;
+        }
+        DEFAULT_MAX_CONTENT_SIZE
+    }
+
+    /// Validate field values that deserialization alone cannot check.
+    ///
+    /// Runs the checks in [`config_validation`](crate::core::config_validation) against
+    /// every configured section (OCR backend and languages, DPI ranges, chunk sizes,
+    /// page ranges, thresholds) and reports all problems at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` whose message lists each problem on its own
+    /// line, prefixed with the offending field path.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kreuzberg::ExtractionConfig;
+    ///
+    /// let mut config = ExtractionConfig::default();
+    /// assert!(config.validate().is_ok());
+    ///
+    /// config.max_concurrent_extractions = Some(0);
+    /// assert!(config.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> crate::Result<()> {
+        let problems = self.validation_problems();
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::KreuzbergError::validation(problems.join("\n")))
+        }
+    }
+
+    /// Collect every validation problem, one `"field.path: message"` entry each.
+    ///
+    /// Returns an empty vector for a valid config. See [`ExtractionConfig::validate`].
+    pub fn validation_problems(&self) -> Vec<String> {
+        use crate::core::config_validation::*;
+
+        let mut problems = Vec::new();
+        let mut check = |field: &str, result: crate::Result<()>| {
+            if let Err(e) = result {
+                let message = match e {
+                    crate::KreuzbergError::Validation { message, .. } => message,
+                    other => other.to_string(),
+                };
+                problems.push(format!("{}: {}", field, message));
+            }
+        };
+
+        if let Some(ocr) = &self.ocr {
+            check("ocr.backend", validate_ocr_backend(&ocr.backend));
+            for language in ocr.language.split('+') {
+                check("ocr.language", validate_language_code(language.trim()));
+            }
+            if let Some(tesseract) = &ocr.tesseract_config {
+                check("ocr.tesseract_config.psm", validate_tesseract_psm(tesseract.psm));
+                check("ocr.tesseract_config.oem", validate_tesseract_oem(tesseract.oem));
+            }
+            if let Some(dpi) = ocr.render_dpi {
+                check("ocr.render_dpi", validate_dpi(dpi));
+            }
+            if let Some(confidence) = ocr.empty_page_confidence {
+                check("ocr.empty_page_confidence", validate_confidence(confidence));
+            }
+            if let Some(page_filter) = &ocr.page_filter {
+                check("ocr.page_filter", validate_page_filter(page_filter));
+            }
+            if let Some(region) = &ocr.region {
+                check("ocr.region", validate_ocr_region(region));
+            }
+        }
+
+        if let Some(chunking) = &self.chunking {
+            let result = match chunking.overlap_unit {
+                super::super::processing::OverlapUnit::Characters => {
+                    validate_chunking_params(chunking.max_characters, chunking.overlap)
+                }
+                _ => validate_chunking_params(chunking.max_characters, 0),
+            };
+            check("chunking", result);
+            if chunking.max_chunks == Some(0) {
+                check(
+                    "chunking.max_chunks",
+                    Err(crate::KreuzbergError::validation("must be greater than 0")),
+                );
+            }
+        }
+
+        if let Some(images) = &self.images {
+            check("images.target_dpi", validate_dpi(images.target_dpi));
+            check("images.min_dpi", validate_dpi(images.min_dpi));
+            check("images.max_dpi", validate_dpi(images.max_dpi));
+            if images.min_dpi > images.max_dpi {
+                check(
+                    "images",
+                    Err(crate::KreuzbergError::validation(format!(
+                        "min_dpi ({}) must not exceed max_dpi ({})",
+                        images.min_dpi, images.max_dpi
+                    ))),
+                );
+            }
+            if images.max_image_dimension <= 0 {
+                check(
+                    "images.max_image_dimension",
+                    Err(crate::KreuzbergError::validation(
+                        "max_image_dimension must be greater than 0",
+                    )),
+                );
+            }
+        }
+
+        #[cfg(feature = "pdf")]
+        if let Some((start, end)) = self.pdf_options.as_ref().and_then(|pdf| pdf.page_range)
+            && (start == 0 || start > end)
+        {
+            check(
+                "pdf_options.page_range",
+                Err(crate::KreuzbergError::validation(format!(
+                    "page range ({}, {}) must be 1-indexed with start <= end",
+                    start, end
+                ))),
+            );
+        }
+
+        if let Some(token_reduction) = &self.token_reduction {
+            check(
+                "token_reduction.mode",
+                validate_token_reduction_level(&token_reduction.mode),
+            );
+        }
+
+        if let Some(language_detection) = &self.language_detection {
+            check(
+                "language_detection.min_confidence",
+                validate_confidence(language_detection.min_confidence),
+            );
+        }
+
+        if let Some(postprocessor) = &self.postprocessor
+            && !(0.0..=1.0).contains(&postprocessor.header_footer_threshold)
+        {
+            check(
+                "postprocessor.header_footer_threshold",
+                Err(crate::KreuzbergError::validation(format!(
+                    "threshold {} must be between 0.0 and 1.0",
+                    postprocessor.header_footer_threshold
+                ))),
+            );
+        }
+
+        if self.max_concurrent_extractions == Some(0) {
+            check(
+                "max_concurrent_extractions",
+                Err(crate::KreuzbergError::validation("must be greater than 0")),
+            );
+        }
+
+        problems
+    }
+
+    /// Layer `other` on top of this config (shallow merge).
+    ///
+    /// Scalar flags are always taken from `other`. Optional sections are replaced
+    /// wholesale when set in `other` and kept otherwise; nested sections are not merged
+    /// field by field. This is the merge used by the FFI `kreuzberg_config_merge` and
+    /// the `kreuzberg config merge` CLI command.
+    pub fn merge(&mut self, other: &ExtractionConfig) {
+        self.use_cache = other.use_cache;
+        self.enable_quality_processing = other.enable_quality_processing;
+        self.force_ocr = other.force_ocr;
+        self.preserve_list_markers = other.preserve_list_markers;
+        self.extract_outline = other.extract_outline;
+        self.extract_references = other.extract_references;
+        self.normalize_bidi = other.normalize_bidi;
+        self.html_include_alt_text = other.html_include_alt_text;
+        self.emit_positions = other.emit_positions;
+        self.emit_source_offsets = other.emit_source_offsets;
+        self.low_memory = other.low_memory;
+        self.output_mode = other.output_mode;
+        self.max_concurrent_extractions = other.max_concurrent_extractions;
+
+        if other.ocr.is_some() {
+            self.ocr = other.ocr.clone();
+        }
+
+        if other.chunking.is_some() {
+            self.chunking = other.chunking.clone();
+        }
+
+        if other.images.is_some() {
+            self.images = other.images.clone();
+        }
+
+        #[cfg(feature = "pdf")]
+        if other.pdf_options.is_some() {
+            self.pdf_options = other.pdf_options.clone();
+        }
+
+        #[cfg(feature = "excel")]
+        if other.xlsx_options.is_some() {
+            self.xlsx_options = other.xlsx_options.clone();
+        }
+
+        #[cfg(feature = "office")]
+        if other.docx_options.is_some() {
+            self.docx_options = other.docx_options.clone();
+        }
+
+        #[cfg(feature = "office")]
+        if other.jupyter_options.is_some() {
+            self.jupyter_options = other.jupyter_options.clone();
+        }
+
+        #[cfg(feature = "archives")]
+        if other.archive_options.is_some() {
+            self.archive_options = other.archive_options.clone();
+        }
+
+        if other.table_options.is_some() {
+            self.table_options = other.table_options;
+        }
+
+        if other.token_reduction.is_some() {
+            self.token_reduction = other.token_reduction.clone();
+        }
+
+        if other.language_detection.is_some() {
+            self.language_detection = other.language_detection.clone();
+        }
+
+        if other.pages.is_some() {
+            self.pages = other.pages.clone();
+        }
+
+        #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+        if other.keywords.is_some() {
+            self.keywords = other.keywords.clone();
+        }
+
+        if other.postprocessor.is_some() {
+            self.postprocessor = other.postprocessor.clone();
+        }
+
+        #[cfg(feature = "html")]
+        if other.html_options.is_some() {
+            self.html_options = other.html_options.clone();
+        }
+
+        if other.max_content_chars.is_some() {
+            self.max_content_chars = other.max_content_chars;
+        }
+
+        if other.normalize_newlines.is_some() {
+            self.normalize_newlines = other.normalize_newlines;
+        }
+    }
 }
 
 fn default_true() -> bool {
     true
 }
+
+fn default_use_cache() -> bool {
+    !crate::cache::cache_disabled_by_env()
+}
+
+fn deserialize_use_cache<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let use_cache = bool::deserialize(deserializer)?;
+    Ok(use_cache && default_use_cache())
+}
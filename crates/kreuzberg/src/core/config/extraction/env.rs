@@ -21,6 +21,7 @@ impl ExtractionConfig {
     /// - `KREUZBERG_CHUNKING_MAX_CHARS`: Maximum characters per chunk (positive integer)
     /// - `KREUZBERG_CHUNKING_MAX_OVERLAP`: Maximum overlap between chunks (non-negative integer)
     /// - `KREUZBERG_CACHE_ENABLED`: Cache enabled flag ("true" or "false")
+    /// - `KREUZBERG_NO_CACHE`: Disables caching outright; beats both the config and `KREUZBERG_CACHE_ENABLED`
     /// - `KREUZBERG_TOKEN_REDUCTION_MODE`: Token reduction mode ("off", "light", "moderate", "aggressive", or "maximum")
     ///
     /// # Behavior
@@ -100,6 +101,9 @@ impl ExtractionConfig {
                     chunker_type: super::super::processing::ChunkerType::Text,
                     embedding: None,
                     preset: None,
+                    overlap_unit: super::super::processing::OverlapUnit::Characters,
+                    max_chunks: None,
+                    balance: false,
                 });
             }
 
@@ -128,6 +132,9 @@ impl ExtractionConfig {
                     chunker_type: super::super::processing::ChunkerType::Text,
                     embedding: None,
                     preset: None,
+                    overlap_unit: super::super::processing::OverlapUnit::Characters,
+                    max_chunks: None,
+                    balance: false,
                 });
             }
 
@@ -156,6 +163,11 @@ impl ExtractionConfig {
             self.use_cache = cache_enabled;
         }
 
+        // KREUZBERG_NO_CACHE wins over the config and KREUZBERG_CACHE_ENABLED
+        if crate::cache::cache_disabled_by_env() {
+            self.use_cache = false;
+        }
+
         // KREUZBERG_TOKEN_REDUCTION_MODE override
         if let Ok(mode) = std::env::var("KREUZBERG_TOKEN_REDUCTION_MODE") {
             validate_token_reduction_level(&mode)?;
@@ -163,6 +175,7 @@ impl ExtractionConfig {
                 self.token_reduction = Some(TokenReductionConfig {
                     mode: "off".to_string(),
                     preserve_important_words: true,
+                    language: None,
                 });
             }
             if let Some(ref mut token_reduction) = self.token_reduction {
@@ -184,12 +184,27 @@ impl ExtractionConfig {
     /// - `Some(config)` if found
     /// - `None` if no config file found
     pub fn discover() -> Result<Option<Self>> {
+        Ok(Self::discover_with_source()?.map(|(config, _)| config))
+    }
+
+    /// Discover configuration file in parent directories, along with its path.
+    ///
+    /// Searches like [`discover`](Self::discover), and also returns the path of the
+    /// `kreuzberg.toml` that was loaded, so callers can tell when a config file from a
+    /// parent directory was picked up.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((config, path))` if found
+    /// - `None` if no config file found
+    pub fn discover_with_source() -> Result<Option<(Self, PathBuf)>> {
         let mut current = std::env::current_dir().map_err(KreuzbergError::Io)?;
 
         loop {
             let kreuzberg_toml = current.join("kreuzberg.toml");
             if kreuzberg_toml.exists() {
-                return Ok(Some(Self::from_toml_file(kreuzberg_toml)?));
+                let config = Self::from_toml_file(&kreuzberg_toml)?;
+                return Ok(Some((config, kreuzberg_toml)));
             }
 
             if let Some(parent) = current.parent() {
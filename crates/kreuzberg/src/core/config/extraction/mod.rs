@@ -15,8 +15,16 @@ mod loaders;
 mod types;
 
 // Re-export all public types for backward compatibility
+#[cfg(any(feature = "office", feature = "archives"))]
+pub(crate) use self::core::DEFAULT_MAX_ARCHIVE_SIZE;
+#[cfg(any(feature = "einvoice", feature = "archives"))]
+pub(crate) use self::core::DEFAULT_MAX_CONTENT_SIZE;
+#[cfg(any(feature = "html", feature = "archives"))]
+pub(crate) use self::core::DEFAULT_MAX_NESTING_DEPTH;
 pub use self::core::ExtractionConfig;
-pub use self::types::{ImageExtractionConfig, LanguageDetectionConfig, TokenReductionConfig};
+#[cfg(any(feature = "pdf", feature = "ocr"))]
+pub(crate) use self::types::DEFAULT_MAX_DECODE_DIMENSION;
+pub use self::types::{ImageExtractionConfig, ImageFormat, LanguageDetectionConfig, TokenReductionConfig};
 
 #[cfg(test)]
 mod tests {
@@ -31,6 +39,71 @@ mod tests {
         assert!(config.ocr.is_none());
     }
 
+    #[test]
+    fn test_image_extraction_limits() {
+        let mut config: ImageExtractionConfig = serde_json::from_str("{}").unwrap();
+        assert!(config.max_images.is_none());
+        assert!(config.max_total_image_bytes.is_none());
+        assert!(config.allows_image(10_000, usize::MAX - 1, 1));
+
+        config.max_images = Some(2);
+        assert!(config.allows_image(1, 0, 100));
+        assert!(!config.allows_image(2, 0, 100));
+
+        config.max_images = None;
+        config.max_total_image_bytes = Some(1000);
+        assert!(config.allows_image(3, 900, 100));
+        assert!(!config.allows_image(3, 900, 101));
+    }
+
+    #[test]
+    fn test_image_extraction_min_dimensions() {
+        let mut config: ImageExtractionConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!((config.min_width, config.min_height), (16, 16));
+        assert!(!config.meets_min_dimensions(1, 1));
+        assert!(!config.meets_min_dimensions(800, 15));
+        assert!(config.meets_min_dimensions(16, 16));
+
+        config.min_width = 0;
+        config.min_height = 0;
+        assert!(config.meets_min_dimensions(1, 1));
+    }
+
+    #[test]
+    fn test_image_extraction_decode_limit() {
+        let png_header = |width: u32, height: u32| {
+            let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+            png.extend_from_slice(b"IHDR");
+            png.extend_from_slice(&width.to_be_bytes());
+            png.extend_from_slice(&height.to_be_bytes());
+            png
+        };
+        let mut config: ImageExtractionConfig = serde_json::from_str("{}").unwrap();
+
+        // A 600 DPI A4 scan is larger than `max_image_dimension` but well within the decode limit.
+        assert!(config.check_decode_dimensions(&png_header(4960, 7016)).is_ok());
+        assert!(config.check_decode_dimensions(&png_header(60_000, 100)).is_err());
+
+        config.max_decode_dimension = 0;
+        assert!(config.check_decode_dimensions(&png_header(60_000, 100)).is_ok());
+    }
+
+    #[test]
+    fn test_image_format_parsing() {
+        let config: ImageExtractionConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.output_format, ImageFormat::Original);
+
+        let config: ImageExtractionConfig = serde_json::from_str(r#"{"output_format": "jpeg:70"}"#).unwrap();
+        assert_eq!(config.output_format, ImageFormat::Jpeg { quality: 70 });
+        assert_eq!(serde_json::to_value(config.output_format).unwrap(), "jpeg:70");
+
+        assert_eq!("JPG".parse::<ImageFormat>().unwrap(), ImageFormat::Jpeg { quality: 85 });
+        assert_eq!("webp".parse::<ImageFormat>().unwrap(), ImageFormat::Webp);
+        assert!("jpeg:0".parse::<ImageFormat>().is_err());
+        assert!("png:50".parse::<ImageFormat>().is_err());
+        assert!("gif".parse::<ImageFormat>().is_err());
+    }
+
     #[test]
     fn test_needs_image_processing() {
         let mut config = ExtractionConfig::default();
@@ -39,4 +112,85 @@ mod tests {
         config.ocr = Some(OcrConfig::default());
         assert!(config.needs_image_processing());
     }
+
+    #[test]
+    fn test_validate_default_config() {
+        assert!(ExtractionConfig::default().validate().is_ok());
+        assert!(ExtractionConfig::default().validation_problems().is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let config = ExtractionConfig {
+            ocr: Some(OcrConfig {
+                backend: "unknown".to_string(),
+                language: "eng+xx".to_string(),
+                ..Default::default()
+            }),
+            language_detection: Some(LanguageDetectionConfig {
+                enabled: true,
+                min_confidence: 1.5,
+                detect_multiple: false,
+            }),
+            max_concurrent_extractions: Some(0),
+            ..Default::default()
+        };
+
+        let problems = config.validation_problems();
+        assert_eq!(problems.len(), 4, "{:?}", problems);
+        assert!(problems[0].starts_with("ocr.backend: "));
+        assert!(problems[1].starts_with("ocr.language: "));
+        assert!(problems[1].contains("'xx'"));
+        assert!(problems[2].starts_with("language_detection.min_confidence: "));
+        assert_eq!(problems[3], "max_concurrent_extractions: must be greater than 0");
+
+        let err = config.validate().unwrap_err();
+        assert!(matches!(err, crate::KreuzbergError::Validation { .. }));
+        assert!(err.to_string().contains("ocr.backend"));
+    }
+
+    #[test]
+    fn test_max_concurrent_alias() {
+        let config: ExtractionConfig = serde_json::from_str(r#"{"max_concurrent": 4}"#).unwrap();
+        assert_eq!(config.max_concurrent_extractions, Some(4));
+    }
+
+    #[test]
+    fn test_validate_chunking_overlap() {
+        let config = ExtractionConfig {
+            chunking: Some(crate::core::config::ChunkingConfig {
+                max_characters: 100,
+                overlap: 100,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let problems = config.validation_problems();
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("chunking: "));
+    }
+
+    #[test]
+    fn test_merge_replaces_set_sections_only() {
+        let mut base = ExtractionConfig {
+            ocr: Some(OcrConfig {
+                language: "deu".to_string(),
+                ..Default::default()
+            }),
+            max_content_chars: Some(500),
+            ..Default::default()
+        };
+        let overrides = ExtractionConfig {
+            use_cache: false,
+            chunking: Some(crate::core::config::ChunkingConfig::default()),
+            ..Default::default()
+        };
+
+        base.merge(&overrides);
+
+        assert!(!base.use_cache);
+        assert_eq!(base.ocr.as_ref().unwrap().language, "deu");
+        assert!(base.chunking.is_some());
+        assert_eq!(base.max_content_chars, Some(500));
+    }
 }
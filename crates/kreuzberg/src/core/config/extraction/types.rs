@@ -6,6 +6,7 @@
 //! - Language detection
 
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Image extraction configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +23,14 @@ pub struct ImageExtractionConfig {
     #[serde(default = "default_max_dimension")]
     pub max_image_dimension: i32,
 
+    /// Largest width or height an image may declare before it is decoded (0 = no limit)
+    ///
+    /// Guards against decompression bombs: image files whose header declares larger
+    /// dimensions are rejected, and embedded images that large are left undecoded.
+    /// Unlike `max_image_dimension`, which images are scaled down to, this is a hard limit.
+    #[serde(default = "default_max_decode_dimension")]
+    pub max_decode_dimension: u32,
+
     /// Automatically adjust DPI based on image content
     #[serde(default = "default_true")]
     pub auto_adjust_dpi: bool,
@@ -33,6 +42,159 @@ pub struct ImageExtractionConfig {
     /// Maximum DPI threshold
     #[serde(default = "default_max_dpi")]
     pub max_dpi: i32,
+
+    /// Maximum number of images to extract per document (None = unlimited)
+    ///
+    /// When the limit is reached, extraction of further images stops and
+    /// `images_truncated` is set in the result metadata.
+    #[serde(default)]
+    pub max_images: Option<usize>,
+
+    /// Maximum total size in bytes of extracted image data per document (None = unlimited)
+    ///
+    /// The first image that would exceed the budget stops image extraction and
+    /// `images_truncated` is set in the result metadata.
+    #[serde(default)]
+    pub max_total_image_bytes: Option<usize>,
+
+    /// Minimum image width in pixels; narrower images are skipped (0 = no minimum)
+    ///
+    /// Filters out tracking pixels and small icons before they are counted against
+    /// the image limits or sent to OCR. Images whose dimensions are unknown are kept.
+    #[serde(default = "default_min_image_dimension")]
+    pub min_width: u32,
+
+    /// Minimum image height in pixels; shorter images are skipped (0 = no minimum)
+    #[serde(default = "default_min_image_dimension")]
+    pub min_height: u32,
+
+    /// Encoding for extracted images (default: keep the original bytes)
+    ///
+    /// Any other format re-encodes every extracted image and updates its `format`.
+    /// Serialized as `"original"`, `"png"`, `"webp"`, `"jpeg"` or `"jpeg:<quality>"`.
+    #[serde(default)]
+    pub output_format: ImageFormat,
+}
+
+/// Output encoding for extracted images.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum ImageFormat {
+    /// Keep the bytes as found in the document (default)
+    #[default]
+    Original,
+    /// Lossless PNG
+    Png,
+    /// JPEG with the given quality (1-100)
+    Jpeg { quality: u8 },
+    /// Lossless WebP
+    Webp,
+}
+
+/// JPEG quality used when none is given.
+const DEFAULT_JPEG_QUALITY: u8 = 85;
+
+impl ImageFormat {
+    /// File extension / format name recorded on re-encoded images, or `None` for `Original`.
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            ImageFormat::Original => None,
+            ImageFormat::Png => Some("png"),
+            ImageFormat::Jpeg { .. } => Some("jpeg"),
+            ImageFormat::Webp => Some("webp"),
+        }
+    }
+}
+
+impl std::fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageFormat::Original => write!(f, "original"),
+            ImageFormat::Png => write!(f, "png"),
+            ImageFormat::Jpeg { quality } => write!(f, "jpeg:{}", quality),
+            ImageFormat::Webp => write!(f, "webp"),
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lower = s.trim().to_lowercase();
+        let (name, quality) = match lower.split_once(':') {
+            Some((name, quality)) => (name, Some(quality)),
+            None => (lower.as_str(), None),
+        };
+
+        match (name, quality) {
+            ("original", None) => Ok(ImageFormat::Original),
+            ("png", None) => Ok(ImageFormat::Png),
+            ("webp", None) => Ok(ImageFormat::Webp),
+            ("jpeg" | "jpg", None) => Ok(ImageFormat::Jpeg {
+                quality: DEFAULT_JPEG_QUALITY,
+            }),
+            ("jpeg" | "jpg", Some(quality)) => match quality.parse::<u8>() {
+                Ok(quality @ 1..=100) => Ok(ImageFormat::Jpeg { quality }),
+                _ => Err(format!(
+                    "Invalid JPEG quality '{}' in image format '{}': must be between 1 and 100",
+                    quality, s
+                )),
+            },
+            _ => Err(format!(
+                "Invalid image format: '{}'. Valid formats: original, png, webp, jpeg, jpeg:<quality>",
+                s
+            )),
+        }
+    }
+}
+
+impl From<ImageFormat> for String {
+    fn from(format: ImageFormat) -> Self {
+        format.to_string()
+    }
+}
+
+impl TryFrom<String> for ImageFormat {
+    type Error = String;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl ImageExtractionConfig {
+    /// Check whether another image fits within the configured image limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `extracted_count` - Number of images already extracted
+    /// * `extracted_bytes` - Total bytes of images already extracted
+    /// * `image_bytes` - Size of the candidate image in bytes
+    pub fn allows_image(&self, extracted_count: usize, extracted_bytes: usize, image_bytes: usize) -> bool {
+        if self.max_images.is_some_and(|max| extracted_count >= max) {
+            return false;
+        }
+
+        self.max_total_image_bytes
+            .is_none_or(|max| extracted_bytes.saturating_add(image_bytes) <= max)
+    }
+
+    /// Check whether an image of the given pixel dimensions meets `min_width` and `min_height`.
+    pub fn meets_min_dimensions(&self, width: u32, height: u32) -> bool {
+        width >= self.min_width && height >= self.min_height
+    }
+
+    /// Reject encoded image data whose declared dimensions exceed `max_decode_dimension`.
+    ///
+    /// Reads only the image header, so it is safe to call before decoding untrusted
+    /// images. A `max_decode_dimension` of 0 disables the check.
+    pub fn check_decode_dimensions(&self, data: &[u8]) -> crate::Result<()> {
+        if self.max_decode_dimension == 0 {
+            return Ok(());
+        }
+        crate::extraction::image_header::check_image_dimensions(data, self.max_decode_dimension)
+    }
 }
 
 /// Token reduction configuration.
@@ -45,6 +207,13 @@ pub struct TokenReductionConfig {
     /// Preserve important words (capitalized, technical terms)
     #[serde(default = "default_true")]
     pub preserve_important_words: bool,
+
+    /// Language of the stopword list, e.g. "de" or "deu" (None = auto).
+    ///
+    /// When unset, the first entry of `detected_languages` is used if language
+    /// detection is enabled, falling back to English.
+    #[serde(default)]
+    pub language: Option<String>,
 }
 
 /// Language detection configuration.
@@ -76,6 +245,17 @@ fn default_max_dimension() -> i32 {
     4096
 }
 
+/// Default for `ImageExtractionConfig::max_decode_dimension`.
+pub(crate) const DEFAULT_MAX_DECODE_DIMENSION: u32 = 32768;
+
+fn default_max_decode_dimension() -> u32 {
+    DEFAULT_MAX_DECODE_DIMENSION
+}
+
+fn default_min_image_dimension() -> u32 {
+    16
+}
+
 fn default_min_dpi() -> i32 {
     72
 }
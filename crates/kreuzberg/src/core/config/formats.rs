@@ -1,8 +1,9 @@
 //! Output format configuration and validation.
 //!
 //! This module defines the `OutputFormat` enum for controlling how extraction
-//! results are formatted (plain text, markdown, HTML, etc.) and provides
-//! serialization/deserialization support.
+//! results are formatted (plain text, markdown, HTML, etc.), the `OutputMode`
+//! toggle for Markdown decoration and the `LineEnding` used for newline
+//! normalization, and provides serialization/deserialization support.
 
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -71,6 +72,84 @@ impl FromStr for OutputFormat {
     }
 }
 
+/// Whether Markdown-producing extractors keep their Markdown decoration.
+///
+/// Extractors such as HTML and DOCX render their content as Markdown. In
+/// `PlainText` mode they emit the same text without heading markers, emphasis,
+/// link syntax, list markers or table pipes, which suits NLP pipelines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputMode {
+    /// Keep Markdown decoration (default)
+    #[default]
+    Markdown,
+    /// Strip Markdown decoration and emit clean prose
+    PlainText,
+}
+
+impl std::fmt::Display for OutputMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputMode::Markdown => write!(f, "markdown"),
+            OutputMode::PlainText => write!(f, "plain_text"),
+        }
+    }
+}
+
+impl FromStr for OutputMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "markdown" | "md" => Ok(OutputMode::Markdown),
+            "plain_text" | "plaintext" | "plain" | "text" => Ok(OutputMode::PlainText),
+            _ => Err(format!(
+                "Invalid output mode: '{}'. Valid modes: markdown, plain_text",
+                s
+            )),
+        }
+    }
+}
+
+/// Line ending applied to extracted text by `ExtractionConfig::normalize_newlines`.
+///
+/// Unrelated to the HTML converter's `NewlineStyle`, which controls how Markdown
+/// hard line breaks are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEnding {
+    /// Leave line endings as extracted (default)
+    #[default]
+    Keep,
+    /// Unix line endings (`\n`)
+    Lf,
+    /// Windows line endings (`\r\n`)
+    CrLf,
+}
+
+impl std::fmt::Display for LineEnding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineEnding::Keep => write!(f, "keep"),
+            LineEnding::Lf => write!(f, "lf"),
+            LineEnding::CrLf => write!(f, "crlf"),
+        }
+    }
+}
+
+impl FromStr for LineEnding {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_'], "").as_str() {
+            "keep" => Ok(LineEnding::Keep),
+            "lf" | "unix" => Ok(LineEnding::Lf),
+            "crlf" | "windows" => Ok(LineEnding::CrLf),
+            _ => Err(format!("Invalid line ending: '{}'. Valid values: lf, crlf, keep", s)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +242,28 @@ mod tests {
             "\"structured\""
         );
     }
+
+    #[test]
+    fn test_output_mode_parsing_and_serde() {
+        assert_eq!(OutputMode::default(), OutputMode::Markdown);
+        assert_eq!("plain_text".parse::<OutputMode>().unwrap(), OutputMode::PlainText);
+        assert_eq!("Plain-Text".parse::<OutputMode>().unwrap(), OutputMode::PlainText);
+        assert_eq!("markdown".parse::<OutputMode>().unwrap(), OutputMode::Markdown);
+        assert!("html".parse::<OutputMode>().is_err());
+
+        assert_eq!(serde_json::to_string(&OutputMode::PlainText).unwrap(), "\"plain_text\"");
+        for mode in [OutputMode::Markdown, OutputMode::PlainText] {
+            assert_eq!(mode.to_string().parse::<OutputMode>().unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn test_line_ending_from_str_and_display() {
+        assert_eq!("LF".parse::<LineEnding>().unwrap(), LineEnding::Lf);
+        assert_eq!("cr-lf".parse::<LineEnding>().unwrap(), LineEnding::CrLf);
+        assert_eq!("keep".parse::<LineEnding>().unwrap(), LineEnding::Keep);
+        assert!("cr".parse::<LineEnding>().is_err());
+        assert_eq!(LineEnding::CrLf.to_string(), "crlf");
+        assert_eq!(serde_json::to_string(&LineEnding::CrLf).unwrap(), "\"crlf\"");
+    }
 }
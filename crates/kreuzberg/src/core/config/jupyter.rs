@@ -0,0 +1,42 @@
+//! Jupyter notebook configuration.
+//!
+//! Defines which cell outputs are kept when extracting `.ipynb` notebooks.
+
+use serde::{Deserialize, Serialize};
+
+/// Jupyter notebook extraction configuration.
+#[cfg(feature = "office")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JupyterConfig {
+    /// Which outputs of code cells are included in the content
+    #[serde(default)]
+    pub outputs: NotebookOutputs,
+}
+
+/// Which outputs of notebook code cells are included in the content.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotebookOutputs {
+    /// Every output, with each representation of rich outputs labelled by MIME type.
+    #[default]
+    All,
+    /// Text only: stream output, errors, and the `text/plain` representation of rich
+    /// outputs. HTML, images and other representations are dropped.
+    Text,
+    /// No outputs; only the markdown and code of the cells.
+    None,
+}
+
+#[cfg(all(test, feature = "office"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jupyter_config_deserialize() {
+        let config: JupyterConfig = serde_json::from_str(r#"{"outputs": "text"}"#).unwrap();
+        assert_eq!(config.outputs, NotebookOutputs::Text);
+
+        let config: JupyterConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, JupyterConfig::default());
+    }
+}
@@ -3,18 +3,38 @@
 //! This module provides utilities for loading extraction configuration from various
 //! sources (TOML, YAML, JSON) and discovering configuration files in the project hierarchy.
 
+pub mod archive;
+pub mod docx;
 pub mod extraction;
 pub mod formats;
+pub mod jupyter;
 pub mod ocr;
 pub mod page;
 pub mod pdf;
 pub mod processing;
+pub mod tables;
+pub mod xlsx;
 
 // Re-export main types for backward compatibility
-pub use extraction::{ExtractionConfig, ImageExtractionConfig, LanguageDetectionConfig, TokenReductionConfig};
-pub use formats::OutputFormat;
-pub use ocr::OcrConfig;
+#[cfg(feature = "archives")]
+pub use archive::{ArchiveConfig, ArchiveMode};
+#[cfg(feature = "office")]
+pub use docx::DocxConfig;
+pub use extraction::{
+    ExtractionConfig, ImageExtractionConfig, ImageFormat, LanguageDetectionConfig, TokenReductionConfig,
+};
+pub use formats::{LineEnding, OutputFormat, OutputMode};
+#[cfg(feature = "office")]
+pub use jupyter::{JupyterConfig, NotebookOutputs};
+pub use ocr::{OcrConfig, Rect};
 pub use page::PageConfig;
 #[cfg(feature = "pdf")]
-pub use pdf::{HierarchyConfig, PdfConfig};
-pub use processing::{ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, PostProcessorConfig};
+pub use pdf::{HierarchyConfig, PdfConfig, ReadingOrder};
+pub use processing::{
+    ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, OverlapUnit, PostProcessorConfig,
+    TextNormalizerConfig,
+};
+pub use tables::{TableDetection, TableExtractionConfig};
+pub use xlsx::FormulaMode;
+#[cfg(feature = "excel")]
+pub use xlsx::XlsxConfig;
@@ -6,7 +6,9 @@
 use serde::{Deserialize, Serialize};
 
 use super::formats::OutputFormat;
-use crate::core::config_validation::validate_ocr_backend;
+use crate::core::config_validation::{
+    validate_confidence, validate_dpi, validate_ocr_backend, validate_ocr_region, validate_page_filter,
+};
 use crate::error::KreuzbergError;
 use crate::types::OcrElementConfig;
 
@@ -17,6 +19,14 @@ pub struct OcrConfig {
     #[serde(default = "default_tesseract_backend")]
     pub backend: String,
 
+    /// Backends to try, in order, when `backend` is not available or fails (default: empty)
+    ///
+    /// For example `["paddle-ocr"]` keeps OCR working where Tesseract is not installed.
+    /// Each fallback logs a warning, and the backend that produced the text is recorded in
+    /// `metadata.ocr_backend`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_backends: Vec<String>,
+
     /// Language code (e.g., "eng", "deu")
     #[serde(default = "default_eng")]
     pub language: String,
@@ -36,25 +46,116 @@ pub struct OcrConfig {
     /// OCR element extraction configuration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub element_config: Option<OcrElementConfig>,
+
+    /// Number of times OCR of a page is retried after a failure (default: 0)
+    ///
+    /// Retries back off exponentially, starting at 100ms. When retries are enabled and
+    /// every attempt fails, the page is left empty and extraction continues; with the
+    /// default of 0 the first failure aborts extraction.
+    #[serde(default)]
+    pub max_retries: u32,
+
+    /// Pick the OCR language from the script detected on the first page (default: false)
+    ///
+    /// Tesseract only; requires `osd.traineddata`. Non-Latin scripts select a matching
+    /// language model (e.g. Cyrillic → `rus`). Latin-script pages, uncertain detections and
+    /// languages that are not installed keep `language`. The chosen language is recorded in
+    /// `metadata.ocr_language`.
+    #[serde(default)]
+    pub auto_language: bool,
+
+    /// Domain-specific words to bias recognition towards, e.g. drug names (default: None)
+    ///
+    /// Tesseract loads them as a user-words dictionary; PaddleOCR replaces recognized words
+    /// that are a near miss of exactly one of them. Entries containing whitespace are split
+    /// into separate words.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_words: Option<Vec<String>>,
+
+    /// DPI at which PDF pages are rendered before OCR (default: None, i.e. 300)
+    ///
+    /// Higher values help with small, dense text at the cost of speed and memory; lower
+    /// values are faster. Pages too large to render within the maximum image dimension
+    /// are still scaled down. The value used is recorded in
+    /// `metadata.additional["ocr_render_dpi"]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub render_dpi: Option<i32>,
+
+    /// Report pages where OCR found no text (default: None, disabled)
+    ///
+    /// A page counts as empty when its recognized text is blank or its normalized
+    /// confidence (0.0-1.0) is below this floor; `0.0` reports blank pages only. Empty
+    /// pages are logged as a warning and listed, 1-indexed, in
+    /// `metadata.additional["ocr_empty_pages"]`. Pages whose OCR failed are reported in
+    /// `ocr_failed_pages` instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub empty_page_confidence: Option<f64>,
+
+    /// Only OCR these 1-indexed PDF pages, keeping the native text of the rest (default: None)
+    ///
+    /// Saves rendering and recognizing every page of long documents where only a few pages
+    /// are scans. When set, it replaces the automatic decision whether to OCR a PDF, and
+    /// `force_ocr`. Page numbers refer to the original document, also with
+    /// `pdf_options.page_range`, and must lie within the extracted pages. An empty list
+    /// is the same as `None`. Other formats ignore it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_filter: Option<Vec<usize>>,
+
+    /// Only OCR this region of each page or image (default: None, the whole page)
+    ///
+    /// Applies to PDF pages and image documents; images embedded in other documents (such
+    /// as inline HTML images) and rasterized SVGs are recognized whole. Coordinates are
+    /// normalized to the page size, so the same region applies to pages rendered at any DPI. Useful for forms where only a stamp or signature box matters:
+    /// the image is cropped before recognition, which is faster and keeps text outside
+    /// the region out of the result. Positions in `ocr_elements` still refer to the full
+    /// page. Multi-frame TIFFs are reduced to their first frame.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<Rect>,
+}
+
+/// A rectangle in coordinates normalized to the page size.
+///
+/// The origin is the top-left corner of the page and `(1.0, 1.0)` its bottom-right
+/// corner. A valid rectangle lies within the page and has a positive width and height.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Rect {
+    /// Left edge, as a fraction of the page width
+    pub left: f64,
+    /// Top edge, as a fraction of the page height
+    pub top: f64,
+    /// Width, as a fraction of the page width
+    pub width: f64,
+    /// Height, as a fraction of the page height
+    pub height: f64,
 }
 
 impl Default for OcrConfig {
     fn default() -> Self {
         Self {
             backend: default_tesseract_backend(),
+            fallback_backends: Vec::new(),
             language: default_eng(),
             tesseract_config: None,
             output_format: None,
             paddle_ocr_config: None,
             element_config: None,
+            max_retries: 0,
+            auto_language: false,
+            user_words: None,
+            render_dpi: None,
+            empty_page_confidence: None,
+            page_filter: None,
+            region: None,
         }
     }
 }
 
 impl OcrConfig {
-    /// Validates that the configured backend is supported.
+    /// Validates that the configured backend and fallback backends are supported, and
+    /// that `render_dpi`, `empty_page_confidence`, `page_filter` and `region`, if set, are
+    /// in range.
     ///
-    /// This method checks that the backend name is one of the supported OCR backends:
+    /// This method checks that each backend name is one of the supported OCR backends:
     /// - tesseract
     /// - easyocr
     /// - paddleocr
@@ -63,7 +164,9 @@ impl OcrConfig {
     ///
     /// # Errors
     ///
-    /// Returns a `KreuzbergError::Validation` if the backend is not recognized.
+    /// Returns a `KreuzbergError::Validation` if a backend is not recognized or
+    /// `render_dpi`, `empty_page_confidence`, `page_filter` or `region` is invalid. Whether the
+    /// pages of `page_filter` exist is only known once the document is opened.
     ///
     /// # Examples
     ///
@@ -85,7 +188,22 @@ impl OcrConfig {
     /// assert!(bad_config.validate().is_err());
     /// ```
     pub fn validate(&self) -> Result<(), KreuzbergError> {
-        validate_ocr_backend(&self.backend)
+        validate_ocr_backend(&self.backend)?;
+        if let Some(dpi) = self.render_dpi {
+            validate_dpi(dpi)?;
+        }
+        if let Some(confidence) = self.empty_page_confidence {
+            validate_confidence(confidence)?;
+        }
+        if let Some(page_filter) = &self.page_filter {
+            validate_page_filter(page_filter)?;
+        }
+        if let Some(region) = &self.region {
+            validate_ocr_region(region)?;
+        }
+        self.fallback_backends
+            .iter()
+            .try_for_each(|backend| validate_ocr_backend(backend))
     }
 }
 
@@ -108,6 +226,7 @@ mod tests {
         assert_eq!(config.language, "eng");
         assert!(config.tesseract_config.is_none());
         assert!(config.output_format.is_none());
+        assert_eq!(config.max_retries, 0);
     }
 
     #[test]
@@ -121,6 +240,76 @@ mod tests {
         assert_eq!(config.language, "fra");
     }
 
+    #[test]
+    fn test_validate_render_dpi() {
+        let config = OcrConfig {
+            render_dpi: Some(400),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let config = OcrConfig {
+            render_dpi: Some(0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_empty_page_confidence() {
+        let config = OcrConfig {
+            empty_page_confidence: Some(0.5),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let config = OcrConfig {
+            empty_page_confidence: Some(1.5),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_page_filter() {
+        let config = OcrConfig {
+            page_filter: Some(vec![3, 7]),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let config = OcrConfig {
+            page_filter: Some(vec![0, 7]),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_region() {
+        let config = OcrConfig {
+            region: Some(Rect {
+                left: 0.5,
+                top: 0.75,
+                width: 0.5,
+                height: 0.25,
+            }),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let config = OcrConfig {
+            region: Some(Rect {
+                left: 0.6,
+                top: 0.0,
+                width: 0.5,
+                height: 1.0,
+            }),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_validate_tesseract_backend() {
         let config = OcrConfig {
@@ -172,6 +361,16 @@ mod tests {
         assert!(err_msg.contains("Invalid OCR backend") || err_msg.contains("Valid options are"));
     }
 
+    #[test]
+    fn test_validate_invalid_fallback_backend() {
+        let config = OcrConfig {
+            fallback_backends: vec!["paddle-ocr".to_string(), "ocr_lib".to_string()],
+            ..Default::default()
+        };
+        let err_msg = config.validate().unwrap_err().to_string();
+        assert!(err_msg.contains("ocr_lib"));
+    }
+
     #[test]
     fn test_validate_default_backend() {
         let config = OcrConfig::default();
@@ -1,10 +1,15 @@
 //! PDF-specific configuration.
 //!
 //! Defines PDF extraction options including metadata handling, image extraction,
-//! password management, and hierarchy extraction for document structure analysis.
+//! password management, hierarchy extraction for document structure analysis,
+//! text reading order, page range selection, page separators, and formula recognition.
 
 use serde::{Deserialize, Serialize};
 
+/// Separator inserted between pages when `PdfConfig.page_separator` is unset.
+#[cfg(feature = "pdf")]
+pub const DEFAULT_PAGE_SEPARATOR: &str = "\n\n";
+
 /// PDF-specific configuration.
 #[cfg(feature = "pdf")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,9 +19,20 @@ pub struct PdfConfig {
     pub extract_images: bool,
 
     /// List of passwords to try when opening encrypted PDFs
+    ///
+    /// The empty user password is always tried first, so PDFs that are only
+    /// owner-locked open without configuration.
     #[serde(default)]
     pub passwords: Option<Vec<String>>,
 
+    /// Extract text from PDFs whose owner permissions forbid copying (default: true)
+    ///
+    /// Only set this when you are permitted to extract the document. A warning is
+    /// logged and `metadata.owner_restrictions_bypassed` is set when restrictions are
+    /// bypassed. When `false`, such PDFs fail with an encryption error.
+    #[serde(default = "default_true")]
+    pub allow_owner_locked: bool,
+
     /// Extract PDF metadata
     #[serde(default = "default_true")]
     pub extract_metadata: bool,
@@ -24,6 +40,87 @@ pub struct PdfConfig {
     /// Hierarchy extraction configuration (None = hierarchy extraction disabled)
     #[serde(default)]
     pub hierarchy: Option<HierarchyConfig>,
+
+    /// Order in which page text is emitted (default: content stream order)
+    #[serde(default)]
+    pub reading_order: ReadingOrder,
+
+    /// Extract only this page range (1-indexed, inclusive), e.g. `(5, 10)`.
+    ///
+    /// Page numbers on tables, images, and page boundaries refer to the original
+    /// document, and document metadata (title, authors, dates, page count) is that of
+    /// the original document. Both ends must lie within the document and `start <= end`.
+    #[serde(default)]
+    pub page_range: Option<(usize, usize)>,
+
+    /// Text inserted between consecutive pages (default: `"\n\n"`)
+    ///
+    /// `{page}` is replaced with the number of the page that follows, so
+    /// `"\n\n<!-- page {page} -->\n\n"` marks where each page after the first starts.
+    /// Also joins the pages of other paginated formats (DOCX, PPTX, EPUB) when page
+    /// tracking (`ExtractionConfig.pages`) is enabled. Not used when
+    /// `PageConfig.insert_page_markers` is enabled.
+    #[serde(default)]
+    pub page_separator: Option<String>,
+
+    /// Recognize mathematical formulas as LaTeX into `ExtractionResult.formulas`
+    ///
+    /// Requires the `math-ocr` feature and its ONNX model. Without the feature the
+    /// option is ignored and `metadata.formula_extraction_error` explains why.
+    #[serde(default)]
+    pub extract_math: bool,
+
+    /// Insert `[image N]` markers into `content` where extracted images appear
+    ///
+    /// `N` is the image's `image_index + 1`. Requires image extraction (`images`);
+    /// markers are placed at the image's vertical position within its page.
+    #[serde(default)]
+    pub insert_image_markers: bool,
+}
+
+#[cfg(feature = "pdf")]
+impl Default for PdfConfig {
+    fn default() -> Self {
+        Self {
+            extract_images: false,
+            passwords: None,
+            allow_owner_locked: true,
+            extract_metadata: true,
+            hierarchy: None,
+            reading_order: ReadingOrder::Raw,
+            page_range: None,
+            page_separator: None,
+            extract_math: false,
+            insert_image_markers: false,
+        }
+    }
+}
+
+#[cfg(feature = "pdf")]
+impl PdfConfig {
+    /// Separator to insert before page `page_number` (1-indexed), with `{page}` substituted.
+    pub fn separator_before_page(&self, page_number: usize) -> std::borrow::Cow<'_, str> {
+        match self.page_separator.as_deref() {
+            Some(separator) if separator.contains("{page}") => {
+                std::borrow::Cow::Owned(separator.replace("{page}", &page_number.to_string()))
+            }
+            Some(separator) => std::borrow::Cow::Borrowed(separator),
+            None => std::borrow::Cow::Borrowed(DEFAULT_PAGE_SEPARATOR),
+        }
+    }
+}
+
+/// Order in which text is read from a PDF page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadingOrder {
+    /// Text in content stream order, as reported by pdfium.
+    #[default]
+    Raw,
+    /// Cluster text blocks into columns by x-position and read each column
+    /// top-to-bottom, left-to-right. Blocks spanning several columns (titles,
+    /// full-width figure captions) break the page into separate bands.
+    ColumnAware,
 }
 
 /// Hierarchy extraction configuration for PDF text structure analysis.
@@ -108,4 +205,42 @@ mod tests {
         assert!(!config.include_bbox);
         assert_eq!(config.ocr_coverage_threshold, Some(0.7));
     }
+
+    #[test]
+    #[cfg(feature = "pdf")]
+    fn test_page_range_serde() {
+        use super::*;
+        assert!(PdfConfig::default().page_range.is_none());
+        let config: PdfConfig = serde_json::from_str(r#"{"page_range": [5, 10]}"#).unwrap();
+        assert_eq!(config.page_range, Some((5, 10)));
+        assert!(config.extract_metadata);
+    }
+
+    #[test]
+    #[cfg(feature = "pdf")]
+    fn test_separator_before_page() {
+        use super::*;
+        assert_eq!(PdfConfig::default().separator_before_page(2), DEFAULT_PAGE_SEPARATOR);
+
+        let config = PdfConfig {
+            page_separator: Some("\n\n<!-- page {page} -->\n\n".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.separator_before_page(7), "\n\n<!-- page 7 -->\n\n");
+
+        let config = PdfConfig {
+            page_separator: Some("\n---\n".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.separator_before_page(3), "\n---\n");
+    }
+
+    #[test]
+    fn test_reading_order_serde() {
+        use super::*;
+        assert_eq!(ReadingOrder::default(), ReadingOrder::Raw);
+        let parsed: ReadingOrder = serde_json::from_str("\"column_aware\"").unwrap();
+        assert_eq!(parsed, ReadingOrder::ColumnAware);
+        assert_eq!(serde_json::to_string(&ReadingOrder::Raw).unwrap(), "\"raw\"");
+    }
 }
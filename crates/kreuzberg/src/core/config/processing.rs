@@ -20,6 +20,20 @@ pub enum ChunkerType {
     Markdown,
 }
 
+/// Unit in which [`ChunkingConfig::overlap`] is measured.
+///
+/// # Variants
+///
+/// * `Characters` - Overlap is a character count; may start mid-word
+/// * `Sentences` - Overlap is a number of trailing sentences carried into the next chunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlapUnit {
+    #[default]
+    Characters,
+    Sentences,
+}
+
 /// Post-processor configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostProcessorConfig {
@@ -42,6 +56,17 @@ pub struct PostProcessorConfig {
     /// Pre-computed HashSet for O(1) disabled processor lookup
     #[serde(skip)]
     pub disabled_set: Option<HashSet<String>>,
+
+    /// Fraction of pages a line must appear on to be stripped by the `header-footer-stripper`
+    /// processor (0.0-1.0)
+    ///
+    /// Default: 0.6
+    #[serde(default = "default_header_footer_threshold")]
+    pub header_footer_threshold: f64,
+
+    /// Options for the `text-normalizer` processor
+    #[serde(default)]
+    pub text_normalizer: TextNormalizerConfig,
 }
 
 impl PostProcessorConfig {
@@ -67,6 +92,45 @@ impl Default for PostProcessorConfig {
             disabled_processors: None,
             enabled_set: None,
             disabled_set: None,
+            header_footer_threshold: default_header_footer_threshold(),
+            text_normalizer: TextNormalizerConfig::default(),
+        }
+    }
+}
+
+/// Options for the opt-in `text-normalizer` post-processor.
+///
+/// The processor only runs when `text-normalizer` is listed in
+/// [`PostProcessorConfig::enabled_processors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextNormalizerConfig {
+    /// Expand typographic ligatures (ﬁ, ﬂ, ﬀ, ﬃ, ﬄ, ﬆ, ĳ) to their ASCII letters
+    ///
+    /// Default: true
+    #[serde(default = "default_true")]
+    pub expand_ligatures: bool,
+
+    /// Replace smart quotes, primes, dashes and ellipses with ASCII equivalents
+    ///
+    /// En dashes and hyphen variants become `-`, em dashes become `--`.
+    ///
+    /// Default: true
+    #[serde(default = "default_true")]
+    pub normalize_punctuation: bool,
+
+    /// Apply Unicode NFC normalization before the replacements above
+    ///
+    /// Default: false
+    #[serde(default)]
+    pub unicode_nfc: bool,
+}
+
+impl Default for TextNormalizerConfig {
+    fn default() -> Self {
+        Self {
+            expand_ligatures: true,
+            normalize_punctuation: true,
+            unicode_nfc: false,
         }
     }
 }
@@ -83,12 +147,21 @@ pub struct ChunkingConfig {
     #[serde(default = "default_chunk_size", rename = "max_chars", alias = "max_characters")]
     pub max_characters: usize,
 
-    /// Overlap between chunks in characters
+    /// Overlap between chunks, in `overlap_unit`s
     ///
     /// Default: 200
     #[serde(default = "default_chunk_overlap", rename = "max_overlap", alias = "overlap")]
     pub overlap: usize,
 
+    /// Unit of `overlap`: characters, or whole trailing sentences
+    ///
+    /// In sentence mode each chunk after the first starts with the last `overlap`
+    /// sentences of the previous chunk; `max_characters` bounds the new text only.
+    ///
+    /// Default: Characters
+    #[serde(default)]
+    pub overlap_unit: OverlapUnit,
+
     /// Whether to trim whitespace from chunk boundaries
     ///
     /// Default: true
@@ -108,6 +181,26 @@ pub struct ChunkingConfig {
     /// Use a preset configuration (overrides individual settings if provided)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub preset: Option<String>,
+
+    /// Stop after this many chunks (must be greater than 0)
+    ///
+    /// Content past the last chunk is not chunked, and the result is marked with
+    /// `metadata.additional["chunks_truncated"] = true`.
+    ///
+    /// Default: None (no limit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_chunks: Option<usize>,
+
+    /// Spread content evenly across chunks instead of filling each one greedily
+    ///
+    /// Greedy splitting fills every chunk up to `max_characters`, which can leave a
+    /// short final chunk. When enabled, a second pass finds the smallest chunk size that
+    /// produces the same number of chunks, so chunk sizes are more uniform. Chunks never
+    /// exceed `max_characters` either way. Not applied when `max_chunks` truncates the text.
+    ///
+    /// Default: false
+    #[serde(default)]
+    pub balance: bool,
 }
 
 impl Default for ChunkingConfig {
@@ -119,6 +212,9 @@ impl Default for ChunkingConfig {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         }
     }
 }
@@ -186,6 +282,10 @@ fn default_true() -> bool {
     true
 }
 
+fn default_header_footer_threshold() -> f64 {
+    0.6
+}
+
 fn default_chunk_size() -> usize {
     1000
 }
@@ -226,6 +326,18 @@ mod tests {
         assert!(config.enabled);
         assert!(config.enabled_processors.is_none());
         assert!(config.disabled_processors.is_none());
+        assert_eq!(config.header_footer_threshold, 0.6);
+        assert!(config.text_normalizer.expand_ligatures);
+        assert!(!config.text_normalizer.unicode_nfc);
+    }
+
+    #[test]
+    fn test_text_normalizer_config_partial_deserialize() {
+        let config: PostProcessorConfig =
+            serde_json::from_str(r#"{"text_normalizer": {"unicode_nfc": true}}"#).unwrap();
+        assert!(config.text_normalizer.unicode_nfc);
+        assert!(config.text_normalizer.expand_ligatures);
+        assert!(config.text_normalizer.normalize_punctuation);
     }
 
     #[test]
@@ -236,6 +348,8 @@ mod tests {
             disabled_processors: Some(vec!["c".to_string()]),
             enabled_set: None,
             disabled_set: None,
+            header_footer_threshold: 0.6,
+            text_normalizer: TextNormalizerConfig::default(),
         };
 
         config.build_lookup_sets();
@@ -255,6 +369,9 @@ mod tests {
             chunker_type: ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         };
         assert_eq!(config.max_characters, 1000);
         assert_eq!(config.overlap, 200);
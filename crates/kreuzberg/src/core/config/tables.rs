@@ -0,0 +1,214 @@
+//! Table post-processing configuration.
+//!
+//! Defines cleanup applied to every detected table before it is returned: trimming
+//! cell whitespace and dropping padding rows and columns that contain no text. It also
+//! selects which HTML tables count as tables in the first place.
+
+use crate::types::{Table, TableMarkdownOptions};
+use serde::{Deserialize, Serialize};
+
+/// Which HTML `<table>` elements are extracted as tables.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TableDetection {
+    /// Every table is extracted as a table (default)
+    #[default]
+    All,
+    /// Only data tables are extracted. Layout tables (`role="presentation"`, no `<th>`
+    /// cells, or a single column) are rendered as plain blocks, and a data table nested
+    /// inside another data table is flattened into the enclosing cell.
+    DataTables,
+}
+
+/// Table extraction configuration.
+///
+/// All cleanup options default to false, which returns tables exactly as the extractor
+/// produced them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableExtractionConfig {
+    /// Trim leading and trailing whitespace from every cell
+    #[serde(default)]
+    pub trim_cells: bool,
+
+    /// Remove rows whose cells are all empty or whitespace
+    #[serde(default)]
+    pub drop_empty_rows: bool,
+
+    /// Remove columns whose cells are all empty or whitespace
+    #[serde(default)]
+    pub drop_empty_columns: bool,
+
+    /// Mark the first row of every table as a header row, overriding extractor detection
+    #[serde(default)]
+    pub assume_first_row_header: bool,
+
+    /// Which tables of an HTML document are extracted as tables
+    #[serde(default)]
+    pub html_table_detection: TableDetection,
+}
+
+impl TableExtractionConfig {
+    /// Whether no option rewrites detected tables. `html_table_detection` is applied by
+    /// the HTML extractor and does not count.
+    pub fn is_noop(&self) -> bool {
+        !(self.trim_cells || self.drop_empty_rows || self.drop_empty_columns || self.assume_first_row_header)
+    }
+
+    /// Apply the configured cleanup to a table's cells.
+    ///
+    /// Returns true if the cells changed.
+    pub fn apply(&self, cells: &mut Vec<Vec<String>>) -> bool {
+        let mut changed = false;
+
+        if self.trim_cells {
+            for cell in cells.iter_mut().flatten() {
+                let trimmed = cell.trim();
+                if trimmed.len() != cell.len() {
+                    *cell = trimmed.to_string();
+                    changed = true;
+                }
+            }
+        }
+
+        if self.drop_empty_rows {
+            let before = cells.len();
+            cells.retain(|row| !row.iter().all(|cell| is_blank(cell)));
+            changed |= cells.len() != before;
+        }
+
+        if self.drop_empty_columns {
+            let num_cols = cells.iter().map(Vec::len).max().unwrap_or(0);
+            let keep: Vec<bool> = (0..num_cols)
+                .map(|col| cells.iter().any(|row| row.get(col).is_some_and(|cell| !is_blank(cell))))
+                .collect();
+            if keep.contains(&false) {
+                for row in cells.iter_mut() {
+                    let mut col = 0;
+                    row.retain(|_| {
+                        let kept = keep[col];
+                        col += 1;
+                        kept
+                    });
+                }
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// Apply the configured cleanup to a table and mark its first row as a header if
+    /// configured, re-rendering its Markdown if either changed.
+    ///
+    /// Merged cell regions are dropped when rows or columns are removed, since they no
+    /// longer line up with the cells.
+    pub fn apply_to_table(&self, table: &mut Table) {
+        let shape = grid_shape(&table.cells);
+        let mut changed = self.apply(&mut table.cells);
+        if changed && grid_shape(&table.cells) != shape {
+            table.cell_spans.clear();
+        }
+        if self.assume_first_row_header && !table.cells.is_empty() && !table.has_header {
+            table.has_header = true;
+            changed = true;
+        }
+        if changed {
+            table.markdown = table.to_markdown(TableMarkdownOptions::default());
+        }
+    }
+}
+
+fn grid_shape(cells: &[Vec<String>]) -> (usize, usize) {
+    (cells.len(), cells.iter().map(Vec::len).max().unwrap_or(0))
+}
+
+fn is_blank(cell: &str) -> bool {
+    cell.trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn padded_table() -> Vec<Vec<String>> {
+        [
+            vec!["Name ", "", " Age"],
+            vec!["", " ", ""],
+            vec!["Alice", "", "30"],
+            vec!["", "", ""],
+        ]
+        .into_iter()
+        .map(|row| row.into_iter().map(String::from).collect())
+        .collect()
+    }
+
+    #[test]
+    fn test_default_keeps_table_unchanged() {
+        let config = TableExtractionConfig::default();
+        assert!(config.is_noop());
+
+        let mut cells = padded_table();
+        assert!(!config.apply(&mut cells));
+        assert_eq!(cells, padded_table());
+    }
+
+    #[test]
+    fn test_drop_empty_rows_removes_padding_rows() {
+        let config = TableExtractionConfig {
+            drop_empty_rows: true,
+            ..Default::default()
+        };
+
+        let mut cells = padded_table();
+        assert!(config.apply(&mut cells));
+        assert_eq!(cells, vec![vec!["Name ", "", " Age"], vec!["Alice", "", "30"]]);
+    }
+
+    #[test]
+    fn test_trim_and_drop_empty_columns() {
+        let config = TableExtractionConfig {
+            trim_cells: true,
+            drop_empty_rows: true,
+            drop_empty_columns: true,
+            ..Default::default()
+        };
+
+        let mut cells = padded_table();
+        assert!(config.apply(&mut cells));
+        assert_eq!(cells, vec![vec!["Name", "Age"], vec!["Alice", "30"]]);
+    }
+
+    #[test]
+    fn test_assume_first_row_header_marks_tables() {
+        let config = TableExtractionConfig {
+            assume_first_row_header: true,
+            ..Default::default()
+        };
+        assert!(!config.is_noop());
+
+        let mut table = Table {
+            cells: padded_table(),
+            markdown: String::new(),
+            page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
+        };
+        config.apply_to_table(&mut table);
+        assert!(table.has_header);
+        assert_eq!(table.cells, padded_table());
+        assert!(table.markdown.starts_with("| Name"));
+    }
+
+    #[test]
+    fn test_table_extraction_config_deserialize() {
+        let config: TableExtractionConfig = serde_json::from_str(r#"{"drop_empty_rows": true}"#).unwrap();
+        assert!(config.drop_empty_rows);
+        assert!(!config.trim_cells);
+        assert!(!config.drop_empty_columns);
+        assert_eq!(config.html_table_detection, TableDetection::All);
+
+        let config: TableExtractionConfig = serde_json::from_str(r#"{"html_table_detection": "data_tables"}"#).unwrap();
+        assert_eq!(config.html_table_detection, TableDetection::DataTables);
+    }
+}
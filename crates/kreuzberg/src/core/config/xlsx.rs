@@ -0,0 +1,93 @@
+//! Spreadsheet-specific configuration.
+//!
+//! Defines options for Excel/OpenDocument spreadsheet extraction: rendering cell
+//! formulas for audit workflows, restricting extraction to selected sheets and
+//! including hidden sheets.
+
+use serde::{Deserialize, Serialize};
+
+/// Spreadsheet extraction configuration.
+#[cfg(feature = "excel")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XlsxConfig {
+    /// Render cell formulas according to `formula_mode`
+    ///
+    /// When false, only computed values are emitted regardless of `formula_mode`.
+    #[serde(default)]
+    pub include_formulas: bool,
+
+    /// How formulas are rendered when `include_formulas` is true
+    #[serde(default)]
+    pub formula_mode: FormulaMode,
+
+    /// Extract only these sheets, in workbook order (None = all sheets)
+    ///
+    /// Naming a sheet that does not exist is a validation error.
+    #[serde(default)]
+    pub sheet_names: Option<Vec<String>>,
+
+    /// Extract hidden and very hidden sheets (default: false)
+    ///
+    /// Hidden sheets often hold stale lookup data or values not meant for readers, so they
+    /// are skipped unless this is set or they are listed in `sheet_names`. Their names are
+    /// reported in the Excel metadata's `hidden_sheets` either way.
+    #[serde(default)]
+    pub include_hidden_sheets: bool,
+}
+
+#[cfg(feature = "excel")]
+impl XlsxConfig {
+    /// The effective formula mode, taking `include_formulas` into account.
+    pub fn effective_formula_mode(&self) -> FormulaMode {
+        if self.include_formulas {
+            self.formula_mode
+        } else {
+            FormulaMode::ValueOnly
+        }
+    }
+}
+
+/// How formula cells are rendered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FormulaMode {
+    /// Computed value only, e.g. `55`.
+    ValueOnly,
+    /// Formula text only, e.g. `=SUM(A1:A10)`. Cells without a formula keep their value.
+    FormulaOnly,
+    /// Computed value followed by the formula, e.g. `55 (=SUM(A1:A10))`.
+    #[default]
+    Both,
+}
+
+#[cfg(all(test, feature = "excel"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formula_mode_requires_include_formulas() {
+        let config = XlsxConfig {
+            formula_mode: FormulaMode::FormulaOnly,
+            ..Default::default()
+        };
+        assert_eq!(config.effective_formula_mode(), FormulaMode::ValueOnly);
+
+        let config = XlsxConfig {
+            include_formulas: true,
+            ..config
+        };
+        assert_eq!(config.effective_formula_mode(), FormulaMode::FormulaOnly);
+    }
+
+    #[test]
+    fn test_xlsx_config_deserialize() {
+        let config: XlsxConfig =
+            serde_json::from_str(r#"{"include_formulas": true, "sheet_names": ["Summary"]}"#).unwrap();
+        assert_eq!(config.formula_mode, FormulaMode::Both);
+        assert_eq!(config.sheet_names, Some(vec!["Summary".to_string()]));
+        assert!(!config.include_hidden_sheets);
+
+        let config: XlsxConfig = serde_json::from_str(r#"{"formula_mode": "formula_only"}"#).unwrap();
+        assert_eq!(config.formula_mode, FormulaMode::FormulaOnly);
+    }
+}
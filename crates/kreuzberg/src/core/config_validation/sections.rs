@@ -432,6 +432,81 @@ pub fn validate_dpi(dpi: i32) -> Result<()> {
     }
 }
 
+/// Validate the page numbers of an OCR page filter.
+///
+/// Pages are 1-indexed, so 0 is rejected. Whether the pages exist is only known once
+/// the document is opened.
+///
+/// # Arguments
+///
+/// * `pages` - The page numbers to validate
+///
+/// # Returns
+///
+/// `Ok(())` if every page number is valid, or a `ValidationError` naming the first invalid one.
+///
+/// # Examples
+///
+/// ```rust
+/// use kreuzberg::core::config_validation::validate_page_filter;
+///
+/// assert!(validate_page_filter(&[3, 7]).is_ok());
+/// assert!(validate_page_filter(&[]).is_ok());
+/// assert!(validate_page_filter(&[0, 7]).is_err());
+/// ```
+pub fn validate_page_filter(pages: &[usize]) -> Result<()> {
+    if pages.contains(&0) {
+        Err(KreuzbergError::Validation {
+            message: "Invalid page number '0' in page filter. Pages are 1-indexed.".to_string(),
+            source: None,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Validate a normalized OCR region.
+///
+/// Every coordinate must be finite, the width and height positive, and the rectangle
+/// must lie within the page, i.e. within `[0, 1]` on both axes.
+///
+/// # Arguments
+///
+/// * `region` - The region to validate
+///
+/// # Returns
+///
+/// `Ok(())` if the region is valid, or a `ValidationError` describing the region.
+///
+/// # Examples
+///
+/// ```rust
+/// use kreuzberg::core::config::ocr::Rect;
+/// use kreuzberg::core::config_validation::validate_ocr_region;
+///
+/// let rect = |left, top, width, height| Rect { left, top, width, height };
+/// assert!(validate_ocr_region(&rect(0.0, 0.0, 1.0, 1.0)).is_ok());
+/// assert!(validate_ocr_region(&rect(0.5, 0.5, 0.6, 0.2)).is_err());
+/// assert!(validate_ocr_region(&rect(0.1, 0.1, 0.0, 0.2)).is_err());
+/// ```
+pub fn validate_ocr_region(region: &crate::core::config::ocr::Rect) -> Result<()> {
+    let within_unit = |start: f64, length: f64| {
+        start.is_finite() && length.is_finite() && start >= 0.0 && length > 0.0 && start + length <= 1.0
+    };
+
+    if within_unit(region.left, region.width) && within_unit(region.top, region.height) {
+        Ok(())
+    } else {
+        Err(KreuzbergError::Validation {
+            message: format!(
+                "Invalid OCR region (left {}, top {}, width {}, height {}). Coordinates are normalized: the region must lie within [0, 1] and have a positive width and height.",
+                region.left, region.top, region.width, region.height
+            ),
+            source: None,
+        })
+    }
+}
+
 /// Validate chunk size parameters.
 ///
 /// Checks that max_chars > 0 and max_overlap < max_chars.
@@ -7,7 +7,7 @@ use crate::core::config::ExtractionConfig;
 use crate::types::{ErrorMetadata, ExtractionResult, Metadata};
 use crate::{KreuzbergError, Result};
 use std::borrow::Cow;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -19,7 +19,7 @@ use super::file::extract_file;
 /// This function processes multiple files in parallel, automatically managing
 /// concurrency to prevent resource exhaustion. The concurrency limit can be
 /// configured via `ExtractionConfig::max_concurrent_extractions` or defaults
-/// to `num_cpus * 2`.
+/// to the number of CPU cores.
 ///
 /// # Arguments
 ///
@@ -60,28 +60,239 @@ pub async fn batch_extract_file(
     paths: Vec<impl AsRef<Path>>,
     config: &ExtractionConfig,
 ) -> Result<Vec<ExtractionResult>> {
-    use tokio::sync::Semaphore;
-    use tokio::task::JoinSet;
-
     if paths.is_empty() {
         return Ok(vec![]);
     }
 
     let config_arc = Arc::new(config.clone());
+    let max_concurrent = max_concurrent_for(std::iter::once(config));
+    let items = paths
+        .into_iter()
+        .map(|path| (path.as_ref().to_path_buf(), Arc::clone(&config_arc)))
+        .collect();
+
+    batch_extract_file_items(items, max_concurrent).await
+}
+
+/// Extract content from multiple files concurrently, each with its own configuration.
+///
+/// Behaves like [`batch_extract_file`] but takes a distinct `ExtractionConfig` per path,
+/// e.g. to force a different OCR backend for some files, while still sharing a single
+/// concurrency pool across the whole batch.
+///
+/// The concurrency limit is the smallest `max_concurrent_extractions` set in any of the
+/// configurations, or the number of CPU cores if none sets it.
+///
+/// # Arguments
+///
+/// * `items` - Vector of (file path, extraction configuration) pairs
+///
+/// # Returns
+///
+/// A vector of `ExtractionResult` in the same order as the input pairs.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::batch_extract_file_with_configs;
+/// use kreuzberg::core::config::{ExtractionConfig, OcrConfig};
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let latin = ExtractionConfig {
+///     ocr: Some(OcrConfig {
+///         backend: "tesseract".to_string(),
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// let cjk = ExtractionConfig {
+///     ocr: Some(OcrConfig {
+///         backend: "paddle-ocr".to_string(),
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+///
+/// let results = batch_extract_file_with_configs(vec![("scan_en.pdf", latin), ("scan_zh.pdf", cjk)]).await?;
+/// println!("Processed {} files", results.len());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "tokio-runtime")]
+#[cfg_attr(feature = "otel", tracing::instrument(
+    skip(items),
+    fields(
+        extraction.batch_size = items.len(),
+    )
+))]
+pub async fn batch_extract_file_with_configs(
+    items: Vec<(impl AsRef<Path>, ExtractionConfig)>,
+) -> Result<Vec<ExtractionResult>> {
+    if items.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let max_concurrent = max_concurrent_for(items.iter().map(|(_, config)| config));
+    let items = items
+        .into_iter()
+        .map(|(path, config)| (path.as_ref().to_path_buf(), Arc::new(config)))
+        .collect();
+
+    batch_extract_file_items(items, max_concurrent).await
+}
+
+/// Extract content from multiple files concurrently, returning each file's outcome.
+///
+/// Unlike [`batch_extract_file`], which turns a failed file into an `ExtractionResult`
+/// whose `metadata.error` describes the failure, this keeps the original
+/// [`KreuzbergError`] for every file so callers can match on it. One unreadable or
+/// corrupt file never discards the results of the others.
+///
+/// # Arguments
+///
+/// * `paths` - Vector of file paths to extract
+/// * `config` - Extraction configuration
+///
+/// # Returns
+///
+/// One `Result` per input path, in the same order as the input paths. A file whose
+/// extraction task panicked yields `KreuzbergError::Other`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::batch_extract_file_lenient;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// # async fn example() {
+/// let config = ExtractionConfig::default();
+/// let paths = vec!["doc1.pdf", "missing.pdf"];
+/// for (path, outcome) in paths.iter().zip(batch_extract_file_lenient(paths.clone(), &config).await) {
+///     match outcome {
+///         Ok(result) => println!("{}: {} chars", path, result.content.len()),
+///         Err(e) => eprintln!("{}: {}", path, e),
+///     }
+/// }
+/// # }
+/// ```
+#[cfg(feature = "tokio-runtime")]
+#[cfg_attr(feature = "otel", tracing::instrument(
+    skip(config, paths),
+    fields(
+        extraction.batch_size = paths.len(),
+    )
+))]
+pub async fn batch_extract_file_lenient(
+    paths: Vec<impl AsRef<Path>>,
+    config: &ExtractionConfig,
+) -> Vec<Result<ExtractionResult>> {
+    if paths.is_empty() {
+        return vec![];
+    }
+
+    let config_arc = Arc::new(config.clone());
+    let max_concurrent = max_concurrent_for(std::iter::once(config));
+    let items = paths
+        .into_iter()
+        .map(|path| (path.as_ref().to_path_buf(), Arc::clone(&config_arc)))
+        .collect();
+
+    run_file_items(items, max_concurrent)
+        .await
+        .into_iter()
+        .map(|outcome| match outcome {
+            FileOutcome::Finished(result, _elapsed_ms) => *result,
+            FileOutcome::Panicked(message) => Err(KreuzbergError::Other(format!("Task panicked: {}", message))),
+        })
+        .collect()
+}
+
+/// Concurrency limit for a batch: the smallest configured limit, or the number of CPU cores.
+#[cfg(feature = "tokio-runtime")]
+fn max_concurrent_for<'a>(configs: impl Iterator<Item = &'a ExtractionConfig>) -> usize {
+    configs
+        .filter_map(|config| config.max_concurrent_extractions)
+        .min()
+        .unwrap_or_else(num_cpus::get)
+}
+
+/// How one file of a batch ended.
+#[cfg(feature = "tokio-runtime")]
+enum FileOutcome {
+    /// The extraction ran to completion (successfully or not) in the given number of milliseconds.
+    Finished(Box<Result<ExtractionResult>>, u64),
+    /// The extraction task panicked.
+    Panicked(String),
+}
+
+#[cfg(feature = "tokio-runtime")]
+async fn batch_extract_file_items(
+    items: Vec<(PathBuf, Arc<ExtractionConfig>)>,
+    max_concurrent: usize,
+) -> Result<Vec<ExtractionResult>> {
+    run_file_items(items, max_concurrent)
+        .await
+        .into_iter()
+        .map(|outcome| match outcome {
+            FileOutcome::Finished(result, elapsed_ms) => match *result {
+                Ok(result) => {
+                    // Timing already added to result.metadata.extraction_duration_ms
+                    Ok(result)
+                }
+                Err(e) => {
+                    // All errors (including Io) should create error results
+                    // instead of failing the whole batch
+                    let metadata = Metadata {
+                        error: Some(ErrorMetadata {
+                            error_type: format!("{:?}", e),
+                            message: e.to_string(),
+                        }),
+                        extraction_duration_ms: Some(elapsed_ms),
+                        ..Default::default()
+                    };
+
+                    Ok(ExtractionResult {
+                        content: format!("Error: {}", e),
+                        mime_type: Cow::Borrowed("text/plain"),
+                        metadata,
+                        tables: vec![],
+                        detected_languages: None,
+                        chunks: None,
+                        images: None,
+                        djot_content: None,
+                        pages: None,
+                        elements: None,
+                        ocr_elements: None,
+                        document: None,
+                        outline: None,
+                        formulas: None,
+                        text_blocks: None,
+                        source_map: None,
+                        references: None,
+                    })
+                }
+            },
+            FileOutcome::Panicked(message) => Err(KreuzbergError::Other(format!("Task panicked: {}", message))),
+        })
+        .collect()
+}
+
+/// Run every file of a batch to completion and return the outcomes in input order.
+#[cfg(feature = "tokio-runtime")]
+async fn run_file_items(items: Vec<(PathBuf, Arc<ExtractionConfig>)>, max_concurrent: usize) -> Vec<FileOutcome> {
+    use std::collections::HashMap;
+    use tokio::sync::Semaphore;
+    use tokio::task::JoinSet;
 
-    let max_concurrent = config_arc
-        .max_concurrent_extractions
-        .unwrap_or_else(|| (num_cpus::get() as f64 * 1.5).ceil() as usize);
     let semaphore = Arc::new(Semaphore::new(max_concurrent));
 
     let mut tasks = JoinSet::new();
+    let mut task_indices = HashMap::with_capacity(items.len());
 
-    for (index, path) in paths.into_iter().enumerate() {
-        let path_buf = path.as_ref().to_path_buf();
-        let config_clone = Arc::clone(&config_arc);
+    for (index, (path_buf, config_clone)) in items.into_iter().enumerate() {
         let semaphore_clone = Arc::clone(&semaphore);
 
-        tasks.spawn(async move {
+        let handle = tasks.spawn(async move {
             let _permit = semaphore_clone.acquire().await.unwrap();
             let start = Instant::now();
             let mut result =
@@ -96,51 +307,28 @@ pub async fn batch_extract_file(
 
             (index, result, elapsed_ms)
         });
+        task_indices.insert(handle.id(), index);
     }
 
-    let mut results: Vec<Option<ExtractionResult>> = vec![None; tasks.len()];
+    let mut outcomes: Vec<Option<FileOutcome>> = std::iter::repeat_with(|| None).take(tasks.len()).collect();
 
     while let Some(task_result) = tasks.join_next().await {
         match task_result {
-            Ok((index, Ok(result), _elapsed_ms)) => {
-                // Timing already added to result.metadata.extraction_duration_ms
-                results[index] = Some(result);
-            }
-            Ok((index, Err(e), elapsed_ms)) => {
-                // All errors (including Io) should create error results
-                // instead of causing early return that abandons running tasks
-                let metadata = Metadata {
-                    error: Some(ErrorMetadata {
-                        error_type: format!("{:?}", e),
-                        message: e.to_string(),
-                    }),
-                    extraction_duration_ms: Some(elapsed_ms),
-                    ..Default::default()
-                };
-
-                results[index] = Some(ExtractionResult {
-                    content: format!("Error: {}", e),
-                    mime_type: Cow::Borrowed("text/plain"),
-                    metadata,
-                    tables: vec![],
-                    detected_languages: None,
-                    chunks: None,
-                    images: None,
-                    djot_content: None,
-                    pages: None,
-                    elements: None,
-                    ocr_elements: None,
-                    document: None,
-                });
+            Ok((index, result, elapsed_ms)) => {
+                outcomes[index] = Some(FileOutcome::Finished(Box::new(result), elapsed_ms));
             }
             Err(join_err) => {
-                return Err(KreuzbergError::Other(format!("Task panicked: {}", join_err)));
+                if let Some(&index) = task_indices.get(&join_err.id()) {
+                    outcomes[index] = Some(FileOutcome::Panicked(join_err.to_string()));
+                }
             }
         }
     }
 
-    #[allow(clippy::unwrap_used)]
-    Ok(results.into_iter().map(|r| r.unwrap()).collect())
+    outcomes
+        .into_iter()
+        .map(|outcome| outcome.unwrap_or_else(|| FileOutcome::Panicked("task did not complete".to_string())))
+        .collect()
 }
 
 /// Extract content from multiple byte arrays concurrently.
@@ -148,7 +336,7 @@ pub async fn batch_extract_file(
 /// This function processes multiple byte arrays in parallel, automatically managing
 /// concurrency to prevent resource exhaustion. The concurrency limit can be
 /// configured via `ExtractionConfig::max_concurrent_extractions` or defaults
-/// to `num_cpus * 2`.
+/// to the number of CPU cores.
 ///
 /// # Arguments
 ///
@@ -196,9 +384,7 @@ pub async fn batch_extract_bytes(
 
     let config_arc = Arc::new(config.clone());
 
-    let max_concurrent = config_arc
-        .max_concurrent_extractions
-        .unwrap_or_else(|| (num_cpus::get() as f64 * 1.5).ceil() as usize);
+    let max_concurrent = max_concurrent_for(std::iter::once(config_arc.as_ref()));
     let semaphore = Arc::new(Semaphore::new(max_concurrent));
 
     let mut tasks = JoinSet::new();
@@ -258,6 +444,11 @@ pub async fn batch_extract_bytes(
                     elements: None,
                     ocr_elements: None,
                     document: None,
+                    outline: None,
+                    formulas: None,
+                    text_blocks: None,
+                    source_map: None,
+                    references: None,
                 });
             }
             Err(join_err) => {
@@ -4,8 +4,8 @@
 //! - MIME type validation
 //! - Legacy format conversion (DOC, PPT)
 //! - Extraction pipeline orchestration
+//! - Extraction of a byte window of a larger buffer
 
-#[cfg(not(feature = "office"))]
 use crate::KreuzbergError;
 use crate::Result;
 use crate::core::config::ExtractionConfig;
@@ -21,9 +21,10 @@ use super::file::record_error;
 /// This is the main entry point for in-memory extraction. It performs the following steps:
 /// 1. Validate MIME type
 /// 2. Handle legacy format conversion if needed
-/// 3. Select appropriate extractor from registry
-/// 4. Extract content
-/// 5. Run post-processing pipeline
+/// 3. Return the result stored by `cache::warm`, if caching is enabled and the content was warmed
+/// 4. Select appropriate extractor from registry
+/// 5. Extract content
+/// 6. Run post-processing pipeline
 ///
 /// # Arguments
 ///
@@ -72,13 +73,15 @@ pub async fn extract_bytes(content: &[u8], mime_type: &str, config: &ExtractionC
         #[cfg(not(feature = "office"))]
         match validated_mime.as_str() {
             LEGACY_WORD_MIME_TYPE => {
-                return Err(KreuzbergError::UnsupportedFormat(
-                    "Legacy Word extraction requires the `office` feature".to_string(),
+                return Err(KreuzbergError::unsupported_format_with_reason(
+                    LEGACY_WORD_MIME_TYPE,
+                    "legacy Word extraction requires the `office` feature",
                 ));
             }
             LEGACY_POWERPOINT_MIME_TYPE => {
-                return Err(KreuzbergError::UnsupportedFormat(
-                    "Legacy PowerPoint extraction requires the `office` feature".to_string(),
+                return Err(KreuzbergError::unsupported_format_with_reason(
+                    LEGACY_POWERPOINT_MIME_TYPE,
+                    "legacy PowerPoint extraction requires the `office` feature",
                 ));
             }
             _ => {}
@@ -102,3 +105,71 @@ pub async fn extract_bytes(content: &[u8], mime_type: &str, config: &ExtractionC
 
     result
 }
+
+/// Extract content from the byte window `start..end` of a byte array.
+///
+/// Useful for append-only logs or concatenated documents that a caller has already split
+/// into byte ranges. The window is extracted as if it were a complete document of the
+/// given MIME type.
+///
+/// A window of UTF-8 text may cut through a multi-byte character. Continuation bytes at
+/// the start of the window and an incomplete character at its end are dropped, so the
+/// window decodes cleanly. Windows that are not UTF-8 text are passed on unchanged.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if `end <= start` or `end` is past the end of
+/// `content`, in addition to the errors of [`extract_bytes`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::extract_bytes_range;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let log = b"first entry\nsecond entry\n";
+/// let result = extract_bytes_range(log, "text/plain", &ExtractionConfig::default(), 12, 25).await?;
+/// assert_eq!(result.content.trim(), "second entry");
+/// # Ok(())
+/// # }
+/// ```
+pub async fn extract_bytes_range(
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+    start: usize,
+    end: usize,
+) -> Result<ExtractionResult> {
+    extract_bytes(byte_window(content, start, end)?, mime_type, config).await
+}
+
+/// Validate a byte range and return the window, aligned to UTF-8 character boundaries
+/// when the window is UTF-8 text.
+pub(super) fn byte_window(content: &[u8], start: usize, end: usize) -> Result<&[u8]> {
+    if end <= start {
+        return Err(KreuzbergError::validation(format!(
+            "Invalid byte range {}..{}: end must be greater than start",
+            start, end
+        )));
+    }
+    if end > content.len() {
+        return Err(KreuzbergError::validation(format!(
+            "Invalid byte range {}..{}: content is only {} bytes long",
+            start,
+            end,
+            content.len()
+        )));
+    }
+
+    let window = &content[start..end];
+    // At most three continuation bytes can precede the next character boundary.
+    let leading = window.iter().take(3).take_while(|&&byte| byte & 0xC0 == 0x80).count();
+    let aligned = &window[leading..];
+    match std::str::from_utf8(aligned) {
+        Ok(_) => Ok(aligned),
+        // `error_len() == None` means the text is only cut off mid-character at the end.
+        Err(e) if e.error_len().is_none() => Ok(&aligned[..e.valid_up_to()]),
+        Err(_) => Ok(window),
+    }
+}
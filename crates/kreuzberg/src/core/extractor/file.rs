@@ -11,10 +11,11 @@ use crate::KreuzbergError;
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::core::mime::{LEGACY_POWERPOINT_MIME_TYPE, LEGACY_WORD_MIME_TYPE};
+use crate::core::progress::{self, ProgressEvent};
 use crate::types::ExtractionResult;
 use std::path::Path;
 
-use super::helpers::get_extractor;
+use super::helpers::{get_extractor, set_extractor_name};
 
 /// Sanitize a file path to return only the filename.
 ///
@@ -78,12 +79,11 @@ pub(in crate::core::extractor) fn record_error(error: &KreuzbergError) {
 /// Extract content from a file.
 ///
 /// This is the main entry point for file-based extraction. It performs the following steps:
-/// 1. Check cache for existing result (if caching enabled)
-/// 2. Detect or validate MIME type
+/// 1. Detect or validate MIME type
+/// 2. Return the result stored by `cache::warm`, if caching is enabled and the file was warmed
 /// 3. Select appropriate extractor from registry
 /// 4. Extract content
 /// 5. Run post-processing pipeline
-/// 6. Store result in cache (if caching enabled)
 ///
 /// # Arguments
 ///
@@ -144,13 +144,15 @@ pub async fn extract_file(
         #[cfg(not(feature = "office"))]
         match detected_mime.as_str() {
             LEGACY_WORD_MIME_TYPE => {
-                return Err(KreuzbergError::UnsupportedFormat(
-                    "Legacy Word extraction requires the `office` feature".to_string(),
+                return Err(KreuzbergError::unsupported_format_with_reason(
+                    LEGACY_WORD_MIME_TYPE,
+                    "legacy Word extraction requires the `office` feature",
                 ));
             }
             LEGACY_POWERPOINT_MIME_TYPE => {
-                return Err(KreuzbergError::UnsupportedFormat(
-                    "Legacy PowerPoint extraction requires the `office` feature".to_string(),
+                return Err(KreuzbergError::unsupported_format_with_reason(
+                    LEGACY_POWERPOINT_MIME_TYPE,
+                    "legacy PowerPoint extraction requires the `office` feature",
                 ));
             }
             _ => {}
@@ -175,15 +177,66 @@ pub async fn extract_file(
     result
 }
 
+/// Extract content from a file, reporting progress to `callback`.
+///
+/// Behaves exactly like [`extract_file`], but invokes `callback` with a
+/// [`ProgressEvent`] at each phase boundary (extractor selected, pages processed,
+/// OCR, post-processing, chunking). A final [`ProgressEvent::ExtractionFinished`]
+/// is always emitted, even when extraction fails.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::extract_file_with_progress;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let config = ExtractionConfig::default();
+/// let result = extract_file_with_progress("document.pdf", None, &config, |event| {
+///     eprintln!("{:?}", event);
+/// })
+/// .await?;
+/// println!("Content: {}", result.content);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "tokio-runtime")]
+pub async fn extract_file_with_progress<F>(
+    path: impl AsRef<Path>,
+    mime_type: Option<&str>,
+    config: &ExtractionConfig,
+    callback: F,
+) -> Result<ExtractionResult>
+where
+    F: FnMut(ProgressEvent) + Send + 'static,
+{
+    let sink = progress::sink_from(callback);
+    progress::scope(sink, async {
+        let result = extract_file(path, mime_type, config).await;
+        progress::emit(ProgressEvent::ExtractionFinished);
+        result
+    })
+    .await
+}
+
 pub(in crate::core::extractor) async fn extract_file_with_extractor(
     path: &Path,
     mime_type: &str,
     config: &ExtractionConfig,
 ) -> Result<ExtractionResult> {
+    #[cfg(feature = "tokio-runtime")]
+    if let Some(cached) = crate::cache::lookup_warmed_file(path, mime_type, config).await {
+        return Ok(cached);
+    }
+
     crate::extractors::ensure_initialized()?;
 
-    let extractor = get_extractor(mime_type)?;
+    let extractor = get_extractor(mime_type).map_err(|err| crate::core::mime::with_detected_extension(err, path))?;
+    progress::emit(ProgressEvent::ExtractionStarted {
+        mime_type: mime_type.to_string(),
+    });
     let mut result = extractor.extract_file(path, mime_type, config).await?;
+    set_extractor_name(&mut result, extractor.as_ref());
     result = crate::core::pipeline::run_pipeline(result, config).await?;
     Ok(result)
 }
@@ -193,10 +246,19 @@ pub(in crate::core::extractor) async fn extract_bytes_with_extractor(
     mime_type: &str,
     config: &ExtractionConfig,
 ) -> Result<ExtractionResult> {
+    #[cfg(feature = "tokio-runtime")]
+    if let Some(cached) = crate::cache::lookup_warmed_bytes(content, mime_type, config) {
+        return Ok(cached);
+    }
+
     crate::extractors::ensure_initialized()?;
 
     let extractor = get_extractor(mime_type)?;
+    progress::emit(ProgressEvent::ExtractionStarted {
+        mime_type: mime_type.to_string(),
+    });
     let mut result = extractor.extract_bytes(content, mime_type, config).await?;
+    set_extractor_name(&mut result, extractor.as_ref());
     result = crate::core::pipeline::run_pipeline(result, config).await?;
     Ok(result)
 }
@@ -3,6 +3,7 @@
 //! This module provides shared utilities used across extraction modules.
 
 use crate::plugins::DocumentExtractor;
+use crate::types::ExtractionResult;
 use crate::utils::{PoolSizeHint, estimate_pool_size};
 use crate::{KreuzbergError, Result};
 use std::sync::Arc;
@@ -24,6 +25,18 @@ pub(in crate::core::extractor) fn get_extractor(mime_type: &str) -> Result<Arc<d
     registry_read.get(mime_type)
 }
 
+/// Record which extractor produced `result` in `metadata.extractor_name`.
+///
+/// The plugin name is shortened by dropping the `-extractor` suffix, so
+/// `"pdf-extractor"` becomes `"pdf"`. Names without the suffix are kept as-is.
+pub(in crate::core::extractor) fn set_extractor_name(result: &mut ExtractionResult, extractor: &dyn DocumentExtractor) {
+    result.metadata.extractor_name = Some(extractor_short_name(extractor.name()).to_string());
+}
+
+fn extractor_short_name(name: &str) -> &str {
+    name.strip_suffix("-extractor").unwrap_or(name)
+}
+
 /// Get optimal pool sizing hint for a document.
 ///
 /// This function calculates recommended pool sizes based on the document's
@@ -51,3 +64,15 @@ pub(in crate::core::extractor) fn get_extractor(mime_type: &str) -> Result<Arc<d
 pub fn get_pool_sizing_hint(file_size: u64, mime_type: &str) -> PoolSizeHint {
     estimate_pool_size(file_size, mime_type)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extractor_short_name() {
+        assert_eq!(extractor_short_name("pdf-extractor"), "pdf");
+        assert_eq!(extractor_short_name("plain-text-extractor"), "plain-text");
+        assert_eq!(extractor_short_name("custom"), "custom");
+    }
+}
@@ -0,0 +1,166 @@
+//! Language-only detection.
+//!
+//! Extracts a small text sample from a document and runs language detection on it,
+//! for routing documents before paying for a full extraction.
+
+use crate::Result;
+use crate::core::config::{ExtractionConfig, LanguageDetectionConfig};
+use crate::core::mime::{MARKDOWN_MIME_TYPE, PLAIN_TEXT_MIME_TYPE};
+use std::io::Read;
+use std::path::Path;
+
+use super::helpers::get_extractor;
+
+/// Number of bytes of text the language detector looks at.
+pub const LANGUAGE_SAMPLE_BYTES: usize = 16 * 1024;
+
+/// Detect the languages of a file from a small text sample.
+///
+/// Much cheaper than [`extract_file`](super::extract_file) followed by detection:
+/// plain text and Markdown files are only read up to [`LANGUAGE_SAMPLE_BYTES`], PDFs
+/// are extracted from their first page only (the first page of `PdfConfig.page_range`
+/// if set), and for other formats everything that does not produce text (images,
+/// tables cleanup, document structure and the whole post-processing pipeline) is
+/// skipped. The detector then looks at the first [`LANGUAGE_SAMPLE_BYTES`] of text.
+///
+/// Detection uses `config.language_detection` (defaults if unset), see
+/// [`rank_languages`](crate::language_detection::rank_languages) for how
+/// `min_confidence` and `detect_multiple` apply. `enabled` is ignored.
+///
+/// # Returns
+///
+/// ISO 639-3 language codes with their confidence, most likely first. Empty if no
+/// language could be detected with sufficient confidence.
+///
+/// # Errors
+///
+/// Same as [`extract_file`](super::extract_file).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::detect_document_language;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let languages = detect_document_language("report.pdf", &ExtractionConfig::default()).await?;
+/// if let Some((code, confidence)) = languages.first() {
+///     println!("{code} ({confidence:.2})");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn detect_document_language(path: impl AsRef<Path>, config: &ExtractionConfig) -> Result<Vec<(String, f64)>> {
+    use crate::core::{io, mime};
+
+    let path = path.as_ref();
+    io::validate_file_exists(path)?;
+    let detected_mime = mime::detect_or_validate(Some(path), None)?;
+
+    let text = if is_plain_text(&detected_mime) {
+        read_text_prefix(path)?
+    } else {
+        crate::extractors::ensure_initialized()?;
+        let extractor =
+            get_extractor(&detected_mime).map_err(|err| crate::core::mime::with_detected_extension(err, path))?;
+
+        let sample_config = language_sample_config(config);
+        let mut content = extractor
+            .extract_file(path, &detected_mime, &sample_config)
+            .await?
+            .content;
+        content.truncate(sample_boundary(&content, LANGUAGE_SAMPLE_BYTES));
+        content
+    };
+
+    let detection = config.language_detection.clone().unwrap_or(LanguageDetectionConfig {
+        enabled: true,
+        min_confidence: 0.8,
+        detect_multiple: false,
+    });
+    Ok(crate::language_detection::rank_languages(&text, &detection))
+}
+
+fn is_plain_text(mime_type: &str) -> bool {
+    mime_type == PLAIN_TEXT_MIME_TYPE || mime_type == MARKDOWN_MIME_TYPE
+}
+
+/// Read at most [`LANGUAGE_SAMPLE_BYTES`] of a text file, dropping a trailing partial character.
+fn read_text_prefix(path: &Path) -> Result<String> {
+    let mut buffer = Vec::with_capacity(LANGUAGE_SAMPLE_BYTES);
+    std::fs::File::open(path)?
+        .take(LANGUAGE_SAMPLE_BYTES as u64)
+        .read_to_end(&mut buffer)?;
+
+    let valid = match std::str::from_utf8(&buffer) {
+        Ok(_) => buffer.len(),
+        Err(err) if err.error_len().is_none() => err.valid_up_to(),
+        Err(_) => buffer.len(),
+    };
+    Ok(String::from_utf8_lossy(&buffer[..valid]).into_owned())
+}
+
+/// Largest char boundary in `text` that is at most `max_bytes`.
+fn sample_boundary(text: &str, max_bytes: usize) -> usize {
+    text.floor_char_boundary(max_bytes)
+}
+
+/// `config` with everything that does not contribute text turned off and PDFs limited to one page.
+fn language_sample_config(config: &ExtractionConfig) -> ExtractionConfig {
+    let mut config = config.clone();
+
+    config.images = None;
+    config.pages = None;
+    config.chunking = None;
+    #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+    {
+        config.keywords = None;
+    }
+    config.language_detection = None;
+    config.token_reduction = None;
+    config.table_options = None;
+    config.enable_quality_processing = false;
+    config.include_document_structure = false;
+    config.extract_outline = false;
+    config.extract_references = false;
+    #[cfg(feature = "pdf")]
+    {
+        let pdf = config.pdf_options.get_or_insert_with(Default::default);
+        let first_page = pdf.page_range.map_or(1, |(start, _)| start);
+        pdf.page_range = Some((first_page, first_page));
+        pdf.extract_images = false;
+        pdf.extract_math = false;
+        pdf.hierarchy = None;
+    }
+
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_boundary_respects_char_boundaries() {
+        assert_eq!(sample_boundary("abc", 10), 3);
+        assert_eq!(sample_boundary("aé", 2), 1);
+        assert_eq!(sample_boundary("aé", 3), 3);
+    }
+
+    #[cfg(feature = "pdf")]
+    #[test]
+    fn test_language_sample_config_limits_pdf_to_first_page() {
+        let config = language_sample_config(&ExtractionConfig::default());
+        assert_eq!(config.pdf_options.unwrap().page_range, Some((1, 1)));
+
+        let ranged = ExtractionConfig {
+            pdf_options: Some(crate::core::config::PdfConfig {
+                page_range: Some((4, 9)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let config = language_sample_config(&ranged);
+        assert_eq!(config.pdf_options.unwrap().page_range, Some((4, 4)));
+    }
+}
@@ -29,8 +29,9 @@ pub(super) fn extract_bytes_sync_impl(
     config: Option<&crate::core::config::ExtractionConfig>,
 ) -> crate::Result<crate::types::ExtractionResult> {
     use crate::KreuzbergError;
-    use crate::core::extractor::helpers::get_extractor;
+    use crate::core::extractor::helpers::{get_extractor, set_extractor_name};
     use crate::core::mime;
+    use crate::plugins::Plugin;
 
     let cfg = config.cloned().unwrap_or_default();
 
@@ -48,13 +49,17 @@ pub(super) fn extract_bytes_sync_impl(
     let extractor = get_extractor(&validated_mime)?;
 
     let sync_extractor = extractor.as_sync_extractor().ok_or_else(|| {
-        KreuzbergError::UnsupportedFormat(format!(
-            "Extractor for '{}' does not support synchronous extraction",
-            validated_mime
-        ))
+        KreuzbergError::unsupported_format_with_reason(
+            validated_mime.as_str(),
+            format!(
+                "extractor '{}' does not support synchronous extraction",
+                extractor.name()
+            ),
+        )
     })?;
 
     let mut result = sync_extractor.extract_sync(content, &validated_mime, &cfg)?;
+    set_extractor_name(&mut result, extractor.as_ref());
 
     result = crate::core::pipeline::run_pipeline_sync(result, &cfg)?;
 
@@ -1,38 +1,54 @@
 //! Main extraction entry points.
 //!
 //! This module provides the primary API for extracting content from files and byte arrays.
-//! It orchestrates the entire extraction pipeline: cache checking, MIME detection,
-//! extractor selection, extraction, post-processing, and cache storage.
+//! It orchestrates the entire extraction pipeline: MIME detection, warmed cache lookup,
+//! extractor selection, extraction, and post-processing.
 //!
 //! # Functions
 //!
 //! - [`extract_file`] - Extract content from a file path
 //! - [`extract_bytes`] - Extract content from a byte array
+//! - [`extract_bytes_range`] - Extract content from a byte window of a byte array
+//! - [`extract_file_with_progress`] - Extract content from a file with progress reporting
+//! - [`extract_tables_only`] - Extract only the tables from a file
+//! - [`detect_document_language`] - Detect the languages of a file from a small text sample
 //! - [`batch_extract_file`] - Extract content from multiple files concurrently
+//! - [`batch_extract_file_with_configs`] - Extract multiple files concurrently, each with its own config
+//! - [`batch_extract_file_lenient`] - Extract multiple files concurrently, keeping each file's error
 //! - [`batch_extract_bytes`] - Extract content from multiple byte arrays concurrently
 
 mod bytes;
 mod file;
 mod helpers;
+#[cfg(feature = "language-detection")]
+mod language;
 mod legacy;
 mod sync;
+mod tables;
 
 #[cfg(feature = "tokio-runtime")]
 mod batch;
 
 // Re-export public API
-pub use bytes::extract_bytes;
+pub use bytes::{extract_bytes, extract_bytes_range};
 pub use file::extract_file;
+#[cfg(feature = "tokio-runtime")]
+pub use file::extract_file_with_progress;
 pub use helpers::get_pool_sizing_hint;
-pub use sync::{batch_extract_bytes_sync, extract_bytes_sync};
+#[cfg(feature = "language-detection")]
+pub use language::{LANGUAGE_SAMPLE_BYTES, detect_document_language};
+pub use sync::{batch_extract_bytes_sync, extract_bytes_range_sync, extract_bytes_sync};
+pub use tables::extract_tables_only;
 
+#[cfg(all(feature = "tokio-runtime", feature = "language-detection"))]
+pub use sync::detect_document_language_sync;
 #[cfg(feature = "tokio-runtime")]
-pub use sync::extract_file_sync;
+pub use sync::{extract_file_sync, extract_file_with_progress_sync, extract_tables_only_sync};
 
 #[cfg(feature = "tokio-runtime")]
-pub use batch::{batch_extract_bytes, batch_extract_file};
+pub use batch::{batch_extract_bytes, batch_extract_file, batch_extract_file_lenient, batch_extract_file_with_configs};
 #[cfg(feature = "tokio-runtime")]
-pub use sync::batch_extract_file_sync;
+pub use sync::{batch_extract_file_lenient_sync, batch_extract_file_sync, batch_extract_file_with_configs_sync};
 
 #[cfg(test)]
 mod tests {
@@ -64,6 +80,76 @@ mod tests {
         assert_eq!(result.mime_type, "text/plain");
     }
 
+    #[tokio::test]
+    async fn test_extract_tables_only_csv() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("people.csv");
+        let mut file = File::create(&file_path).unwrap();
+        file.write_all(b"Name,Age\nAlice,30\nBob,25\n").unwrap();
+
+        let tables = extract_tables_only(&file_path, None, &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].cells[0], vec!["Name", "Age"]);
+        assert_eq!(tables[0].cells.len(), 3);
+    }
+
+    #[cfg(feature = "language-detection")]
+    #[tokio::test]
+    async fn test_detect_document_language_text_file() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("note.txt");
+        let mut file = File::create(&file_path).unwrap();
+        file.write_all(
+            "Die Bundesregierung hat am Mittwoch beschlossen, die Förderung für erneuerbare Energien \
+             deutlich zu erhöhen. Nach Angaben des Ministeriums sollen vor allem kleine Unternehmen \
+             und Haushalte davon profitieren. "
+                .repeat(200)
+                .as_bytes(),
+        )
+        .unwrap();
+
+        let languages = detect_document_language(&file_path, &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(languages.len(), 1);
+        assert_eq!(languages[0].0, "deu");
+        assert!(languages[0].1 >= 0.8);
+    }
+
+    #[tokio::test]
+    async fn test_extract_file_with_progress_reports_phases() {
+        use crate::core::progress::ProgressEvent;
+        use std::sync::Mutex;
+
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("progress.txt");
+        let mut file = File::create(&file_path).unwrap();
+        file.write_all(b"Hello, progress!").unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        let config = ExtractionConfig::default();
+        let result = extract_file_with_progress(&file_path, None, &config, move |event| {
+            recorded.lock().unwrap().push(event);
+        })
+        .await
+        .unwrap();
+
+        assert_text_content(&result.content, "Hello, progress!");
+        let events = events.lock().unwrap();
+        assert_eq!(
+            events.first(),
+            Some(&ProgressEvent::ExtractionStarted {
+                mime_type: "text/plain".to_string()
+            })
+        );
+        assert_eq!(events.last(), Some(&ProgressEvent::ExtractionFinished));
+    }
+
     #[tokio::test]
     async fn test_extract_file_with_mime_override() {
         let dir = tempdir().unwrap();
@@ -104,6 +190,49 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_extract_bytes_range() {
+        let config = ExtractionConfig::default();
+        let log = "first entry\nsecond entry\n".as_bytes();
+
+        let result = extract_bytes_range(log, "text/plain", &config, 12, 24).await.unwrap();
+        assert_text_content(&result.content, "second entry");
+    }
+
+    #[test]
+    fn test_extract_bytes_range_sync() {
+        let config = ExtractionConfig::default();
+        let log = "first entry\nsecond entry\n".as_bytes();
+
+        let result = extract_bytes_range_sync(log, "text/plain", &config, 0, 11).unwrap();
+        assert_text_content(&result.content, "first entry");
+    }
+
+    #[tokio::test]
+    async fn test_extract_bytes_range_aligns_to_utf8_boundaries() {
+        let config = ExtractionConfig::default();
+        let text = "na\u{ef}ve caf\u{e9}".as_bytes();
+
+        // Starts inside "ï" and ends inside "é".
+        let result = extract_bytes_range(text, "text/plain", &config, 3, 11).await.unwrap();
+        assert_text_content(&result.content, "ve caf");
+    }
+
+    #[tokio::test]
+    async fn test_extract_bytes_range_rejects_invalid_ranges() {
+        let config = ExtractionConfig::default();
+
+        for (start, end) in [(4, 4), (5, 2), (0, 6)] {
+            let result = extract_bytes_range(b"hello", "text/plain", &config, start, end).await;
+            assert!(
+                matches!(result, Err(crate::KreuzbergError::Validation { .. })),
+                "{}..{} should be rejected",
+                start,
+                end
+            );
+        }
+    }
+
     #[tokio::test]
     async fn test_batch_extract_file() {
         let dir = tempdir().unwrap();
@@ -135,6 +264,59 @@ mod tests {
         assert_eq!(results.unwrap().len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_batch_extract_file_with_configs() {
+        let dir = tempdir().unwrap();
+
+        let file1 = dir.path().join("test1.txt");
+        let file2 = dir.path().join("test2.txt");
+
+        File::create(&file1).unwrap().write_all(b"first document").unwrap();
+        File::create(&file2).unwrap().write_all(b"second document").unwrap();
+
+        let truncating = ExtractionConfig {
+            max_content_chars: Some(6),
+            max_concurrent_extractions: Some(1),
+            ..Default::default()
+        };
+        let items = vec![(file1, ExtractionConfig::default()), (file2, truncating)];
+        let results = batch_extract_file_with_configs(items).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_text_content(&results[0].content, "first document");
+        assert_eq!(results[0].metadata.truncated, None);
+        assert_eq!(results[1].content, "second");
+        assert_eq!(results[1].metadata.truncated, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_batch_extract_file_with_configs_empty() {
+        let items: Vec<(std::path::PathBuf, ExtractionConfig)> = vec![];
+        let results = batch_extract_file_with_configs(items).await.unwrap();
+
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_extract_file_lenient_keeps_per_file_errors() {
+        let dir = tempdir().unwrap();
+
+        let file1 = dir.path().join("test1.txt");
+        let missing = dir.path().join("missing.txt");
+        let file3 = dir.path().join("test3.txt");
+
+        File::create(&file1).unwrap().write_all(b"content 1").unwrap();
+        File::create(&file3).unwrap().write_all(b"content 3").unwrap();
+
+        let config = ExtractionConfig::default();
+        let outcomes = batch_extract_file_lenient(vec![file1, missing, file3], &config).await;
+
+        assert_eq!(outcomes.len(), 3);
+        assert_text_content(&outcomes[0].as_ref().unwrap().content, "content 1");
+        assert!(outcomes[1].is_err());
+        assert_text_content(&outcomes[2].as_ref().unwrap().content, "content 3");
+    }
+
     #[tokio::test]
     async fn test_batch_extract_bytes() {
         let config = ExtractionConfig::default();
@@ -274,7 +456,7 @@ mod tests {
 
         assert!(result.is_err());
         use crate::KreuzbergError;
-        assert!(matches!(result.unwrap_err(), KreuzbergError::UnsupportedFormat(_)));
+        assert!(matches!(result.unwrap_err(), KreuzbergError::UnsupportedFormat { .. }));
     }
 
     #[tokio::test]
@@ -14,11 +14,15 @@ use std::path::Path;
 use once_cell::sync::Lazy;
 
 #[cfg(feature = "tokio-runtime")]
-use super::batch::{batch_extract_bytes, batch_extract_file};
+use super::batch::{
+    batch_extract_bytes, batch_extract_file, batch_extract_file_lenient, batch_extract_file_with_configs,
+};
 #[cfg(feature = "tokio-runtime")]
 use super::bytes::extract_bytes;
 #[cfg(feature = "tokio-runtime")]
-use super::file::extract_file;
+use super::file::{extract_file, extract_file_with_progress};
+#[cfg(feature = "tokio-runtime")]
+use crate::core::progress::ProgressEvent;
 
 /// Global Tokio runtime for synchronous operations.
 ///
@@ -76,6 +80,43 @@ pub fn extract_file_sync(
     GLOBAL_RUNTIME.block_on(extract_file(path, mime_type, config))
 }
 
+/// Synchronous wrapper for `extract_file_with_progress`.
+///
+/// Blocks the current thread on the global Tokio runtime. The callback is invoked
+/// from runtime worker threads, so it must be `Send`.
+#[cfg(feature = "tokio-runtime")]
+pub fn extract_file_with_progress_sync<F>(
+    path: impl AsRef<Path>,
+    mime_type: Option<&str>,
+    config: &ExtractionConfig,
+    callback: F,
+) -> Result<ExtractionResult>
+where
+    F: FnMut(ProgressEvent) + Send + 'static,
+{
+    GLOBAL_RUNTIME.block_on(extract_file_with_progress(path, mime_type, config, callback))
+}
+
+/// Synchronous wrapper for `extract_tables_only`.
+///
+/// Blocks the current thread on the global Tokio runtime.
+#[cfg(feature = "tokio-runtime")]
+pub fn extract_tables_only_sync(
+    path: impl AsRef<Path>,
+    mime_type: Option<&str>,
+    config: &ExtractionConfig,
+) -> Result<Vec<crate::types::Table>> {
+    GLOBAL_RUNTIME.block_on(super::extract_tables_only(path, mime_type, config))
+}
+
+/// Synchronous wrapper for `detect_document_language`.
+///
+/// Blocks the current thread on the global Tokio runtime.
+#[cfg(all(feature = "tokio-runtime", feature = "language-detection"))]
+pub fn detect_document_language_sync(path: impl AsRef<Path>, config: &ExtractionConfig) -> Result<Vec<(String, f64)>> {
+    GLOBAL_RUNTIME.block_on(super::detect_document_language(path, config))
+}
+
 /// Synchronous wrapper for `extract_bytes`.
 ///
 /// Uses the global Tokio runtime for 100x+ performance improvement over creating
@@ -110,6 +151,20 @@ pub fn extract_bytes_sync(content: &[u8], mime_type: &str, config: &ExtractionCo
     super::legacy::extract_bytes_sync_impl(content, Some(mime_type), Some(config))
 }
 
+/// Synchronous wrapper for `extract_bytes_range`.
+///
+/// Validates and aligns the byte window exactly like the async version, then extracts it
+/// with [`extract_bytes_sync`].
+pub fn extract_bytes_range_sync(
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+    start: usize,
+    end: usize,
+) -> Result<ExtractionResult> {
+    extract_bytes_sync(super::bytes::byte_window(content, start, end)?, mime_type, config)
+}
+
 /// Synchronous wrapper for `batch_extract_file`.
 ///
 /// Uses the global Tokio runtime for 100x+ performance improvement over creating
@@ -138,6 +193,56 @@ pub fn batch_extract_file_sync(
     GLOBAL_RUNTIME.block_on(batch_extract_file(paths, config))
 }
 
+/// Synchronous wrapper for `batch_extract_file_with_configs`.
+///
+/// Uses the global Tokio runtime for 100x+ performance improvement over creating
+/// a new runtime per call.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::batch_extract_file_with_configs_sync;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// let items = vec![
+///     ("doc1.pdf", ExtractionConfig::default()),
+///     ("doc2.pdf", ExtractionConfig { force_ocr: true, ..Default::default() }),
+/// ];
+/// let results = batch_extract_file_with_configs_sync(items)?;
+/// println!("Processed {} files", results.len());
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// ```
+#[cfg(feature = "tokio-runtime")]
+pub fn batch_extract_file_with_configs_sync(
+    items: Vec<(impl AsRef<Path>, ExtractionConfig)>,
+) -> Result<Vec<ExtractionResult>> {
+    GLOBAL_RUNTIME.block_on(batch_extract_file_with_configs(items))
+}
+
+/// Synchronous wrapper for `batch_extract_file_lenient`.
+///
+/// Uses the global Tokio runtime for 100x+ performance improvement over creating
+/// a new runtime per call.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::batch_extract_file_lenient_sync;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// let config = ExtractionConfig::default();
+/// let outcomes = batch_extract_file_lenient_sync(vec!["doc1.pdf", "doc2.pdf"], &config);
+/// let failed = outcomes.iter().filter(|outcome| outcome.is_err()).count();
+/// println!("{} of {} files failed", failed, outcomes.len());
+/// ```
+#[cfg(feature = "tokio-runtime")]
+pub fn batch_extract_file_lenient_sync(
+    paths: Vec<impl AsRef<Path>>,
+    config: &ExtractionConfig,
+) -> Vec<Result<ExtractionResult>> {
+    GLOBAL_RUNTIME.block_on(batch_extract_file_lenient(paths, config))
+}
+
 /// Synchronous wrapper for `batch_extract_bytes`.
 ///
 /// Uses the global Tokio runtime for 100x+ performance improvement over creating
@@ -204,6 +309,11 @@ pub fn batch_extract_bytes_sync(
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         }));
     }
     Ok(results)
@@ -0,0 +1,120 @@
+//! Table-only extraction.
+//!
+//! Runs a document through its extractor with everything that does not contribute
+//! to tables switched off, and returns only the tables. The post-processing
+//! pipeline (chunking, keywords, language detection, ...) is skipped entirely.
+
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::types::Table;
+use std::path::Path;
+
+use super::helpers::get_extractor;
+
+/// Extract only the tables from a file.
+///
+/// Uses the same table extractors as [`extract_file`](super::extract_file), but skips
+/// prose assembly work that tables do not need: image extraction, page content,
+/// document structure, formula recognition and the whole post-processing pipeline.
+/// `ExtractionConfig.table_options` cleanup is still applied.
+/// OCR stays enabled for images, where tables are detected in the OCR output, and is
+/// disabled for every other format. `PdfConfig.page_range` is respected.
+///
+/// # Errors
+///
+/// Same as [`extract_file`](super::extract_file).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::core::extractor::extract_tables_only;
+/// use kreuzberg::core::config::ExtractionConfig;
+///
+/// # async fn example() -> kreuzberg::Result<()> {
+/// let tables = extract_tables_only("report.pdf", None, &ExtractionConfig::default()).await?;
+/// for table in &tables {
+///     println!("page {}: {} rows", table.page_number, table.cells.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn extract_tables_only(
+    path: impl AsRef<Path>,
+    mime_type: Option<&str>,
+    config: &ExtractionConfig,
+) -> Result<Vec<Table>> {
+    use crate::core::{io, mime};
+
+    let path = path.as_ref();
+    io::validate_file_exists(path)?;
+    let detected_mime = mime::detect_or_validate(Some(path), mime_type)?;
+
+    crate::extractors::ensure_initialized()?;
+    let extractor =
+        get_extractor(&detected_mime).map_err(|err| crate::core::mime::with_detected_extension(err, path))?;
+
+    let config = tables_only_config(config, &detected_mime);
+    let mut result = extractor.extract_file(path, &detected_mime, &config).await?;
+    if let Some(options) = config.table_options {
+        for table in &mut result.tables {
+            options.apply_to_table(table);
+        }
+    }
+    Ok(result.tables)
+}
+
+/// `config` with every option that does not affect table detection turned off.
+fn tables_only_config(config: &ExtractionConfig, mime_type: &str) -> ExtractionConfig {
+    let mut config = config.clone();
+
+    if !mime_type.starts_with("image/") {
+        config.ocr = None;
+        config.force_ocr = false;
+    }
+    config.images = None;
+    config.pages = None;
+    config.chunking = None;
+    #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+    {
+        config.keywords = None;
+    }
+    config.language_detection = None;
+    config.token_reduction = None;
+    config.enable_quality_processing = false;
+    config.include_document_structure = false;
+    config.extract_outline = false;
+    config.extract_references = false;
+    #[cfg(feature = "pdf")]
+    if let Some(pdf) = config.pdf_options.as_mut() {
+        pdf.extract_images = false;
+        pdf.extract_math = false;
+        pdf.hierarchy = None;
+    }
+
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{ChunkingConfig, OcrConfig};
+
+    #[test]
+    fn test_tables_only_config_keeps_ocr_for_images_only() {
+        let config = ExtractionConfig {
+            ocr: Some(OcrConfig::default()),
+            force_ocr: true,
+            chunking: Some(ChunkingConfig::default()),
+            ..Default::default()
+        };
+
+        let pdf = tables_only_config(&config, "application/pdf");
+        assert!(pdf.ocr.is_none());
+        assert!(!pdf.force_ocr);
+        assert!(pdf.chunking.is_none());
+
+        let image = tables_only_config(&config, "image/png");
+        assert!(image.ocr.is_some());
+        assert!(image.force_ocr);
+    }
+}
@@ -6,6 +6,7 @@
 use crate::{KreuzbergError, Result};
 use once_cell::sync::Lazy;
 use std::collections::{HashMap, HashSet};
+use std::io::Read;
 use std::path::Path;
 
 pub const HTML_MIME_TYPE: &str = "text/html";
@@ -25,6 +26,7 @@ pub const TOML_MIME_TYPE: &str = "application/toml";
 pub const XML_MIME_TYPE: &str = "application/xml";
 pub const XML_TEXT_MIME_TYPE: &str = "text/xml";
 pub const SVG_MIME_TYPE: &str = "image/svg+xml";
+pub const DXF_MIME_TYPE: &str = "image/vnd.dxf";
 
 pub const EXCEL_MIME_TYPE: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
 pub const EXCEL_BINARY_MIME_TYPE: &str = "application/vnd.ms-excel";
@@ -35,6 +37,12 @@ pub const EXCEL_TEMPLATE_MIME_TYPE: &str = "application/vnd.ms-excel.template.ma
 
 pub const OPENDOC_SPREADSHEET_MIME_TYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
 
+pub const PAGES_MIME_TYPE: &str = "application/vnd.apple.pages";
+pub const NUMBERS_MIME_TYPE: &str = "application/vnd.apple.numbers";
+
+/// Number of bytes [`detect_mime_type_from_reader`] reads from the start of a stream.
+pub const MIME_SNIFF_BYTES: usize = 8 * 1024;
+
 /// Extension to MIME type mapping (ported from Python EXT_TO_MIME_TYPE).
 static EXT_TO_MIME: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
     let mut m = HashMap::new();
@@ -67,6 +75,8 @@ static EXT_TO_MIME: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
     m.insert("docx", DOCX_MIME_TYPE);
     m.insert("doc", LEGACY_WORD_MIME_TYPE);
     m.insert("odt", "application/vnd.oasis.opendocument.text");
+    m.insert("pages", PAGES_MIME_TYPE);
+    m.insert("numbers", NUMBERS_MIME_TYPE);
 
     m.insert("bmp", "image/bmp");
     m.insert("gif", "image/gif");
@@ -90,6 +100,7 @@ static EXT_TO_MIME: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
     m.insert("ppm", "image/x-portable-pixmap");
 
     m.insert("csv", "text/csv");
+    m.insert("dxf", DXF_MIME_TYPE);
     m.insert("tsv", "text/tab-separated-values");
     m.insert("json", JSON_MIME_TYPE);
     m.insert("yaml", YAML_MIME_TYPE);
@@ -182,6 +193,8 @@ static SUPPORTED_MIME_TYPES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     set.insert("application/x-pubmed");
     set.insert("application/x-typst");
     set.insert("text/csv");
+    set.insert(DXF_MIME_TYPE);
+    set.insert("application/dxf");
     set.insert("text/tab-separated-values");
     set.insert("text/troff");
     set.insert("text/x-commonmark");
@@ -203,6 +216,9 @@ static SUPPORTED_MIME_TYPES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     set.insert(EXCEL_TEMPLATE_MIME_TYPE);
     set.insert(OPENDOC_SPREADSHEET_MIME_TYPE);
 
+    set.insert(PAGES_MIME_TYPE);
+    set.insert(NUMBERS_MIME_TYPE);
+
     set.insert(PDF_MIME_TYPE);
     set.insert(POWER_POINT_MIME_TYPE);
     set.insert("application/vnd.openxmlformats-officedocument.presentationml.slideshow"); // PPSX
@@ -258,6 +274,13 @@ static SUPPORTED_MIME_TYPES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
 /// Uses file extension to determine MIME type. Falls back to `mime_guess` crate
 /// if extension-based detection fails.
 ///
+/// If any [`MimeDetector`](crate::plugins::MimeDetector) is registered and
+/// `check_exists` is true, the first [`MIME_SNIFF_BYTES`] of the file are offered to the
+/// detectors before looking at the extension. Files that cannot be read are left to the
+/// extension-based detection. With `check_exists` false the path is treated as a name
+/// only and never opened, so it is safe to pass paths that do not refer to the local
+/// filesystem, such as archive member names.
+///
 /// # Arguments
 ///
 /// * `path` - Path to the file
@@ -281,6 +304,10 @@ pub fn detect_mime_type(path: impl AsRef<Path>, check_exists: bool) -> Result<St
         )));
     }
 
+    if check_exists && let Some(mime_type) = detect_file_with_registered_detectors(path)? {
+        return Ok(mime_type);
+    }
+
     let extension = path.extension().and_then(|ext| ext.to_str()).map(|s| s.to_lowercase());
 
     if let Some(ext) = &extension
@@ -295,10 +322,10 @@ pub fn detect_mime_type(path: impl AsRef<Path>, check_exists: bool) -> Result<St
     }
 
     if let Some(ext) = extension {
-        return Err(KreuzbergError::UnsupportedFormat(format!(
-            "Unknown extension: .{}",
-            ext
-        )));
+        return Err(KreuzbergError::unsupported_format_with_extension(
+            "application/octet-stream",
+            ext,
+        ));
     }
 
     Err(KreuzbergError::validation(format!(
@@ -338,7 +365,17 @@ pub fn validate_mime_type(mime_type: &str) -> Result<String> {
         }
     }
 
-    Err(KreuzbergError::UnsupportedFormat(mime_type.to_string()))
+    // Types claimed by a registered extractor, such as a custom format recognized by a
+    // registered `MimeDetector`.
+    let registry = crate::plugins::registry::get_document_extractor_registry();
+    let registry = registry
+        .read()
+        .map_err(|_| KreuzbergError::LockPoisoned("Document extractor registry lock poisoned".to_string()))?;
+    if registry.get(mime_type).is_ok() {
+        return Ok(mime_type.to_string());
+    }
+
+    Err(KreuzbergError::unsupported_format(mime_type))
 }
 
 /// Detect or validate MIME type.
@@ -358,7 +395,7 @@ pub fn detect_or_validate(path: Option<&Path>, mime_type: Option<&str>) -> Resul
         validate_mime_type(mime)
     } else if let Some(p) = path {
         let detected = detect_mime_type(p, true)?;
-        validate_mime_type(&detected)
+        validate_mime_type(&detected).map_err(|err| with_detected_extension(err, p))
     } else {
         Err(KreuzbergError::validation(
             "Must provide either path or mime_type".to_string(),
@@ -369,7 +406,8 @@ pub fn detect_or_validate(path: Option<&Path>, mime_type: Option<&str>) -> Resul
 /// Detect MIME type from raw file bytes.
 ///
 /// Uses magic byte signatures to detect file type from content.
-/// Falls back to `infer` crate for comprehensive detection.
+/// Falls back to `infer` crate for comprehensive detection. Registered
+/// [`MimeDetector`](crate::plugins::MimeDetector)s are asked first.
 ///
 /// For ZIP-based files, inspects contents to distinguish Office Open XML
 /// formats (DOCX, XLSX, PPTX) from plain ZIP archives.
@@ -386,6 +424,56 @@ pub fn detect_or_validate(path: Option<&Path>, mime_type: Option<&str>) -> Resul
 ///
 /// Returns `KreuzbergError::UnsupportedFormat` if MIME type cannot be determined.
 pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
+    detect_mime_type_from_content(content, false)
+}
+
+/// Detect MIME type from the start of a stream.
+///
+/// Reads at most [`MIME_SNIFF_BYTES`] from `reader` and detects the type from them, so
+/// large streams are never buffered in full. Returns the detected type together with
+/// the bytes that were read, which the caller can put back in front of the rest of the
+/// stream, e.g. with `Cursor::new(header).chain(reader)`.
+///
+/// Detection works as for [`detect_mime_type_from_bytes`], with allowances for a header
+/// that ends mid-document: JSON only has to be valid up to the cut, and a UTF-8 sequence
+/// split by the cut is ignored. Office formats are recognized when their marker entry
+/// starts within the header, which is where Word, Excel and PowerPoint write it.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Io` if reading fails, or
+/// `KreuzbergError::UnsupportedFormat` if the MIME type cannot be determined.
+///
+/// # Example
+///
+/// ```
+/// use kreuzberg::core::mime::detect_mime_type_from_reader;
+/// use std::io::{Cursor, Read};
+///
+/// let mut stream = Cursor::new(b"%PDF-1.7\n...".to_vec());
+/// let (mime_type, header) = detect_mime_type_from_reader(&mut stream).unwrap();
+/// assert_eq!(mime_type, "application/pdf");
+///
+/// let mut content = Vec::new();
+/// Cursor::new(header).chain(stream).read_to_end(&mut content).unwrap();
+/// assert_eq!(content, b"%PDF-1.7\n...");
+/// ```
+pub fn detect_mime_type_from_reader<R: Read + ?Sized>(reader: &mut R) -> Result<(String, Vec<u8>)> {
+    let mut header = Vec::with_capacity(MIME_SNIFF_BYTES);
+    Read::take(&mut *reader, MIME_SNIFF_BYTES as u64).read_to_end(&mut header)?;
+
+    let truncated = header.len() == MIME_SNIFF_BYTES;
+    let mime_type = detect_mime_type_from_content(&header, truncated)?;
+    Ok((mime_type, header))
+}
+
+/// Detect MIME type from `content`, which is only the start of the document when
+/// `truncated` is set.
+fn detect_mime_type_from_content(content: &[u8], truncated: bool) -> Result<String> {
+    if let Some(mime_type) = detect_with_registered_detectors(content)? {
+        return Ok(mime_type);
+    }
+
     if let Some(kind) = infer::get(content) {
         let mime_type = kind.mime_type();
 
@@ -401,11 +489,23 @@ pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
         }
     }
 
-    if let Ok(text) = std::str::from_utf8(content) {
+    let text = match std::str::from_utf8(content) {
+        Ok(text) => Some(text),
+        // A multi-byte character cut off at the end of the header
+        Err(error) if truncated && error.error_len().is_none() => {
+            std::str::from_utf8(&content[..error.valid_up_to()]).ok()
+        }
+        Err(_) => None,
+    };
+
+    if let Some(text) = text {
         let trimmed = text.trim_start();
 
         if (trimmed.starts_with('{') || trimmed.starts_with('['))
-            && serde_json::from_str::<serde_json::Value>(text).is_ok()
+            && match serde_json::from_str::<serde_json::Value>(text) {
+                Ok(_) => true,
+                Err(error) => truncated && error.is_eof(),
+            }
         {
             return Ok(JSON_MIME_TYPE.to_string());
         }
@@ -425,11 +525,45 @@ pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
         return Ok(PLAIN_TEXT_MIME_TYPE.to_string());
     }
 
-    Err(KreuzbergError::UnsupportedFormat(
-        "Could not determine MIME type from bytes".to_string(),
+    Err(KreuzbergError::unsupported_format_with_reason(
+        "application/octet-stream",
+        "could not determine MIME type from bytes",
     ))
 }
 
+/// Ask the registered MIME detectors about `header`.
+pub(crate) fn detect_with_registered_detectors(header: &[u8]) -> Result<Option<String>> {
+    let registry = crate::plugins::registry::get_mime_detector_registry();
+    let registry = registry
+        .read()
+        .map_err(|_| KreuzbergError::LockPoisoned("MIME detector registry lock poisoned".to_string()))?;
+
+    Ok(registry.detect(header))
+}
+
+/// Ask the registered MIME detectors about the start of the file at `path`.
+///
+/// The file is only read when a detector is registered.
+fn detect_file_with_registered_detectors(path: &Path) -> Result<Option<String>> {
+    let registry = crate::plugins::registry::get_mime_detector_registry();
+    let has_detectors = !registry
+        .read()
+        .map_err(|_| KreuzbergError::LockPoisoned("MIME detector registry lock poisoned".to_string()))?
+        .is_empty();
+    if !has_detectors {
+        return Ok(None);
+    }
+
+    let mut header = Vec::with_capacity(MIME_SNIFF_BYTES);
+    let read =
+        std::fs::File::open(path).and_then(|file| Read::take(file, MIME_SNIFF_BYTES as u64).read_to_end(&mut header));
+    if read.is_err() {
+        return Ok(None);
+    }
+
+    detect_with_registered_detectors(&header)
+}
+
 /// Detect Office Open XML format from ZIP content by scanning for marker files.
 ///
 /// Office Open XML formats (DOCX, XLSX, PPTX) are ZIP archives containing specific
@@ -437,6 +571,7 @@ pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
 /// - DOCX: contains `word/document.xml`
 /// - XLSX: contains `xl/workbook.xml`
 /// - PPTX: contains `ppt/presentation.xml`
+/// - Pages / Numbers: contain `Index/Document.iwa` (Numbers also `Index/CalculationEngine.iwa`)
 ///
 /// This function scans the ZIP's local file headers without fully parsing the archive,
 /// making it efficient for MIME type detection.
@@ -445,6 +580,9 @@ fn detect_office_format_from_zip(content: &[u8]) -> Option<&'static str> {
     const DOCX_MARKER: &[u8] = b"word/document.xml";
     const XLSX_MARKER: &[u8] = b"xl/workbook.xml";
     const PPTX_MARKER: &[u8] = b"ppt/presentation.xml";
+    const IWORK_MARKER: &[u8] = b"Index/Document.iwa";
+    const NUMBERS_MARKER: &[u8] = b"Index/CalculationEngine.iwa";
+    const KEYNOTE_MARKER: &[u8] = b"Index/Slide";
 
     // Check for each marker using a sliding window search
     if contains_subsequence(content, DOCX_MARKER) {
@@ -457,6 +595,17 @@ fn detect_office_format_from_zip(content: &[u8]) -> Option<&'static str> {
         return Some(POWER_POINT_MIME_TYPE);
     }
 
+    // iWork bundles: every app writes Index/Document.iwa, Numbers adds its calculation engine
+    // and Keynote (unsupported) its slides
+    if contains_subsequence(content, IWORK_MARKER) {
+        if contains_subsequence(content, NUMBERS_MARKER) {
+            return Some(NUMBERS_MIME_TYPE);
+        }
+        if !contains_subsequence(content, KEYNOTE_MARKER) {
+            return Some(PAGES_MIME_TYPE);
+        }
+    }
+
     None
 }
 
@@ -507,10 +656,25 @@ pub fn get_extensions_for_mime(mime_type: &str) -> Result<Vec<String>> {
         return Ok(exts.iter().map(|s| s.to_string()).collect());
     }
 
-    Err(KreuzbergError::UnsupportedFormat(format!(
-        "No known extensions for MIME type: {}",
-        mime_type
-    )))
+    Err(KreuzbergError::unsupported_format(mime_type))
+}
+
+/// Record the extension of `path` on an `UnsupportedFormat` error that doesn't have one yet.
+///
+/// Other errors are returned unchanged.
+pub(crate) fn with_detected_extension(error: KreuzbergError, path: &Path) -> KreuzbergError {
+    match error {
+        KreuzbergError::UnsupportedFormat {
+            mime_type,
+            detected_extension: None,
+            reason,
+        } => KreuzbergError::UnsupportedFormat {
+            mime_type,
+            detected_extension: path.extension().and_then(|ext| ext.to_str()).map(str::to_lowercase),
+            reason,
+        },
+        other => other,
+    }
 }
 
 #[cfg(test)]
@@ -519,6 +683,24 @@ mod tests {
     use std::fs::File;
     use tempfile::tempdir;
 
+    /// Reader that fails when asked for bytes past the MIME sniffing window.
+    struct SniffWindowReader {
+        data: Vec<u8>,
+        position: usize,
+    }
+
+    impl Read for SniffWindowReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.position + buf.len() > MIME_SNIFF_BYTES {
+                return Err(std::io::Error::other("read past the sniffing window"));
+            }
+            let len = buf.len().min(self.data.len() - self.position);
+            buf[..len].copy_from_slice(&self.data[self.position..self.position + len]);
+            self.position += len;
+            Ok(len)
+        }
+    }
+
     #[test]
     fn test_detect_mime_type_pdf() {
         let dir = tempdir().unwrap();
@@ -570,6 +752,8 @@ mod tests {
             ("test.ppt", LEGACY_POWERPOINT_MIME_TYPE),
             ("test.docx", DOCX_MIME_TYPE),
             ("test.doc", LEGACY_WORD_MIME_TYPE),
+            ("test.pages", PAGES_MIME_TYPE),
+            ("test.numbers", NUMBERS_MIME_TYPE),
         ];
 
         for (filename, expected_mime) in test_cases {
@@ -590,6 +774,7 @@ mod tests {
             ("test.toml", TOML_MIME_TYPE),
             ("test.xml", XML_MIME_TYPE),
             ("test.csv", "text/csv"),
+            ("drawing.dxf", DXF_MIME_TYPE),
         ];
 
         for (filename, expected_mime) in test_cases {
@@ -711,6 +896,52 @@ mod tests {
         assert_eq!(mime2, EXCEL_MIME_TYPE);
     }
 
+    #[test]
+    fn test_detect_mime_type_from_reader_reads_only_the_header() {
+        let mut data = b"%PDF-1.7\n".to_vec();
+        data.resize(1024 * 1024, b'x');
+        let mut reader = SniffWindowReader { data, position: 0 };
+
+        let (mime, header) = detect_mime_type_from_reader(&mut reader).unwrap();
+        assert_eq!(mime, PDF_MIME_TYPE);
+        assert_eq!(header.len(), MIME_SNIFF_BYTES);
+        assert_eq!(header, reader.data[..MIME_SNIFF_BYTES]);
+    }
+
+    #[test]
+    fn test_detect_mime_type_from_reader_handles_truncated_text() {
+        let items: Vec<String> = (0..2000).map(|i| format!("{{\"id\": {}}}", i)).collect();
+        let json = format!("[{}]", items.join(", "));
+        let mut reader = SniffWindowReader {
+            data: json.into_bytes(),
+            position: 0,
+        };
+        let (mime, _) = detect_mime_type_from_reader(&mut reader).unwrap();
+        assert_eq!(mime, JSON_MIME_TYPE);
+
+        // A two-byte character straddling the end of the window
+        let mut text = "a".repeat(MIME_SNIFF_BYTES - 1);
+        text.push_str("é and more text");
+        let mut reader = SniffWindowReader {
+            data: text.into_bytes(),
+            position: 0,
+        };
+        let (mime, _) = detect_mime_type_from_reader(&mut reader).unwrap();
+        assert_eq!(mime, PLAIN_TEXT_MIME_TYPE);
+    }
+
+    #[test]
+    fn test_detect_mime_type_from_reader_short_stream() {
+        let mut reader = std::io::Cursor::new(br#"{"a": 1}"#.to_vec());
+        let (mime, header) = detect_mime_type_from_reader(&mut reader).unwrap();
+        assert_eq!(mime, JSON_MIME_TYPE);
+        assert_eq!(header, br#"{"a": 1}"#);
+
+        let mut reader = std::io::Cursor::new(b"[not json".to_vec());
+        let (mime, _) = detect_mime_type_from_reader(&mut reader).unwrap();
+        assert_eq!(mime, PLAIN_TEXT_MIME_TYPE);
+    }
+
     #[test]
     fn test_detect_office_format_from_zip_bytes() {
         // Test DOCX detection - minimal ZIP with word/document.xml marker
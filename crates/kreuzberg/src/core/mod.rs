@@ -13,6 +13,7 @@
 //! - **Pipeline**: Orchestrating post-processing steps (chunking, quality, etc.)
 //! - **Configuration**: Loading and managing extraction configuration
 //! - **I/O**: File reading and validation utilities
+//! - **Thumbnails**: PNG previews of documents via `extract_thumbnail()`
 //!
 //! # Example
 //!
@@ -39,18 +40,22 @@ pub mod formats;
 pub mod io;
 pub mod mime;
 pub mod pipeline;
+pub mod progress;
 pub mod server_config;
+#[cfg(any(feature = "pdf", feature = "ocr"))]
+pub mod thumbnail;
 
 #[cfg(feature = "pdf")]
 pub use config::HierarchyConfig;
 pub use config::{
-    ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig, ImageExtractionConfig,
-    LanguageDetectionConfig, OcrConfig, OutputFormat, PageConfig, PostProcessorConfig, TokenReductionConfig,
+    ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig, ImageExtractionConfig, ImageFormat,
+    LanguageDetectionConfig, LineEnding, OcrConfig, OutputFormat, OutputMode, PageConfig, PostProcessorConfig,
+    TokenReductionConfig,
 };
 pub use config_validation::{
     validate_binarization_method, validate_chunking_params, validate_confidence, validate_dpi, validate_language_code,
-    validate_ocr_backend, validate_output_format, validate_tesseract_oem, validate_tesseract_psm,
-    validate_token_reduction_level,
+    validate_ocr_backend, validate_ocr_region, validate_output_format, validate_page_filter, validate_tesseract_oem,
+    validate_tesseract_psm, validate_token_reduction_level,
 };
 pub use formats::{KNOWN_FORMATS, is_valid_format_field};
 pub use server_config::ServerConfig;
@@ -62,3 +67,8 @@ pub use config::PdfConfig;
 #[cfg(feature = "tokio-runtime")]
 pub use extractor::{batch_extract_bytes, batch_extract_file};
 pub use extractor::{extract_bytes, extract_file};
+#[cfg(feature = "tokio-runtime")]
+pub use extractor::{extract_file_with_progress, extract_file_with_progress_sync};
+pub use progress::ProgressEvent;
+#[cfg(any(feature = "pdf", feature = "ocr"))]
+pub use thumbnail::extract_thumbnail;
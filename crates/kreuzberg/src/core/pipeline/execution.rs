@@ -1,7 +1,7 @@
 //! Core processor execution logic.
 //!
-//! This module handles the execution of post-processors and validators
-//! in the correct order.
+//! This module handles the execution of post-processors, validators and
+//! image captioners in the correct order.
 
 use crate::core::config::ExtractionConfig;
 use crate::plugins::ProcessingStage;
@@ -88,3 +88,66 @@ pub(super) async fn execute_validators(result: &ExtractionResult, config: &Extra
 
     Ok(())
 }
+
+/// Caption extracted images with the active image captioner, if one is registered.
+///
+/// Only images without a caption are sent to the captioner. Captions are copied onto
+/// the matching per-page images (by `image_index`) so both views agree.
+pub(super) async fn execute_image_captioning(result: &mut ExtractionResult) -> Result<()> {
+    if result.images.as_ref().is_none_or(|images| images.is_empty()) {
+        return Ok(());
+    }
+
+    let captioner = {
+        let registry = crate::plugins::registry::get_image_captioner_registry();
+        let registry = registry
+            .read()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Image captioner registry lock poisoned: {}", e)))?;
+        registry.active()
+    };
+    let Some(captioner) = captioner else {
+        return Ok(());
+    };
+
+    let mut errors = Vec::new();
+    if let Some(images) = result.images.as_mut() {
+        for image in images.iter_mut().filter(|image| image.caption.is_none()) {
+            match captioner.caption(image).await {
+                Ok(caption) => image.caption = Some(caption),
+                Err(err @ KreuzbergError::Io(_))
+                | Err(err @ KreuzbergError::LockPoisoned(_))
+                | Err(err @ KreuzbergError::Plugin { .. }) => {
+                    return Err(err);
+                }
+                Err(err) => errors.push(serde_json::json!({
+                    "image_index": image.image_index,
+                    "error": err.to_string(),
+                })),
+            }
+        }
+    }
+
+    if let (Some(images), Some(pages)) = (result.images.as_ref(), result.pages.as_mut()) {
+        for page_image in pages.iter_mut().flat_map(|page| page.images.iter_mut()) {
+            if page_image.caption.is_some() {
+                continue;
+            }
+            let caption = images
+                .iter()
+                .find(|image| image.image_index == page_image.image_index)
+                .and_then(|image| image.caption.clone());
+            if caption.is_some() {
+                std::sync::Arc::make_mut(page_image).caption = caption;
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        result
+            .metadata
+            .additional
+            .insert(Cow::Borrowed("image_caption_errors"), serde_json::Value::Array(errors));
+    }
+
+    Ok(())
+}
@@ -1,23 +1,593 @@
 //! Feature processing logic.
 //!
-//! This module handles feature-specific processing like chunking,
-//! embedding generation, and language detection.
+//! This module handles feature-specific processing like content truncation,
+//! chunking, embedding generation, language detection and newline normalization.
 
 use crate::Result;
-use crate::core::config::ExtractionConfig;
+use crate::core::config::{ExtractionConfig, ImageFormat, LineEnding};
+use crate::text::bidi::{contains_rtl, normalize_bidi};
 use crate::types::ExtractionResult;
 use std::borrow::Cow;
 
+/// Maximum number of characters to look back from the limit for a paragraph break.
+const TRUNCATION_PARAGRAPH_TOLERANCE: usize = 200;
+
+/// Truncate content to `max_content_chars` if configured.
+///
+/// Cuts on a character boundary, preferring the last paragraph break within a
+/// small window before the limit. Page boundaries and pages past the cut are dropped,
+/// and the page the cut falls in is shortened, so pages and chunking stay consistent
+/// with the shortened content.
+pub(super) fn execute_truncation(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    let Some(max_chars) = config.max_content_chars else {
+        return;
+    };
+    let Some(cut) = truncation_point(&result.content, max_chars) else {
+        return;
+    };
+
+    result.content.truncate(cut);
+
+    match result.metadata.pages.as_mut().and_then(|ps| ps.boundaries.as_mut()) {
+        Some(boundaries) => {
+            let cut_page = boundaries
+                .iter()
+                .find(|boundary| boundary.byte_start < cut && boundary.byte_end > cut)
+                .map(|boundary| (boundary.page_number, boundary.byte_start));
+            boundaries.retain(|boundary| boundary.byte_start < cut);
+            for boundary in boundaries.iter_mut() {
+                boundary.byte_end = boundary.byte_end.min(cut);
+            }
+
+            if let Some(pages) = result.pages.as_mut() {
+                pages.retain(|page| boundaries.iter().any(|b| b.page_number == page.page_number));
+                if let Some((page_number, byte_start)) = cut_page
+                    && let Some(page) = pages.iter_mut().find(|page| page.page_number == page_number)
+                    && let Some(kept) = result.content.get(byte_start..)
+                {
+                    page.content = kept.to_string();
+                }
+            }
+        }
+        None => {
+            if let Some(pages) = result.pages.as_mut() {
+                truncate_pages(pages, result.content.chars().count());
+            }
+        }
+    }
+
+    result.metadata.truncated = Some(true);
+}
+
+/// Keep the leading pages holding at most `max_chars` characters, shortening the last one.
+fn truncate_pages(pages: &mut Vec<crate::types::PageContent>, max_chars: usize) {
+    let mut remaining = max_chars;
+    pages.retain_mut(|page| {
+        if remaining == 0 {
+            return false;
+        }
+        match page.content.char_indices().nth(remaining) {
+            Some((end, _)) => {
+                page.content.truncate(end);
+                remaining = 0;
+            }
+            None => remaining -= page.content.chars().count(),
+        }
+        true
+    });
+}
+
+/// Move the source map onto the final content if the pipeline rewrote it.
+///
+/// `extracted_content` is the content the extractor built the map against. Spans whose
+/// text is gone from the content (truncation, token reduction) are dropped.
+pub(super) fn execute_source_map_realignment(result: &mut ExtractionResult, extracted_content: Option<String>) {
+    let (Some(extracted_content), Some(source_map)) = (extracted_content, result.source_map.as_mut()) else {
+        return;
+    };
+    if extracted_content != result.content {
+        *source_map = crate::types::source_map::realign_source_map(source_map, &extracted_content, &result.content);
+    }
+}
+
+/// Rewrite line endings to `normalize_newlines` if configured.
+///
+/// Applies to `content`, page contents and chunk contents. Page boundaries, chunk byte
+/// offsets and source map ranges are shifted to match the rewritten content.
+pub(super) fn execute_newline_normalization(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    let target = match config.normalize_newlines {
+        Some(target @ (LineEnding::Lf | LineEnding::CrLf)) => target,
+        Some(LineEnding::Keep) | None => return,
+    };
+
+    let Some((content, breaks)) = convert_line_endings(&result.content, target) else {
+        return;
+    };
+    result.content = content;
+
+    let remap = |offset: usize| {
+        let shifted = breaks.partition_point(|&pos| pos < offset);
+        match target {
+            LineEnding::CrLf => offset + shifted,
+            _ => offset - shifted,
+        }
+    };
+
+    if let Some(boundaries) = result.metadata.pages.as_mut().and_then(|ps| ps.boundaries.as_mut()) {
+        for boundary in boundaries.iter_mut() {
+            boundary.byte_start = remap(boundary.byte_start);
+            boundary.byte_end = remap(boundary.byte_end);
+        }
+    }
+
+    for chunk in result.chunks.iter_mut().flatten() {
+        chunk.metadata.byte_start = remap(chunk.metadata.byte_start);
+        chunk.metadata.byte_end = remap(chunk.metadata.byte_end);
+        if let Some((content, _)) = convert_line_endings(&chunk.content, target) {
+            chunk.content = content;
+        }
+    }
+
+    for span in result.source_map.iter_mut().flatten() {
+        span.content_start = remap(span.content_start);
+        span.content_end = remap(span.content_end);
+    }
+
+    for page in result.pages.iter_mut().flatten() {
+        if let Some((content, _)) = convert_line_endings(&page.content, target) {
+            page.content = content;
+        }
+    }
+}
+
+/// Convert every line break in `text` (`\r\n`, `\n` or a lone `\r`) to `target`.
+///
+/// Returns the converted text and the byte offsets, in the original text, of the
+/// line breaks whose length changed. Each of them shifts later offsets by one byte.
+/// Returns `None` when the text is already in the target form.
+pub(super) fn convert_line_endings(text: &str, target: LineEnding) -> Option<(String, Vec<usize>)> {
+    let bytes = text.as_bytes();
+    let mut breaks = Vec::new();
+    let mut changed = false;
+    let mut output = String::with_capacity(text.len());
+    let mut last = 0;
+
+    for (pos, &byte) in bytes.iter().enumerate() {
+        let crlf = byte == b'\r' && bytes.get(pos + 1) == Some(&b'\n');
+        let replacement = match (target, byte) {
+            (LineEnding::Lf, b'\r') if crlf => {
+                breaks.push(pos);
+                ""
+            }
+            (LineEnding::Lf, b'\r') => "\n",
+            (LineEnding::CrLf, b'\r') if crlf => continue,
+            (LineEnding::CrLf, b'\r') => {
+                breaks.push(pos);
+                "\r\n"
+            }
+            (LineEnding::CrLf, b'\n') if pos == 0 || bytes[pos - 1] != b'\r' => {
+                breaks.push(pos);
+                "\r\n"
+            }
+            _ => continue,
+        };
+        changed = true;
+        output.push_str(&text[last..pos]);
+        output.push_str(replacement);
+        last = pos + 1;
+    }
+
+    if !changed {
+        return None;
+    }
+    output.push_str(&text[last..]);
+    Some((output, breaks))
+}
+
+/// Re-encode extracted images to `images.output_format` if configured.
+///
+/// Applies to `result.images` and to the images attached to each page; each image is
+/// encoded once and its page copies share the result. Images that cannot be decoded,
+/// or whose dimensions exceed `max_decode_dimension`, keep their original bytes and
+/// format. Without an image codec compiled in (the `pdf` or `ocr` features), images
+/// are left untouched.
+pub(super) fn execute_image_conversion(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    let Some(images_config) = config.images.as_ref() else {
+        return;
+    };
+    let target = images_config.output_format;
+    if target == ImageFormat::Original {
+        return;
+    }
+
+    #[cfg(any(feature = "pdf", feature = "ocr"))]
+    {
+        for image in result.images.iter_mut().flatten() {
+            reencode_image(image, target, images_config);
+        }
+        // Page images are copies of `result.images`; reuse their encoding by `image_index`
+        // and only encode the ones without a counterpart.
+        let images = result.images.as_deref().unwrap_or_default();
+        for page_image in result
+            .pages
+            .iter_mut()
+            .flatten()
+            .flat_map(|page| page.images.iter_mut())
+        {
+            match images.iter().find(|image| image.image_index == page_image.image_index) {
+                Some(image) => {
+                    let page_image = std::sync::Arc::make_mut(page_image);
+                    page_image.data = image.data.clone();
+                    page_image.format = image.format.clone();
+                    page_image.width = image.width;
+                    page_image.height = image.height;
+                }
+                None => reencode_image(std::sync::Arc::make_mut(page_image), target, images_config),
+            }
+        }
+    }
+
+    #[cfg(not(any(feature = "pdf", feature = "ocr")))]
+    {
+        if result.images.as_ref().is_some_and(|images| !images.is_empty()) {
+            tracing::warn!(
+                "images.output_format = {} requires the 'pdf' or 'ocr' feature; keeping original images",
+                target
+            );
+        }
+    }
+}
+
+/// Decode `image` and replace its data with the `target` encoding.
+#[cfg(any(feature = "pdf", feature = "ocr"))]
+fn reencode_image(
+    image: &mut crate::types::ExtractedImage,
+    target: ImageFormat,
+    images_config: &crate::core::config::ImageExtractionConfig,
+) {
+    use image::ImageEncoder;
+    use image::codecs::jpeg::JpegEncoder;
+    use image::codecs::png::PngEncoder;
+    use image::codecs::webp::WebPEncoder;
+
+    let Some(extension) = target.extension() else {
+        return;
+    };
+
+    let decoded = match crate::extraction::image_header::decode_image(&image.data, images_config.max_decode_dimension) {
+        Ok(decoded) => decoded,
+        Err(e) => {
+            tracing::debug!("Keeping image {} as {}: {}", image.image_index, image.format, e);
+            return;
+        }
+    };
+
+    // JPEG has no alpha channel and WebP is written lossless from 8-bit RGBA.
+    let decoded = match target {
+        ImageFormat::Jpeg { .. } => image::DynamicImage::ImageRgb8(decoded.to_rgb8()),
+        ImageFormat::Webp => image::DynamicImage::ImageRgba8(decoded.to_rgba8()),
+        _ => decoded,
+    };
+    let (width, height) = (decoded.width(), decoded.height());
+
+    let mut buffer = Vec::new();
+    let encoded = match target {
+        ImageFormat::Png => {
+            PngEncoder::new(&mut buffer).write_image(decoded.as_bytes(), width, height, decoded.color().into())
+        }
+        ImageFormat::Jpeg { quality } => JpegEncoder::new_with_quality(&mut buffer, quality).write_image(
+            decoded.as_bytes(),
+            width,
+            height,
+            decoded.color().into(),
+        ),
+        ImageFormat::Webp => WebPEncoder::new_lossless(&mut buffer).write_image(
+            decoded.as_bytes(),
+            width,
+            height,
+            decoded.color().into(),
+        ),
+        ImageFormat::Original => return,
+    };
+
+    if let Err(e) = encoded {
+        tracing::debug!(
+            "Keeping image {} as {}: failed to encode ({})",
+            image.image_index,
+            image.format,
+            e
+        );
+        return;
+    }
+
+    image.data = bytes::Bytes::from(buffer);
+    image.format = Cow::Borrowed(extension);
+    image.width = Some(width);
+    image.height = Some(height);
+}
+
+/// Join the pages of non-PDF documents with `pdf_options.page_separator`, if set.
+///
+/// The PDF extractor inserts the separator itself. For other paginated formats (DOCX,
+/// PPTX, EPUB) the text between consecutive pages is replaced with the separator and the
+/// page boundaries are moved onto the new content, so this needs page tracking
+/// (`pages`) to be enabled. Skipped when `pages.insert_page_markers` is set. Runs before
+/// truncation so `max_content_chars` applies to the joined content.
+pub(super) fn execute_page_separator(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    #[cfg(feature = "pdf")]
+    {
+        let Some(pdf_config) = config
+            .pdf_options
+            .as_ref()
+            .filter(|pdf_config| pdf_config.page_separator.is_some())
+        else {
+            return;
+        };
+        if result.mime_type == "application/pdf" || config.pages.as_ref().is_some_and(|pages| pages.insert_page_markers)
+        {
+            return;
+        }
+        let Some(boundaries) = result
+            .metadata
+            .pages
+            .as_mut()
+            .and_then(|pages| pages.boundaries.as_mut())
+            .filter(|boundaries| boundaries.len() > 1)
+        else {
+            return;
+        };
+
+        // `rewrite_pages` visits the text before the first page, between each pair of
+        // pages, and after the last page, in order.
+        let page_numbers: Vec<usize> = boundaries.iter().map(|boundary| boundary.page_number).collect();
+        let mut gap = 0;
+        let rewritten =
+            crate::types::PageBoundary::rewrite_pages(&result.content, boundaries, str::to_string, |between| {
+                let index = gap;
+                gap += 1;
+                match page_numbers.get(index) {
+                    Some(&page_number) if index > 0 => pdf_config.separator_before_page(page_number).into_owned(),
+                    _ => between.to_string(),
+                }
+            });
+        if let Some(content) = rewritten {
+            result.content = content;
+        }
+    }
+
+    #[cfg(not(feature = "pdf"))]
+    let _ = (result, config);
+}
+
+/// Reorder right-to-left text into logical order if `normalize_bidi` is set.
+///
+/// Runs right after truncation so later steps see logical order. Reordering keeps the byte
+/// length of every line, and `content` is reordered page by page, so page boundaries
+/// stay valid.
+pub(super) fn execute_bidi_normalization(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    if !config.normalize_bidi {
+        return;
+    }
+
+    if contains_rtl(&result.content) {
+        let content = &result.content;
+        let mut cuts: Vec<usize> = result
+            .metadata
+            .pages
+            .as_ref()
+            .and_then(|pages| pages.boundaries.as_deref())
+            .unwrap_or_default()
+            .iter()
+            .flat_map(|boundary| [boundary.byte_start, boundary.byte_end])
+            .filter(|&offset| offset < content.len() && content.is_char_boundary(offset))
+            .collect();
+        cuts.push(content.len());
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let mut normalized = String::with_capacity(content.len());
+        let mut start = 0;
+        for end in cuts {
+            normalized.push_str(&normalize_bidi(&content[start..end]));
+            start = end;
+        }
+        result.content = normalized;
+    }
+
+    for page in result.pages.iter_mut().flatten() {
+        if let Cow::Owned(normalized) = normalize_bidi(&page.content) {
+            page.content = normalized;
+        }
+    }
+}
+
+/// Date fields normalized by [`execute_date_normalization`].
+const DATE_FIELDS: [&str; 2] = ["created_at", "modified_at"];
+
+/// Clean up detected tables according to `table_options`.
+///
+/// Applies to `result.tables` and to the tables attached to each page. Only the
+/// table cells and their Markdown are rewritten; tables already rendered into
+/// `content` by the extractor are left as they are.
+pub(super) fn execute_table_cleanup(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    let Some(options) = config.table_options.filter(|options| !options.is_noop()) else {
+        return;
+    };
+
+    for table in &mut result.tables {
+        options.apply_to_table(table);
+    }
+    for page in result.pages.iter_mut().flatten() {
+        for table in &mut page.tables {
+            options.apply_to_table(std::sync::Arc::make_mut(table));
+        }
+    }
+}
+
+/// Normalize `created_at` / `modified_at` metadata to RFC 3339.
+///
+/// Applies to the typed fields and to string values of the same name in
+/// `additional` (used by the Office extractors). When a value is rewritten, the
+/// original is kept under `<field>_raw`. Unrecognized dates are left untouched.
+pub(super) fn execute_date_normalization(result: &mut ExtractionResult) {
+    let metadata = &mut result.metadata;
+    let mut raw_values = Vec::new();
+
+    for (field, value) in DATE_FIELDS
+        .into_iter()
+        .zip([&mut metadata.created_at, &mut metadata.modified_at])
+    {
+        if let Some(raw) = value.as_mut()
+            && let Some(normalized) = normalize_changed(raw)
+        {
+            raw_values.push((field, std::mem::replace(raw, normalized)));
+        }
+    }
+
+    for field in DATE_FIELDS {
+        if let Some(serde_json::Value::String(raw)) = metadata.additional.get_mut(field)
+            && let Some(normalized) = normalize_changed(raw)
+        {
+            raw_values.push((field, std::mem::replace(raw, normalized)));
+        }
+    }
+
+    for (field, raw) in raw_values {
+        metadata
+            .additional
+            .entry(Cow::Owned(format!("{}_raw", field)))
+            .or_insert(serde_json::Value::String(raw));
+    }
+}
+
+fn normalize_changed(raw: &str) -> Option<String> {
+    crate::utils::normalize_date(raw).filter(|normalized| normalized != raw)
+}
+
+/// Byte offset at which content longer than `max_chars` characters should be cut.
+///
+/// Returns `None` when the content already fits.
+pub(super) fn truncation_point(content: &str, max_chars: usize) -> Option<usize> {
+    let limit = content.char_indices().nth(max_chars).map(|(idx, _)| idx)?;
+
+    let tolerance = TRUNCATION_PARAGRAPH_TOLERANCE.min(max_chars / 10);
+    let window_start = content
+        .char_indices()
+        .nth(max_chars - tolerance)
+        .map_or(limit, |(idx, _)| idx);
+
+    let cut = content[window_start..limit]
+        .rfind("\n\n")
+        .map_or(limit, |offset| window_start + offset);
+
+    Some(content[..cut].trim_end().len())
+}
+
+/// Apply token reduction if configured.
+///
+/// The reducer gets `mode`, `language` and `preserve_important_words` from
+/// `token_reduction`; the language falls back to the primary detected language. Records `token_reduction_stats` (`original_chars`, `reduced_chars`, `reduction_ratio`)
+/// in metadata. When page boundaries are known each page is reduced separately and the
+/// boundaries are rebuilt for the reduced content.
+pub(super) fn execute_token_reduction(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    #[cfg(feature = "quality")]
+    if let Some(ref reduction_config) = config.token_reduction {
+        use crate::text::token_reduction::{
+            ReductionLevel, TokenReducer, TokenReductionConfig, get_reduction_statistics,
+        };
+
+        let mut reducer_config = TokenReductionConfig::from(reduction_config);
+        if reducer_config.level == ReductionLevel::Off {
+            return;
+        }
+
+        // An explicit language wins; otherwise use the primary detected language.
+        if reducer_config.language_hint.is_none() {
+            reducer_config.language_hint = result
+                .detected_languages
+                .as_ref()
+                .and_then(|languages| languages.first().cloned());
+        }
+
+        match TokenReducer::new(&reducer_config, None) {
+            Ok(reducer) => {
+                let original = result.content.clone();
+                reduce_content(result, &reducer);
+                let (reduction_ratio, _, original_chars, reduced_chars, _, _) =
+                    get_reduction_statistics(&original, &result.content);
+
+                result.metadata.additional.insert(
+                    Cow::Borrowed("token_reduction_stats"),
+                    serde_json::json!({
+                        "original_chars": original_chars,
+                        "reduced_chars": reduced_chars,
+                        "reduction_ratio": reduction_ratio,
+                    }),
+                );
+            }
+            Err(e) => {
+                result.metadata.additional.insert(
+                    Cow::Borrowed("token_reduction_error"),
+                    serde_json::Value::String(e.to_string()),
+                );
+            }
+        }
+    }
+
+    #[cfg(not(feature = "quality"))]
+    if config.token_reduction.is_some() {
+        result.metadata.additional.insert(
+            Cow::Borrowed("token_reduction_error"),
+            serde_json::Value::String("Token reduction requires the quality feature".to_string()),
+        );
+    }
+}
+
+/// Reduce the content page by page, keeping the text between pages (markers, separators) as is.
+#[cfg(feature = "quality")]
+fn reduce_content(result: &mut ExtractionResult, reducer: &crate::text::token_reduction::TokenReducer) {
+    let content = std::mem::take(&mut result.content);
+    let boundaries = result
+        .metadata
+        .pages
+        .as_mut()
+        .and_then(|pages| pages.boundaries.as_mut())
+        .filter(|boundaries| !boundaries.is_empty());
+
+    let Some(boundaries) = boundaries else {
+        result.content = reducer.reduce(&content);
+        return;
+    };
+
+    match crate::types::PageBoundary::rewrite_pages(&content, boundaries, |page| reducer.reduce(page), str::to_string) {
+        Some(reduced) => result.content = reduced,
+        None => {
+            result.content = reducer.reduce(&content);
+            if let Some(pages) = result.metadata.pages.as_mut() {
+                pages.boundaries = None;
+            }
+        }
+    }
+}
+
 /// Execute chunking if configured.
 pub(super) fn execute_chunking(result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
     #[cfg(feature = "chunking")]
     if let Some(ref chunking_config) = config.chunking {
+        crate::core::progress::emit(crate::core::progress::ProgressEvent::ChunkingStarted);
         let page_boundaries = result.metadata.pages.as_ref().and_then(|ps| ps.boundaries.as_deref());
 
         match crate::chunking::chunk_text(&result.content, chunking_config, page_boundaries) {
             Ok(chunking_result) => {
                 result.chunks = Some(chunking_result.chunks);
 
+                if chunking_result.truncated {
+                    result
+                        .metadata
+                        .additional
+                        .insert(Cow::Borrowed("chunks_truncated"), serde_json::Value::Bool(true));
+                }
+
                 if let Some(ref chunks) = result.chunks {
                     result.metadata.additional.insert(
                         Cow::Borrowed("chunk_count"),
@@ -104,3 +674,27 @@ pub(super) fn execute_language_detection(result: &mut ExtractionResult, config:
 
     Ok(())
 }
+
+/// Parse the references section of the content if `extract_references` is set.
+///
+/// Runs before token reduction so entries are parsed from the full text. Results set
+/// by the extractor are kept.
+pub(super) fn execute_reference_extraction(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    if !config.extract_references || result.references.is_some() {
+        return;
+    }
+
+    #[cfg(feature = "references")]
+    {
+        let references = crate::text::references::extract_references(&result.content);
+        if !references.is_empty() {
+            result.references = Some(references);
+        }
+    }
+
+    #[cfg(not(feature = "references"))]
+    result.metadata.additional.insert(
+        Cow::Borrowed("reference_extraction_error"),
+        serde_json::Value::String("References feature not enabled".to_string()),
+    );
+}
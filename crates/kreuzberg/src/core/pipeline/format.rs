@@ -104,7 +104,7 @@ pub fn apply_output_format(result: &mut ExtractionResult, output_format: OutputF
                 }
             } else {
                 // For non-djot documents, wrap plain text in basic HTML
-                let escaped_content = html_escape(&result.content);
+                let escaped_content = crate::text::html_escape(&result.content);
                 result.content = format!("<pre>{}</pre>", escaped_content);
             }
         }
@@ -125,15 +125,6 @@ pub fn apply_output_format(result: &mut ExtractionResult, output_format: OutputF
     }
 }
 
-/// Escape HTML special characters in a string.
-fn html_escape(s: &str) -> String {
-    s.replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('"', "&quot;")
-        .replace('\'', "&#39;")
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,6 +145,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         apply_output_format(&mut result, OutputFormat::Plain);
@@ -201,6 +197,11 @@ mod tests {
                 attributes: Vec::new(),
             }),
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         apply_output_format(&mut result, OutputFormat::Djot);
@@ -224,6 +225,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         apply_output_format(&mut result, OutputFormat::Djot);
@@ -248,6 +254,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         apply_output_format(&mut result, OutputFormat::Html);
@@ -273,6 +284,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         apply_output_format(&mut result, OutputFormat::Html);
@@ -298,6 +314,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         apply_output_format(&mut result, OutputFormat::Markdown);
@@ -330,6 +351,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         apply_output_format(&mut result, OutputFormat::Djot);
@@ -350,6 +376,9 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |".to_string(),
             page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         };
 
         let mut result = ExtractionResult {
@@ -365,6 +394,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         apply_output_format(&mut result, OutputFormat::Html);
@@ -415,6 +449,11 @@ mod tests {
             ocr_elements: None,
             djot_content: Some(djot_content),
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         apply_output_format(&mut result, OutputFormat::Djot);
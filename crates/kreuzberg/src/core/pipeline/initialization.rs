@@ -15,7 +15,7 @@ type ProcessorStages = (
 );
 
 /// Initialize feature-specific systems that may be needed during pipeline execution.
-pub(super) fn initialize_features() {
+pub(crate) fn initialize_features() {
     #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
     {
         let _ = crate::keywords::ensure_initialized();
@@ -31,10 +31,18 @@ pub(super) fn initialize_features() {
         let _ = crate::chunking::ensure_initialized();
     }
 
+    {
+        let registry = crate::plugins::registry::get_post_processor_registry();
+        if let Ok(mut reg) = registry.write() {
+            let _ = reg.register(std::sync::Arc::new(crate::text::HeaderFooterStripper), 40);
+        }
+    }
+
     #[cfg(feature = "quality")]
     {
         let registry = crate::plugins::registry::get_post_processor_registry();
         if let Ok(mut reg) = registry.write() {
+            let _ = reg.register(std::sync::Arc::new(crate::text::TextNormalizer), 35);
             let _ = reg.register(std::sync::Arc::new(crate::text::QualityProcessor), 30);
         }
     }
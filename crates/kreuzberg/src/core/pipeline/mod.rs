@@ -19,17 +19,35 @@ use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::types::ExtractionResult;
 
-use execution::{execute_processors, execute_validators};
-use features::{execute_chunking, execute_language_detection};
-use initialization::{get_processors_from_cache, initialize_features, initialize_processor_cache};
+use execution::{execute_image_captioning, execute_processors, execute_validators};
+use features::{
+    execute_bidi_normalization, execute_chunking, execute_date_normalization, execute_image_conversion,
+    execute_language_detection, execute_newline_normalization, execute_page_separator, execute_reference_extraction,
+    execute_source_map_realignment, execute_table_cleanup, execute_token_reduction, execute_truncation,
+};
+pub(crate) use initialization::initialize_features;
+use initialization::{get_processors_from_cache, initialize_processor_cache};
 
 /// Run the post-processing pipeline on an extraction result.
 ///
 /// Executes post-processing in the following order:
+/// 0. Truncation - Join the pages of non-PDF documents with `pdf_options.page_separator`
+///    if set, parse the references section from the full content if
+///    `extract_references` is set, then enforce `max_content_chars` before any further
+///    processing, reorder right-to-left text if `normalize_bidi` is set, normalize `created_at` /
+///    `modified_at` metadata to RFC 3339, re-encode images to `images.output_format`,
+///    and caption images with the registered `ImageCaptioner`
 /// 1. Post-Processors - Execute by stage (Early, Middle, Late) to modify/enhance the result
 /// 2. Quality Processing - Text cleaning and quality scoring
-/// 3. Chunking - Text splitting if enabled
-/// 4. Validators - Run validation hooks on the processed result (can fail fast)
+/// 3. Language Detection - Detect languages if `language_detection` is configured
+/// 4. Token Reduction - Reduce content if `token_reduction` is configured, using the
+///    detected language's stopwords unless `token_reduction.language` is set
+/// 5. Chunking - Text splitting if enabled
+/// 6. Validators - Run validation hooks on the processed result (can fail fast)
+/// 7. Source Map Realignment - Move `source_map` ranges onto the final content if
+///    any step above (or output format conversion) rewrote it
+/// 8. Newline Normalization - Rewrite line endings if `normalize_newlines` is set,
+///    after output format conversion
 ///
 /// # Arguments
 ///
@@ -53,10 +71,22 @@ use initialization::{get_processors_from_cache, initialize_features, initialize_
     )
 ))]
 pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    // The source map refers to the content as extracted; keep a copy to realign it
+    let extracted_content = result.source_map.is_some().then(|| result.content.clone());
+    execute_page_separator(&mut result, config);
+    execute_reference_extraction(&mut result, config);
+    execute_truncation(&mut result, config);
+    execute_bidi_normalization(&mut result, config);
+    execute_date_normalization(&mut result);
+    execute_image_conversion(&mut result, config);
+    execute_image_captioning(&mut result).await?;
+    execute_table_cleanup(&mut result, config);
+
     let pp_config = config.postprocessor.as_ref();
     let postprocessing_enabled = pp_config.is_none_or(|c| c.enabled);
 
     if postprocessing_enabled {
+        crate::core::progress::emit(crate::core::progress::ProgressEvent::PostProcessingStarted);
         initialize_features();
         initialize_processor_cache()?;
 
@@ -73,8 +103,9 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
         .await?;
     }
 
-    execute_chunking(&mut result, config)?;
     execute_language_detection(&mut result, config)?;
+    execute_token_reduction(&mut result, config);
+    execute_chunking(&mut result, config)?;
     execute_validators(&result, config).await?;
 
     // Transform to element-based output if requested
@@ -89,8 +120,19 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
         result.document = Some(crate::extraction::transform::transform_to_document_structure(&result));
     }
 
-    // Apply output format conversion as the final step
+    // Derive text blocks from OCR elements when the extractor did not provide any
+    if config.emit_positions
+        && result.text_blocks.is_none()
+        && let Some(ocr_elements) = result.ocr_elements.as_deref()
+    {
+        result.text_blocks = Some(crate::types::TextBlock::from_ocr_elements(ocr_elements));
+    }
+
+    // Apply output format conversion, realign the source map with the resulting content,
+    // then rewrite line endings of the final content
     apply_output_format(&mut result, config.output_format);
+    execute_source_map_realignment(&mut result, extracted_content);
+    execute_newline_normalization(&mut result, config);
 
     Ok(result)
 }
@@ -114,17 +156,36 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
 ///
 /// This function is only available when the `tokio-runtime` feature is disabled.
 /// It handles:
+/// - Reference extraction (if `extract_references` is set), before truncation
+/// - Content truncation (if `max_content_chars` is set)
+/// - Right-to-left text reordering (if `normalize_bidi` is set)
+/// - Date normalization of `created_at` / `modified_at`
+/// - Image re-encoding (if `images.output_format` is set)
 /// - Quality processing (if enabled)
-/// - Chunking (if enabled)
 /// - Language detection (if enabled)
+/// - Token reduction (if enabled)
+/// - Chunking (if enabled)
+/// - Source map realignment (if the extractor produced a source map)
+/// - Newline normalization (if `normalize_newlines` is set)
 ///
 /// It does NOT handle:
 /// - Async post-processors
 /// - Async validators
+/// - Image captioners
 #[cfg(not(feature = "tokio-runtime"))]
 pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig) -> Result<ExtractionResult> {
-    execute_chunking(&mut result, config)?;
+    // The source map refers to the content as extracted; keep a copy to realign it
+    let extracted_content = result.source_map.is_some().then(|| result.content.clone());
+    execute_page_separator(&mut result, config);
+    execute_reference_extraction(&mut result, config);
+    execute_truncation(&mut result, config);
+    execute_bidi_normalization(&mut result, config);
+    execute_date_normalization(&mut result);
+    execute_image_conversion(&mut result, config);
+    execute_table_cleanup(&mut result, config);
     execute_language_detection(&mut result, config)?;
+    execute_token_reduction(&mut result, config);
+    execute_chunking(&mut result, config)?;
 
     // Transform to element-based output if requested
     if config.result_format == crate::types::OutputFormat::ElementBased {
@@ -138,8 +199,19 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
         result.document = Some(crate::extraction::transform::transform_to_document_structure(&result));
     }
 
-    // Apply output format conversion as the final step
+    // Derive text blocks from OCR elements when the extractor did not provide any
+    if config.emit_positions
+        && result.text_blocks.is_none()
+        && let Some(ocr_elements) = result.ocr_elements.as_deref()
+    {
+        result.text_blocks = Some(crate::types::TextBlock::from_ocr_elements(ocr_elements));
+    }
+
+    // Apply output format conversion, realign the source map with the resulting content,
+    // then rewrite line endings of the final content
     apply_output_format(&mut result, config.output_format);
+    execute_source_map_realignment(&mut result, extracted_content);
+    execute_newline_normalization(&mut result, config);
 
     Ok(result)
 }
@@ -31,6 +31,11 @@ async fn test_run_pipeline_basic() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     result.metadata.additional.insert(
         Cow::Borrowed(VALIDATION_MARKER_KEY),
@@ -58,6 +63,11 @@ async fn test_pipeline_with_quality_processing() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig {
         enable_quality_processing: true,
@@ -83,6 +93,11 @@ async fn test_pipeline_without_quality_processing() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig {
         enable_quality_processing: false,
@@ -109,6 +124,11 @@ async fn test_pipeline_with_chunking() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig {
         chunking: Some(crate::ChunkingConfig {
@@ -118,6 +138,9 @@ async fn test_pipeline_with_chunking() {
             chunker_type: crate::ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: crate::OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         }),
         ..Default::default()
     };
@@ -128,6 +151,50 @@ async fn test_pipeline_with_chunking() {
     assert!(chunk_count.as_u64().unwrap() > 1);
 }
 
+#[tokio::test]
+#[cfg(feature = "chunking")]
+async fn test_pipeline_marks_truncated_chunks() {
+    let result = ExtractionResult {
+        content: "This is a long text that should be chunked. ".repeat(100),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+    let config = ExtractionConfig {
+        chunking: Some(crate::ChunkingConfig {
+            max_characters: 100,
+            overlap: 0,
+            max_chunks: Some(3),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let processed = run_pipeline(result, &config).await.unwrap();
+    assert_eq!(processed.chunks.as_ref().map(Vec::len), Some(3));
+    assert_eq!(
+        processed.metadata.additional.get("chunk_count"),
+        Some(&serde_json::json!(3))
+    );
+    assert_eq!(
+        processed.metadata.additional.get("chunks_truncated"),
+        Some(&serde_json::json!(true))
+    );
+}
+
 #[tokio::test]
 async fn test_pipeline_without_chunking() {
     let result = ExtractionResult {
@@ -143,6 +210,11 @@ async fn test_pipeline_without_chunking() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig {
         chunking: None,
@@ -153,6 +225,216 @@ async fn test_pipeline_without_chunking() {
     assert!(!processed.metadata.additional.contains_key("chunk_count"));
 }
 
+#[cfg(feature = "references")]
+#[tokio::test]
+async fn test_pipeline_extracts_references() {
+    let result = ExtractionResult {
+        content: "Results were good.\n\nReferences\n[1] A. Smith, \"A study of things,\" 2020. doi: 10.1000/abc.1\n"
+            .to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+
+    let processed = run_pipeline(result.clone(), &ExtractionConfig::default())
+        .await
+        .unwrap();
+    assert!(processed.references.is_none());
+
+    let config = ExtractionConfig {
+        extract_references: true,
+        ..Default::default()
+    };
+    let processed = run_pipeline(result, &config).await.unwrap();
+    let references = processed.references.expect("references should be extracted");
+    assert_eq!(references.len(), 1);
+    assert_eq!(references[0].authors, vec!["A. Smith"]);
+    assert_eq!(references[0].title.as_deref(), Some("A study of things"));
+    assert_eq!(references[0].year, Some(2020));
+    assert_eq!(references[0].doi.as_deref(), Some("10.1000/abc.1"));
+}
+
+#[cfg(feature = "references")]
+#[tokio::test]
+async fn test_pipeline_extracts_references_before_truncation() {
+    let result = ExtractionResult {
+        content: "Results were good.\n\nReferences\n[1] A. Smith, \"A study of things,\" 2020.\n".to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+
+    let config = ExtractionConfig {
+        extract_references: true,
+        max_content_chars: Some(18),
+        ..Default::default()
+    };
+    let processed = run_pipeline(result, &config).await.unwrap();
+    assert_eq!(processed.metadata.truncated, Some(true));
+    assert!(!processed.content.contains("References"));
+    let references = processed.references.expect("references should be extracted");
+    assert_eq!(references[0].authors, vec!["A. Smith"]);
+}
+
+#[tokio::test]
+async fn test_pipeline_normalizes_bidi_per_page() {
+    use crate::types::{PageBoundary, PageStructure, PageUnitType};
+
+    // The second page holds "shalom 2024" in visual order, on the same line as the first.
+    let page1 = "Invoice 7";
+    let page2 = "\u{05DD}\u{05D5}\u{05DC}\u{05E9} 2024";
+    let content = format!("{} {}", page1, page2);
+    let result = ExtractionResult {
+        content: content.clone(),
+        mime_type: Cow::Borrowed("application/pdf"),
+        metadata: Metadata {
+            pages: Some(PageStructure {
+                total_count: 2,
+                unit_type: PageUnitType::Page,
+                boundaries: Some(vec![
+                    PageBoundary {
+                        byte_start: 0,
+                        byte_end: page1.len(),
+                        page_number: 1,
+                    },
+                    PageBoundary {
+                        byte_start: page1.len() + 1,
+                        byte_end: content.len(),
+                        page_number: 2,
+                    },
+                ]),
+                pages: None,
+            }),
+            ..Default::default()
+        },
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+
+    let config = ExtractionConfig {
+        enable_quality_processing: false,
+        ..Default::default()
+    };
+    let processed = run_pipeline(result.clone(), &config).await.unwrap();
+    assert_eq!(processed.content, content);
+
+    let config = ExtractionConfig {
+        normalize_bidi: true,
+        enable_quality_processing: false,
+        ..Default::default()
+    };
+    let processed = run_pipeline(result, &config).await.unwrap();
+    assert_eq!(processed.content, "Invoice 7 2024 \u{05E9}\u{05DC}\u{05D5}\u{05DD}");
+    let boundaries = processed.metadata.pages.unwrap().boundaries.unwrap();
+    assert_eq!(&processed.content[..boundaries[0].byte_end], page1);
+    assert_eq!(
+        &processed.content[boundaries[1].byte_start..boundaries[1].byte_end],
+        "2024 \u{05E9}\u{05DC}\u{05D5}\u{05DD}"
+    );
+}
+
+#[cfg(feature = "pdf")]
+#[tokio::test]
+async fn test_pipeline_applies_page_separator_to_other_formats() {
+    use crate::core::config::PdfConfig;
+    use crate::types::{PageBoundary, PageStructure, PageUnitType};
+
+    let content = "Slide one\n\nSlide two\n\nSlide three";
+    let boundaries = [(0, 9), (11, 20), (22, content.len())]
+        .into_iter()
+        .enumerate()
+        .map(|(index, (byte_start, byte_end))| PageBoundary {
+            byte_start,
+            byte_end,
+            page_number: index + 1,
+        })
+        .collect();
+    let result = ExtractionResult {
+        content: content.to_string(),
+        mime_type: Cow::Borrowed("application/vnd.openxmlformats-officedocument.presentationml.presentation"),
+        metadata: Metadata {
+            pages: Some(PageStructure {
+                total_count: 3,
+                unit_type: PageUnitType::Slide,
+                boundaries: Some(boundaries),
+                pages: None,
+            }),
+            ..Default::default()
+        },
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+
+    let config = ExtractionConfig {
+        enable_quality_processing: false,
+        pdf_options: Some(PdfConfig {
+            page_separator: Some("\n<!-- page {page} -->\n".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let processed = run_pipeline(result, &config).await.unwrap();
+    assert_eq!(
+        processed.content,
+        "Slide one\n<!-- page 2 -->\nSlide two\n<!-- page 3 -->\nSlide three"
+    );
+    let boundaries = processed.metadata.pages.unwrap().boundaries.unwrap();
+    assert_eq!(
+        &processed.content[boundaries[2].byte_start..boundaries[2].byte_end],
+        "Slide three"
+    );
+}
+
 #[tokio::test]
 async fn test_pipeline_preserves_metadata() {
     use ahash::AHashMap;
@@ -176,6 +458,11 @@ async fn test_pipeline_preserves_metadata() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -190,6 +477,53 @@ async fn test_pipeline_preserves_metadata() {
     );
 }
 
+#[tokio::test]
+async fn test_pipeline_normalizes_metadata_dates() {
+    use ahash::AHashMap;
+    let mut additional = AHashMap::new();
+    additional.insert(Cow::Borrowed("modified_at"), serde_json::json!("2024-10-09T12:43:00Z"));
+
+    let result = ExtractionResult {
+        content: "test".to_string(),
+        mime_type: Cow::Borrowed("application/pdf"),
+        metadata: Metadata {
+            created_at: Some("D:20230101120000+01'00'".to_string()),
+            modified_at: Some("sometime last week".to_string()),
+            additional,
+            ..Default::default()
+        },
+        pages: None,
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+    let config = ExtractionConfig::default();
+
+    let processed = run_pipeline(result, &config).await.unwrap();
+    let metadata = &processed.metadata;
+    assert_eq!(metadata.created_at.as_deref(), Some("2023-01-01T12:00:00+01:00"));
+    assert_eq!(
+        metadata.additional.get("created_at_raw"),
+        Some(&serde_json::json!("D:20230101120000+01'00'"))
+    );
+    assert_eq!(metadata.modified_at.as_deref(), Some("sometime last week"));
+    assert_eq!(
+        metadata.additional.get("modified_at"),
+        Some(&serde_json::json!("2024-10-09T12:43:00Z"))
+    );
+    assert!(!metadata.additional.contains_key("modified_at_raw"));
+}
+
 #[tokio::test]
 async fn test_pipeline_preserves_tables() {
     use crate::types::Table;
@@ -198,6 +532,9 @@ async fn test_pipeline_preserves_tables() {
         cells: vec![vec!["A".to_string(), "B".to_string()]],
         markdown: "| A | B |".to_string(),
         page_number: 0,
+        name: None,
+        has_header: false,
+        cell_spans: Vec::new(),
     };
 
     let result = ExtractionResult {
@@ -213,6 +550,11 @@ async fn test_pipeline_preserves_tables() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -247,6 +589,11 @@ async fn test_pipeline_empty_content() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -272,6 +619,11 @@ async fn test_pipeline_with_all_features() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig {
         enable_quality_processing: true,
@@ -282,6 +634,9 @@ async fn test_pipeline_with_all_features() {
             chunker_type: crate::ChunkerType::Text,
             embedding: None,
             preset: None,
+            overlap_unit: crate::OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         }),
         ..Default::default()
     };
@@ -329,6 +684,11 @@ Natural language processing enables computers to understand human language.
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     #[cfg(feature = "keywords-yake")]
@@ -378,6 +738,11 @@ async fn test_pipeline_without_keyword_config() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     let config = ExtractionConfig {
@@ -418,6 +783,11 @@ async fn test_pipeline_keyword_extraction_short_content() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     #[cfg(feature = "keywords-yake")]
@@ -557,6 +927,11 @@ async fn test_postprocessor_runs_before_validator() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     result.metadata.additional.insert(
         Cow::Borrowed(VALIDATION_MARKER_KEY),
@@ -570,6 +945,8 @@ async fn test_postprocessor_runs_before_validator() {
             disabled_set: None,
             enabled_processors: None,
             disabled_processors: None,
+            header_footer_threshold: 0.6,
+            text_normalizer: Default::default(),
         }),
         ..Default::default()
     };
@@ -656,6 +1033,11 @@ async fn test_quality_processing_runs_before_validator() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     result.metadata.additional.insert(
         Cow::Borrowed(VALIDATION_MARKER_KEY),
@@ -856,6 +1238,11 @@ async fn test_multiple_postprocessors_run_before_validator() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     let config = ExtractionConfig::default();
@@ -885,6 +1272,11 @@ async fn test_run_pipeline_with_output_format_plain() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     let config = crate::core::config::ExtractionConfig {
@@ -935,6 +1327,11 @@ async fn test_run_pipeline_with_output_format_djot() {
             attributes: Vec::new(),
         }),
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     let config = crate::core::config::ExtractionConfig {
@@ -962,6 +1359,11 @@ async fn test_run_pipeline_with_output_format_html() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     let config = crate::core::config::ExtractionConfig {
@@ -1003,6 +1405,11 @@ async fn test_run_pipeline_applies_output_format_last() {
             attributes: Vec::new(),
         }),
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     let config = crate::core::config::ExtractionConfig {
@@ -1016,3 +1423,845 @@ async fn test_run_pipeline_applies_output_format_last() {
     // The result should have gone through the pipeline successfully
     assert!(processed.djot_content.is_some());
 }
+
+#[test]
+fn test_truncation_point_within_limit() {
+    assert_eq!(features::truncation_point("short", 10), None);
+    assert_eq!(features::truncation_point("exact", 5), None);
+}
+
+#[test]
+fn test_truncation_point_respects_char_boundaries() {
+    let content = "äöü".repeat(10);
+    let cut = features::truncation_point(&content, 4).unwrap();
+    assert!(content.is_char_boundary(cut));
+    assert_eq!(content[..cut].chars().count(), 4);
+}
+
+#[test]
+fn test_truncation_point_prefers_paragraph_break() {
+    let content = format!("{}\n\n{}", "a".repeat(95), "b".repeat(100));
+    let cut = features::truncation_point(&content, 100).unwrap();
+    assert_eq!(&content[..cut], "a".repeat(95));
+}
+
+#[tokio::test]
+async fn test_pipeline_truncates_content() {
+    let result = ExtractionResult {
+        content: "word ".repeat(100),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+    let config = ExtractionConfig {
+        max_content_chars: Some(42),
+        enable_quality_processing: false,
+        ..Default::default()
+    };
+
+    let processed = run_pipeline(result, &config).await.unwrap();
+    assert!(processed.content.chars().count() <= 42);
+    assert_eq!(processed.metadata.truncated, Some(true));
+}
+
+#[tokio::test]
+async fn test_pipeline_truncates_pages_with_content() {
+    use crate::types::{PageBoundary, PageContent, PageStructure, PageUnitType};
+
+    let page = |page_number: usize, content: &str| PageContent {
+        page_number,
+        content: content.to_string(),
+        tables: vec![],
+        images: vec![],
+        hierarchy: None,
+        is_blank: None,
+    };
+    let texts = ["First page text.", "Second page text.", "Third page text."];
+    let content = texts.join("\n\n");
+    let mut boundaries = Vec::new();
+    let mut offset = 0;
+    for (idx, text) in texts.iter().enumerate() {
+        boundaries.push(PageBoundary {
+            byte_start: offset,
+            byte_end: offset + text.len(),
+            page_number: idx + 1,
+        });
+        offset += text.len() + 2;
+    }
+    let pages: Vec<PageContent> = texts
+        .iter()
+        .enumerate()
+        .map(|(idx, text)| page(idx + 1, text))
+        .collect();
+
+    let mut result = ExtractionResult {
+        content,
+        mime_type: Cow::Borrowed("application/pdf"),
+        metadata: Metadata {
+            pages: Some(PageStructure {
+                total_count: 3,
+                unit_type: PageUnitType::Page,
+                boundaries: Some(boundaries),
+                pages: None,
+            }),
+            ..Default::default()
+        },
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: Some(pages),
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+    let config = ExtractionConfig {
+        max_content_chars: Some(25),
+        enable_quality_processing: false,
+        ..Default::default()
+    };
+
+    let processed = run_pipeline(result.clone(), &config).await.unwrap();
+    assert_eq!(processed.content, "First page text.\n\nSecond");
+    let processed_pages = processed.pages.unwrap();
+    assert_eq!(processed_pages.len(), 2);
+    assert_eq!(processed_pages[0].content, "First page text.");
+    assert_eq!(processed_pages[1].content, "Second");
+
+    // Without page boundaries the pages are cut by their own character counts.
+    result.metadata.pages = None;
+    let processed = run_pipeline(result, &config).await.unwrap();
+    let contents: Vec<String> = processed.pages.unwrap().into_iter().map(|p| p.content).collect();
+    assert_eq!(contents, vec!["First page text.", "Second p"]);
+}
+
+#[tokio::test]
+async fn test_pipeline_does_not_mark_short_content_truncated() {
+    let result = ExtractionResult {
+        content: "short content".to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+    let config = ExtractionConfig {
+        max_content_chars: Some(1000),
+        ..Default::default()
+    };
+
+    let processed = run_pipeline(result, &config).await.unwrap();
+    assert_eq!(processed.content, "short content");
+    assert!(processed.metadata.truncated.is_none());
+}
+
+#[test]
+fn test_convert_line_endings_mixed_input() {
+    use crate::core::config::LineEnding;
+
+    let mixed = "a\r\nb\nc\rd";
+    let (lf, removed) = features::convert_line_endings(mixed, LineEnding::Lf).unwrap();
+    assert_eq!(lf, "a\nb\nc\nd");
+    assert_eq!(removed, vec![1]);
+
+    let (crlf, inserted) = features::convert_line_endings(mixed, LineEnding::CrLf).unwrap();
+    assert_eq!(crlf, "a\r\nb\r\nc\r\nd");
+    assert_eq!(inserted, vec![4, 6]);
+
+    assert!(features::convert_line_endings("a\nb", LineEnding::Lf).is_none());
+    assert!(features::convert_line_endings("a\r\nb", LineEnding::CrLf).is_none());
+}
+
+#[tokio::test]
+async fn test_pipeline_normalizes_mixed_newlines() {
+    use crate::core::config::LineEnding;
+    use crate::types::{PageBoundary, PageStructure, PageUnitType};
+
+    let page1 = "first\r\nline";
+    let page2 = "second\nline\r\nend";
+    let content = format!("{}\n{}", page1, page2);
+    let result = ExtractionResult {
+        content: content.clone(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata {
+            pages: Some(PageStructure {
+                total_count: 2,
+                unit_type: PageUnitType::Page,
+                boundaries: Some(vec![
+                    PageBoundary {
+                        byte_start: 0,
+                        byte_end: page1.len(),
+                        page_number: 1,
+                    },
+                    PageBoundary {
+                        byte_start: page1.len() + 1,
+                        byte_end: content.len(),
+                        page_number: 2,
+                    },
+                ]),
+                pages: None,
+            }),
+            ..Default::default()
+        },
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+
+    let lf_config = ExtractionConfig {
+        normalize_newlines: Some(LineEnding::Lf),
+        enable_quality_processing: false,
+        ..Default::default()
+    };
+    let processed = run_pipeline(result.clone(), &lf_config).await.unwrap();
+    assert_eq!(processed.content, "first\nline\nsecond\nline\nend");
+    let boundaries = processed.metadata.pages.unwrap().boundaries.unwrap();
+    assert_eq!(
+        &processed.content[boundaries[0].byte_start..boundaries[0].byte_end],
+        "first\nline"
+    );
+    assert_eq!(
+        &processed.content[boundaries[1].byte_start..boundaries[1].byte_end],
+        "second\nline\nend"
+    );
+
+    let crlf_config = ExtractionConfig {
+        normalize_newlines: Some(LineEnding::CrLf),
+        ..lf_config.clone()
+    };
+    let processed = run_pipeline(result.clone(), &crlf_config).await.unwrap();
+    assert_eq!(processed.content, "first\r\nline\r\nsecond\r\nline\r\nend");
+    let boundaries = processed.metadata.pages.unwrap().boundaries.unwrap();
+    assert_eq!(
+        &processed.content[boundaries[1].byte_start..boundaries[1].byte_end],
+        "second\r\nline\r\nend"
+    );
+
+    let keep_config = ExtractionConfig {
+        normalize_newlines: Some(LineEnding::Keep),
+        ..lf_config
+    };
+    let processed = run_pipeline(result, &keep_config).await.unwrap();
+    assert_eq!(processed.content, content);
+}
+
+#[tokio::test]
+async fn test_pipeline_realigns_source_map() {
+    use crate::core::config::LineEnding;
+    use crate::types::{SourceSpan, SourceUnit};
+
+    let content = "first line\nsecond line\nthird line";
+    let span = |content_start: usize, content_end: usize, source_start: usize| SourceSpan {
+        content_start,
+        content_end,
+        page: Some(1),
+        source_start,
+        source_end: source_start + (content_end - content_start),
+        unit: SourceUnit::PageChar,
+    };
+    let result = ExtractionResult {
+        content: content.to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: Some(vec![span(0, 10, 0), span(11, 22, 12), span(23, 33, 25)]),
+        references: None,
+    };
+    let config = ExtractionConfig {
+        max_content_chars: Some(22),
+        normalize_newlines: Some(LineEnding::CrLf),
+        enable_quality_processing: false,
+        ..Default::default()
+    };
+
+    let processed = run_pipeline(result, &config).await.unwrap();
+    let source_map = processed.source_map.expect("source map");
+    let texts: Vec<&str> = source_map
+        .iter()
+        .map(|span| &processed.content[span.content_start..span.content_end])
+        .collect();
+    assert_eq!(texts, vec!["first line", "second line"]);
+    assert_eq!(source_map[1].source_start, 12);
+}
+
+#[cfg(feature = "quality")]
+fn token_reduction_config(mode: &str) -> ExtractionConfig {
+    ExtractionConfig {
+        token_reduction: Some(crate::core::config::TokenReductionConfig {
+            mode: mode.to_string(),
+            preserve_important_words: true,
+            language: None,
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+#[cfg(feature = "quality")]
+fn test_token_reduction_records_stats() {
+    let mut result = ExtractionResult {
+        content: "The   quick brown fox jumps over the lazy dog and the cat!!!  ".repeat(5),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+    let original_chars = result.content.chars().count();
+
+    features::execute_token_reduction(&mut result, &token_reduction_config("moderate"));
+
+    let stats = &result.metadata.additional["token_reduction_stats"];
+    assert_eq!(stats["original_chars"], original_chars);
+    assert_eq!(stats["reduced_chars"], result.content.chars().count());
+    assert!(stats["reduction_ratio"].as_f64().unwrap() > 0.0);
+}
+
+#[test]
+#[cfg(feature = "quality")]
+fn test_token_reduction_rebuilds_page_boundaries() {
+    use crate::types::{PageBoundary, PageStructure, PageUnitType};
+
+    let page1 = "The   first page has   some text.";
+    let separator = "\n\n";
+    let page2 = "The   second page has   more text.";
+    let content = format!("{}{}{}", page1, separator, page2);
+    let mut result = ExtractionResult {
+        content,
+        mime_type: Cow::Borrowed("application/pdf"),
+        metadata: Metadata {
+            pages: Some(PageStructure {
+                total_count: 2,
+                unit_type: PageUnitType::Page,
+                boundaries: Some(vec![
+                    PageBoundary {
+                        byte_start: 0,
+                        byte_end: page1.len(),
+                        page_number: 1,
+                    },
+                    PageBoundary {
+                        byte_start: page1.len() + separator.len(),
+                        byte_end: page1.len() + separator.len() + page2.len(),
+                        page_number: 2,
+                    },
+                ]),
+                pages: None,
+            }),
+            ..Default::default()
+        },
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+
+    features::execute_token_reduction(&mut result, &token_reduction_config("light"));
+
+    let boundaries = result.metadata.pages.unwrap().boundaries.unwrap();
+    let first = &result.content[boundaries[0].byte_start..boundaries[0].byte_end];
+    let second = &result.content[boundaries[1].byte_start..boundaries[1].byte_end];
+    assert!(first.contains("first page") && !first.contains("second"));
+    assert!(second.contains("second page") && !second.contains("first"));
+    assert_eq!(
+        &result.content[boundaries[0].byte_end..boundaries[1].byte_start],
+        separator
+    );
+}
+
+#[test]
+#[cfg(feature = "quality")]
+fn test_token_reduction_off_leaves_content_untouched() {
+    let mut result = ExtractionResult {
+        content: "The   quick brown fox.".to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+
+    features::execute_token_reduction(&mut result, &token_reduction_config("off"));
+
+    assert_eq!(result.content, "The   quick brown fox.");
+    assert!(!result.metadata.additional.contains_key("token_reduction_stats"));
+}
+
+#[test]
+#[cfg(any(feature = "pdf", feature = "ocr"))]
+fn test_image_conversion_reencodes_to_jpeg() {
+    use crate::core::config::{ImageExtractionConfig, ImageFormat};
+    use crate::types::{ExtractedImage, PageContent};
+    use image::ImageEncoder;
+    use image::codecs::png::PngEncoder;
+    use std::sync::Arc;
+
+    let pixels = vec![200u8; 4 * 3 * 4];
+    let mut png = Vec::new();
+    PngEncoder::new(&mut png)
+        .write_image(&pixels, 4, 3, image::ExtendedColorType::Rgba8)
+        .unwrap();
+
+    let page_image = ExtractedImage {
+        data: bytes::Bytes::from(png),
+        format: Cow::Borrowed("png"),
+        image_index: 0,
+        page_number: Some(1),
+        width: None,
+        height: None,
+        colorspace: None,
+        bits_per_component: None,
+        is_mask: false,
+        description: None,
+        alt_text: None,
+        page_position: None,
+        caption: None,
+        ocr_result: None,
+    };
+
+    let mut result = ExtractionResult {
+        content: String::new(),
+        mime_type: Cow::Borrowed("application/pdf"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: Some(vec![
+            page_image.clone(),
+            ExtractedImage {
+                data: bytes::Bytes::from_static(b"not an image"),
+                format: Cow::Borrowed("jbig2"),
+                image_index: 1,
+                page_number: Some(1),
+                width: None,
+                height: None,
+                colorspace: None,
+                bits_per_component: None,
+                is_mask: false,
+                description: None,
+                alt_text: None,
+                page_position: None,
+                caption: None,
+                ocr_result: None,
+            },
+        ]),
+        djot_content: None,
+        pages: Some(vec![PageContent {
+            page_number: 1,
+            content: String::new(),
+            tables: vec![],
+            images: vec![Arc::new(page_image)],
+            hierarchy: None,
+            is_blank: None,
+        }]),
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+    let config = ExtractionConfig {
+        images: Some(ImageExtractionConfig {
+            extract_images: true,
+            target_dpi: 300,
+            max_image_dimension: 4096,
+            max_decode_dimension: 32768,
+            auto_adjust_dpi: true,
+            min_dpi: 72,
+            max_dpi: 600,
+            max_images: None,
+            max_total_image_bytes: None,
+            min_width: 16,
+            min_height: 16,
+            output_format: ImageFormat::Jpeg { quality: 90 },
+        }),
+        ..Default::default()
+    };
+
+    features::execute_image_conversion(&mut result, &config);
+
+    let images = result.images.unwrap();
+    assert_eq!(images[0].format, "jpeg");
+    // The page copy shares the bytes encoded for `result.images` instead of encoding again.
+    let page_image = &result.pages.unwrap()[0].images[0];
+    assert_eq!(page_image.format, "jpeg");
+    assert_eq!(page_image.data.as_ptr(), images[0].data.as_ptr());
+    assert!(images[0].data.starts_with(&[0xFF, 0xD8]));
+    assert_eq!((images[0].width, images[0].height), (Some(4), Some(3)));
+    assert_eq!(images[1].format, "jbig2");
+    assert_eq!(&images[1].data[..], b"not an image");
+}
+
+#[cfg(feature = "quality")]
+fn german_result(detected_languages: Option<Vec<String>>) -> ExtractionResult {
+    ExtractionResult {
+        content: "Die Katze und der Hund spielen mit einem Ball im Garten.".to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    }
+}
+
+#[cfg(feature = "quality")]
+fn has_word(content: &str, word: &str) -> bool {
+    content.split_whitespace().any(|w| w == word)
+}
+
+#[test]
+#[cfg(feature = "quality")]
+fn test_token_reduction_removes_german_stopwords_for_language() {
+    let mut english = german_result(None);
+    features::execute_token_reduction(&mut english, &token_reduction_config("moderate"));
+    assert!(has_word(&english.content, "und"), "English stopwords should keep 'und'");
+
+    let mut config = token_reduction_config("moderate");
+    config.token_reduction.as_mut().unwrap().language = Some("de".to_string());
+    let mut german = german_result(None);
+    features::execute_token_reduction(&mut german, &config);
+
+    for stopword in ["und", "der", "mit", "einem", "im"] {
+        assert!(
+            !has_word(&german.content, stopword),
+            "'{stopword}' left in {:?}",
+            german.content
+        );
+    }
+    for word in ["Katze", "Hund", "spielen", "Ball"] {
+        assert!(
+            german.content.contains(word),
+            "'{word}' missing from {:?}",
+            german.content
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "quality")]
+fn test_token_reduction_uses_detected_language() {
+    let mut result = german_result(Some(vec!["deu".to_string()]));
+    features::execute_token_reduction(&mut result, &token_reduction_config("moderate"));
+
+    assert!(!has_word(&result.content, "und"), "content: {:?}", result.content);
+    assert!(!has_word(&result.content, "der"), "content: {:?}", result.content);
+    assert!(result.content.contains("Katze"));
+}
+
+#[tokio::test]
+async fn test_pipeline_drops_empty_table_rows_only_when_configured() {
+    use crate::core::config::TableExtractionConfig;
+    use crate::types::{Table, TableMarkdownOptions};
+
+    let cells: Vec<Vec<String>> = [vec!["Name", "Age"], vec!["", ""], vec!["Alice", "30"], vec![" ", ""]]
+        .into_iter()
+        .map(|row| row.into_iter().map(String::from).collect())
+        .collect();
+    let result = ExtractionResult {
+        content: "table".to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![Table {
+            markdown: String::new(),
+            cells: cells.clone(),
+            page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
+        }],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+
+    let config = ExtractionConfig {
+        enable_quality_processing: false,
+        ..Default::default()
+    };
+    let processed = run_pipeline(result.clone(), &config).await.unwrap();
+    assert_eq!(processed.tables[0].cells, cells);
+
+    let config = ExtractionConfig {
+        enable_quality_processing: false,
+        table_options: Some(TableExtractionConfig {
+            drop_empty_rows: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let processed = run_pipeline(result, &config).await.unwrap();
+    assert_eq!(
+        processed.tables[0].cells,
+        vec![vec!["Name", "Age"], vec!["Alice", "30"]]
+    );
+    assert_eq!(
+        processed.tables[0].markdown,
+        processed.tables[0].to_markdown(TableMarkdownOptions::default())
+    );
+}
+
+#[tokio::test]
+async fn test_pipeline_derives_text_blocks_from_ocr_elements() {
+    use crate::types::{OcrBoundingGeometry, OcrConfidence, OcrElement};
+
+    let result = ExtractionResult {
+        content: "Invoice".to_string(),
+        mime_type: Cow::Borrowed("image/png"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        djot_content: None,
+        pages: None,
+        elements: None,
+        ocr_elements: Some(vec![
+            OcrElement::new(
+                "Invoice",
+                OcrBoundingGeometry::Rectangle {
+                    left: 10,
+                    top: 20,
+                    width: 100,
+                    height: 30,
+                },
+                OcrConfidence::from_tesseract(95.0),
+            )
+            .with_page_number(1),
+        ]),
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+
+    let config = ExtractionConfig {
+        enable_quality_processing: false,
+        ..Default::default()
+    };
+    let processed = run_pipeline(result.clone(), &config).await.unwrap();
+    assert!(processed.text_blocks.is_none());
+
+    let config = ExtractionConfig {
+        enable_quality_processing: false,
+        emit_positions: true,
+        ..Default::default()
+    };
+    let processed = run_pipeline(result, &config).await.unwrap();
+    let blocks = processed.text_blocks.expect("text blocks should be derived");
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].text, "Invoice");
+    assert_eq!(blocks[0].page, 1);
+    assert_eq!(blocks[0].bbox.x1, 110.0);
+    assert_eq!(blocks[0].bbox.y1, 50.0);
+}
+
+#[tokio::test]
+#[allow(clippy::await_holding_lock)]
+async fn test_image_captioner_fills_missing_captions() {
+    let _guard = REGISTRY_TEST_GUARD.lock().unwrap();
+    use crate::plugins::{ImageCaptioner, Plugin};
+    use crate::types::{ExtractedImage, PageContent};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    struct FormatCaptioner;
+    impl Plugin for FormatCaptioner {
+        fn name(&self) -> &str {
+            "format-captioner"
+        }
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ImageCaptioner for FormatCaptioner {
+        async fn caption(&self, image: &ExtractedImage) -> Result<String> {
+            if image.data.is_empty() {
+                return Err(crate::KreuzbergError::parsing("empty image"));
+            }
+            Ok(format!("{} image", image.format))
+        }
+    }
+
+    let image = |index: usize, data: &'static [u8], caption: Option<&str>| ExtractedImage {
+        data: bytes::Bytes::from_static(data),
+        format: Cow::Borrowed("png"),
+        image_index: index,
+        page_number: Some(1),
+        width: None,
+        height: None,
+        colorspace: None,
+        bits_per_component: None,
+        is_mask: false,
+        description: None,
+        alt_text: None,
+        page_position: None,
+        caption: caption.map(str::to_string),
+        ocr_result: None,
+    };
+
+    let mut result = ExtractionResult {
+        content: String::new(),
+        mime_type: Cow::Borrowed("application/pdf"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: Some(vec![
+            image(0, b"png", None),
+            image(1, b"png", Some("authored")),
+            image(2, b"", None),
+        ]),
+        djot_content: None,
+        pages: Some(vec![PageContent {
+            page_number: 1,
+            content: String::new(),
+            tables: vec![],
+            images: vec![Arc::new(image(0, b"png", None))],
+            hierarchy: None,
+            is_blank: None,
+        }]),
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    };
+
+    crate::plugins::register_image_captioner(Arc::new(FormatCaptioner)).unwrap();
+    let captioned = execution::execute_image_captioning(&mut result).await;
+    crate::plugins::unregister_image_captioner("format-captioner").unwrap();
+    captioned.unwrap();
+
+    let images = result.images.as_ref().unwrap();
+    assert_eq!(images[0].caption.as_deref(), Some("png image"));
+    assert_eq!(images[1].caption.as_deref(), Some("authored"));
+    assert_eq!(images[2].caption, None);
+    assert_eq!(
+        result.pages.as_ref().unwrap()[0].images[0].caption.as_deref(),
+        Some("png image")
+    );
+    assert_eq!(
+        result.metadata.additional.get("image_caption_errors"),
+        Some(&serde_json::json!([{ "image_index": 2, "error": "Parsing error: empty image" }]))
+    );
+}
@@ -0,0 +1,159 @@
+//! Progress reporting for long-running extractions.
+//!
+//! A progress callback is installed for the duration of a single extraction
+//! (see [`extract_file_with_progress`](crate::core::extractor::extract_file_with_progress))
+//! and extractors report phase boundaries through [`emit`]. The callback is stored in
+//! a task-local, so no extractor signature has to carry it and extraction without a
+//! callback only pays for a failed task-local lookup.
+
+use serde::Serialize;
+#[cfg(feature = "tokio-runtime")]
+use std::sync::{Arc, Mutex};
+
+/// A progress event emitted during extraction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProgressEvent {
+    /// An extractor was selected and extraction of the document started.
+    ExtractionStarted {
+        /// MIME type being extracted
+        mime_type: String,
+    },
+    /// Processing of a page started.
+    PageStarted {
+        /// Page number (1-indexed)
+        page: usize,
+        /// Total number of pages
+        total: usize,
+    },
+    /// OCR processing started.
+    OcrStarted,
+    /// The post-processing pipeline started.
+    PostProcessingStarted,
+    /// Chunking started.
+    ChunkingStarted,
+    /// Extraction finished (successfully or with an error).
+    ExtractionFinished,
+}
+
+/// Shared, thread-safe handle to a progress callback.
+#[cfg(feature = "tokio-runtime")]
+pub(crate) type ProgressSink = Arc<Mutex<dyn FnMut(ProgressEvent) + Send>>;
+
+#[cfg(feature = "tokio-runtime")]
+tokio::task_local! {
+    static PROGRESS_SINK: ProgressSink;
+}
+
+/// Wrap a callback into a [`ProgressSink`].
+#[cfg(feature = "tokio-runtime")]
+pub(crate) fn sink_from<F>(callback: F) -> ProgressSink
+where
+    F: FnMut(ProgressEvent) + Send + 'static,
+{
+    Arc::new(Mutex::new(callback))
+}
+
+/// Run `future` with `sink` installed as the progress callback.
+#[cfg(feature = "tokio-runtime")]
+pub(crate) async fn scope<F: std::future::Future>(sink: ProgressSink, future: F) -> F::Output {
+    PROGRESS_SINK.scope(sink, future).await
+}
+
+/// Return the progress callback of the current extraction, if any.
+///
+/// Used to carry the callback into `spawn_blocking` closures, which do not
+/// inherit task-locals; re-install it there with [`scope_sync`].
+#[cfg(feature = "tokio-runtime")]
+#[cfg_attr(not(feature = "pdf"), allow(dead_code))]
+pub(crate) fn current() -> Option<ProgressSink> {
+    PROGRESS_SINK.try_with(Arc::clone).ok()
+}
+
+/// Run `f` synchronously with `sink` (if any) installed as the progress callback.
+#[cfg(feature = "tokio-runtime")]
+#[cfg_attr(not(feature = "pdf"), allow(dead_code))]
+pub(crate) fn scope_sync<R>(sink: Option<ProgressSink>, f: impl FnOnce() -> R) -> R {
+    match sink {
+        Some(sink) => PROGRESS_SINK.sync_scope(sink, f),
+        None => f(),
+    }
+}
+
+/// Report a progress event to the current extraction's callback.
+///
+/// Does nothing when no callback is installed.
+pub(crate) fn emit(event: ProgressEvent) {
+    #[cfg(feature = "tokio-runtime")]
+    {
+        let _ = PROGRESS_SINK.try_with(|sink| {
+            if let Ok(mut callback) = sink.lock() {
+                callback(event);
+            }
+        });
+    }
+
+    #[cfg(not(feature = "tokio-runtime"))]
+    let _ = event;
+}
+
+#[cfg(all(test, feature = "tokio-runtime"))]
+mod tests {
+    use super::*;
+
+    fn recording_sink() -> (ProgressSink, Arc<Mutex<Vec<ProgressEvent>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        let sink = sink_from(move |event| recorded.lock().unwrap().push(event));
+        (sink, events)
+    }
+
+    #[test]
+    fn test_emit_without_sink_is_noop() {
+        emit(ProgressEvent::OcrStarted);
+        assert!(current().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scope_delivers_events() {
+        let (sink, events) = recording_sink();
+
+        scope(sink, async {
+            emit(ProgressEvent::OcrStarted);
+            emit(ProgressEvent::PageStarted { page: 1, total: 2 });
+        })
+        .await;
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                ProgressEvent::OcrStarted,
+                ProgressEvent::PageStarted { page: 1, total: 2 }
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_current_propagates_into_blocking_tasks() {
+        let (sink, events) = recording_sink();
+
+        scope(sink, async {
+            let sink = current();
+            tokio::task::spawn_blocking(move || scope_sync(sink, || emit(ProgressEvent::ChunkingStarted)))
+                .await
+                .unwrap();
+        })
+        .await;
+
+        assert_eq!(*events.lock().unwrap(), vec![ProgressEvent::ChunkingStarted]);
+    }
+
+    #[test]
+    fn test_progress_event_serialization() {
+        let json = serde_json::to_value(ProgressEvent::PageStarted { page: 3, total: 10 }).unwrap();
+        assert_eq!(json["event"], "page_started");
+        assert_eq!(json["page"], 3);
+        assert_eq!(json["total"], 10);
+    }
+}
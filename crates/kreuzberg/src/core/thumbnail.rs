@@ -0,0 +1,211 @@
+//! Document thumbnails.
+//!
+//! Renders a small PNG preview of a document: the first page of a PDF, the preview
+//! PowerPoint embeds in a PPTX, or the image itself for image formats. Formats without a
+//! visual representation, such as plain text, are rejected.
+
+use crate::core::config::ExtractionConfig;
+use crate::core::io::read_file_sync;
+use crate::core::mime::detect_mime_type;
+use crate::extraction::image_header::decode_image;
+use crate::{KreuzbergError, Result};
+use image::DynamicImage;
+use image::imageops::FilterType;
+use std::io::Cursor;
+use std::path::Path;
+
+/// Widest thumbnail allowed when `config.images` is unset, matching the default
+/// `ImageExtractionConfig.max_image_dimension`.
+const DEFAULT_MAX_WIDTH: u32 = 4096;
+
+/// Image formats without a raster decoder, which have no thumbnail.
+const VECTOR_IMAGE_MIME_TYPES: &[&str] = &["image/svg+xml", "image/vnd.dxf"];
+
+/// Render a thumbnail of a document as PNG bytes.
+///
+/// The thumbnail is `width` pixels wide; its height follows the aspect ratio of the
+/// source. `width` may not exceed `config.images.max_image_dimension` (4096 when
+/// `config.images` is unset), and neither may the height: sources too tall for that
+/// are scaled down to fit, giving a thumbnail narrower than `width`. PDFs are rendered from their first page, opened with the
+/// passwords in `config.pdf_options`. Images are scaled directly. PPTX slides are not
+/// rendered: the thumbnail is the first-slide preview PowerPoint stores in the file,
+/// which presentations saved by other tools (LibreOffice, python-pptx, Google Slides)
+/// usually lack.
+///
+/// # Errors
+///
+/// - `KreuzbergError::Validation` - `width` is zero or larger than the maximum image dimension
+/// - `KreuzbergError::UnsupportedFormat` - The format has no visual representation (including
+///   SVG, DXF and PPTX files without an embedded preview), or support for it is not compiled in
+/// - `KreuzbergError::Parsing` - The PDF cannot be rendered
+/// - `KreuzbergError::ImageProcessing` - The image cannot be decoded or encoded
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::{ExtractionConfig, extract_thumbnail};
+///
+/// # fn example() -> kreuzberg::Result<()> {
+/// let png = extract_thumbnail("document.pdf", &ExtractionConfig::default(), 256)?;
+/// std::fs::write("thumb.png", png)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn extract_thumbnail(path: impl AsRef<Path>, config: &ExtractionConfig, width: u32) -> Result<Vec<u8>> {
+    let path = path.as_ref();
+    if width == 0 {
+        return Err(KreuzbergError::validation("Thumbnail width must be greater than 0"));
+    }
+    let max_width = config
+        .images
+        .as_ref()
+        .map_or(DEFAULT_MAX_WIDTH, |images| images.max_image_dimension.max(1) as u32);
+    if width > max_width {
+        return Err(KreuzbergError::validation(format!(
+            "Thumbnail width must be at most {} (images.max_image_dimension)",
+            max_width
+        )));
+    }
+
+    let mime_type = detect_mime_type(path, true)?;
+    let bytes = read_file_sync(path)?;
+
+    let image = render_first_page(&bytes, &mime_type, config, width, max_width)?;
+    encode_png(&image)
+}
+
+fn render_first_page(
+    bytes: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+    width: u32,
+    max_height: u32,
+) -> Result<DynamicImage> {
+    #[cfg(feature = "pdf")]
+    if mime_type == crate::core::mime::PDF_MIME_TYPE {
+        let passwords = config
+            .pdf_options
+            .as_ref()
+            .and_then(|pdf| pdf.passwords.as_deref())
+            .unwrap_or_default();
+        let renderer = crate::pdf::rendering::PdfRenderer::new()?;
+        return Ok(renderer.render_page_to_width(bytes, 0, width, max_height, passwords)?);
+    }
+
+    #[cfg(feature = "office")]
+    if mime_type == crate::core::mime::POWER_POINT_MIME_TYPE {
+        let preview = crate::extraction::pptx::read_pptx_thumbnail(bytes)?.ok_or_else(|| {
+            KreuzbergError::unsupported_format_with_reason(
+                mime_type,
+                "Slides are not rendered and the presentation has no embedded preview image",
+            )
+        })?;
+        let image = decode_image(&preview, config.max_decode_dimension())?;
+        return scale_to_width(&image, width, max_height);
+    }
+
+    if mime_type.starts_with("image/") && !VECTOR_IMAGE_MIME_TYPES.contains(&mime_type) {
+        let image = decode_image(bytes, config.max_decode_dimension())?;
+        return scale_to_width(&image, width, max_height);
+    }
+
+    Err(KreuzbergError::unsupported_format(mime_type))
+}
+
+/// Scale `image` to `width`, keeping its aspect ratio, unless that makes it taller than
+/// `max_height`; then it is scaled to `max_height` instead.
+fn scale_to_width(image: &DynamicImage, width: u32, max_height: u32) -> Result<DynamicImage> {
+    if image.width() == 0 || image.height() == 0 {
+        return Err(KreuzbergError::image_processing(
+            "Cannot create a thumbnail of an empty image",
+        ));
+    }
+
+    let mut width = width;
+    let mut height = (image.height() as f64 * width as f64 / image.width() as f64)
+        .round()
+        .max(1.0) as u32;
+    if height > max_height {
+        width = (image.width() as f64 * max_height as f64 / image.height() as f64)
+            .round()
+            .max(1.0) as u32;
+        height = max_height;
+    }
+    Ok(image.resize_exact(width, height, FilterType::Lanczos3))
+}
+
+fn encode_png(image: &DynamicImage) -> Result<Vec<u8>> {
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)
+        .map_err(|e| KreuzbergError::image_processing_with_source("Failed to encode thumbnail as PNG", e))?;
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgb, RgbImage};
+    use std::io::Write;
+
+    fn write_image(width: u32, height: u32, suffix: &str, format: image::ImageFormat) -> tempfile::NamedTempFile {
+        let img: RgbImage = ImageBuffer::from_fn(width, height, |x, _| Rgb([(x % 256) as u8, 64, 128]));
+        let mut bytes = Vec::new();
+        img.write_to(&mut Cursor::new(&mut bytes), format).unwrap();
+
+        let mut file = tempfile::Builder::new().suffix(suffix).tempfile().unwrap();
+        file.write_all(&bytes).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_image_thumbnail_preserves_aspect_ratio() {
+        let file = write_image(400, 300, ".jpg", image::ImageFormat::Jpeg);
+
+        let png = extract_thumbnail(file.path(), &ExtractionConfig::default(), 100).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        let thumbnail = image::load_from_memory(&png).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (100, 75));
+    }
+
+    #[test]
+    fn test_tall_image_thumbnail_is_capped_in_height() {
+        let file = write_image(10, 1000, ".png", image::ImageFormat::Png);
+
+        let png = extract_thumbnail(file.path(), &ExtractionConfig::default(), 100).unwrap();
+
+        let thumbnail = image::load_from_memory(&png).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (41, DEFAULT_MAX_WIDTH));
+    }
+
+    #[test]
+    fn test_thumbnail_rejects_invalid_width() {
+        let file = write_image(10, 10, ".png", image::ImageFormat::Png);
+
+        let err = extract_thumbnail(file.path(), &ExtractionConfig::default(), 0).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+
+        let err = extract_thumbnail(file.path(), &ExtractionConfig::default(), 200_000).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+    }
+
+    #[test]
+    fn test_thumbnail_rejects_vector_images() {
+        let mut file = tempfile::Builder::new().suffix(".svg").tempfile().unwrap();
+        file.write_all(br#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"/>"#)
+            .unwrap();
+
+        let err = extract_thumbnail(file.path(), &ExtractionConfig::default(), 100).unwrap_err();
+        assert!(matches!(err, KreuzbergError::UnsupportedFormat { .. }));
+    }
+
+    #[test]
+    fn test_thumbnail_rejects_plain_text() {
+        let mut file = tempfile::Builder::new().suffix(".txt").tempfile().unwrap();
+        file.write_all(b"No pixels here.").unwrap();
+
+        let err = extract_thumbnail(file.path(), &ExtractionConfig::default(), 100).unwrap_err();
+        assert!(matches!(err, KreuzbergError::UnsupportedFormat { .. }));
+    }
+}
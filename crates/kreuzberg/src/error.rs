@@ -63,7 +63,8 @@ pub type Result<T> = std::result::Result<T, KreuzbergError>;
 /// - `MissingDependency` - Missing optional dependencies (tesseract, etc.)
 /// - `Plugin` - Plugin-specific errors
 /// - `LockPoisoned` - Mutex/RwLock poisoning (should not happen in normal operation)
-/// - `UnsupportedFormat` - Unsupported MIME type or file format
+/// - `UnsupportedFormat` - Recognized but unsupported MIME type or file format (as opposed to
+///   `Parsing`, which means a supported format could not be read)
 /// - `Other` - Catch-all for uncommon errors
 #[derive(Debug, Error)]
 pub enum KreuzbergError {
@@ -121,8 +122,19 @@ pub enum KreuzbergError {
     #[error("Lock poisoned: {0}")]
     LockPoisoned(String),
 
-    #[error("Unsupported format: {0}")]
-    UnsupportedFormat(String),
+    #[error(
+        "Unsupported format: {mime_type}{}{}",
+        .detected_extension.as_deref().map(|ext| format!(" (.{})", ext)).unwrap_or_default(),
+        .reason.as_deref().map(|reason| format!(": {}", reason)).unwrap_or_default()
+    )]
+    UnsupportedFormat {
+        /// The detected or requested MIME type
+        mime_type: String,
+        /// File extension the format was detected from, if any (without leading dot)
+        detected_extension: Option<String>,
+        /// Why the format can't be handled, when more specific than the MIME type alone
+        reason: Option<String>,
+    },
 
     #[error("{0}")]
     Other(String),
@@ -207,6 +219,33 @@ impl KreuzbergError {
     error_constructor!(cache, Cache);
     error_constructor!(image_processing, ImageProcessing);
     error_constructor!(serialization, Serialization);
+
+    /// Create an UnsupportedFormat error for a MIME type
+    pub fn unsupported_format<S: Into<String>>(mime_type: S) -> Self {
+        Self::UnsupportedFormat {
+            mime_type: mime_type.into(),
+            detected_extension: None,
+            reason: None,
+        }
+    }
+
+    /// Create an UnsupportedFormat error for a MIME type detected from a file extension
+    pub fn unsupported_format_with_extension<S: Into<String>, E: Into<String>>(mime_type: S, extension: E) -> Self {
+        Self::UnsupportedFormat {
+            mime_type: mime_type.into(),
+            detected_extension: Some(extension.into()),
+            reason: None,
+        }
+    }
+
+    /// Create an UnsupportedFormat error for a MIME type with an explanation of why it can't be handled
+    pub fn unsupported_format_with_reason<S: Into<String>, R: Into<String>>(mime_type: S, reason: R) -> Self {
+        Self::UnsupportedFormat {
+            mime_type: mime_type.into(),
+            detected_extension: None,
+            reason: Some(reason.into()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -322,10 +361,32 @@ mod tests {
 
     #[test]
     fn test_unsupported_format_error() {
-        let err = KreuzbergError::UnsupportedFormat("application/unknown".to_string());
+        let err = KreuzbergError::unsupported_format("application/unknown");
         assert_eq!(err.to_string(), "Unsupported format: application/unknown");
     }
 
+    #[test]
+    fn test_unsupported_format_error_with_extension() {
+        let err = KreuzbergError::unsupported_format_with_extension("image/vnd.adobe.photoshop", "psd");
+        assert_eq!(err.to_string(), "Unsupported format: image/vnd.adobe.photoshop (.psd)");
+        assert!(matches!(
+            err,
+            KreuzbergError::UnsupportedFormat { detected_extension: Some(ref ext), .. } if ext == "psd"
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_format_error_with_reason() {
+        let err = KreuzbergError::unsupported_format_with_reason(
+            "application/msword",
+            "legacy Word extraction requires the `office` feature",
+        );
+        assert_eq!(
+            err.to_string(),
+            "Unsupported format: application/msword: legacy Word extraction requires the `office` feature"
+        );
+    }
+
     #[test]
     fn test_other_error() {
         let err = KreuzbergError::Other("unexpected error".to_string());
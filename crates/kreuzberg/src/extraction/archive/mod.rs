@@ -18,7 +18,7 @@ mod zip;
 pub use gzip::{decompress_gzip, extract_gzip, extract_gzip_metadata, extract_gzip_text_content};
 pub use sevenz::{extract_7z_metadata, extract_7z_text_content};
 pub use tar::{extract_tar_metadata, extract_tar_text_content};
-pub use zip::{extract_zip_metadata, extract_zip_text_content};
+pub use zip::{extract_zip_files, extract_zip_metadata, extract_zip_text_content};
 
 /// Archive metadata extracted from an archive file.
 #[derive(Debug, Clone)]
@@ -45,8 +45,9 @@ pub fn extract_zip_metadata(bytes: &[u8], limits: &SecurityLimits) -> Result<Arc
     let mut total_size = 0u64;
 
     for i in 0..archive.len() {
+        // Raw access lists encrypted entries too, without needing their password.
         let file = archive
-            .by_index(i)
+            .by_index_raw(i)
             .map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP entry: {}", e)))?;
 
         let path = file.name().to_string();
@@ -133,3 +134,86 @@ pub fn extract_zip_text_content(bytes: &[u8], limits: &SecurityLimits) -> Result
 
     Ok(contents)
 }
+
+/// Read the bytes of every file in a ZIP archive, in archive order.
+///
+/// Directories are skipped. Encrypted entries are decrypted with `password`.
+///
+/// # Arguments
+///
+/// * `bytes` - The ZIP archive bytes
+/// * `limits` - Security limits for archive extraction
+/// * `password` - Password for encrypted entries
+///
+/// # Returns
+///
+/// Returns `(path, bytes)` pairs for all files in the archive.
+///
+/// # Errors
+///
+/// Returns a parsing error if the archive cannot be read, or if an entry is encrypted
+/// and `password` is missing or wrong. Returns a validation error if the archive has
+/// more entries than `limits.max_files_in_archive` or its files add up to more than
+/// `limits.max_archive_size` bytes.
+pub fn extract_zip_files(
+    bytes: &[u8],
+    limits: &SecurityLimits,
+    password: Option<&str>,
+) -> Result<Vec<(String, Vec<u8>)>> {
+    let cursor = Cursor::new(bytes);
+    let mut archive =
+        ZipArchive::new(cursor).map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP archive: {}", e)))?;
+
+    if archive.len() > limits.max_files_in_archive {
+        return Err(KreuzbergError::validation(format!(
+            "ZIP archive has too many files: {} (max: {})",
+            archive.len(),
+            limits.max_files_in_archive
+        )));
+    }
+
+    let mut files = Vec::with_capacity(archive.len());
+    let mut total_size = 0u64;
+
+    for i in 0..archive.len() {
+        let encrypted = archive
+            .by_index_raw(i)
+            .map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP entry: {}", e)))?
+            .encrypted();
+
+        let file = match (encrypted, password) {
+            (false, _) => archive.by_index(i),
+            (true, Some(password)) => archive.by_index_decrypt(i, password.as_bytes()),
+            (true, None) => {
+                return Err(KreuzbergError::parsing(
+                    "ZIP archive is password-protected; set ArchiveConfig.password",
+                ));
+            }
+        };
+        let file = file.map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP entry: {}", e)))?;
+
+        if file.is_dir() {
+            continue;
+        }
+
+        let path = file.name().to_string();
+        // Never read more than the remaining budget, whatever the entry header claims.
+        let remaining = (limits.max_archive_size as u64).saturating_sub(total_size);
+        let mut content = Vec::with_capacity((file.size().min(remaining) as usize).min(10 * 1024 * 1024));
+        file.take(remaining + 1)
+            .read_to_end(&mut content)
+            .map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP entry '{}': {}", path, e)))?;
+
+        total_size += content.len() as u64;
+        if total_size > limits.max_archive_size as u64 {
+            return Err(KreuzbergError::validation(format!(
+                "ZIP archive total uncompressed size exceeds limit: {} bytes (max: {} bytes)",
+                total_size, limits.max_archive_size
+            )));
+        }
+
+        files.push((path, content));
+    }
+
+    Ok(files)
+}
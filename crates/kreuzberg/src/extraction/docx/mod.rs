@@ -4,7 +4,9 @@
 //! streaming XML parsing for efficiency.
 //!
 //! Page break detection is best-effort, detecting only explicit page breaks (`<w:br w:type="page"/>`)
-//! in the document XML. This does not account for automatic pagination based on content reflowing.
+//! in the document XML. This does not account for automatic pagination based on content reflowing;
+//! [`estimate_page_boundaries`] approximates it from content length when `DocxConfig.estimate_pages`
+//! is enabled.
 
 pub mod parser;
 
@@ -180,6 +182,88 @@ fn map_page_breaks_to_boundaries(text: &str, page_breaks: Vec<usize>) -> Result<
     Ok(boundaries)
 }
 
+/// Characters on a typical printed page (A4 or Letter, 11-12pt, single spacing).
+pub const ESTIMATED_CHARS_PER_PAGE: usize = 3000;
+
+/// Estimate page boundaries from content length and explicit page breaks.
+///
+/// The text is first split at `explicit_breaks` (byte offsets where a new page starts). Each
+/// section is then split every `chars_per_page` characters, moving each soft break back to the
+/// last paragraph end (or line end, or space) in the second half of the page so pages do not
+/// start mid-word. `chars_per_page` is derived from `page_count` (the page count Word last
+/// saved in `docProps/app.xml`) when known, and is [`ESTIMATED_CHARS_PER_PAGE`] otherwise.
+///
+/// # LIMITATION
+/// This is an estimate: it ignores fonts, margins, images and table layout, so boundaries can
+/// be off by a good part of a page.
+///
+/// # Returns
+/// Contiguous boundaries covering the whole text, or an empty vector for empty text.
+pub fn estimate_page_boundaries(text: &str, explicit_breaks: &[usize], page_count: Option<usize>) -> Vec<PageBoundary> {
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    let chars_per_page = match page_count {
+        Some(pages) if pages > 0 => text.chars().count().div_ceil(pages),
+        _ => ESTIMATED_CHARS_PER_PAGE,
+    };
+
+    let mut section_ends: Vec<usize> = explicit_breaks
+        .iter()
+        .copied()
+        .filter(|&offset| offset > 0 && offset < text.len() && text.is_char_boundary(offset))
+        .collect();
+    section_ends.sort_unstable();
+    section_ends.dedup();
+    section_ends.push(text.len());
+
+    let mut page_starts = vec![0];
+    let mut page_start = 0;
+    for section_end in section_ends {
+        loop {
+            let mut chars = text[page_start..section_end].char_indices();
+            let Some((half, _)) = chars.nth(chars_per_page / 2) else {
+                break;
+            };
+            let Some((full, _)) = chars.nth(chars_per_page - chars_per_page / 2 - 1) else {
+                break;
+            };
+            page_start = soft_page_break(text, page_start + half, page_start + full);
+            page_starts.push(page_start);
+        }
+        if section_end < text.len() {
+            page_start = section_end;
+            page_starts.push(page_start);
+        }
+    }
+
+    page_starts
+        .iter()
+        .enumerate()
+        .map(|(index, &byte_start)| PageBoundary {
+            byte_start,
+            byte_end: page_starts.get(index + 1).copied().unwrap_or(text.len()),
+            page_number: index + 1,
+        })
+        .collect()
+}
+
+/// Pick where a page that should end at `target` breaks: after the last paragraph end, line end
+/// or space in `text[min..target]`, falling back to `target` itself.
+fn soft_page_break(text: &str, min: usize, target: usize) -> usize {
+    let window = &text[min..target];
+    if let Some(pos) = window.rfind("\n\n") {
+        min + pos + 2
+    } else if let Some(pos) = window.rfind('\n') {
+        min + pos + 1
+    } else if let Some(pos) = window.rfind(' ') {
+        min + pos + 1
+    } else {
+        target
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -371,6 +455,49 @@ mod tests {
         assert_eq!(reconstructed, text);
     }
 
+    #[test]
+    fn test_estimate_page_boundaries_splits_at_paragraphs() {
+        // Six 999-character paragraphs: a page holds two of them and the start of a third.
+        let paragraph = "word ".repeat(199) + "word";
+        let text = vec![paragraph; 6].join("\n\n");
+
+        let result = estimate_page_boundaries(&text, &[], None);
+
+        let starts: Vec<usize> = result.iter().map(|page| page.byte_start).collect();
+        assert_eq!(starts, vec![0, 2002, 4004]);
+        assert_eq!(result[0].byte_end, result[1].byte_start);
+        assert_eq!(result[2].byte_end, text.len());
+        assert!(estimate_page_boundaries("", &[], None).is_empty());
+    }
+
+    #[test]
+    fn test_estimate_page_boundaries_keeps_explicit_breaks() {
+        let text = "Cover page\n\nShort intro\n\nChapter one";
+        let chapter = text.find("Chapter").unwrap();
+        let intro = text.find("Short").unwrap();
+
+        let result = estimate_page_boundaries(text, &[0, chapter, intro, text.len()], None);
+
+        let starts: Vec<usize> = result.iter().map(|page| page.byte_start).collect();
+        assert_eq!(starts, vec![0, intro, chapter]);
+        assert_eq!(result[2].page_number, 3);
+        assert_eq!(result[2].byte_end, text.len());
+    }
+
+    #[test]
+    fn test_estimate_page_boundaries_calibrates_to_page_count() {
+        let text = "Ünïcödé ".repeat(100);
+
+        let result = estimate_page_boundaries(&text, &[], Some(4));
+
+        assert_eq!(result.len(), 4);
+        for boundary in &result {
+            assert!(text.is_char_boundary(boundary.byte_start));
+            assert!(text[boundary.byte_start..].starts_with('Ü'));
+        }
+        assert_eq!(result[3].byte_end, text.len());
+    }
+
     #[test]
     fn test_detect_page_breaks_no_feature() {
         let result = detect_page_breaks(b"invalid");
@@ -36,6 +36,9 @@ pub struct Document {
     pub numbering_defs: HashMap<(i64, i64), ListType>,
     /// Document elements in their original order.
     pub elements: Vec<DocumentElement>,
+    /// Indices into `elements` of the elements that start a new page because of an
+    /// explicit page break (`<w:br w:type="page"/>` or `<w:pageBreakBefore/>`).
+    pub page_breaks: Vec<usize>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -64,11 +67,26 @@ pub struct Table {
 #[derive(Debug, Clone, Default)]
 pub struct TableRow {
     pub cells: Vec<TableCell>,
+    /// Marked as a header row repeated on each page (`w:tblHeader`).
+    pub is_header: bool,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct TableCell {
     pub paragraphs: Vec<Paragraph>,
+    /// Number of grid columns the cell spans (`w:gridSpan`), if set.
+    pub grid_span: Option<usize>,
+    /// Vertical merge with the cells above or below (`w:vMerge`), if set.
+    pub v_merge: Option<VerticalMerge>,
+}
+
+/// Role of a cell in a vertically merged region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerticalMerge {
+    /// First cell of the region (`w:val="restart"`); holds the content.
+    Restart,
+    /// Covered by the region started above.
+    Continue,
 }
 
 #[derive(Debug, Clone)]
@@ -152,6 +170,13 @@ fn get_val_attr_string(e: &BytesStart) -> Option<String> {
     None
 }
 
+/// Record that the element at `index` of `document.elements` starts a new page.
+fn record_page_break(document: &mut Document, index: usize) {
+    if document.page_breaks.last() != Some(&index) {
+        document.page_breaks.push(index);
+    }
+}
+
 /// Map heading style name to markdown heading level.
 fn heading_level_from_style(style: &str) -> Option<u8> {
     match style {
@@ -170,6 +195,23 @@ fn heading_level_from_style(style: &str) -> Option<u8> {
     }
 }
 
+/// Map a heading style name to its outline depth: `Title` is 0, `HeadingN` is N.
+///
+/// Unlike [`heading_level_from_style`] this is not clamped to markdown's six levels,
+/// so deep headings still nest correctly in the outline.
+fn outline_level_from_style(style: &str) -> Option<usize> {
+    match style {
+        "Title" => Some(0),
+        s if s.starts_with("Heading") || s.starts_with("heading") => s
+            .trim_start_matches("Heading")
+            .trim_start_matches("heading")
+            .parse::<usize>()
+            .ok()
+            .filter(|n| (1..=9).contains(n)),
+        _ => None,
+    }
+}
+
 // --- Impls ---
 
 impl Document {
@@ -207,19 +249,57 @@ impl Document {
         text
     }
 
+    /// Build the document outline from heading-styled paragraphs.
+    ///
+    /// Headings nest by their style level (`Title`, then `Heading1`..`Heading9`). DOCX
+    /// carries no reliable page numbers, so entries have no `page_number`.
+    pub fn outline(&self) -> Vec<crate::types::OutlineNode> {
+        let entries = self.paragraphs.iter().filter_map(|paragraph| {
+            let level = outline_level_from_style(paragraph.style.as_deref()?)?;
+            let title = paragraph.to_text().trim().to_string();
+            (!title.is_empty()).then_some((level, title, None))
+        });
+        crate::types::OutlineNode::build_tree(entries)
+    }
+
     /// Render the document as markdown.
     pub fn to_markdown(&self) -> String {
+        self.to_markdown_with_list_markers(true)
+    }
+
+    /// Render the document as markdown, optionally dropping list item markers.
+    ///
+    /// With `preserve_list_markers`, list items keep their `- ` / `1. ` prefixes and are
+    /// indented two spaces per nesting level; without it, each item is emitted as a bare line.
+    pub fn to_markdown_with_list_markers(&self, preserve_list_markers: bool) -> String {
+        self.to_markdown_with_offsets(preserve_list_markers).0
+    }
+
+    /// Render the document as markdown, also returning where each element starts.
+    ///
+    /// The second value holds one byte offset into the markdown per entry of `elements`,
+    /// pointing at the first character of that element's text (or at the next element's
+    /// text when the element renders as nothing).
+    pub fn to_markdown_with_offsets(&self, preserve_list_markers: bool) -> (String, Vec<usize>) {
         let mut output = String::new();
+        let mut offsets = Vec::with_capacity(self.elements.len());
         let mut list_counters: HashMap<(i64, i64), usize> = HashMap::new();
         let mut prev_was_list = false;
 
         // Use elements ordering if populated, otherwise fall back to paragraphs-only
         if !self.elements.is_empty() {
             for element in &self.elements {
+                offsets.push(output.len());
                 match element {
                     DocumentElement::Paragraph(idx) => {
                         let paragraph = &self.paragraphs[*idx];
-                        self.append_paragraph_markdown(paragraph, &mut output, &mut list_counters, &mut prev_was_list);
+                        self.append_paragraph_markdown(
+                            paragraph,
+                            &mut output,
+                            &mut list_counters,
+                            &mut prev_was_list,
+                            preserve_list_markers,
+                        );
                     }
                     DocumentElement::Table(idx) => {
                         let table = &self.tables[*idx];
@@ -238,7 +318,13 @@ impl Document {
             }
         } else {
             for paragraph in &self.paragraphs {
-                self.append_paragraph_markdown(paragraph, &mut output, &mut list_counters, &mut prev_was_list);
+                self.append_paragraph_markdown(
+                    paragraph,
+                    &mut output,
+                    &mut list_counters,
+                    &mut prev_was_list,
+                    preserve_list_markers,
+                );
             }
         }
 
@@ -274,7 +360,17 @@ impl Document {
             }
         }
 
-        output.trim().to_string()
+        let leading = output.len() - output.trim_start().len();
+        let text = output.trim();
+        let offsets = offsets
+            .into_iter()
+            .map(|offset| {
+                let offset = offset.saturating_sub(leading).min(text.len());
+                offset + (text.len() - offset - text[offset..].trim_start().len())
+            })
+            .collect();
+
+        (text.to_string(), offsets)
     }
 
     /// Helper: append a paragraph's markdown to output, managing list transitions.
@@ -284,6 +380,7 @@ impl Document {
         output: &mut String,
         list_counters: &mut HashMap<(i64, i64), usize>,
         prev_was_list: &mut bool,
+        preserve_list_markers: bool,
     ) {
         let para_text = paragraph.to_text();
         let is_list = paragraph.numbering_id.is_some();
@@ -306,7 +403,11 @@ impl Document {
             }
         }
 
-        let md = paragraph.to_markdown(&self.numbering_defs, list_counters);
+        let md = if is_list && !preserve_list_markers && !paragraph.is_heading() {
+            paragraph.runs_to_markdown()
+        } else {
+            paragraph.to_markdown(&self.numbering_defs, list_counters)
+        };
         if md.is_empty() && para_text.is_empty() {
             *prev_was_list = is_list;
             return;
@@ -382,6 +483,8 @@ impl Paragraph {
         if let (Some(num_id), Some(level)) = (self.numbering_id, self.numbering_level) {
             let indent = "  ".repeat(level as usize);
             let key = (num_id, level);
+            // A new item ends any deeper sublists, so their numbering restarts
+            list_counters.retain(|&(id, lvl), _| id != num_id || lvl <= level);
             let list_type = numbering_defs.get(&key).cloned().unwrap_or(ListType::Bullet);
 
             match list_type {
@@ -400,6 +503,13 @@ impl Paragraph {
         inline
     }
 
+    /// Whether the paragraph style is a heading style.
+    pub fn is_heading(&self) -> bool {
+        self.style
+            .as_deref()
+            .is_some_and(|style| heading_level_from_style(style).is_some())
+    }
+
     pub fn add_run(&mut self, run: Run) {
         self.runs.push(run);
     }
@@ -729,6 +839,24 @@ impl<R: Read + Seek> DocxParser<R> {
                             run.strikethrough = is_format_enabled(e);
                         }
                     }
+                    b"w:tblHeader" => {
+                        if let Some(ref mut row) = current_row {
+                            row.is_header = is_format_enabled(e);
+                        }
+                    }
+                    b"w:gridSpan" => {
+                        if let Some(ref mut cell) = current_cell {
+                            cell.grid_span = get_val_attr(e).and_then(|span| usize::try_from(span).ok());
+                        }
+                    }
+                    b"w:vMerge" => {
+                        if let Some(ref mut cell) = current_cell {
+                            cell.v_merge = Some(match get_val_attr_string(e).as_deref() {
+                                Some("restart") => VerticalMerge::Restart,
+                                _ => VerticalMerge::Continue,
+                            });
+                        }
+                    }
                     b"w:pStyle" => {
                         let para = if in_table {
                             table_paragraph.as_mut()
@@ -792,6 +920,24 @@ impl<R: Read + Seek> DocxParser<R> {
                             run.strikethrough = is_format_enabled(e);
                         }
                     }
+                    b"w:tblHeader" => {
+                        if let Some(ref mut row) = current_row {
+                            row.is_header = is_format_enabled(e);
+                        }
+                    }
+                    b"w:gridSpan" => {
+                        if let Some(ref mut cell) = current_cell {
+                            cell.grid_span = get_val_attr(e).and_then(|span| usize::try_from(span).ok());
+                        }
+                    }
+                    b"w:vMerge" => {
+                        if let Some(ref mut cell) = current_cell {
+                            cell.v_merge = Some(match get_val_attr_string(e).as_deref() {
+                                Some("restart") => VerticalMerge::Restart,
+                                _ => VerticalMerge::Continue,
+                            });
+                        }
+                    }
                     b"w:pStyle" => {
                         let para = if in_table {
                             table_paragraph.as_mut()
@@ -822,6 +968,31 @@ impl<R: Read + Seek> DocxParser<R> {
                             para.numbering_id = get_val_attr(e);
                         }
                     }
+                    b"w:br" => {
+                        let is_page_break = e
+                            .attributes()
+                            .flatten()
+                            .any(|attr| attr.key.as_ref() == b"w:type" && attr.value.as_ref() == b"page");
+                        if is_page_break
+                            && !in_table
+                            && let Some(ref para) = current_paragraph
+                        {
+                            // A break after some text moves the following element to the new page.
+                            let has_text = para
+                                .runs
+                                .iter()
+                                .chain(current_run.as_ref())
+                                .any(|run| !run.text.is_empty());
+                            let index = document.elements.len() + usize::from(has_text);
+                            record_page_break(document, index);
+                        }
+                    }
+                    b"w:pageBreakBefore" => {
+                        if !in_table && current_paragraph.is_some() && is_format_enabled(e) {
+                            let index = document.elements.len();
+                            record_page_break(document, index);
+                        }
+                    }
                     _ => {}
                 },
                 Ok(Event::Text(e)) => {
@@ -1458,6 +1629,50 @@ mod tests {
         assert_eq!(para.to_markdown(&defs, &mut counters), "  - Nested");
     }
 
+    fn list_paragraph(num_id: i64, level: i64, text: &str) -> Paragraph {
+        let mut para = Paragraph::new();
+        para.numbering_id = Some(num_id);
+        para.numbering_level = Some(level);
+        para.add_run(Run::new(text.to_string()));
+        para
+    }
+
+    #[test]
+    fn test_nested_ordered_list_numbering_restarts() {
+        let mut defs = HashMap::new();
+        defs.insert((1, 0), ListType::Numbered);
+        defs.insert((1, 1), ListType::Numbered);
+        let mut counters = HashMap::new();
+
+        let rendered: Vec<String> = [(0, "First"), (1, "Sub a"), (1, "Sub b"), (0, "Second"), (1, "Sub c")]
+            .iter()
+            .map(|&(level, text)| list_paragraph(1, level, text).to_markdown(&defs, &mut counters))
+            .collect();
+
+        assert_eq!(
+            rendered,
+            vec!["1. First", "  1. Sub a", "  2. Sub b", "2. Second", "  1. Sub c"]
+        );
+    }
+
+    #[test]
+    fn test_document_list_markers_toggle() {
+        let mut document = Document::new();
+        document.numbering_defs.insert((1, 0), ListType::Numbered);
+        document.numbering_defs.insert((1, 1), ListType::Bullet);
+        document.paragraphs = vec![
+            list_paragraph(1, 0, "Step one"),
+            list_paragraph(1, 1, "Detail"),
+            list_paragraph(1, 0, "Step two"),
+        ];
+
+        assert_eq!(document.to_markdown(), "1. Step one\n  - Detail\n2. Step two");
+        assert_eq!(
+            document.to_markdown_with_list_markers(false),
+            "Step one\nDetail\nStep two"
+        );
+    }
+
     #[test]
     fn test_heading_level_from_style() {
         assert_eq!(heading_level_from_style("Title"), Some(1));
@@ -1468,6 +1683,33 @@ mod tests {
         assert_eq!(heading_level_from_style("Normal"), None);
     }
 
+    #[test]
+    fn test_document_outline_nests_headings() {
+        let mut doc = Document::new();
+        for (style, text) in [
+            (Some("Title"), "Report"),
+            (Some("Heading1"), "Introduction"),
+            (None, "Body text"),
+            (Some("Heading2"), "Scope"),
+            (Some("Heading1"), "  "),
+            (Some("Heading1"), "Results"),
+        ] {
+            let mut para = Paragraph::new();
+            para.style = style.map(str::to_string);
+            para.add_run(Run::new(text.to_string()));
+            doc.paragraphs.push(para);
+        }
+
+        let outline = doc.outline();
+        assert_eq!(outline.len(), 1);
+        assert_eq!(outline[0].title, "Report");
+        let sections: Vec<&str> = outline[0].children.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(sections, vec!["Introduction", "Results"]);
+        assert_eq!(outline[0].children[0].children[0].title, "Scope");
+        assert_eq!(outline[0].children[0].children[0].level, 3);
+        assert!(outline[0].children[0].children[0].page_number.is_none());
+    }
+
     #[test]
     fn test_is_format_enabled_no_val() {
         // <w:b/> - no w:val attribute means enabled
@@ -150,14 +150,22 @@ pub fn parse_eml_content(data: &[u8]) -> Result<EmailExtractionResult> {
 
     let message_id = message.message_id().map(|id| id.to_string());
 
-    let plain_text = message.body_text(0).map(|s| s.to_string());
+    // `body_text` converts HTML-only bodies to text; keep those on the HTML path instead.
+    let has_plain_part = message
+        .text_part(0)
+        .is_some_and(|part| matches!(part.body, mail_parser::PartType::Text(_)));
+    let plain_text = if has_plain_part {
+        message.body_text(0).map(|s| s.to_string())
+    } else {
+        None
+    };
 
     let html_content = message.body_html(0).map(|s| s.to_string());
 
     let cleaned_text = if let Some(ref plain) = plain_text {
         plain.clone()
     } else if let Some(html) = &html_content {
-        clean_html_content(html)
+        html_body_to_text(html)
     } else {
         String::new()
     };
@@ -329,7 +337,7 @@ fn extract_msg_from_cfb<F: std::io::Read + std::io::Seek>(
     let cleaned_text = if let Some(ref plain) = plain_text {
         plain.clone()
     } else if let Some(ref html) = html_content {
-        clean_html_content(html)
+        html_body_to_text(html)
     } else {
         String::new()
     };
@@ -527,6 +535,18 @@ pub fn build_email_text_output(result: &EmailExtractionResult) -> String {
     text_parts.join("\n")
 }
 
+/// Text for an HTML-only body: Markdown when the HTML converter is available, stripped text otherwise.
+fn html_body_to_text(html: &str) -> String {
+    #[cfg(feature = "html")]
+    if let Ok(markdown) =
+        crate::extraction::html::convert_html_to_markdown(html, None, Some(crate::core::config::OutputFormat::Markdown))
+    {
+        return markdown.trim().to_string();
+    }
+
+    clean_html_content(html)
+}
+
 fn clean_html_content(html: &str) -> String {
     if html.is_empty() {
         return String::new();
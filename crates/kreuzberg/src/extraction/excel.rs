@@ -7,7 +7,7 @@
 //! # Features
 //!
 //! - **Multiple formats**: XLSX, XLSM, XLS, XLSB, ODS
-//! - **Sheet extraction**: Reads all sheets from workbook
+//! - **Sheet extraction**: Reads all visible sheets from workbook (hidden sheets on request)
 //! - **Markdown conversion**: Converts spreadsheet data to Markdown tables
 //! - **Office metadata**: Extracts core properties, custom properties (when `office` feature enabled)
 //! - **Error handling**: Distinguishes between format errors and true I/O errors
@@ -27,12 +27,13 @@
 //! # Ok(())
 //! # }
 //! ```
-use calamine::{Data, DataRef, Range, Reader, open_workbook_auto};
+use calamine::{Data, DataRef, Range, Reader, SheetVisible, open_workbook_auto};
 use std::collections::HashMap;
 use std::fmt::Write as FmtWrite;
 use std::io::{Cursor, Read, Seek};
 use std::path::Path;
 
+use crate::core::config::{FormulaMode, XlsxConfig};
 use crate::error::{KreuzbergError, Result};
 use crate::extraction::capacity;
 use crate::types::{ExcelSheet, ExcelWorkbook};
@@ -52,6 +53,11 @@ use crate::extraction::office_metadata::{
 use serde_json::Value;
 
 pub fn read_excel_file(file_path: &str) -> Result<ExcelWorkbook> {
+    read_excel_file_with_config(file_path, &XlsxConfig::default())
+}
+
+/// Read a spreadsheet file, applying sheet selection and formula rendering from `config`.
+pub fn read_excel_file_with_config(file_path: &str, config: &XlsxConfig) -> Result<ExcelWorkbook> {
     let lower_path = file_path.to_lowercase();
 
     #[cfg(feature = "office")]
@@ -73,7 +79,7 @@ pub fn read_excel_file(file_path: &str) -> Result<ExcelWorkbook> {
         let file = std::fs::File::open(file_path)?;
         let workbook = calamine::Xlsx::new(std::io::BufReader::new(file))
             .map_err(|e| KreuzbergError::parsing(format!("Failed to parse XLSX: {}", e)))?;
-        return process_xlsx_workbook(workbook, office_metadata);
+        return process_xlsx_workbook(workbook, office_metadata, config);
     }
 
     // For .xlam (Excel add-in), try XLSX parsing but gracefully return empty workbook on failure
@@ -81,13 +87,14 @@ pub fn read_excel_file(file_path: &str) -> Result<ExcelWorkbook> {
         let file = std::fs::File::open(file_path)?;
         match calamine::Xlsx::new(std::io::BufReader::new(file)) {
             Ok(workbook) => {
-                return process_xlsx_workbook(workbook, office_metadata);
+                return process_xlsx_workbook(workbook, office_metadata, config);
             }
             Err(_) => {
                 // .xlam files may not contain proper workbook data - return empty workbook
                 return Ok(ExcelWorkbook {
                     sheets: vec![],
                     metadata: office_metadata.unwrap_or_default(),
+                    hidden_sheets: vec![],
                 });
             }
         }
@@ -98,12 +105,13 @@ pub fn read_excel_file(file_path: &str) -> Result<ExcelWorkbook> {
         let file = std::fs::File::open(file_path)?;
         match calamine::Xls::new(std::io::BufReader::new(file)) {
             Ok(workbook) => {
-                return process_workbook(workbook, office_metadata);
+                return process_workbook(workbook, office_metadata, config);
             }
             Err(_) => {
                 return Ok(ExcelWorkbook {
                     sheets: vec![],
                     metadata: office_metadata.unwrap_or_default(),
+                    hidden_sheets: vec![],
                 });
             }
         }
@@ -114,7 +122,7 @@ pub fn read_excel_file(file_path: &str) -> Result<ExcelWorkbook> {
         let file = std::fs::File::open(file_path)?;
         let workbook = calamine::Xlsb::new(std::io::BufReader::new(file))
             .map_err(|e| KreuzbergError::parsing(format!("Failed to parse XLSB: {}", e)))?;
-        return process_workbook(workbook, office_metadata);
+        return process_workbook(workbook, office_metadata, config);
     }
 
     // For other formats, use open_workbook_auto
@@ -133,10 +141,15 @@ pub fn read_excel_file(file_path: &str) -> Result<ExcelWorkbook> {
         Err(e) => return Err(KreuzbergError::parsing(format!("Failed to parse Excel file: {}", e))),
     };
 
-    process_workbook(workbook, office_metadata)
+    process_workbook(workbook, office_metadata, config)
 }
 
 pub fn read_excel_bytes(data: &[u8], file_extension: &str) -> Result<ExcelWorkbook> {
+    read_excel_bytes_with_config(data, file_extension, &XlsxConfig::default())
+}
+
+/// Read spreadsheet bytes, applying sheet selection and formula rendering from `config`.
+pub fn read_excel_bytes_with_config(data: &[u8], file_extension: &str, config: &XlsxConfig) -> Result<ExcelWorkbook> {
     #[cfg(feature = "office")]
     let office_metadata = match file_extension.to_lowercase().as_str() {
         ".xlsx" | ".xlsm" | ".xlam" | ".xltm" => extract_xlsx_office_metadata_from_bytes(data).ok(),
@@ -152,18 +165,19 @@ pub fn read_excel_bytes(data: &[u8], file_extension: &str) -> Result<ExcelWorkbo
             let cursor = Cursor::new(data);
             let workbook = calamine::Xlsx::new(cursor)
                 .map_err(|e| KreuzbergError::parsing(format!("Failed to parse XLSX: {}", e)))?;
-            process_xlsx_workbook(workbook, office_metadata)
+            process_xlsx_workbook(workbook, office_metadata, config)
         }
         // Exotic format: .xlam (Excel add-in) - may not contain proper workbook data
         ".xlam" => {
             let cursor = Cursor::new(data);
             match calamine::Xlsx::new(cursor) {
-                Ok(workbook) => process_xlsx_workbook(workbook, office_metadata),
+                Ok(workbook) => process_xlsx_workbook(workbook, office_metadata, config),
                 Err(_) => {
                     // .xlam files may not contain proper workbook data - return empty workbook
                     Ok(ExcelWorkbook {
                         sheets: vec![],
                         metadata: office_metadata.unwrap_or_default(),
+                        hidden_sheets: vec![],
                     })
                 }
             }
@@ -173,18 +187,19 @@ pub fn read_excel_bytes(data: &[u8], file_extension: &str) -> Result<ExcelWorkbo
             let cursor = Cursor::new(data);
             let workbook = calamine::Xls::new(cursor)
                 .map_err(|e| KreuzbergError::parsing(format!("Failed to parse XLS: {}", e)))?;
-            process_workbook(workbook, office_metadata)
+            process_workbook(workbook, office_metadata, config)
         }
         // Exotic format: .xla (legacy add-in) - may not contain proper workbook data
         ".xla" => {
             let cursor = Cursor::new(data);
             match calamine::Xls::new(cursor) {
-                Ok(workbook) => process_workbook(workbook, office_metadata),
+                Ok(workbook) => process_workbook(workbook, office_metadata, config),
                 Err(_) => {
                     // .xla files may not contain proper workbook data - return empty workbook
                     Ok(ExcelWorkbook {
                         sheets: vec![],
                         metadata: office_metadata.unwrap_or_default(),
+                        hidden_sheets: vec![],
                     })
                 }
             }
@@ -194,14 +209,14 @@ pub fn read_excel_bytes(data: &[u8], file_extension: &str) -> Result<ExcelWorkbo
             let cursor = Cursor::new(data);
             let workbook = calamine::Xlsb::new(cursor)
                 .map_err(|e| KreuzbergError::parsing(format!("Failed to parse XLSB: {}", e)))?;
-            process_workbook(workbook, office_metadata)
+            process_workbook(workbook, office_metadata, config)
         }
         // Standard OpenDocument format
         ".ods" => {
             let cursor = Cursor::new(data);
             let workbook = calamine::Ods::new(cursor)
                 .map_err(|e| KreuzbergError::parsing(format!("Failed to parse ODS: {}", e)))?;
-            process_workbook(workbook, office_metadata)
+            process_workbook(workbook, office_metadata, config)
         }
         _ => Err(KreuzbergError::parsing(format!(
             "Unsupported file extension: {}",
@@ -219,13 +234,16 @@ pub fn read_excel_bytes(data: &[u8], file_extension: &str) -> Result<ExcelWorkbo
 fn process_xlsx_workbook<RS: Read + Seek>(
     mut workbook: calamine::Xlsx<RS>,
     office_metadata: Option<HashMap<String, String>>,
+    config: &XlsxConfig,
 ) -> Result<ExcelWorkbook> {
     let sheet_names = workbook.sheet_names();
-    let mut sheets = Vec::with_capacity(sheet_names.len());
+    let hidden_sheets = hidden_sheet_names(&workbook);
+    let selected = select_sheets(&sheet_names, &hidden_sheets, config)?;
+    let mut sheets = Vec::with_capacity(selected.len());
 
-    for name in &sheet_names {
+    for name in &selected {
         // Use worksheet_cells_reader to stream cells and detect pathological bounding boxes
-        match process_xlsx_sheet_safe(&mut workbook, name) {
+        match process_xlsx_sheet_safe(&mut workbook, name, config.effective_formula_mode()) {
             Ok(sheet) => sheets.push(sheet),
             Err(e) => {
                 // Log but don't fail - continue with other sheets
@@ -235,14 +253,22 @@ fn process_xlsx_workbook<RS: Read + Seek>(
     }
 
     let metadata = extract_metadata(&workbook, &sheet_names, office_metadata);
-    Ok(ExcelWorkbook { sheets, metadata })
+    Ok(ExcelWorkbook {
+        sheets,
+        metadata,
+        hidden_sheets,
+    })
 }
 
 /// Process a single XLSX sheet safely by pre-checking the bounding box.
 ///
 /// This function streams cells to compute the actual bounding box without allocating
 /// a full Range, then only creates the Range if the bounding box is within safe limits.
-fn process_xlsx_sheet_safe<RS: Read + Seek>(workbook: &mut calamine::Xlsx<RS>, sheet_name: &str) -> Result<ExcelSheet> {
+fn process_xlsx_sheet_safe<RS: Read + Seek>(
+    workbook: &mut calamine::Xlsx<RS>,
+    sheet_name: &str,
+    formula_mode: FormulaMode,
+) -> Result<ExcelSheet> {
     // First pass: stream cells to compute actual bounding box and collect cell data
     let (cells, row_min, row_max, col_min, col_max) = {
         let mut cell_reader = workbook
@@ -301,7 +327,16 @@ fn process_xlsx_sheet_safe<RS: Read + Seek>(workbook: &mut calamine::Xlsx<RS>, s
     // Check for pathological bounding box
     if bb_cells > MAX_BOUNDING_BOX_CELLS {
         // Sheet has sparse data at extreme positions - process directly from cells
-        return process_sparse_sheet_from_cells(sheet_name, cells, row_min, row_max, col_min, col_max);
+        let formulas = load_sparse_formulas(workbook, sheet_name, formula_mode);
+        return process_sparse_sheet_from_cells(
+            sheet_name,
+            cells,
+            formulas.as_ref(),
+            row_min,
+            row_max,
+            col_min,
+            col_max,
+        );
     }
 
     // Safe to create a Range - bounding box is within limits
@@ -309,8 +344,9 @@ fn process_xlsx_sheet_safe<RS: Read + Seek>(workbook: &mut calamine::Xlsx<RS>, s
     let range = workbook
         .worksheet_range(sheet_name)
         .map_err(|e| KreuzbergError::parsing(format!("Failed to parse sheet '{}': {}", sheet_name, e)))?;
+    let formulas = load_formulas(workbook, sheet_name, formula_mode);
 
-    Ok(process_sheet(sheet_name, &range))
+    Ok(process_sheet(sheet_name, &range, formulas.as_ref()))
 }
 
 /// Process a sparse sheet directly from collected cells without creating a full Range.
@@ -320,6 +356,7 @@ fn process_xlsx_sheet_safe<RS: Read + Seek>(workbook: &mut calamine::Xlsx<RS>, s
 fn process_sparse_sheet_from_cells(
     sheet_name: &str,
     cells: Vec<((u32, u32), Data)>,
+    formulas: Option<&SheetFormulas>,
     row_min: u32,
     row_max: u32,
     col_min: u32,
@@ -368,7 +405,10 @@ fn process_sparse_sheet_from_cells(
                 break;
             }
             let cell_ref = col_to_excel_letter(col);
-            let cell_str = format_cell_to_string(data);
+            let cell_str = match formulas {
+                Some(formulas) => formulas.render(format_cell_to_string(data), (row, col)),
+                None => format_cell_to_string(data),
+            };
             if !cell_str.is_empty() {
                 writeln!(markdown, "- **{}{}**: {}", cell_ref, row + 1, cell_str).expect("write to String cannot fail");
                 output_count += 1;
@@ -398,28 +438,168 @@ fn col_to_excel_letter(col: u32) -> String {
     result
 }
 
-fn process_workbook<RS, R>(mut workbook: R, office_metadata: Option<HashMap<String, String>>) -> Result<ExcelWorkbook>
+fn process_workbook<RS, R>(
+    mut workbook: R,
+    office_metadata: Option<HashMap<String, String>>,
+    config: &XlsxConfig,
+) -> Result<ExcelWorkbook>
 where
     RS: std::io::Read + std::io::Seek,
     R: Reader<RS>,
 {
     let sheet_names = workbook.sheet_names();
+    let hidden_sheets = hidden_sheet_names(&workbook);
+    let selected = select_sheets(&sheet_names, &hidden_sheets, config)?;
 
-    let mut sheets = Vec::with_capacity(sheet_names.len());
+    let mut sheets = Vec::with_capacity(selected.len());
 
-    for name in &sheet_names {
+    for name in &selected {
         if let Ok(range) = workbook.worksheet_range(name) {
-            sheets.push(process_sheet(name, &range));
+            let formulas = load_formulas(&mut workbook, name, config.effective_formula_mode());
+            sheets.push(process_sheet(name, &range, formulas.as_ref()));
         }
     }
 
     let metadata = extract_metadata(&workbook, &sheet_names, office_metadata);
 
-    Ok(ExcelWorkbook { sheets, metadata })
+    Ok(ExcelWorkbook {
+        sheets,
+        metadata,
+        hidden_sheets,
+    })
+}
+
+/// Names of the hidden and very hidden sheets of a workbook, in workbook order.
+fn hidden_sheet_names<RS, R>(workbook: &R) -> Vec<String>
+where
+    RS: std::io::Read + std::io::Seek,
+    R: Reader<RS>,
+{
+    workbook
+        .sheets_metadata()
+        .iter()
+        .filter(|sheet| sheet.visible != SheetVisible::Visible)
+        .map(|sheet| sheet.name.clone())
+        .collect()
+}
+
+/// Sheets to extract, in workbook order.
+///
+/// Hidden sheets are skipped unless `config.include_hidden_sheets` is set or they are
+/// named in `config.sheet_names`. Returns a validation error if `config.sheet_names`
+/// names a sheet that does not exist.
+fn select_sheets(sheet_names: &[String], hidden_sheets: &[String], config: &XlsxConfig) -> Result<Vec<String>> {
+    let Some(requested) = &config.sheet_names else {
+        return Ok(sheet_names
+            .iter()
+            .filter(|name| config.include_hidden_sheets || !hidden_sheets.contains(name))
+            .cloned()
+            .collect());
+    };
+
+    if let Some(missing) = requested.iter().find(|name| !sheet_names.contains(name)) {
+        return Err(KreuzbergError::validation(format!(
+            "Sheet '{}' not found. Available sheets: {}",
+            missing,
+            sheet_names.join(", ")
+        )));
+    }
+
+    Ok(sheet_names
+        .iter()
+        .filter(|name| requested.contains(name))
+        .cloned()
+        .collect())
+}
+
+/// Formula text of a sheet, keyed by absolute cell position, and how to render it
+/// next to cell values.
+struct SheetFormulas {
+    cells: HashMap<(u32, u32), String>,
+    mode: FormulaMode,
+}
+
+impl SheetFormulas {
+    /// Render the cell at absolute `position` given its formatted `value`.
+    fn render(&self, value: String, position: (u32, u32)) -> String {
+        let formula = match self.cells.get(&position) {
+            Some(formula) if !formula.is_empty() => formula,
+            _ => return value,
+        };
+
+        match self.mode {
+            FormulaMode::ValueOnly => value,
+            FormulaMode::Both if !value.is_empty() => format!("{} (={})", value, formula),
+            FormulaMode::FormulaOnly | FormulaMode::Both => format!("={}", formula),
+        }
+    }
+}
+
+/// Read formulas for `sheet_name` unless only values are requested.
+///
+/// Formats without formula support fall back to values with a warning.
+fn load_formulas<RS, R>(workbook: &mut R, sheet_name: &str, mode: FormulaMode) -> Option<SheetFormulas>
+where
+    RS: std::io::Read + std::io::Seek,
+    R: Reader<RS>,
+{
+    if mode == FormulaMode::ValueOnly {
+        return None;
+    }
+
+    match workbook.worksheet_formula(sheet_name) {
+        Ok(range) => {
+            let (start_row, start_col) = range.start().unwrap_or_default();
+            let cells = range
+                .used_cells()
+                .map(|(row, col, formula)| ((start_row + row as u32, start_col + col as u32), formula.clone()))
+                .collect();
+            Some(SheetFormulas { cells, mode })
+        }
+        Err(e) => {
+            tracing::warn!("Failed to read formulas for sheet '{}': {:?}", sheet_name, e);
+            None
+        }
+    }
+}
+
+/// Read formulas for a sheet too sparse for a dense `Range`, one cell at a time.
+fn load_sparse_formulas<RS: Read + Seek>(
+    workbook: &mut calamine::Xlsx<RS>,
+    sheet_name: &str,
+    mode: FormulaMode,
+) -> Option<SheetFormulas> {
+    if mode == FormulaMode::ValueOnly {
+        return None;
+    }
+
+    let mut cell_reader = match workbook.worksheet_cells_reader(sheet_name) {
+        Ok(reader) => reader,
+        Err(e) => {
+            tracing::warn!("Failed to read formulas for sheet '{}': {:?}", sheet_name, e);
+            return None;
+        }
+    };
+
+    let mut cells = HashMap::new();
+    loop {
+        match cell_reader.next_formula() {
+            Ok(Some(cell)) if !cell.get_value().is_empty() => {
+                cells.insert(cell.get_position(), cell.get_value().clone());
+            }
+            Ok(Some(_)) => {}
+            Ok(None) => break,
+            Err(e) => {
+                tracing::warn!("Failed to read formulas for sheet '{}': {:?}", sheet_name, e);
+                return None;
+            }
+        }
+    }
+    Some(SheetFormulas { cells, mode })
 }
 
 #[inline]
-fn process_sheet(name: &str, range: &Range<Data>) -> ExcelSheet {
+fn process_sheet(name: &str, range: &Range<Data>, formulas: Option<&SheetFormulas>) -> ExcelSheet {
     let (rows, cols) = range.get_size();
     let cell_count = range.used_cells().count();
 
@@ -439,7 +619,7 @@ fn process_sheet(name: &str, range: &Range<Data>) -> ExcelSheet {
             table_cells: None,
         }
     } else {
-        let (markdown, table_cells) = generate_markdown_and_cells(name, range, estimated_capacity);
+        let (markdown, table_cells) = generate_markdown_and_cells(name, range, estimated_capacity, formulas);
         ExcelSheet {
             name: name.to_owned(),
             markdown,
@@ -458,7 +638,12 @@ fn process_sheet(name: &str, range: &Range<Data>) -> ExcelSheet {
 /// was previously done in `sheets_to_tables()`.
 ///
 /// Returns (markdown, table_cells) where table_cells is a 2D vector of strings.
-fn generate_markdown_and_cells(sheet_name: &str, range: &Range<Data>, capacity: usize) -> (String, Vec<Vec<String>>) {
+fn generate_markdown_and_cells(
+    sheet_name: &str,
+    range: &Range<Data>,
+    capacity: usize,
+    formulas: Option<&SheetFormulas>,
+) -> (String, Vec<Vec<String>>) {
     // Fix for issue #331: Protect against extreme declared dimensions.
     // Excel Solver files can declare A1:XFD1048575 (1M+ rows) but only have ~26 actual cells.
     // Calling range.rows().collect() would iterate ALL declared rows causing OOM.
@@ -492,6 +677,15 @@ fn generate_markdown_and_cells(sheet_name: &str, range: &Range<Data>, capacity:
         return (result, Vec::new());
     }
 
+    let start = range.start().unwrap_or_default();
+    let render_cell = |cell: &Data, row: usize, col: usize| {
+        let value = format_cell_to_string(cell);
+        match formulas {
+            Some(formulas) => formulas.render(value, (start.0 + row as u32, start.1 + col as u32)),
+            None => value,
+        }
+    };
+
     let header = &rows[0];
     let header_len = header.len();
     let row_count = rows.len();
@@ -518,7 +712,7 @@ fn generate_markdown_and_cells(sheet_name: &str, range: &Range<Data>, capacity:
         if i > 0 {
             markdown.push_str(" | ");
         }
-        let cell_str = format_cell_to_string(cell);
+        let cell_str = render_cell(cell, 0, i);
 
         if cell_str.contains('|') || cell_str.contains('\\') {
             escape_markdown_into(&mut markdown, &cell_str);
@@ -539,7 +733,7 @@ fn generate_markdown_and_cells(sheet_name: &str, range: &Range<Data>, capacity:
     }
     markdown.push_str(" |\n");
 
-    for row in rows.iter().skip(1) {
+    for (row_idx, row) in rows.iter().enumerate().skip(1) {
         let mut row_cells = Vec::with_capacity(header_len);
         markdown.push_str("| ");
         for i in 0..header_len {
@@ -547,7 +741,7 @@ fn generate_markdown_and_cells(sheet_name: &str, range: &Range<Data>, capacity:
                 markdown.push_str(" | ");
             }
             let cell_str = if let Some(cell) = row.get(i) {
-                let cell_str = format_cell_to_string(cell);
+                let cell_str = render_cell(cell, row_idx, i);
 
                 if cell_str.contains('|') || cell_str.contains('\\') {
                     escape_markdown_into(&mut markdown, &cell_str);
@@ -883,7 +1077,7 @@ mod tests {
     #[test]
     fn test_process_sheet_empty() {
         let range: Range<Data> = Range::empty();
-        let sheet = process_sheet("EmptySheet", &range);
+        let sheet = process_sheet("EmptySheet", &range, None);
 
         assert_eq!(sheet.name, "EmptySheet");
         assert_eq!(sheet.row_count, 0);
@@ -897,7 +1091,7 @@ mod tests {
         let mut range: Range<Data> = Range::new((0, 0), (0, 0));
         range.set_value((0, 0), Data::String("Single Cell".to_owned()));
 
-        let sheet = process_sheet("Sheet1", &range);
+        let sheet = process_sheet("Sheet1", &range, None);
 
         assert_eq!(sheet.name, "Sheet1");
         assert_eq!(sheet.row_count, 1);
@@ -916,7 +1110,7 @@ mod tests {
         range.set_value((2, 0), Data::String("Bob".to_owned()));
         range.set_value((2, 1), Data::Int(25));
 
-        let sheet = process_sheet("People", &range);
+        let sheet = process_sheet("People", &range, None);
 
         assert_eq!(sheet.name, "People");
         assert_eq!(sheet.row_count, 3);
@@ -930,7 +1124,7 @@ mod tests {
     #[test]
     fn test_generate_markdown_and_cells_empty() {
         let range: Range<Data> = Range::empty();
-        let (markdown, cells) = generate_markdown_and_cells("Test", &range, 100);
+        let (markdown, cells) = generate_markdown_and_cells("Test", &range, 100, None);
 
         assert!(markdown.contains("## Test"));
         assert!(cells.is_empty());
@@ -946,7 +1140,7 @@ mod tests {
         range.set_value((1, 1), Data::String("B".to_owned()));
         range.set_value((1, 2), Data::String("C".to_owned()));
 
-        let (markdown, cells) = generate_markdown_and_cells("Sheet1", &range, 200);
+        let (markdown, cells) = generate_markdown_and_cells("Sheet1", &range, 200, None);
 
         assert!(markdown.contains("## Sheet1"));
         assert!(markdown.contains("Col1"));
@@ -963,7 +1157,7 @@ mod tests {
         range.set_value((1, 0), Data::String("X".to_owned()));
         range.set_value((1, 2), Data::String("Z".to_owned()));
 
-        let (markdown, cells) = generate_markdown_and_cells("Sparse", &range, 200);
+        let (markdown, cells) = generate_markdown_and_cells("Sparse", &range, 200, None);
 
         assert!(markdown.contains("X"));
         assert!(markdown.contains("Z"));
@@ -1010,7 +1204,7 @@ mod tests {
         range.set_value((1, 0), Data::String("A".to_owned()));
         range.set_value((1, 1), Data::String("B".to_owned()));
 
-        let (markdown, _cells) = generate_markdown_and_cells("Test", &range, 100);
+        let (markdown, _cells) = generate_markdown_and_cells("Test", &range, 100, None);
 
         let lines: Vec<&str> = markdown.lines().collect();
         assert!(lines[0].contains("## Test"));
@@ -1028,10 +1222,103 @@ mod tests {
             }
         }
 
-        let sheet = process_sheet("Data", &range);
+        let sheet = process_sheet("Data", &range, None);
 
         assert_eq!(sheet.row_count, 10);
         assert_eq!(sheet.col_count, 5);
         assert_eq!(sheet.cell_count, 50);
     }
+
+    fn sum_sheet() -> (Range<Data>, HashMap<(u32, u32), String>) {
+        let mut values: Range<Data> = Range::new((0, 0), (2, 1));
+        values.set_value((0, 0), Data::String("Item".to_owned()));
+        values.set_value((0, 1), Data::String("Amount".to_owned()));
+        values.set_value((1, 0), Data::String("A".to_owned()));
+        values.set_value((1, 1), Data::Float(10.0));
+        values.set_value((2, 0), Data::String("Total".to_owned()));
+        values.set_value((2, 1), Data::Float(10.0));
+
+        let formulas = HashMap::from([((2, 1), "SUM(B2:B2)".to_owned())]);
+
+        (values, formulas)
+    }
+
+    #[test]
+    fn test_formula_modes_render_cells() {
+        let (values, formulas) = sum_sheet();
+
+        let both = SheetFormulas {
+            cells: formulas.clone(),
+            mode: FormulaMode::Both,
+        };
+        let (markdown, cells) = generate_markdown_and_cells("Sums", &values, 100, Some(&both));
+        assert_eq!(cells[2][1], "10.0 (=SUM(B2:B2))");
+        assert_eq!(cells[1][1], "10.0");
+        assert!(markdown.contains("| Total | 10.0 (=SUM(B2:B2)) |"));
+
+        let formula_only = SheetFormulas {
+            cells: formulas,
+            mode: FormulaMode::FormulaOnly,
+        };
+        let (_markdown, cells) = generate_markdown_and_cells("Sums", &values, 100, Some(&formula_only));
+        assert_eq!(cells[2][1], "=SUM(B2:B2)");
+        assert_eq!(cells[1][1], "10.0");
+    }
+
+    #[test]
+    fn test_sparse_sheet_renders_formulas() {
+        let cells = vec![((0, 0), Data::Float(10.0)), ((1_048_575, 16_383), Data::Float(10.0))];
+        let formulas = SheetFormulas {
+            cells: HashMap::from([((1_048_575, 16_383), "SUM(A1:A1)".to_owned())]),
+            mode: FormulaMode::Both,
+        };
+
+        let sheet = process_sparse_sheet_from_cells("Sparse", cells, Some(&formulas), 0, 1_048_575, 0, 16_383).unwrap();
+
+        assert!(sheet.markdown.contains("- **A1**: 10.0\n"));
+        assert!(sheet.markdown.contains("- **XFD1048576**: 10.0 (=SUM(A1:A1))"));
+    }
+
+    #[test]
+    fn test_select_sheets_filters_and_validates() {
+        let names = vec!["Summary".to_string(), "Data".to_string(), "Notes".to_string()];
+
+        let config = XlsxConfig {
+            sheet_names: Some(vec!["Notes".to_string(), "Summary".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(select_sheets(&names, &[], &config).unwrap(), vec!["Summary", "Notes"]);
+        assert_eq!(select_sheets(&names, &[], &XlsxConfig::default()).unwrap(), names);
+
+        let config = XlsxConfig {
+            sheet_names: Some(vec!["Missing".to_string()]),
+            ..Default::default()
+        };
+        let err = select_sheets(&names, &[], &config).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+        assert!(err.to_string().contains("Summary, Data, Notes"));
+    }
+
+    #[test]
+    fn test_select_sheets_skips_hidden_sheets() {
+        let names = vec!["Summary".to_string(), "Data".to_string(), "Notes".to_string()];
+        let hidden = vec!["Data".to_string()];
+
+        assert_eq!(
+            select_sheets(&names, &hidden, &XlsxConfig::default()).unwrap(),
+            vec!["Summary", "Notes"]
+        );
+
+        let config = XlsxConfig {
+            include_hidden_sheets: true,
+            ..Default::default()
+        };
+        assert_eq!(select_sheets(&names, &hidden, &config).unwrap(), names);
+
+        let config = XlsxConfig {
+            sheet_names: Some(vec!["Data".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(select_sheets(&names, &hidden, &config).unwrap(), vec!["Data"]);
+    }
 }
@@ -0,0 +1,158 @@
+//! Accessibility text.
+//!
+//! Image `alt` text and the `aria-label` of interactive elements describe content that
+//! has no visible text of its own, so HTML conversion drops it. [`inline_accessibility_text`]
+//! rewrites the HTML before conversion so this text ends up in the extracted content.
+
+use std::borrow::Cow;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::layout_tables::{RAW_TEXT_TAGS, find_tag_end};
+
+static ALT_ATTRIBUTE: Lazy<Regex> = Lazy::new(|| attribute_regex("alt"));
+static ARIA_LABEL_ATTRIBUTE: Lazy<Regex> = Lazy::new(|| attribute_regex("aria-label"));
+static INTERACTIVE_ROLE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)\srole\s*=\s*["']?\s*(button|link|checkbox|radio|switch|tab|menuitem|option|combobox|searchbox|slider|textbox)\b"#)
+        .expect("valid regex")
+});
+
+/// Elements that are interactive without an explicit `role`.
+const INTERACTIVE_TAGS: &[&str] = &["a", "button", "input", "select", "textarea", "summary"];
+
+fn attribute_regex(name: &str) -> Regex {
+    Regex::new(&format!(
+        r#"(?i)\s{}\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#,
+        regex::escape(name)
+    ))
+    .expect("valid regex")
+}
+
+fn attribute_value<'a>(regex: &Regex, tag: &'a str) -> Option<&'a str> {
+    let captures = regex.captures(tag)?;
+    let value = (1..=3).find_map(|group| captures.get(group))?.as_str().trim();
+    (!value.is_empty()).then_some(value)
+}
+
+/// Insert image alt text and `aria-label`s of interactive elements as text.
+///
+/// Each `<img>` with alt text is followed by `[alt: ...]` when `include_image_alt` is set;
+/// callers leave it unset for Markdown and Djot output, whose image syntax already keeps
+/// the alt text. Links, buttons, form controls and elements with an interactive `role`
+/// that carry an `aria-label` get `[aria-label: ...]` after their opening tag, unless
+/// their text is already the label.
+pub fn inline_accessibility_text(html: &str, include_image_alt: bool) -> Cow<'_, str> {
+    let lower = html.to_ascii_lowercase();
+    let bytes = lower.as_bytes();
+    let mut insertions: Vec<(usize, String)> = Vec::new();
+    let mut position = 0;
+
+    while let Some(offset) = lower[position..].find('<') {
+        let start = position + offset;
+        if lower[start..].starts_with("<!--") {
+            position = lower[start..].find("-->").map_or(lower.len(), |end| start + end + 3);
+            continue;
+        }
+        if bytes.get(start + 1) == Some(&b'/') {
+            position = start + 2;
+            continue;
+        }
+
+        let name_start = start + 1;
+        let name_end = lower[name_start..]
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .map_or(lower.len(), |end| name_start + end);
+        let name = &lower[name_start..name_end];
+        if name.is_empty() {
+            position = start + 1;
+            continue;
+        }
+
+        let Some(end) = find_tag_end(bytes, name_end) else {
+            break;
+        };
+        position = end;
+        let tag = &html[name_end..end];
+
+        if name == "img" {
+            if include_image_alt && let Some(alt) = attribute_value(&ALT_ATTRIBUTE, tag) {
+                insertions.push((end, format!(" [alt: {}] ", escape_text(alt))));
+            }
+        } else if (INTERACTIVE_TAGS.contains(&name) || INTERACTIVE_ROLE.is_match(tag))
+            && let Some(label) = attribute_value(&ARIA_LABEL_ATTRIBUTE, tag)
+        {
+            let text_end = lower[end..].find('<').map_or(lower.len(), |offset| end + offset);
+            if !html[end..text_end].trim().eq_ignore_ascii_case(label) {
+                insertions.push((end, format!("[aria-label: {}] ", escape_text(label))));
+            }
+        }
+
+        if RAW_TEXT_TAGS.contains(&name) {
+            let close = format!("</{}", name);
+            position = lower[end..].find(&close).map_or(lower.len(), |offset| end + offset);
+        }
+    }
+
+    if insertions.is_empty() {
+        return Cow::Borrowed(html);
+    }
+
+    let mut output = String::with_capacity(html.len() + insertions.iter().map(|(_, text)| text.len()).sum::<usize>());
+    let mut copied = 0;
+    for (at, text) in insertions {
+        output.push_str(&html[copied..at]);
+        output.push_str(&text);
+        copied = at;
+    }
+    output.push_str(&html[copied..]);
+    Cow::Owned(output)
+}
+
+/// Attribute values keep their entities; only the characters that would open or close
+/// a tag need escaping when the value becomes text.
+fn escape_text(value: &str) -> String {
+    value.replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_alt_text_is_inlined() {
+        let html = r#"<p>Logo: <img src="logo.png" alt="Acme Corp"> and <img src="spacer.gif" alt=""></p>"#;
+
+        assert_eq!(
+            inline_accessibility_text(html, true),
+            r#"<p>Logo: <img src="logo.png" alt="Acme Corp"> [alt: Acme Corp]  and <img src="spacer.gif" alt=""></p>"#
+        );
+        assert!(matches!(inline_accessibility_text(html, false), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_aria_labels_of_interactive_elements_are_inlined() {
+        let html = concat!(
+            r#"<button aria-label="Close dialog">&times;</button>"#,
+            r#"<a href="/" aria-label='Home'>Home</a>"#,
+            r#"<div role="button" aria-label="Open menu"></div>"#,
+            r#"<section aria-label="Sidebar">text</section>"#,
+        );
+
+        assert_eq!(
+            inline_accessibility_text(html, true),
+            concat!(
+                r#"<button aria-label="Close dialog">[aria-label: Close dialog] &times;</button>"#,
+                r#"<a href="/" aria-label='Home'>Home</a>"#,
+                r#"<div role="button" aria-label="Open menu">[aria-label: Open menu] </div>"#,
+                r#"<section aria-label="Sidebar">text</section>"#,
+            )
+        );
+    }
+
+    #[test]
+    fn test_attributes_in_scripts_and_data_attributes_are_ignored() {
+        let html = r#"<script>let img = '<img alt="x">';</script><img data-alt="x" src="a.png">"#;
+        assert!(matches!(inline_accessibility_text(html, true), Cow::Borrowed(_)));
+    }
+}
@@ -2,6 +2,7 @@
 
 use bytes::Bytes;
 
+use super::layout_tables::{RAW_TEXT_TAGS, find_tag_end};
 use super::types::ExtractedInlineImage;
 use html_to_markdown_rs::{InlineImage, InlineImageFormat};
 
@@ -69,6 +70,116 @@ pub fn inline_image_to_extracted(image: InlineImage) -> ExtractedInlineImage {
     }
 }
 
+/// Collect the markup of the images inline image extraction decodes: `<img>` tags with a
+/// `data:` source and inline `<svg>` elements.
+///
+/// Decoding images takes a conversion pass of its own. Running it over this markup instead
+/// of the whole document keeps HTML extraction from converting the document twice. Returns
+/// `None` when the document has no inline images.
+pub fn inline_image_markup(html: &str) -> Option<String> {
+    let lower = html.to_ascii_lowercase();
+    let bytes = lower.as_bytes();
+    let mut markup = String::new();
+    let mut position = 0;
+
+    while let Some(offset) = lower[position..].find('<') {
+        let start = position + offset;
+        if lower[start..].starts_with("<!--") {
+            position = lower[start..].find("-->").map_or(lower.len(), |end| start + end + 3);
+            continue;
+        }
+        if bytes.get(start + 1) == Some(&b'/') {
+            position = start + 2;
+            continue;
+        }
+
+        let name_start = start + 1;
+        let name_end = lower[name_start..]
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .map_or(lower.len(), |end| name_start + end);
+        let name = &lower[name_start..name_end];
+        if name.is_empty() {
+            position = start + 1;
+            continue;
+        }
+
+        let Some(end) = find_tag_end(bytes, name_end) else {
+            break;
+        };
+        position = end;
+
+        match name {
+            "img" if has_data_uri_source(&lower[name_end..end]) => {
+                markup.push_str(&html[start..end]);
+                markup.push('\n');
+            }
+            "svg" => {
+                if !lower[..end].ends_with("/>") {
+                    position = svg_element_end(&lower, end);
+                }
+                markup.push_str(&html[start..position]);
+                markup.push('\n');
+            }
+            _ if RAW_TEXT_TAGS.contains(&name) => {
+                let close = format!("</{}", name);
+                position = lower[end..].find(&close).map_or(lower.len(), |offset| end + offset);
+            }
+            _ => {}
+        }
+    }
+
+    (!markup.is_empty()).then(|| format!("<html><body>\n{}</body></html>", markup))
+}
+
+/// Whether the attributes of a lowercased `<img>` tag have a `src` starting with `data:`.
+fn has_data_uri_source(attributes: &str) -> bool {
+    let mut rest = attributes;
+    while let Some(offset) = rest.find("src") {
+        let preceded_by_space = rest[..offset].ends_with(|c: char| c.is_ascii_whitespace());
+        rest = &rest[offset + 3..];
+        let Some(value) = rest.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start().trim_start_matches(['"', '\'']).trim_start();
+        if preceded_by_space && value.starts_with("data:") {
+            return true;
+        }
+    }
+    false
+}
+
+/// Offset just past the `</svg>` that closes the element whose opening tag ends at `from`.
+fn svg_element_end(lower: &str, from: usize) -> usize {
+    let bytes = lower.as_bytes();
+    let mut depth = 1usize;
+    let mut position = from;
+
+    while let Some(offset) = lower[position..].find("svg") {
+        let at = position + offset;
+        position = at + 3;
+        if bytes.get(position).is_some_and(|byte| byte.is_ascii_alphanumeric()) {
+            continue;
+        }
+        let closing = lower[..at].ends_with("</");
+        if !closing && !lower[..at].ends_with('<') {
+            continue;
+        }
+        let Some(end) = find_tag_end(bytes, position) else {
+            break;
+        };
+        if closing {
+            depth -= 1;
+            if depth == 0 {
+                return end;
+            }
+        } else if !lower[..end].ends_with("/>") {
+            depth += 1;
+        }
+    }
+
+    lower.len()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,6 +203,32 @@ mod tests {
         assert_eq!(inline_image_format_to_str(&format), "bin");
     }
 
+    #[test]
+    fn test_inline_image_markup_keeps_only_inline_images() {
+        let html = concat!(
+            "<html><body><h1>Report</h1>",
+            r#"<p><img src="chart.png" alt="linked"><img alt="inline" src="data:image/png;base64,AAAA"></p>"#,
+            r#"<script>var s = '<svg></svg>';</script>"#,
+            r#"<svg width="10"><g><svg/></g><text>label</text></svg><p>tail</p>"#,
+            "</body></html>",
+        );
+
+        assert_eq!(
+            inline_image_markup(html).unwrap(),
+            concat!(
+                "<html><body>\n",
+                r#"<img alt="inline" src="data:image/png;base64,AAAA">"#,
+                "\n",
+                r#"<svg width="10"><g><svg/></g><text>label</text></svg>"#,
+                "\n</body></html>",
+            )
+        );
+        assert_eq!(
+            inline_image_markup(r#"<p><img src="chart.png" data-src="data:x"></p>"#),
+            None
+        );
+    }
+
     #[test]
     fn test_inline_image_format_other_x_prefix() {
         let format = InlineImageFormat::Other("x-custom".to_string());
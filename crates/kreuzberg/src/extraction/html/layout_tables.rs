@@ -0,0 +1,376 @@
+//! Layout table detection.
+//!
+//! Many scraped pages use `<table>` to position content rather than to present data.
+//! Converted as-is, such pages become Markdown tables holding navigation bars and whole
+//! paragraphs. [`unwrap_layout_tables`] rewrites the HTML before conversion so layout
+//! tables become plain blocks and only data tables are rendered as tables.
+//! [`first_row_headers`] reports which tables start with a header row.
+
+use std::borrow::Cow;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static PRESENTATION_ROLE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)\brole\s*=\s*["']?\s*(presentation|none)\b"#).expect("valid regex"));
+
+/// Table elements rewritten when their table is unwrapped.
+const TABLE_TAGS: &[&str] = &[
+    "table", "caption", "colgroup", "col", "thead", "tbody", "tfoot", "tr", "th", "td",
+];
+
+/// Elements whose content is raw text and cannot contain tags.
+pub(super) const RAW_TEXT_TAGS: &[&str] = &["script", "style", "textarea"];
+
+struct Tag {
+    start: usize,
+    end: usize,
+    name: &'static str,
+    closing: bool,
+}
+
+#[derive(Default)]
+struct TableInfo {
+    parent: Option<usize>,
+    tags: Vec<usize>,
+    presentation: bool,
+    has_header: bool,
+    first_row_header: bool,
+    rows: usize,
+    max_columns: usize,
+    row_cells: usize,
+}
+
+impl TableInfo {
+    fn end_row(&mut self) {
+        self.max_columns = self.max_columns.max(self.row_cells);
+        self.row_cells = 0;
+    }
+
+    fn is_layout(&self) -> bool {
+        self.presentation || !self.has_header || self.max_columns <= 1
+    }
+}
+
+/// Rewrite layout tables as plain blocks and flatten nested data tables.
+///
+/// A table is a layout table when it has `role="presentation"` (or `role="none"`), has no
+/// `<th>` cell, or has at most one cell per row. Its rows and cells become `<div>`s, so
+/// its content is kept as ordinary text and any data table inside it is converted as a
+/// top-level table. A data table nested inside another data table is flattened into the
+/// text of the enclosing cell.
+pub fn unwrap_layout_tables(html: &str) -> Cow<'_, str> {
+    let tags = scan_table_tags(html);
+    if tags.is_empty() {
+        return Cow::Borrowed(html);
+    }
+
+    let tables = collect_tables(html, &tags);
+    let mut replacements: Vec<Option<&'static str>> = vec![None; tags.len()];
+    for table in &tables {
+        let inside_data_table = std::iter::successors(table.parent, |&parent| tables[parent].parent)
+            .any(|ancestor| !tables[ancestor].is_layout());
+        for &tag_index in &table.tags {
+            let tag = &tags[tag_index];
+            replacements[tag_index] = if inside_data_table && !table.is_layout() {
+                Some(" ")
+            } else if table.is_layout() {
+                Some(match (tag.name, tag.closing) {
+                    ("thead" | "tbody" | "tfoot" | "colgroup" | "col", _) => "",
+                    (_, false) => "<div>",
+                    (_, true) => "</div>",
+                })
+            } else {
+                None
+            };
+        }
+    }
+
+    if replacements.iter().all(Option::is_none) {
+        return Cow::Borrowed(html);
+    }
+
+    let mut output = String::with_capacity(html.len());
+    let mut position = 0;
+    for (tag, replacement) in tags.iter().zip(replacements) {
+        if let Some(replacement) = replacement {
+            output.push_str(&html[position..tag.start]);
+            output.push_str(replacement);
+            position = tag.end;
+        }
+    }
+    output.push_str(&html[position..]);
+    Cow::Owned(output)
+}
+
+/// Whether each table starts with a header row, in document order (nested tables included).
+///
+/// A first row is a header row when it is inside `<thead>`, has a `<th>` cell, or all of
+/// its text is bold (`<b>` or `<strong>`) while some text in later rows is not.
+pub fn first_row_headers(html: &str) -> Vec<bool> {
+    let tags = scan_table_tags(html);
+    collect_tables(html, &tags)
+        .iter()
+        .map(|table| table.first_row_header || first_row_is_bold(html, &tags, table))
+        .collect()
+}
+
+/// Whether all text in the first row of a table is bold while some text in later rows is not.
+fn first_row_is_bold(html: &str, tags: &[Tag], table: &TableInfo) -> bool {
+    let mut rows = 0;
+    let mut first_row_bold = None;
+    let mut later_row_plain = false;
+
+    for (position, &tag_index) in table.tags.iter().enumerate() {
+        let tag = &tags[tag_index];
+        match (tag.name, tag.closing) {
+            ("tr", false) => rows += 1,
+            ("td", false) => {
+                let end = table
+                    .tags
+                    .get(position + 1)
+                    .map_or(html.len(), |&next| tags[next].start);
+                match (text_is_bold(&html[tag.end..end]), rows <= 1) {
+                    (None, _) => {}
+                    (Some(bold), true) => first_row_bold = Some(first_row_bold.unwrap_or(true) && bold),
+                    (Some(bold), false) => later_row_plain |= !bold,
+                }
+            }
+            _ => {}
+        }
+    }
+
+    first_row_bold == Some(true) && later_row_plain
+}
+
+/// Whether all text in an HTML fragment is inside `<b>` or `<strong>`, or `None` if it has no text.
+fn text_is_bold(fragment: &str) -> Option<bool> {
+    let mut depth = 0usize;
+    let mut has_text = false;
+    let mut rest = fragment;
+
+    while !rest.is_empty() {
+        if rest.starts_with('<') {
+            let end = find_tag_end(rest.as_bytes(), 1).unwrap_or(rest.len());
+            let tag = &rest[1..end];
+            let closing = tag.starts_with('/');
+            let name = tag
+                .trim_start_matches('/')
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .next()
+                .unwrap_or_default();
+            if name.eq_ignore_ascii_case("b") || name.eq_ignore_ascii_case("strong") {
+                depth = if closing { depth.saturating_sub(1) } else { depth + 1 };
+            }
+            rest = &rest[end..];
+            continue;
+        }
+
+        let text_end = rest.find('<').unwrap_or(rest.len());
+        if !rest[..text_end].trim().is_empty() {
+            if depth == 0 {
+                return Some(false);
+            }
+            has_text = true;
+        }
+        rest = &rest[text_end..];
+    }
+
+    has_text.then_some(true)
+}
+
+/// Group table tags by the table they belong to, in document order.
+fn collect_tables(html: &str, tags: &[Tag]) -> Vec<TableInfo> {
+    let mut tables: Vec<TableInfo> = Vec::new();
+    let mut open: Vec<usize> = Vec::new();
+
+    for (tag_index, tag) in tags.iter().enumerate() {
+        match (tag.name, tag.closing) {
+            ("table", false) => {
+                tables.push(TableInfo {
+                    parent: open.last().copied(),
+                    tags: vec![tag_index],
+                    presentation: PRESENTATION_ROLE.is_match(&html[tag.start..tag.end]),
+                    ..Default::default()
+                });
+                open.push(tables.len() - 1);
+            }
+            ("table", true) => {
+                if let Some(table) = open.pop() {
+                    tables[table].tags.push(tag_index);
+                    tables[table].end_row();
+                }
+            }
+            (name, closing) => {
+                let Some(&table) = open.last() else {
+                    continue;
+                };
+                let table = &mut tables[table];
+                table.tags.push(tag_index);
+                match (name, closing) {
+                    ("tr", false) => {
+                        table.end_row();
+                        table.rows += 1;
+                    }
+                    ("td", false) => table.row_cells += 1,
+                    ("th", false) => {
+                        table.row_cells += 1;
+                        table.has_header = true;
+                        table.first_row_header |= table.rows <= 1;
+                    }
+                    ("thead", false) => table.first_row_header |= table.rows == 0,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    for table in open {
+        tables[table].end_row();
+    }
+    tables
+}
+
+/// Find every table-related tag, skipping comments and the content of raw text elements.
+fn scan_table_tags(html: &str) -> Vec<Tag> {
+    let lower = html.to_ascii_lowercase();
+    let bytes = lower.as_bytes();
+    let mut tags = Vec::new();
+    let mut position = 0;
+
+    while let Some(offset) = lower[position..].find('<') {
+        let start = position + offset;
+        if lower[start..].starts_with("<!--") {
+            position = lower[start..].find("-->").map_or(lower.len(), |end| start + end + 3);
+            continue;
+        }
+
+        let closing = bytes.get(start + 1) == Some(&b'/');
+        let name_start = start + 1 + usize::from(closing);
+        let name_end = lower[name_start..]
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .map_or(lower.len(), |end| name_start + end);
+        let name = &lower[name_start..name_end];
+        if name.is_empty() {
+            position = start + 1;
+            continue;
+        }
+
+        let Some(end) = find_tag_end(bytes, name_end) else {
+            break;
+        };
+        position = end;
+
+        if !closing && RAW_TEXT_TAGS.contains(&name) {
+            let close = format!("</{}", name);
+            position = lower[end..].find(&close).map_or(lower.len(), |offset| end + offset);
+        } else if let Some(&name) = TABLE_TAGS.iter().find(|&&tag| tag == name) {
+            tags.push(Tag {
+                start,
+                end,
+                name,
+                closing,
+            });
+        }
+    }
+
+    tags
+}
+
+/// Byte offset just past the `>` closing a tag, ignoring `>` inside quoted attribute values.
+pub(super) fn find_tag_end(bytes: &[u8], from: usize) -> Option<usize> {
+    let mut quote = None;
+    for (index, &byte) in bytes.iter().enumerate().skip(from) {
+        match (quote, byte) {
+            (None, b'"' | b'\'') => quote = Some(byte),
+            (Some(open), _) if byte == open => quote = None,
+            (None, b'>') => return Some(index + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA_TABLE: &str = "<table><tr><th>Name</th><th>Age</th></tr><tr><td>Alice</td><td>30</td></tr></table>";
+
+    #[test]
+    fn test_data_table_is_kept() {
+        assert!(matches!(unwrap_layout_tables(DATA_TABLE), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_layout_table_is_unwrapped_around_data_table() {
+        let html = format!(
+            r#"<table role="presentation"><tr><td><a href="/">Home</a></td><td>{}</td></tr></table>"#,
+            DATA_TABLE
+        );
+
+        assert_eq!(
+            unwrap_layout_tables(&html),
+            format!(
+                r#"<div><div><div><a href="/">Home</a></div><div>{}</div></div></div>"#,
+                DATA_TABLE
+            )
+        );
+    }
+
+    #[test]
+    fn test_tables_without_headers_or_columns_are_layout() {
+        let no_header = "<table><tbody><tr><td>a</td><td>b</td></tr></tbody></table>";
+        let single_column = "<TABLE><TR><TH>Menu</TH></TR><TR><TD>Item</TD></TR></TABLE>";
+
+        assert_eq!(
+            unwrap_layout_tables(no_header),
+            "<div><div><div>a</div><div>b</div></div></div>"
+        );
+        assert_eq!(
+            unwrap_layout_tables(single_column),
+            "<div><div><div>Menu</div></div><div><div>Item</div></div></div>"
+        );
+    }
+
+    #[test]
+    fn test_data_table_nested_in_data_table_is_flattened() {
+        let html = format!(
+            "<table><tr><th>Item</th><th>Detail</th></tr><tr><td>A</td><td>{}</td></tr></table>",
+            DATA_TABLE
+        );
+
+        let rewritten = unwrap_layout_tables(&html);
+        assert!(rewritten.starts_with("<table><tr><th>Item</th>"));
+        assert!(rewritten.contains("<td>   Name  Age    Alice  30   </td>"));
+    }
+
+    #[test]
+    fn test_first_row_headers() {
+        let html = format!(
+            "{}<table><tr><td>a</td></tr><tr><th>b</th></tr></table>\
+             <table><thead><tr><td>c</td></tr></thead><tbody><tr><td>d</td></tr></tbody></table>",
+            DATA_TABLE
+        );
+
+        assert_eq!(first_row_headers(&html), vec![true, false, true]);
+    }
+
+    #[test]
+    fn test_bold_first_row_is_header() {
+        let bold = "<table><tr><td><b>Name</b></td><td><STRONG>Age</STRONG></td></tr>\
+                    <tr><td>Alice</td><td>30</td></tr></table>";
+        let all_bold = "<table><tr><td><b>Name</b></td></tr><tr><td><b>Alice</b></td></tr></table>";
+        let partly_bold = "<table><tr><td><b>Name</b> (full)</td></tr><tr><td>Alice</td></tr></table>";
+
+        assert_eq!(first_row_headers(bold), vec![true]);
+        assert_eq!(first_row_headers(all_bold), vec![false]);
+        assert_eq!(first_row_headers(partly_bold), vec![false]);
+    }
+
+    #[test]
+    fn test_tags_in_comments_and_scripts_are_ignored() {
+        let html = r#"<!-- <table> --><script>let t = "<table>";</script><p>text</p>"#;
+        assert!(matches!(unwrap_layout_tables(html), Cow::Borrowed(_)));
+    }
+}
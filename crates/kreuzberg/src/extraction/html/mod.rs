@@ -10,6 +10,8 @@
 //! - **YAML frontmatter**: Parse YAML metadata from Markdown output
 //! - **Customizable conversion**: Full access to `html-to-markdown-rs` options
 //! - **Output format selection**: Choose between Markdown and Djot formats
+//! - **Layout table detection**: Render layout tables as plain blocks instead of Markdown tables
+//! - **Accessibility text**: Keep image alt text and `aria-label`s in the output
 //!
 //! # Example
 //!
@@ -33,16 +35,24 @@
 //! # }
 //! ```
 
+mod accessibility;
 mod converter;
 mod image_handling;
+mod layout_tables;
 mod processor;
+mod source_text;
 mod stack_management;
 mod types;
 
 // Public API re-exports
+pub use accessibility::inline_accessibility_text;
 pub use converter::convert_html_to_markdown;
 pub use converter::convert_html_to_markdown_with_metadata;
+pub(crate) use image_handling::inline_image_markup;
+pub use layout_tables::{first_row_headers, unwrap_layout_tables};
 pub use processor::process_html;
+pub(crate) use source_text::html_source_text;
+pub use stack_management::check_nesting_depth;
 pub use types::{
     CodeBlockStyle, HeadingStyle, HighlightStyle, ListIndentType, NewlineStyle, PreprocessingOptions,
     PreprocessingPreset, WhitespaceMode,
@@ -0,0 +1,159 @@
+//! Text nodes of an HTML document with their byte offsets, for source maps.
+//!
+//! A lightweight scan in the spirit of [`super::layout_tables`]: tags and comments are
+//! skipped, the text between them is kept with its position. Only the common character
+//! references are decoded, which is enough to locate the text in the converted output.
+
+use super::layout_tables::{RAW_TEXT_TAGS, find_tag_end};
+use crate::types::source_map::{SourceText, SourceUnit};
+
+/// Elements whose text is not part of the converted output, besides [`RAW_TEXT_TAGS`].
+const NON_CONTENT_TAGS: &[&str] = &["title"];
+
+/// Collect the text nodes of `html` in document order.
+///
+/// Each node is trimmed, and its byte range in `html` excludes the surrounding
+/// whitespace. Comments and the content of raw text elements and `<title>` are skipped.
+pub(crate) fn html_source_text(html: &str) -> Vec<SourceText> {
+    let lower = html.to_ascii_lowercase();
+    let bytes = lower.as_bytes();
+    let mut texts = Vec::new();
+    let mut text_start = 0;
+    let mut position = 0;
+
+    while let Some(offset) = lower[position..].find('<') {
+        let start = position + offset;
+        let end = if lower[start..].starts_with("<!--") {
+            lower[start..].find("-->").map_or(lower.len(), |end| start + end + 3)
+        } else {
+            let closing = bytes.get(start + 1) == Some(&b'/');
+            let name_start = start + 1 + usize::from(closing);
+            let name_end = lower[name_start..]
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .map_or(lower.len(), |end| name_start + end);
+            let name = &lower[name_start..name_end];
+            // A `<` that does not open a tag or declaration is part of the text.
+            if name.is_empty() && !matches!(bytes.get(name_start), Some(b'!' | b'?')) {
+                position = start + 1;
+                continue;
+            }
+
+            let Some(end) = find_tag_end(bytes, name_end) else {
+                break;
+            };
+            if !closing && (RAW_TEXT_TAGS.contains(&name) || NON_CONTENT_TAGS.contains(&name)) {
+                let close = format!("</{}", name);
+                lower[end..].find(&close).map_or(lower.len(), |offset| end + offset)
+            } else {
+                end
+            }
+        };
+
+        push_text(html, text_start, start, &mut texts);
+        text_start = end;
+        position = end;
+    }
+
+    // An unterminated tag ends the scan; whatever follows it is not text.
+    if let Some(offset) = lower[position..].find('<') {
+        push_text(html, text_start, position + offset, &mut texts);
+    } else {
+        push_text(html, text_start, html.len(), &mut texts);
+    }
+
+    texts
+}
+
+fn push_text(html: &str, start: usize, end: usize, texts: &mut Vec<SourceText>) {
+    let raw = &html[start..end];
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return;
+    }
+
+    let source_start = start + (raw.len() - raw.trim_start().len());
+    texts.push(SourceText {
+        text: decode_character_references(trimmed),
+        page: None,
+        source_start,
+        source_end: source_start + trimmed.len(),
+        unit: SourceUnit::Byte,
+    });
+}
+
+/// Decode numeric and the most common named character references.
+fn decode_character_references(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let reference = rest[1..].find(';').filter(|&end| end <= 10).map(|end| &rest[1..=end]);
+        match reference.and_then(decode_reference) {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[reference.map_or(0, str::len) + 2..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_reference(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => number.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_source_text_keeps_offsets_of_text_nodes() {
+        let html = "<!DOCTYPE html><html><head><title>Ignored</title><style>p { x: 1 }</style></head>\
+                    <body><!-- note --><p>  Fish &amp; chips </p><p>1 < 2</p></body></html>";
+        let texts = html_source_text(html);
+
+        let decoded: Vec<&str> = texts.iter().map(|text| text.text.as_str()).collect();
+        assert_eq!(decoded, vec!["Fish & chips", "1 < 2"]);
+        assert_eq!(&html[texts[0].source_start..texts[0].source_end], "Fish &amp; chips");
+        assert_eq!(&html[texts[1].source_start..texts[1].source_end], "1 < 2");
+        assert!(
+            texts
+                .iter()
+                .all(|text| text.unit == SourceUnit::Byte && text.page.is_none())
+        );
+    }
+
+    #[test]
+    fn test_decode_character_references() {
+        assert_eq!(
+            decode_character_references("a &lt;b&gt; &#65;&#x42; &unknown; &"),
+            "a <b> AB &unknown; &"
+        );
+    }
+}
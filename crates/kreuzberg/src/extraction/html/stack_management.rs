@@ -4,7 +4,10 @@
 //! particularly for large HTML documents that may require more stack space than the default.
 //! On WASM, stack size is limited and cannot be increased, so size limits are enforced.
 //! On native platforms, dedicated threads with larger stacks are used for large HTML.
+//! Independently of size, documents nesting elements deeper than the configured limit
+//! are rejected before conversion.
 
+use super::layout_tables::{RAW_TEXT_TAGS, find_tag_end};
 use crate::error::{KreuzbergError, Result};
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -45,6 +48,100 @@ pub fn check_wasm_size_limit(_html: &str) -> Result<()> {
     Ok(())
 }
 
+/// Elements that have no content and no closing tag.
+const VOID_TAGS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr",
+];
+
+/// For elements whose closing tag is commonly omitted, the open elements that opening
+/// one implicitly closes, and the elements that bound the search for them.
+///
+/// Everything opened after the closed element is closed with it, so `<li><p>a<li>` and
+/// `<tr><td>a<tr>` return to the depth of the first `li` and `tr`.
+fn implied_end(name: &str) -> Option<(&'static [&'static str], &'static [&'static str])> {
+    match name {
+        "p" => Some((&["p"], &["button", "caption", "table", "td", "th"])),
+        "li" => Some((&["li"], &["ol", "ul"])),
+        "dt" | "dd" => Some((&["dt", "dd"], &["dl"])),
+        "option" => Some((&["option"], &["datalist", "optgroup", "select"])),
+        "tr" => Some((&["tr"], &["table", "tbody", "tfoot", "thead"])),
+        "td" | "th" => Some((&["td", "th"], &["table", "tr"])),
+        _ => None,
+    }
+}
+
+/// Reject HTML whose elements nest deeper than `max_depth`.
+///
+/// HTML conversion walks the element tree recursively, so pathologically nested input
+/// can exhaust the stack whatever its size. Depth is estimated from the tags alone:
+/// void elements and self-closing tags do not nest, a closing tag also closes the
+/// unclosed elements inside it, and an opening `p`, `li`, `td` (and similar) closes an
+/// open element of the same kind in its scope along with everything inside it.
+pub fn check_nesting_depth(html: &str, max_depth: usize) -> Result<()> {
+    let lower = html.to_ascii_lowercase();
+    let bytes = lower.as_bytes();
+    let mut open: Vec<&str> = Vec::new();
+    let mut position = 0;
+
+    while let Some(offset) = lower[position..].find('<') {
+        let start = position + offset;
+        if lower[start..].starts_with("<!--") {
+            position = lower[start..].find("-->").map_or(lower.len(), |end| start + end + 3);
+            continue;
+        }
+
+        let closing = bytes.get(start + 1) == Some(&b'/');
+        let name_start = start + 1 + usize::from(closing);
+        let name_end = lower[name_start..]
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .map_or(lower.len(), |end| name_start + end);
+        let name = &lower[name_start..name_end];
+        if name.is_empty() {
+            position = start + 1;
+            continue;
+        }
+
+        let Some(end) = find_tag_end(bytes, name_end) else {
+            break;
+        };
+        position = end;
+
+        if closing {
+            if let Some(index) = open.iter().rposition(|&tag| tag == name) {
+                open.truncate(index);
+            }
+            continue;
+        }
+        if VOID_TAGS.contains(&name) || bytes[end - 2] == b'/' {
+            continue;
+        }
+
+        if let Some((closes, scope)) = implied_end(name) {
+            let scope_start = open
+                .iter()
+                .rposition(|tag| scope.contains(tag))
+                .map_or(0, |index| index + 1);
+            if let Some(index) = open[scope_start..].iter().rposition(|tag| closes.contains(tag)) {
+                open.truncate(scope_start + index);
+            }
+        }
+        open.push(name);
+        if open.len() > max_depth {
+            return Err(KreuzbergError::validation(format!(
+                "HTML nesting too deep: more than {} levels of elements (security_limits.max_nesting_depth)",
+                max_depth
+            )));
+        }
+
+        if RAW_TEXT_TAGS.contains(&name) {
+            let close = format!("</{}", name);
+            position = lower[end..].find(&close).map_or(lower.len(), |offset| end + offset);
+        }
+    }
+
+    Ok(())
+}
+
 /// Determine if HTML requires a dedicated stack due to size.
 ///
 /// On native platforms, HTML larger than the threshold will be processed
@@ -101,3 +198,43 @@ fn extract_panic_reason(panic: &Box<dyn Any + Send + 'static>) -> String {
         "unknown panic".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_nesting_depth_counts_open_elements() {
+        let html = format!("{}text{}", "<div>".repeat(10), "</div>".repeat(10));
+        assert!(check_nesting_depth(&html, 10).is_ok());
+        let err = check_nesting_depth(&html, 9).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+    }
+
+    #[test]
+    fn test_check_nesting_depth_ignores_void_and_implicitly_closed_elements() {
+        let paragraphs = "<p>one<br><img src=x.png><p>two<span/><p>three".repeat(50);
+        let html = format!("<html><body><ul><li>a<li>b<li>c</ul>{}</body></html>", paragraphs);
+        assert!(check_nesting_depth(&html, 4).is_ok());
+
+        let unclosed = "<div><span>text</div>".repeat(50);
+        assert!(check_nesting_depth(&unclosed, 2).is_ok());
+    }
+
+    #[test]
+    fn test_check_nesting_depth_closes_omitted_end_tags_in_scope() {
+        let rows = "<tr><td>a<td><b>b</b><th>c".repeat(50);
+        let table = format!("<table>{}</table>", rows);
+        assert!(check_nesting_depth(&table, 4).is_ok());
+
+        let items = format!("<ul>{}</ul>", "<li><p>text".repeat(50));
+        assert!(check_nesting_depth(&items, 3).is_ok());
+
+        let terms = format!("<dl>{}</dl>", "<dt><span>term</span><dd><p>definition".repeat(50));
+        assert!(check_nesting_depth(&terms, 3).is_ok());
+
+        // A nested list is a new scope, so its items do not close the outer one.
+        let nested = "<ul><li>outer<ul><li>inner</ul></ul>";
+        assert!(check_nesting_depth(nested, 3).is_err());
+    }
+}
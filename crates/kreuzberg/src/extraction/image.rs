@@ -5,7 +5,6 @@
 
 use crate::error::{KreuzbergError, Result};
 use exif::{In, Reader, Tag};
-use image::ImageReader;
 use std::collections::HashMap;
 use std::io::Cursor;
 
@@ -323,7 +322,19 @@ pub(crate) fn decode_jbig2_to_gray(bytes: &[u8]) -> Result<image::GrayImage> {
 /// Extracts dimensions, format, and EXIF data from the image.
 /// Attempts to decode using the standard image crate first, then falls back to
 /// pure Rust JP2 box parsing for JPEG 2000 formats if the standard decoder fails.
+/// Images wider or taller than the default `max_decode_dimension` are refused.
 pub fn extract_image_metadata(bytes: &[u8]) -> Result<ImageMetadata> {
+    extract_image_metadata_with_limits(bytes, crate::core::config::extraction::DEFAULT_MAX_DECODE_DIMENSION)
+}
+
+/// Extract metadata from image bytes, refusing images wider or taller than
+/// `max_dimension` pixels (0 = no limit).
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if the image exceeds the limit, and
+/// `KreuzbergError::Parsing` if it cannot be decoded.
+pub fn extract_image_metadata_with_limits(bytes: &[u8], max_dimension: u32) -> Result<ImageMetadata> {
     // Check for JP2/J2K before attempting standard format detection
     if is_jp2(bytes) || (bytes.len() >= 2 && bytes[0] == 0xFF && bytes[1] == 0x4F) {
         // Try the fallback JP2 parser first for JPEG 2000 files
@@ -332,33 +343,20 @@ pub fn extract_image_metadata(bytes: &[u8]) -> Result<ImageMetadata> {
         }
     }
 
-    let reader = ImageReader::new(Cursor::new(bytes))
-        .with_guessed_format()
-        .map_err(|e| KreuzbergError::parsing(format!("Failed to read image format: {}", e)))?;
-
-    let format = reader
-        .format()
-        .ok_or_else(|| KreuzbergError::parsing("Could not determine image format".to_string()))?;
+    let format = image::guess_format(bytes)
+        .map_err(|e| KreuzbergError::parsing(format!("Could not determine image format: {}", e)))?;
 
-    match reader.decode() {
-        Ok(image) => {
-            let width = image.width();
-            let height = image.height();
-            let format_str = format!("{:?}", format).to_uppercase();
-            let exif_data = extract_exif_data(bytes);
+    let image = crate::extraction::image_header::decode_image(bytes, max_dimension).map_err(|e| match e {
+        KreuzbergError::ImageProcessing { message, .. } => KreuzbergError::parsing(message),
+        e => e,
+    })?;
 
-            Ok(ImageMetadata {
-                width,
-                height,
-                format: format_str,
-                exif_data,
-            })
-        }
-        Err(decode_err) => Err(KreuzbergError::parsing(format!(
-            "Failed to decode image: {}",
-            decode_err
-        ))),
-    }
+    Ok(ImageMetadata {
+        width: image.width(),
+        height: image.height(),
+        format: format!("{:?}", format).to_uppercase(),
+        exif_data: extract_exif_data(bytes),
+    })
 }
 
 /// Extract EXIF data from image bytes.
@@ -0,0 +1,177 @@
+//! Image dimension sniffing from file headers.
+//!
+//! Embedded images in office documents and HTML often come without size information.
+//! Reading the header is enough to filter out tracking pixels and icons without
+//! decoding the image.
+
+/// Read pixel dimensions from the header of PNG, GIF, BMP or JPEG data.
+pub fn detect_image_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let be_u16 = |at: usize| data.get(at..at + 2).map(|b| u16::from_be_bytes([b[0], b[1]]) as u32);
+    let le_u16 = |at: usize| data.get(at..at + 2).map(|b| u16::from_le_bytes([b[0], b[1]]) as u32);
+    let be_u32 = |at: usize| {
+        data.get(at..at + 4)
+            .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    };
+    let le_i32 = |at: usize| {
+        data.get(at..at + 4)
+            .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+
+    if data.starts_with(&[0x89, 0x50, 0x4E, 0x47]) {
+        if data.get(12..16)? != b"IHDR" {
+            return None;
+        }
+        return Some((be_u32(16)?, be_u32(20)?));
+    }
+    if data.starts_with(b"GIF") {
+        return Some((le_u16(6)?, le_u16(8)?));
+    }
+    if data.starts_with(b"BM") {
+        return Some((le_i32(18)?.unsigned_abs(), le_i32(22)?.unsigned_abs()));
+    }
+    if data.starts_with(&[0xFF, 0xD8]) {
+        let mut pos = 2;
+        while pos + 4 <= data.len() {
+            if data[pos] != 0xFF {
+                return None;
+            }
+            let marker = data[pos + 1];
+            if marker == 0xFF {
+                pos += 1;
+                continue;
+            }
+            if marker == 0x01 || (0xD0..=0xD8).contains(&marker) {
+                pos += 2;
+                continue;
+            }
+            // SOF markers carry the frame size; C4 (DHT), C8 (JPG) and CC (DAC) do not.
+            if (0xC0..=0xCF).contains(&marker) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+                return Some((be_u16(pos + 7)?, be_u16(pos + 5)?));
+            }
+            pos += 2 + be_u16(pos + 2)? as usize;
+        }
+    }
+    None
+}
+
+/// Reject images whose declared dimensions exceed `max_dimension` on either side.
+///
+/// Only the header is read, so a crafted image with huge declared dimensions is
+/// refused before a decoder allocates its pixel buffer. Images whose header cannot
+/// be read pass, and are left to the decoder.
+///
+/// # Errors
+///
+/// Returns [`KreuzbergError::Validation`](crate::KreuzbergError::Validation) naming the
+/// offending dimensions.
+pub fn check_image_dimensions(data: &[u8], max_dimension: u32) -> crate::Result<()> {
+    match detect_image_dimensions(data) {
+        Some((width, height)) if width > max_dimension || height > max_dimension => {
+            Err(crate::KreuzbergError::validation(format!(
+                "Image dimensions {}x{} exceed the maximum of {} pixels per side",
+                width, height, max_dimension
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Decode image data, refusing images wider or taller than `max_dimension` pixels.
+///
+/// The header is checked first with [`check_image_dimensions`]; the decoder then
+/// enforces the same limit for formats the header check does not know, along with its
+/// default allocation limit. A `max_dimension` of 0 disables the dimension limit.
+///
+/// # Errors
+///
+/// Returns [`KreuzbergError::Validation`](crate::KreuzbergError::Validation) if the
+/// image exceeds a limit, and
+/// [`KreuzbergError::ImageProcessing`](crate::KreuzbergError::ImageProcessing) if it
+/// cannot be decoded.
+#[cfg(any(feature = "pdf", feature = "ocr"))]
+pub fn decode_image(data: &[u8], max_dimension: u32) -> crate::Result<image::DynamicImage> {
+    let mut limits = image::Limits::default();
+    if max_dimension > 0 {
+        check_image_dimensions(data, max_dimension)?;
+        limits.max_image_width = Some(max_dimension);
+        limits.max_image_height = Some(max_dimension);
+    }
+
+    let mut reader = image::ImageReader::new(std::io::Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|e| {
+            crate::KreuzbergError::image_processing_with_source(format!("Failed to read image format: {}", e), e)
+        })?;
+    reader.limits(limits);
+    reader.decode().map_err(|e| match e {
+        image::ImageError::Limits(e) => {
+            crate::KreuzbergError::validation(format!("Image exceeds decoding limits: {}", e))
+        }
+        e => crate::KreuzbergError::image_processing_with_source(format!("Failed to decode image: {}", e), e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png_header(width: u32, height: u32) -> Vec<u8> {
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+        png.extend_from_slice(b"IHDR");
+        png.extend_from_slice(&width.to_be_bytes());
+        png.extend_from_slice(&height.to_be_bytes());
+        png
+    }
+
+    #[test]
+    fn test_detect_png_and_gif_dimensions() {
+        assert_eq!(detect_image_dimensions(&png_header(800, 200)), Some((800, 200)));
+        assert_eq!(detect_image_dimensions(b"GIF89a\x01\x00\x02\x00"), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_detect_jpeg_dimensions_after_app_segment() {
+        let jpeg = [
+            0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x00, 0x20, 0x00, 0x40,
+        ];
+        assert_eq!(detect_image_dimensions(&jpeg), Some((64, 32)));
+    }
+
+    #[test]
+    fn test_check_image_dimensions_rejects_oversized_header() {
+        let bomb = png_header(100_000, 100_000);
+        let err = check_image_dimensions(&bomb, 4096).unwrap_err();
+        assert!(matches!(err, crate::KreuzbergError::Validation { .. }));
+        assert!(err.to_string().contains("100000x100000"));
+
+        assert!(check_image_dimensions(&png_header(4096, 10), 4096).is_ok());
+        assert!(check_image_dimensions(b"not an image", 1).is_ok());
+    }
+
+    #[test]
+    #[cfg(any(feature = "pdf", feature = "ocr"))]
+    fn test_decode_image_enforces_limit() {
+        let mut png = Vec::new();
+        image::DynamicImage::new_rgb8(8, 2)
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+
+        assert_eq!(decode_image(&png, 8).unwrap().width(), 8);
+        assert_eq!(decode_image(&png, 0).unwrap().width(), 8);
+        assert!(matches!(
+            decode_image(&png, 4),
+            Err(crate::KreuzbergError::Validation { .. })
+        ));
+        assert!(matches!(
+            decode_image(b"not an image", 4),
+            Err(crate::KreuzbergError::ImageProcessing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_truncated_or_unknown_headers() {
+        assert_eq!(detect_image_dimensions(b"GIF89a"), None);
+        assert_eq!(detect_image_dimensions(&[0x89, b'P', b'N', b'G']), None);
+        assert_eq!(detect_image_dimensions(b"<svg></svg>"), None);
+    }
+}
@@ -3,7 +3,7 @@
 //! This module provides utilities for converting tabular data into GitHub-Flavored Markdown (GFM) tables.
 //! It's used by multiple extractors (DOCX, HTML) that need to represent structured table data in markdown format.
 
-use crate::extraction::capacity;
+use crate::types::TableMarkdownOptions;
 
 /// Converts a 2D vector of cell strings into a GitHub-Flavored Markdown table.
 ///
@@ -13,6 +13,8 @@ use crate::extraction::capacity;
 /// - A separator row is inserted after the header
 /// - Pipe characters (`|`) in cell content are automatically escaped with backslash
 /// - Irregular tables (rows with varying column counts) are padded with empty cells to match the header
+/// - Newlines in cell content are collapsed to spaces
+/// - Uses [`TableMarkdownOptions::default()`]; see [`crate::types::Table::to_markdown`] for other layouts
 /// - Returns an empty string for empty input
 ///
 /// # Arguments
@@ -38,53 +40,7 @@ use crate::extraction::capacity;
 /// assert!(markdown.contains("|------|------|"));
 /// ```
 pub fn cells_to_markdown(cells: &[Vec<String>]) -> String {
-    if cells.is_empty() {
-        return String::new();
-    }
-
-    let num_cols = cells.first().map(|r| r.len()).unwrap_or(0);
-    if num_cols == 0 {
-        return String::new();
-    }
-
-    let estimated_capacity = capacity::estimate_table_markdown_capacity(cells.len(), num_cols);
-    let mut markdown = String::with_capacity(estimated_capacity);
-
-    if let Some(header) = cells.first() {
-        markdown.push('|');
-        for cell in header {
-            markdown.push(' ');
-            let escaped = cell.replace('|', "\\|");
-            markdown.push_str(&escaped);
-            markdown.push_str(" |");
-        }
-        markdown.push('\n');
-
-        markdown.push('|');
-        for _ in 0..num_cols {
-            markdown.push_str("------|");
-        }
-        markdown.push('\n');
-    }
-
-    for row in cells.iter().skip(1) {
-        markdown.push('|');
-        for (idx, cell) in row.iter().enumerate() {
-            if idx >= num_cols {
-                break;
-            }
-            markdown.push(' ');
-            let escaped = cell.replace('|', "\\|");
-            markdown.push_str(&escaped);
-            markdown.push_str(" |");
-        }
-        for _ in row.len()..num_cols {
-            markdown.push_str(" |");
-        }
-        markdown.push('\n');
-    }
-
-    markdown
+    crate::types::tables::cells_to_markdown_with_options(cells, &TableMarkdownOptions::default())
 }
 
 #[cfg(test)]
@@ -13,6 +13,8 @@ pub mod image;
 /// reallocation cycles during string building operations.
 pub mod capacity;
 
+pub mod image_header;
+
 #[cfg(feature = "archives")]
 pub mod archive;
 
@@ -57,7 +59,7 @@ pub use transform::{
 };
 
 #[cfg(feature = "ocr")]
-pub use image::{ImageMetadata, extract_image_metadata};
+pub use image::{ImageMetadata, extract_image_metadata, extract_image_metadata_with_limits};
 
 #[cfg(feature = "archives")]
 pub use archive::{
@@ -3,12 +3,11 @@
 //! This module handles opening PPTX files, reading files from the ZIP archive,
 //! finding slide paths, and iterating through slides.
 
-use std::collections::HashMap;
 use std::io::{Cursor, Read, Seek};
 use std::path::Path;
 use zip::ZipArchive;
 
-use super::elements::Slide;
+use super::elements::{ImageReference, Slide};
 use super::image_handling::get_full_image_path;
 use crate::error::{KreuzbergError, Result};
 
@@ -66,13 +65,33 @@ impl<R: Read + Seek> PptxContainer<R> {
     }
 
     pub(super) fn read_file(&mut self, path: &str) -> Result<Vec<u8>> {
+        let mut file = self.open_file(path)?;
+        let mut contents = Vec::new();
+        // IO errors must bubble up - file read issues need user reports ~keep
+        file.read_to_end(&mut contents)?;
+        Ok(contents)
+    }
+
+    /// Read a file, or `None` if it is larger than `max_bytes`.
+    ///
+    /// The declared size is checked before anything is decompressed, and the read stops
+    /// one byte past `max_bytes` in case the declared size is wrong.
+    pub(super) fn read_file_limited(&mut self, path: &str, max_bytes: usize) -> Result<Option<Vec<u8>>> {
+        let file = self.open_file(path)?;
+        if file.size() > max_bytes as u64 {
+            return Ok(None);
+        }
+
+        let mut contents = Vec::new();
+        // IO errors must bubble up - file read issues need user reports ~keep
+        file.take((max_bytes as u64).saturating_add(1))
+            .read_to_end(&mut contents)?;
+        Ok((contents.len() <= max_bytes).then_some(contents))
+    }
+
+    fn open_file(&mut self, path: &str) -> Result<zip::read::ZipFile<'_, R>> {
         match self.archive.by_name(path) {
-            Ok(mut file) => {
-                let mut contents = Vec::new();
-                // IO errors must bubble up - file read issues need user reports ~keep
-                file.read_to_end(&mut contents)?;
-                Ok(contents)
-            }
+            Ok(file) => Ok(file),
             Err(zip::result::ZipError::FileNotFound) => {
                 Err(KreuzbergError::parsing("File not found in archive".to_string()))
             }
@@ -81,6 +100,14 @@ impl<R: Read + Seek> PptxContainer<R> {
         }
     }
 
+    /// Path of the preview image PowerPoint saves under `docProps/`, if any.
+    pub(super) fn thumbnail_path(&self) -> Option<String> {
+        self.archive
+            .file_names()
+            .find(|name| name.starts_with("docProps/thumbnail."))
+            .map(str::to_string)
+    }
+
     pub(super) fn get_slide_rels_path(&self, slide_path: &str) -> String {
         super::image_handling::get_slide_rels_path(slide_path)
     }
@@ -102,7 +129,7 @@ impl<R: Read + Seek> PptxContainer<R> {
             }
         }
 
-        slide_paths.sort();
+        super::parser::sort_slide_paths(&mut slide_paths);
         Ok(slide_paths)
     }
 
@@ -164,18 +191,19 @@ impl<R: Read + Seek> SlideIterator<R> {
         Ok(Some(slide))
     }
 
-    pub(super) fn get_slide_images(&mut self, slide: &Slide) -> Result<HashMap<String, Vec<u8>>> {
-        let mut image_data = HashMap::new();
-
-        for img_ref in &slide.images {
-            let slide_path = &self.container.slide_paths()[slide.slide_number as usize - 1];
-            let full_path = get_full_image_path(slide_path, &img_ref.target);
-
-            if let Ok(data) = self.container.read_file(&full_path) {
-                image_data.insert(img_ref.id.clone(), data);
-            }
+    /// Read one of the slide's images, or `None` if it is larger than `max_bytes`.
+    pub(super) fn read_slide_image(
+        &mut self,
+        slide: &Slide,
+        image: &ImageReference,
+        max_bytes: Option<usize>,
+    ) -> Result<Option<Vec<u8>>> {
+        let slide_path = &self.container.slide_paths()[slide.slide_number as usize - 1];
+        let full_path = get_full_image_path(slide_path, &image.target);
+
+        match max_bytes {
+            Some(max_bytes) => self.container.read_file_limited(&full_path, max_bytes),
+            None => self.container.read_file(&full_path).map(Some),
         }
-
-        Ok(image_data)
     }
 }
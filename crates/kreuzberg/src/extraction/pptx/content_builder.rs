@@ -107,7 +107,7 @@ impl ContentBuilder {
                 self.content.push('<');
                 self.content.push_str(tag);
                 self.content.push('>');
-                self.content.push_str(&super::image_handling::html_escape(cell));
+                self.content.push_str(&crate::text::html_escape(cell));
                 self.content.push_str("</");
                 self.content.push_str(tag);
                 self.content.push('>');
@@ -130,10 +130,10 @@ impl ContentBuilder {
         self.content.push('\n');
     }
 
-    pub(super) fn add_image(&mut self, image_id: &str, slide_number: u32) {
+    pub(super) fn add_image(&mut self, image_id: &str, alt_text: Option<&str>, slide_number: u32) {
         let filename = format!("slide_{}_image_{}.jpg", slide_number, image_id);
         self.content.push_str("![");
-        self.content.push_str(image_id);
+        self.content.push_str(alt_text.unwrap_or(image_id));
         self.content.push_str("](");
         self.content.push_str(&filename);
         self.content.push_str(")\n");
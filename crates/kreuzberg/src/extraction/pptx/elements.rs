@@ -81,6 +81,8 @@ pub(super) struct TableElement {
 pub(super) struct ImageReference {
     pub(super) id: String,
     pub(super) target: String,
+    /// Alternative text from the picture's `descr` attribute
+    pub(super) alt_text: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -115,6 +117,7 @@ pub(super) struct Slide {
 pub(super) struct ParserConfig {
     pub(super) extract_images: bool,
     pub(super) include_slide_comment: bool,
+    pub(super) image_limits: Option<crate::core::config::ImageExtractionConfig>,
 }
 
 impl Default for ParserConfig {
@@ -122,6 +125,7 @@ impl Default for ParserConfig {
         Self {
             extract_images: true,
             include_slide_comment: false,
+            image_limits: None,
         }
     }
 }
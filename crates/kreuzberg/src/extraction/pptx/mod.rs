@@ -48,11 +48,12 @@ mod parser;
 use bytes::Bytes;
 
 use crate::error::Result;
+use crate::extraction::image_header::detect_image_dimensions;
 use crate::types::{ExtractedImage, PptxExtractionResult};
 
 use container::{PptxContainer, SlideIterator};
 use content_builder::ContentBuilder;
-use elements::{ParserConfig, SlideElement};
+use elements::{ElementPosition, ImageReference, ParserConfig, SlideElement};
 use image_handling::detect_image_format;
 use metadata::{extract_all_notes, extract_metadata};
 
@@ -73,7 +74,11 @@ pub fn extract_pptx_from_path(
     page_config: Option<&crate::core::config::PageConfig>,
 ) -> Result<PptxExtractionResult> {
     let container = PptxContainer::open(path)?;
-    extract_pptx_from_container(container, extract_images, page_config)
+    let config = ParserConfig {
+        extract_images,
+        ..Default::default()
+    };
+    extract_pptx_from_container(container, config, page_config)
 }
 
 /// Extract PPTX content from a byte buffer.
@@ -93,19 +98,63 @@ pub fn extract_pptx_from_bytes(
     page_config: Option<&crate::core::config::PageConfig>,
 ) -> Result<PptxExtractionResult> {
     let container = PptxContainer::from_bytes(data)?;
-    extract_pptx_from_container(container, extract_images, page_config)
+    let config = ParserConfig {
+        extract_images,
+        ..Default::default()
+    };
+    extract_pptx_from_container(container, config, page_config)
 }
 
-fn extract_pptx_from_container<R: std::io::Read + std::io::Seek>(
-    mut container: PptxContainer<R>,
-    extract_images: bool,
+/// Extract PPTX content from a file path, honoring the limits in `image_config`.
+///
+/// Images are extracted only when `image_config` is set and `extract_images` is enabled.
+/// Once `max_images` or `max_total_image_bytes` is reached, no further images are read.
+pub(crate) fn extract_pptx_from_path_with_image_config(
+    path: &str,
+    image_config: Option<&crate::core::config::ImageExtractionConfig>,
     page_config: Option<&crate::core::config::PageConfig>,
 ) -> Result<PptxExtractionResult> {
-    let config = ParserConfig {
-        extract_images,
+    let container = PptxContainer::open(path)?;
+    extract_pptx_from_container(container, parser_config_for_images(image_config), page_config)
+}
+
+/// Extract PPTX content from a byte buffer, honoring the limits in `image_config`.
+///
+/// See [`extract_pptx_from_path_with_image_config`] for details.
+pub(crate) fn extract_pptx_from_bytes_with_image_config(
+    data: &[u8],
+    image_config: Option<&crate::core::config::ImageExtractionConfig>,
+    page_config: Option<&crate::core::config::PageConfig>,
+) -> Result<PptxExtractionResult> {
+    let container = PptxContainer::from_bytes(data)?;
+    extract_pptx_from_container(container, parser_config_for_images(image_config), page_config)
+}
+
+/// Read the preview image embedded in a PPTX file.
+///
+/// PowerPoint stores a rendering of the first slide as `docProps/thumbnail.jpeg` when
+/// saving. Returns the raw image bytes, or `None` if the presentation has no preview.
+pub(crate) fn read_pptx_thumbnail(data: &[u8]) -> Result<Option<Vec<u8>>> {
+    let mut container = PptxContainer::from_bytes(data)?;
+    match container.thumbnail_path() {
+        Some(path) => container.read_file(&path).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn parser_config_for_images(image_config: Option<&crate::core::config::ImageExtractionConfig>) -> ParserConfig {
+    ParserConfig {
+        extract_images: image_config.is_some_and(|img| img.extract_images),
+        image_limits: image_config.cloned(),
         ..Default::default()
-    };
+    }
+}
 
+fn extract_pptx_from_container<R: std::io::Read + std::io::Seek>(
+    mut container: PptxContainer<R>,
+    config: ParserConfig,
+    page_config: Option<&crate::core::config::PageConfig>,
+) -> Result<PptxExtractionResult> {
     let metadata = extract_metadata(&mut container.archive);
 
     let notes = extract_all_notes(&mut container)?;
@@ -119,6 +168,8 @@ fn extract_pptx_from_container<R: std::io::Read + std::io::Seek>(
     let mut total_image_count = 0;
     let mut total_table_count = 0;
     let mut extracted_images = Vec::new();
+    let mut extracted_image_bytes = 0usize;
+    let mut images_truncated = false;
 
     while let Some(slide) = iterator.next_slide()? {
         let byte_start = if page_config.is_some() {
@@ -138,10 +189,37 @@ fn extract_pptx_from_container<R: std::io::Read + std::io::Seek>(
             content_builder.end_slide(slide.slide_number, byte_start, slide_content.clone());
         }
 
-        if config.extract_images
-            && let Ok(image_data) = iterator.get_slide_images(&slide)
-        {
-            for (_, data) in image_data {
+        if config.extract_images && !images_truncated {
+            // Images are read one at a time, and no further than the remaining byte
+            // budget, so an oversized image is never decompressed in full. Images that
+            // cannot be read from the archive are skipped.
+            for img_ref in slide.ordered_images() {
+                let limits = config.image_limits.as_ref();
+                if limits.is_some_and(|limits| !limits.allows_image(extracted_images.len(), extracted_image_bytes, 0)) {
+                    images_truncated = true;
+                    break;
+                }
+                let max_bytes = limits
+                    .and_then(|limits| limits.max_total_image_bytes)
+                    .map(|max| max.saturating_sub(extracted_image_bytes));
+
+                let data = match iterator.read_slide_image(&slide, &img_ref, max_bytes) {
+                    Ok(Some(data)) => data,
+                    Ok(None) => {
+                        images_truncated = true;
+                        break;
+                    }
+                    Err(_) => continue,
+                };
+
+                let dimensions = detect_image_dimensions(&data);
+                if limits.is_some_and(|limits| {
+                    dimensions.is_some_and(|(width, height)| !limits.meets_min_dimensions(width, height))
+                }) {
+                    continue;
+                }
+
+                extracted_image_bytes += data.len();
                 let format = detect_image_format(&data);
                 let image_index = extracted_images.len();
 
@@ -150,12 +228,15 @@ fn extract_pptx_from_container<R: std::io::Read + std::io::Seek>(
                     format, // Already a Cow<'static, str> from detect_image_format
                     image_index,
                     page_number: Some(slide.slide_number as usize),
-                    width: None,
-                    height: None,
+                    width: dimensions.map(|(width, _)| width),
+                    height: dimensions.map(|(_, height)| height),
                     colorspace: None,
                     bits_per_component: None,
                     is_mask: false,
                     description: None,
+                    alt_text: img_ref.alt_text,
+                    page_position: None,
+                    caption: None,
                     ocr_result: None,
                 });
             }
@@ -205,6 +286,7 @@ fn extract_pptx_from_container<R: std::io::Read + std::io::Seek>(
         image_count: total_image_count,
         table_count: total_table_count,
         images: extracted_images,
+        images_truncated,
         page_structure,
         page_contents,
     })
@@ -275,7 +357,7 @@ impl elements::Slide {
                     }
                 }
                 SlideElement::Image(img_ref, _) => {
-                    builder.add_image(&img_ref.id, self.slide_number);
+                    builder.add_image(&img_ref.id, img_ref.alt_text.as_deref(), self.slide_number);
                 }
                 SlideElement::Unknown => {}
             }
@@ -284,6 +366,44 @@ impl elements::Slide {
         builder.build().0
     }
 
+    /// Image references in reading order (top-to-bottom, left-to-right), with
+    /// relationship targets resolved.
+    ///
+    /// Images related to the slide but not placed as pictures follow in relationship order.
+    fn ordered_images(&self) -> Vec<ImageReference> {
+        let mut pictures: Vec<(ElementPosition, &ImageReference)> = self
+            .elements
+            .iter()
+            .filter_map(|element| match element {
+                SlideElement::Image(img_ref, pos) => Some((*pos, img_ref)),
+                _ => None,
+            })
+            .collect();
+        pictures.sort_by_key(|(pos, _)| (pos.y, pos.x));
+
+        let mut ordered: Vec<ImageReference> = Vec::with_capacity(self.images.len());
+        for (_, picture) in pictures {
+            if ordered.iter().any(|img| img.id == picture.id) {
+                continue;
+            }
+            if let Some(rel) = self.images.iter().find(|rel| rel.id == picture.id) {
+                ordered.push(ImageReference {
+                    id: rel.id.clone(),
+                    target: rel.target.clone(),
+                    alt_text: picture.alt_text.clone(),
+                });
+            }
+        }
+
+        for rel in &self.images {
+            if !ordered.iter().any(|img| img.id == rel.id) {
+                ordered.push(rel.clone());
+            }
+        }
+
+        ordered
+    }
+
     fn image_count(&self) -> usize {
         self.elements
             .iter()
@@ -302,6 +422,7 @@ impl elements::Slide {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::config::ImageExtractionConfig;
 
     fn create_test_pptx_bytes(slides: Vec<&str>) -> Vec<u8> {
         use std::io::Write;
@@ -406,6 +527,248 @@ mod tests {
         buffer
     }
 
+    fn add_slide_images(pptx_bytes: Vec<u8>, slide_count: usize, image_size: usize) -> Vec<u8> {
+        use std::io::Write;
+        use zip::write::{SimpleFileOptions, ZipWriter};
+
+        let mut zip = ZipWriter::new_append(std::io::Cursor::new(pptx_bytes)).unwrap();
+        let options = SimpleFileOptions::default();
+
+        for i in 1..=slide_count {
+            zip.start_file(format!("ppt/slides/_rels/slide{}.xml.rels", i), options)
+                .unwrap();
+            zip.write_all(
+                format!(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+    <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/image{}.png"/>
+</Relationships>"#,
+                    i
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+
+            let mut image = png_header(64, 64);
+            image.resize(image_size, 0);
+            zip.start_file(format!("ppt/media/image{}.png", i), options).unwrap();
+            zip.write_all(&image).unwrap();
+        }
+
+        zip.finish().unwrap().into_inner()
+    }
+
+    /// PNG signature followed by an IHDR chunk header declaring `width` x `height`.
+    fn png_header(width: u32, height: u32) -> Vec<u8> {
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+        png.extend_from_slice(b"IHDR");
+        png.extend_from_slice(&width.to_be_bytes());
+        png.extend_from_slice(&height.to_be_bytes());
+        png
+    }
+
+    fn image_limits(max_images: Option<usize>, max_total_image_bytes: Option<usize>) -> ImageExtractionConfig {
+        ImageExtractionConfig {
+            extract_images: true,
+            target_dpi: 300,
+            max_image_dimension: 4096,
+            max_decode_dimension: 32768,
+            auto_adjust_dpi: true,
+            min_dpi: 72,
+            max_dpi: 600,
+            max_images,
+            max_total_image_bytes,
+            min_width: 16,
+            min_height: 16,
+            output_format: crate::core::config::ImageFormat::Original,
+        }
+    }
+
+    #[test]
+    fn test_extract_pptx_images_without_limits() {
+        let pptx_bytes = add_slide_images(create_test_pptx_bytes(vec!["One", "Two", "Three"]), 3, 64);
+        let config = image_limits(None, None);
+        let result = extract_pptx_from_bytes_with_image_config(&pptx_bytes, Some(&config), None).unwrap();
+
+        assert_eq!(result.images.len(), 3);
+        assert!(!result.images_truncated);
+    }
+
+    #[test]
+    fn test_extract_pptx_images_respects_max_images() {
+        let pptx_bytes = add_slide_images(create_test_pptx_bytes(vec!["One", "Two", "Three"]), 3, 64);
+        let config = image_limits(Some(2), None);
+        let result = extract_pptx_from_bytes_with_image_config(&pptx_bytes, Some(&config), None).unwrap();
+
+        assert_eq!(result.images.len(), 2);
+        assert!(result.images_truncated);
+        assert_eq!(result.images[0].page_number, Some(1));
+        assert_eq!(result.images[1].page_number, Some(2));
+    }
+
+    #[test]
+    fn test_extract_pptx_images_skips_tiny_images() {
+        use std::io::Write;
+        use zip::write::{SimpleFileOptions, ZipWriter};
+
+        let pptx_bytes = add_slide_images(create_test_pptx_bytes(vec!["One", "Two"]), 1, 64);
+        let mut zip = ZipWriter::new_append(std::io::Cursor::new(pptx_bytes)).unwrap();
+        let options = SimpleFileOptions::default();
+        zip.start_file("ppt/slides/_rels/slide2.xml.rels", options).unwrap();
+        zip.write_all(br#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+    <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/pixel.png"/>
+</Relationships>"#).unwrap();
+        zip.start_file("ppt/media/pixel.png", options).unwrap();
+        zip.write_all(&png_header(1, 1)).unwrap();
+        let pptx_bytes = zip.finish().unwrap().into_inner();
+
+        let config = image_limits(None, None);
+        let result = extract_pptx_from_bytes_with_image_config(&pptx_bytes, Some(&config), None).unwrap();
+
+        assert_eq!(result.images.len(), 1);
+        assert_eq!(result.images[0].page_number, Some(1));
+        assert_eq!(result.images[0].width, Some(64));
+    }
+
+    #[test]
+    fn test_extract_pptx_images_respects_max_total_bytes() {
+        let pptx_bytes = add_slide_images(create_test_pptx_bytes(vec!["One", "Two", "Three"]), 3, 100);
+        let config = image_limits(None, Some(250));
+        let result = extract_pptx_from_bytes_with_image_config(&pptx_bytes, Some(&config), None).unwrap();
+
+        assert_eq!(result.images.len(), 2);
+        assert!(result.images_truncated);
+    }
+
+    #[test]
+    fn test_read_file_limited_rejects_larger_files() {
+        let pptx_bytes = add_slide_images(create_test_pptx_bytes(vec!["One"]), 1, 100);
+        let mut container = PptxContainer::from_bytes(&pptx_bytes).unwrap();
+
+        assert_eq!(container.read_file_limited("ppt/media/image1.png", 99).unwrap(), None);
+        assert_eq!(
+            container
+                .read_file_limited("ppt/media/image1.png", 100)
+                .unwrap()
+                .map(|data| data.len()),
+            Some(100)
+        );
+    }
+
+    fn create_pptx_with_pictures() -> Vec<u8> {
+        use std::io::Write;
+        use zip::write::{SimpleFileOptions, ZipWriter};
+
+        let picture = |id: u32, rel: &str, descr: &str, y: i64| {
+            format!(
+                r#"<p:pic>
+                <p:nvPicPr><p:cNvPr id="{id}" name="Picture {id}" descr="{descr}"/><p:cNvPicPr/><p:nvPr/></p:nvPicPr>
+                <p:blipFill><a:blip r:embed="{rel}"/></p:blipFill>
+                <p:spPr><a:xfrm><a:off x="0" y="{y}"/><a:ext cx="100" cy="100"/></a:xfrm></p:spPr>
+            </p:pic>"#
+            )
+        };
+        let slide_xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<p:sld xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+       xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"
+       xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+    <p:cSld>
+        <p:spTree>
+            {}
+            {}
+        </p:spTree>
+    </p:cSld>
+</p:sld>"#,
+            picture(2, "rId1", "Bar chart of revenue", 5000),
+            picture(3, "rId2", "Company logo", 100)
+        );
+
+        let mut buffer = Vec::new();
+        {
+            let mut zip = ZipWriter::new(std::io::Cursor::new(&mut buffer));
+            let options = SimpleFileOptions::default();
+
+            zip.start_file("ppt/_rels/presentation.xml.rels", options).unwrap();
+            zip.write_all(br#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+    <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slides/slide1.xml"/>
+</Relationships>"#).unwrap();
+
+            zip.start_file("ppt/slides/slide1.xml", options).unwrap();
+            zip.write_all(slide_xml.as_bytes()).unwrap();
+
+            zip.start_file("ppt/slides/_rels/slide1.xml.rels", options).unwrap();
+            zip.write_all(br#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+    <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/chart.png"/>
+    <Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/logo.gif"/>
+</Relationships>"#).unwrap();
+
+            zip.start_file("ppt/media/chart.png", options).unwrap();
+            zip.write_all(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A])
+                .unwrap();
+            zip.start_file("ppt/media/logo.gif", options).unwrap();
+            zip.write_all(b"GIF89a").unwrap();
+
+            let _ = zip.finish().unwrap();
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_extract_pptx_images_include_alt_text_in_reading_order() {
+        let pptx_bytes = create_pptx_with_pictures();
+        let config = image_limits(None, None);
+        let result = extract_pptx_from_bytes_with_image_config(&pptx_bytes, Some(&config), None).unwrap();
+
+        assert_eq!(result.images.len(), 2);
+        assert_eq!(result.images[0].alt_text.as_deref(), Some("Company logo"));
+        assert_eq!(result.images[0].format, "gif");
+        assert_eq!(result.images[1].alt_text.as_deref(), Some("Bar chart of revenue"));
+        assert_eq!(result.images[1].format, "png");
+        assert!(result.images.iter().all(|img| img.page_number == Some(1)));
+        assert_eq!(result.images[1].image_index, 1);
+        assert!(result.content.contains("![Company logo]("));
+    }
+
+    #[test]
+    fn test_extract_pptx_images_ordered_by_slide_number() {
+        let slides: Vec<String> = (1..=11).map(|i| format!("Slide {}", i)).collect();
+        let pptx_bytes = add_slide_images(
+            create_test_pptx_bytes(slides.iter().map(String::as_str).collect()),
+            11,
+            64,
+        );
+        let config = image_limits(None, None);
+        let result = extract_pptx_from_bytes_with_image_config(&pptx_bytes, Some(&config), None).unwrap();
+
+        let pages: Vec<Option<usize>> = result.images.iter().map(|img| img.page_number).collect();
+        assert_eq!(pages, (1..=11).map(Some).collect::<Vec<_>>());
+        let slide_2 = result.content.find("Slide 2").unwrap();
+        let slide_10 = result.content.find("Slide 10").unwrap();
+        assert!(slide_2 < slide_10);
+    }
+
+    #[test]
+    fn test_sort_slide_paths_numeric() {
+        let mut paths = vec![
+            "ppt/slides/slide10.xml".to_string(),
+            "ppt/slides/slide2.xml".to_string(),
+            "ppt/slides/slide1.xml".to_string(),
+        ];
+        parser::sort_slide_paths(&mut paths);
+        assert_eq!(
+            paths,
+            vec![
+                "ppt/slides/slide1.xml",
+                "ppt/slides/slide2.xml",
+                "ppt/slides/slide10.xml"
+            ]
+        );
+    }
+
     #[test]
     fn test_extract_pptx_from_bytes_single_slide() {
         let pptx_bytes = create_test_pptx_bytes(vec!["Hello World"]);
@@ -520,19 +883,6 @@ mod tests {
         assert_eq!(detect_image_format(unknown_data), "unknown");
     }
 
-    #[test]
-    fn test_html_escape() {
-        assert_eq!(image_handling::html_escape("plain text"), "plain text");
-        assert_eq!(image_handling::html_escape("a & b"), "a &amp; b");
-        assert_eq!(image_handling::html_escape("<tag>"), "&lt;tag&gt;");
-        assert_eq!(image_handling::html_escape("\"quoted\""), "&quot;quoted&quot;");
-        assert_eq!(image_handling::html_escape("'apostrophe'"), "&#x27;apostrophe&#x27;");
-        assert_eq!(
-            image_handling::html_escape("<a href=\"url\" title='test'>text & more</a>"),
-            "&lt;a href=&quot;url&quot; title=&#x27;test&#x27;&gt;text &amp; more&lt;/a&gt;"
-        );
-    }
-
     #[test]
     fn test_get_slide_rels_path() {
         assert_eq!(
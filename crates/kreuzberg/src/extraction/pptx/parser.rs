@@ -210,9 +210,18 @@ fn parse_pic(pic_node: &Node) -> Result<ImageReference> {
         .or_else(|| blip_node.attribute("r:embed"))
         .ok_or_else(|| KreuzbergError::parsing("Image embed attribute not found".to_string()))?;
 
+    let alt_text = pic_node
+        .descendants()
+        .find(|n| n.is_element() && n.tag_name().name() == "cNvPr" && n.tag_name().namespace() == Some(P_NAMESPACE))
+        .and_then(|n| n.attribute("descr"))
+        .map(str::trim)
+        .filter(|descr| !descr.is_empty())
+        .map(str::to_string);
+
     let image_ref = ImageReference {
         id: embed_attr.to_string(),
         target: String::new(),
+        alt_text,
     };
 
     Ok(image_ref)
@@ -351,6 +360,7 @@ pub(super) fn parse_slide_rels(rels_data: &[u8]) -> Result<Vec<ImageReference>>
             images.push(ImageReference {
                 id: id.to_string(),
                 target: target.to_string(),
+                alt_text: None,
             });
         }
     }
@@ -388,7 +398,21 @@ pub(super) fn parse_presentation_rels(rels_data: &[u8]) -> Result<Vec<String>> {
     // PowerPoint doesn't guarantee relationship order in the rels file.
     // GitHub Issue #329: Without sorting, slides can be processed in wrong order,
     // causing images to have incorrect page numbers.
-    slide_paths.sort();
+    sort_slide_paths(&mut slide_paths);
 
     Ok(slide_paths)
 }
+
+/// Sort slide paths by slide number, so `slide10.xml` follows `slide9.xml`.
+pub(super) fn sort_slide_paths(slide_paths: &mut [String]) {
+    slide_paths.sort_by(|a, b| {
+        slide_number_from_path(a)
+            .cmp(&slide_number_from_path(b))
+            .then_with(|| a.cmp(b))
+    });
+}
+
+fn slide_number_from_path(path: &str) -> Option<u32> {
+    let file_name = path.rsplit('/').next()?;
+    file_name.strip_prefix("slide")?.strip_suffix(".xml")?.parse().ok()
+}
@@ -468,6 +468,12 @@ mod tests {
             json_schema: None,
             error: None,
             extraction_duration_ms: None,
+            truncated: None,
+            ocr_used: false,
+            ocr_page_count: None,
+            ocr_language: None,
+            ocr_backend: None,
+            extractor_name: None,
             additional: Default::default(),
         }
     }
@@ -486,6 +492,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         }
     }
 
@@ -685,6 +696,9 @@ mod tests {
                 ],
                 markdown: "| Name | Age |\n|---|---|\n| Alice | 30 |".to_string(),
                 page_number: 1,
+                name: None,
+                has_header: false,
+                cell_spans: Vec::new(),
             }],
             ..test_result("Some content")
         };
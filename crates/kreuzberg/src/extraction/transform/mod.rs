@@ -256,6 +256,12 @@ mod tests {
             json_schema: None,
             error: None,
             extraction_duration_ms: None,
+            truncated: None,
+            ocr_used: false,
+            ocr_page_count: None,
+            ocr_language: None,
+            ocr_backend: None,
+            extractor_name: None,
             additional: Default::default(),
         }
     }
@@ -312,6 +318,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let elements = transform_extraction_result_to_elements(&result);
@@ -365,6 +376,9 @@ mod tests {
             ],
             markdown: "| Header1 | Header2 |\n| Cell1 | Cell2 |".to_string(),
             page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         };
 
         let image = ExtractedImage {
@@ -378,6 +392,9 @@ mod tests {
             bits_per_component: Some(8),
             is_mask: false,
             description: None,
+            alt_text: None,
+            page_position: None,
+            caption: None,
             ocr_result: None,
         };
 
@@ -401,6 +418,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let elements = transform_extraction_result_to_elements(&result);
@@ -445,6 +467,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let elements = transform_extraction_result_to_elements(&result);
@@ -479,6 +506,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let elements = transform_extraction_result_to_elements(&result);
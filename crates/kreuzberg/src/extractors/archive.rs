@@ -1,19 +1,25 @@
 //! Archive extractors for ZIP, TAR, 7z, and GZIP formats.
+//!
+//! By default archives are listed and the contents of plain text members included.
+//! With `ExtractionConfig.archive_options` set, every member of a ZIP archive is
+//! extracted with the extractor for its format.
 
-use crate::Result;
-use crate::core::config::ExtractionConfig;
+use crate::core::config::{ArchiveConfig, ArchiveMode, ExtractionConfig};
 use crate::extraction::archive::{
     ArchiveMetadata as ExtractedMetadata, extract_7z_metadata, extract_7z_text_content, extract_gzip,
-    extract_tar_metadata, extract_tar_text_content, extract_zip_metadata, extract_zip_text_content,
+    extract_tar_metadata, extract_tar_text_content, extract_zip_files, extract_zip_metadata, extract_zip_text_content,
 };
-use crate::extractors::security::ZipBombValidator;
+use crate::extractors::security::{SecurityError, SecurityLimits, ZipBombValidator};
 use crate::plugins::{DocumentExtractor, Plugin};
 use crate::types::{ArchiveMetadata, ExtractionResult, Metadata};
+use crate::{KreuzbergError, Result};
 use ahash::AHashMap;
 use async_trait::async_trait;
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::future::Future;
 use std::io::Cursor;
+use std::pin::Pin;
 
 /// Build an ExtractionResult from archive metadata and text contents.
 ///
@@ -25,33 +31,7 @@ fn build_archive_result(
     format_name: &'static str,
     mime_type: &str,
 ) -> ExtractionResult {
-    let file_names: Vec<String> = extraction_metadata
-        .file_list
-        .iter()
-        .map(|entry| entry.path.clone())
-        .collect();
-
-    let archive_metadata = ArchiveMetadata {
-        format: Cow::Borrowed(format_name),
-        file_count: extraction_metadata.file_count,
-        file_list: file_names,
-        total_size: extraction_metadata.total_size as usize,
-        compressed_size: None,
-    };
-
-    let mut additional = AHashMap::new();
-    let file_details: Vec<serde_json::Value> = extraction_metadata
-        .file_list
-        .iter()
-        .map(|entry| {
-            serde_json::json!({
-                "path": entry.path,
-                "size": entry.size,
-                "is_dir": entry.is_dir,
-            })
-        })
-        .collect();
-    additional.insert(Cow::Borrowed("files"), serde_json::json!(file_details));
+    let (archive_metadata, additional) = archive_format_metadata(&extraction_metadata, format_name);
 
     let mut output = format!(
         "{} Archive ({} files, {} bytes)\n\n",
@@ -86,12 +66,257 @@ fn build_archive_result(
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    }
+}
+
+/// Archive format metadata and the `files` listing for `metadata.additional`.
+fn archive_format_metadata(
+    extraction_metadata: &ExtractedMetadata,
+    format_name: &'static str,
+) -> (ArchiveMetadata, AHashMap<Cow<'static, str>, serde_json::Value>) {
+    let file_names: Vec<String> = extraction_metadata
+        .file_list
+        .iter()
+        .map(|entry| entry.path.clone())
+        .collect();
+
+    let archive_metadata = ArchiveMetadata {
+        format: Cow::Borrowed(format_name),
+        file_count: extraction_metadata.file_count,
+        file_list: file_names,
+        total_size: extraction_metadata.total_size as usize,
+        compressed_size: None,
+    };
+
+    let mut additional = AHashMap::new();
+    let file_details: Vec<serde_json::Value> = extraction_metadata
+        .file_list
+        .iter()
+        .map(|entry| {
+            serde_json::json!({
+                "path": entry.path,
+                "size": entry.size,
+                "is_dir": entry.is_dir,
+            })
+        })
+        .collect();
+    additional.insert(Cow::Borrowed("files"), serde_json::json!(file_details));
+
+    (archive_metadata, additional)
+}
+
+/// What is left of `max_archive_size` and `max_files_in_archive` while extracting an
+/// archive and the archives nested in it.
+///
+/// Every archive's members are charged to the same budget, so nesting cannot multiply
+/// the amount of data a single document expands to.
+struct ArchiveBudget {
+    bytes: usize,
+    files: usize,
+}
+
+impl ArchiveBudget {
+    fn new(limits: &SecurityLimits) -> Self {
+        Self {
+            bytes: limits.max_archive_size,
+            files: limits.max_files_in_archive,
+        }
+    }
+
+    /// `limits` with the archive size and file count reduced to what is left.
+    fn limits(&self, limits: &SecurityLimits) -> SecurityLimits {
+        SecurityLimits {
+            max_archive_size: self.bytes,
+            max_files_in_archive: self.files,
+            ..limits.clone()
+        }
+    }
+
+    fn charge(&mut self, files: &[(String, Vec<u8>)]) {
+        let bytes: usize = files.iter().map(|(_, bytes)| bytes.len()).sum();
+        self.bytes = self.bytes.saturating_sub(bytes);
+        self.files = self.files.saturating_sub(files.len());
+    }
+}
+
+/// Extract every member of a ZIP archive with the extractor for its format.
+///
+/// Members whose format is unknown or whose extraction fails are skipped and recorded in
+/// `metadata.additional["archive_errors"]`. Nested ZIP archives are extracted the same
+/// way, `depth` counting the archives they are nested in; past
+/// `limits.max_nesting_depth` the whole extraction fails, as it does once the members
+/// of all archives together exceed `budget`. Other nested archives and macOS resource
+/// forks are skipped silently. The extracted members are listed, in order, in
+/// `metadata.additional["archive_members"]`.
+async fn extract_zip_documents(
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+    archive_config: &ArchiveConfig,
+    limits: &SecurityLimits,
+    depth: usize,
+    budget: &mut ArchiveBudget,
+) -> Result<ExtractionResult> {
+    let remaining = budget.limits(limits);
+    let extraction_metadata = extract_zip_metadata(content, &remaining)?;
+    let files = extract_zip_files(content, &remaining, archive_config.password.as_deref())?;
+    budget.charge(&files);
+
+    let mut documents = Vec::with_capacity(files.len());
+    let mut members = Vec::with_capacity(files.len());
+    let mut errors = Vec::new();
+
+    for (path, bytes) in files {
+        if path.starts_with("__MACOSX/") {
+            continue;
+        }
+
+        let member_mime = match member_mime_type(&path, &bytes) {
+            Ok(member_mime) => member_mime,
+            Err(err) => {
+                errors.push(serde_json::json!({ "path": path, "error": err.to_string() }));
+                continue;
+            }
+        };
+        if is_archive_mime_type(&member_mime) {
+            if !ZipExtractor.supported_mime_types().contains(&member_mime.as_str()) {
+                continue;
+            }
+            if depth >= limits.max_nesting_depth {
+                return Err(KreuzbergError::validation(
+                    SecurityError::NestingTooDeep {
+                        depth: depth + 1,
+                        max: limits.max_nesting_depth,
+                    }
+                    .to_string(),
+                ));
+            }
+            match extract_nested_zip(&bytes, &member_mime, config, archive_config, limits, depth + 1, budget).await {
+                Ok(document) => {
+                    members.push(serde_json::json!({ "path": path, "mime_type": member_mime }));
+                    documents.push(document);
+                }
+                Err(err @ (KreuzbergError::LockPoisoned(_) | KreuzbergError::Validation { .. })) => return Err(err),
+                Err(err) => errors.push(serde_json::json!({ "path": path, "error": err.to_string() })),
+            }
+            continue;
+        }
+
+        let extracted = match archive_config.mode {
+            // The combined result goes through the pipeline as a whole afterwards.
+            ArchiveMode::Combined => extract_member(&bytes, &member_mime, config).await,
+            ArchiveMode::Separate => crate::core::extractor::extract_bytes(&bytes, &member_mime, config).await,
+        };
+        match extracted {
+            Ok(document) => {
+                members.push(serde_json::json!({ "path": path, "mime_type": member_mime }));
+                documents.push(document);
+            }
+            Err(err @ KreuzbergError::LockPoisoned(_)) => return Err(err),
+            Err(err) => errors.push(serde_json::json!({ "path": path, "error": err.to_string() })),
+        }
     }
+
+    let mut result = match archive_config.mode {
+        ArchiveMode::Combined => {
+            let (archive_metadata, additional) = archive_format_metadata(&extraction_metadata, "ZIP");
+            let mut merged = ExtractionResult::merge(documents);
+            merged.mime_type = mime_type.to_string().into();
+            merged.metadata.format = Some(crate::types::FormatMetadata::Archive(archive_metadata));
+            merged.metadata.additional.extend(additional);
+            merged
+        }
+        ArchiveMode::Separate => {
+            let mut listing = build_archive_result(extraction_metadata, HashMap::new(), "ZIP", mime_type);
+            listing
+                .metadata
+                .additional
+                .insert(Cow::Borrowed("documents"), serde_json::to_value(&documents)?);
+            listing
+        }
+    };
+
+    result
+        .metadata
+        .additional
+        .insert(Cow::Borrowed("archive_members"), serde_json::Value::Array(members));
+    if !errors.is_empty() {
+        result
+            .metadata
+            .additional
+            .insert(Cow::Borrowed("archive_errors"), serde_json::Value::Array(errors));
+    }
+
+    Ok(result)
+}
+
+/// Check a ZIP archive found inside another for ZIP bombs and extract its members.
+///
+/// Boxed because it recurses through [`extract_zip_documents`].
+fn extract_nested_zip<'a>(
+    content: &'a [u8],
+    mime_type: &'a str,
+    config: &'a ExtractionConfig,
+    archive_config: &'a ArchiveConfig,
+    limits: &'a SecurityLimits,
+    depth: usize,
+    budget: &'a mut ArchiveBudget,
+) -> Pin<Box<dyn Future<Output = Result<ExtractionResult>> + Send + 'a>> {
+    Box::pin(async move {
+        let mut archive = zip::ZipArchive::new(Cursor::new(content))
+            .map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP archive: {}", e)))?;
+        ZipBombValidator::new(budget.limits(limits))
+            .validate(&mut archive)
+            .map_err(|e| KreuzbergError::validation(e.to_string()))?;
+
+        extract_zip_documents(content, mime_type, config, archive_config, limits, depth, budget).await
+    })
+}
+
+/// MIME type of an archive member, from its extension or else its content.
+///
+/// Registered MIME detectors are offered the start of the member's bytes first; the
+/// member path is only used as a name and never opened.
+fn member_mime_type(path: &str, bytes: &[u8]) -> Result<String> {
+    let header = &bytes[..bytes.len().min(crate::core::mime::MIME_SNIFF_BYTES)];
+    if let Some(mime_type) = crate::core::mime::detect_with_registered_detectors(header)? {
+        return Ok(mime_type);
+    }
+    crate::core::mime::detect_mime_type(path, false).or_else(|_| crate::core::mime::detect_mime_type_from_bytes(bytes))
+}
+
+fn is_archive_mime_type(mime_type: &str) -> bool {
+    [
+        ZipExtractor.supported_mime_types(),
+        TarExtractor.supported_mime_types(),
+        SevenZExtractor.supported_mime_types(),
+        GzipExtractor.supported_mime_types(),
+    ]
+    .iter()
+    .any(|mime_types| mime_types.contains(&mime_type))
+}
+
+/// Extract a member with the extractor registered for its MIME type, without post-processing.
+async fn extract_member(bytes: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    let extractor = {
+        let registry = crate::plugins::registry::get_document_extractor_registry();
+        let registry = registry
+            .read()
+            .map_err(|e| KreuzbergError::LockPoisoned(format!("Document extractor registry lock poisoned: {}", e)))?;
+        registry.get(mime_type)?
+    };
+    extractor.extract_bytes(bytes, mime_type, config).await
 }
 
 /// ZIP archive extractor.
 ///
-/// Extracts file lists and text content from ZIP archives.
+/// Extracts file lists and text content from ZIP archives, or every member document
+/// when `ExtractionConfig.archive_options` is set.
 pub struct ZipExtractor;
 
 impl ZipExtractor {
@@ -159,6 +384,11 @@ impl DocumentExtractor for ZipExtractor {
             .validate(&mut archive)
             .map_err(|e| crate::error::KreuzbergError::validation(e.to_string()))?;
 
+        if let Some(archive_config) = &config.archive_options {
+            let mut budget = ArchiveBudget::new(&limits);
+            return extract_zip_documents(content, mime_type, config, archive_config, &limits, 1, &mut budget).await;
+        }
+
         let extraction_metadata = extract_zip_metadata(content, &limits)?;
         let text_contents = extract_zip_text_content(content, &limits)?;
         Ok(build_archive_result(
@@ -178,6 +178,11 @@ impl DocumentExtractor for BibtexExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
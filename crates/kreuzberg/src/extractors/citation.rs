@@ -112,6 +112,11 @@ impl DocumentExtractor for CitationExtractor {
                     elements: None,
                     ocr_elements: None,
                     document: None,
+                    outline: None,
+                    formulas: None,
+                    text_blocks: None,
+                    source_map: None,
+                    references: None,
                 });
             }
         };
@@ -272,6 +277,11 @@ impl DocumentExtractor for CitationExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -85,12 +85,16 @@ impl DocumentExtractor for CsvExtractor {
             .join("\n");
 
         // Build markdown table
-        let markdown = build_markdown_table(&rows);
+        let has_header = crate::types::tables::first_row_looks_like_header(&rows);
+        let markdown = build_markdown_table(&rows, has_header);
 
         let table = Table {
+            has_header,
             cells: rows.clone(),
             markdown,
             page_number: 1,
+            name: None,
+            cell_spans: Vec::new(),
         };
 
         let row_count = rows.len();
@@ -126,6 +130,11 @@ impl DocumentExtractor for CsvExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -206,7 +215,10 @@ fn parse_csv(text: &str, delimiter: char) -> Vec<Vec<String>> {
 }
 
 /// Build a Markdown table from parsed rows.
-fn build_markdown_table(rows: &[Vec<String>]) -> String {
+///
+/// Without a header row, the rows are put under an empty header so the first one stays
+/// in the body.
+fn build_markdown_table(rows: &[Vec<String>], has_header: bool) -> String {
     if rows.is_empty() {
         return String::new();
     }
@@ -217,8 +229,10 @@ fn build_markdown_table(rows: &[Vec<String>]) -> String {
     }
 
     let mut markdown = String::new();
+    let empty_header = Vec::new();
+    let header_rows = if has_header { None } else { Some(&empty_header) };
 
-    for (i, row) in rows.iter().enumerate() {
+    for (i, row) in header_rows.into_iter().chain(rows).enumerate() {
         markdown.push('|');
         for j in 0..col_count {
             let cell = row.get(j).map(|s| s.trim()).unwrap_or("");
@@ -287,10 +301,13 @@ mod tests {
             vec!["Name".to_string(), "Age".to_string()],
             vec!["Alice".to_string(), "30".to_string()],
         ];
-        let md = build_markdown_table(&rows);
+        let md = build_markdown_table(&rows, true);
         assert!(md.contains("| Name | Age |"));
         assert!(md.contains("| --- | --- |"));
         assert!(md.contains("| Alice | 30 |"));
+
+        let md = build_markdown_table(&rows[1..], false);
+        assert_eq!(md, "|  |  |\n| --- | --- |\n| Alice | 30 |\n");
     }
 
     #[tokio::test]
@@ -185,6 +185,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let markup = extraction_result_to_djot(&result).expect("Should convert");
@@ -206,6 +211,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let markup = extraction_result_to_djot(&result).expect("Should convert");
@@ -117,6 +117,11 @@ impl DocumentExtractor for DjotExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
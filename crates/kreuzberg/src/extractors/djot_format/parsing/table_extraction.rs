@@ -15,12 +15,14 @@ pub fn extract_tables_from_events(events: &[Event]) -> Vec<Table> {
     let mut current_row: Vec<String> = Vec::new();
     let mut current_cell = String::new();
     let mut in_table_cell = false;
+    let mut first_row_is_head = false;
     let mut table_index = 0;
 
     for event in events {
         match event {
             Event::Start(Container::Table, _) => {
                 current_table = Some((Vec::new(), table_index));
+                first_row_is_head = false;
             }
             Event::Start(Container::TableRow { .. }, _) => {
                 current_row = Vec::new();
@@ -39,10 +41,13 @@ pub fn extract_tables_from_events(events: &[Event]) -> Vec<Table> {
                     in_table_cell = false;
                 }
             }
-            Event::End(Container::TableRow { .. }) => {
+            Event::End(Container::TableRow { head }) => {
                 if !current_row.is_empty()
                     && let Some((ref mut rows, _)) = current_table
                 {
+                    if rows.is_empty() {
+                        first_row_is_head = *head;
+                    }
                     rows.push(std::mem::take(&mut current_row));
                 }
                 current_row = Vec::new();
@@ -56,6 +61,9 @@ pub fn extract_tables_from_events(events: &[Event]) -> Vec<Table> {
                         cells,
                         markdown,
                         page_number: idx + 1,
+                        name: None,
+                        has_header: first_row_is_head,
+                        cell_spans: Vec::new(),
                     });
                     table_index += 1;
                 }
@@ -128,6 +128,11 @@ impl DocumentExtractor for DocExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -242,6 +242,9 @@ fn parse_docbook_single_pass(content: &str) -> Result<DocBookParseResult> {
                                 cells: current_table.clone(),
                                 markdown,
                                 page_number: table_index + 1,
+                                name: None,
+                                has_header: false,
+                                cell_spans: Vec::new(),
                             });
                             table_index += 1;
                             current_table.clear();
@@ -409,6 +412,11 @@ impl DocumentExtractor for DocbookExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -5,17 +5,36 @@
 //! Supports: Microsoft Word (.docx)
 
 use crate::Result;
-use crate::core::config::ExtractionConfig;
-use crate::extraction::{cells_to_markdown, office_metadata};
+use crate::core::config::{ExtractionConfig, OutputMode};
+use crate::extraction::office_metadata;
 use crate::plugins::{DocumentExtractor, Plugin};
-#[cfg(feature = "tokio-runtime")]
-use crate::types::PageBoundary;
-use crate::types::{ExtractionResult, Metadata, PageInfo, PageStructure, PageUnitType, Table};
+use crate::types::{
+    CellSpan, ExtractionResult, Metadata, OutlineNode, PageBoundary, PageInfo, PageStructure, PageUnitType, Table,
+    TableMarkdownOptions,
+};
 use ahash::AHashMap;
 use async_trait::async_trait;
 use std::borrow::Cow;
 use std::io::Cursor;
 
+/// Text, tables, page breaks and outline parsed from a DOCX body.
+struct DocxBody {
+    text: String,
+    tables: Vec<Table>,
+    /// Boundaries from explicit page breaks (None when estimating pages)
+    page_boundaries: Option<Vec<PageBoundary>>,
+    /// Offsets into `text` needed to estimate pages (empty unless estimating pages)
+    anchors: DocxAnchors,
+    outline: Option<Vec<OutlineNode>>,
+}
+
+/// Byte offsets into the rendered text of explicit page breaks and of each table.
+#[derive(Default)]
+struct DocxAnchors {
+    page_breaks: Vec<usize>,
+    tables: Vec<usize>,
+}
+
 /// High-performance DOCX extractor.
 ///
 /// This extractor provides:
@@ -64,6 +83,11 @@ impl Plugin for DocxExtractor {
 
 /// Convert parsed DOCX table to Kreuzberg Table struct with markdown representation.
 ///
+/// Merged cells are laid out on the table grid: a cell spanning several columns
+/// (`w:gridSpan`) is followed by empty cells, and the cells below a vertically merged
+/// cell (`w:vMerge`) keep their (usually empty) content. Both are recorded in
+/// `cell_spans`.
+///
 /// # Arguments
 /// * `docx_table` - The parsed DOCX table
 /// * `table_index` - Index of the table in the document (used as page_number)
@@ -71,38 +95,195 @@ impl Plugin for DocxExtractor {
 /// # Returns
 /// * `Table` - Converted table with cells and markdown representation
 fn convert_docx_table_to_table(docx_table: &crate::extraction::docx::parser::Table, table_index: usize) -> Table {
-    let cells: Vec<Vec<String>> = docx_table
-        .rows
-        .iter()
-        .map(|row| {
-            row.cells
-                .iter()
-                .map(|cell| {
-                    cell.paragraphs
-                        .iter()
-                        .map(|para| para.runs_to_markdown())
-                        .collect::<Vec<_>>()
-                        .join(" ")
-                        .trim()
-                        .to_string()
-                })
-                .collect()
-        })
-        .collect();
+    use crate::extraction::docx::parser::VerticalMerge;
+
+    let mut cells: Vec<Vec<String>> = Vec::with_capacity(docx_table.rows.len());
+    let mut cell_spans: Vec<CellSpan> = Vec::new();
+    // Grid column -> index in `cell_spans` of the vertical merge continuing into the next row
+    let mut open_merges: AHashMap<usize, usize> = AHashMap::new();
+
+    for (row_idx, row) in docx_table.rows.iter().enumerate() {
+        let mut grid_row = Vec::with_capacity(row.cells.len());
+        let mut row_merges = AHashMap::new();
+
+        for cell in &row.cells {
+            let col = grid_row.len();
+            let col_span = cell.grid_span.unwrap_or(1).max(1);
+            grid_row.push(
+                cell.paragraphs
+                    .iter()
+                    .map(|para| para.runs_to_markdown())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .trim()
+                    .to_string(),
+            );
+            grid_row.resize(col + col_span, String::new());
+
+            match (cell.v_merge, open_merges.get(&col)) {
+                (Some(VerticalMerge::Continue), Some(&span_idx)) => {
+                    cell_spans[span_idx].row_span += 1;
+                    row_merges.insert(col, span_idx);
+                }
+                (v_merge, _) => {
+                    if v_merge == Some(VerticalMerge::Restart) {
+                        row_merges.insert(col, cell_spans.len());
+                    }
+                    if v_merge == Some(VerticalMerge::Restart) || col_span > 1 {
+                        cell_spans.push(CellSpan {
+                            row: row_idx,
+                            col,
+                            row_span: 1,
+                            col_span,
+                        });
+                    }
+                }
+            }
+        }
 
-    let markdown = cells_to_markdown(&cells);
+        open_merges = row_merges;
+        cells.push(grid_row);
+    }
+    cell_spans.retain(|span| span.row_span > 1 || span.col_span > 1);
 
-    Table {
+    let mut table = Table {
         cells,
-        markdown,
+        markdown: String::new(),
         page_number: table_index + 1,
+        name: None,
+        has_header: first_row_is_header(docx_table),
+        cell_spans,
+    };
+    table.markdown = table.to_markdown(TableMarkdownOptions::default());
+    table
+}
+
+/// Whether the first row of a DOCX table is a header row.
+///
+/// It is when it is marked as a repeated header row (`w:tblHeader`), or when all of its
+/// text is bold while some text in later rows is not.
+fn first_row_is_header(docx_table: &crate::extraction::docx::parser::Table) -> bool {
+    use crate::extraction::docx::parser::{Run, TableRow};
+
+    fn text_runs(row: &TableRow) -> impl Iterator<Item = &Run> {
+        row.cells
+            .iter()
+            .flat_map(|cell| &cell.paragraphs)
+            .flat_map(|para| &para.runs)
+            .filter(|run| !run.text.trim().is_empty())
     }
+
+    let Some((first, rest)) = docx_table.rows.split_first() else {
+        return false;
+    };
+    if first.is_header {
+        return true;
+    }
+
+    let mut first_runs = text_runs(first).peekable();
+    first_runs.peek().is_some()
+        && first_runs.all(|run| run.bold)
+        && rest.iter().flat_map(text_runs).any(|run| !run.bold)
+}
+
+/// Parse a DOCX body into markdown text, tables, page breaks and outline.
+///
+/// With `estimate_pages`, explicit page breaks and table positions are returned as offsets
+/// into the text instead of as page boundaries, so pages can be estimated later.
+fn parse_docx_body(
+    content: &[u8],
+    preserve_list_markers: bool,
+    extract_outline: bool,
+    estimate_pages: bool,
+) -> Result<DocxBody> {
+    use crate::extraction::docx::parser::DocumentElement;
+
+    let doc = crate::extraction::docx::parser::parse_document(content)?;
+
+    let (text, element_offsets) = doc.to_markdown_with_offsets(preserve_list_markers);
+    let outline = extract_outline.then(|| doc.outline());
+
+    let tables: Vec<Table> = doc
+        .tables
+        .iter()
+        .enumerate()
+        .map(|(idx, table)| convert_docx_table_to_table(table, idx))
+        .collect();
+
+    let (page_boundaries, anchors) = if estimate_pages {
+        let anchors = DocxAnchors {
+            page_breaks: doc
+                .page_breaks
+                .iter()
+                .filter_map(|&index| element_offsets.get(index).copied())
+                .collect(),
+            tables: doc
+                .elements
+                .iter()
+                .zip(&element_offsets)
+                .filter(|(element, _)| matches!(element, DocumentElement::Table(_)))
+                .map(|(_, &offset)| offset)
+                .collect(),
+        };
+        (None, anchors)
+    } else {
+        let page_boundaries = crate::extraction::docx::detect_page_breaks_from_docx(content)?;
+        (page_boundaries, DocxAnchors::default())
+    };
+
+    Ok(DocxBody {
+        text,
+        tables,
+        page_boundaries,
+        anchors,
+        outline,
+    })
+}
+
+/// Convert markdown to plain text, remapping `anchors` into the plain text.
+///
+/// The markdown is converted piecewise between anchors, which always sit at the start of
+/// a block, so each anchor lands exactly on the start of the same block in the output.
+fn markdown_to_plain_text_with_anchors(markdown: &str, anchors: DocxAnchors) -> (String, DocxAnchors) {
+    if anchors.page_breaks.is_empty() && anchors.tables.is_empty() {
+        return (crate::text::markdown_to_plain_text(markdown), anchors);
+    }
+
+    let mut cuts: Vec<usize> = anchors.page_breaks.iter().chain(&anchors.tables).copied().collect();
+    cuts.sort_unstable();
+    cuts.dedup();
+
+    let mut text = String::with_capacity(markdown.len());
+    let mut segment_starts = Vec::with_capacity(cuts.len() + 1);
+    let mut segment_start = 0;
+    for segment_end in cuts.iter().copied().chain(std::iter::once(markdown.len())) {
+        let segment = crate::text::markdown_to_plain_text(&markdown[segment_start..segment_end]);
+        if !segment.is_empty() && !text.is_empty() {
+            let blank_line = markdown[..segment_start].ends_with("\n\n");
+            text.push_str(if blank_line { "\n\n" } else { "\n" });
+        }
+        segment_starts.push((segment_start, text.len()));
+        text.push_str(&segment);
+        segment_start = segment_end;
+    }
+
+    let remap = |offset: &usize| {
+        segment_starts
+            .iter()
+            .find(|(markdown_offset, _)| markdown_offset == offset)
+            .map_or(text.len(), |&(_, plain_offset)| plain_offset)
+    };
+    let anchors = DocxAnchors {
+        page_breaks: anchors.page_breaks.iter().map(remap).collect(),
+        tables: anchors.tables.iter().map(remap).collect(),
+    };
+    (text, anchors)
 }
 
 #[async_trait]
 impl DocumentExtractor for DocxExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -112,69 +293,38 @@ impl DocumentExtractor for DocxExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let (text, tables, page_boundaries) = {
+        let preserve_list_markers = config.preserve_list_markers;
+        let extract_outline = config.extract_outline;
+        let estimate_pages = config.docx_options.as_ref().is_some_and(|docx| docx.estimate_pages);
+        let body = {
             #[cfg(feature = "tokio-runtime")]
             if crate::core::batch_mode::is_batch_mode() {
                 let content_owned = content.to_vec();
                 let span = tracing::Span::current();
-                tokio::task::spawn_blocking(
-                    move || -> crate::error::Result<(String, Vec<Table>, Option<Vec<PageBoundary>>)> {
-                        let _guard = span.entered();
-                        let doc = crate::extraction::docx::parser::parse_document(&content_owned)?;
-
-                        let text = doc.to_markdown();
-
-                        let tables: Vec<Table> = doc
-                            .tables
-                            .iter()
-                            .enumerate()
-                            .map(|(idx, table)| convert_docx_table_to_table(table, idx))
-                            .collect();
-
-                        let page_boundaries = crate::extraction::docx::detect_page_breaks_from_docx(&content_owned)?;
-
-                        Ok((text, tables, page_boundaries))
-                    },
-                )
+                tokio::task::spawn_blocking(move || -> crate::error::Result<DocxBody> {
+                    let _guard = span.entered();
+                    parse_docx_body(&content_owned, preserve_list_markers, extract_outline, estimate_pages)
+                })
                 .await
                 .map_err(|e| crate::error::KreuzbergError::parsing(format!("DOCX extraction task failed: {}", e)))??
             } else {
-                let doc = crate::extraction::docx::parser::parse_document(content)?;
-
-                let text = doc.to_markdown();
-
-                let tables: Vec<Table> = doc
-                    .tables
-                    .iter()
-                    .enumerate()
-                    .map(|(idx, table)| convert_docx_table_to_table(table, idx))
-                    .collect();
-
-                let page_boundaries = crate::extraction::docx::detect_page_breaks_from_docx(content)?;
-
-                (text, tables, page_boundaries)
+                parse_docx_body(content, preserve_list_markers, extract_outline, estimate_pages)?
             }
 
             #[cfg(not(feature = "tokio-runtime"))]
             {
-                let doc = crate::extraction::docx::parser::parse_document(content)?;
-
-                let text = doc.to_markdown();
-
-                let tables: Vec<Table> = doc
-                    .tables
-                    .iter()
-                    .enumerate()
-                    .map(|(idx, table)| convert_docx_table_to_table(table, idx))
-                    .collect();
-
-                let page_boundaries = crate::extraction::docx::detect_page_breaks_from_docx(content)?;
-
-                (text, tables, page_boundaries)
+                parse_docx_body(content, preserve_list_markers, extract_outline, estimate_pages)?
             }
         };
+        let DocxBody {
+            text,
+            mut tables,
+            page_boundaries,
+            anchors,
+            outline,
+        } = body;
 
         let mut archive = {
             #[cfg(feature = "tokio-runtime")]
@@ -206,6 +356,11 @@ impl DocumentExtractor for DocxExtractor {
             }
         };
 
+        let (text, anchors) = match config.output_mode {
+            OutputMode::PlainText => markdown_to_plain_text_with_anchors(&text, anchors),
+            OutputMode::Markdown => (text, anchors),
+        };
+
         let mut metadata_map = AHashMap::new();
         let mut parsed_keywords: Option<Vec<String>> = None;
 
@@ -307,6 +462,28 @@ impl DocumentExtractor for DocxExtractor {
             }
         }
 
+        let page_boundaries = if estimate_pages {
+            let page_count = metadata_map
+                .get("page_count")
+                .and_then(|count| count.as_u64())
+                .map(|count| count as usize);
+            let boundaries = crate::extraction::docx::estimate_page_boundaries(&text, &anchors.page_breaks, page_count);
+            for (table, offset) in tables.iter_mut().zip(&anchors.tables) {
+                table.page_number = boundaries
+                    .iter()
+                    .find(|page| *offset < page.byte_end)
+                    .or(boundaries.last())
+                    .map_or(1, |page| page.page_number);
+            }
+            metadata_map.insert(
+                Cow::Borrowed("page_boundaries_estimated"),
+                serde_json::Value::Bool(true),
+            );
+            (!boundaries.is_empty()).then_some(boundaries)
+        } else {
+            page_boundaries
+        };
+
         let page_structure = if let Some(boundaries) = page_boundaries {
             let total_count = boundaries.len();
             Some(PageStructure {
@@ -349,6 +526,11 @@ impl DocumentExtractor for DocxExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -441,5 +623,149 @@ mod tests {
         assert_eq!(result.cells[1], vec!["Alice", "30"]);
         assert!(result.markdown.contains("| Name | Age |"));
         assert!(result.markdown.contains("| Alice | 30 |"));
+        assert!(!result.has_header);
+        assert!(result.markdown.starts_with("|  |  |\n"));
+    }
+
+    #[test]
+    fn test_convert_docx_table_detects_bold_header_row() {
+        use crate::extraction::docx::parser::{Paragraph, Run, Table as DocxTable, TableCell, TableRow};
+
+        fn row(texts: &[&str], bold: bool) -> TableRow {
+            let mut row = TableRow::default();
+            for text in texts {
+                let mut para = Paragraph::new();
+                let mut run = Run::new(text.to_string());
+                run.bold = bold;
+                para.add_run(run);
+                let mut cell = TableCell::default();
+                cell.paragraphs.push(para);
+                row.cells.push(cell);
+            }
+            row
+        }
+
+        let mut table = DocxTable::new();
+        table.rows.push(row(&["Name", "Age"], true));
+        table.rows.push(row(&["Alice", "30"], false));
+        let converted = convert_docx_table_to_table(&table, 0);
+        assert!(converted.has_header);
+        assert!(converted.markdown.starts_with("| **Name** | **Age** |\n"));
+
+        let mut all_bold = DocxTable::new();
+        all_bold.rows.push(row(&["Name", "Age"], true));
+        all_bold.rows.push(row(&["Alice", "30"], true));
+        assert!(!convert_docx_table_to_table(&all_bold, 0).has_header);
+
+        let mut repeated_header = DocxTable::new();
+        repeated_header.rows.push(TableRow {
+            is_header: true,
+            ..row(&["Name", "Age"], false)
+        });
+        repeated_header.rows.push(row(&["Alice", "30"], false));
+        assert!(convert_docx_table_to_table(&repeated_header, 0).has_header);
+    }
+
+    #[tokio::test]
+    async fn test_docx_merged_cells_become_cell_spans() {
+        let docx = docx_with_body(concat!(
+            r#"<w:tbl><w:tr>"#,
+            r#"<w:tc><w:tcPr><w:vMerge w:val="restart"/></w:tcPr><w:p><w:r><w:t>a</w:t></w:r></w:p></w:tc>"#,
+            r#"<w:tc><w:tcPr><w:gridSpan w:val="2"/></w:tcPr><w:p><w:r><w:t>b</w:t></w:r></w:p></w:tc>"#,
+            r#"</w:tr><w:tr>"#,
+            r#"<w:tc><w:tcPr><w:vMerge/></w:tcPr><w:p/></w:tc>"#,
+            r#"<w:tc><w:p><w:r><w:t>c</w:t></w:r></w:p></w:tc>"#,
+            r#"<w:tc><w:p><w:r><w:t>d</w:t></w:r></w:p></w:tc>"#,
+            r#"</w:tr></w:tbl>"#,
+        ));
+
+        let result = DocxExtractor::new()
+            .extract_bytes(
+                &docx,
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+                &ExtractionConfig::default(),
+            )
+            .await
+            .unwrap();
+
+        let table = &result.tables[0];
+        assert_eq!(table.cells, vec![vec!["a", "b", ""], vec!["", "c", "d"]]);
+        assert_eq!(
+            table.cell_spans,
+            vec![
+                CellSpan {
+                    row: 0,
+                    col: 0,
+                    row_span: 2,
+                    col_span: 1,
+                },
+                CellSpan {
+                    row: 0,
+                    col: 1,
+                    row_span: 1,
+                    col_span: 2,
+                },
+            ]
+        );
+        assert!(
+            table
+                .to_html()
+                .contains(r#"<tr><td rowspan="2">a</td><td colspan="2">b</td></tr>"#)
+        );
+    }
+
+    fn docx_with_body(body: &str) -> Vec<u8> {
+        use std::io::Write;
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        zip.start_file("word/document.xml", options).unwrap();
+        write!(
+            zip,
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{body}</w:body></w:document>"#
+        )
+        .unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[tokio::test]
+    async fn test_docx_estimate_pages_uses_explicit_breaks() {
+        let docx = docx_with_body(concat!(
+            r#"<w:p><w:r><w:t>Cover</w:t></w:r><w:r><w:br w:type="page"/></w:r></w:p>"#,
+            r#"<w:p><w:r><w:t>Body</w:t></w:r></w:p>"#,
+            r#"<w:p><w:pPr><w:pageBreakBefore/></w:pPr><w:r><w:t>Chapter</w:t></w:r></w:p>"#,
+            r#"<w:tbl><w:tr><w:tc><w:p><w:r><w:t>A</w:t></w:r></w:p></w:tc>"#,
+            r#"<w:tc><w:p><w:r><w:t>B</w:t></w:r></w:p></w:tc></w:tr></w:tbl>"#,
+        ));
+        let config = ExtractionConfig {
+            output_mode: OutputMode::PlainText,
+            docx_options: Some(crate::core::config::DocxConfig { estimate_pages: true }),
+            ..Default::default()
+        };
+
+        let result = DocxExtractor::new()
+            .extract_bytes(
+                &docx,
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+                &config,
+            )
+            .await
+            .unwrap();
+
+        let boundaries = result.metadata.pages.unwrap().boundaries.unwrap();
+        let pages: Vec<&str> = boundaries
+            .iter()
+            .map(|page| result.content[page.byte_start..page.byte_end].trim())
+            .collect();
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[0], "Cover");
+        assert_eq!(pages[1], "Body");
+        assert!(pages[2].starts_with("Chapter"));
+        assert_eq!(result.tables[0].page_number, 3);
+        assert_eq!(
+            result.metadata.additional.get("page_boundaries_estimated"),
+            Some(&serde_json::Value::Bool(true))
+        );
     }
 }
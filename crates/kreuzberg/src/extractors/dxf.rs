@@ -0,0 +1,498 @@
+//! DXF (AutoCAD Drawing Exchange Format) extractor.
+//!
+//! An ASCII DXF file is a flat list of group code / value line pairs. Only the text
+//! annotations of a drawing are extracted: `TEXT` and `MTEXT` entities, from both the
+//! `ENTITIES` section and block definitions. Geometry is skipped. Binary DXF is not
+//! supported.
+//!
+//! Drawings saved before AutoCAD 2007 store text in the code page named by the
+//! `$DWGCODEPAGE` header variable rather than UTF-8. Files that are not valid UTF-8 are
+//! decoded in that code page, or in the detected encoding when none is declared; without
+//! the `quality` feature they are decoded lossily as UTF-8.
+
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::plugins::{DocumentExtractor, Plugin};
+use crate::types::{ExtractionResult, Metadata};
+use async_trait::async_trait;
+use std::borrow::Cow;
+
+/// Sentinel at the start of binary DXF files.
+const BINARY_DXF_SENTINEL: &[u8] = b"AutoCAD Binary DXF";
+
+/// A text entity read from a DXF file.
+#[derive(Debug, Default)]
+struct DxfText {
+    /// `TEXT` or `MTEXT`.
+    kind: String,
+    layer: Option<String>,
+    /// MTEXT carries text longer than 250 characters in leading group 3 chunks.
+    chunks: Vec<String>,
+    text: Option<String>,
+}
+
+impl DxfText {
+    /// Decoded text of the entity, or `None` when it is blank.
+    fn decoded(&self) -> Option<String> {
+        let text = self.text.as_deref()?;
+        let decoded = if self.kind == "MTEXT" {
+            let raw: String = self.chunks.iter().map(String::as_str).chain([text]).collect();
+            decode_mtext(&raw)
+        } else {
+            decode_unicode_escapes(text)
+        };
+        let decoded = decode_special_codes(&decoded);
+
+        let lines: Vec<&str> = decoded.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+}
+
+/// Text annotations read from a DXF drawing.
+struct DxfContent {
+    /// One entry per non-blank text entity, in file order.
+    texts: Vec<String>,
+    /// Layers of the text entities, in order of first appearance.
+    layers: Vec<String>,
+}
+
+/// DXF extractor.
+///
+/// Outputs the text of each `TEXT`/`MTEXT` entity on its own line (MTEXT paragraphs on
+/// separate lines) and records the layers those entities are on in metadata.
+pub struct DxfExtractor;
+
+impl DxfExtractor {
+    /// Create a new DXF extractor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for DxfExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for DxfExtractor {
+    fn name(&self) -> &str {
+        "dxf-extractor"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn description(&self) -> &str {
+        "Extracts TEXT and MTEXT annotations from ASCII DXF drawings"
+    }
+
+    fn author(&self) -> &str {
+        "Kreuzberg Team"
+    }
+}
+
+#[async_trait]
+impl DocumentExtractor for DxfExtractor {
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, content, _config),
+        fields(
+            extractor.name = self.name(),
+            content.size_bytes = content.len(),
+        )
+    ))]
+    async fn extract_bytes(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        _config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let dxf = parse_dxf(content)?;
+
+        let mut additional = ahash::AHashMap::new();
+        additional.insert(
+            Cow::Borrowed("text_entity_count"),
+            serde_json::Value::Number(dxf.texts.len().into()),
+        );
+        additional.insert(
+            Cow::Borrowed("layers"),
+            serde_json::Value::Array(dxf.layers.into_iter().map(serde_json::Value::String).collect()),
+        );
+
+        Ok(ExtractionResult {
+            content: dxf.texts.join("\n"),
+            mime_type: mime_type.to_string().into(),
+            metadata: Metadata {
+                additional,
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            djot_content: None,
+            elements: None,
+            ocr_elements: None,
+            document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
+        })
+    }
+
+    fn supported_mime_types(&self) -> &[&str] {
+        &["image/vnd.dxf", "application/dxf"]
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+}
+
+fn parse_dxf(content: &[u8]) -> Result<DxfContent> {
+    if content.starts_with(BINARY_DXF_SENTINEL) {
+        return Err(crate::KreuzbergError::parsing(
+            "Binary DXF is not supported; save the drawing as ASCII DXF",
+        ));
+    }
+
+    let text = decode_dxf(content);
+    let mut lines = text.lines();
+
+    let mut texts = Vec::new();
+    let mut layers: Vec<String> = Vec::new();
+    let mut current: Option<DxfText> = None;
+
+    while let Some(code_line) = lines.next() {
+        let Some(value) = lines.next() else {
+            break;
+        };
+        let code: i32 = code_line
+            .trim()
+            .parse()
+            .map_err(|_| crate::KreuzbergError::parsing(format!("Invalid DXF group code: {:?}", code_line.trim())))?;
+
+        if code == 0 {
+            if let Some(entity) = current.take()
+                && let Some(decoded) = entity.decoded()
+            {
+                if let Some(layer) = entity.layer
+                    && !layers.contains(&layer)
+                {
+                    layers.push(layer);
+                }
+                texts.push(decoded);
+            }
+
+            let kind = value.trim();
+            if kind == "EOF" {
+                break;
+            }
+            if kind == "TEXT" || kind == "MTEXT" {
+                current = Some(DxfText {
+                    kind: kind.to_string(),
+                    ..Default::default()
+                });
+            }
+            continue;
+        }
+
+        if let Some(entity) = current.as_mut() {
+            match code {
+                1 => entity.text = Some(value.to_string()),
+                3 => entity.chunks.push(value.to_string()),
+                8 => entity.layer = Some(value.trim().to_string()).filter(|layer| !layer.is_empty()),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(DxfContent { texts, layers })
+}
+
+/// Decode DXF bytes to text, see the module docs.
+fn decode_dxf(content: &[u8]) -> Cow<'_, str> {
+    if let Ok(text) = std::str::from_utf8(content) {
+        return Cow::Borrowed(text);
+    }
+
+    #[cfg(feature = "quality")]
+    {
+        if let Some(encoding) =
+            declared_code_page(content).and_then(|label| encoding_rs::Encoding::for_label(label.as_bytes()))
+        {
+            let (text, _, _) = encoding.decode(content);
+            return Cow::Owned(text.into_owned());
+        }
+        Cow::Owned(crate::utils::safe_decode(content, None))
+    }
+    #[cfg(not(feature = "quality"))]
+    String::from_utf8_lossy(content)
+}
+
+/// Encoding label for the code page in the `$DWGCODEPAGE` header variable, e.g.
+/// `windows-1252` for `ANSI_1252`.
+#[cfg(feature = "quality")]
+fn declared_code_page(content: &[u8]) -> Option<&'static str> {
+    let mut lines = content
+        .split(|&byte| byte == b'\n')
+        .map(|line| line.trim_ascii())
+        .take_while(|line| *line != b"ENTITIES");
+    lines.find(|line| *line == b"$DWGCODEPAGE")?;
+    let _group_code = lines.next()?;
+
+    Some(match lines.next()? {
+        b"ANSI_874" => "windows-874",
+        b"ANSI_932" => "shift_jis",
+        b"ANSI_936" => "gbk",
+        b"ANSI_949" => "euc-kr",
+        b"ANSI_950" => "big5",
+        b"ANSI_1250" => "windows-1250",
+        b"ANSI_1251" => "windows-1251",
+        b"ANSI_1252" => "windows-1252",
+        b"ANSI_1253" => "windows-1253",
+        b"ANSI_1254" => "windows-1254",
+        b"ANSI_1255" => "windows-1255",
+        b"ANSI_1256" => "windows-1256",
+        b"ANSI_1257" => "windows-1257",
+        b"ANSI_1258" => "windows-1258",
+        _ => return None,
+    })
+}
+
+/// Strip MTEXT inline formatting, turning paragraph breaks (`\P`) into newlines.
+///
+/// Font, height, color and similar property codes (`\f...;`, `\H2.5;`) are dropped,
+/// toggles (`\L`, `\O`, `\K`) and grouping braces are removed, and stacked fractions
+/// (`\S1^2;`) become `1/2`.
+fn decode_mtext(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' => {}
+            '\\' => match chars.next() {
+                Some('P' | 'X') => out.push('\n'),
+                Some('~') => out.push(' '),
+                Some(escaped @ ('\\' | '{' | '}')) => out.push(escaped),
+                Some('L' | 'l' | 'O' | 'o' | 'K' | 'k' | 'N') => {}
+                Some('U') if chars.peek() == Some(&'+') => {
+                    chars.next();
+                    push_unicode_escape(&mut out, &mut chars);
+                }
+                Some('S') => {
+                    let stacked: String = chars.by_ref().take_while(|&c| c != ';').collect();
+                    out.push_str(&stacked.replace(['^', '#'], "/"));
+                }
+                Some(_) => {
+                    // Property codes such as \f, \H, \C, \A, \Q, \T, \W and \p run to ';'.
+                    for c in chars.by_ref() {
+                        if c == ';' {
+                            break;
+                        }
+                    }
+                }
+                None => {}
+            },
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Replace `\U+XXXX` escapes, used by pre-2007 DXF files for non-ASCII characters.
+fn decode_unicode_escapes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.peek() == Some(&'U') {
+            chars.next();
+            if chars.peek() == Some(&'+') {
+                chars.next();
+                push_unicode_escape(&mut out, &mut chars);
+            } else {
+                out.push_str("\\U");
+            }
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+/// Push the character for the four hex digits following `\U+`, or the escape verbatim
+/// when the digits are invalid.
+fn push_unicode_escape(out: &mut String, chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+    let hex: String = chars.clone().take(4).collect();
+    match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+        Some(decoded) if hex.len() == 4 => {
+            out.push(decoded);
+            for _ in 0..4 {
+                chars.next();
+            }
+        }
+        _ => out.push_str("\\U+"),
+    }
+}
+
+/// Replace AutoCAD `%%` control codes: `%%d` (°), `%%p` (±), `%%c` (⌀) and `%%%` (%).
+/// Underline and overline toggles (`%%u`, `%%o`) are removed.
+fn decode_special_codes(text: &str) -> String {
+    if !text.contains("%%") {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(index) = rest.find("%%") {
+        out.push_str(&rest[..index]);
+        let after = &rest[index + 2..];
+        let mut code = after.chars();
+        match code.next().map(|c| c.to_ascii_lowercase()) {
+            Some('d') => out.push('°'),
+            Some('p') => out.push('±'),
+            Some('c') => out.push('⌀'),
+            Some('%') => out.push('%'),
+            Some('u' | 'o') => {}
+            _ => {
+                out.push_str("%%");
+                rest = after;
+                continue;
+            }
+        }
+        rest = code.as_str();
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dxf(pairs: &[(i32, &str)]) -> Vec<u8> {
+        pairs
+            .iter()
+            .map(|(code, value)| format!("{code:>3}\r\n{value}\r\n"))
+            .collect::<String>()
+            .into_bytes()
+    }
+
+    #[tokio::test]
+    async fn test_dxf_extracts_text_and_mtext_with_layers() {
+        let content = dxf(&[
+            (0, "SECTION"),
+            (2, "ENTITIES"),
+            (0, "LINE"),
+            (8, "GEOMETRY"),
+            (10, "0.0"),
+            (0, "TEXT"),
+            (8, "NOTES"),
+            (10, "1.0"),
+            (20, "2.0"),
+            (1, "Bore %%c12 %%p0.05"),
+            (0, "MTEXT"),
+            (8, "TITLE"),
+            (1, "{\\fArial|b1;PART 42}\\PMaterial: \\LSteel\\l"),
+            (0, "TEXT"),
+            (8, "NOTES"),
+            (1, "   "),
+            (0, "ENDSEC"),
+            (0, "EOF"),
+        ]);
+
+        let extractor = DxfExtractor::new();
+        let result = extractor
+            .extract_bytes(&content, "image/vnd.dxf", &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.content, "Bore ⌀12 ±0.05\nPART 42\nMaterial: Steel");
+        assert_eq!(
+            result.metadata.additional.get("layers"),
+            Some(&serde_json::json!(["NOTES", "TITLE"]))
+        );
+        assert_eq!(
+            result.metadata.additional.get("text_entity_count"),
+            Some(&serde_json::json!(2))
+        );
+    }
+
+    #[test]
+    fn test_mtext_joins_continuation_chunks() {
+        let content = dxf(&[
+            (0, "MTEXT"),
+            (3, "Long note "),
+            (3, "continues "),
+            (1, "here"),
+            (0, "EOF"),
+        ]);
+        let parsed = parse_dxf(&content).unwrap();
+        assert_eq!(parsed.texts, vec!["Long note continues here"]);
+        assert!(parsed.layers.is_empty());
+    }
+
+    #[test]
+    fn test_decode_mtext_formatting() {
+        assert_eq!(decode_mtext("\\H2.5;Fit \\S1^2; in\\~max"), "Fit 1/2 in max");
+        assert_eq!(decode_mtext("Caf\\U+00E9 \\{x\\}"), "Café {x}");
+    }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn test_dxf_decodes_declared_code_page() {
+        let mut content = dxf(&[
+            (0, "SECTION"),
+            (2, "HEADER"),
+            (9, "$DWGCODEPAGE"),
+            (3, "ANSI_1251"),
+            (0, "ENDSEC"),
+            (0, "TEXT"),
+        ]);
+        // "Привет" in windows-1251.
+        content.extend_from_slice(b"  1\n\xcf\xf0\xe8\xe2\xe5\xf2\n  0\nEOF\n");
+
+        let parsed = parse_dxf(&content).unwrap();
+        assert_eq!(parsed.texts, vec!["Привет"]);
+    }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn test_dxf_detects_undeclared_encoding() {
+        let mut content = dxf(&[(0, "TEXT")]);
+        // "Café crème brûlée" in windows-1252.
+        content.extend_from_slice(b"  1\nCaf\xe9 cr\xe8me br\xfbl\xe9e\n  0\nEOF\n");
+
+        let parsed = parse_dxf(&content).unwrap();
+        assert_eq!(parsed.texts, vec!["Café crème brûlée"]);
+    }
+
+    #[test]
+    fn test_binary_dxf_is_parsing_error() {
+        let result = parse_dxf(b"AutoCAD Binary DXF\r\n\x1a\x00");
+        assert!(matches!(result, Err(crate::KreuzbergError::Parsing { .. })));
+    }
+
+    #[test]
+    fn test_dxf_plugin_interface() {
+        let extractor = DxfExtractor::new();
+        assert_eq!(extractor.name(), "dxf-extractor");
+        assert_eq!(extractor.supported_mime_types(), &["image/vnd.dxf", "application/dxf"]);
+    }
+}
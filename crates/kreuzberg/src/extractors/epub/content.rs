@@ -4,44 +4,55 @@
 //! with markdown conversion and HTML cleaning utilities.
 
 use crate::Result;
+use crate::types::PageBoundary;
 use std::io::Cursor;
 use zip::ZipArchive;
 
 use super::metadata::parse_opf;
 use super::parsing::{read_file_from_zip, resolve_path};
 
-/// Extract text content from an EPUB document by reading in spine order
+/// Extract text content from an EPUB document by reading in spine order.
+///
+/// Returns the concatenated text together with one boundary per non-empty
+/// spine item (chapter), numbered from 1 in reading order.
 pub(super) fn extract_content(
     archive: &mut ZipArchive<Cursor<Vec<u8>>>,
     opf_path: &str,
     manifest_dir: &str,
-) -> Result<String> {
+) -> Result<(String, Vec<PageBoundary>)> {
     let opf_xml = read_file_from_zip(archive, opf_path)?;
     let (_, spine_hrefs) = parse_opf(&opf_xml)?;
 
     let mut content = String::new();
+    let mut chapters = Vec::new();
 
-    for (index, href) in spine_hrefs.iter().enumerate() {
+    for href in &spine_hrefs {
         let file_path = resolve_path(manifest_dir, href);
 
-        match read_file_from_zip(archive, &file_path) {
-            Ok(xhtml_content) => {
-                let text = extract_text_from_xhtml(&xhtml_content);
-                if !text.is_empty() {
-                    if index > 0 && !content.ends_with('\n') {
-                        content.push('\n');
-                    }
-                    content.push_str(&text);
-                    content.push('\n');
-                }
-            }
-            Err(_) => {
-                continue;
-            }
+        let Ok(xhtml_content) = read_file_from_zip(archive, &file_path) else {
+            continue;
+        };
+
+        let text = extract_text_from_xhtml(&xhtml_content);
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
         }
+
+        if !content.is_empty() {
+            content.push_str("\n\n");
+        }
+
+        let byte_start = content.len();
+        content.push_str(text);
+        chapters.push(PageBoundary {
+            byte_start,
+            byte_end: content.len(),
+            page_number: chapters.len() + 1,
+        });
     }
 
-    Ok(content.trim().to_string())
+    Ok((content, chapters))
 }
 
 /// Extract text from XHTML content using html-to-markdown-rs
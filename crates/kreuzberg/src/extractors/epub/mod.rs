@@ -3,7 +3,7 @@
 //! This extractor provides native Rust-based EPUB extraction without GPL-licensed
 //! dependencies, extracting:
 //! - Metadata from OPF (Open Packaging Format) using Dublin Core standards
-//! - Content from XHTML files in spine order
+//! - Content from XHTML files in spine order, with one page boundary per chapter
 //! - Proper handling of EPUB2 and EPUB3 formats
 //!
 //! Uses only permissive-licensed crates:
@@ -18,7 +18,7 @@ mod parsing;
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata};
+use crate::types::{ExtractionResult, Metadata, PageBoundary, PageContent, PageInfo, PageStructure, PageUnitType};
 use ahash::AHashMap;
 use async_trait::async_trait;
 use std::borrow::Cow;
@@ -74,13 +74,67 @@ impl Plugin for EpubExtractor {
     }
 }
 
+/// Build the chapter page structure and optional per-chapter content.
+///
+/// Chapters reuse the page-boundary mechanism so that chunking and page
+/// extraction work on spine items the same way they do on PDF pages.
+fn build_chapter_pages(
+    content: &str,
+    chapters: Vec<PageBoundary>,
+    extract_pages: bool,
+) -> (Option<PageStructure>, Option<Vec<PageContent>>) {
+    if chapters.is_empty() {
+        return (None, None);
+    }
+
+    let page_contents = extract_pages.then(|| {
+        chapters
+            .iter()
+            .map(|chapter| {
+                let text = content[chapter.byte_start..chapter.byte_end].to_string();
+                let is_blank = Some(crate::extraction::blank_detection::is_page_text_blank(&text));
+                PageContent {
+                    page_number: chapter.page_number,
+                    content: text,
+                    tables: Vec::new(),
+                    images: Vec::new(),
+                    hierarchy: None,
+                    is_blank,
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let page_structure = PageStructure {
+        total_count: chapters.len(),
+        unit_type: PageUnitType::Chapter,
+        pages: Some(
+            chapters
+                .iter()
+                .map(|chapter| PageInfo {
+                    number: chapter.page_number,
+                    title: None,
+                    dimensions: None,
+                    image_count: None,
+                    table_count: None,
+                    hidden: None,
+                    is_blank: None,
+                })
+                .collect(),
+        ),
+        boundaries: Some(chapters),
+    };
+
+    (Some(page_structure), page_contents)
+}
+
 #[cfg(feature = "office")]
 #[async_trait]
 impl DocumentExtractor for EpubExtractor {
     #[cfg_attr(
         feature = "otel",
         tracing::instrument(
-            skip(self, content, _config),
+            skip(self, content, config),
             fields(
                 extractor.name = self.name(),
                 content.size_bytes = content.len(),
@@ -91,7 +145,7 @@ impl DocumentExtractor for EpubExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let cursor = Cursor::new(content.to_vec());
 
@@ -111,7 +165,9 @@ impl DocumentExtractor for EpubExtractor {
 
         let opf_xml = read_file_from_zip(&mut archive, &opf_path)?;
 
-        let extracted_content = extract_content(&mut archive, &opf_path, &manifest_dir)?;
+        let (extracted_content, chapters) = extract_content(&mut archive, &opf_path, &manifest_dir)?;
+        let extract_pages = config.pages.as_ref().is_some_and(|p| p.extract_pages);
+        let (page_structure, page_contents) = build_chapter_pages(&extracted_content, chapters, extract_pages);
 
         let (epub_metadata, additional_metadata) = extract_metadata(&opf_xml)?;
         let metadata_map: AHashMap<Cow<'static, str>, serde_json::Value> = additional_metadata
@@ -127,10 +183,11 @@ impl DocumentExtractor for EpubExtractor {
                 authors: epub_metadata.creator.map(|c| vec![c]),
                 language: epub_metadata.language,
                 created_at: epub_metadata.date,
+                pages: page_structure,
                 additional: metadata_map,
                 ..Default::default()
             },
-            pages: None,
+            pages: page_contents,
             tables: vec![],
             detected_languages: None,
             chunks: None,
@@ -139,6 +196,11 @@ impl DocumentExtractor for EpubExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -181,6 +243,41 @@ mod tests {
         assert!(extractor.shutdown().is_ok());
     }
 
+    #[test]
+    fn test_build_chapter_pages() {
+        let content = "Chapter one\n\nChapter two";
+        let chapters = vec![
+            PageBoundary {
+                byte_start: 0,
+                byte_end: 11,
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: 13,
+                byte_end: 24,
+                page_number: 2,
+            },
+        ];
+
+        let (structure, pages) = build_chapter_pages(content, chapters, true);
+        let structure = structure.expect("chapters should produce a page structure");
+        assert_eq!(structure.total_count, 2);
+        assert_eq!(structure.unit_type, PageUnitType::Chapter);
+        assert_eq!(structure.boundaries.as_ref().map(Vec::len), Some(2));
+
+        let pages = pages.expect("extract_pages should produce page contents");
+        assert_eq!(pages[0].content, "Chapter one");
+        assert_eq!(pages[1].content, "Chapter two");
+        assert_eq!(pages[1].page_number, 2);
+    }
+
+    #[test]
+    fn test_build_chapter_pages_empty() {
+        let (structure, pages) = build_chapter_pages("", Vec::new(), true);
+        assert!(structure.is_none());
+        assert!(pages.is_none());
+    }
+
     #[test]
     fn test_epub_extractor_supported_mime_types() {
         let extractor = EpubExtractor::new();
@@ -42,10 +42,19 @@ impl ExcelExtractor {
             if let Some(cells) = &sheet.table_cells
                 && !cells.is_empty()
             {
+                let has_header = crate::types::tables::first_row_looks_like_header(cells);
+                let markdown = if has_header {
+                    sheet.markdown.clone()
+                } else {
+                    headerless_sheet_markdown(&sheet.markdown, cells[0].len())
+                };
                 tables.push(Table {
                     cells: cells.clone(),
-                    markdown: sheet.markdown.clone(),
+                    markdown,
                     page_number: sheet_index + 1,
+                    name: Some(sheet.name.clone()),
+                    has_header,
+                    cell_spans: Vec::new(),
                 });
             }
         }
@@ -54,6 +63,36 @@ impl ExcelExtractor {
     }
 }
 
+/// Rewrite a sheet's Markdown so its first row is a body row under an empty header.
+///
+/// Sheet Markdown is a `## name` heading followed by a table whose first row is the
+/// header; Markdown without a table is returned unchanged.
+fn headerless_sheet_markdown(markdown: &str, col_count: usize) -> String {
+    let Some((heading, table)) = markdown.split_once("\n\n") else {
+        return markdown.to_string();
+    };
+    let mut lines = table.splitn(3, '\n');
+    let (Some(first_row), Some(separator)) = (lines.next(), lines.next()) else {
+        return markdown.to_string();
+    };
+    if !first_row.starts_with('|') || !separator.starts_with("| ---") {
+        return markdown.to_string();
+    }
+    let rest = lines.next().unwrap_or_default();
+
+    let mut result = String::with_capacity(markdown.len() + col_count * 3 + 2);
+    result.push_str(heading);
+    result.push_str("\n\n|");
+    result.push_str(&"  |".repeat(col_count));
+    result.push('\n');
+    result.push_str(separator);
+    result.push('\n');
+    result.push_str(first_row);
+    result.push('\n');
+    result.push_str(rest);
+    result
+}
+
 impl Plugin for ExcelExtractor {
     fn name(&self) -> &str {
         "excel-extractor"
@@ -75,7 +114,7 @@ impl Plugin for ExcelExtractor {
 #[async_trait]
 impl DocumentExtractor for ExcelExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -85,7 +124,7 @@ impl DocumentExtractor for ExcelExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let extension = match mime_type {
             "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => ".xlsx",
@@ -99,18 +138,19 @@ impl DocumentExtractor for ExcelExtractor {
             _ => ".xlsx",
         };
 
+        let xlsx_config = config.xlsx_options.clone().unwrap_or_default();
         let workbook = if crate::core::batch_mode::is_batch_mode() {
             let content_owned = content.to_vec();
             let extension_owned = extension.to_string();
             let span = tracing::Span::current();
             tokio::task::spawn_blocking(move || {
                 let _guard = span.entered();
-                crate::extraction::excel::read_excel_bytes(&content_owned, &extension_owned)
+                crate::extraction::excel::read_excel_bytes_with_config(&content_owned, &extension_owned, &xlsx_config)
             })
             .await
             .map_err(|e| crate::error::KreuzbergError::parsing(format!("Excel extraction task failed: {}", e)))??
         } else {
-            crate::extraction::excel::read_excel_bytes(content, extension)?
+            crate::extraction::excel::read_excel_bytes_with_config(content, extension, &xlsx_config)?
         };
 
         let text_content = crate::extraction::excel::excel_to_text(&workbook);
@@ -120,6 +160,7 @@ impl DocumentExtractor for ExcelExtractor {
         let excel_metadata = ExcelMetadata {
             sheet_count: workbook.sheets.len(),
             sheet_names,
+            hidden_sheets: workbook.hidden_sheets.clone(),
         };
 
         let mut additional = AHashMap::new();
@@ -146,21 +187,27 @@ impl DocumentExtractor for ExcelExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, path, _config),
+        skip(self, path, config),
         fields(
             extractor.name = self.name(),
         )
     ))]
-    async fn extract_file(&self, path: &Path, mime_type: &str, _config: &ExtractionConfig) -> Result<ExtractionResult> {
+    async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
         let path_str = path
             .to_str()
             .ok_or_else(|| crate::KreuzbergError::validation("Invalid file path".to_string()))?;
 
-        let workbook = crate::extraction::excel::read_excel_file(path_str)?;
+        let xlsx_config = config.xlsx_options.clone().unwrap_or_default();
+        let workbook = crate::extraction::excel::read_excel_file_with_config(path_str, &xlsx_config)?;
         let text_content = crate::extraction::excel::excel_to_text(&workbook);
         let tables = Self::sheets_to_tables(&workbook);
 
@@ -168,6 +215,7 @@ impl DocumentExtractor for ExcelExtractor {
         let excel_metadata = ExcelMetadata {
             sheet_count: workbook.sheets.len(),
             sheet_names,
+            hidden_sheets: workbook.hidden_sheets.clone(),
         };
 
         let mut additional = AHashMap::new();
@@ -194,6 +242,11 @@ impl DocumentExtractor for ExcelExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -264,6 +317,7 @@ mod tests {
         let workbook = crate::types::ExcelWorkbook {
             sheets: vec![sheet],
             metadata: HashMap::new(),
+            hidden_sheets: vec![],
         };
 
         let tables = ExcelExtractor::sheets_to_tables(&workbook);
@@ -274,6 +328,39 @@ mod tests {
         assert_eq!(tables[0].cells[0], vec!["Name", "Age", "City"]);
         assert_eq!(tables[0].cells[1], vec!["Alice", "30", "NYC"]);
         assert_eq!(tables[0].cells[2], vec!["Bob", "25", "LA"]);
+        assert!(tables[0].has_header);
+        assert_eq!(tables[0].markdown, workbook.sheets[0].markdown);
+    }
+
+    #[test]
+    fn test_sheets_to_tables_headerless_sheet() {
+        use crate::types::ExcelSheet;
+        use std::collections::HashMap;
+
+        let sheet = ExcelSheet {
+            name: "Readings".to_string(),
+            markdown: "## Readings\n\n| 1 | 2.5 |\n| --- | --- |\n| 2 | 3.5 |\n".to_string(),
+            row_count: 2,
+            col_count: 2,
+            cell_count: 4,
+            table_cells: Some(vec![
+                vec!["1".to_string(), "2.5".to_string()],
+                vec!["2".to_string(), "3.5".to_string()],
+            ]),
+        };
+
+        let workbook = crate::types::ExcelWorkbook {
+            sheets: vec![sheet],
+            metadata: HashMap::new(),
+            hidden_sheets: vec![],
+        };
+
+        let tables = ExcelExtractor::sheets_to_tables(&workbook);
+        assert!(!tables[0].has_header);
+        assert_eq!(
+            tables[0].markdown,
+            "## Readings\n\n|  |  |\n| --- | --- |\n| 1 | 2.5 |\n| 2 | 3.5 |\n"
+        );
     }
 
     #[test]
@@ -293,6 +380,7 @@ mod tests {
         let workbook = crate::types::ExcelWorkbook {
             sheets: vec![sheet],
             metadata: HashMap::new(),
+            hidden_sheets: vec![],
         };
 
         let tables = ExcelExtractor::sheets_to_tables(&workbook);
@@ -343,6 +431,7 @@ mod tests {
         let workbook = crate::types::ExcelWorkbook {
             sheets: vec![sheet1, sheet2],
             metadata: HashMap::new(),
+            hidden_sheets: vec![],
         };
 
         let tables = ExcelExtractor::sheets_to_tables(&workbook);
@@ -350,6 +439,8 @@ mod tests {
         assert_eq!(tables.len(), 2);
         assert_eq!(tables[0].page_number, 1);
         assert_eq!(tables[1].page_number, 2);
+        assert_eq!(tables[0].name.as_deref(), Some("Sheet1"));
+        assert_eq!(tables[1].name.as_deref(), Some("Sheet2"));
     }
 
     #[test]
@@ -380,6 +471,7 @@ mod tests {
         let workbook = crate::types::ExcelWorkbook {
             sheets: vec![sheet],
             metadata: HashMap::new(),
+            hidden_sheets: vec![],
         };
 
         let tables = ExcelExtractor::sheets_to_tables(&workbook);
@@ -1,7 +1,9 @@
 //! Shared frontmatter and metadata utilities for markup extractors.
 //!
 //! This module provides common functionality for extractors that process
-//! documents with YAML frontmatter (Markdown, Djot, etc.).
+//! documents with frontmatter (Markdown, Djot, etc.). Both YAML (`---`) and
+//! TOML (`+++`) frontmatter are supported; TOML is converted to a YAML value so
+//! metadata mapping is shared.
 //!
 //! This is a core module used by the Djot extractor (always available) and
 //! the enhanced Markdown extractor (requires `office` feature).
@@ -18,6 +20,10 @@ use std::borrow::Cow;
 /// - `---` appearing within YAML strings or arrays
 /// - Both `---` and `...` as end delimiters (YAML spec compliant)
 /// - Multiline YAML values containing dashes
+/// - `\n` and `\r\n` line endings
+///
+/// TOML frontmatter delimited by `+++` is also accepted and returned as an equivalent YAML value.
+/// Frontmatter that fails to parse is left in place and treated as content.
 ///
 /// Returns a tuple of (parsed YAML value, remaining content after frontmatter).
 ///
@@ -30,6 +36,10 @@ use std::borrow::Cow;
 /// assert!(remaining.contains("# Content"));
 /// ```
 pub fn extract_frontmatter(content: &str) -> (Option<YamlValue>, String) {
+    if content.starts_with("+++") {
+        return extract_toml_frontmatter(content);
+    }
+
     // Frontmatter must start at the beginning of the document
     if !content.starts_with("---") {
         return (None, content.to_string());
@@ -56,8 +66,8 @@ pub fn extract_frontmatter(content: &str) -> (Option<YamlValue>, String) {
         let remaining = &rest[after_newline..];
         if remaining.starts_with("---") || remaining.starts_with("...") {
             // Verify it's on its own line (followed by newline or EOF)
-            let delimiter_end = after_newline + 3;
-            if delimiter_end >= rest.len() || rest.as_bytes()[delimiter_end] == b'\n' {
+            let line_end = &rest[after_newline + 3..];
+            if line_end.is_empty() || line_end.starts_with('\n') || line_end.starts_with("\r\n") {
                 end_pos = Some(absolute_pos);
                 break;
             }
@@ -68,26 +78,12 @@ pub fn extract_frontmatter(content: &str) -> (Option<YamlValue>, String) {
 
     if let Some(end) = end_pos {
         let frontmatter_str = &rest[..end];
-        // Skip past the closing delimiter and any following newline
-        let after_delimiter = end + 1; // Skip the newline before delimiter
-        let remaining_start = if after_delimiter + 3 < rest.len() {
-            // Skip "---" or "..."
-            let after_delim = after_delimiter + 3;
-            // Skip trailing newline after delimiter if present
-            if after_delim < rest.len() && rest.as_bytes()[after_delim] == b'\n' {
-                after_delim + 1
-            } else {
-                after_delim
-            }
-        } else {
-            rest.len()
-        };
-
-        let remaining = if remaining_start < rest.len() {
-            &rest[remaining_start..]
-        } else {
-            ""
-        };
+        // Skip the newline before the delimiter, the "---" or "...", and any newline after it
+        let after_delimiter = &rest[(end + 1 + 3).min(rest.len())..];
+        let remaining = after_delimiter
+            .strip_prefix("\r\n")
+            .or_else(|| after_delimiter.strip_prefix('\n'))
+            .unwrap_or(after_delimiter);
 
         // Try to parse the frontmatter as YAML
         match serde_yaml_ng::from_str::<YamlValue>(frontmatter_str) {
@@ -100,12 +96,76 @@ pub fn extract_frontmatter(content: &str) -> (Option<YamlValue>, String) {
     }
 }
 
+/// Extract TOML frontmatter delimited by `+++` lines.
+fn extract_toml_frontmatter(content: &str) -> (Option<YamlValue>, String) {
+    let Some(rest) = content
+        .strip_prefix("+++\n")
+        .or_else(|| content.strip_prefix("+++\r\n"))
+    else {
+        return (None, content.to_string());
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end_matches(['\r', '\n']) == "+++" {
+            let frontmatter_str = &rest[..offset];
+            let remaining = &rest[offset + line.len()..];
+
+            return match toml::from_str::<toml::Table>(frontmatter_str) {
+                Ok(table) => (Some(toml_to_yaml(toml::Value::Table(table))), remaining.to_string()),
+                Err(_) => (None, content.to_string()),
+            };
+        }
+        offset += line.len();
+    }
+
+    // No closing delimiter found
+    (None, content.to_string())
+}
+
+/// Convert a TOML value into the equivalent YAML value.
+///
+/// Datetimes become strings in their TOML (RFC 3339) representation.
+fn toml_to_yaml(value: toml::Value) -> YamlValue {
+    match value {
+        toml::Value::String(s) => YamlValue::String(s),
+        toml::Value::Integer(i) => YamlValue::Number(i.into()),
+        toml::Value::Float(f) => YamlValue::Number(f.into()),
+        toml::Value::Boolean(b) => YamlValue::Bool(b),
+        toml::Value::Datetime(dt) => YamlValue::String(dt.to_string()),
+        toml::Value::Array(items) => YamlValue::Sequence(items.into_iter().map(toml_to_yaml).collect()),
+        toml::Value::Table(table) => YamlValue::Mapping(
+            table
+                .into_iter()
+                .map(|(key, value)| (YamlValue::String(key), toml_to_yaml(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Frontmatter keys mapped to dedicated metadata fields by [`extract_metadata_from_yaml`].
+const KNOWN_FRONTMATTER_KEYS: &[&str] = &[
+    "title",
+    "author",
+    "date",
+    "keywords",
+    "description",
+    "abstract",
+    "subject",
+    "category",
+    "tags",
+    "language",
+    "version",
+];
+
 /// Extract metadata from YAML frontmatter.
 ///
 /// Extracts the following YAML fields into Kreuzberg metadata:
 /// - **Standard fields**: title, author, date, description (as subject)
 /// - **Extended fields**: abstract, subject, category, tags, language, version
 /// - **Array fields** (keywords, tags): converted to comma-separated strings
+/// - **Custom fields**: any other top-level key is stored as `frontmatter.<key>`,
+///   keeping its structure (arrays, nested tables, numbers)
 ///
 /// # Arguments
 ///
@@ -202,6 +262,23 @@ pub fn extract_metadata_from_yaml(yaml: &YamlValue) -> Metadata {
         metadata.additional.insert(Cow::Borrowed("version"), version.into());
     }
 
+    // Custom fields
+    if let Some(mapping) = yaml.as_mapping() {
+        for (key, value) in mapping {
+            let Some(key) = key.as_str() else {
+                continue;
+            };
+            if KNOWN_FRONTMATTER_KEYS.contains(&key) {
+                continue;
+            }
+            if let Ok(json_value) = serde_json::to_value(value) {
+                metadata
+                    .additional
+                    .insert(Cow::Owned(format!("frontmatter.{}", key)), json_value);
+            }
+        }
+    }
+
     metadata
 }
 
@@ -376,6 +453,89 @@ mod tests {
         assert_eq!(remaining, content);
     }
 
+    #[test]
+    fn test_toml_frontmatter() {
+        let content =
+            "+++\ntitle = \"Test\"\ndate = 2024-01-15\ntags = [\"rust\", \"docs\"]\ndraft = true\n+++\n\n# Content";
+        let (yaml, remaining) = extract_frontmatter(content);
+
+        assert!(yaml.is_some());
+        assert_eq!(remaining, "\n# Content");
+
+        let metadata = extract_metadata_from_yaml(&yaml.unwrap());
+        assert_eq!(metadata.additional.get("title").and_then(|v| v.as_str()), Some("Test"));
+        assert_eq!(metadata.created_at.as_deref(), Some("2024-01-15"));
+        assert_eq!(
+            metadata.additional.get("tags").and_then(|v| v.as_str()),
+            Some("rust, docs")
+        );
+        assert_eq!(
+            metadata.additional.get("frontmatter.draft"),
+            Some(&serde_json::Value::Bool(true))
+        );
+    }
+
+    #[test]
+    fn test_crlf_frontmatter() {
+        let (yaml, remaining) = extract_frontmatter("---\r\ntitle: Test\r\n---\r\n\r\n# Content");
+        assert_eq!(remaining, "\r\n# Content");
+        let metadata = extract_metadata_from_yaml(&yaml.unwrap());
+        assert_eq!(metadata.additional.get("title").and_then(|v| v.as_str()), Some("Test"));
+
+        let (yaml, remaining) = extract_frontmatter("+++\r\ntitle = \"Test\"\r\n+++\r\n# Content");
+        assert_eq!(remaining, "# Content");
+        let metadata = extract_metadata_from_yaml(&yaml.unwrap());
+        assert_eq!(metadata.additional.get("title").and_then(|v| v.as_str()), Some("Test"));
+    }
+
+    #[test]
+    fn test_malformed_toml_frontmatter_is_content() {
+        let content = "+++\ntitle = = broken\n+++\n\n# Content";
+        let (yaml, remaining) = extract_frontmatter(content);
+
+        assert!(yaml.is_none());
+        assert_eq!(remaining, content);
+    }
+
+    #[test]
+    fn test_unterminated_toml_frontmatter_is_content() {
+        let content = "+++\ntitle = \"Test\"\n\n# Content";
+        let (yaml, remaining) = extract_frontmatter(content);
+
+        assert!(yaml.is_none());
+        assert_eq!(remaining, content);
+    }
+
+    #[test]
+    fn test_malformed_yaml_frontmatter_is_content() {
+        let content = "---\ntitle: [unclosed\n---\n\n# Content";
+        let (yaml, remaining) = extract_frontmatter(content);
+
+        assert!(yaml.is_none());
+        assert_eq!(remaining, content);
+    }
+
+    #[test]
+    fn test_custom_frontmatter_fields_are_namespaced() {
+        let content = "---\ntitle: Test\nslug: hello-world\nweight: 3\nseo:\n  noindex: true\n---\nBody";
+        let (yaml, _) = extract_frontmatter(content);
+        let metadata = extract_metadata_from_yaml(&yaml.unwrap());
+
+        assert_eq!(
+            metadata.additional.get("frontmatter.slug").and_then(|v| v.as_str()),
+            Some("hello-world")
+        );
+        assert_eq!(
+            metadata.additional.get("frontmatter.weight").and_then(|v| v.as_i64()),
+            Some(3)
+        );
+        assert_eq!(
+            metadata.additional["frontmatter.seo"]["noindex"],
+            serde_json::Value::Bool(true)
+        );
+        assert!(!metadata.additional.contains_key("frontmatter.title"));
+    }
+
     #[test]
     fn test_extract_title_from_content() {
         let content = "# My Document\n\nContent here";
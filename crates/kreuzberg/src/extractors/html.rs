@@ -1,12 +1,13 @@
 //! HTML document extractor.
 
 use crate::Result;
-use crate::core::config::{ExtractionConfig, OutputFormat};
+use crate::core::config::{ExtractionConfig, ImageExtractionConfig, OutputFormat, OutputMode, TableDetection};
 use crate::extractors::SyncExtractor;
 use crate::plugins::{DocumentExtractor, Plugin};
 use crate::text::utf8_validation;
-use crate::types::{ExtractionResult, Metadata, Table};
+use crate::types::{ExtractedImage, ExtractionResult, Metadata, Table};
 use async_trait::async_trait;
+use std::borrow::Cow;
 #[cfg(feature = "tokio-runtime")]
 use std::path::Path;
 
@@ -27,6 +28,108 @@ impl HtmlExtractor {
     }
 }
 
+/// Per-image size limit for decoded inline images when no byte budget is configured.
+const DEFAULT_MAX_INLINE_IMAGE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Decode images embedded in the HTML (base64 `data:` URIs and inline SVG) into `ExtractedImage`s.
+///
+/// The decoding conversion pass runs over the image markup only, so the document itself is
+/// converted once, and it is skipped when the document has no inline images. Images larger
+/// than `max_total_image_bytes` (or 10 MiB when unset) are skipped by the decoder, as are
+/// images smaller than `min_width`/`min_height`; `max_images` and `max_total_image_bytes`
+/// then cap the collection. Returns the images and whether the limits cut extraction short.
+fn extract_inline_images(
+    html: &str,
+    config: &ExtractionConfig,
+    limits: &ImageExtractionConfig,
+) -> Result<(Vec<ExtractedImage>, bool)> {
+    let Some(markup) = crate::extraction::html::inline_image_markup(html) else {
+        return Ok((Vec::new(), false));
+    };
+    let max_image_size = limits
+        .max_total_image_bytes
+        .map_or(DEFAULT_MAX_INLINE_IMAGE_BYTES, |max| max as u64);
+    let extraction = crate::extraction::html::process_html(
+        &markup,
+        config.html_options.clone(),
+        true,
+        max_image_size,
+        config.output_format,
+    )?;
+
+    let mut images = Vec::with_capacity(extraction.images.len());
+    let mut extracted_bytes = 0usize;
+    for image in extraction.images {
+        let dimensions = image
+            .dimensions
+            .or_else(|| crate::extraction::image_header::detect_image_dimensions(&image.data));
+        if dimensions.is_some_and(|(width, height)| !limits.meets_min_dimensions(width, height)) {
+            continue;
+        }
+        if !limits.allows_image(images.len(), extracted_bytes, image.data.len()) {
+            return Ok((images, true));
+        }
+        extracted_bytes += image.data.len();
+
+        // The converter reports the `alt` attribute (or `title` without one) as the description.
+        let alt_text = image.description.clone();
+
+        images.push(ExtractedImage {
+            data: image.data,
+            format: Cow::Owned(image.format),
+            image_index: images.len(),
+            page_number: None,
+            width: dimensions.map(|(width, _)| width),
+            height: dimensions.map(|(_, height)| height),
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: image.description,
+            alt_text,
+            page_position: None,
+            caption: None,
+            ocr_result: None,
+        });
+    }
+
+    Ok((images, false))
+}
+
+/// Run OCR on extracted inline images with the configured OCR backend.
+///
+/// SVG images and images larger than `images.max_decode_dimension` are skipped. OCR failures
+/// leave `ocr_result` unset rather than failing extraction.
+#[cfg(feature = "ocr")]
+async fn ocr_inline_images(images: &mut [ExtractedImage], config: &ExtractionConfig) -> Result<()> {
+    let Some(ocr_config) = config.ocr.as_ref() else {
+        return Ok(());
+    };
+
+    let mut backends = crate::ocr::OcrBackendChain::from_config(ocr_config)?;
+
+    let mut ocr_config = ocr_config.clone();
+    ocr_config.output_format = Some(config.output_format);
+
+    for image in images.iter_mut().filter(|image| image.format != "svg") {
+        if let Some(images_config) = config.images.as_ref()
+            && let Err(e) = images_config.check_decode_dimensions(&image.data)
+        {
+            tracing::debug!("Skipping OCR of inline HTML image {}: {}", image.image_index, e);
+            continue;
+        }
+        match backends.process_image(&image.data, &ocr_config).await {
+            Ok(mut result) => {
+                result.metadata.ocr_used = true;
+                result.metadata.ocr_backend = Some(backends.backend_name().to_string());
+                image.ocr_result = Some(Box::new(result));
+            }
+            Err(e) => tracing::debug!("OCR of inline HTML image {} failed: {}", image.image_index, e),
+        }
+    }
+
+    Ok(())
+}
+
 /// Extract all tables from pre-converted markdown content.
 ///
 /// Parses markdown pipe-delimited format to extract table structures.
@@ -58,11 +161,14 @@ fn parse_markdown_tables(markdown: &str) -> Vec<Table> {
             && let Some((cells, end_idx)) = extract_markdown_table(&lines, i)
             && !cells.is_empty()
         {
-            let markdown_table = reconstruct_markdown_table(&cells);
+            let markdown_table = reconstruct_markdown_table(&cells, false);
             tables.push(Table {
                 cells,
                 markdown: markdown_table,
                 page_number: table_index + 1,
+                name: None,
+                has_header: false,
+                cell_spans: Vec::new(),
             });
             table_index += 1;
             i = end_idx;
@@ -149,14 +255,16 @@ fn is_markdown_table_separator(line: &str) -> bool {
 /// Reconstruct markdown table from cells.
 ///
 /// Takes parsed table cells and creates a properly formatted markdown table string.
-fn reconstruct_markdown_table(cells: &[Vec<String>]) -> String {
-    if cells.is_empty() {
+fn reconstruct_markdown_table(cells: &[Vec<String>], has_header: bool) -> String {
+    let Some(first) = cells.first() else {
         return String::new();
-    }
+    };
 
     let mut markdown = String::new();
 
-    for (row_idx, row) in cells.iter().enumerate() {
+    // Without a header row, the first row is rendered as a body row under an empty header.
+    let empty_header = (!has_header).then(|| vec![String::new(); first.len()]);
+    for (row_idx, row) in empty_header.iter().chain(cells).enumerate() {
         markdown.push('|');
         for cell in row {
             markdown.push(' ');
@@ -201,17 +309,71 @@ impl SyncExtractor for HtmlExtractor {
         let html = utf8_validation::from_utf8(content)
             .map(|s| s.to_string())
             .unwrap_or_else(|_| String::from_utf8_lossy(content).to_string());
+        crate::extraction::html::check_nesting_depth(&html, config.max_nesting_depth())?;
+        // Offsets refer to the document as given, before layout tables and alt text are
+        // rewritten below.
+        let source_text = config
+            .emit_source_offsets
+            .then(|| crate::extraction::html::html_source_text(&html));
+        let html = match config.table_options.map(|options| options.html_table_detection) {
+            Some(TableDetection::DataTables) => crate::extraction::html::unwrap_layout_tables(&html).into_owned(),
+            _ => html,
+        };
+        let html = if config.html_include_alt_text {
+            let include_image_alt = !matches!(config.output_format, OutputFormat::Markdown | OutputFormat::Djot);
+            crate::extraction::html::inline_accessibility_text(&html, include_image_alt).into_owned()
+        } else {
+            html
+        };
 
-        let (content_text, html_metadata) = crate::extraction::html::convert_html_to_markdown_with_metadata(
+        let (mut content_text, html_metadata) = crate::extraction::html::convert_html_to_markdown_with_metadata(
             &html,
             config.html_options.clone(),
             Some(config.output_format),
         )?;
 
-        let tables = extract_html_tables(&content_text)?;
+        let mut tables = extract_html_tables(&content_text)?;
+        // Tables are recovered from the converted Markdown; only trust the header flags
+        // when every HTML table maps to exactly one of them.
+        let first_row_headers = crate::extraction::html::first_row_headers(&html);
+        if first_row_headers.len() == tables.len() {
+            for (table, has_header) in tables.iter_mut().zip(first_row_headers) {
+                if has_header {
+                    table.has_header = true;
+                    table.markdown = reconstruct_markdown_table(&table.cells, true);
+                }
+            }
+        }
+
+        // Djot output is not Markdown, so plain text mode only applies to Markdown renderings.
+        let plain_text = config.output_mode == OutputMode::PlainText && config.output_format != OutputFormat::Djot;
+        if plain_text {
+            content_text = crate::text::markdown_to_plain_text(&content_text);
+        }
+
+        let mut metadata = Metadata {
+            format: html_metadata.map(|m| crate::types::FormatMetadata::Html(Box::new(m))),
+            ..Default::default()
+        };
+
+        let images = match config.images.as_ref().filter(|images| images.extract_images) {
+            Some(limits) => {
+                let (images, truncated) = extract_inline_images(&html, config, limits)?;
+                if truncated {
+                    metadata
+                        .additional
+                        .insert(Cow::Borrowed("images_truncated"), serde_json::json!(true));
+                }
+                Some(images)
+            }
+            None => None,
+        };
+
+        let source_map = source_text.map(|texts| crate::types::source_map::align_source_text(&content_text, texts));
 
         // Set mime_type based on actual output format
         let result_mime_type = match config.output_format {
+            OutputFormat::Markdown if plain_text => mime_type,
             OutputFormat::Markdown => "text/markdown",
             OutputFormat::Djot => "text/djot",
             _ => mime_type, // Preserve original mime_type for other formats
@@ -220,19 +382,21 @@ impl SyncExtractor for HtmlExtractor {
         Ok(ExtractionResult {
             content: content_text,
             mime_type: result_mime_type.to_string().into(),
-            metadata: Metadata {
-                format: html_metadata.map(|m| crate::types::FormatMetadata::Html(Box::new(m))),
-                ..Default::default()
-            },
+            metadata,
             pages: None,
             tables,
             detected_languages: None,
             chunks: None,
-            images: None,
+            images,
             djot_content: None,
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map,
+            references: None,
         })
     }
 }
@@ -252,7 +416,17 @@ impl DocumentExtractor for HtmlExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        self.extract_sync(content, mime_type, config)
+        #[cfg_attr(not(feature = "ocr"), allow(unused_mut))]
+        let mut result = self.extract_sync(content, mime_type, config)?;
+
+        #[cfg(feature = "ocr")]
+        if config.force_ocr
+            && let Some(images) = result.images.as_mut()
+        {
+            ocr_inline_images(images, config).await?;
+        }
+
+        Ok(result)
     }
 
     #[cfg(feature = "tokio-runtime")]
@@ -490,4 +664,208 @@ mod tests {
         assert_eq!(pipeline_result.content, original_content);
         assert_eq!(pipeline_result.mime_type, "text/djot");
     }
+
+    const ONE_PIXEL_PNG: &str =
+        "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAAAAAA6fptVAAAACklEQVR4nGNgAAAAAgABSK+kcQAAAABJRU5ErkJggg==";
+
+    /// Image extraction with no minimum size, so the 1x1 fixture images are kept.
+    fn image_config(max_images: Option<usize>) -> ExtractionConfig {
+        ExtractionConfig {
+            images: Some(ImageExtractionConfig {
+                extract_images: true,
+                target_dpi: 300,
+                max_image_dimension: 4096,
+                max_decode_dimension: 32768,
+                auto_adjust_dpi: true,
+                min_dpi: 72,
+                max_dpi: 600,
+                max_images,
+                max_total_image_bytes: None,
+                min_width: 0,
+                min_height: 0,
+                output_format: crate::core::config::ImageFormat::Original,
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn html_with_data_uri_images(count: usize) -> String {
+        let images: String = (0..count)
+            .map(|i| format!(r#"<img src="data:image/png;base64,{ONE_PIXEL_PNG}" alt="figure {i}">"#))
+            .collect();
+        format!("<html><body><p>Report</p>{images}</body></html>")
+    }
+
+    #[tokio::test]
+    async fn test_html_extractor_decodes_data_uri_images() {
+        let html = html_with_data_uri_images(2);
+        let result = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &image_config(None))
+            .await
+            .unwrap();
+
+        let images = result.images.expect("images should be extracted");
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0].format, "png");
+        assert_eq!(images[1].image_index, 1);
+        assert_eq!(images[0].alt_text.as_deref(), Some("figure 0"));
+        assert!(images[0].data.starts_with(b"\x89PNG"));
+        assert!(result.content.contains("Report"));
+    }
+
+    #[tokio::test]
+    async fn test_html_extractor_images_disabled_by_default() {
+        let html = html_with_data_uri_images(1);
+        let result = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert!(result.images.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_html_extractor_data_uri_images_respect_max_images() {
+        let html = html_with_data_uri_images(3);
+        let result = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &image_config(Some(1)))
+            .await
+            .unwrap();
+
+        assert_eq!(result.images.unwrap().len(), 1);
+        assert_eq!(
+            result.metadata.additional.get("images_truncated"),
+            Some(&serde_json::json!(true))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_html_extractor_skips_images_below_min_dimensions() {
+        let html = html_with_data_uri_images(2);
+        let mut config = image_config(None);
+        if let Some(images) = config.images.as_mut() {
+            images.min_width = 16;
+            images.min_height = 16;
+        }
+
+        let result = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &config)
+            .await
+            .unwrap();
+
+        assert!(result.images.unwrap().is_empty());
+        assert!(!result.metadata.additional.contains_key("images_truncated"));
+    }
+
+    #[tokio::test]
+    async fn test_html_extractor_keeps_alt_text_and_aria_labels() {
+        let html = r#"<html><body>
+            <p>Welcome <img src="logo.png" alt="Acme Corp logo"></p>
+            <button aria-label="Close dialog">&times;</button>
+            <a href="/search" aria-label="Search the catalogue"><img src="search.svg" alt=""></a>
+        </body></html>"#;
+
+        let result = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &ExtractionConfig::default())
+            .await
+            .unwrap();
+        assert!(result.content.contains("[alt: Acme Corp logo]"), "{}", result.content);
+        assert!(result.content.contains("Close dialog"), "{}", result.content);
+        assert!(result.content.contains("Search the catalogue"), "{}", result.content);
+
+        let config = ExtractionConfig {
+            html_include_alt_text: false,
+            ..Default::default()
+        };
+        let result = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &config)
+            .await
+            .unwrap();
+        assert!(!result.content.contains("[alt: Acme Corp logo]"), "{}", result.content);
+        assert!(!result.content.contains("Close dialog"));
+    }
+
+    #[tokio::test]
+    async fn test_html_extractor_marks_th_first_row_as_header() {
+        let html = "<table><tr><th>Name</th><th>Age</th></tr><tr><td>Alice</td><td>30</td></tr></table>";
+
+        let result = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &ExtractionConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(result.tables.len(), 1);
+        assert!(result.tables[0].has_header);
+        assert!(!result.tables[0].markdown.starts_with("|  |"));
+    }
+
+    #[tokio::test]
+    async fn test_html_extractor_renders_headerless_tables_under_empty_header() {
+        let html = "<table><tr><td><b>Name</b></td><td><b>Age</b></td></tr><tr><td>Alice</td><td>30</td></tr></table>\
+                    <table><tr><td>Bob</td><td>25</td></tr><tr><td>Carol</td><td>41</td></tr></table>";
+
+        let result = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &ExtractionConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(result.tables.len(), 2);
+        assert!(result.tables[0].has_header);
+        assert!(!result.tables[0].markdown.starts_with("|  |"));
+        assert!(!result.tables[1].has_header);
+        assert!(
+            result.tables[1]
+                .markdown
+                .starts_with("|  |  |\n|------|------|\n| Bob | 25 |\n")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_html_extractor_emits_source_map() {
+        let html = "<html><body><h1>Report</h1><p>Sales grew by <b>ten</b> percent.</p></body></html>";
+        let config = ExtractionConfig {
+            emit_source_offsets: true,
+            ..Default::default()
+        };
+
+        let result = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &config)
+            .await
+            .unwrap();
+        let source_map = result.source_map.expect("source map requested");
+        let pairs: Vec<(&str, &str)> = source_map
+            .iter()
+            .map(|span| {
+                (
+                    &result.content[span.content_start..span.content_end],
+                    &html[span.source_start..span.source_end],
+                )
+            })
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("Report", "Report"),
+                ("Sales grew by", "Sales grew by"),
+                ("ten", "ten"),
+                ("percent.", "percent."),
+            ]
+        );
+
+        let result = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &ExtractionConfig::default())
+            .await
+            .unwrap();
+        assert!(result.source_map.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_html_extractor_rejects_pathologically_nested_html() {
+        let depth = 100_000;
+        let html = format!("{}deep{}", "<div>".repeat(depth), "</div>".repeat(depth));
+
+        let err = HtmlExtractor::new()
+            .extract_bytes(html.as_bytes(), "text/html", &ExtractionConfig::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::KreuzbergError::Validation { .. }), "{}", err);
+    }
 }
@@ -2,7 +2,7 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
-use crate::extraction::image::extract_image_metadata;
+use crate::extraction::image::extract_image_metadata_with_limits;
 use crate::plugins::{DocumentExtractor, Plugin};
 use crate::types::{ExtractionResult, Metadata};
 use async_trait::async_trait;
@@ -28,27 +28,21 @@ impl ImageExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        use crate::plugins::registry::get_ocr_backend_registry;
-
         let ocr_config = config.ocr.as_ref().ok_or_else(|| crate::KreuzbergError::Parsing {
             message: "OCR config required for image OCR".to_string(),
             source: None,
         })?;
 
-        let backend = {
-            let registry = get_ocr_backend_registry();
-            let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
-                message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
-                plugin_name: "ocr-registry".to_string(),
-            })?;
-            registry.get(&ocr_config.backend)?
-        };
+        let mut backends = crate::ocr::OcrBackendChain::from_config(ocr_config)?;
+
+        let auto_language_config = crate::ocr::auto_language::resolve_auto_language(content, ocr_config).await;
+        let ocr_language = auto_language_config.as_ref().map(|c| c.language.clone());
 
         // Thread output_format from ExtractionConfig to OcrConfig
-        let mut ocr_config_with_format = ocr_config.clone();
+        let mut ocr_config_with_format = auto_language_config.unwrap_or_else(|| ocr_config.clone());
         ocr_config_with_format.output_format = Some(config.output_format);
 
-        let ocr_result = backend.process_image(content, &ocr_config_with_format).await?;
+        let ocr_result = backends.process_page(content, &ocr_config_with_format).await?;
 
         let ocr_text = ocr_result.content.clone();
         let ocr_extraction_result = crate::extraction::image::extract_text_from_image_with_ocr(
@@ -61,6 +55,26 @@ impl ImageExtractor {
         let mut result = ocr_result;
         result.content = ocr_extraction_result.content;
         result.pages = ocr_extraction_result.page_contents;
+        result.metadata.ocr_used = true;
+        result.metadata.ocr_language = ocr_language;
+        result.metadata.ocr_backend = Some(backends.backend_name().to_string());
+
+        if let Some(floor) = ocr_config.empty_page_confidence {
+            use crate::ocr::confidence::{OCR_CONFIDENCE_NORMALIZED_KEY, OCR_EMPTY_PAGES_KEY, is_empty_page};
+
+            let normalized = result
+                .metadata
+                .additional
+                .get(OCR_CONFIDENCE_NORMALIZED_KEY)
+                .and_then(serde_json::Value::as_f64);
+            if is_empty_page(&result.content, normalized, floor) {
+                tracing::warn!("OCR found no text in image");
+                result
+                    .metadata
+                    .additional
+                    .insert(std::borrow::Cow::Borrowed(OCR_EMPTY_PAGES_KEY), serde_json::json!([1]));
+            }
+        }
 
         Ok(result)
     }
@@ -113,7 +127,7 @@ impl DocumentExtractor for ImageExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let extraction_metadata = extract_image_metadata(content)?;
+        let extraction_metadata = extract_image_metadata_with_limits(content, config.max_decode_dimension())?;
 
         let image_metadata = crate::types::ImageMetadata {
             width: extraction_metadata.width,
@@ -155,6 +169,11 @@ impl DocumentExtractor for ImageExtractor {
                     elements: None,
                     ocr_elements: None,
                     document: None,
+                    outline: None,
+                    formulas: None,
+                    text_blocks: None,
+                    source_map: None,
+                    references: None,
                 });
             }
         }
@@ -178,6 +197,11 @@ impl DocumentExtractor for ImageExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -225,6 +249,22 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_image_extractor_rejects_oversized_declared_dimensions() {
+        let extractor = ImageExtractor::new();
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+        png.extend_from_slice(b"IHDR");
+        png.extend_from_slice(&60_000u32.to_be_bytes());
+        png.extend_from_slice(&60_000u32.to_be_bytes());
+        png.extend_from_slice(&[8, 6, 0, 0, 0]);
+        // The decode limit applies even without an image extraction config.
+        let config = ExtractionConfig::default();
+
+        let err = extractor.extract_bytes(&png, "image/png", &config).await.unwrap_err();
+        assert!(matches!(err, crate::KreuzbergError::Validation { .. }));
+        assert!(err.to_string().contains("60000x60000"));
+    }
+
     #[test]
     fn test_image_plugin_interface() {
         let extractor = ImageExtractor::new();
@@ -0,0 +1,356 @@
+#![cfg(feature = "office")]
+
+//! Apple iWork extractor.
+//!
+//! Supports: Pages (.pages), Numbers (.numbers)
+//!
+//! iWork documents are ZIP bundles whose content is stored as IWA archives
+//! (Snappy-compressed protobuf) in Apple's undocumented format. This extractor does not
+//! parse IWA. Instead it extracts the preview the iWork apps render into the bundle —
+//! `QuickLook/Preview.pdf`, or the `preview.jpg` first-page image, which is OCRed — along
+//! with the bundle metadata. Layout, tables and pages that are not part of the preview
+//! are not recovered, and documents saved without a preview cannot be extracted.
+
+use crate::core::config::ExtractionConfig;
+use crate::core::mime::{NUMBERS_MIME_TYPE, PAGES_MIME_TYPE};
+use crate::plugins::{DocumentExtractor, Plugin};
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use ahash::AHashMap;
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::io::{Cursor, Read};
+
+/// Full-document preview PDF written by iWork '09 and by newer apps when requested.
+const PDF_PREVIEW: &str = "QuickLook/Preview.pdf";
+
+/// First-page preview images, largest first.
+const IMAGE_PREVIEWS: &[&str] = &["preview.jpg", "QuickLook/Thumbnail.jpg"];
+
+/// Apple iWork (Pages, Numbers) extractor.
+///
+/// Extracts text from the preview embedded in the bundle and document metadata from
+/// `Metadata/Properties.plist` and `Metadata/BuildVersionHistory.plist`.
+/// See the module documentation for limitations.
+pub struct IworkExtractor;
+
+impl IworkExtractor {
+    /// Create a new iWork extractor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for IworkExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for IworkExtractor {
+    fn name(&self) -> &str {
+        "iwork-extractor"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn description(&self) -> &str {
+        "Apple Pages and Numbers extractor using the embedded document preview"
+    }
+
+    fn author(&self) -> &str {
+        "Kreuzberg Team"
+    }
+}
+
+/// Preview found in an iWork bundle.
+enum Preview {
+    Pdf(Vec<u8>),
+    Image(&'static str, Vec<u8>),
+}
+
+impl Preview {
+    fn source(&self) -> &'static str {
+        match self {
+            Preview::Pdf(_) => PDF_PREVIEW,
+            Preview::Image(name, _) => name,
+        }
+    }
+}
+
+/// Read a bundle entry, or `None` if it is missing or unreadable.
+///
+/// Returns a validation error if the entry decompresses to more than `max_size` bytes.
+fn read_entry(archive: &mut zip::ZipArchive<Cursor<&[u8]>>, name: &str, max_size: usize) -> Result<Option<Vec<u8>>> {
+    let Ok(file) = archive.by_name(name) else {
+        return Ok(None);
+    };
+
+    // Never read more than the limit, whatever the entry header claims.
+    let mut bytes = Vec::with_capacity((file.size().min(max_size as u64) as usize).min(10 * 1024 * 1024));
+    if file.take(max_size as u64 + 1).read_to_end(&mut bytes).is_err() {
+        return Ok(None);
+    }
+    if bytes.len() > max_size {
+        return Err(KreuzbergError::validation(format!(
+            "iWork bundle entry '{}' exceeds uncompressed size limit of {} bytes",
+            name, max_size
+        )));
+    }
+
+    Ok(Some(bytes))
+}
+
+/// Find the best preview in the bundle, preferring the full-document PDF.
+fn find_preview(archive: &mut zip::ZipArchive<Cursor<&[u8]>>, max_size: usize) -> Result<Option<Preview>> {
+    if let Some(bytes) = read_entry(archive, PDF_PREVIEW, max_size)? {
+        return Ok(Some(Preview::Pdf(bytes)));
+    }
+
+    for name in IMAGE_PREVIEWS {
+        if let Some(bytes) = read_entry(archive, name, max_size)? {
+            return Ok(Some(Preview::Image(name, bytes)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Read bundle metadata from the plists in `Metadata/`.
+fn extract_bundle_metadata(
+    archive: &mut zip::ZipArchive<Cursor<&[u8]>>,
+    max_size: usize,
+) -> AHashMap<Cow<'static, str>, serde_json::Value> {
+    let mut metadata = AHashMap::new();
+
+    if let Some(properties) = read_plist(archive, "Metadata/Properties.plist", max_size)
+        && let Some(properties) = properties.as_dictionary()
+    {
+        for (key, field) in [
+            ("documentUUID", "document_uuid"),
+            ("fileFormatVersion", "file_format_version"),
+        ] {
+            if let Some(value) = properties.get(key).and_then(|value| value.as_string()) {
+                metadata.insert(Cow::Borrowed(field), serde_json::Value::String(value.to_string()));
+            }
+        }
+    }
+
+    if let Some(history) = read_plist(archive, "Metadata/BuildVersionHistory.plist", max_size)
+        && let Some(history) = history.as_array()
+    {
+        let versions: Vec<serde_json::Value> = history
+            .iter()
+            .filter_map(|entry| entry.as_string())
+            .map(|entry| serde_json::Value::String(entry.to_string()))
+            .collect();
+        if !versions.is_empty() {
+            metadata.insert(Cow::Borrowed("build_versions"), serde_json::Value::Array(versions));
+        }
+    }
+
+    metadata
+}
+
+fn read_plist(archive: &mut zip::ZipArchive<Cursor<&[u8]>>, name: &str, max_size: usize) -> Option<plist::Value> {
+    // Metadata is optional; an oversized plist is skipped rather than failing the extraction.
+    let bytes = read_entry(archive, name, max_size).ok().flatten()?;
+    plist::Value::from_reader(Cursor::new(bytes)).ok()
+}
+
+/// Extract the preview PDF, OCRing it if it has no text layer.
+#[cfg(feature = "pdf")]
+async fn extract_pdf_preview(bytes: &[u8], config: &ExtractionConfig) -> Result<ExtractionResult> {
+    use crate::core::mime::PDF_MIME_TYPE;
+    use crate::extractors::PdfExtractor;
+
+    let result = PdfExtractor::new().extract_bytes(bytes, PDF_MIME_TYPE, config).await?;
+
+    #[cfg(feature = "ocr")]
+    if result.content.trim().is_empty() && !config.force_ocr {
+        let ocr_config = ExtractionConfig {
+            force_ocr: true,
+            ocr: Some(config.ocr.clone().unwrap_or_default()),
+            ..config.clone()
+        };
+        return PdfExtractor::new()
+            .extract_bytes(bytes, PDF_MIME_TYPE, &ocr_config)
+            .await;
+    }
+
+    Ok(result)
+}
+
+#[cfg(not(feature = "pdf"))]
+async fn extract_pdf_preview(_bytes: &[u8], _config: &ExtractionConfig) -> Result<ExtractionResult> {
+    Err(KreuzbergError::MissingDependency(
+        "The 'pdf' feature is required to extract iWork preview PDFs".to_string(),
+    ))
+}
+
+/// OCR the preview image, using the default OCR settings if none are configured.
+#[cfg(feature = "ocr")]
+async fn extract_image_preview(bytes: &[u8], config: &ExtractionConfig) -> Result<ExtractionResult> {
+    use crate::extractors::ImageExtractor;
+
+    let ocr_config = ExtractionConfig {
+        ocr: Some(config.ocr.clone().unwrap_or_default()),
+        ..config.clone()
+    };
+    ImageExtractor::new()
+        .extract_bytes(bytes, "image/jpeg", &ocr_config)
+        .await
+}
+
+#[cfg(not(feature = "ocr"))]
+async fn extract_image_preview(_bytes: &[u8], _config: &ExtractionConfig) -> Result<ExtractionResult> {
+    Err(KreuzbergError::MissingDependency(
+        "The 'ocr' feature is required to extract iWork documents that only contain a preview image".to_string(),
+    ))
+}
+
+#[async_trait]
+impl DocumentExtractor for IworkExtractor {
+    #[cfg_attr(
+        feature = "otel",
+        tracing::instrument(
+            skip(self, content, config),
+            fields(
+                extractor.name = self.name(),
+                content.size_bytes = content.len(),
+            )
+        )
+    )]
+    async fn extract_bytes(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let max_size = config.max_archive_size();
+        let (bundle_metadata, preview) = {
+            let mut archive = zip::ZipArchive::new(Cursor::new(content))
+                .map_err(|e| KreuzbergError::parsing(format!("Failed to open iWork bundle: {}", e)))?;
+            (
+                extract_bundle_metadata(&mut archive, max_size),
+                find_preview(&mut archive, max_size)?,
+            )
+        };
+        let preview = preview.ok_or_else(|| {
+            KreuzbergError::parsing(
+                "iWork document has no embedded preview; its content is stored in Apple's proprietary IWA \
+                 format, which Kreuzberg cannot read. Re-save the document with a preview or export it to PDF",
+            )
+        })?;
+
+        let preview_source = preview.source();
+        let mut result = match preview {
+            Preview::Pdf(bytes) => extract_pdf_preview(&bytes, config).await?,
+            Preview::Image(_, bytes) => extract_image_preview(&bytes, config).await?,
+        };
+
+        result.mime_type = mime_type.to_string().into();
+        result.metadata.additional.extend(bundle_metadata);
+        result.metadata.additional.insert(
+            Cow::Borrowed("preview_source"),
+            serde_json::Value::String(preview_source.to_string()),
+        );
+
+        Ok(result)
+    }
+
+    fn supported_mime_types(&self) -> &[&str] {
+        &[PAGES_MIME_TYPE, NUMBERS_MIME_TYPE]
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::extraction::DEFAULT_MAX_ARCHIVE_SIZE;
+    use std::io::Write;
+
+    fn bundle(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        for (name, bytes) in entries {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(bytes).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_iwork_extractor_plugin_interface() {
+        let extractor = IworkExtractor::new();
+        assert_eq!(extractor.name(), "iwork-extractor");
+        assert_eq!(extractor.supported_mime_types(), &[PAGES_MIME_TYPE, NUMBERS_MIME_TYPE]);
+    }
+
+    #[test]
+    fn test_find_preview_prefers_pdf() {
+        let bytes = bundle(&[("preview.jpg", b"jpeg"), (PDF_PREVIEW, b"%PDF-1.4")]);
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes.as_slice())).unwrap();
+        assert!(matches!(
+            find_preview(&mut archive, DEFAULT_MAX_ARCHIVE_SIZE),
+            Ok(Some(Preview::Pdf(_)))
+        ));
+
+        let bytes = bundle(&[("preview.jpg", b"jpeg")]);
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes.as_slice())).unwrap();
+        let preview = find_preview(&mut archive, DEFAULT_MAX_ARCHIVE_SIZE).unwrap().unwrap();
+        assert_eq!(preview.source(), "preview.jpg");
+    }
+
+    #[test]
+    fn test_extract_bundle_metadata() {
+        let mut properties = plist::Dictionary::new();
+        properties.insert("documentUUID".to_string(), plist::Value::from("ABC-123"));
+        properties.insert("fileFormatVersion".to_string(), plist::Value::from("14.0.1"));
+        let mut properties_bytes = Vec::new();
+        plist::Value::Dictionary(properties)
+            .to_writer_binary(&mut properties_bytes)
+            .unwrap();
+
+        let bytes = bundle(&[("Metadata/Properties.plist", &properties_bytes)]);
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes.as_slice())).unwrap();
+        let metadata = extract_bundle_metadata(&mut archive, DEFAULT_MAX_ARCHIVE_SIZE);
+
+        assert_eq!(metadata.get("document_uuid"), Some(&serde_json::json!("ABC-123")));
+        assert_eq!(metadata.get("file_format_version"), Some(&serde_json::json!("14.0.1")));
+        assert!(!metadata.contains_key("build_versions"));
+    }
+
+    #[tokio::test]
+    async fn test_bundle_without_preview_errors() {
+        let bytes = bundle(&[("Index/Document.iwa", b"iwa")]);
+        let result = IworkExtractor::new()
+            .extract_bytes(&bytes, PAGES_MIME_TYPE, &ExtractionConfig::default())
+            .await;
+        assert!(matches!(result, Err(KreuzbergError::Parsing { .. })));
+    }
+
+    #[test]
+    fn test_oversized_preview_errors() {
+        let bytes = bundle(&[(PDF_PREVIEW, &[b'%'; 64])]);
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes.as_slice())).unwrap();
+        assert!(matches!(
+            find_preview(&mut archive, 32),
+            Err(KreuzbergError::Validation { .. })
+        ));
+    }
+}
@@ -275,6 +275,9 @@ pub(super) fn extract_jats_all_in_one(content: &str) -> Result<(JatsMetadataExtr
                                 cells: current_table.clone(),
                                 markdown,
                                 page_number: table_index + 1,
+                                name: None,
+                                has_header: false,
+                                cell_spans: Vec::new(),
                             });
                             table_index += 1;
                             current_table.clear();
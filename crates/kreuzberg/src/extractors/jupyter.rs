@@ -3,8 +3,8 @@
 //! This extractor provides native Rust parsing of Jupyter notebooks,
 //! extracting:
 //! - Notebook metadata (kernelspec, language_info, nbformat)
-//! - Cell content (markdown and code cells in order)
-//! - Cell outputs (text, HTML, images)
+//! - Cell content (markdown and code cells in order, code fenced with the kernel language)
+//! - Cell outputs (text, HTML, images), filtered by [`JupyterConfig::outputs`]
 //! - Cell metadata (execution_count, tags)
 //!
 //! Requires the `office` feature.
@@ -12,7 +12,7 @@
 #[cfg(feature = "office")]
 use crate::Result;
 #[cfg(feature = "office")]
-use crate::core::config::ExtractionConfig;
+use crate::core::config::{ExtractionConfig, JupyterConfig, NotebookOutputs};
 #[cfg(feature = "office")]
 use crate::plugins::{DocumentExtractor, Plugin};
 #[cfg(feature = "office")]
@@ -26,6 +26,19 @@ use serde_json::{Value, json};
 #[cfg(feature = "office")]
 use std::borrow::Cow;
 
+/// Language of code cells when the notebook does not name its kernel language.
+#[cfg(feature = "office")]
+const DEFAULT_LANGUAGE: &str = "python";
+
+/// Per-notebook settings applied to each cell.
+#[cfg(feature = "office")]
+struct CellOptions<'a> {
+    /// Language code cells are fenced with
+    language: &'a str,
+    /// Which outputs of code cells are included
+    outputs: NotebookOutputs,
+}
+
 /// Jupyter Notebook extractor.
 ///
 /// Extracts content from Jupyter notebook JSON files, including:
@@ -44,7 +57,10 @@ impl JupyterExtractor {
     }
 
     /// Extract content from a Jupyter notebook.
-    fn extract_notebook(content: &[u8]) -> Result<(String, AHashMap<Cow<'static, str>, Value>)> {
+    fn extract_notebook(
+        content: &[u8],
+        options: &JupyterConfig,
+    ) -> Result<(String, AHashMap<Cow<'static, str>, Value>)> {
         let notebook: Value = serde_json::from_slice(content)
             .map_err(|e| crate::KreuzbergError::parsing(format!("Failed to parse JSON: {}", e)))?;
 
@@ -67,6 +83,11 @@ impl JupyterExtractor {
             }
         }
 
+        let language = Self::kernel_language(&notebook);
+        if let Some(language) = language {
+            metadata.insert(Cow::Borrowed("kernel_language"), Value::String(language.to_string()));
+        }
+
         if let Some(nbformat) = notebook.get("nbformat") {
             extracted_content.push_str(&format!("NBFormat: {}\n", nbformat));
             metadata.insert(Cow::Borrowed("nbformat"), nbformat.clone());
@@ -75,18 +96,40 @@ impl JupyterExtractor {
         extracted_content.push('\n');
 
         if let Some(cells) = notebook.get("cells").and_then(|c| c.as_array()) {
+            let cell_options = CellOptions {
+                language: language.unwrap_or(DEFAULT_LANGUAGE),
+                outputs: options.outputs,
+            };
             for (cell_idx, cell) in cells.iter().enumerate() {
-                Self::extract_cell(cell, cell_idx, &mut extracted_content, &mut metadata)?;
+                Self::extract_cell(cell, cell_idx, &cell_options, &mut extracted_content, &mut metadata)?;
             }
         }
 
         Ok((extracted_content, metadata))
     }
 
+    /// Programming language of the notebook's kernel.
+    ///
+    /// Taken from `language_info.name`, falling back to `kernelspec.language`.
+    fn kernel_language(notebook: &Value) -> Option<&str> {
+        let notebook_metadata = notebook.get("metadata")?;
+        notebook_metadata
+            .get("language_info")
+            .and_then(|info| info.get("name"))
+            .or_else(|| {
+                notebook_metadata
+                    .get("kernelspec")
+                    .and_then(|spec| spec.get("language"))
+            })
+            .and_then(|name| name.as_str())
+            .filter(|name| !name.is_empty())
+    }
+
     /// Extract content from a single cell.
     fn extract_cell(
         cell: &Value,
         cell_idx: usize,
+        options: &CellOptions<'_>,
         content: &mut String,
         _metadata: &mut AHashMap<Cow<'static, str>, Value>,
     ) -> Result<()> {
@@ -115,7 +158,7 @@ impl JupyterExtractor {
 
         match cell_type {
             "markdown" => Self::extract_markdown_cell(cell, content)?,
-            "code" => Self::extract_code_cell(cell, content)?,
+            "code" => Self::extract_code_cell(cell, options, content)?,
             "raw" => Self::extract_raw_cell(cell, content)?,
             _ => {
                 content.push_str(&format!("Unknown cell type: {}\n", cell_type));
@@ -136,7 +179,7 @@ impl JupyterExtractor {
     }
 
     /// Extract code cell content and outputs.
-    fn extract_code_cell(cell: &Value, content: &mut String) -> Result<()> {
+    fn extract_code_cell(cell: &Value, options: &CellOptions<'_>, content: &mut String) -> Result<()> {
         if let Some(exec_count) = cell.get("execution_count")
             && !exec_count.is_null()
         {
@@ -145,14 +188,16 @@ impl JupyterExtractor {
 
         if let Some(source) = cell.get("source") {
             let cell_text = Self::extract_source(source);
-            content.push_str("```python\n");
+            content.push_str(&format!("```{}\n", options.language));
             content.push_str(&cell_text);
             content.push_str("```\n");
         }
 
-        if let Some(outputs) = cell.get("outputs").and_then(|o| o.as_array()) {
+        if options.outputs != NotebookOutputs::None
+            && let Some(outputs) = cell.get("outputs").and_then(|o| o.as_array())
+        {
             for output in outputs {
-                Self::extract_output(output, content)?;
+                Self::extract_output(output, options.outputs, content)?;
             }
         }
 
@@ -180,7 +225,7 @@ impl JupyterExtractor {
     }
 
     /// Extract output from a cell.
-    fn extract_output(output: &Value, content: &mut String) -> Result<()> {
+    fn extract_output(output: &Value, outputs: NotebookOutputs, content: &mut String) -> Result<()> {
         let output_type = output.get("output_type").and_then(|t| t.as_str()).unwrap_or("unknown");
 
         content.push_str(&format!("::: {{.output .{}", output_type));
@@ -195,7 +240,7 @@ impl JupyterExtractor {
 
         match output_type {
             "stream" => Self::extract_stream_output(output, content)?,
-            "execute_result" | "display_data" => Self::extract_data_output(output, content)?,
+            "execute_result" | "display_data" => Self::extract_data_output(output, outputs, content)?,
             "error" => Self::extract_error_output(output, content)?,
             _ => {
                 content.push_str(&format!("Unknown output type: {}\n", output_type));
@@ -221,19 +266,25 @@ impl JupyterExtractor {
     }
 
     /// Extract data output (execute_result or display_data).
-    fn extract_data_output(output: &Value, content: &mut String) -> Result<()> {
+    ///
+    /// With [`NotebookOutputs::Text`] only the `text/plain` representation is kept.
+    fn extract_data_output(output: &Value, outputs: NotebookOutputs, content: &mut String) -> Result<()> {
         if let Some(data) = output.get("data").and_then(|d| d.as_object()) {
-            let mime_types = vec![
-                "text/markdown",
-                "text/html",
-                "image/svg+xml",
-                "image/png",
-                "image/jpeg",
-                "application/json",
-                "text/plain",
-            ];
-
-            for mime_type in mime_types {
+            let mime_types: &[&str] = if outputs == NotebookOutputs::Text {
+                &["text/plain"]
+            } else {
+                &[
+                    "text/markdown",
+                    "text/html",
+                    "image/svg+xml",
+                    "image/png",
+                    "image/jpeg",
+                    "application/json",
+                    "text/plain",
+                ]
+            };
+
+            for &mime_type in mime_types {
                 if let Some(mime_content) = data.get(mime_type) {
                     content.push_str(&format!("MIME: {}\n", mime_type));
                     let mime_text = Self::extract_source(mime_content);
@@ -311,7 +362,7 @@ impl DocumentExtractor for JupyterExtractor {
     #[cfg_attr(
         feature = "otel",
         tracing::instrument(
-            skip(self, content, _config),
+            skip(self, content, config),
             fields(
                 extractor.name = self.name(),
                 content.size_bytes = content.len(),
@@ -322,9 +373,10 @@ impl DocumentExtractor for JupyterExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let (extracted_content, additional_metadata) = Self::extract_notebook(content)?;
+        let options = config.jupyter_options.clone().unwrap_or_default();
+        let (extracted_content, additional_metadata) = Self::extract_notebook(content, &options)?;
 
         let mut metadata_additional = AHashMap::new();
         for (key, value) in additional_metadata {
@@ -347,6 +399,11 @@ impl DocumentExtractor for JupyterExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -129,6 +129,9 @@ pub fn process_table(content: &str, output: &mut String, tables: &mut Vec<Table>
             cells: rows,
             markdown: markdown.clone(),
             page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         };
         tables.push(table);
     }
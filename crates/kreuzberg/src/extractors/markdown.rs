@@ -1,10 +1,11 @@
-//! Enhanced Markdown extractor with YAML frontmatter support.
+//! Enhanced Markdown extractor with YAML and TOML frontmatter support.
 //!
 //! This extractor provides:
 //! - Comprehensive markdown parsing using pulldown-cmark
-//! - Complete YAML frontmatter metadata extraction:
+//! - Complete frontmatter metadata extraction (YAML `---` or TOML `+++`):
 //!   - Standard fields: title, author, date, description, keywords
 //!   - Extended fields: abstract, subject, category, tags, language, version
+//!   - Any other field under `frontmatter.<key>`
 //! - Automatic conversion of array fields (keywords, tags) to comma-separated strings
 //! - Table extraction as structured data
 //! - Heading structure preservation
@@ -51,8 +52,13 @@ impl MarkdownExtractor {
     // Frontmatter utilities moved to shared frontmatter_utils module
 
     /// Extract plain text from markdown AST.
-    fn extract_text_from_events(events: &[Event]) -> String {
+    ///
+    /// List items are placed on their own lines. With `preserve_list_markers`, they keep
+    /// a `- ` or `1. ` marker and are indented two spaces per nesting level.
+    fn extract_text_from_events(events: &[Event], preserve_list_markers: bool) -> String {
         let mut text = String::new();
+        // Next number for each open list; `None` for bullet lists
+        let mut lists: Vec<Option<u64>> = Vec::new();
         for event in events {
             match event {
                 Event::Text(s) | Event::Code(s) | Event::Html(s) => {
@@ -61,6 +67,30 @@ impl MarkdownExtractor {
                 Event::SoftBreak | Event::HardBreak => {
                     text.push('\n');
                 }
+                Event::Start(Tag::List(start)) => {
+                    Self::ensure_line_start(&mut text);
+                    lists.push(*start);
+                }
+                Event::End(TagEnd::List(_)) => {
+                    lists.pop();
+                    Self::ensure_line_start(&mut text);
+                }
+                Event::Start(Tag::Item) => {
+                    Self::ensure_line_start(&mut text);
+                    if preserve_list_markers {
+                        text.push_str(&"  ".repeat(lists.len().saturating_sub(1)));
+                        match lists.last_mut() {
+                            Some(Some(number)) => {
+                                text.push_str(&format!("{}. ", number));
+                                *number += 1;
+                            }
+                            _ => text.push_str("- "),
+                        }
+                    }
+                }
+                Event::TaskListMarker(checked) if preserve_list_markers => {
+                    text.push_str(if *checked { "[x] " } else { "[ ] " });
+                }
                 Event::Start(_) | Event::End(_) | Event::TaskListMarker(_) => {}
                 Event::FootnoteReference(s) => {
                     text.push('[');
@@ -76,6 +106,12 @@ impl MarkdownExtractor {
         text
     }
 
+    fn ensure_line_start(text: &mut String) {
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+    }
+
     /// Extract tables from markdown AST.
     fn extract_tables_from_events(events: &[Event]) -> Vec<Table> {
         let mut tables = Vec::new();
@@ -136,6 +172,10 @@ impl MarkdownExtractor {
                             cells,
                             markdown,
                             page_number: idx + 1,
+                            name: None,
+                            // GFM tables always start with a header row
+                            has_header: true,
+                            cell_spans: Vec::new(),
                         });
                         table_index += 1;
                     }
@@ -188,7 +228,7 @@ impl Plugin for MarkdownExtractor {
 #[async_trait]
 impl DocumentExtractor for MarkdownExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -198,7 +238,7 @@ impl DocumentExtractor for MarkdownExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let text = String::from_utf8_lossy(content).into_owned();
 
@@ -219,7 +259,7 @@ impl DocumentExtractor for MarkdownExtractor {
         let parser = Parser::new_ext(&remaining_content, Options::ENABLE_TABLES);
         let events: Vec<Event> = parser.collect();
 
-        let extracted_text = Self::extract_text_from_events(&events);
+        let extracted_text = Self::extract_text_from_events(&events, config.preserve_list_markers);
 
         let tables = Self::extract_tables_from_events(&events);
 
@@ -236,6 +276,11 @@ impl DocumentExtractor for MarkdownExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -286,7 +331,7 @@ mod tests {
 
         let parser = Parser::new_ext(&remaining, Options::ENABLE_TABLES);
         let events: Vec<Event> = parser.collect();
-        let extracted = MarkdownExtractor::extract_text_from_events(&events);
+        let extracted = MarkdownExtractor::extract_text_from_events(&events, true);
 
         assert!(extracted.contains("Header"));
         assert!(extracted.contains("This is a paragraph"));
@@ -394,7 +439,7 @@ mod tests {
 
         let parser = Parser::new_ext(&remaining, Options::ENABLE_TABLES);
         let events: Vec<Event> = parser.collect();
-        let extracted = MarkdownExtractor::extract_text_from_events(&events);
+        let extracted = MarkdownExtractor::extract_text_from_events(&events, true);
         assert!(extracted.is_empty());
     }
 
@@ -408,10 +453,31 @@ mod tests {
 
         let parser = Parser::new_ext(&remaining, Options::ENABLE_TABLES);
         let events: Vec<Event> = parser.collect();
-        let extracted = MarkdownExtractor::extract_text_from_events(&events);
+        let extracted = MarkdownExtractor::extract_text_from_events(&events, true);
         assert!(extracted.trim().is_empty());
     }
 
+    #[test]
+    fn test_nested_list_markers_preserved() {
+        let markdown = "Steps:\n\n1. Prepare\n   - Gather tools\n   - Clear the desk\n2. Assemble\n   1. Attach legs\n   2. Tighten bolts\n\nDone.";
+        let events: Vec<Event> = Parser::new_ext(markdown, Options::ENABLE_TABLES).collect();
+
+        let extracted = MarkdownExtractor::extract_text_from_events(&events, true);
+        assert_eq!(
+            extracted,
+            "Steps:\n1. Prepare\n  - Gather tools\n  - Clear the desk\n2. Assemble\n  1. Attach legs\n  2. Tighten bolts\nDone."
+        );
+    }
+
+    #[test]
+    fn test_list_markers_dropped_when_disabled() {
+        let markdown = "3. Third\n4. Fourth\n   - Nested";
+        let events: Vec<Event> = Parser::new_ext(markdown, Options::ENABLE_TABLES).collect();
+
+        let extracted = MarkdownExtractor::extract_text_from_events(&events, false);
+        assert_eq!(extracted, "Third\nFourth\nNested\n");
+    }
+
     #[test]
     fn test_unicode_content() {
         let content = "# 日本語のタイトル\n\nこれは日本語の内容です。\n\n## Español\n\nEste es un documento en español.\n\n## Русский\n\nЭто русский текст.".as_bytes();
@@ -423,7 +489,7 @@ mod tests {
 
         let parser = Parser::new_ext(&remaining, Options::ENABLE_TABLES);
         let events: Vec<Event> = parser.collect();
-        let extracted = MarkdownExtractor::extract_text_from_events(&events);
+        let extracted = MarkdownExtractor::extract_text_from_events(&events, true);
 
         assert!(extracted.contains("日本語"));
         assert!(extracted.contains("Español"));
@@ -485,7 +551,7 @@ mod tests {
 
         let parser = Parser::new_ext(&text, Options::ENABLE_TABLES);
         let events: Vec<Event> = parser.collect();
-        let extracted = MarkdownExtractor::extract_text_from_events(&events);
+        let extracted = MarkdownExtractor::extract_text_from_events(&events, true);
 
         assert!(extracted.contains("Google"));
         assert!(extracted.contains("Rust"));
@@ -498,7 +564,7 @@ mod tests {
 
         let parser = Parser::new_ext(&text, Options::ENABLE_TABLES);
         let events: Vec<Event> = parser.collect();
-        let extracted = MarkdownExtractor::extract_text_from_events(&events);
+        let extracted = MarkdownExtractor::extract_text_from_events(&events, true);
 
         assert!(extracted.contains("main"));
         assert!(extracted.contains("println"));
@@ -585,7 +651,20 @@ nested:
             Some("1.2.3")
         );
 
-        assert_eq!(metadata.additional.len(), 8, "Should extract all standard fields");
-        println!("\nSuccessfully extracted all 8 additional metadata fields");
+        assert_eq!(
+            metadata
+                .additional
+                .get("frontmatter.custom_field")
+                .and_then(|v| v.as_str()),
+            Some("custom_value")
+        );
+        assert!(metadata.additional.contains_key("frontmatter.nested"));
+
+        assert_eq!(
+            metadata.additional.len(),
+            10,
+            "Should extract all standard fields plus custom frontmatter fields"
+        );
+        println!("\nSuccessfully extracted all 10 additional metadata fields");
     }
 }
@@ -62,6 +62,7 @@ pub trait SyncExtractor {
 }
 
 pub mod csv;
+pub mod dxf;
 pub mod structured;
 pub mod text;
 
@@ -113,6 +114,9 @@ pub mod rst;
 #[cfg(feature = "office")]
 pub mod latex;
 
+#[cfg(feature = "office")]
+pub mod iwork;
+
 #[cfg(feature = "office")]
 pub mod jupyter;
 
@@ -143,6 +147,9 @@ pub mod pptx;
 #[cfg(feature = "office")]
 pub mod rtf;
 
+#[cfg(feature = "xml")]
+pub mod svg;
+
 #[cfg(feature = "xml")]
 pub mod xml;
 
@@ -150,6 +157,7 @@ pub mod xml;
 pub mod docbook;
 
 pub use csv::CsvExtractor;
+pub use dxf::DxfExtractor;
 pub use structured::StructuredExtractor;
 pub use text::{MarkdownExtractor, PlainTextExtractor};
 
@@ -197,6 +205,9 @@ pub use rst::RstExtractor;
 #[cfg(feature = "office")]
 pub use latex::LatexExtractor;
 
+#[cfg(feature = "office")]
+pub use iwork::IworkExtractor;
+
 #[cfg(feature = "office")]
 pub use jupyter::JupyterExtractor;
 
@@ -227,6 +238,9 @@ pub use pptx::PptxExtractor;
 #[cfg(feature = "office")]
 pub use rtf::RtfExtractor;
 
+#[cfg(feature = "xml")]
+pub use svg::SvgExtractor;
+
 #[cfg(feature = "xml")]
 pub use xml::XmlExtractor;
 
@@ -294,6 +308,7 @@ pub fn register_default_extractors() -> Result<()> {
     registry.register(Arc::new(MarkdownExtractor::new()))?;
     registry.register(Arc::new(StructuredExtractor::new()))?;
     registry.register(Arc::new(CsvExtractor::new()))?;
+    registry.register(Arc::new(DxfExtractor::new()))?;
 
     #[cfg(feature = "ocr")]
     registry.register(Arc::new(ImageExtractor::new()))?;
@@ -301,6 +316,7 @@ pub fn register_default_extractors() -> Result<()> {
     #[cfg(feature = "xml")]
     {
         registry.register(Arc::new(XmlExtractor::new()))?;
+        registry.register(Arc::new(SvgExtractor::new()))?;
         registry.register(Arc::new(JatsExtractor::new()))?;
         registry.register(Arc::new(DocbookExtractor::new()))?;
     }
@@ -332,6 +348,7 @@ pub fn register_default_extractors() -> Result<()> {
         registry.register(Arc::new(PptExtractor::new()))?;
         registry.register(Arc::new(PptxExtractor::new()))?;
         registry.register(Arc::new(OdtExtractor::new()))?;
+        registry.register(Arc::new(IworkExtractor::new()))?;
     }
 
     #[cfg(feature = "email")]
@@ -373,12 +390,13 @@ mod tests {
         let extractor_names = reg.list();
 
         #[allow(unused_mut)]
-        let mut expected_count = 5; // plain-text, markdown, structured, djot, csv
+        let mut expected_count = 6; // plain-text, markdown, structured, djot, csv, dxf
         assert!(extractor_names.contains(&"plain-text-extractor".to_string()));
         assert!(extractor_names.contains(&"markdown-extractor".to_string()));
         assert!(extractor_names.contains(&"structured-extractor".to_string()));
         assert!(extractor_names.contains(&"djot-extractor".to_string()));
         assert!(extractor_names.contains(&"csv-extractor".to_string()));
+        assert!(extractor_names.contains(&"dxf-extractor".to_string()));
 
         #[cfg(feature = "ocr")]
         {
@@ -388,8 +406,9 @@ mod tests {
 
         #[cfg(feature = "xml")]
         {
-            expected_count += 3;
+            expected_count += 4;
             assert!(extractor_names.contains(&"xml-extractor".to_string()));
+            assert!(extractor_names.contains(&"svg-extractor".to_string()));
             assert!(extractor_names.contains(&"jats-extractor".to_string()));
             assert!(extractor_names.contains(&"docbook-extractor".to_string()));
         }
@@ -408,7 +427,7 @@ mod tests {
 
         #[cfg(feature = "office")]
         {
-            expected_count += 11;
+            expected_count += 12;
             assert!(extractor_names.contains(&"markdown-extractor".to_string()));
             assert!(extractor_names.contains(&"bibtex-extractor".to_string()));
             assert!(extractor_names.contains(&"citation-extractor".to_string()));
@@ -421,6 +440,7 @@ mod tests {
             assert!(extractor_names.contains(&"orgmode-extractor".to_string()));
             assert!(extractor_names.contains(&"opml-extractor".to_string()));
             assert!(extractor_names.contains(&"typst-extractor".to_string()));
+            assert!(extractor_names.contains(&"iwork-extractor".to_string()));
         }
 
         #[cfg(all(feature = "tokio-runtime", feature = "office"))]
@@ -442,6 +442,9 @@ fn parse_odt_table(table_node: roxmltree::Node, table_index: usize) -> Option<Ta
         cells,
         markdown,
         page_number: table_index + 1,
+        name: None,
+        has_header: false,
+        cell_spans: Vec::new(),
     })
 }
 
@@ -653,6 +656,11 @@ impl DocumentExtractor for OdtExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
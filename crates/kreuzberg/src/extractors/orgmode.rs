@@ -183,6 +183,9 @@ impl OrgModeExtractor {
                             cells: current_table.clone(),
                             markdown,
                             page_number: 1,
+                            name: None,
+                            has_header: false,
+                            cell_spans: Vec::new(),
                         });
                         current_table.clear();
                     }
@@ -196,6 +199,9 @@ impl OrgModeExtractor {
                     cells: current_table,
                     markdown,
                     page_number: 1,
+                    name: None,
+                    has_header: false,
+                    cell_spans: Vec::new(),
                 });
             }
         }
@@ -311,6 +317,11 @@ impl DocumentExtractor for OrgModeExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -67,8 +67,9 @@ fn extract_tables_from_document(
     document: &PdfDocument,
     _metadata: &crate::pdf::metadata::PdfExtractionMetadata,
 ) -> Result<Vec<Table>> {
-    use crate::ocr::table::{reconstruct_table, table_to_markdown};
+    use crate::ocr::table::reconstruct_table;
     use crate::pdf::table::extract_words_from_page;
+    use crate::types::TableMarkdownOptions;
 
     let mut all_tables = Vec::new();
 
@@ -85,13 +86,16 @@ fn extract_tables_from_document(
         let table_cells = reconstruct_table(&words, column_threshold, row_threshold_ratio);
 
         if !table_cells.is_empty() {
-            let markdown = table_to_markdown(&table_cells);
-
-            all_tables.push(Table {
+            let mut table = Table {
                 cells: table_cells,
-                markdown,
+                markdown: String::new(),
                 page_number: page_index + 1,
-            });
+                name: None,
+                has_header: false,
+                cell_spans: Vec::new(),
+            };
+            table.markdown = table.to_markdown(TableMarkdownOptions::default());
+            all_tables.push(table);
         }
     }
 
@@ -27,7 +27,36 @@ pub use ocr::{NativeTextStats, OcrFallbackDecision, evaluate_native_text_for_ocr
 use extraction::extract_all_from_document;
 #[cfg(feature = "ocr")]
 use ocr::extract_with_ocr;
-use pages::assign_tables_and_images_to_pages;
+use pages::{assign_tables_and_images_to_pages, insert_image_markers};
+
+/// Run blocking pdfium work off the async executor where a Tokio runtime is available.
+#[cfg(feature = "pdf")]
+async fn run_blocking<T, F>(work: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    #[cfg(all(not(target_arch = "wasm32"), feature = "tokio-runtime"))]
+    {
+        tokio::task::spawn_blocking(work)
+            .await
+            .map_err(|e| crate::error::KreuzbergError::Other(format!("PDF extraction task failed: {}", e)))?
+    }
+    #[cfg(not(all(not(target_arch = "wasm32"), feature = "tokio-runtime")))]
+    {
+        work()
+    }
+}
+
+/// Record a failed optional extraction step in the result metadata under `key`.
+#[cfg(feature = "pdf")]
+fn record_error(result: &mut ExtractionResult, key: &'static str, error: crate::error::KreuzbergError) {
+    tracing::warn!("PDF {}: {}", key, error);
+    result
+        .metadata
+        .additional
+        .insert(std::borrow::Cow::Borrowed(key), serde_json::json!(error.to_string()));
+}
 
 /// PDF document extractor using pypdfium2 and playa-pdf.
 pub struct PdfExtractor;
@@ -62,6 +91,30 @@ impl Plugin for PdfExtractor {
     }
 }
 
+#[cfg(feature = "pdf")]
+fn pdf_passwords(config: &ExtractionConfig) -> &[String] {
+    config
+        .pdf_options
+        .as_ref()
+        .and_then(|pdf| pdf.passwords.as_deref())
+        .unwrap_or_default()
+}
+
+/// Open the document with the configured passwords and check its owner restrictions.
+///
+/// Returns the document and whether owner restrictions were bypassed.
+#[cfg(feature = "pdf")]
+fn open_document<'a>(
+    pdfium: &'a pdfium_render::prelude::Pdfium,
+    content: &'a [u8],
+    config: &ExtractionConfig,
+) -> std::result::Result<(pdfium_render::prelude::PdfDocument<'a>, bool), PdfError> {
+    let document = crate::pdf::password::load_document(pdfium, content, pdf_passwords(config))?;
+    let allow_owner_locked = config.pdf_options.as_ref().is_none_or(|pdf| pdf.allow_owner_locked);
+    let owner_restrictions_bypassed = crate::pdf::password::check_owner_restrictions(&document, allow_owner_locked)?;
+    Ok((document, owner_restrictions_bypassed))
+}
+
 #[async_trait]
 impl DocumentExtractor for PdfExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
@@ -83,9 +136,25 @@ impl DocumentExtractor for PdfExtractor {
         let derotated = crate::pdf::text::strip_page_rotation(content);
         #[cfg(feature = "pdf")]
         let content = &*derotated;
+        #[cfg(feature = "pdf")]
+        let full_content = content;
+
+        // Restrict every extraction phase to the requested pages; page numbers are
+        // mapped back to the original document at the end.
+        #[cfg(feature = "pdf")]
+        let page_range = config.pdf_options.as_ref().and_then(|pdf| pdf.page_range);
+        #[cfg(feature = "pdf")]
+        let selected_pages = match (page_range, config.pdf_options.as_ref()) {
+            (Some(range), Some(pdf)) => Some(crate::pdf::page_range::extract_page_range(content, range, pdf)?),
+            _ => None,
+        };
+        #[cfg(feature = "pdf")]
+        let content = selected_pages
+            .as_ref()
+            .map_or(content, |selection| selection.bytes.as_slice());
 
         #[cfg(feature = "pdf")]
-        let (mut pdf_metadata, native_text, tables, page_contents, _boundaries) = {
+        let ((mut pdf_metadata, native_text, tables, page_contents, _boundaries), owner_restrictions_bypassed) = {
             #[cfg(target_arch = "wasm32")]
             {
                 let pdfium = crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")
@@ -103,16 +172,12 @@ impl DocumentExtractor for PdfExtractor {
                         }
                     })?;
 
-                let document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
-                    let err_msg = crate::pdf::error::format_pdfium_error(e);
-                    if err_msg.contains("password") || err_msg.contains("Password") {
-                        PdfError::PasswordRequired
-                    } else {
-                        PdfError::InvalidPdf(err_msg)
-                    }
-                })?;
+                let (document, owner_restrictions_bypassed) = open_document(&pdfium, content, config)?;
 
-                extract_all_from_document(&document, config)?
+                (
+                    extract_all_from_document(&document, config)?,
+                    owner_restrictions_bypassed,
+                )
             }
             #[cfg(all(not(target_arch = "wasm32"), feature = "tokio-runtime"))]
             {
@@ -120,27 +185,21 @@ impl DocumentExtractor for PdfExtractor {
                     let content_owned = content.to_vec();
                     let span = tracing::Span::current();
                     let config_owned = config.clone();
+                    let progress_sink = crate::core::progress::current();
                     let result = tokio::task::spawn_blocking(move || {
                         let _guard = span.entered();
 
                         let pdfium =
                             crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")?;
 
-                        let document = match pdfium.load_pdf_from_byte_slice(&content_owned, None) {
-                            Ok(doc) => doc,
-                            Err(e) => {
-                                let err_msg = crate::pdf::error::format_pdfium_error(e);
-                                if err_msg.contains("password") || err_msg.contains("Password") {
-                                    return Err(PdfError::PasswordRequired);
-                                } else {
-                                    return Err(PdfError::InvalidPdf(err_msg));
-                                }
-                            }
-                        };
+                        let (document, owner_restrictions_bypassed) =
+                            open_document(&pdfium, &content_owned, &config_owned)?;
 
                         let (pdf_metadata, native_text, tables, page_contents, _boundaries) =
-                            extract_all_from_document(&document, &config_owned)
-                                .map_err(|e| PdfError::ExtractionFailed(e.to_string()))?;
+                            crate::core::progress::scope_sync(progress_sink, || {
+                                extract_all_from_document(&document, &config_owned)
+                            })
+                            .map_err(|e| PdfError::ExtractionFailed(e.to_string()))?;
 
                         if let Some(page_cfg) = config_owned.pages.as_ref()
                             && page_cfg.extract_pages
@@ -153,11 +212,8 @@ impl DocumentExtractor for PdfExtractor {
                         }
 
                         Ok::<_, crate::pdf::error::PdfError>((
-                            pdf_metadata,
-                            native_text,
-                            tables,
-                            page_contents,
-                            _boundaries,
+                            (pdf_metadata, native_text, tables, page_contents, _boundaries),
+                            owner_restrictions_bypassed,
                         ))
                     })
                     .await
@@ -171,16 +227,12 @@ impl DocumentExtractor for PdfExtractor {
                     let pdfium =
                         crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")?;
 
-                    let document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
-                        let err_msg = crate::pdf::error::format_pdfium_error(e);
-                        if err_msg.contains("password") || err_msg.contains("Password") {
-                            PdfError::PasswordRequired
-                        } else {
-                            PdfError::InvalidPdf(err_msg)
-                        }
-                    })?;
+                    let (document, owner_restrictions_bypassed) = open_document(&pdfium, content, config)?;
 
-                    extract_all_from_document(&document, config)?
+                    (
+                        extract_all_from_document(&document, config)?,
+                        owner_restrictions_bypassed,
+                    )
                 }
             }
             #[cfg(all(not(target_arch = "wasm32"), not(feature = "tokio-runtime")))]
@@ -188,25 +240,35 @@ impl DocumentExtractor for PdfExtractor {
                 let pdfium =
                     crate::pdf::bindings::bind_pdfium(PdfError::MetadataExtractionFailed, "initialize Pdfium")?;
 
-                let document = pdfium.load_pdf_from_byte_slice(content, None).map_err(|e| {
-                    let err_msg = crate::pdf::error::format_pdfium_error(e);
-                    if err_msg.contains("password") || err_msg.contains("Password") {
-                        PdfError::PasswordRequired
-                    } else {
-                        PdfError::InvalidPdf(err_msg)
-                    }
-                })?;
+                let (document, owner_restrictions_bypassed) = open_document(&pdfium, content, config)?;
 
-                extract_all_from_document(&document, config)?
+                (
+                    extract_all_from_document(&document, config)?,
+                    owner_restrictions_bypassed,
+                )
             }
         };
 
         #[cfg(feature = "ocr")]
-        let text = if config.force_ocr {
+        let ocr_page_offset = page_range.map_or(0, |(start, _)| start - 1);
+        // A page filter names the pages to OCR, replacing the automatic decision.
+        #[cfg(feature = "ocr")]
+        let ocr_forced = config.force_ocr || ocr::page_filter(config).is_some();
+        #[cfg(feature = "ocr")]
+        let (text, ocr_failed_pages, ocr_pages, ocr_language, ocr_backend, ocr_additional) = if ocr_forced {
             if config.ocr.is_some() {
-                extract_with_ocr(content, config).await?
+                let output = extract_with_ocr(content, config).await?;
+                let ocr_additional = output.additional_metadata(ocr_page_offset);
+                (
+                    output.text,
+                    output.failed_pages,
+                    output.pages,
+                    output.language,
+                    Some(output.backend),
+                    ocr_additional,
+                )
             } else {
-                native_text
+                (native_text, Vec::new(), Vec::new(), None, None, Vec::new())
             }
         } else if config.ocr.is_some() {
             let decision = ocr::evaluate_per_page_ocr(
@@ -230,16 +292,36 @@ impl DocumentExtractor for PdfExtractor {
             }
 
             if decision.fallback {
-                extract_with_ocr(content, config).await?
+                let output = extract_with_ocr(content, config).await?;
+                let ocr_additional = output.additional_metadata(ocr_page_offset);
+                (
+                    output.text,
+                    output.failed_pages,
+                    output.pages,
+                    output.language,
+                    Some(output.backend),
+                    ocr_additional,
+                )
             } else {
-                native_text
+                (native_text, Vec::new(), Vec::new(), None, None, Vec::new())
             }
         } else {
-            native_text
+            (native_text, Vec::new(), Vec::new(), None, None, Vec::new())
         };
 
+        #[cfg(feature = "ocr")]
+        let ocr_page_count = (!ocr_pages.is_empty()).then_some(ocr_pages.len());
+
         #[cfg(not(feature = "ocr"))]
         let text = native_text;
+        #[cfg(not(feature = "ocr"))]
+        let ocr_pages: Vec<usize> = Vec::new();
+        #[cfg(not(feature = "ocr"))]
+        let ocr_page_count: Option<usize> = None;
+        #[cfg(not(feature = "ocr"))]
+        let ocr_language: Option<String> = None;
+        #[cfg(not(feature = "ocr"))]
+        let ocr_backend: Option<String> = None;
 
         #[cfg(feature = "pdf")]
         if let Some(ref page_cfg) = config.pages
@@ -255,10 +337,12 @@ impl DocumentExtractor for PdfExtractor {
             }
         }
 
-        let images = if config.images.as_ref().map(|c| c.extract_images).unwrap_or(false) {
+        let mut images_truncated = false;
+        let images = if let Some(image_config) = config.images.as_ref().filter(|c| c.extract_images) {
             // Image extraction is enabled, extract images if present
-            match crate::pdf::images::extract_images_from_pdf(content) {
-                Ok(pdf_images) => Some(
+            match crate::pdf::images::extract_images_from_pdf_with_limits(content, image_config) {
+                Ok((pdf_images, truncated)) => Some({
+                    images_truncated = truncated;
                     pdf_images
                         .into_iter()
                         .enumerate()
@@ -280,11 +364,14 @@ impl DocumentExtractor for PdfExtractor {
                                 bits_per_component: img.bits_per_component.map(|b| b as u32),
                                 is_mask: false,
                                 description: None,
+                                alt_text: None,
+                                page_position: img.page_position,
+                                caption: None,
                                 ocr_result: None,
                             }
                         })
-                        .collect(),
-                ),
+                        .collect()
+                }),
                 // If extraction fails, return empty vector instead of None
                 Err(_) => Some(vec![]),
             }
@@ -306,7 +393,45 @@ impl DocumentExtractor for PdfExtractor {
             }
         }
 
-        Ok(ExtractionResult {
+        let mut additional = ahash::AHashMap::new();
+        #[cfg(feature = "pdf")]
+        if owner_restrictions_bypassed
+            || selected_pages
+                .as_ref()
+                .is_some_and(|selection| selection.owner_restrictions_bypassed)
+        {
+            additional.insert(
+                std::borrow::Cow::Borrowed("owner_restrictions_bypassed"),
+                serde_json::json!(true),
+            );
+        }
+        if images_truncated {
+            additional.insert(std::borrow::Cow::Borrowed("images_truncated"), serde_json::json!(true));
+        }
+        #[cfg(feature = "ocr")]
+        if !ocr_failed_pages.is_empty() {
+            let failed_pages: Vec<usize> = ocr_failed_pages.iter().map(|page| page + ocr_page_offset).collect();
+            additional.insert(
+                std::borrow::Cow::Borrowed("ocr_failed_pages"),
+                serde_json::json!(failed_pages),
+            );
+        }
+        #[cfg(feature = "ocr")]
+        for (key, value) in ocr_additional {
+            additional.insert(std::borrow::Cow::Borrowed(key), value);
+        }
+
+        let page_count = pdf_metadata.pdf_specific.page_count;
+        // Document metadata, including the page count, describes the original document
+        // rather than the selected pages.
+        #[cfg(feature = "pdf")]
+        if let Some(selection) = &selected_pages {
+            pdf_metadata = crate::pdf::metadata::PdfExtractionMetadata {
+                page_structure: pdf_metadata.page_structure.take(),
+                ..selection.metadata.clone()
+            };
+        }
+        let mut result = ExtractionResult {
             content: text,
             mime_type: mime_type.to_string().into(),
             metadata: Metadata {
@@ -328,6 +453,11 @@ impl DocumentExtractor for PdfExtractor {
                 pages: pdf_metadata.page_structure.clone(),
                 #[cfg(feature = "pdf")]
                 format: Some(crate::types::FormatMetadata::Pdf(pdf_metadata.pdf_specific)),
+                ocr_used: ocr_page_count.is_some(),
+                ocr_page_count,
+                ocr_language,
+                ocr_backend,
+                additional,
                 ..Default::default()
             },
             pages: final_pages,
@@ -339,7 +469,108 @@ impl DocumentExtractor for PdfExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
-        })
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
+        };
+
+        if config.pdf_options.as_ref().is_some_and(|pdf| pdf.insert_image_markers) {
+            insert_image_markers(&mut result, page_count);
+        }
+
+        // Formulas are read from the selected pages, so they are shifted with the rest
+        // of the result below. Recognition failures are reported, not fatal.
+        if config.pdf_options.as_ref().is_some_and(|pdf| pdf.extract_math) {
+            #[cfg(all(feature = "math-ocr", not(target_arch = "wasm32")))]
+            {
+                let content_owned = content.to_vec();
+                let pdf_options = config.pdf_options.clone();
+                let formulas = tokio::task::spawn_blocking(move || {
+                    crate::pdf::math::extract_formulas(&content_owned, pdf_options.as_ref())
+                })
+                .await
+                .map_err(|e| crate::error::KreuzbergError::Other(format!("Formula extraction task failed: {}", e)))
+                .and_then(|formulas| formulas);
+
+                match formulas {
+                    Ok(formulas) => result.formulas = Some(formulas),
+                    Err(e) => {
+                        tracing::warn!("Formula extraction failed: {}", e);
+                        result.metadata.additional.insert(
+                            std::borrow::Cow::Borrowed("formula_extraction_error"),
+                            serde_json::json!(e.to_string()),
+                        );
+                    }
+                }
+            }
+            #[cfg(not(all(feature = "math-ocr", not(target_arch = "wasm32"))))]
+            result.metadata.additional.insert(
+                std::borrow::Cow::Borrowed("formula_extraction_error"),
+                serde_json::json!("Formula extraction requires the math-ocr feature"),
+            );
+        }
+
+        // Text blocks and source lines are read from the selected pages, so they are
+        // shifted with the rest of the result below. Both come from the text layer, which
+        // doesn't describe OCR output: blocks are only kept for pages that were not OCR'd
+        // (when OCR produced all of the text, they are left for the pipeline to derive from
+        // OCR elements), and no source map is built once any page was OCR'd. Failures are
+        // reported, not fatal.
+        #[cfg(feature = "pdf")]
+        let all_pages_ocr = !ocr_pages.is_empty() && page_count.is_none_or(|count| ocr_pages.len() >= count);
+        #[cfg(feature = "pdf")]
+        if (config.emit_positions || config.emit_source_offsets) && !all_pages_ocr {
+            let pdf_bytes: std::sync::Arc<[u8]> = content.into();
+
+            if config.emit_positions {
+                let (pdf_bytes, pdf_options) = (pdf_bytes.clone(), config.pdf_options.clone());
+                let blocks = run_blocking(move || {
+                    crate::pdf::positions::extract_text_blocks(&pdf_bytes, pdf_options.as_ref()).map_err(Into::into)
+                })
+                .await;
+                match blocks {
+                    Ok(mut blocks) => {
+                        blocks.retain(|block| !ocr_pages.contains(&block.page));
+                        result.text_blocks = Some(blocks);
+                    }
+                    Err(e) => record_error(&mut result, "text_blocks_error", e),
+                }
+            }
+
+            if config.emit_source_offsets && ocr_pages.is_empty() {
+                let pdf_options = config.pdf_options.clone();
+                let lines = run_blocking(move || {
+                    crate::pdf::positions::extract_source_lines(&pdf_bytes, pdf_options.as_ref()).map_err(Into::into)
+                })
+                .await;
+                match lines {
+                    Ok(lines) => {
+                        result.source_map = Some(crate::types::source_map::align_source_text(&result.content, lines))
+                    }
+                    Err(e) => record_error(&mut result, "source_map_error", e),
+                }
+            }
+        }
+
+        #[cfg(feature = "pdf")]
+        if let Some((start, _)) = page_range {
+            result.shift_page_numbers(start - 1);
+        }
+
+        // The outline is read from the full document, so its page numbers are already
+        // original page numbers and it is set after the page-range shift. A failure is
+        // reported, not fatal.
+        #[cfg(feature = "pdf")]
+        if config.extract_outline {
+            match crate::pdf::outline::extract_outline(full_content, config.pdf_options.as_ref()) {
+                Ok(outline) => result.outline = Some(outline),
+                Err(e) => record_error(&mut result, "outline_error", e.into()),
+            }
+        }
+
+        Ok(result)
     }
 
     #[cfg(feature = "tokio-runtime")]
@@ -556,6 +787,22 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_empty_ocr_page_filter_is_unset() {
+        let config_with = |page_filter: Option<Vec<usize>>| ExtractionConfig {
+            ocr: Some(crate::core::config::OcrConfig {
+                page_filter,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(ocr::page_filter(&config_with(Some(vec![3, 7]))), Some(&[3, 7][..]));
+        assert_eq!(ocr::page_filter(&config_with(Some(Vec::new()))), None);
+        assert_eq!(ocr::page_filter(&config_with(None)), None);
+    }
+
     #[tokio::test]
     #[cfg(feature = "pdf")]
     async fn test_pdf_batch_mode_validates_page_config_enabled() {
@@ -650,6 +897,33 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[cfg(feature = "pdf")]
+    async fn test_pdf_custom_page_separator() {
+        use crate::core::config::PdfConfig;
+
+        let extractor = PdfExtractor::new();
+
+        let config = ExtractionConfig {
+            pdf_options: Some(PdfConfig {
+                page_separator: Some("\n\n<!-- page {page} -->\n\n".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let pdf_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test_documents/pdf/multi_page.pdf");
+        if let Ok(content) = std::fs::read(pdf_path) {
+            let result = extractor
+                .extract_bytes(&content, "application/pdf", &config)
+                .await
+                .expect("Failed to extract PDF with custom page separator");
+
+            assert!(result.content.contains("<!-- page 2 -->"));
+            assert!(!result.content.contains("<!-- page 1 -->"));
+        }
+    }
+
     #[test]
     #[cfg(feature = "pdf")]
     fn test_pdf_extractor_without_feature_pdf() {
@@ -24,6 +24,54 @@ pub struct NativeTextStats {
     pub alnum_ratio: f64,
 }
 
+/// Result of running OCR over every page of a PDF.
+#[cfg(feature = "ocr")]
+pub(crate) struct PdfOcrOutput {
+    /// Text from all pages, separated by double newlines
+    pub text: String,
+    /// 1-indexed pages whose OCR failed after all retries (left empty in `text`)
+    pub failed_pages: Vec<usize>,
+    /// 1-indexed pages where OCR found no text, when `OcrConfig.empty_page_confidence` is set
+    pub empty_pages: Vec<usize>,
+    /// 1-indexed pages run through OCR; with `OcrConfig.page_filter` the other pages keep
+    /// their native text
+    pub pages: Vec<usize>,
+    /// Language selected by `OcrConfig.auto_language`, if enabled
+    pub language: Option<String>,
+    /// OCR backend that recognized the pages, after any fallbacks
+    pub backend: String,
+    /// DPI the pages were rendered at (oversized pages are scaled down further)
+    pub render_dpi: i32,
+    /// Mean raw confidence of the pages, when every page was recognized by the same backend
+    pub confidence: Option<f64>,
+    /// Mean normalized confidence of the pages (see [`crate::ocr::confidence`])
+    pub confidence_normalized: Option<f64>,
+}
+
+#[cfg(feature = "ocr")]
+impl PdfOcrOutput {
+    /// Entries for `metadata.additional` describing the OCR run.
+    ///
+    /// `page_offset` is added to page numbers, for when only a range of the document was
+    /// run through OCR.
+    pub fn additional_metadata(&self, page_offset: usize) -> Vec<(&'static str, serde_json::Value)> {
+        use crate::ocr::confidence::{OCR_CONFIDENCE_KEY, OCR_CONFIDENCE_NORMALIZED_KEY, OCR_EMPTY_PAGES_KEY};
+
+        let mut entries = vec![("ocr_render_dpi", serde_json::json!(self.render_dpi))];
+        if let Some(confidence) = self.confidence {
+            entries.push((OCR_CONFIDENCE_KEY, serde_json::json!(confidence)));
+        }
+        if let Some(confidence) = self.confidence_normalized {
+            entries.push((OCR_CONFIDENCE_NORMALIZED_KEY, serde_json::json!(confidence)));
+        }
+        if !self.empty_pages.is_empty() {
+            let empty_pages: Vec<usize> = self.empty_pages.iter().map(|page| page + page_offset).collect();
+            entries.push((OCR_EMPTY_PAGES_KEY, serde_json::json!(empty_pages)));
+        }
+        entries
+    }
+}
+
 #[cfg(feature = "ocr")]
 pub struct OcrFallbackDecision {
     pub stats: NativeTextStats,
@@ -168,7 +216,9 @@ pub fn evaluate_per_page_ocr(
 
 /// Extract text from PDF using OCR.
 ///
-/// Renders all pages to images and processes them with OCR backend.
+/// Renders all pages to images and processes them with OCR backend. With
+/// `OcrConfig.page_filter`, only the listed pages are rendered and recognized; the other
+/// pages keep their native text.
 ///
 /// # Arguments
 ///
@@ -177,11 +227,13 @@ pub fn evaluate_per_page_ocr(
 ///
 /// # Returns
 ///
-/// Concatenated text from all pages, separated by double newlines
+/// See [`PdfOcrOutput`]. Pages are only skipped when `max_retries > 0`; otherwise the
+/// first failure is returned. A page filter listing pages outside the document is a
+/// validation error.
 #[cfg(feature = "ocr")]
-pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig) -> crate::Result<String> {
+pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig) -> crate::Result<PdfOcrOutput> {
+    use crate::ocr::confidence::{OCR_CONFIDENCE_KEY, OCR_CONFIDENCE_NORMALIZED_KEY, is_empty_page};
     use crate::pdf::rendering::{PageRenderOptions, PdfRenderer};
-    use crate::plugins::registry::get_ocr_backend_registry;
     use image::ImageEncoder;
     use image::codecs::png::PngEncoder;
     use std::io::Cursor;
@@ -191,33 +243,72 @@ pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig)
         source: None,
     })?;
 
-    let backend = {
-        let registry = get_ocr_backend_registry();
-        let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
-            message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
-            plugin_name: "ocr-registry".to_string(),
-        })?;
-        registry.get(&ocr_config.backend)?
+    let mut backends = crate::ocr::OcrBackendChain::from_config(ocr_config)?;
+
+    let mut render_options = PageRenderOptions::default();
+    if let Some(dpi) = ocr_config.render_dpi {
+        crate::core::config_validation::validate_dpi(dpi)?;
+        render_options.target_dpi = dpi;
+        render_options.min_dpi = render_options.min_dpi.min(dpi);
+        render_options.max_dpi = render_options.max_dpi.max(dpi);
+    }
+
+    // Read before rendering: the renderer holds the Pdfium lock until it is dropped.
+    let mut native_pages = match page_filter(config) {
+        Some(page_filter) => Some(native_text_outside_filter(content, config, page_filter)?),
+        None => None,
     };
 
     let images = {
-        let render_options = PageRenderOptions::default();
         let renderer = PdfRenderer::new().map_err(|e| crate::KreuzbergError::Parsing {
             message: format!("Failed to initialize PDF renderer: {}", e),
             source: None,
         })?;
 
-        renderer
-            .render_all_pages(content, &render_options)
-            .map_err(|e| crate::KreuzbergError::Parsing {
-                message: format!("Failed to render PDF pages: {}", e),
-                source: None,
-            })?
+        let rendered: crate::pdf::error::Result<Vec<Option<image::DynamicImage>>> = match &native_pages {
+            None => renderer
+                .render_all_pages(content, &render_options)
+                .map(|images| images.into_iter().map(Some).collect()),
+            Some(pages) => pages
+                .iter()
+                .enumerate()
+                .map(|(page_index, native)| match native {
+                    Some(_) => Ok(None),
+                    None => renderer
+                        .render_page_to_image(content, page_index, &render_options)
+                        .map(Some),
+                })
+                .collect(),
+        };
+        rendered.map_err(|e| crate::KreuzbergError::Parsing {
+            message: format!("Failed to render PDF pages: {}", e),
+            source: None,
+        })?
     };
 
-    let mut page_texts = Vec::with_capacity(images.len());
+    crate::core::progress::emit(crate::core::progress::ProgressEvent::OcrStarted);
+
+    let total_pages = images.len();
+    let mut page_texts = Vec::with_capacity(total_pages);
+    let mut ocr_pages = Vec::new();
+    let mut failed_pages = Vec::new();
+    let mut empty_pages = Vec::new();
+    let mut auto_language_config = None;
+    let mut raw_confidences: Vec<(String, f64)> = Vec::new();
+    let mut normalized_confidences = Vec::new();
+
+    for (page_index, image) in images.into_iter().enumerate() {
+        crate::core::progress::emit(crate::core::progress::ProgressEvent::PageStarted {
+            page: page_index + 1,
+            total: total_pages,
+        });
+
+        let Some(image) = image else {
+            let native = native_pages.as_mut().and_then(|pages| pages[page_index].take());
+            page_texts.push(native.unwrap_or_default());
+            continue;
+        };
 
-    for image in images {
         let rgb_image = image.to_rgb8();
         let (width, height) = rgb_image.dimensions();
 
@@ -232,10 +323,138 @@ pub(crate) async fn extract_with_ocr(content: &[u8], config: &ExtractionConfig)
 
         let image_data = image_bytes.into_inner();
 
-        let ocr_result = backend.process_image(&image_data, ocr_config).await?;
+        if ocr_pages.is_empty() {
+            auto_language_config = crate::ocr::auto_language::resolve_auto_language(&image_data, ocr_config).await;
+        }
+        ocr_pages.push(page_index + 1);
+        let ocr_config = auto_language_config.as_ref().unwrap_or(ocr_config);
 
-        page_texts.push(ocr_result.content);
+        match backends.process_page(&image_data, ocr_config).await {
+            Ok(ocr_result) => {
+                let additional = &ocr_result.metadata.additional;
+                let confidence = |key| additional.get(key).and_then(serde_json::Value::as_f64);
+                if let Some(raw) = confidence(OCR_CONFIDENCE_KEY) {
+                    raw_confidences.push((backends.backend_name().to_string(), raw));
+                }
+                let normalized = confidence(OCR_CONFIDENCE_NORMALIZED_KEY);
+                if let Some(normalized) = normalized {
+                    normalized_confidences.push(normalized);
+                }
+                if let Some(floor) = ocr_config.empty_page_confidence
+                    && is_empty_page(&ocr_result.content, normalized, floor)
+                {
+                    tracing::warn!("OCR found no text on page {}", page_index + 1);
+                    empty_pages.push(page_index + 1);
+                }
+                page_texts.push(ocr_result.content);
+            }
+            Err(e) if ocr_config.max_retries > 0 => {
+                tracing::warn!(
+                    "OCR failed for page {} after {} retries, continuing with empty text: {}",
+                    page_index + 1,
+                    ocr_config.max_retries,
+                    e
+                );
+                failed_pages.push(page_index + 1);
+                page_texts.push(String::new());
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    let pdf_config = config.pdf_options.as_ref();
+    let first_page_number = crate::pdf::text::first_page_number(pdf_config);
+    let mut text = String::new();
+    for (page_index, page_text) in page_texts.iter().enumerate() {
+        if page_index > 0 {
+            text.push_str(&crate::pdf::text::page_separator(
+                pdf_config,
+                page_index + first_page_number,
+            ));
+        }
+        text.push_str(page_text);
+    }
+
+    // Raw values are only comparable when one backend recognized every page.
+    let single_backend = raw_confidences.windows(2).all(|pair| pair[0].0 == pair[1].0);
+    let confidence = if single_backend {
+        mean(raw_confidences.iter().map(|(_, raw)| *raw))
+    } else {
+        None
+    };
+
+    Ok(PdfOcrOutput {
+        text,
+        failed_pages,
+        empty_pages,
+        pages: ocr_pages,
+        language: auto_language_config.map(|config| config.language),
+        backend: backends.backend_name().to_string(),
+        render_dpi: render_options.target_dpi,
+        confidence,
+        confidence_normalized: mean(normalized_confidences.into_iter()),
+    })
+}
+
+/// `OcrConfig.page_filter`, if set and not empty.
+///
+/// An empty filter selects no pages, which is treated like no filter at all: the
+/// automatic OCR decision applies.
+#[cfg(feature = "ocr")]
+pub(crate) fn page_filter(config: &ExtractionConfig) -> Option<&[usize]> {
+    config
+        .ocr
+        .as_ref()
+        .and_then(|ocr| ocr.page_filter.as_deref())
+        .filter(|pages| !pages.is_empty())
+}
+
+/// Native text of each page not listed in `page_filter`, and `None` for the listed pages.
+///
+/// `page_filter` holds page numbers of the original document; with
+/// `PdfConfig.page_range`, `content` only holds the selected pages.
+#[cfg(feature = "ocr")]
+fn native_text_outside_filter(
+    content: &[u8],
+    config: &ExtractionConfig,
+    page_filter: &[usize],
+) -> crate::Result<Vec<Option<String>>> {
+    use crate::pdf::error::PdfError;
+
+    let pdfium = crate::pdf::bindings::bind_pdfium(PdfError::TextExtractionFailed, "OCR page filter")?;
+    let (document, _) = super::open_document(&pdfium, content, config)?;
+
+    let pdf_config = config.pdf_options.as_ref();
+    let reading_order = pdf_config.map(|pdf| pdf.reading_order).unwrap_or_default();
+    let first_page_number = crate::pdf::text::first_page_number(pdf_config);
+    let pages = first_page_number..first_page_number + document.pages().len() as usize;
+    if let Some(page) = page_filter.iter().find(|&page| !pages.contains(page)) {
+        return Err(crate::KreuzbergError::validation(format!(
+            "ocr.page_filter: page {} is outside the extracted pages ({}-{})",
+            page,
+            pages.start,
+            pages.end.saturating_sub(1)
+        )));
     }
 
-    Ok(page_texts.join("\n\n"))
+    document
+        .pages()
+        .iter()
+        .enumerate()
+        .map(|(page_index, page)| {
+            if page_filter.contains(&(page_index + first_page_number)) {
+                return Ok(None);
+            }
+            let text = page
+                .text()
+                .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
+            Ok(Some(crate::pdf::reading_order::page_text(&page, &text, reading_order)))
+        })
+        .collect()
+}
+
+#[cfg(feature = "ocr")]
+fn mean(values: impl ExactSizeIterator<Item = f64>) -> Option<f64> {
+    let count = values.len();
+    (count > 0).then(|| values.sum::<f64>() / count as f64)
 }
@@ -56,3 +56,220 @@ pub(crate) fn assign_tables_and_images_to_pages(
 
     Some(updated_pages)
 }
+
+/// Insert `[image N]` markers into the content at the positions of the extracted images.
+///
+/// `N` is `image_index + 1`. With page boundaries, a marker goes before the line of its
+/// page whose relative position in the page text matches the top of the image (lines are
+/// assumed to be spread evenly down the page); images without a position are marked at
+/// the end of their page. Without page boundaries, the whole content is treated as
+/// `page_count` pages of equal length. Boundaries and per-page content are updated to
+/// include the markers.
+pub(crate) fn insert_image_markers(result: &mut crate::types::ExtractionResult, page_count: Option<usize>) {
+    let Some(images) = result.images.as_deref().filter(|images| !images.is_empty()) else {
+        return;
+    };
+    let boundaries = result
+        .metadata
+        .pages
+        .as_ref()
+        .and_then(|pages| pages.boundaries.as_deref())
+        .filter(|boundaries| !boundaries.is_empty());
+
+    // (byte offset, index of the boundary the marker belongs to, marker text)
+    let mut insertions: Vec<(usize, usize, String)> = Vec::new();
+    for image in images {
+        let Some(page_number) = image.page_number else {
+            continue;
+        };
+        let y = image.page_position.map_or(1.0, |(_, y)| y);
+
+        let (owner, start, end, fraction) = match boundaries {
+            Some(boundaries) => {
+                let Some((owner, boundary)) = boundaries
+                    .iter()
+                    .enumerate()
+                    .find(|(_, boundary)| boundary.page_number == page_number)
+                else {
+                    continue;
+                };
+                (owner, boundary.byte_start, boundary.byte_end, y)
+            }
+            None => {
+                let pages = page_count.unwrap_or(1).max(page_number) as f32;
+                (0, 0, result.content.len(), ((page_number - 1) as f32 + y) / pages)
+            }
+        };
+        let Some(span) = result.content.get(start..end) else {
+            continue;
+        };
+
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(span.match_indices('\n').map(|(idx, _)| idx + 1))
+            .filter(|&idx| idx < span.len())
+            .collect();
+        let line = (fraction * line_starts.len() as f32).floor() as usize;
+        let marker = format!("[image {}]", image.image_index + 1);
+        match line_starts.get(line) {
+            Some(&offset) => insertions.push((start + offset, owner, format!("{}\n", marker))),
+            None if span.is_empty() => insertions.push((end, owner, marker)),
+            None => insertions.push((end, owner, format!("\n{}", marker))),
+        }
+    }
+    if insertions.is_empty() {
+        return;
+    }
+    insertions.sort_by_key(|(offset, owner, _)| (*offset, *owner));
+
+    let shift = |offset: usize, owner: usize, inclusive: bool| -> usize {
+        insertions
+            .iter()
+            .filter(|(at, at_owner, _)| {
+                *at < offset || (*at == offset && (*at_owner < owner || (inclusive && *at_owner == owner)))
+            })
+            .map(|(_, _, marker)| marker.len())
+            .sum()
+    };
+    let shifted: Option<Vec<(usize, usize)>> = boundaries.map(|boundaries| {
+        boundaries
+            .iter()
+            .enumerate()
+            .map(|(index, boundary)| {
+                (
+                    boundary.byte_start + shift(boundary.byte_start, index, false),
+                    boundary.byte_end + shift(boundary.byte_end, index, true),
+                )
+            })
+            .collect()
+    });
+
+    for (offset, _, marker) in insertions.iter().rev() {
+        result.content.insert_str(*offset, marker);
+    }
+
+    let Some(shifted) = shifted else {
+        return;
+    };
+    if let Some(boundaries) = result
+        .metadata
+        .pages
+        .as_mut()
+        .and_then(|pages| pages.boundaries.as_mut())
+    {
+        for (boundary, (start, end)) in boundaries.iter_mut().zip(&shifted) {
+            boundary.byte_start = *start;
+            boundary.byte_end = *end;
+        }
+    }
+    if let (Some(pages), Some(boundaries)) = (
+        result.pages.as_mut(),
+        result
+            .metadata
+            .pages
+            .as_ref()
+            .and_then(|pages| pages.boundaries.as_ref()),
+    ) {
+        for page in pages.iter_mut() {
+            if let Some(text) = boundaries
+                .iter()
+                .find(|boundary| boundary.page_number == page.page_number)
+                .and_then(|boundary| result.content.get(boundary.byte_start..boundary.byte_end))
+            {
+                page.content = text.to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ExtractedImage, ExtractionResult, Metadata, PageBoundary, PageStructure, PageUnitType};
+    use bytes::Bytes;
+    use std::borrow::Cow;
+
+    fn image(index: usize, page_number: usize, y: f32) -> ExtractedImage {
+        ExtractedImage {
+            data: Bytes::new(),
+            format: Cow::Borrowed("png"),
+            image_index: index,
+            page_number: Some(page_number),
+            width: None,
+            height: None,
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: None,
+            alt_text: None,
+            page_position: Some((0.0, y)),
+            caption: None,
+            ocr_result: None,
+        }
+    }
+
+    fn result(content: &str, images: Vec<ExtractedImage>) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: Cow::Borrowed("application/pdf"),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: Some(images),
+            pages: None,
+            djot_content: None,
+            elements: None,
+            ocr_elements: None,
+            document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
+        }
+    }
+
+    #[test]
+    fn test_insert_image_markers_shifts_page_boundaries() {
+        let mut result = result("A1\nA2\nB1\nB2\n", vec![image(0, 1, 0.6), image(1, 2, 0.0)]);
+        result.metadata.pages = Some(PageStructure {
+            total_count: 2,
+            unit_type: PageUnitType::Page,
+            boundaries: Some(vec![
+                PageBoundary {
+                    byte_start: 0,
+                    byte_end: 6,
+                    page_number: 1,
+                },
+                PageBoundary {
+                    byte_start: 6,
+                    byte_end: 12,
+                    page_number: 2,
+                },
+            ]),
+            pages: None,
+        });
+
+        insert_image_markers(&mut result, Some(2));
+
+        assert_eq!(result.content, "A1\n[image 1]\nA2\n[image 2]\nB1\nB2\n");
+        let boundaries = result.metadata.pages.unwrap().boundaries.unwrap();
+        assert_eq!(
+            &result.content[boundaries[0].byte_start..boundaries[0].byte_end],
+            "A1\n[image 1]\nA2\n"
+        );
+        assert_eq!(
+            &result.content[boundaries[1].byte_start..boundaries[1].byte_end],
+            "[image 2]\nB1\nB2\n"
+        );
+    }
+
+    #[test]
+    fn test_insert_image_markers_without_boundaries() {
+        let mut result = result("A1\nA2\nB1\nB2", vec![image(0, 2, 0.0)]);
+
+        insert_image_markers(&mut result, Some(2));
+
+        assert_eq!(result.content, "A1\nA2\n[image 1]\nB1\nB2");
+    }
+}
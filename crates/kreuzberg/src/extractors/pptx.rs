@@ -46,6 +46,12 @@ impl PptxExtractor {
         let output_format = config.output_format;
 
         for image in &mut images {
+            if let Some(images_config) = config.images.as_ref()
+                && let Err(e) = images_config.check_decode_dimensions(&image.data)
+            {
+                tracing::debug!("Skipping OCR of slide image {}: {}", image.image_index, e);
+                continue;
+            }
             let image_data = image.data.clone();
             let tess_config_clone = tess_config.clone();
             let span = tracing::Span::current();
@@ -69,7 +75,10 @@ impl PptxExtractor {
                     let extraction_result = ExtractionResult {
                         content: ocr_extraction.content,
                         mime_type: ocr_extraction.mime_type.into(),
-                        metadata: Metadata::default(),
+                        metadata: Metadata {
+                            ocr_used: true,
+                            ..Default::default()
+                        },
                         tables: vec![],
                         detected_languages: None,
                         chunks: None,
@@ -79,6 +88,11 @@ impl PptxExtractor {
                         elements: None,
                         ocr_elements: None,
                         document: None,
+                        outline: None,
+                        formulas: None,
+                        text_blocks: None,
+                        source_map: None,
+                        references: None,
                     };
                     image.ocr_result = Some(Box::new(extraction_result));
                 }
@@ -133,12 +147,13 @@ impl DocumentExtractor for PptxExtractor {
                 let pages_config = config.pages.clone();
                 if crate::core::batch_mode::is_batch_mode() {
                     let content_owned = content.to_vec();
+                    let images_config = config.images.clone();
                     let span = tracing::Span::current();
                     tokio::task::spawn_blocking(move || {
                         let _guard = span.entered();
-                        crate::extraction::pptx::extract_pptx_from_bytes(
+                        crate::extraction::pptx::extract_pptx_from_bytes_with_image_config(
                             &content_owned,
-                            extract_images,
+                            images_config.as_ref(),
                             pages_config.as_ref(),
                         )
                     })
@@ -147,13 +162,21 @@ impl DocumentExtractor for PptxExtractor {
                         crate::error::KreuzbergError::parsing(format!("PPTX extraction task failed: {}", e))
                     })??
                 } else {
-                    crate::extraction::pptx::extract_pptx_from_bytes(content, extract_images, config.pages.as_ref())?
+                    crate::extraction::pptx::extract_pptx_from_bytes_with_image_config(
+                        content,
+                        config.images.as_ref(),
+                        config.pages.as_ref(),
+                    )?
                 }
             }
 
             #[cfg(not(feature = "tokio-runtime"))]
             {
-                crate::extraction::pptx::extract_pptx_from_bytes(content, extract_images, config.pages.as_ref())?
+                crate::extraction::pptx::extract_pptx_from_bytes_with_image_config(
+                    content,
+                    config.images.as_ref(),
+                    config.pages.as_ref(),
+                )?
             }
         };
 
@@ -161,6 +184,9 @@ impl DocumentExtractor for PptxExtractor {
         additional.insert(Cow::Borrowed("slide_count"), serde_json::json!(pptx_result.slide_count));
         additional.insert(Cow::Borrowed("image_count"), serde_json::json!(pptx_result.image_count));
         additional.insert(Cow::Borrowed("table_count"), serde_json::json!(pptx_result.table_count));
+        if pptx_result.images_truncated {
+            additional.insert(Cow::Borrowed("images_truncated"), serde_json::json!(true));
+        }
 
         let images = if extract_images {
             // Image extraction is enabled, return images or empty vector
@@ -205,6 +231,11 @@ impl DocumentExtractor for PptxExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -221,13 +252,19 @@ impl DocumentExtractor for PptxExtractor {
 
         let extract_images = config.images.as_ref().is_some_and(|img| img.extract_images);
 
-        let pptx_result =
-            crate::extraction::pptx::extract_pptx_from_path(path_str, extract_images, config.pages.as_ref())?;
+        let pptx_result = crate::extraction::pptx::extract_pptx_from_path_with_image_config(
+            path_str,
+            config.images.as_ref(),
+            config.pages.as_ref(),
+        )?;
 
         let mut additional: AHashMap<Cow<'static, str>, serde_json::Value> = AHashMap::new();
         additional.insert(Cow::Borrowed("slide_count"), serde_json::json!(pptx_result.slide_count));
         additional.insert(Cow::Borrowed("image_count"), serde_json::json!(pptx_result.image_count));
         additional.insert(Cow::Borrowed("table_count"), serde_json::json!(pptx_result.table_count));
+        if pptx_result.images_truncated {
+            additional.insert(Cow::Borrowed("images_truncated"), serde_json::json!(true));
+        }
 
         let images = if extract_images {
             // Image extraction is enabled, return images or empty vector
@@ -272,6 +309,11 @@ impl DocumentExtractor for PptxExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -347,6 +347,9 @@ impl RstExtractor {
             cells,
             markdown,
             page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         })
     }
 
@@ -460,6 +463,11 @@ impl DocumentExtractor for RstExtractor {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
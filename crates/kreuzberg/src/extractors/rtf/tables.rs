@@ -72,6 +72,9 @@ impl TableState {
             cells: self.rows,
             markdown,
             page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         })
     }
 }
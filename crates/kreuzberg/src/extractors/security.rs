@@ -26,7 +26,8 @@ pub struct SecurityLimits {
     /// Maximum number of files in archive (10,000)
     pub max_files_in_archive: usize,
 
-    /// Maximum nesting depth for structures (100)
+    /// Maximum nesting depth for structures: HTML elements and archives within
+    /// archives (100)
     pub max_nesting_depth: usize,
 
     /// Maximum entity/string length (32)
@@ -48,12 +49,12 @@ pub struct SecurityLimits {
 impl Default for SecurityLimits {
     fn default() -> Self {
         Self {
-            max_archive_size: 500 * 1024 * 1024,
+            max_archive_size: crate::core::config::extraction::DEFAULT_MAX_ARCHIVE_SIZE,
             max_compression_ratio: 100,
             max_files_in_archive: 10_000,
-            max_nesting_depth: 100,
+            max_nesting_depth: crate::core::config::extraction::DEFAULT_MAX_NESTING_DEPTH,
             max_entity_length: 32,
-            max_content_size: 100 * 1024 * 1024,
+            max_content_size: crate::core::config::extraction::DEFAULT_MAX_CONTENT_SIZE,
             max_iterations: 10_000_000,
             max_xml_depth: 100,
             max_table_cells: 100_000,
@@ -175,7 +176,8 @@ impl ZipBombValidator {
         let mut total_compressed: u64 = 0;
 
         for i in 0..file_count {
-            if let Ok(file) = archive.by_index(i) {
+            // Raw access reads sizes without decompressing or decrypting the entry.
+            if let Ok(file) = archive.by_index_raw(i) {
                 let compressed_size = file.compressed_size();
                 let uncompressed_size = file.size();
 
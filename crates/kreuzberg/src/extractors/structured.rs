@@ -66,7 +66,7 @@ impl DocumentExtractor for StructuredExtractor {
                 crate::extraction::structured::parse_yaml(content)?
             }
             "application/toml" | "text/toml" => crate::extraction::structured::parse_toml(content)?,
-            _ => return Err(crate::KreuzbergError::UnsupportedFormat(mime_type.to_string())),
+            _ => return Err(crate::KreuzbergError::unsupported_format(mime_type)),
         };
 
         let mut additional = AHashMap::new();
@@ -99,6 +99,11 @@ impl DocumentExtractor for StructuredExtractor {
             djot_content: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
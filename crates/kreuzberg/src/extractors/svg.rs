@@ -0,0 +1,338 @@
+//! SVG extractor.
+//!
+//! SVG text lives in `<text>` elements and can be read straight from the XML without
+//! rasterization. Text that was converted to outlines (`<path>` glyphs) is only
+//! recoverable by rendering the image and running OCR, which happens when `force_ocr`
+//! is set.
+
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::plugins::{DocumentExtractor, Plugin};
+use crate::types::{ExtractionResult, FormatMetadata, Metadata, XmlMetadata};
+use async_trait::async_trait;
+use std::collections::BTreeSet;
+
+/// Text and structure read from an SVG document.
+struct SvgText {
+    /// One line per `<text>` element, or per positioned `<tspan>`.
+    content: String,
+    /// Document `<title>`, if present.
+    title: Option<String>,
+    element_count: usize,
+    unique_elements: Vec<String>,
+}
+
+/// SVG extractor.
+///
+/// Concatenates `<text>`/`<tspan>` content in document order. With `force_ocr`, the
+/// image is rasterized and OCR'd instead so outlined text is recovered as well.
+pub struct SvgExtractor;
+
+impl SvgExtractor {
+    /// Create a new SVG extractor.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Rasterize the SVG and run OCR on the rendered image.
+    #[cfg(feature = "ocr")]
+    async fn extract_with_ocr(&self, content: &[u8], config: &ExtractionConfig) -> Result<String> {
+        let png = rasterize_svg(content, config.max_decode_dimension())?;
+        let ocr_config = config.ocr.clone().unwrap_or_default();
+
+        let mut backends = crate::ocr::OcrBackendChain::from_config(&ocr_config)?;
+        let ocr_result = backends.process_image(&png, &ocr_config).await?;
+        Ok(ocr_result.content)
+    }
+}
+
+impl Default for SvgExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for SvgExtractor {
+    fn name(&self) -> &str {
+        "svg-extractor"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn description(&self) -> &str {
+        "Extracts text elements from SVG images, with optional OCR for outlined text"
+    }
+
+    fn author(&self) -> &str {
+        "Kreuzberg Team"
+    }
+}
+
+#[async_trait]
+impl DocumentExtractor for SvgExtractor {
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, content, config),
+        fields(
+            extractor.name = self.name(),
+            content.size_bytes = content.len(),
+        )
+    ))]
+    async fn extract_bytes(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let svg = parse_svg_text(content)?;
+
+        #[cfg_attr(not(feature = "ocr"), allow(unused_mut))]
+        let mut text = svg.content;
+        #[cfg_attr(not(feature = "ocr"), allow(unused_mut))]
+        let mut ocr_used = false;
+
+        #[cfg(feature = "ocr")]
+        if config.force_ocr {
+            let ocr_text = self.extract_with_ocr(content, config).await?;
+            if !ocr_text.trim().is_empty() {
+                text = ocr_text;
+                ocr_used = true;
+            }
+        }
+        #[cfg(not(feature = "ocr"))]
+        let _ = config;
+
+        Ok(ExtractionResult {
+            content: text,
+            mime_type: mime_type.to_string().into(),
+            metadata: Metadata {
+                title: svg.title,
+                format: Some(FormatMetadata::Xml(XmlMetadata {
+                    element_count: svg.element_count,
+                    unique_elements: svg.unique_elements,
+                })),
+                ocr_used,
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            djot_content: None,
+            elements: None,
+            ocr_elements: None,
+            document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
+        })
+    }
+
+    fn supported_mime_types(&self) -> &[&str] {
+        &["image/svg+xml"]
+    }
+
+    /// Takes precedence over the generic XML extractor for SVG.
+    fn priority(&self) -> i32 {
+        60
+    }
+}
+
+fn parse_svg_text(content: &[u8]) -> Result<SvgText> {
+    let xml = String::from_utf8_lossy(content);
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+    let doc = roxmltree::Document::parse_with_options(&xml, options)
+        .map_err(|e| crate::KreuzbergError::parsing(format!("Failed to parse SVG: {}", e)))?;
+
+    let root = doc.root_element();
+    let title = root
+        .children()
+        .find(|node| node.has_tag_name("title"))
+        .and_then(|node| node.text())
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty());
+
+    let mut element_count = 0;
+    let mut unique_elements = BTreeSet::new();
+    let mut lines = Vec::new();
+    for node in root.descendants().filter(|node| node.is_element()) {
+        element_count += 1;
+        unique_elements.insert(node.tag_name().name().to_string());
+
+        if node.has_tag_name("text") {
+            let mut current = String::new();
+            collect_text(node, &mut lines, &mut current);
+            flush_line(&mut lines, &mut current);
+        }
+    }
+
+    Ok(SvgText {
+        content: lines.join("\n"),
+        title,
+        element_count,
+        unique_elements: unique_elements.into_iter().collect(),
+    })
+}
+
+/// Append the character data of a `<text>` element to `current`, starting a new line
+/// at every `<tspan>` that sets its own position.
+fn collect_text(node: roxmltree::Node, lines: &mut Vec<String>, current: &mut String) {
+    for child in node.children() {
+        if child.is_text() {
+            current.push_str(child.text().unwrap_or_default());
+            continue;
+        }
+        match child.tag_name().name() {
+            "tspan" => {
+                if ["x", "y", "dy"].iter().any(|attr| child.has_attribute(*attr)) {
+                    flush_line(lines, current);
+                }
+                collect_text(child, lines, current);
+            }
+            "textPath" | "a" => collect_text(child, lines, current),
+            _ => {}
+        }
+    }
+}
+
+fn flush_line(lines: &mut Vec<String>, current: &mut String) {
+    let line = current.split_whitespace().collect::<Vec<_>>().join(" ");
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    current.clear();
+}
+
+/// System fonts for SVG rendering, loaded once per process.
+#[cfg(feature = "ocr")]
+static FONTDB: std::sync::OnceLock<std::sync::Arc<resvg::usvg::fontdb::Database>> = std::sync::OnceLock::new();
+
+/// Render the SVG onto a white background as PNG for OCR.
+///
+/// The image is scaled so that its longer side is roughly 2000px, which keeps small
+/// vector text legible to OCR engines. Neither side exceeds `max_dimension` (0 means
+/// no limit), so huge declared canvas sizes are scaled down instead of allocated.
+#[cfg(feature = "ocr")]
+fn rasterize_svg(content: &[u8], max_dimension: u32) -> Result<Vec<u8>> {
+    use resvg::{tiny_skia, usvg};
+
+    const TARGET_LONG_SIDE: f32 = 2000.0;
+
+    let options = usvg::Options {
+        fontdb: FONTDB
+            .get_or_init(|| {
+                let mut fontdb = usvg::fontdb::Database::new();
+                fontdb.load_system_fonts();
+                std::sync::Arc::new(fontdb)
+            })
+            .clone(),
+        ..Default::default()
+    };
+    let tree = usvg::Tree::from_data(content, &options)
+        .map_err(|e| crate::KreuzbergError::parsing(format!("Failed to parse SVG for rendering: {}", e)))?;
+
+    let limit = if max_dimension == 0 { u32::MAX } else { max_dimension };
+    let size = tree.size();
+    let long_side = size.width().max(size.height());
+    let scale = (TARGET_LONG_SIDE / long_side)
+        .clamp(1.0, 8.0)
+        .min(limit as f32 / long_side);
+    let width = ((size.width() * scale).ceil() as u32).min(limit);
+    let height = ((size.height() * scale).ceil() as u32).min(limit);
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height).ok_or_else(|| crate::KreuzbergError::ImageProcessing {
+        message: format!("Invalid SVG render size {}x{}", width, height),
+        source: None,
+    })?;
+    pixmap.fill(tiny_skia::Color::WHITE);
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap.encode_png().map_err(|e| crate::KreuzbergError::ImageProcessing {
+        message: format!("Failed to encode rendered SVG: {}", e),
+        source: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SVG: &[u8] = br#"<?xml version="1.0"?>
+<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+  <title> Chart </title>
+  <text x="0" y="10">Revenue by <tspan font-weight="bold">Quarter</tspan></text>
+  <text><tspan x="0" y="20">Q1</tspan><tspan x="40" y="20">Q2</tspan></text>
+  <path d="M0 0 h10 v10 z" />
+</svg>"#;
+
+    #[tokio::test]
+    async fn test_svg_extracts_text_in_document_order() {
+        let extractor = SvgExtractor::new();
+        let result = extractor
+            .extract_bytes(SVG, "image/svg+xml", &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.content, "Revenue by Quarter\nQ1\nQ2");
+        assert_eq!(result.metadata.title.as_deref(), Some("Chart"));
+        assert!(!result.metadata.ocr_used);
+        match result.metadata.format {
+            Some(FormatMetadata::Xml(meta)) => {
+                assert_eq!(meta.element_count, 8);
+                assert!(meta.unique_elements.contains(&"tspan".to_string()));
+            }
+            other => panic!("Expected Xml metadata, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_svg_without_text_is_empty() {
+        let svg =
+            parse_svg_text(br#"<svg xmlns="http://www.w3.org/2000/svg"><rect width="1" height="1"/></svg>"#).unwrap();
+        assert!(svg.content.is_empty());
+        assert!(svg.title.is_none());
+    }
+
+    #[test]
+    fn test_invalid_svg_is_parsing_error() {
+        let result = parse_svg_text(b"<svg><text>unclosed");
+        assert!(matches!(result, Err(crate::KreuzbergError::Parsing { .. })));
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_rasterize_svg_respects_max_dimension() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="100000" height="50"><rect width="10" height="10"/></svg>"#;
+        let png = rasterize_svg(svg, 1000).unwrap();
+        let (width, height) = image::load_from_memory(&png).unwrap().into_rgb8().dimensions();
+        assert_eq!(width, 1000);
+        assert!(height <= 1000);
+    }
+
+    #[test]
+    fn test_svg_plugin_interface() {
+        let extractor = SvgExtractor::new();
+        assert_eq!(extractor.name(), "svg-extractor");
+        assert_eq!(extractor.supported_mime_types(), &["image/svg+xml"]);
+        assert!(extractor.priority() > crate::extractors::XmlExtractor::new().priority());
+    }
+}
@@ -1,5 +1,6 @@
 //! Plain text and Markdown extractors.
 
+use super::frontmatter_utils::{extract_frontmatter, extract_metadata_from_yaml};
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::text::parse_text;
@@ -95,6 +96,11 @@ impl DocumentExtractor for PlainTextExtractor {
             djot_content: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -150,7 +156,7 @@ impl Plugin for MarkdownExtractor {
     }
 
     fn description(&self) -> &str {
-        "Extracts content from Markdown files with metadata parsing"
+        "Extracts content from Markdown files with metadata and frontmatter parsing"
     }
 
     fn author(&self) -> &str {
@@ -173,22 +179,30 @@ impl DocumentExtractor for MarkdownExtractor {
         mime_type: &str,
         _config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let text_result = parse_text(content, true)?;
+        let text = String::from_utf8_lossy(content);
+        let (frontmatter, remaining) = extract_frontmatter(&text);
+
+        let (text_result, mut metadata) = match frontmatter {
+            Some(yaml) => (
+                parse_text(remaining.as_bytes(), true)?,
+                extract_metadata_from_yaml(&yaml),
+            ),
+            None => (parse_text(content, true)?, crate::types::Metadata::default()),
+        };
+
+        metadata.format = Some(crate::types::FormatMetadata::Text(crate::types::TextMetadata {
+            line_count: text_result.line_count,
+            word_count: text_result.word_count,
+            character_count: text_result.character_count,
+            headers: text_result.headers,
+            links: text_result.links,
+            code_blocks: text_result.code_blocks,
+        }));
 
         Ok(ExtractionResult {
             content: text_result.content,
             mime_type: mime_type.to_string().into(),
-            metadata: crate::types::Metadata {
-                format: Some(crate::types::FormatMetadata::Text(crate::types::TextMetadata {
-                    line_count: text_result.line_count,
-                    word_count: text_result.word_count,
-                    character_count: text_result.character_count,
-                    headers: text_result.headers,
-                    links: text_result.links,
-                    code_blocks: text_result.code_blocks,
-                })),
-                ..Default::default()
-            },
+            metadata,
             pages: None,
             tables: vec![],
             detected_languages: None,
@@ -198,6 +212,11 @@ impl DocumentExtractor for MarkdownExtractor {
             djot_content: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -261,6 +280,81 @@ mod tests {
         assert!(text_meta.code_blocks.is_some());
     }
 
+    #[tokio::test]
+    async fn test_markdown_extractor_strips_yaml_frontmatter() {
+        let extractor = MarkdownExtractor::new();
+        let content = b"---\ntitle: Notes\ndate: 2024-03-01\ntags: [a, b]\nlayout: post\n---\n# Header\n\nBody";
+        let config = ExtractionConfig::default();
+
+        let result = extractor
+            .extract_bytes(content, "text/markdown", &config)
+            .await
+            .unwrap();
+
+        assert!(result.content.starts_with("# Header"));
+        assert!(!result.content.contains("layout"));
+        assert_eq!(
+            result.metadata.additional.get("title").and_then(|v| v.as_str()),
+            Some("Notes")
+        );
+        assert_eq!(result.metadata.created_at.as_deref(), Some("2024-03-01"));
+        assert_eq!(
+            result.metadata.additional.get("tags").and_then(|v| v.as_str()),
+            Some("a, b")
+        );
+        assert_eq!(
+            result
+                .metadata
+                .additional
+                .get("frontmatter.layout")
+                .and_then(|v| v.as_str()),
+            Some("post")
+        );
+        assert!(matches!(
+            result.metadata.format,
+            Some(crate::types::FormatMetadata::Text(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_markdown_extractor_strips_toml_frontmatter() {
+        let extractor = MarkdownExtractor::new();
+        let content = b"+++\ntitle = \"Notes\"\ndate = 2024-03-01T10:00:00Z\ndraft = false\n+++\n# Header\n\nBody";
+        let config = ExtractionConfig::default();
+
+        let result = extractor
+            .extract_bytes(content, "text/markdown", &config)
+            .await
+            .unwrap();
+
+        assert!(result.content.starts_with("# Header"));
+        assert_eq!(
+            result.metadata.additional.get("title").and_then(|v| v.as_str()),
+            Some("Notes")
+        );
+        assert_eq!(result.metadata.created_at.as_deref(), Some("2024-03-01T10:00:00Z"));
+        assert_eq!(
+            result.metadata.additional.get("frontmatter.draft"),
+            Some(&serde_json::Value::Bool(false))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_markdown_extractor_keeps_malformed_frontmatter_as_content() {
+        let extractor = MarkdownExtractor::new();
+        let content = b"+++\ntitle = = broken\n+++\n# Header";
+        let config = ExtractionConfig::default();
+
+        let result = extractor
+            .extract_bytes(content, "text/markdown", &config)
+            .await
+            .unwrap();
+
+        assert!(result.content.starts_with("+++"));
+        assert!(result.content.contains("# Header"));
+        assert!(!result.metadata.additional.contains_key("title"));
+    }
+
     #[test]
     fn test_plain_text_plugin_interface() {
         let extractor = PlainTextExtractor::new();
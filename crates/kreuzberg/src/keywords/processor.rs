@@ -119,6 +119,11 @@ machine learning that uses neural networks with multiple layers.
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -152,6 +157,11 @@ machine learning that uses neural networks with multiple layers.
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -181,6 +191,11 @@ machine learning that uses neural networks with multiple layers.
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -210,6 +225,11 @@ machine learning that uses neural networks with multiple layers.
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -250,6 +270,11 @@ machine learning that uses neural networks with multiple layers.
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let config_with_keywords = ExtractionConfig {
@@ -279,6 +304,11 @@ machine learning that uses neural networks with multiple layers.
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let long_result = ExtractionResult {
@@ -294,6 +324,11 @@ machine learning that uses neural networks with multiple layers.
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -111,7 +111,14 @@ fn run_rake_with_stopwords(
         keywords.retain(|k| k.score >= config.min_score);
     }
 
-    keywords.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    // Break score ties by text: RAKE collects its candidates in a hash map, so equally
+    // scored phrases would otherwise come out in a different order on every run.
+    keywords.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.text.cmp(&b.text))
+    });
 
     keywords.truncate(config.max_keywords);
 
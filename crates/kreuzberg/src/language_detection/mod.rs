@@ -52,6 +52,76 @@ pub fn detect_languages(text: &str, config: &LanguageDetectionConfig) -> Result<
     detect_multiple_languages(text, config)
 }
 
+/// Detect languages in text together with their confidence, most likely first.
+///
+/// Unlike [`detect_languages`], `config.enabled` is ignored: calling this function is
+/// the request to detect. Without `detect_multiple` the result is the single best guess
+/// for the whole text, kept only if its confidence reaches `min_confidence`. With
+/// `detect_multiple` the text is analysed in chunks as in [`detect_languages`] and each
+/// language is scored by the summed confidence of its chunks divided by the number of
+/// chunks, so the scores add up to at most 1.0.
+///
+/// Returns an empty list if no language could be detected.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::language_detection::rank_languages;
+/// use kreuzberg::core::config::LanguageDetectionConfig;
+///
+/// let config = LanguageDetectionConfig {
+///     enabled: true,
+///     min_confidence: 0.5,
+///     detect_multiple: false,
+/// };
+/// let ranked = rank_languages("Hello world! This is English text.", &config);
+/// assert_eq!(ranked[0].0, "eng");
+/// ```
+pub fn rank_languages(text: &str, config: &LanguageDetectionConfig) -> Vec<(String, f64)> {
+    if text.trim().is_empty() {
+        return Vec::new();
+    }
+
+    let single = || match detect(text) {
+        Some(info) if info.confidence() >= config.min_confidence => {
+            vec![(lang_to_iso639_3(info.lang()), info.confidence())]
+        }
+        _ => Vec::new(),
+    };
+
+    if !config.detect_multiple {
+        return single();
+    }
+
+    const CHUNK_SIZE: usize = 200;
+    let char_vec: Vec<char> = text.chars().collect();
+    let chunk_count = char_vec.len().div_ceil(CHUNK_SIZE);
+    let threshold = config.min_confidence.min(0.35);
+
+    let mut scores: Vec<(Lang, f64)> = Vec::new();
+    for chunk in char_vec.chunks(CHUNK_SIZE) {
+        let chunk: String = chunk.iter().collect();
+        if let Some(info) = detect(&chunk)
+            && info.confidence() >= threshold
+        {
+            match scores.iter_mut().find(|(lang, _)| *lang == info.lang()) {
+                Some((_, score)) => *score += info.confidence(),
+                None => scores.push((info.lang(), info.confidence())),
+            }
+        }
+    }
+
+    if scores.is_empty() {
+        return single();
+    }
+
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scores
+        .into_iter()
+        .map(|(lang, score)| (lang_to_iso639_3(lang), score / chunk_count as f64))
+        .collect()
+}
+
 /// Detect a single primary language in the text.
 fn detect_single_language(text: &str, config: &LanguageDetectionConfig) -> Result<Option<Vec<String>>> {
     match detect(text) {
@@ -305,6 +375,31 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_rank_languages_scores_and_orders() {
+        let english = "The quick brown fox jumps over the lazy dog while the farmer watches from the porch. ".repeat(6);
+        let spanish =
+            "El rápido zorro marrón salta sobre el perro perezoso mientras el granjero mira desde el porche. ";
+        let text = format!("{english}{spanish}");
+        let config = LanguageDetectionConfig {
+            enabled: false,
+            min_confidence: 0.3,
+            detect_multiple: true,
+        };
+
+        let ranked = rank_languages(&text, &config);
+        assert_eq!(ranked[0].0, "eng");
+        assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(ranked.iter().map(|(_, score)| score).sum::<f64>() <= 1.0 + f64::EPSILON);
+
+        let strict = LanguageDetectionConfig {
+            min_confidence: 0.99,
+            detect_multiple: false,
+            ..config
+        };
+        assert!(rank_languages("ok yes no", &strict).is_empty());
+    }
+
     #[test]
     fn test_lang_to_iso639_3() {
         assert_eq!(lang_to_iso639_3(Lang::Eng), "eng");
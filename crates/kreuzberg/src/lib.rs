@@ -34,6 +34,7 @@
 
 #![deny(unsafe_code)]
 
+pub mod build_info;
 pub mod cache;
 pub mod core;
 pub mod error;
@@ -57,6 +58,9 @@ pub mod chunking;
 #[cfg(feature = "embeddings")]
 pub mod embeddings;
 
+#[cfg(feature = "tokenize")]
+pub mod tokenize;
+
 #[cfg(feature = "ocr")]
 pub mod image;
 
@@ -72,37 +76,76 @@ pub mod keywords;
 #[cfg(feature = "ocr")]
 pub mod ocr;
 
-#[cfg(any(feature = "paddle-ocr", feature = "embeddings"))]
+#[cfg(any(feature = "paddle-ocr", feature = "embeddings", feature = "math-ocr"))]
 pub mod ort_discovery;
 
+#[cfg(feature = "math-ocr")]
+pub mod math_ocr;
+
 #[cfg(feature = "paddle-ocr")]
 pub mod paddle_ocr;
 
 #[cfg(feature = "pdf")]
 pub mod pdf;
 
+pub use build_info::{BuildInfo, build_info};
 pub use error::{KreuzbergError, Result};
 pub use types::*;
 
 #[cfg(feature = "tokio-runtime")]
-pub use core::extractor::{batch_extract_bytes, batch_extract_file};
-pub use core::extractor::{extract_bytes, extract_file};
+pub use core::extractor::{
+    batch_extract_bytes, batch_extract_file, batch_extract_file_lenient, batch_extract_file_with_configs,
+};
+pub use core::extractor::{extract_bytes, extract_bytes_range, extract_file, extract_tables_only};
 
-pub use core::extractor::{batch_extract_bytes_sync, extract_bytes_sync};
+pub use core::extractor::{batch_extract_bytes_sync, extract_bytes_range_sync, extract_bytes_sync};
 
 #[cfg(feature = "tokio-runtime")]
-pub use core::extractor::{batch_extract_file_sync, extract_file_sync};
+pub use core::extractor::{
+    batch_extract_file_lenient_sync, batch_extract_file_sync, batch_extract_file_with_configs_sync, extract_file_sync,
+};
+
+#[cfg(feature = "tokio-runtime")]
+pub use core::extractor::{extract_file_with_progress, extract_file_with_progress_sync, extract_tables_only_sync};
+
+#[cfg(feature = "language-detection")]
+pub use core::extractor::detect_document_language;
+
+#[cfg(all(feature = "tokio-runtime", feature = "language-detection"))]
+pub use core::extractor::detect_document_language_sync;
+pub use core::progress::ProgressEvent;
+
+#[cfg(any(feature = "pdf", feature = "ocr"))]
+pub use core::thumbnail::extract_thumbnail;
 
 pub use core::config::{
     ChunkerType, ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig, ImageExtractionConfig,
-    LanguageDetectionConfig, OcrConfig, OutputFormat, PageConfig, PostProcessorConfig, TokenReductionConfig,
+    ImageFormat, LanguageDetectionConfig, LineEnding, OcrConfig, OutputFormat, OutputMode, OverlapUnit, PageConfig,
+    PostProcessorConfig, TableDetection, TableExtractionConfig, TextNormalizerConfig, TokenReductionConfig,
 };
 
+#[cfg(feature = "api")]
+pub use api::openapi::result_json_schema;
+
 #[cfg(feature = "api")]
 pub use core::server_config::ServerConfig;
 
 #[cfg(feature = "pdf")]
-pub use core::config::{HierarchyConfig, PdfConfig};
+pub use core::config::{HierarchyConfig, PdfConfig, ReadingOrder};
+
+#[cfg(feature = "excel")]
+pub use core::config::{FormulaMode, XlsxConfig};
+
+#[cfg(feature = "office")]
+pub use core::config::{DocxConfig, JupyterConfig, NotebookOutputs};
+
+#[cfg(feature = "archives")]
+pub use core::config::{ArchiveConfig, ArchiveMode};
+
+#[cfg(feature = "einvoice")]
+pub use pdf::einvoice::{
+    EInvoice, EInvoiceLineItem, EInvoiceParty, EInvoiceSyntax, extract_einvoice, extract_einvoice_with_config,
+};
 
 #[cfg(feature = "paddle-ocr")]
 pub use paddle_ocr::{CacheStats, ModelManager, ModelPaths, PaddleLanguage, PaddleOcrBackend, PaddleOcrConfig};
@@ -110,13 +153,14 @@ pub use paddle_ocr::{CacheStats, ModelManager, ModelPaths, PaddleLanguage, Paddl
 pub use core::mime::{
     DOCX_MIME_TYPE, EXCEL_MIME_TYPE, HTML_MIME_TYPE, JSON_MIME_TYPE, MARKDOWN_MIME_TYPE, PDF_MIME_TYPE,
     PLAIN_TEXT_MIME_TYPE, POWER_POINT_MIME_TYPE, XML_MIME_TYPE, detect_mime_type, detect_mime_type_from_bytes,
-    detect_or_validate, get_extensions_for_mime, validate_mime_type,
+    detect_mime_type_from_reader, detect_or_validate, get_extensions_for_mime, validate_mime_type,
 };
 
 pub use core::formats::{KNOWN_FORMATS, is_valid_format_field};
 
 pub use plugins::registry::{
-    get_document_extractor_registry, get_ocr_backend_registry, get_post_processor_registry, get_validator_registry,
+    get_document_extractor_registry, get_image_captioner_registry, get_mime_detector_registry,
+    get_ocr_backend_registry, get_post_processor_registry, get_validator_registry,
 };
 
 #[cfg(feature = "embeddings")]
@@ -0,0 +1,27 @@
+//! Formula recognition (math OCR) using ONNX Runtime.
+//!
+//! Turns images of mathematical formulas into LaTeX with a vision encoder-decoder
+//! model. PDF extraction uses it when `PdfConfig.extract_math` is enabled: formula
+//! regions are found in the text layer, rendered, and passed to [`recognize_formulas`].
+//!
+//! # Model Files
+//!
+//! The recognizer needs four files from an encoder-decoder export in the layout
+//! produced by Hugging Face Optimum:
+//! - `encoder_model.onnx`: image encoder (`pixel_values` → `last_hidden_state`)
+//! - `decoder_model.onnx`: text decoder (`input_ids`, `encoder_hidden_states` → `logits`)
+//! - `tokenizer.json`: tokenizer used to decode LaTeX tokens
+//! - `config.json`: model config with the decoder start and end token ids
+//!
+//! By default they are downloaded on first use from the [`HF_REPO_ID`] repository
+//! into `$KREUZBERG_CACHE_DIR/math-ocr/` (or `.kreuzberg/math-ocr/`). For offline
+//! deployments, place the files in a directory and point [`MODEL_DIR_ENV`] at it;
+//! nothing is downloaded then.
+//!
+//! ONNX Runtime is loaded dynamically, as for PaddleOCR.
+
+mod model;
+mod recognizer;
+
+pub use model::{HF_REPO_ID, MODEL_DIR_ENV, MathModelPaths, ensure_models};
+pub use recognizer::{MathRecognizer, recognize_formulas};
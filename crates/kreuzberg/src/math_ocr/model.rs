@@ -0,0 +1,175 @@
+//! Provisioning of the formula recognition model files.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::KreuzbergError;
+
+/// HuggingFace repository the formula recognition model is downloaded from.
+///
+/// A TrOCR-style encoder-decoder trained to transcribe formula images to LaTeX,
+/// exported to ONNX.
+pub const HF_REPO_ID: &str = "breezedeus/pix2text-mfr";
+
+/// Environment variable naming a directory that already holds the model files.
+///
+/// When set, the model is loaded from there and never downloaded.
+pub const MODEL_DIR_ENV: &str = "KREUZBERG_MATH_OCR_MODEL_DIR";
+
+const ENCODER_FILE: &str = "encoder_model.onnx";
+const DECODER_FILE: &str = "decoder_model.onnx";
+const TOKENIZER_FILE: &str = "tokenizer.json";
+const CONFIG_FILE: &str = "config.json";
+
+const MODEL_FILES: &[&str] = &[ENCODER_FILE, DECODER_FILE, TOKENIZER_FILE, CONFIG_FILE];
+
+/// Paths to the files of the formula recognition model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MathModelPaths {
+    /// Image encoder ONNX model.
+    pub encoder: PathBuf,
+    /// Text decoder ONNX model.
+    pub decoder: PathBuf,
+    /// Tokenizer definition.
+    pub tokenizer: PathBuf,
+    /// Model config with the generation token ids.
+    pub config: PathBuf,
+}
+
+impl MathModelPaths {
+    /// Paths of the model files inside `dir`.
+    pub fn in_dir(dir: &Path) -> Self {
+        Self {
+            encoder: dir.join(ENCODER_FILE),
+            decoder: dir.join(DECODER_FILE),
+            tokenizer: dir.join(TOKENIZER_FILE),
+            config: dir.join(CONFIG_FILE),
+        }
+    }
+
+    fn missing(&self) -> Vec<&Path> {
+        [&self.encoder, &self.decoder, &self.tokenizer, &self.config]
+            .into_iter()
+            .map(PathBuf::as_path)
+            .filter(|path| !path.is_file())
+            .collect()
+    }
+}
+
+/// Make sure the model files are available locally and return their paths.
+///
+/// Uses the directory in [`MODEL_DIR_ENV`] when set. Otherwise the files are taken
+/// from the cache directory, downloading the missing ones from [`HF_REPO_ID`].
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if [`MODEL_DIR_ENV`] points at a directory
+/// without the model files, and `KreuzbergError::Plugin` if a download fails.
+pub fn ensure_models() -> Result<MathModelPaths, KreuzbergError> {
+    if let Ok(dir) = std::env::var(MODEL_DIR_ENV) {
+        return models_in_dir(Path::new(&dir));
+    }
+
+    let cache_dir = resolve_cache_dir();
+    let paths = MathModelPaths::in_dir(&cache_dir);
+    if paths.missing().is_empty() {
+        tracing::debug!(cache_dir = ?cache_dir, "Math OCR model found in cache");
+        return Ok(paths);
+    }
+
+    download_model(&cache_dir)?;
+    Ok(paths)
+}
+
+/// Model files in a user-provided directory, which must already hold all of them.
+fn models_in_dir(dir: &Path) -> Result<MathModelPaths, KreuzbergError> {
+    let paths = MathModelPaths::in_dir(dir);
+    let missing = paths.missing();
+    if !missing.is_empty() {
+        return Err(KreuzbergError::validation(format!(
+            "{} is set to '{}' but the math OCR model files are missing: {}",
+            MODEL_DIR_ENV,
+            dir.display(),
+            missing
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+    Ok(paths)
+}
+
+/// Cache directory for the model: `$KREUZBERG_CACHE_DIR/math-ocr` or `.kreuzberg/math-ocr`.
+fn resolve_cache_dir() -> PathBuf {
+    if let Ok(env_path) = std::env::var("KREUZBERG_CACHE_DIR") {
+        return PathBuf::from(env_path).join("math-ocr");
+    }
+
+    std::env::current_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join(".kreuzberg")
+        .join("math-ocr")
+}
+
+/// Download the missing model files into `cache_dir` via hf-hub.
+fn download_model(cache_dir: &Path) -> Result<(), KreuzbergError> {
+    fs::create_dir_all(cache_dir)?;
+
+    tracing::info!(repo = HF_REPO_ID, cache_dir = ?cache_dir, "Downloading math OCR model via hf-hub");
+
+    // hf-hub handles auth (HF_TOKEN env), caching, CDN, retries
+    let api = hf_hub::api::sync::ApiBuilder::new()
+        .with_progress(true)
+        .build()
+        .map_err(|e| KreuzbergError::Plugin {
+            message: format!("Failed to initialize HuggingFace Hub API: {}", e),
+            plugin_name: "math-ocr".to_string(),
+        })?;
+    let repo = api.model(HF_REPO_ID.to_string());
+
+    for file in MODEL_FILES {
+        let target = cache_dir.join(file);
+        if target.is_file() {
+            continue;
+        }
+
+        let cached_path = repo.get(file).map_err(|e| KreuzbergError::Plugin {
+            message: format!("Failed to download '{}' from {}: {}", file, HF_REPO_ID, e),
+            plugin_name: "math-ocr".to_string(),
+        })?;
+        fs::copy(&cached_path, &target).map_err(|e| KreuzbergError::Plugin {
+            message: format!("Failed to copy model file to {}: {}", target.display(), e),
+            plugin_name: "math-ocr".to_string(),
+        })?;
+    }
+
+    tracing::info!("Math OCR model ready");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_models_in_dir_with_all_files() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in MODEL_FILES {
+            fs::write(dir.path().join(file), b"stub").unwrap();
+        }
+
+        assert_eq!(models_in_dir(dir.path()).unwrap(), MathModelPaths::in_dir(dir.path()));
+    }
+
+    #[test]
+    fn test_models_in_dir_reports_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join(ENCODER_FILE), b"stub").unwrap();
+
+        let message = models_in_dir(dir.path()).unwrap_err().to_string();
+        assert!(message.contains(DECODER_FILE), "{message}");
+        assert!(message.contains(CONFIG_FILE), "{message}");
+        assert!(!message.contains(ENCODER_FILE), "{message}");
+    }
+}
@@ -0,0 +1,266 @@
+//! Greedy LaTeX decoding with the ONNX encoder-decoder model.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use image::DynamicImage;
+use ndarray::Array4;
+use ort::inputs;
+use ort::session::Session;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::value::Tensor;
+use tokenizers::Tokenizer;
+
+use super::model::{MathModelPaths, ensure_models};
+use crate::error::{KreuzbergError, Result};
+
+/// Side length of the square input image expected by the encoder.
+const IMAGE_SIZE: u32 = 384;
+
+/// Upper bound on generated tokens per formula.
+const MAX_TOKENS: usize = 256;
+
+/// Recognizer shared by all extractions; loading the model is expensive.
+static RECOGNIZER: Mutex<Option<MathRecognizer>> = Mutex::new(None);
+
+/// Token ids that start and end decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct GenerationIds {
+    decoder_start: i64,
+    eos: i64,
+}
+
+impl GenerationIds {
+    /// Read the ids from a Hugging Face `config.json`, looking in the decoder
+    /// sub-config when the top level does not define them.
+    fn from_config(config: &serde_json::Value) -> Result<Self> {
+        let lookup = |key: &str| {
+            config
+                .get(key)
+                .and_then(serde_json::Value::as_i64)
+                .or_else(|| config.get("decoder")?.get(key)?.as_i64())
+        };
+
+        let decoder_start = lookup("decoder_start_token_id").or_else(|| lookup("bos_token_id"));
+        match (decoder_start, lookup("eos_token_id")) {
+            (Some(decoder_start), Some(eos)) => Ok(Self { decoder_start, eos }),
+            _ => Err(KreuzbergError::parsing(
+                "Math OCR config.json does not define decoder_start_token_id and eos_token_id",
+            )),
+        }
+    }
+}
+
+/// Formula recognizer holding the loaded encoder, decoder and tokenizer.
+pub struct MathRecognizer {
+    encoder: Session,
+    decoder: Session,
+    tokenizer: Tokenizer,
+    ids: GenerationIds,
+    encoder_input: String,
+    decoder_ids_input: String,
+    decoder_hidden_input: String,
+}
+
+impl MathRecognizer {
+    /// Load the model from `paths`.
+    pub fn new(paths: &MathModelPaths) -> Result<Self> {
+        crate::ort_discovery::ensure_ort_available();
+
+        let encoder = load_session(&paths.encoder)?;
+        let decoder = load_session(&paths.decoder)?;
+
+        let tokenizer = Tokenizer::from_file(&paths.tokenizer)
+            .map_err(|e| model_error(format!("Failed to load math OCR tokenizer: {}", e)))?;
+
+        let config: serde_json::Value = serde_json::from_slice(&std::fs::read(&paths.config)?)
+            .map_err(|e| KreuzbergError::parsing(format!("Invalid math OCR config.json: {}", e)))?;
+        let ids = GenerationIds::from_config(&config)?;
+
+        let encoder_input = encoder
+            .inputs()
+            .first()
+            .map(|input| input.name().to_string())
+            .ok_or_else(|| model_error("Math OCR encoder has no inputs".to_string()))?;
+        let decoder_ids_input = find_input(&decoder, "input_ids")?;
+        let decoder_hidden_input = find_input(&decoder, "encoder_hidden_states")?;
+
+        Ok(Self {
+            encoder,
+            decoder,
+            tokenizer,
+            ids,
+            encoder_input,
+            decoder_ids_input,
+            decoder_hidden_input,
+        })
+    }
+
+    /// Transcribe one formula image to LaTeX.
+    pub fn recognize(&mut self, image: &DynamicImage) -> Result<String> {
+        let pixels = Tensor::from_array(preprocess(image)).map_err(ort_error)?;
+        let encoder_outputs = self
+            .encoder
+            .run(inputs![self.encoder_input.clone() => pixels])
+            .map_err(ort_error)?;
+        let (_, hidden) = encoder_outputs
+            .iter()
+            .next()
+            .ok_or_else(|| model_error("Math OCR encoder produced no output".to_string()))?;
+        let (shape, data) = hidden.try_extract_tensor::<f32>().map_err(ort_error)?;
+        let hidden_shape: Vec<usize> = shape.iter().map(|&dim| dim as usize).collect();
+        let hidden = Tensor::from_array((hidden_shape, data.to_vec())).map_err(ort_error)?;
+        drop(encoder_outputs);
+
+        let mut tokens = vec![self.ids.decoder_start];
+        while tokens.len() <= MAX_TOKENS {
+            let input_ids = Tensor::from_array(([1usize, tokens.len()], tokens.clone())).map_err(ort_error)?;
+            let outputs = self
+                .decoder
+                .run(inputs![
+                    self.decoder_ids_input.clone() => input_ids,
+                    self.decoder_hidden_input.clone() => &hidden,
+                ])
+                .map_err(ort_error)?;
+            let (_, logits) = outputs
+                .iter()
+                .next()
+                .ok_or_else(|| model_error("Math OCR decoder produced no output".to_string()))?;
+            let (shape, logits) = logits.try_extract_tensor::<f32>().map_err(ort_error)?;
+
+            let vocab_size = shape.last().copied().unwrap_or_default() as usize;
+            let next = last_position_argmax(logits, vocab_size)
+                .ok_or_else(|| model_error("Math OCR decoder returned empty logits".to_string()))?;
+            if next == self.ids.eos {
+                break;
+            }
+            tokens.push(next);
+        }
+
+        let ids: Vec<u32> = tokens[1..].iter().map(|&id| id as u32).collect();
+        let latex = self
+            .tokenizer
+            .decode(&ids, true)
+            .map_err(|e| model_error(format!("Failed to decode math OCR tokens: {}", e)))?;
+        Ok(latex.trim().to_string())
+    }
+}
+
+/// Transcribe formula images to LaTeX, in order.
+///
+/// The model is loaded (and downloaded if needed, see [`ensure_models`]) on the first
+/// call and kept for the lifetime of the process.
+///
+/// # Errors
+///
+/// Returns an error if the model cannot be provisioned or loaded, or inference fails.
+pub fn recognize_formulas(images: &[DynamicImage]) -> Result<Vec<String>> {
+    if images.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let mut guard = RECOGNIZER
+        .lock()
+        .expect("Math OCR recognizer lock poisoned - critical runtime error");
+    if guard.is_none() {
+        *guard = Some(MathRecognizer::new(&ensure_models()?)?);
+    }
+    let recognizer = guard
+        .as_mut()
+        .ok_or_else(|| model_error("Math OCR recognizer is not initialized".to_string()))?;
+
+    images.iter().map(|image| recognizer.recognize(image)).collect()
+}
+
+fn load_session(path: &Path) -> Result<Session> {
+    Session::builder()
+        .and_then(|builder| builder.with_optimization_level(GraphOptimizationLevel::Level3))
+        .and_then(|builder| builder.commit_from_file(path))
+        .map_err(|e| model_error(format!("Failed to load math OCR model {}: {}", path.display(), e)))
+}
+
+fn find_input(session: &Session, name: &str) -> Result<String> {
+    session
+        .inputs()
+        .iter()
+        .map(|input| input.name())
+        .find(|input| input.contains(name))
+        .map(str::to_string)
+        .ok_or_else(|| model_error(format!("Math OCR decoder has no '{}' input", name)))
+}
+
+/// Resize to the encoder's square input and normalize RGB to `[-1, 1]`, NCHW.
+fn preprocess(image: &DynamicImage) -> Array4<f32> {
+    let rgb = image::imageops::resize(
+        &image.to_rgb8(),
+        IMAGE_SIZE,
+        IMAGE_SIZE,
+        image::imageops::FilterType::Triangle,
+    );
+
+    let mut pixels = Array4::zeros((1, 3, IMAGE_SIZE as usize, IMAGE_SIZE as usize));
+    for (x, y, pixel) in rgb.enumerate_pixels() {
+        for channel in 0..3 {
+            pixels[[0, channel, y as usize, x as usize]] = pixel[channel] as f32 / 127.5 - 1.0;
+        }
+    }
+    pixels
+}
+
+/// Most likely token at the last sequence position of `[1, seq, vocab]` logits.
+fn last_position_argmax(logits: &[f32], vocab_size: usize) -> Option<i64> {
+    if vocab_size == 0 || logits.len() < vocab_size {
+        return None;
+    }
+
+    logits[logits.len() - vocab_size..]
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index as i64)
+}
+
+fn ort_error(e: ort::Error) -> KreuzbergError {
+    model_error(format!("Math OCR inference failed: {}", e))
+}
+
+fn model_error(message: String) -> KreuzbergError {
+    KreuzbergError::Ocr { message, source: None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generation_ids_from_config() {
+        let config = serde_json::json!({"decoder_start_token_id": 2, "decoder": {"eos_token_id": 3}});
+        assert_eq!(
+            GenerationIds::from_config(&config).unwrap(),
+            GenerationIds {
+                decoder_start: 2,
+                eos: 3
+            }
+        );
+
+        assert!(GenerationIds::from_config(&serde_json::json!({"eos_token_id": 3})).is_err());
+    }
+
+    #[test]
+    fn test_preprocess_resizes_and_normalizes() {
+        let image = DynamicImage::ImageRgb8(image::RgbImage::from_pixel(40, 10, image::Rgb([255, 0, 255])));
+        let pixels = preprocess(&image);
+
+        assert_eq!(pixels.shape(), &[1, 3, IMAGE_SIZE as usize, IMAGE_SIZE as usize]);
+        assert_eq!(pixels[[0, 0, 0, 0]], 1.0);
+        assert_eq!(pixels[[0, 1, 100, 100]], -1.0);
+    }
+
+    #[test]
+    fn test_last_position_argmax() {
+        let logits = [0.9, 0.1, 0.0, 0.2, 0.1, 0.7];
+        assert_eq!(last_position_argmax(&logits, 3), Some(2));
+        assert_eq!(last_position_argmax(&logits, 0), None);
+    }
+}
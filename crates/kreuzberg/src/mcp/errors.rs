@@ -27,9 +27,7 @@ pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
             McpError::invalid_params(error_message, None)
         }
 
-        KreuzbergError::UnsupportedFormat(mime_type) => {
-            McpError::invalid_params(format!("Unsupported format: {}", mime_type), None)
-        }
+        error @ KreuzbergError::UnsupportedFormat { .. } => McpError::invalid_params(error.to_string(), None),
 
         KreuzbergError::MissingDependency(dep) => McpError::invalid_params(
             format!(
@@ -120,7 +118,7 @@ mod tests {
 
     #[test]
     fn test_map_unsupported_format_to_invalid_params() {
-        let error = KreuzbergError::UnsupportedFormat("application/unknown".to_string());
+        let error = KreuzbergError::unsupported_format("application/unknown");
         let mcp_error = map_kreuzberg_error_to_mcp(error);
 
         assert_eq!(mcp_error.code.0, -32602);
@@ -286,7 +284,7 @@ mod tests {
     fn test_all_error_variants_have_mappings() {
         let errors = vec![
             KreuzbergError::validation("test"),
-            KreuzbergError::UnsupportedFormat("test/unknown".to_string()),
+            KreuzbergError::unsupported_format("test/unknown"),
             KreuzbergError::MissingDependency("test-dep".to_string()),
             KreuzbergError::parsing("test"),
             KreuzbergError::Io(std::io::Error::other("test")),
@@ -302,6 +302,11 @@ mod tests {
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let formatted = format_extraction_result(&result);
@@ -325,6 +330,9 @@ mod tests {
                 ],
                 page_number: 1,
                 markdown: "| Col1 | Col2 |\n|------|------|\n| A    | B    |".to_string(),
+                name: None,
+                has_header: false,
+                cell_spans: Vec::new(),
             }],
             detected_languages: None,
             chunks: None,
@@ -334,6 +342,11 @@ mod tests {
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let formatted = format_extraction_result(&result);
@@ -362,6 +375,7 @@ mod tests {
                     total_chunks: 1,
                     first_page: None,
                     last_page: None,
+                    heading_path: None,
                 },
             }]),
             images: None,
@@ -370,6 +384,11 @@ mod tests {
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let formatted = format_extraction_result(&result);
@@ -394,6 +413,11 @@ mod tests {
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let formatted = format_extraction_result(&result);
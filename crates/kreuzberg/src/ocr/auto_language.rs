@@ -0,0 +1,203 @@
+//! Automatic OCR language selection.
+//!
+//! When `OcrConfig.auto_language` is enabled, Tesseract's orientation and script
+//! detection (OSD) runs on a downsampled copy of the first page and the detected script
+//! is mapped to a traineddata language (Cyrillic → `rus`, Arabic → `ara`, ...).
+//!
+//! Script detection cannot tell Latin-script languages apart, so Latin pages keep the
+//! configured language. The configured language is also kept when detection is
+//! uncertain, `osd.traineddata` is not installed, or the mapped language is not installed.
+
+use crate::core::config::OcrConfig;
+use crate::ocr::processor::resolve_tessdata_path;
+use kreuzberg_tesseract::{TessPageSegMode, TesseractAPI};
+use std::path::Path;
+
+/// Longest side, in pixels, of the image handed to script detection.
+const DETECTION_MAX_DIMENSION: u32 = 1024;
+
+/// Minimum Tesseract script confidence for a detection to be used.
+const MIN_SCRIPT_CONFIDENCE: f32 = 1.0;
+
+/// Tesseract OSD script names and the language model used for each.
+const SCRIPT_LANGUAGES: &[(&str, &str)] = &[
+    ("Arabic", "ara"),
+    ("Armenian", "hye"),
+    ("Bengali", "ben"),
+    ("Cyrillic", "rus"),
+    ("Devanagari", "hin"),
+    ("Ethiopic", "amh"),
+    ("Georgian", "kat"),
+    ("Greek", "ell"),
+    ("Gujarati", "guj"),
+    ("Gurmukhi", "pan"),
+    ("Han", "chi_sim"),
+    ("HanS", "chi_sim"),
+    ("HanT", "chi_tra"),
+    ("Hangul", "kor"),
+    ("Korean", "kor"),
+    ("Hebrew", "heb"),
+    ("Hiragana", "jpn"),
+    ("Katakana", "jpn"),
+    ("Japanese", "jpn"),
+    ("Kannada", "kan"),
+    ("Khmer", "khm"),
+    ("Lao", "lao"),
+    ("Malayalam", "mal"),
+    ("Myanmar", "mya"),
+    ("Oriya", "ori"),
+    ("Sinhala", "sin"),
+    ("Tamil", "tam"),
+    ("Telugu", "tel"),
+    ("Thai", "tha"),
+    ("Tibetan", "bod"),
+];
+
+/// Resolve `OcrConfig.auto_language` against a page image.
+///
+/// Returns `None` when `auto_language` is off or the backend is not Tesseract. Otherwise
+/// returns a copy of `config` with the selected language set (on `tesseract_config` too,
+/// if present) — the configured language when no better choice was found.
+pub async fn resolve_auto_language(image_bytes: &[u8], config: &OcrConfig) -> Option<OcrConfig> {
+    if !config.auto_language || config.backend != "tesseract" {
+        return None;
+    }
+
+    let fallback = configured_language(config).to_string();
+    let image_bytes = image_bytes.to_vec();
+    let language = tokio::task::spawn_blocking(move || detect_ocr_language(&image_bytes, &fallback))
+        .await
+        .unwrap_or_else(|_| configured_language(config).to_string());
+
+    let mut resolved = config.clone();
+    resolved.language = language.clone();
+    if let Some(tesseract) = resolved.tesseract_config.as_mut() {
+        tesseract.language = language;
+    }
+    Some(resolved)
+}
+
+/// Detect the script of `image_bytes` and return the matching Tesseract language,
+/// or `fallback` if detection is uncertain or the language is not installed.
+pub fn detect_ocr_language(image_bytes: &[u8], fallback: &str) -> String {
+    let tessdata_path = resolve_tessdata_path();
+    let detected = detect_script(image_bytes, &tessdata_path)
+        .and_then(|script| script_language(&script).map(|language| (script, language)));
+
+    match detected {
+        Some((_, language)) if traineddata_installed(&tessdata_path, language) => {
+            tracing::debug!("Auto-selected OCR language '{}'", language);
+            language.to_string()
+        }
+        Some((script, language)) => {
+            tracing::warn!(
+                "Detected {} script but '{}' traineddata is not installed; using '{}'",
+                script,
+                language,
+                fallback
+            );
+            fallback.to_string()
+        }
+        None => fallback.to_string(),
+    }
+}
+
+/// Map a Tesseract OSD script name to a language model. Latin and unknown scripts map to `None`.
+pub fn script_language(script: &str) -> Option<&'static str> {
+    SCRIPT_LANGUAGES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(script))
+        .map(|(_, language)| *language)
+}
+
+fn configured_language(config: &OcrConfig) -> &str {
+    config
+        .tesseract_config
+        .as_ref()
+        .map_or(config.language.as_str(), |tesseract| tesseract.language.as_str())
+}
+
+fn traineddata_installed(tessdata_path: &str, language: &str) -> bool {
+    // Without a known tessdata directory, leave it to Tesseract's own search path.
+    tessdata_path.is_empty()
+        || Path::new(tessdata_path)
+            .join(format!("{}.traineddata", language))
+            .exists()
+}
+
+fn detect_script(image_bytes: &[u8], tessdata_path: &str) -> Option<String> {
+    if tessdata_path.is_empty() || !Path::new(tessdata_path).join("osd.traineddata").exists() {
+        tracing::debug!("osd.traineddata not found; skipping OCR language detection");
+        return None;
+    }
+
+    let image = crate::extraction::image_header::decode_image(
+        image_bytes,
+        crate::core::config::extraction::DEFAULT_MAX_DECODE_DIMENSION,
+    )
+    .ok()?;
+    let image = if image.width().max(image.height()) > DETECTION_MAX_DIMENSION {
+        image.resize(
+            DETECTION_MAX_DIMENSION,
+            DETECTION_MAX_DIMENSION,
+            image::imageops::FilterType::Triangle,
+        )
+    } else {
+        image
+    };
+    let rgb = image.to_rgb8();
+    let (width, height) = rgb.dimensions();
+
+    let api = TesseractAPI::new();
+    api.init(tessdata_path, "osd").ok()?;
+    api.set_page_seg_mode(TessPageSegMode::PSM_OSD_ONLY).ok()?;
+    api.set_image(rgb.as_raw(), width as i32, height as i32, 3, (width * 3) as i32)
+        .ok()?;
+
+    let (_, _, script, confidence) = api.detect_os().ok()?;
+    tracing::debug!("OSD detected script '{}' (confidence {:.2})", script, confidence);
+    (!script.is_empty() && confidence >= MIN_SCRIPT_CONFIDENCE).then_some(script)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_language_mapping() {
+        assert_eq!(script_language("Cyrillic"), Some("rus"));
+        assert_eq!(script_language("arabic"), Some("ara"));
+        assert_eq!(script_language("HanT"), Some("chi_tra"));
+        assert_eq!(script_language("Latin"), None);
+        assert_eq!(script_language(""), None);
+    }
+
+    #[test]
+    fn test_undecodable_image_falls_back() {
+        assert_eq!(detect_ocr_language(b"not an image", "deu"), "deu");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_auto_language_requires_opt_in() {
+        let config = OcrConfig::default();
+        assert!(resolve_auto_language(b"", &config).await.is_none());
+
+        let config = OcrConfig {
+            auto_language: true,
+            backend: "paddleocr".to_string(),
+            ..Default::default()
+        };
+        assert!(resolve_auto_language(b"", &config).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_auto_language_keeps_configured_language_on_failure() {
+        let config = OcrConfig {
+            auto_language: true,
+            language: "fra".to_string(),
+            ..Default::default()
+        };
+        let resolved = resolve_auto_language(b"not an image", &config).await.unwrap();
+        assert_eq!(resolved.language, "fra");
+    }
+}
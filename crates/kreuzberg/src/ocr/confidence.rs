@@ -0,0 +1,108 @@
+//! Backend-independent OCR confidence.
+//!
+//! Each backend reports confidence on its own scale: Tesseract's mean word confidence runs
+//! from 0 to 100, PaddleOCR's recognition scores from 0.0 to 1.0. Backends record their raw
+//! value under [`OCR_CONFIDENCE_KEY`] and the value mapped by [`normalize_confidence`] under
+//! [`OCR_CONFIDENCE_NORMALIZED_KEY`], so a single threshold works whichever backend ran.
+
+use std::borrow::Cow;
+
+use ahash::AHashMap;
+
+/// Metadata key for the confidence on the backend's own scale.
+pub const OCR_CONFIDENCE_KEY: &str = "ocr_confidence";
+
+/// Metadata key for the confidence mapped to 0.0–1.0.
+pub const OCR_CONFIDENCE_NORMALIZED_KEY: &str = "ocr_confidence_normalized";
+
+/// Metadata key listing the 1-indexed pages where OCR found no text.
+///
+/// Only recorded when `OcrConfig.empty_page_confidence` is set, see [`is_empty_page`].
+pub const OCR_EMPTY_PAGES_KEY: &str = "ocr_empty_pages";
+
+/// Map a backend's raw confidence to 0.0–1.0.
+///
+/// The normalized value estimates the share of recognized text the backend is sure of:
+/// 1.0 is full confidence, 0.0 none.
+///
+/// - `tesseract`: mean word confidence (`MeanTextConf`, 0–100), divided by 100
+/// - `paddle-ocr`: mean recognition score of the detected text lines (0.0–1.0), unchanged
+/// - `easyocr`: mean recognition probability (0.0–1.0), unchanged
+///
+/// Values are clamped to 0.0–1.0. Returns `None` for other backends, whose scale is unknown.
+pub fn normalize_confidence(backend: &str, raw: f64) -> Option<f64> {
+    let normalized = match backend {
+        "tesseract" => raw / 100.0,
+        "paddle-ocr" | "paddleocr" | "easyocr" => raw,
+        _ => return None,
+    };
+    Some(normalized.clamp(0.0, 1.0))
+}
+
+/// Record `raw` and its normalized value in result metadata.
+pub fn insert_confidence(additional: &mut AHashMap<Cow<'static, str>, serde_json::Value>, backend: &str, raw: f64) {
+    additional.insert(Cow::Borrowed(OCR_CONFIDENCE_KEY), serde_json::json!(raw));
+    if let Some(normalized) = normalize_confidence(backend, raw) {
+        additional.insert(
+            Cow::Borrowed(OCR_CONFIDENCE_NORMALIZED_KEY),
+            serde_json::json!(normalized),
+        );
+    }
+}
+
+/// Whether OCR found no text on a page.
+///
+/// A page is empty when `content` has no visible characters or its normalized confidence
+/// is below `floor`. Pages without a normalized confidence are judged by their text alone.
+pub fn is_empty_page(content: &str, normalized: Option<f64>, floor: f64) -> bool {
+    content.trim().is_empty() || normalized.is_some_and(|confidence| confidence < floor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_tesseract() {
+        assert_eq!(normalize_confidence("tesseract", 0.0), Some(0.0));
+        assert_eq!(normalize_confidence("tesseract", 87.0), Some(0.87));
+        assert_eq!(normalize_confidence("tesseract", 100.0), Some(1.0));
+        assert_eq!(normalize_confidence("tesseract", -1.0), Some(0.0));
+    }
+
+    #[test]
+    fn test_normalize_probability_backends() {
+        assert_eq!(normalize_confidence("paddle-ocr", 0.93), Some(0.93));
+        assert_eq!(normalize_confidence("paddleocr", 1.02), Some(1.0));
+        assert_eq!(normalize_confidence("easyocr", 0.5), Some(0.5));
+    }
+
+    #[test]
+    fn test_normalize_unknown_backend() {
+        assert_eq!(normalize_confidence("custom-ocr", 0.5), None);
+    }
+
+    #[test]
+    fn test_insert_confidence() {
+        let mut additional = AHashMap::new();
+        insert_confidence(&mut additional, "tesseract", 91.0);
+        assert_eq!(additional.get(OCR_CONFIDENCE_KEY), Some(&serde_json::json!(91.0)));
+        assert_eq!(
+            additional.get(OCR_CONFIDENCE_NORMALIZED_KEY),
+            Some(&serde_json::json!(0.91))
+        );
+
+        let mut additional = AHashMap::new();
+        insert_confidence(&mut additional, "custom-ocr", 0.4);
+        assert!(additional.contains_key(OCR_CONFIDENCE_KEY));
+        assert!(!additional.contains_key(OCR_CONFIDENCE_NORMALIZED_KEY));
+    }
+
+    #[test]
+    fn test_is_empty_page() {
+        assert!(is_empty_page("  \n\t", Some(0.95), 0.0));
+        assert!(is_empty_page("~ ,", Some(0.2), 0.5));
+        assert!(!is_empty_page("Invoice 42", Some(0.9), 0.5));
+        assert!(!is_empty_page("Invoice 42", None, 0.5));
+    }
+}
@@ -0,0 +1,274 @@
+//! Fallback between OCR backends.
+//!
+//! [`OcrBackendChain`] resolves [`OcrConfig::backend`] followed by
+//! [`OcrConfig::fallback_backends`] against the OCR backend registry. Backends that are
+//! not registered (e.g. Tesseract is not installed) are skipped, and a backend that fails
+//! hands over to the next one in the chain.
+
+use std::sync::Arc;
+
+use crate::core::config::OcrConfig;
+use crate::core::config_validation::validate_ocr_region;
+use crate::plugins::OcrBackend;
+use crate::plugins::registry::OcrBackendRegistry;
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+
+use super::region::{crop_to_region, offset_elements};
+use super::retry::process_image_with_retry;
+
+/// The available OCR backends of a configuration, in fallback order.
+///
+/// The chain remembers the backend that last succeeded: once it has fallen back, later
+/// images (e.g. the remaining pages of a PDF) go straight to the working backend.
+pub struct OcrBackendChain {
+    backends: Vec<(String, Arc<dyn OcrBackend>)>,
+    active: usize,
+}
+
+impl OcrBackendChain {
+    /// Resolve the configured backends against the global OCR backend registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns the lookup error of the primary backend when none of the backends is registered,
+    /// or `KreuzbergError::Validation` when `config.region` is invalid.
+    pub fn from_config(config: &OcrConfig) -> Result<Self> {
+        if let Some(region) = &config.region {
+            validate_ocr_region(region)?;
+        }
+
+        let registry = crate::plugins::registry::get_ocr_backend_registry();
+        let registry = registry.read().map_err(|e| KreuzbergError::Plugin {
+            message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
+            plugin_name: "ocr-registry".to_string(),
+        })?;
+        Self::resolve(&registry, config)
+    }
+
+    fn resolve(registry: &OcrBackendRegistry, config: &OcrConfig) -> Result<Self> {
+        let mut backends: Vec<(String, Arc<dyn OcrBackend>)> = Vec::new();
+        let mut first_error = None;
+
+        for name in std::iter::once(&config.backend).chain(&config.fallback_backends) {
+            if backends.iter().any(|(existing, _)| existing == name) {
+                continue;
+            }
+            match registry.get(name) {
+                Ok(backend) => backends.push((name.clone(), backend)),
+                Err(e) => {
+                    if !config.fallback_backends.is_empty() {
+                        tracing::warn!(
+                            "OCR backend '{}' is not available, trying the next fallback: {}",
+                            name,
+                            e
+                        );
+                    }
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        if backends.is_empty() {
+            return Err(first_error.unwrap_or_else(|| KreuzbergError::Plugin {
+                message: "No OCR backend configured".to_string(),
+                plugin_name: "ocr-registry".to_string(),
+            }));
+        }
+        Ok(Self { backends, active: 0 })
+    }
+
+    /// Name of the backend the next image is sent to.
+    pub fn backend_name(&self) -> &str {
+        &self.backends[self.active].0
+    }
+
+    /// Run OCR on a page (a rendered PDF page or an image document), falling back to the
+    /// next backend when the current one fails.
+    ///
+    /// Like [`process_image`](Self::process_image), but when `config.region` is set only
+    /// that region of the page is recognized; element positions in the result still refer
+    /// to the full page.
+    pub async fn process_page(&mut self, page_bytes: &[u8], config: &OcrConfig) -> Result<ExtractionResult> {
+        let Some(region) = &config.region else {
+            return self.process_image(page_bytes, config).await;
+        };
+
+        let cropped = crop_to_region(page_bytes, region)?;
+        let mut result = self.process_image(&cropped.png, config).await?;
+        if let Some(elements) = result.ocr_elements.as_mut() {
+            offset_elements(elements, cropped.left, cropped.top);
+        }
+        Ok(result)
+    }
+
+    /// Run OCR on one whole image, falling back to the next backend when the current one fails.
+    ///
+    /// Each backend gets `config.max_retries` retries before the chain moves on. The error
+    /// of the last backend is returned when every backend fails. `config.region` is not
+    /// applied: images embedded in a document are not pages.
+    pub async fn process_image(&mut self, image_bytes: &[u8], config: &OcrConfig) -> Result<ExtractionResult> {
+        loop {
+            let (name, backend) = &self.backends[self.active];
+            match process_image_with_retry(backend.as_ref(), image_bytes, config).await {
+                Ok(result) => return Ok(result),
+                Err(e) if self.active + 1 < self.backends.len() => {
+                    tracing::warn!(
+                        "OCR backend '{}' failed, falling back to '{}': {}",
+                        name,
+                        self.backends[self.active + 1].0,
+                        e
+                    );
+                    self.active += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::{OcrBackendType, Plugin};
+    use crate::types::Metadata;
+    use async_trait::async_trait;
+    use std::borrow::Cow;
+
+    struct StubBackend {
+        name: &'static str,
+        fails: bool,
+    }
+
+    impl Plugin for StubBackend {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl OcrBackend for StubBackend {
+        async fn process_image(&self, _image_bytes: &[u8], _config: &OcrConfig) -> Result<ExtractionResult> {
+            if self.fails {
+                return Err(KreuzbergError::Ocr {
+                    message: format!("{} failed", self.name),
+                    source: None,
+                });
+            }
+            Ok(ExtractionResult {
+                content: self.name.to_string(),
+                mime_type: Cow::Borrowed("text/plain"),
+                metadata: Metadata::default(),
+                tables: vec![],
+                detected_languages: None,
+                chunks: None,
+                images: None,
+                pages: None,
+                djot_content: None,
+                elements: None,
+                ocr_elements: None,
+                document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
+            })
+        }
+
+        fn supports_language(&self, _lang: &str) -> bool {
+            true
+        }
+
+        fn backend_type(&self) -> OcrBackendType {
+            OcrBackendType::Custom
+        }
+    }
+
+    fn registry(backends: &[(&'static str, bool)]) -> OcrBackendRegistry {
+        let mut registry = OcrBackendRegistry::new_empty();
+        for &(name, fails) in backends {
+            registry.register(Arc::new(StubBackend { name, fails })).unwrap();
+        }
+        registry
+    }
+
+    fn config(backend: &str, fallback_backends: &[&str]) -> OcrConfig {
+        OcrConfig {
+            backend: backend.to_string(),
+            fallback_backends: fallback_backends.iter().map(|name| name.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_skips_unregistered_primary() {
+        let config = config("tesseract", &["paddle-ocr"]);
+        let mut chain = OcrBackendChain::resolve(&registry(&[("paddle-ocr", false)]), &config).unwrap();
+
+        assert_eq!(chain.backend_name(), "paddle-ocr");
+        assert_eq!(chain.process_image(&[], &config).await.unwrap().content, "paddle-ocr");
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_when_primary_fails() {
+        let config = config("tesseract", &["paddle-ocr"]);
+        let registry = registry(&[("tesseract", true), ("paddle-ocr", false)]);
+        let mut chain = OcrBackendChain::resolve(&registry, &config).unwrap();
+
+        assert_eq!(chain.process_image(&[], &config).await.unwrap().content, "paddle-ocr");
+        assert_eq!(chain.backend_name(), "paddle-ocr");
+    }
+
+    #[tokio::test]
+    async fn test_returns_last_error_when_all_backends_fail() {
+        let config = config("tesseract", &["paddle-ocr"]);
+        let registry = registry(&[("tesseract", true), ("paddle-ocr", true)]);
+        let mut chain = OcrBackendChain::resolve(&registry, &config).unwrap();
+
+        let error = chain.process_image(&[], &config).await.unwrap_err();
+        assert!(error.to_string().contains("paddle-ocr failed"));
+    }
+
+    #[tokio::test]
+    async fn test_region_only_applies_to_pages() {
+        let config = OcrConfig {
+            region: Some(crate::core::config::Rect {
+                left: 0.5,
+                top: 0.5,
+                width: 0.5,
+                height: 0.5,
+            }),
+            ..config("tesseract", &[])
+        };
+        let mut chain = OcrBackendChain::resolve(&registry(&[("tesseract", false)]), &config).unwrap();
+
+        // Pages are decoded to be cropped; whole images go to the backend untouched.
+        assert!(matches!(
+            chain.process_page(b"not an image", &config).await,
+            Err(KreuzbergError::ImageProcessing { .. })
+        ));
+        assert_eq!(
+            chain.process_image(b"not an image", &config).await.unwrap().content,
+            "tesseract"
+        );
+    }
+
+    #[test]
+    fn test_errors_without_any_registered_backend() {
+        let result = OcrBackendChain::resolve(&registry(&[]), &config("tesseract", &[]));
+        assert!(matches!(result, Err(KreuzbergError::Plugin { .. })));
+    }
+}
@@ -11,6 +11,10 @@
 //! - **hOCR to Markdown**: Convert hOCR format to clean Markdown
 //! - **Batch processing**: Process multiple images efficiently
 //! - **Language support**: Validate and configure Tesseract languages
+//! - **Auto language**: Choose the OCR language from the detected script
+//! - **Backend fallback**: Try further OCR backends when the configured one is unavailable or fails
+//! - **User words**: Bias recognition towards domain-specific terms
+//! - **Confidence**: Backend confidence mapped to a common 0.0-1.0 scale
 //! - **PSM modes**: Support for all Tesseract Page Segmentation Modes
 //!
 //! # Example
@@ -37,16 +41,22 @@
 //! [dependencies]
 //! kreuzberg = { version = "4.0", features = ["ocr"] }
 //! ```
+pub mod auto_language;
 mod backends;
 pub mod cache;
+pub mod confidence;
 pub mod conversion;
 pub mod error;
+pub mod fallback;
 pub mod hocr;
 pub mod language_registry;
 pub mod processor;
+pub mod region;
+pub mod retry;
 pub mod table;
 pub mod tesseract_backend;
 pub mod types;
+pub mod user_words;
 pub mod utils;
 pub mod validation;
 
@@ -55,9 +65,11 @@ pub use cache::{OcrCache, OcrCacheStats};
 pub use conversion::text_block_to_element;
 pub use conversion::{TsvRow, element_to_hocr_word, elements_to_hocr_words, tsv_row_to_element};
 pub use error::OcrError;
+pub use fallback::OcrBackendChain;
 pub use hocr::convert_hocr_to_markdown;
 pub use language_registry::LanguageRegistry;
 pub use processor::OcrProcessor;
+pub use retry::process_image_with_retry;
 pub use table::{HocrWord, extract_words_from_tsv, reconstruct_table, table_to_markdown};
 pub use tesseract_backend::TesseractBackend;
 pub use types::{BatchItemResult, ExtractionResult, PSMMode, Table, TesseractConfig};
@@ -40,6 +40,7 @@ pub(super) fn hash_config(config: &TesseractConfig) -> String {
     config.tessedit_use_primary_params_model.hash(&mut hasher);
     config.textord_space_size_is_variable.hash(&mut hasher);
     config.thresholding_method.hash(&mut hasher);
+    config.user_words.hash(&mut hasher);
 
     format!("{:016x}", hasher.finish())
 }
@@ -14,6 +14,7 @@ use crate::ocr::error::OcrError;
 use crate::ocr::hocr::convert_hocr_to_markdown;
 use crate::ocr::table::{extract_words_from_tsv, reconstruct_table, table_to_markdown};
 use crate::ocr::types::{BatchItemResult, TesseractConfig};
+use crate::ocr::user_words::UserWordsFile;
 use crate::types::{OcrExtractionResult, OcrTable};
 use kreuzberg_tesseract::{TessPageSegMode, TesseractAPI};
 use std::collections::HashMap;
@@ -22,6 +23,9 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::types::OcrElement;
 
+/// `OEM_DEFAULT`, the engine mode used by `TesseractAPI::init`.
+const TESSERACT_DEFAULT_OEM: i32 = 3;
+
 /// Parse Tesseract TSV output into structured OcrElements.
 ///
 /// TSV format columns: level, page_num, block_num, par_num, line_num, word_num, left, top, width, height, conf, text
@@ -154,8 +158,11 @@ pub(super) fn perform_ocr(
                 .map(image::DynamicImage::ImageLuma8)
                 .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode JBIG2 image: {}", e)))?
         } else {
-            image::load_from_memory(image_bytes)
-                .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode image: {}", e)))?
+            crate::extraction::image_header::decode_image(
+                image_bytes,
+                crate::core::config::extraction::DEFAULT_MAX_DECODE_DIMENSION,
+            )
+            .map_err(|e| OcrError::ImageProcessingFailed(e.to_string()))?
         }
     };
 
@@ -204,7 +211,24 @@ pub(super) fn perform_ocr(
     // Validate language and traineddata files
     validate_language_and_traineddata(&config.language, &tessdata_path)?;
 
-    let init_result = api.init(&tessdata_path, &config.language);
+    // user_words_file is only read during initialization, so it is passed as a config file.
+    let user_words_file = if config.user_words.is_empty() {
+        None
+    } else {
+        Some(
+            UserWordsFile::create(&config.user_words)
+                .map_err(|e| OcrError::InvalidConfiguration(format!("Failed to write user words file: {}", e)))?,
+        )
+    };
+    let init_result = match &user_words_file {
+        Some(file) => {
+            let config_path = file.config_path();
+            let config_path = config_path.to_string_lossy();
+            api.init_1(&tessdata_path, &config.language, TESSERACT_DEFAULT_OEM, &[&config_path])
+        }
+        None => api.init(&tessdata_path, &config.language),
+    };
+    drop(user_words_file);
     log_ci_debug(ci_debug_enabled, "init", || match &init_result {
         Ok(_) => format!("language={} datapath='{}'", config.language, tessdata_path),
         Err(err) => format!(
@@ -264,6 +288,9 @@ pub(super) fn perform_ocr(
 
     log_ci_debug(ci_debug_enabled, "recognize", || "completed".to_string());
 
+    // Mean word confidence (0-100); normalized by the Tesseract backend.
+    let mean_confidence = api.mean_text_conf().ok().filter(|confidence| *confidence >= 0);
+
     let tsv_data_for_tables = if config.enable_table_detection || config.output_format == "tsv" {
         Some(
             api.get_tsv_text(0)
@@ -335,6 +362,12 @@ pub(super) fn perform_ocr(
         "tables_detected".to_string(),
         serde_json::Value::String("0".to_string()),
     );
+    if let Some(confidence) = mean_confidence {
+        metadata.insert(
+            crate::ocr::confidence::OCR_CONFIDENCE_KEY.to_string(),
+            serde_json::json!(confidence),
+        );
+    }
     if config.output_format == "markdown" {
         metadata.insert(
             "source_format".to_string(),
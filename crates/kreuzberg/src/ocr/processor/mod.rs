@@ -9,6 +9,8 @@ mod config;
 mod execution;
 mod validation;
 
+pub(crate) use validation::resolve_tessdata_path;
+
 use crate::ocr::cache::OcrCache;
 use crate::ocr::error::OcrError;
 use crate::ocr::types::{BatchItemResult, TesseractConfig};
@@ -60,7 +60,7 @@ pub(super) fn validate_language_and_traineddata(language: &str, tessdata_path: &
 /// # Returns
 ///
 /// Path to tessdata directory if found, otherwise empty string
-pub(super) fn resolve_tessdata_path() -> String {
+pub(crate) fn resolve_tessdata_path() -> String {
     let tessdata_env = env::var("TESSDATA_PREFIX").ok();
     let fallback_paths = [
         "/opt/homebrew/share/tessdata",
@@ -0,0 +1,163 @@
+//! OCR region of interest.
+//!
+//! When [`OcrConfig::region`](crate::core::config::OcrConfig::region) is set, pages (PDF
+//! pages and image documents) are cropped to that region before recognition with
+//! [`crop_to_region`], and the positions the backend reports are moved back onto the full
+//! page with [`offset_elements`].
+
+use std::io::Cursor;
+
+use crate::core::config::Rect;
+use crate::types::{OcrBoundingGeometry, OcrElement};
+use crate::{KreuzbergError, Result};
+
+/// An image cropped to a region, with the pixel offset of the crop in the full image.
+#[derive(Debug)]
+pub struct CroppedImage {
+    /// The cropped image, PNG-encoded
+    pub png: Vec<u8>,
+    /// Left edge of the crop in the full image, in pixels
+    pub left: u32,
+    /// Top edge of the crop in the full image, in pixels
+    pub top: u32,
+}
+
+/// Crop an encoded image to a normalized region.
+///
+/// The region is widened to whole pixels and is at least one pixel wide and high.
+/// Multi-frame images are reduced to their first frame.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::ImageProcessing` if the image cannot be decoded or the crop
+/// cannot be encoded.
+pub fn crop_to_region(image_bytes: &[u8], region: &Rect) -> Result<CroppedImage> {
+    let image = crate::extraction::image_header::decode_image(
+        image_bytes,
+        crate::core::config::extraction::DEFAULT_MAX_DECODE_DIMENSION,
+    )?;
+
+    let (left, width) = pixel_span(region.left, region.width, image.width());
+    let (top, height) = pixel_span(region.top, region.height, image.height());
+    let cropped = image.crop_imm(left, top, width, height);
+
+    let mut png = Vec::new();
+    cropped
+        .write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)
+        .map_err(|e| KreuzbergError::image_processing_with_source("Failed to encode OCR region as PNG", e))?;
+
+    Ok(CroppedImage { png, left, top })
+}
+
+/// Start and length in pixels of a normalized span along an axis of `size` pixels.
+fn pixel_span(start: f64, length: f64, size: u32) -> (u32, u32) {
+    let size_f = size as f64;
+    let first = ((start * size_f).floor() as u32).min(size.saturating_sub(1));
+    let end = ((start + length) * size_f).ceil().min(size_f) as u32;
+    (first, end.saturating_sub(first).max(1))
+}
+
+/// Move the positions of OCR elements recognized on a crop onto the full image.
+pub fn offset_elements(elements: &mut [OcrElement], left: u32, top: u32) {
+    for element in elements {
+        match &mut element.geometry {
+            OcrBoundingGeometry::Rectangle { left: x, top: y, .. } => {
+                *x += left;
+                *y += top;
+            }
+            OcrBoundingGeometry::Quadrilateral { points } => {
+                for (x, y) in points.iter_mut() {
+                    *x += left;
+                    *y += top;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgb, RgbImage};
+
+    fn rect(left: f64, top: f64, width: f64, height: f64) -> Rect {
+        Rect {
+            left,
+            top,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_crop_to_region_keeps_only_the_region() {
+        // White image with a black 20x10 block at (60, 30).
+        let img: RgbImage = ImageBuffer::from_fn(100, 50, |x, y| {
+            if (60..80).contains(&x) && (30..40).contains(&y) {
+                Rgb([0, 0, 0])
+            } else {
+                Rgb([255, 255, 255])
+            }
+        });
+        let mut bytes = Vec::new();
+        img.write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .unwrap();
+
+        let cropped = crop_to_region(&bytes, &rect(0.6, 0.6, 0.2, 0.2)).unwrap();
+        assert_eq!((cropped.left, cropped.top), (60, 30));
+
+        let region = image::load_from_memory(&cropped.png).unwrap().to_rgb8();
+        assert_eq!(region.dimensions(), (20, 10));
+        assert!(region.pixels().all(|pixel| *pixel == Rgb([0, 0, 0])));
+    }
+
+    #[test]
+    fn test_pixel_span_rounds_outwards_and_clamps() {
+        assert_eq!(pixel_span(0.0, 1.0, 300), (0, 300));
+        assert_eq!(pixel_span(0.334, 0.333, 300), (100, 101));
+        assert_eq!(pixel_span(0.999, 0.001, 10), (9, 1));
+    }
+
+    #[test]
+    fn test_offset_elements_moves_geometry() {
+        use crate::types::OcrConfidence;
+
+        let mut elements = vec![
+            OcrElement::new(
+                "word",
+                OcrBoundingGeometry::Rectangle {
+                    left: 1,
+                    top: 2,
+                    width: 3,
+                    height: 4,
+                },
+                OcrConfidence::from_tesseract(90.0),
+            ),
+            OcrElement::new(
+                "line",
+                OcrBoundingGeometry::Quadrilateral {
+                    points: [(0, 0), (5, 0), (5, 5), (0, 5)],
+                },
+                OcrConfidence::from_paddle(0.9, 0.9),
+            ),
+        ];
+
+        offset_elements(&mut elements, 10, 20);
+
+        assert_eq!(
+            elements[0].geometry,
+            OcrBoundingGeometry::Rectangle {
+                left: 11,
+                top: 22,
+                width: 3,
+                height: 4,
+            }
+        );
+        assert_eq!(
+            elements[1].geometry,
+            OcrBoundingGeometry::Quadrilateral {
+                points: [(10, 20), (15, 20), (15, 25), (10, 25)],
+            }
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! Retry with exponential backoff for transient OCR failures.
+//!
+//! OCR engines occasionally fail under memory pressure. [`process_image_with_retry`]
+//! retries a failed call up to [`OcrConfig::max_retries`] times, doubling the delay
+//! between attempts. The delay needs the `tokio-runtime` feature; without it there is
+//! no timer to wait on without blocking the caller, so retries follow immediately.
+
+use std::time::Duration;
+
+use crate::core::config::OcrConfig;
+use crate::plugins::OcrBackend;
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+
+/// Delay before the first retry.
+const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// Upper bound for the delay between retries.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Run OCR on one image, retrying failures up to `config.max_retries` times.
+///
+/// Configuration and dependency errors are returned immediately since retrying
+/// cannot fix them.
+pub async fn process_image_with_retry(
+    backend: &dyn OcrBackend,
+    image_bytes: &[u8],
+    config: &OcrConfig,
+) -> Result<ExtractionResult> {
+    let mut attempt = 0;
+    loop {
+        match backend.process_image(image_bytes, config).await {
+            Ok(result) => return Ok(result),
+            Err(error) if attempt < config.max_retries && is_retryable(&error) => {
+                let delay = retry_delay(attempt);
+                attempt += 1;
+                tracing::debug!(
+                    "OCR attempt {} of {} failed, retrying in {:?}: {}",
+                    attempt,
+                    config.max_retries + 1,
+                    delay,
+                    error
+                );
+                #[cfg(feature = "tokio-runtime")]
+                tokio::time::sleep(delay).await;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+fn is_retryable(error: &KreuzbergError) -> bool {
+    !matches!(
+        error,
+        KreuzbergError::Validation { .. }
+            | KreuzbergError::MissingDependency(_)
+            | KreuzbergError::UnsupportedFormat { .. }
+    )
+}
+
+/// Exponential backoff: 100ms, 200ms, 400ms, ... capped at [`MAX_RETRY_DELAY`].
+fn retry_delay(attempt: u32) -> Duration {
+    INITIAL_RETRY_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_RETRY_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::{OcrBackendType, Plugin};
+    use crate::types::Metadata;
+    use async_trait::async_trait;
+    use std::borrow::Cow;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Backend that fails a fixed number of times before succeeding.
+    struct FlakyBackend {
+        failures: u32,
+        calls: AtomicU32,
+    }
+
+    impl Plugin for FlakyBackend {
+        fn name(&self) -> &str {
+            "flaky"
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl OcrBackend for FlakyBackend {
+        async fn process_image(&self, _image_bytes: &[u8], _config: &OcrConfig) -> Result<ExtractionResult> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            if call < self.failures {
+                return Err(KreuzbergError::Ocr {
+                    message: "transient failure".to_string(),
+                    source: None,
+                });
+            }
+            Ok(ExtractionResult {
+                content: "recognized".to_string(),
+                mime_type: Cow::Borrowed("text/plain"),
+                metadata: Metadata::default(),
+                tables: vec![],
+                detected_languages: None,
+                chunks: None,
+                images: None,
+                pages: None,
+                djot_content: None,
+                elements: None,
+                ocr_elements: None,
+                document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
+            })
+        }
+
+        fn supports_language(&self, _lang: &str) -> bool {
+            true
+        }
+
+        fn backend_type(&self) -> OcrBackendType {
+            OcrBackendType::Custom
+        }
+    }
+
+    fn flaky(failures: u32) -> FlakyBackend {
+        FlakyBackend {
+            failures,
+            calls: AtomicU32::new(0),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_recovers_from_transient_failure() {
+        let backend = flaky(2);
+        let config = OcrConfig {
+            max_retries: 2,
+            ..Default::default()
+        };
+
+        let result = process_image_with_retry(&backend, &[], &config).await.unwrap();
+
+        assert_eq!(result.content, "recognized");
+        assert_eq!(backend.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_no_retries_by_default() {
+        let backend = flaky(1);
+
+        let result = process_image_with_retry(&backend, &[], &OcrConfig::default()).await;
+
+        assert!(result.is_err());
+        assert_eq!(backend.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_retry_delay_backs_off_exponentially() {
+        assert_eq!(retry_delay(0), Duration::from_millis(100));
+        assert_eq!(retry_delay(1), Duration::from_millis(200));
+        assert_eq!(retry_delay(3), Duration::from_millis(800));
+        assert_eq!(retry_delay(20), MAX_RETRY_DELAY);
+    }
+}
@@ -70,7 +70,7 @@ impl TesseractBackend {
             table_min_confidence: public_config.table_min_confidence,
             table_column_threshold: public_config.table_column_threshold as u32,
             table_row_threshold_ratio: public_config.table_row_threshold_ratio,
-            use_cache: public_config.use_cache,
+            use_cache: public_config.use_cache && !crate::cache::cache_disabled_by_env(),
             classify_use_pre_adapted_templates: public_config.classify_use_pre_adapted_templates,
             language_model_ngram_on: public_config.language_model_ngram_on,
             tessedit_dont_blkrej_good_wds: public_config.tessedit_dont_blkrej_good_wds,
@@ -81,6 +81,7 @@ impl TesseractBackend {
             tessedit_use_primary_params_model: public_config.tessedit_use_primary_params_model,
             textord_space_size_is_variable: public_config.textord_space_size_is_variable,
             thresholding_method: public_config.thresholding_method,
+            user_words: Vec::new(),
         }
     }
 
@@ -89,13 +90,17 @@ impl TesseractBackend {
     /// Uses tesseract_config from OcrConfig if provided, otherwise uses defaults
     /// with the language from OcrConfig.
     fn config_to_tesseract(&self, config: &OcrConfig) -> InternalTesseractConfig {
-        match &config.tesseract_config {
+        let mut tess_config = match &config.tesseract_config {
             Some(tess_config) => Self::convert_config(tess_config),
             None => InternalTesseractConfig {
                 language: config.language.clone(),
                 ..Default::default()
             },
+        };
+        if let Some(user_words) = &config.user_words {
+            tess_config.user_words = crate::ocr::user_words::normalize_user_words(user_words);
         }
+        tess_config
     }
 
     /// Get cached available languages, lazily querying Tesseract if needed.
@@ -211,6 +216,12 @@ impl OcrBackend for TesseractBackend {
         for (key, value) in ocr_result.metadata {
             additional.insert(Cow::Owned(key), value);
         }
+        if let Some(raw) = additional
+            .get(crate::ocr::confidence::OCR_CONFIDENCE_KEY)
+            .and_then(serde_json::Value::as_f64)
+        {
+            crate::ocr::confidence::insert_confidence(&mut additional, self.name(), raw);
+        }
 
         let metadata = crate::types::Metadata {
             format: Some(crate::types::FormatMetadata::Ocr(crate::types::OcrMetadata {
@@ -240,6 +251,9 @@ impl OcrBackend for TesseractBackend {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number,
+                    name: None,
+                    has_header: false,
+                    cell_spans: Vec::new(),
                 })
                 .collect(),
             detected_languages: None,
@@ -249,6 +263,11 @@ impl OcrBackend for TesseractBackend {
             ocr_elements: ocr_result.ocr_elements,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -316,6 +335,9 @@ impl OcrBackend for TesseractBackend {
                     cells: t.cells,
                     markdown: t.markdown,
                     page_number: t.page_number,
+                    name: None,
+                    has_header: false,
+                    cell_spans: Vec::new(),
                 })
                 .collect(),
             detected_languages: None,
@@ -325,6 +347,11 @@ impl OcrBackend for TesseractBackend {
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -73,6 +73,10 @@ pub struct TesseractConfig {
     pub tessedit_use_primary_params_model: bool,
     pub textord_space_size_is_variable: bool,
     pub thresholding_method: bool,
+
+    /// Words loaded as a Tesseract user-words dictionary.
+    #[serde(default)]
+    pub user_words: Vec<String>,
 }
 
 impl Default for TesseractConfig {
@@ -99,6 +103,7 @@ impl Default for TesseractConfig {
             tessedit_use_primary_params_model: true,
             textord_space_size_is_variable: true,
             thresholding_method: false,
+            user_words: Vec::new(),
         }
     }
 }
@@ -133,7 +138,7 @@ impl From<&crate::types::TesseractConfig> for TesseractConfig {
             table_min_confidence: config.table_min_confidence,
             table_column_threshold: config.table_column_threshold as u32,
             table_row_threshold_ratio: config.table_row_threshold_ratio,
-            use_cache: config.use_cache,
+            use_cache: config.use_cache && !crate::cache::cache_disabled_by_env(),
             classify_use_pre_adapted_templates: config.classify_use_pre_adapted_templates,
             language_model_ngram_on: config.language_model_ngram_on,
             tessedit_dont_blkrej_good_wds: config.tessedit_dont_blkrej_good_wds,
@@ -144,6 +149,7 @@ impl From<&crate::types::TesseractConfig> for TesseractConfig {
             tessedit_use_primary_params_model: config.tessedit_use_primary_params_model,
             textord_space_size_is_variable: config.textord_space_size_is_variable,
             thresholding_method: config.thresholding_method,
+            user_words: Vec::new(),
         }
     }
 }
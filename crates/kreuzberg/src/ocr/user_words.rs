@@ -0,0 +1,215 @@
+//! User dictionaries for OCR.
+//!
+//! [`OcrConfig::user_words`](crate::core::config::OcrConfig::user_words) lists domain terms
+//! (drug names, case citations, part numbers) that OCR engines tend to misread. Tesseract
+//! loads them as a `user-words` file at initialization; recognizers without dictionary
+//! support (PaddleOCR) use [`correct_with_user_words`] to snap near misses onto the
+//! supplied terms.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Words shorter than this are never corrected; too many short words are one edit apart.
+const MIN_CORRECTION_LEN: usize = 5;
+
+/// Split the configured entries into single words, dropping empty and duplicate entries.
+pub fn normalize_user_words(words: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for word in words.iter().flat_map(|entry| entry.split_whitespace()) {
+        if !normalized.iter().any(|existing| existing == word) {
+            normalized.push(word.to_string());
+        }
+    }
+    normalized
+}
+
+/// Replace recognized words that are one edit (two for words of 8+ characters) away
+/// from exactly one user word, ignoring case.
+///
+/// The user word's spelling replaces the recognized word while leading and trailing
+/// punctuation is kept, so with `metoprolol` supplied `"Metoprol0l,"` becomes `"metoprolol,"`.
+/// Words that already match a user word, short words and ambiguous near misses are left
+/// unchanged.
+pub fn correct_with_user_words(text: &str, user_words: &[String]) -> String {
+    let user_words = normalize_user_words(user_words);
+    if user_words.is_empty() {
+        return text.to_string();
+    }
+    let lowered: Vec<Vec<char>> = user_words
+        .iter()
+        .map(|word| word.to_lowercase().chars().collect())
+        .collect();
+
+    let mut corrected = String::with_capacity(text.len());
+    let mut rest = text;
+    while !rest.is_empty() {
+        let token_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (token, tail) = rest.split_at(token_end);
+        corrected.push_str(&correct_token(token, &user_words, &lowered));
+
+        let space_end = tail.find(|c: char| !c.is_whitespace()).unwrap_or(tail.len());
+        corrected.push_str(&tail[..space_end]);
+        rest = &tail[space_end..];
+    }
+    corrected
+}
+
+fn correct_token(token: &str, user_words: &[String], lowered: &[Vec<char>]) -> String {
+    let core = token.trim_matches(|c: char| !c.is_alphanumeric());
+    let candidate: Vec<char> = core.to_lowercase().chars().collect();
+    if candidate.len() < MIN_CORRECTION_LEN || lowered.contains(&candidate) {
+        return token.to_string();
+    }
+
+    let max_distance = if candidate.len() >= 8 { 2 } else { 1 };
+    let mut matches = lowered
+        .iter()
+        .enumerate()
+        .filter(|(_, word)| edit_distance(word, &candidate, max_distance) <= max_distance);
+    match (matches.next(), matches.next()) {
+        (Some((index, _)), None) => {
+            let start = token.len() - token.trim_start_matches(|c: char| !c.is_alphanumeric()).len();
+            format!(
+                "{}{}{}",
+                &token[..start],
+                user_words[index],
+                &token[start + core.len()..]
+            )
+        }
+        _ => token.to_string(),
+    }
+}
+
+/// Levenshtein distance, stopping early once it exceeds `limit`.
+fn edit_distance(a: &[char], b: &[char], limit: usize) -> usize {
+    if a.len().abs_diff(b.len()) > limit {
+        return limit + 1;
+    }
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        if current.iter().all(|&distance| distance > limit) {
+            return limit + 1;
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// A private temporary directory holding a Tesseract `user-words` file and the config
+/// file that points Tesseract at it. The directory is removed on drop.
+pub(crate) struct UserWordsFile {
+    dir: PathBuf,
+}
+
+impl UserWordsFile {
+    /// Write `words` (one per line) and a config file setting `user_words_file`.
+    pub(crate) fn create(words: &[String]) -> std::io::Result<Self> {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let dir = loop {
+            let candidate = std::env::temp_dir().join(format!(
+                "kreuzberg-user-words-{}-{}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            match create_private_dir(&candidate) {
+                Ok(()) => break candidate,
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        };
+        let file = Self { dir };
+
+        let words_path = file.dir.join("user.words");
+        let mut words_file = fs::File::create_new(&words_path)?;
+        for word in normalize_user_words(words) {
+            writeln!(words_file, "{}", word)?;
+        }
+        let mut config_file = fs::File::create_new(file.config_path())?;
+        writeln!(config_file, "user_words_file {}", words_path.display())?;
+
+        Ok(file)
+    }
+
+    /// Tesseract config file to pass to `Init`.
+    pub(crate) fn config_path(&self) -> PathBuf {
+        self.dir.join("user-words.config")
+    }
+}
+
+impl Drop for UserWordsFile {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_dir_all(&self.dir) {
+            tracing::debug!("Failed to remove user words directory {}: {}", self.dir.display(), e);
+        }
+    }
+}
+
+#[cfg(unix)]
+fn create_private_dir(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::DirBuilderExt;
+    fs::DirBuilder::new().mode(0o700).create(path)
+}
+
+#[cfg(not(unix))]
+fn create_private_dir(path: &Path) -> std::io::Result<()> {
+    fs::create_dir(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn test_domain_term_is_corrected_only_when_supplied() {
+        let ocr_text = "Patient started on rnetoprolol, 25 mg.";
+
+        assert_eq!(correct_with_user_words(ocr_text, &[]), ocr_text);
+        assert_eq!(
+            correct_with_user_words(ocr_text, &words(&["metoprolol"])),
+            "Patient started on metoprolol, 25 mg."
+        );
+    }
+
+    #[test]
+    fn test_correction_keeps_exact_short_and_ambiguous_words() {
+        let user_words = words(&["Lisinopril", "cast", "carbamide", "carbamate"]);
+
+        assert_eq!(
+            correct_with_user_words("LISINOPRIL cost\tcarbamite", &user_words),
+            "LISINOPRIL cost\tcarbamite"
+        );
+    }
+
+    #[test]
+    fn test_normalize_user_words_splits_and_dedupes() {
+        assert_eq!(
+            normalize_user_words(&words(&["metoprolol  atenolol", "", "metoprolol"])),
+            words(&["metoprolol", "atenolol"])
+        );
+    }
+
+    #[test]
+    fn test_user_words_file_is_removed_on_drop() {
+        let file = UserWordsFile::create(&words(&["metoprolol", "atenolol"])).unwrap();
+        let config_path = file.config_path();
+        let config = fs::read_to_string(&config_path).unwrap();
+        let words_path = config.trim().strip_prefix("user_words_file ").unwrap().to_string();
+        assert_eq!(fs::read_to_string(&words_path).unwrap(), "metoprolol\natenolol\n");
+
+        drop(file);
+        assert!(!config_path.exists());
+        assert!(!Path::new(&words_path).exists());
+    }
+}
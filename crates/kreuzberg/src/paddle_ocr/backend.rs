@@ -286,12 +286,15 @@ impl PaddleOcrBackend {
         config: &PaddleOcrConfig,
     ) -> Result<Vec<kreuzberg_paddle_ocr::TextBlock>> {
         // 1. Decode image bytes to RGB8
-        let img = image::load_from_memory(image_bytes)
-            .map_err(|e| crate::KreuzbergError::Ocr {
-                message: format!("Failed to decode image: {}", e),
-                source: None,
-            })?
-            .to_rgb8();
+        let img = crate::extraction::image_header::decode_image(
+            image_bytes,
+            crate::core::config::extraction::DEFAULT_MAX_DECODE_DIMENSION,
+        )
+        .map_err(|e| crate::KreuzbergError::Ocr {
+            message: e.to_string(),
+            source: None,
+        })?
+        .to_rgb8();
 
         // 2. Acquire lock on OCR engine
         let mut engine_guard = ocr_engine.lock().map_err(|e| crate::KreuzbergError::Plugin {
@@ -397,6 +400,10 @@ impl OcrBackend for PaddleOcrBackend {
         let (text, ocr_elements) = self
             .do_ocr(image_bytes, paddle_lang, Arc::clone(&effective_config))
             .await?;
+        let text = match &config.user_words {
+            Some(user_words) => crate::ocr::user_words::correct_with_user_words(&text, user_words),
+            None => text,
+        };
 
         // Attempt table detection if enabled and we have elements
         let mut tables: Vec<Table> = vec![];
@@ -426,6 +433,9 @@ impl OcrBackend for PaddleOcrBackend {
                         cells,
                         markdown: table_markdown,
                         page_number: 1, // Single image = page 1
+                        name: None,
+                        has_header: false,
+                        cell_spans: Vec::new(),
                     });
                 }
             }
@@ -434,6 +444,14 @@ impl OcrBackend for PaddleOcrBackend {
         // Build metadata
         let mut additional = AHashMap::new();
         additional.insert(Cow::Borrowed("backend"), serde_json::json!("paddle-ocr"));
+        if !ocr_elements.is_empty() {
+            let mean_score = ocr_elements
+                .iter()
+                .map(|element| element.confidence.recognition)
+                .sum::<f64>()
+                / ocr_elements.len() as f64;
+            crate::ocr::confidence::insert_confidence(&mut additional, self.name(), mean_score);
+        }
 
         let metadata = Metadata {
             format: Some(FormatMetadata::Ocr(OcrMetadata {
@@ -470,6 +488,11 @@ impl OcrBackend for PaddleOcrBackend {
             elements: None,
             ocr_elements: ocr_elements_opt,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
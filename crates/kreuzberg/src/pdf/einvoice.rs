@@ -0,0 +1,761 @@
+//! Structured data from ZUGFeRD / Factur-X / XRechnung e-invoices.
+//!
+//! Hybrid e-invoices are PDF/A-3 files with the machine-readable invoice embedded as
+//! an XML attachment. Two XML syntaxes are in use: UN/CEFACT Cross Industry Invoice
+//! (CII, used by ZUGFeRD and Factur-X) and OASIS UBL (used by XRechnung and Peppol).
+//! [`extract_einvoice`] finds the attachment, detects the syntax, and maps the core
+//! EN 16931 fields onto [`EInvoice`].
+//!
+//! Elements are matched by local name, so namespace prefixes don't matter. Fields that
+//! are absent from the XML are `None`; no attempt is made to validate the invoice
+//! against a profile.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::pdf::einvoice::extract_einvoice;
+//!
+//! # fn example() -> kreuzberg::Result<()> {
+//! let bytes = std::fs::read("invoice.pdf")?;
+//! let invoice = extract_einvoice(&bytes)?;
+//!
+//! println!("{:?} {:?} {:?}", invoice.invoice_number, invoice.grand_total, invoice.currency);
+//! for item in &invoice.line_items {
+//!     println!("{:?} x {:?}: {:?}", item.quantity, item.name, item.net_amount);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::config::ExtractionConfig;
+use crate::{KreuzbergError, Result};
+use lopdf::{Dictionary, Document, Object, Stream};
+use roxmltree::Node;
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+
+/// Attachment names used by the ZUGFeRD, Factur-X and XRechnung specifications,
+/// tried before any other XML attachment.
+const KNOWN_ATTACHMENT_NAMES: &[&str] = &[
+    "factur-x.xml",
+    "zugferd-invoice.xml",
+    "zugferd_invoice.xml",
+    "xrechnung.xml",
+];
+
+/// Maximum depth followed in the `/EmbeddedFiles` name tree.
+const MAX_NAME_TREE_DEPTH: usize = 32;
+
+/// XML syntax of an e-invoice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EInvoiceSyntax {
+    /// UN/CEFACT Cross Industry Invoice (ZUGFeRD, Factur-X)
+    Cii,
+    /// OASIS Universal Business Language (XRechnung, Peppol)
+    Ubl,
+}
+
+/// Core fields of an e-invoice.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EInvoice {
+    /// XML syntax the invoice was written in
+    pub syntax: EInvoiceSyntax,
+
+    /// Specification identifier (CII guideline ID / UBL `CustomizationID`),
+    /// e.g. `urn:cen.eu:en16931:2017`
+    pub profile: Option<String>,
+
+    /// Name of the PDF attachment the XML was read from (None for standalone XML)
+    pub attachment_name: Option<String>,
+
+    /// Invoice number
+    pub invoice_number: Option<String>,
+
+    /// Issue date as written in the XML (CII: `YYYYMMDD`, UBL: `YYYY-MM-DD`)
+    pub issue_date: Option<String>,
+
+    /// ISO 4217 invoice currency code
+    pub currency: Option<String>,
+
+    /// Seller (supplier)
+    pub seller: Option<EInvoiceParty>,
+
+    /// Buyer (customer)
+    pub buyer: Option<EInvoiceParty>,
+
+    /// Invoice lines, in document order
+    pub line_items: Vec<EInvoiceLineItem>,
+
+    /// Total amount without VAT
+    pub net_total: Option<f64>,
+
+    /// Total VAT amount
+    pub tax_total: Option<f64>,
+
+    /// Total amount with VAT
+    pub grand_total: Option<f64>,
+
+    /// Amount due for payment
+    pub amount_due: Option<f64>,
+}
+
+/// A seller or buyer on an e-invoice.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EInvoiceParty {
+    /// Legal or trading name
+    pub name: Option<String>,
+
+    /// VAT identifier
+    pub vat_id: Option<String>,
+
+    /// Street line of the postal address
+    pub street: Option<String>,
+
+    /// Postal code
+    pub postcode: Option<String>,
+
+    /// City
+    pub city: Option<String>,
+
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: Option<String>,
+}
+
+/// A single invoice line.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EInvoiceLineItem {
+    /// Line identifier
+    pub line_id: Option<String>,
+
+    /// Item name
+    pub name: Option<String>,
+
+    /// Invoiced quantity
+    pub quantity: Option<f64>,
+
+    /// UN/ECE Rec 20 unit code of the quantity, e.g. `H87` (piece)
+    pub unit_code: Option<String>,
+
+    /// Net price per unit
+    pub unit_price: Option<f64>,
+
+    /// Net line amount
+    pub net_amount: Option<f64>,
+}
+
+/// Extract the structured e-invoice embedded in a PDF.
+///
+/// `bytes` may be a hybrid PDF (ZUGFeRD / Factur-X / XRechnung attachment) or the
+/// invoice XML itself.
+/// Attachments are decompressed up to the default content size limit (100 MB); use
+/// [`extract_einvoice_with_config`] to change it.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Parsing` if the PDF cannot be loaded, no embedded CII or
+/// UBL invoice is present, or the XML is malformed.
+pub fn extract_einvoice(bytes: &[u8]) -> Result<EInvoice> {
+    extract_einvoice_with_config(bytes, &ExtractionConfig::default())
+}
+
+/// Extract the structured e-invoice embedded in a PDF, with limits from `config`.
+///
+/// Like [`extract_einvoice`], but attachments are decompressed up to
+/// `config.security_limits.max_content_size` bytes instead of the default limit.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Parsing` if the PDF cannot be loaded, no embedded CII or
+/// UBL invoice is present, or the XML is malformed. Returns `KreuzbergError::Validation`
+/// if an XML attachment decompresses to more than the content size limit.
+pub fn extract_einvoice_with_config(bytes: &[u8], config: &ExtractionConfig) -> Result<EInvoice> {
+    if looks_like_xml(bytes) {
+        return parse_einvoice_xml(bytes, None);
+    }
+
+    let document = Document::load_mem(bytes)
+        .map_err(|e| super::error::PdfError::InvalidPdf(format!("Failed to load PDF: {}", e)))?;
+
+    let mut attachments = embedded_files(&document);
+    attachments.retain(|(name, _)| name.to_ascii_lowercase().ends_with(".xml"));
+    attachments.sort_by_key(|(name, _)| {
+        let lower = name.to_ascii_lowercase();
+        !KNOWN_ATTACHMENT_NAMES.contains(&lower.as_str())
+    });
+
+    let max_size = config.max_content_size();
+    for (name, stream) in &attachments {
+        let content = match read_attachment(stream, name, max_size) {
+            Ok(content) => content,
+            Err(e @ KreuzbergError::Validation { .. }) => return Err(e),
+            Err(e) => {
+                tracing::debug!("Attachment '{}' could not be read: {}", name, e);
+                continue;
+            }
+        };
+        match parse_einvoice_xml(&content, Some(name.clone())) {
+            Ok(invoice) => return Ok(invoice),
+            Err(e) => tracing::debug!("Attachment '{}' is not an e-invoice: {}", name, e),
+        }
+    }
+
+    Err(KreuzbergError::parsing(if attachments.is_empty() {
+        "No embedded e-invoice found: the PDF has no XML attachments (expected factur-x.xml, zugferd-invoice.xml or xrechnung.xml)".to_string()
+    } else {
+        format!(
+            "No embedded e-invoice found: none of the XML attachments ({}) is a CII or UBL invoice",
+            attachments
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }))
+}
+
+/// Parse e-invoice XML in either CII or UBL syntax.
+pub fn parse_einvoice_xml(xml: &[u8], attachment_name: Option<String>) -> Result<EInvoice> {
+    let text = std::str::from_utf8(xml)
+        .map_err(|e| KreuzbergError::parsing(format!("E-invoice XML is not valid UTF-8: {}", e)))?;
+    let text = text.trim_start_matches('\u{FEFF}');
+    let xml = roxmltree::Document::parse(text)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to parse e-invoice XML: {}", e)))?;
+    let root = xml.root_element();
+
+    let mut invoice = match root.tag_name().name() {
+        "CrossIndustryInvoice" => parse_cii(root),
+        "Invoice" | "CreditNote" => parse_ubl(root),
+        other => {
+            return Err(KreuzbergError::parsing(format!(
+                "Unsupported e-invoice root element '{}': expected CrossIndustryInvoice, Invoice or CreditNote",
+                other
+            )));
+        }
+    };
+    invoice.attachment_name = attachment_name;
+    Ok(invoice)
+}
+
+fn parse_cii(root: Node) -> EInvoice {
+    let transaction = child(root, "SupplyChainTradeTransaction");
+    let agreement = transaction.and_then(|t| child(t, "ApplicableHeaderTradeAgreement"));
+    let settlement = transaction.and_then(|t| child(t, "ApplicableHeaderTradeSettlement"));
+    let totals = settlement.and_then(|s| child(s, "SpecifiedTradeSettlementHeaderMonetarySummation"));
+    let amount = |name: &str| totals.and_then(|t| child(t, name)).and_then(number);
+
+    let line_items = transaction
+        .map(|t| {
+            children(t, "IncludedSupplyChainTradeLineItem")
+                .map(|line| {
+                    let quantity = path(line, &["SpecifiedLineTradeDelivery", "BilledQuantity"]);
+                    EInvoiceLineItem {
+                        line_id: path_text(line, &["AssociatedDocumentLineDocument", "LineID"]),
+                        name: path_text(line, &["SpecifiedTradeProduct", "Name"]),
+                        quantity: quantity.and_then(number),
+                        unit_code: quantity.and_then(|q| q.attribute("unitCode")).map(str::to_string),
+                        unit_price: path(
+                            line,
+                            &[
+                                "SpecifiedLineTradeAgreement",
+                                "NetPriceProductTradePrice",
+                                "ChargeAmount",
+                            ],
+                        )
+                        .and_then(number),
+                        net_amount: path(
+                            line,
+                            &[
+                                "SpecifiedLineTradeSettlement",
+                                "SpecifiedTradeSettlementLineMonetarySummation",
+                                "LineTotalAmount",
+                            ],
+                        )
+                        .and_then(number),
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    EInvoice {
+        syntax: EInvoiceSyntax::Cii,
+        profile: path_text(
+            root,
+            &[
+                "ExchangedDocumentContext",
+                "GuidelineSpecifiedDocumentContextParameter",
+                "ID",
+            ],
+        ),
+        attachment_name: None,
+        invoice_number: path_text(root, &["ExchangedDocument", "ID"]),
+        issue_date: path_text(root, &["ExchangedDocument", "IssueDateTime", "DateTimeString"]),
+        currency: settlement.and_then(|s| child(s, "InvoiceCurrencyCode")).and_then(text),
+        seller: agreement.and_then(|a| child(a, "SellerTradeParty")).map(cii_party),
+        buyer: agreement.and_then(|a| child(a, "BuyerTradeParty")).map(cii_party),
+        line_items,
+        net_total: amount("TaxBasisTotalAmount"),
+        tax_total: amount("TaxTotalAmount"),
+        grand_total: amount("GrandTotalAmount"),
+        amount_due: amount("DuePayableAmount"),
+    }
+}
+
+fn cii_party(party: Node) -> EInvoiceParty {
+    let address = child(party, "PostalTradeAddress");
+    let address_field = |name: &str| address.and_then(|a| child(a, name)).and_then(text);
+    EInvoiceParty {
+        name: child(party, "Name").and_then(text),
+        vat_id: children(party, "SpecifiedTaxRegistration")
+            .filter_map(|registration| child(registration, "ID"))
+            .find(|id| id.attribute("schemeID") == Some("VA"))
+            .and_then(text),
+        street: address_field("LineOne"),
+        postcode: address_field("PostcodeCode"),
+        city: address_field("CityName"),
+        country_code: address_field("CountryID"),
+    }
+}
+
+fn parse_ubl(root: Node) -> EInvoice {
+    let totals = child(root, "LegalMonetaryTotal");
+    let amount = |name: &str| totals.and_then(|t| child(t, name)).and_then(number);
+
+    let line_items = root
+        .children()
+        .filter(|n| matches!(n.tag_name().name(), "InvoiceLine" | "CreditNoteLine"))
+        .map(|line| {
+            let quantity = child(line, "InvoicedQuantity").or_else(|| child(line, "CreditedQuantity"));
+            EInvoiceLineItem {
+                line_id: child(line, "ID").and_then(text),
+                name: path_text(line, &["Item", "Name"]),
+                quantity: quantity.and_then(number),
+                unit_code: quantity.and_then(|q| q.attribute("unitCode")).map(str::to_string),
+                unit_price: path(line, &["Price", "PriceAmount"]).and_then(number),
+                net_amount: child(line, "LineExtensionAmount").and_then(number),
+            }
+        })
+        .collect();
+
+    EInvoice {
+        syntax: EInvoiceSyntax::Ubl,
+        profile: child(root, "CustomizationID").and_then(text),
+        attachment_name: None,
+        invoice_number: child(root, "ID").and_then(text),
+        issue_date: child(root, "IssueDate").and_then(text),
+        currency: child(root, "DocumentCurrencyCode").and_then(text),
+        seller: path(root, &["AccountingSupplierParty", "Party"]).map(ubl_party),
+        buyer: path(root, &["AccountingCustomerParty", "Party"]).map(ubl_party),
+        line_items,
+        net_total: amount("TaxExclusiveAmount"),
+        tax_total: children(root, "TaxTotal")
+            .filter_map(|total| child(total, "TaxAmount"))
+            .find_map(number),
+        grand_total: amount("TaxInclusiveAmount"),
+        amount_due: amount("PayableAmount"),
+    }
+}
+
+fn ubl_party(party: Node) -> EInvoiceParty {
+    let address = child(party, "PostalAddress");
+    let address_field = |name: &str| address.and_then(|a| child(a, name)).and_then(text);
+    EInvoiceParty {
+        name: path_text(party, &["PartyLegalEntity", "RegistrationName"])
+            .or_else(|| path_text(party, &["PartyName", "Name"])),
+        vat_id: path_text(party, &["PartyTaxScheme", "CompanyID"]),
+        street: address_field("StreetName"),
+        postcode: address_field("PostalZone"),
+        city: address_field("CityName"),
+        country_code: address.and_then(|a| path_text(a, &["Country", "IdentificationCode"])),
+    }
+}
+
+fn children<'a, 'input: 'a>(node: Node<'a, 'input>, name: &'a str) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn path<'a, 'input>(node: Node<'a, 'input>, names: &[&str]) -> Option<Node<'a, 'input>> {
+    names.iter().try_fold(node, |current, name| child(current, name))
+}
+
+fn path_text(node: Node, names: &[&str]) -> Option<String> {
+    path(node, names).and_then(text)
+}
+
+fn text(node: Node) -> Option<String> {
+    node.text().map(str::trim).filter(|t| !t.is_empty()).map(str::to_string)
+}
+
+fn number(node: Node) -> Option<f64> {
+    node.text()?.trim().parse().ok()
+}
+
+fn looks_like_xml(bytes: &[u8]) -> bool {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    bytes
+        .iter()
+        .find(|b| !b.is_ascii_whitespace())
+        .is_some_and(|b| *b == b'<')
+}
+
+/// Collect `(name, stream)` for every file attached through the `/EmbeddedFiles`
+/// name tree or the catalog's `/AF` (associated files) array.
+fn embedded_files(document: &Document) -> Vec<(String, &Stream)> {
+    let Ok(catalog) = document.catalog() else {
+        return Vec::new();
+    };
+
+    let mut filespecs = Vec::new();
+    if let Some(tree) = catalog
+        .get(b"Names")
+        .ok()
+        .and_then(|names| resolve_dict(document, names))
+        .and_then(|names| names.get(b"EmbeddedFiles").ok())
+        .and_then(|tree| resolve_dict(document, tree))
+    {
+        collect_name_tree(document, tree, &mut filespecs, 0);
+    }
+    if let Ok(Object::Array(associated)) = catalog
+        .get(b"AF")
+        .and_then(|af| document.dereference(af).map(|(_, o)| o))
+    {
+        filespecs.extend(associated.iter().filter_map(|spec| resolve_dict(document, spec)));
+    }
+
+    let mut files: Vec<(String, &Stream)> = Vec::new();
+    for spec in filespecs {
+        if let Some(file) = read_filespec(document, spec)
+            && !files.iter().any(|(name, _)| *name == file.0)
+        {
+            files.push(file);
+        }
+    }
+    files
+}
+
+fn collect_name_tree<'a>(document: &'a Document, node: &'a Dictionary, out: &mut Vec<&'a Dictionary>, depth: usize) {
+    if depth > MAX_NAME_TREE_DEPTH {
+        return;
+    }
+    if let Ok(Object::Array(names)) = node.get(b"Names") {
+        // Alternating key / value pairs
+        out.extend(
+            names
+                .iter()
+                .skip(1)
+                .step_by(2)
+                .filter_map(|spec| resolve_dict(document, spec)),
+        );
+    }
+    if let Ok(Object::Array(kids)) = node.get(b"Kids") {
+        for kid in kids.iter().filter_map(|kid| resolve_dict(document, kid)) {
+            collect_name_tree(document, kid, out, depth + 1);
+        }
+    }
+}
+
+fn read_filespec<'a>(document: &'a Document, spec: &'a Dictionary) -> Option<(String, &'a Stream)> {
+    let name = [b"UF".as_slice(), b"F".as_slice()]
+        .iter()
+        .find_map(|key| match spec.get(key).ok()? {
+            Object::String(bytes, _) => Some(decode_pdf_string(bytes)),
+            _ => None,
+        })?;
+
+    let embedded = resolve_dict(document, spec.get(b"EF").ok()?)?;
+    let stream_ref = embedded.get(b"F").or_else(|_| embedded.get(b"UF")).ok()?;
+    let stream = document.dereference(stream_ref).ok()?.1.as_stream().ok()?;
+    Some((name, stream))
+}
+
+/// Decode an embedded file stream, reading at most `max_size` decompressed bytes.
+///
+/// Only unfiltered and `FlateDecode` streams are supported, which covers the
+/// attachments written by e-invoicing tools.
+fn read_attachment(stream: &Stream, name: &str, max_size: usize) -> Result<Vec<u8>> {
+    let filters: Vec<&[u8]> = match stream.dict.get(b"Filter") {
+        Ok(Object::Name(filter)) => vec![filter.as_slice()],
+        Ok(Object::Array(filters)) => filters.iter().filter_map(|filter| filter.as_name().ok()).collect(),
+        _ => Vec::new(),
+    };
+
+    let content = match filters.as_slice() {
+        [] => stream.content.clone(),
+        [filter] if *filter == b"FlateDecode" => {
+            // Never inflate more than the limit, whatever the stream claims.
+            let mut content = Vec::new();
+            flate2::read::ZlibDecoder::new(stream.content.as_slice())
+                .take(max_size as u64 + 1)
+                .read_to_end(&mut content)
+                .map_err(|e| KreuzbergError::parsing(format!("Failed to decompress attachment '{}': {}", name, e)))?;
+            content
+        }
+        _ => {
+            return Err(KreuzbergError::parsing(format!(
+                "Attachment '{}' uses an unsupported stream filter",
+                name
+            )));
+        }
+    };
+
+    if content.len() > max_size {
+        return Err(KreuzbergError::validation(format!(
+            "Attachment '{}' exceeds content size limit of {} bytes",
+            name, max_size
+        )));
+    }
+    Ok(content)
+}
+
+fn resolve_dict<'a>(document: &'a Document, object: &'a Object) -> Option<&'a Dictionary> {
+    document.dereference(object).ok()?.1.as_dict().ok()
+}
+
+/// Decode a PDF text string (UTF-16BE with BOM, otherwise treated as Latin-1/PDFDocEncoding).
+fn decode_pdf_string(bytes: &[u8]) -> String {
+    match bytes.strip_prefix(b"\xFE\xFF") {
+        Some(utf16) => {
+            let units: Vec<u16> = utf16
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        None => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::{StringFormat, dictionary};
+
+    const FACTURX_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rsm:CrossIndustryInvoice xmlns:rsm="urn:un:unece:uncefact:data:standard:CrossIndustryInvoice:100"
+    xmlns:ram="urn:un:unece:uncefact:data:standard:ReusableAggregateBusinessInformationEntity:100"
+    xmlns:udt="urn:un:unece:uncefact:data:standard:UnqualifiedDataType:100">
+  <rsm:ExchangedDocumentContext>
+    <ram:GuidelineSpecifiedDocumentContextParameter>
+      <ram:ID>urn:cen.eu:en16931:2017</ram:ID>
+    </ram:GuidelineSpecifiedDocumentContextParameter>
+  </rsm:ExchangedDocumentContext>
+  <rsm:ExchangedDocument>
+    <ram:ID>INV-2024-001</ram:ID>
+    <ram:TypeCode>380</ram:TypeCode>
+    <ram:IssueDateTime><udt:DateTimeString format="102">20240315</udt:DateTimeString></ram:IssueDateTime>
+  </rsm:ExchangedDocument>
+  <rsm:SupplyChainTradeTransaction>
+    <ram:IncludedSupplyChainTradeLineItem>
+      <ram:AssociatedDocumentLineDocument><ram:LineID>1</ram:LineID></ram:AssociatedDocumentLineDocument>
+      <ram:SpecifiedTradeProduct><ram:Name>Consulting</ram:Name></ram:SpecifiedTradeProduct>
+      <ram:SpecifiedLineTradeAgreement>
+        <ram:NetPriceProductTradePrice><ram:ChargeAmount>100.00</ram:ChargeAmount></ram:NetPriceProductTradePrice>
+      </ram:SpecifiedLineTradeAgreement>
+      <ram:SpecifiedLineTradeDelivery><ram:BilledQuantity unitCode="HUR">8</ram:BilledQuantity></ram:SpecifiedLineTradeDelivery>
+      <ram:SpecifiedLineTradeSettlement>
+        <ram:SpecifiedTradeSettlementLineMonetarySummation>
+          <ram:LineTotalAmount>800.00</ram:LineTotalAmount>
+        </ram:SpecifiedTradeSettlementLineMonetarySummation>
+      </ram:SpecifiedLineTradeSettlement>
+    </ram:IncludedSupplyChainTradeLineItem>
+    <ram:ApplicableHeaderTradeAgreement>
+      <ram:SellerTradeParty>
+        <ram:Name>Muster GmbH</ram:Name>
+        <ram:PostalTradeAddress>
+          <ram:PostcodeCode>10115</ram:PostcodeCode>
+          <ram:LineOne>Hauptstr. 1</ram:LineOne>
+          <ram:CityName>Berlin</ram:CityName>
+          <ram:CountryID>DE</ram:CountryID>
+        </ram:PostalTradeAddress>
+        <ram:SpecifiedTaxRegistration><ram:ID schemeID="FC">201/113/40209</ram:ID></ram:SpecifiedTaxRegistration>
+        <ram:SpecifiedTaxRegistration><ram:ID schemeID="VA">DE123456789</ram:ID></ram:SpecifiedTaxRegistration>
+      </ram:SellerTradeParty>
+      <ram:BuyerTradeParty><ram:Name>Client SARL</ram:Name></ram:BuyerTradeParty>
+    </ram:ApplicableHeaderTradeAgreement>
+    <ram:ApplicableHeaderTradeSettlement>
+      <ram:InvoiceCurrencyCode>EUR</ram:InvoiceCurrencyCode>
+      <ram:SpecifiedTradeSettlementHeaderMonetarySummation>
+        <ram:TaxBasisTotalAmount>800.00</ram:TaxBasisTotalAmount>
+        <ram:TaxTotalAmount currencyID="EUR">152.00</ram:TaxTotalAmount>
+        <ram:GrandTotalAmount>952.00</ram:GrandTotalAmount>
+        <ram:DuePayableAmount>952.00</ram:DuePayableAmount>
+      </ram:SpecifiedTradeSettlementHeaderMonetarySummation>
+    </ram:ApplicableHeaderTradeSettlement>
+  </rsm:SupplyChainTradeTransaction>
+</rsm:CrossIndustryInvoice>"#;
+
+    const UBL_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Invoice xmlns="urn:oasis:names:specification:ubl:schema:xsd:Invoice-2"
+    xmlns:cac="urn:oasis:names:specification:ubl:schema:xsd:CommonAggregateComponents-2"
+    xmlns:cbc="urn:oasis:names:specification:ubl:schema:xsd:CommonBasicComponents-2">
+  <cbc:CustomizationID>urn:cen.eu:en16931:2017#compliant#urn:xeinkauf.de:kosit:xrechnung_3.0</cbc:CustomizationID>
+  <cbc:ID>R-42</cbc:ID>
+  <cbc:IssueDate>2024-03-15</cbc:IssueDate>
+  <cbc:DocumentCurrencyCode>EUR</cbc:DocumentCurrencyCode>
+  <cac:AccountingSupplierParty><cac:Party>
+    <cac:PostalAddress>
+      <cbc:StreetName>Hauptstr. 1</cbc:StreetName>
+      <cbc:CityName>Berlin</cbc:CityName>
+      <cbc:PostalZone>10115</cbc:PostalZone>
+      <cac:Country><cbc:IdentificationCode>DE</cbc:IdentificationCode></cac:Country>
+    </cac:PostalAddress>
+    <cac:PartyTaxScheme><cbc:CompanyID>DE123456789</cbc:CompanyID></cac:PartyTaxScheme>
+    <cac:PartyLegalEntity><cbc:RegistrationName>Muster GmbH</cbc:RegistrationName></cac:PartyLegalEntity>
+  </cac:Party></cac:AccountingSupplierParty>
+  <cac:AccountingCustomerParty><cac:Party>
+    <cac:PartyName><cbc:Name>Stadt Musterhausen</cbc:Name></cac:PartyName>
+  </cac:Party></cac:AccountingCustomerParty>
+  <cac:TaxTotal><cbc:TaxAmount currencyID="EUR">19.00</cbc:TaxAmount></cac:TaxTotal>
+  <cac:LegalMonetaryTotal>
+    <cbc:TaxExclusiveAmount currencyID="EUR">100.00</cbc:TaxExclusiveAmount>
+    <cbc:TaxInclusiveAmount currencyID="EUR">119.00</cbc:TaxInclusiveAmount>
+    <cbc:PayableAmount currencyID="EUR">119.00</cbc:PayableAmount>
+  </cac:LegalMonetaryTotal>
+  <cac:InvoiceLine>
+    <cbc:ID>1</cbc:ID>
+    <cbc:InvoicedQuantity unitCode="H87">4</cbc:InvoicedQuantity>
+    <cbc:LineExtensionAmount currencyID="EUR">100.00</cbc:LineExtensionAmount>
+    <cac:Item><cbc:Name>Paper</cbc:Name></cac:Item>
+    <cac:Price><cbc:PriceAmount currencyID="EUR">25.00</cbc:PriceAmount></cac:Price>
+  </cac:InvoiceLine>
+</Invoice>"#;
+
+    /// Build a one-page PDF with `attachments` in the `/EmbeddedFiles` name tree.
+    fn build_pdf(attachments: &[(&str, &str)]) -> Vec<u8> {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let page_id = doc.add_object(dictionary! { "Type" => "Page", "Parent" => pages_id });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![Object::Reference(page_id)],
+                "Count" => 1,
+            }),
+        );
+
+        let mut names = Vec::new();
+        for (name, content) in attachments {
+            let stream_id = doc.add_object(Stream::new(
+                dictionary! { "Type" => "EmbeddedFile" },
+                content.as_bytes().to_vec(),
+            ));
+            let spec_id = doc.add_object(dictionary! {
+                "Type" => "Filespec",
+                "F" => Object::String(name.as_bytes().to_vec(), StringFormat::Literal),
+                "EF" => dictionary! { "F" => stream_id },
+            });
+            names.push(Object::String(name.as_bytes().to_vec(), StringFormat::Literal));
+            names.push(Object::Reference(spec_id));
+        }
+
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+            "Names" => dictionary! { "EmbeddedFiles" => dictionary! { "Names" => names } },
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_extract_facturx_from_pdf() {
+        let pdf = build_pdf(&[("notes.xml", "<notes/>"), ("factur-x.xml", FACTURX_XML)]);
+        let invoice = extract_einvoice(&pdf).unwrap();
+
+        assert_eq!(invoice.syntax, EInvoiceSyntax::Cii);
+        assert_eq!(invoice.attachment_name.as_deref(), Some("factur-x.xml"));
+        assert_eq!(invoice.profile.as_deref(), Some("urn:cen.eu:en16931:2017"));
+        assert_eq!(invoice.invoice_number.as_deref(), Some("INV-2024-001"));
+        assert_eq!(invoice.issue_date.as_deref(), Some("20240315"));
+        assert_eq!(invoice.currency.as_deref(), Some("EUR"));
+        assert_eq!(invoice.grand_total, Some(952.0));
+        assert_eq!(invoice.tax_total, Some(152.0));
+        assert_eq!(invoice.net_total, Some(800.0));
+
+        let seller = invoice.seller.unwrap();
+        assert_eq!(seller.name.as_deref(), Some("Muster GmbH"));
+        assert_eq!(seller.vat_id.as_deref(), Some("DE123456789"));
+        assert_eq!(seller.city.as_deref(), Some("Berlin"));
+        assert_eq!(seller.country_code.as_deref(), Some("DE"));
+        assert_eq!(invoice.buyer.unwrap().name.as_deref(), Some("Client SARL"));
+
+        assert_eq!(
+            invoice.line_items,
+            vec![EInvoiceLineItem {
+                line_id: Some("1".to_string()),
+                name: Some("Consulting".to_string()),
+                quantity: Some(8.0),
+                unit_code: Some("HUR".to_string()),
+                unit_price: Some(100.0),
+                net_amount: Some(800.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_extract_ubl_xml() {
+        let invoice = extract_einvoice(UBL_XML.as_bytes()).unwrap();
+
+        assert_eq!(invoice.syntax, EInvoiceSyntax::Ubl);
+        assert!(invoice.attachment_name.is_none());
+        assert_eq!(invoice.invoice_number.as_deref(), Some("R-42"));
+        assert_eq!(invoice.issue_date.as_deref(), Some("2024-03-15"));
+        assert_eq!(invoice.amount_due, Some(119.0));
+        assert_eq!(invoice.tax_total, Some(19.0));
+
+        let seller = invoice.seller.unwrap();
+        assert_eq!(seller.name.as_deref(), Some("Muster GmbH"));
+        assert_eq!(seller.vat_id.as_deref(), Some("DE123456789"));
+        assert_eq!(seller.postcode.as_deref(), Some("10115"));
+        assert_eq!(invoice.buyer.unwrap().name.as_deref(), Some("Stadt Musterhausen"));
+
+        assert_eq!(invoice.line_items.len(), 1);
+        assert_eq!(invoice.line_items[0].name.as_deref(), Some("Paper"));
+        assert_eq!(invoice.line_items[0].unit_price, Some(25.0));
+        assert_eq!(invoice.line_items[0].quantity, Some(4.0));
+    }
+
+    #[test]
+    fn test_pdf_without_einvoice_is_an_error() {
+        let err = extract_einvoice(&build_pdf(&[])).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Parsing { .. }));
+        assert!(err.to_string().contains("No embedded e-invoice found"));
+
+        let err = extract_einvoice(&build_pdf(&[("notes.xml", "<notes/>")])).unwrap_err();
+        assert!(err.to_string().contains("notes.xml"));
+    }
+
+    #[test]
+    fn test_decode_utf16_filename() {
+        assert_eq!(decode_pdf_string(b"\xFE\xFF\x00a\x00.\x00x"), "a.x");
+        assert_eq!(decode_pdf_string(b"factur-x.xml"), "factur-x.xml");
+    }
+
+    #[test]
+    fn test_read_attachment_caps_decompressed_size() {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(FACTURX_XML.as_bytes()).unwrap();
+        let stream = Stream::new(
+            dictionary! { "Type" => "EmbeddedFile", "Filter" => "FlateDecode" },
+            encoder.finish().unwrap(),
+        );
+
+        let content = read_attachment(&stream, "factur-x.xml", FACTURX_XML.len()).unwrap();
+        assert_eq!(content, FACTURX_XML.as_bytes());
+
+        let err = read_attachment(&stream, "factur-x.xml", 64).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+    }
+}
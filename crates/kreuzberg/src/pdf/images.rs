@@ -1,6 +1,9 @@
 use super::error::{PdfError, Result};
-use lopdf::Document;
+use crate::core::config::ImageExtractionConfig;
+use lopdf::content::Content;
+use lopdf::{Dictionary, Document, Object, ObjectId};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PdfImage {
@@ -12,8 +15,15 @@ pub struct PdfImage {
     pub bits_per_component: Option<i64>,
     pub filters: Vec<String>,
     pub data: Vec<u8>,
+    /// Top-left corner of the image on its page, normalized to 0..1 with the origin at the
+    /// top-left of the page. `None` when the image is not drawn directly by the page content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_position: Option<(f32, f32)>,
 }
 
+/// A page image together with its normalized position on the page.
+type PositionedImage<'a> = (lopdf::xobject::PdfImage<'a>, Option<(f32, f32)>);
+
 #[derive(Debug)]
 pub struct PdfImageExtractor {
     document: Document,
@@ -28,7 +38,8 @@ impl PdfImageExtractor {
         let mut doc =
             Document::load_mem(pdf_bytes).map_err(|e| PdfError::InvalidPdf(format!("Failed to load PDF: {}", e)))?;
 
-        if doc.is_encrypted() {
+        // Owner-locked PDFs are encrypted with an empty user password.
+        if doc.is_encrypted() && doc.decrypt("").is_err() {
             if let Some(pwd) = password {
                 doc.decrypt(pwd).map_err(|_| PdfError::InvalidPassword)?;
             } else {
@@ -40,16 +51,43 @@ impl PdfImageExtractor {
     }
 
     pub fn extract_images(&self) -> Result<Vec<PdfImage>> {
+        self.collect_images(None).map(|(images, _)| images)
+    }
+
+    /// Extract images, stopping as soon as the configured image limits are reached.
+    ///
+    /// Images smaller than `min_width`/`min_height` are skipped. Image data is only
+    /// copied out of the document for images that fit within `max_images` and
+    /// `max_total_image_bytes`.
+    ///
+    /// # Returns
+    ///
+    /// The extracted images and a flag indicating whether extraction stopped early.
+    pub fn extract_images_with_limits(&self, limits: &ImageExtractionConfig) -> Result<(Vec<PdfImage>, bool)> {
+        self.collect_images(Some(limits))
+    }
+
+    fn collect_images(&self, limits: Option<&ImageExtractionConfig>) -> Result<(Vec<PdfImage>, bool)> {
         let mut all_images = Vec::new();
+        let mut total_bytes = 0usize;
         let pages = self.document.get_pages();
 
         for (page_num, page_id) in pages.iter() {
-            let images = self
-                .document
-                .get_page_images(*page_id)
-                .map_err(|e| PdfError::MetadataExtractionFailed(format!("Failed to get page images: {}", e)))?;
+            let images = self.page_images_in_reading_order(*page_id)?;
+
+            for (img_index, (img, page_position)) in images.iter().enumerate() {
+                if let Some(limits) = limits {
+                    let width = u32::try_from(img.width).unwrap_or(0);
+                    let height = u32::try_from(img.height).unwrap_or(0);
+                    if !limits.meets_min_dimensions(width, height) {
+                        continue;
+                    }
+                    if !limits.allows_image(all_images.len(), total_bytes, img.content.len()) {
+                        return Ok((all_images, true));
+                    }
+                }
 
-            for (img_index, img) in images.iter().enumerate() {
+                total_bytes += img.content.len();
                 let filters = img.filters.clone().unwrap_or_default();
 
                 all_images.push(PdfImage {
@@ -61,11 +99,38 @@ impl PdfImageExtractor {
                     bits_per_component: img.bits_per_component,
                     filters,
                     data: img.content.to_vec(),
+                    page_position: *page_position,
                 });
             }
         }
 
-        Ok(all_images)
+        Ok((all_images, false))
+    }
+
+    /// Images of a page with their positions, sorted top-to-bottom, then left-to-right.
+    ///
+    /// Images without a known position keep their resource order after the positioned ones.
+    fn page_images_in_reading_order(&self, page_id: ObjectId) -> Result<Vec<PositionedImage<'_>>> {
+        let images = self
+            .document
+            .get_page_images(page_id)
+            .map_err(|e| PdfError::MetadataExtractionFailed(format!("Failed to get page images: {}", e)))?;
+        let positions = image_positions(&self.document, page_id);
+
+        let mut images: Vec<_> = images
+            .into_iter()
+            .map(|img| {
+                let position = positions.get(&img.id).copied();
+                (img, position)
+            })
+            .collect();
+        images.sort_by(|(_, a), (_, b)| match (a, b) {
+            (Some((ax, ay)), Some((bx, by))) => ay.total_cmp(by).then(ax.total_cmp(bx)),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+        Ok(images)
     }
 
     pub fn extract_images_from_page(&self, page_number: u32) -> Result<Vec<PdfImage>> {
@@ -74,13 +139,10 @@ impl PdfImageExtractor {
             .get(&page_number)
             .ok_or(PdfError::PageNotFound(page_number as usize))?;
 
-        let images = self
-            .document
-            .get_page_images(*page_id)
-            .map_err(|e| PdfError::MetadataExtractionFailed(format!("Failed to get page images: {}", e)))?;
+        let images = self.page_images_in_reading_order(*page_id)?;
 
         let mut page_images = Vec::new();
-        for (img_index, img) in images.iter().enumerate() {
+        for (img_index, (img, page_position)) in images.iter().enumerate() {
             let filters = img.filters.clone().unwrap_or_default();
 
             page_images.push(PdfImage {
@@ -92,6 +154,7 @@ impl PdfImageExtractor {
                 bits_per_component: img.bits_per_component,
                 filters,
                 data: img.content.to_vec(),
+                page_position: *page_position,
             });
         }
 
@@ -104,11 +167,144 @@ impl PdfImageExtractor {
     }
 }
 
+/// Where each image XObject is first drawn by the page content stream.
+///
+/// Tracks the current transformation matrix through `q`/`Q`/`cm` and maps the image's
+/// unit square for every `Do` of an image XObject. Positions are the top-left corner,
+/// normalized to the page's MediaBox with the origin at the top-left. Images drawn
+/// inside form XObjects are not tracked.
+fn image_positions(document: &Document, page_id: ObjectId) -> HashMap<ObjectId, (f32, f32)> {
+    let mut positions = HashMap::new();
+
+    let Some([x0, y0, x1, y1]) = media_box(document, page_id) else {
+        return positions;
+    };
+    let (width, height) = (x1 - x0, y1 - y0);
+    if width <= 0.0 || height <= 0.0 {
+        return positions;
+    }
+
+    let xobjects = xobject_ids(document, page_id);
+    if xobjects.is_empty() {
+        return positions;
+    }
+    let Some(content) = document
+        .get_page_content(page_id)
+        .ok()
+        .and_then(|bytes| Content::decode(&bytes).ok())
+    else {
+        return positions;
+    };
+
+    let mut ctm = IDENTITY;
+    let mut stack = Vec::new();
+    for operation in &content.operations {
+        match operation.operator.as_str() {
+            "q" => stack.push(ctm),
+            "Q" => ctm = stack.pop().unwrap_or(IDENTITY),
+            "cm" => {
+                let values: Vec<f32> = operation.operands.iter().filter_map(|o| o.as_float().ok()).collect();
+                if let Ok(matrix) = <[f32; 6]>::try_from(values) {
+                    ctm = multiply(&matrix, &ctm);
+                }
+            }
+            "Do" => {
+                let Some(id) = operation
+                    .operands
+                    .first()
+                    .and_then(|name| name.as_name().ok())
+                    .and_then(|name| xobjects.get(name))
+                else {
+                    continue;
+                };
+                let corners = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)]
+                    .map(|(u, v)| (ctm[0] * u + ctm[2] * v + ctm[4], ctm[1] * u + ctm[3] * v + ctm[5]));
+                let left = corners.iter().map(|(x, _)| *x).fold(f32::INFINITY, f32::min);
+                let top = corners.iter().map(|(_, y)| *y).fold(f32::NEG_INFINITY, f32::max);
+                positions.entry(*id).or_insert((
+                    ((left - x0) / width).clamp(0.0, 1.0),
+                    ((y1 - top) / height).clamp(0.0, 1.0),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    positions
+}
+
+const IDENTITY: [f32; 6] = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+/// `m × ctm`, the CTM after a `cm` operator with matrix `m`.
+fn multiply(m: &[f32; 6], ctm: &[f32; 6]) -> [f32; 6] {
+    [
+        m[0] * ctm[0] + m[1] * ctm[2],
+        m[0] * ctm[1] + m[1] * ctm[3],
+        m[2] * ctm[0] + m[3] * ctm[2],
+        m[2] * ctm[1] + m[3] * ctm[3],
+        m[4] * ctm[0] + m[5] * ctm[2] + ctm[4],
+        m[4] * ctm[1] + m[5] * ctm[3] + ctm[5],
+    ]
+}
+
+/// A page attribute, following `Parent` links for inherited attributes.
+fn inherited_attribute<'a>(document: &'a Document, page_id: ObjectId, key: &[u8]) -> Option<&'a Object> {
+    let mut dict = document.get_dictionary(page_id).ok()?;
+    for _ in 0..32 {
+        if let Ok(value) = dict.get(key) {
+            return document.dereference(value).ok().map(|(_, value)| value);
+        }
+        let parent = dict.get(b"Parent").ok()?.as_reference().ok()?;
+        dict = document.get_dictionary(parent).ok()?;
+    }
+    None
+}
+
+fn media_box(document: &Document, page_id: ObjectId) -> Option<[f32; 4]> {
+    let values: Vec<f32> = inherited_attribute(document, page_id, b"MediaBox")?
+        .as_array()
+        .ok()?
+        .iter()
+        .filter_map(|value| document.dereference(value).ok()?.1.as_float().ok())
+        .collect();
+    let [ax, ay, bx, by] = <[f32; 4]>::try_from(values).ok()?;
+    Some([ax.min(bx), ay.min(by), ax.max(bx), ay.max(by)])
+}
+
+/// XObject resource names of a page mapped to their object ids.
+fn xobject_ids(document: &Document, page_id: ObjectId) -> HashMap<Vec<u8>, ObjectId> {
+    let xobjects: Option<&Dictionary> = inherited_attribute(document, page_id, b"Resources")
+        .and_then(|resources| resources.as_dict().ok())
+        .and_then(|resources| resources.get(b"XObject").ok())
+        .and_then(|xobjects| document.dereference(xobjects).ok())
+        .and_then(|(_, xobjects)| xobjects.as_dict().ok());
+
+    xobjects
+        .map(|xobjects| {
+            xobjects
+                .iter()
+                .filter_map(|(name, value)| Some((name.clone(), value.as_reference().ok()?)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 pub fn extract_images_from_pdf(pdf_bytes: &[u8]) -> Result<Vec<PdfImage>> {
     let extractor = PdfImageExtractor::new(pdf_bytes)?;
     extractor.extract_images()
 }
 
+/// Extract images from a PDF, honoring the image count and size limits in `limits`.
+///
+/// Returns the extracted images and whether extraction stopped because a limit was reached.
+pub fn extract_images_from_pdf_with_limits(
+    pdf_bytes: &[u8],
+    limits: &ImageExtractionConfig,
+) -> Result<(Vec<PdfImage>, bool)> {
+    let extractor = PdfImageExtractor::new(pdf_bytes)?;
+    extractor.extract_images_with_limits(limits)
+}
+
 pub fn extract_images_from_pdf_with_password(pdf_bytes: &[u8], password: &str) -> Result<Vec<PdfImage>> {
     let extractor = PdfImageExtractor::new_with_password(pdf_bytes, Some(password))?;
     extractor.extract_images()
@@ -117,6 +313,71 @@ pub fn extract_images_from_pdf_with_password(pdf_bytes: &[u8], password: &str) -
 #[cfg(test)]
 mod tests {
     use super::*;
+    use lopdf::{Stream, dictionary};
+
+    /// One 600x800pt page drawing image `ImB` near the bottom, then `ImA` near the top.
+    fn build_pdf_with_images() -> Vec<u8> {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+
+        let image = |gray: u8| {
+            Stream::new(
+                dictionary! {
+                    "Type" => "XObject",
+                    "Subtype" => "Image",
+                    "Width" => 1,
+                    "Height" => 1,
+                    "ColorSpace" => "DeviceGray",
+                    "BitsPerComponent" => 8,
+                },
+                vec![gray],
+            )
+        };
+        let image_a = doc.add_object(image(0));
+        let image_b = doc.add_object(image(255));
+
+        let content = b"q 100 0 0 50 300 100 cm /ImB Do Q q 100 0 0 50 60 600 cm /ImA Do Q".to_vec();
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content));
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 600.into(), 800.into()],
+            "Contents" => content_id,
+            "Resources" => dictionary! {
+                "XObject" => dictionary! { "ImA" => image_a, "ImB" => image_b },
+            },
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![Object::Reference(page_id)],
+                "Count" => 1,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_extract_images_in_reading_order_with_positions() {
+        let images = extract_images_from_pdf(&build_pdf_with_images()).unwrap();
+
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0].data, vec![0]);
+        assert_eq!(images[0].image_index, 1);
+        assert_eq!(images[0].page_position, Some((0.1, 0.1875)));
+        assert_eq!(images[1].data, vec![255]);
+        assert_eq!(images[1].image_index, 2);
+        assert_eq!(images[1].page_position, Some((0.5, 0.8125)));
+    }
 
     #[test]
     fn test_extractor_creation() {
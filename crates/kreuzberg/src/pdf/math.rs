@@ -0,0 +1,383 @@
+//! Formula detection and recognition in PDF pages.
+//!
+//! Formula regions are found in the text layer: glyphs set in math fonts (Computer
+//! Modern math italic and symbols, AMS, STIX, ...) or drawn from Unicode math blocks.
+//! Lines made mostly of such glyphs become display formulas, shorter runs inside
+//! running text become inline formulas. Each region is rendered and transcribed to
+//! LaTeX by the `math_ocr` recognizer.
+//!
+//! Pages without a text layer (scanned documents) yield no formulas.
+
+use super::error::{PdfError, Result};
+use crate::core::config::PdfConfig;
+use crate::types::{BoundingBox, Formula, FormulaKind};
+use image::DynamicImage;
+use pdfium_render::prelude::*;
+
+/// Render scale for formula crops (216 DPI); small glyphs such as sub- and
+/// superscripts need the extra resolution.
+const RENDER_SCALE: f32 = 3.0;
+
+/// Padding around each region in PDF points, so descenders and fraction bars are kept.
+const REGION_MARGIN: f32 = 2.0;
+
+/// Font name fragments identifying math fonts, matched against the lowercased name
+/// without its subset prefix. `symbol` is left out on purpose: it is mostly used
+/// for bullets.
+const MATH_FONT_MARKERS: &[&str] = &[
+    "cmmi", "cmsy", "cmex", "cmbsy", "msam", "msbm", "math", "stix", "mtmi", "mtsy", "mtex", "txmi", "txsy", "txex",
+    "pxmi", "pxsy", "eufm", "rsfs", "euclid",
+];
+
+/// A character of the text layer with its position in PDF points.
+#[derive(Debug, Clone, Copy)]
+struct Glyph {
+    ch: char,
+    math: bool,
+    left: f32,
+    bottom: f32,
+    right: f32,
+    top: f32,
+}
+
+/// A formula region on a page, in PDF points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Region {
+    kind: FormulaKind,
+    left: f32,
+    bottom: f32,
+    right: f32,
+    top: f32,
+}
+
+impl Region {
+    fn from_glyphs(kind: FormulaKind, glyphs: &[Glyph]) -> Self {
+        glyphs.iter().fold(
+            Self {
+                kind,
+                left: f32::MAX,
+                bottom: f32::MAX,
+                right: f32::MIN,
+                top: f32::MIN,
+            },
+            |region, g| Self {
+                kind,
+                left: region.left.min(g.left),
+                bottom: region.bottom.min(g.bottom),
+                right: region.right.max(g.right),
+                top: region.top.max(g.top),
+            },
+        )
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self {
+            kind: self.kind,
+            left: self.left.min(other.left),
+            bottom: self.bottom.min(other.bottom),
+            right: self.right.max(other.right),
+            top: self.top.max(other.top),
+        }
+    }
+}
+
+/// Recognize the formulas in a PDF as LaTeX.
+///
+/// Page numbers are 1-indexed and refer to `pdf_bytes`. The Pdfium lock is only held
+/// while regions are located and rendered, not during recognition.
+///
+/// # Errors
+///
+/// Returns an error if the document cannot be opened or rendered, or if the math OCR
+/// model cannot be loaded or run.
+pub(crate) fn extract_formulas(pdf_bytes: &[u8], pdf_config: Option<&PdfConfig>) -> crate::Result<Vec<Formula>> {
+    let crops = render_formula_regions(pdf_bytes, pdf_config)?;
+    if crops.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let images: Vec<DynamicImage> = crops.iter().map(|(_, _, image)| image.clone()).collect();
+    let latex = crate::math_ocr::recognize_formulas(&images)?;
+
+    Ok(crops
+        .into_iter()
+        .zip(latex)
+        .filter(|(_, latex)| !latex.is_empty())
+        .map(|((page_number, region, _), latex)| Formula {
+            latex,
+            kind: region.kind,
+            page_number,
+            bounding_box: Some(BoundingBox {
+                x0: region.left as f64,
+                y0: region.bottom as f64,
+                x1: region.right as f64,
+                y1: region.top as f64,
+            }),
+        })
+        .collect())
+}
+
+/// Locate formula regions on every page and render each one to an image.
+fn render_formula_regions(
+    pdf_bytes: &[u8],
+    pdf_config: Option<&PdfConfig>,
+) -> Result<Vec<(usize, Region, DynamicImage)>> {
+    let pdfium = super::bindings::bind_pdfium(PdfError::RenderingFailed, "formula rendering")?;
+
+    let passwords = pdf_config.and_then(|pdf| pdf.passwords.as_deref()).unwrap_or_default();
+    let document = super::password::load_document(&pdfium, pdf_bytes, passwords)?;
+
+    let mut crops = Vec::new();
+    for (page_index, page) in document.pages().iter().enumerate() {
+        let regions = detect_regions(&page_glyphs(&page)?);
+        if regions.is_empty() {
+            continue;
+        }
+
+        let page_height = page.height().value;
+        let render_config = PdfRenderConfig::new().scale_page_by_factor(RENDER_SCALE);
+        let rendered = page
+            .render_with_config(&render_config)
+            .map_err(|e| PdfError::RenderingFailed(format!("Failed to render page: {}", e)))?
+            .as_image();
+
+        for region in regions {
+            if let Some(image) = crop_region(&rendered, &region, page_height) {
+                crops.push((page_index + 1, region, image));
+            }
+        }
+    }
+
+    Ok(crops)
+}
+
+/// Glyphs of a page's text layer, in content order.
+fn page_glyphs(page: &PdfPage) -> Result<Vec<Glyph>> {
+    let text = page
+        .text()
+        .map_err(|e| PdfError::TextExtractionFailed(format!("Failed to get page text: {}", e)))?;
+
+    Ok(text
+        .chars()
+        .iter()
+        .filter_map(|pdf_char| {
+            let ch = pdf_char.unicode_char()?;
+            let bounds = pdf_char.loose_bounds().ok()?;
+            Some(Glyph {
+                ch,
+                math: is_math_char(ch) || is_math_font(&pdf_char.font_name()),
+                left: bounds.left().value,
+                bottom: bounds.bottom().value,
+                right: bounds.right().value,
+                top: bounds.top().value,
+            })
+        })
+        .collect())
+}
+
+/// Cut a region out of a page rendered at [`RENDER_SCALE`].
+fn crop_region(rendered: &DynamicImage, region: &Region, page_height: f32) -> Option<DynamicImage> {
+    let to_px = |points: f32| (points.max(0.0) * RENDER_SCALE) as u32;
+
+    let x = to_px(region.left - REGION_MARGIN);
+    let y = to_px(page_height - region.top - REGION_MARGIN);
+    let right = to_px(region.right + REGION_MARGIN).min(rendered.width());
+    let bottom = to_px(page_height - region.bottom + REGION_MARGIN).min(rendered.height());
+
+    (right > x && bottom > y).then(|| rendered.crop_imm(x, y, right - x, bottom - y))
+}
+
+/// Whether a font is a math font, judged by its name.
+fn is_math_font(font_name: &str) -> bool {
+    // Embedded subsets are named like "ABCDEF+CMMI10".
+    let name = match font_name.split_once('+') {
+        Some((prefix, rest)) if prefix.len() == 6 && prefix.chars().all(|c| c.is_ascii_uppercase()) => rest,
+        _ => font_name,
+    };
+    let name = name.to_ascii_lowercase();
+
+    MATH_FONT_MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+/// Whether a character is a Greek letter, operator, arrow or other math symbol.
+fn is_math_char(ch: char) -> bool {
+    matches!(ch,
+        '\u{0391}'..='\u{03C9}'
+        | '\u{2070}'..='\u{209F}'
+        | '\u{2190}'..='\u{21FF}'
+        | '\u{2200}'..='\u{22FF}'
+        | '\u{27C0}'..='\u{27EF}'
+        | '\u{2980}'..='\u{2AFF}'
+        | '\u{1D400}'..='\u{1D7FF}'
+        | '±' | '×' | '÷'
+    )
+}
+
+/// Find display and inline formula regions among a page's glyphs.
+fn detect_regions(glyphs: &[Glyph]) -> Vec<Region> {
+    let mut displays: Vec<Region> = Vec::new();
+    let mut inlines = Vec::new();
+
+    for line in split_lines(glyphs) {
+        let math_count = line.iter().filter(|g| g.math).count();
+        if math_count == 0 {
+            continue;
+        }
+
+        let text_count = line.iter().filter(|g| !g.math && g.ch.is_alphabetic()).count();
+        if math_count >= 2 && text_count * 4 <= math_count {
+            let region = Region::from_glyphs(FormulaKind::Display, line);
+            // Fractions and multi-line equations span several text lines.
+            match displays.last_mut() {
+                Some(previous) if vertically_adjacent(previous, &region) => *previous = previous.union(region),
+                _ => displays.push(region),
+            }
+        } else {
+            inlines.extend(inline_runs(line));
+        }
+    }
+
+    displays.extend(inlines);
+    displays
+}
+
+/// Split glyphs into text lines, on line breaks, backward jumps and vertical gaps.
+fn split_lines(glyphs: &[Glyph]) -> Vec<&[Glyph]> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+
+    for i in 1..glyphs.len() {
+        let (previous, current) = (&glyphs[i - 1], &glyphs[i]);
+        let line_break = matches!(current.ch, '\n' | '\r')
+            || current.left + 1.0 < previous.left
+            || current.bottom > previous.top
+            || current.top < previous.bottom;
+
+        if line_break {
+            lines.push(&glyphs[start..i]);
+            start = i;
+        }
+    }
+    if start < glyphs.len() {
+        lines.push(&glyphs[start..]);
+    }
+
+    lines
+        .into_iter()
+        .map(|line| {
+            let first = line.iter().position(|g| !g.ch.is_whitespace()).unwrap_or(line.len());
+            &line[first..]
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Runs of math glyphs within a line of text, spanning digits, punctuation and
+/// spaces between them but stopping at words.
+fn inline_runs(line: &[Glyph]) -> Vec<Region> {
+    let mut runs = Vec::new();
+    let mut run: Option<(usize, usize)> = None;
+
+    for (i, glyph) in line.iter().enumerate() {
+        if glyph.math {
+            run = Some(run.map_or((i, i), |(start, _)| (start, i)));
+        } else if glyph.ch.is_alphabetic()
+            && let Some((start, end)) = run.take()
+        {
+            runs.push(Region::from_glyphs(FormulaKind::Inline, &line[start..=end]));
+        }
+    }
+    if let Some((start, end)) = run {
+        runs.push(Region::from_glyphs(FormulaKind::Inline, &line[start..=end]));
+    }
+
+    runs
+}
+
+/// Whether `below` starts right under `above`, within about one line height.
+fn vertically_adjacent(above: &Region, below: &Region) -> bool {
+    let line_height = (above.top - above.bottom).max(below.top - below.bottom);
+    let horizontal_overlap = above.left < below.right && below.left < above.right;
+    horizontal_overlap && above.bottom - below.top <= line_height * 0.5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Glyphs for `text` on one line, 6pt wide each, with `math` marking math glyphs.
+    fn line(text: &str, math: impl Fn(char) -> bool, bottom: f32) -> Vec<Glyph> {
+        text.chars()
+            .enumerate()
+            .map(|(i, ch)| Glyph {
+                ch,
+                math: math(ch),
+                left: 72.0 + i as f32 * 6.0,
+                bottom,
+                right: 78.0 + i as f32 * 6.0,
+                top: bottom + 10.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_is_math_font_strips_subset_prefix() {
+        assert!(is_math_font("ABCDEF+CMMI10"));
+        assert!(is_math_font("STIXTwoMath-Regular"));
+        assert!(is_math_font("CambriaMath"));
+        assert!(!is_math_font("ABCDEF+CMR10"));
+        assert!(!is_math_font("Symbol"));
+    }
+
+    #[test]
+    fn test_is_math_char() {
+        assert!(is_math_char('α'));
+        assert!(is_math_char('∑'));
+        assert!(is_math_char('≤'));
+        assert!(!is_math_char('a'));
+        assert!(!is_math_char('='));
+    }
+
+    #[test]
+    fn test_detect_regions_finds_display_formula() {
+        let mut glyphs = line("Consider the sum", |_| false, 700.0);
+        glyphs.extend(line("∑xᵢ = ∫f", |ch| ch != ' ' && ch != '=', 680.0));
+
+        let regions = detect_regions(&glyphs);
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].kind, FormulaKind::Display);
+        assert_eq!(regions[0].bottom, 680.0);
+    }
+
+    #[test]
+    fn test_detect_regions_merges_stacked_display_lines() {
+        let mut glyphs = line("αβ", |_| true, 700.0);
+        glyphs.extend(line("γδ", |_| true, 692.0));
+
+        let regions = detect_regions(&glyphs);
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!((regions[0].bottom, regions[0].top), (692.0, 710.0));
+    }
+
+    #[test]
+    fn test_detect_regions_finds_inline_formula() {
+        let glyphs = line("where α ≤ 2β holds", |ch| matches!(ch, 'α' | '≤' | 'β'), 700.0);
+
+        let regions = detect_regions(&glyphs);
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].kind, FormulaKind::Inline);
+        assert_eq!(regions[0].left, 72.0 + 6.0 * 6.0);
+        assert_eq!(regions[0].right, 78.0 + 6.0 * 11.0);
+    }
+
+    #[test]
+    fn test_detect_regions_ignores_plain_text() {
+        let mut glyphs = line("Plain text only.", |_| false, 700.0);
+        glyphs.extend(line("Second line, 42.", |_| false, 686.0));
+
+        assert!(detect_regions(&glyphs).is_empty());
+    }
+}
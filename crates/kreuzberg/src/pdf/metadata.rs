@@ -60,11 +60,14 @@ pub struct PdfExtractionMetadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub keywords: Option<Vec<String>>,
 
-    /// Creation timestamp (ISO 8601 format)
+    /// Creation timestamp as stored in the PDF, e.g. `D:20230115123045+01'00'`
+    ///
+    /// The extraction pipeline normalizes it to RFC 3339 in `Metadata.created_at` and keeps
+    /// this value as `created_at_raw`; see [`crate::utils::normalize_date`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
 
-    /// Last modification timestamp (ISO 8601 format)
+    /// Last modification timestamp as stored in the PDF (see `created_at`)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_at: Option<String>,
 
@@ -327,9 +330,10 @@ pub fn extract_common_metadata_from_document(document: &PdfDocument<'_>) -> Resu
         .map(|keywords_str| parse_keywords(keywords_str))
         .and_then(|parsed| if !parsed.is_empty() { Some(parsed) } else { None });
 
-    let created_at = metadata_cache[4].as_ref().map(|date_str| parse_pdf_date(date_str));
+    // Dates stay raw; the pipeline normalizes them and records the original as `<field>_raw`.
+    let created_at = metadata_cache[4].as_ref().map(|date_str| date_str.trim().to_string());
 
-    let modified_at = metadata_cache[5].as_ref().map(|date_str| parse_pdf_date(date_str));
+    let modified_at = metadata_cache[5].as_ref().map(|date_str| date_str.trim().to_string());
 
     let created_by = metadata_cache[6].clone();
 
@@ -386,36 +390,6 @@ fn parse_keywords(keywords_str: &str) -> Vec<String> {
         .collect()
 }
 
-fn parse_pdf_date(date_str: &str) -> String {
-    let cleaned = date_str.trim();
-
-    if cleaned.starts_with("D:") && cleaned.len() >= 10 {
-        let year = &cleaned[2..6];
-        let month = &cleaned[6..8];
-        let day = &cleaned[8..10];
-
-        if cleaned.len() >= 16 {
-            let hour = &cleaned[10..12];
-            let minute = &cleaned[12..14];
-            let second = &cleaned[14..16];
-            format!("{}-{}-{}T{}:{}:{}Z", year, month, day, hour, minute, second)
-        } else if cleaned.len() >= 14 {
-            let hour = &cleaned[10..12];
-            let minute = &cleaned[12..14];
-            format!("{}-{}-{}T{}:{}:00Z", year, month, day, hour, minute)
-        } else {
-            format!("{}-{}-{}T00:00:00Z", year, month, day)
-        }
-    } else if cleaned.len() >= 8 {
-        let year = &cleaned[0..4];
-        let month = &cleaned[4..6];
-        let day = &cleaned[6..8];
-        format!("{}-{}-{}T00:00:00Z", year, month, day)
-    } else {
-        date_str.to_string()
-    }
-}
-
 fn format_pdf_version(version: PdfDocumentVersion) -> Option<String> {
     match version {
         PdfDocumentVersion::Unset => None,
@@ -486,20 +460,26 @@ mod tests {
 
     #[test]
     fn test_parse_pdf_date_full() {
-        let date = parse_pdf_date("D:20230115123045");
-        assert_eq!(date, "2023-01-15T12:30:45Z");
+        let date = crate::utils::normalize_date("D:20230115123045");
+        assert_eq!(date.as_deref(), Some("2023-01-15T12:30:45Z"));
     }
 
     #[test]
     fn test_parse_pdf_date_no_time() {
-        let date = parse_pdf_date("D:20230115");
-        assert_eq!(date, "2023-01-15T00:00:00Z");
+        let date = crate::utils::normalize_date("D:20230115");
+        assert_eq!(date.as_deref(), Some("2023-01-15T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_parse_pdf_date_with_offset() {
+        let date = crate::utils::normalize_date("D:20230101120000+01'00'");
+        assert_eq!(date.as_deref(), Some("2023-01-01T12:00:00+01:00"));
     }
 
     #[test]
     fn test_parse_pdf_date_no_prefix() {
-        let date = parse_pdf_date("20230115");
-        assert_eq!(date, "2023-01-15T00:00:00Z");
+        let date = crate::utils::normalize_date("20230115");
+        assert_eq!(date.as_deref(), Some("2023-01-15T00:00:00Z"));
     }
 
     #[test]
@@ -10,6 +10,9 @@
 //! - **Metadata extraction**: Parse PDF metadata (title, author, creation date, etc.)
 //! - **Image extraction**: Extract embedded images from PDF pages
 //! - **Page rendering**: Render PDF pages to images for OCR processing
+//! - **Reading order**: Optional column-aware text ordering for multi-column layouts
+//! - **PDF/A checks**: Report claimed PDF/A conformance and basic rule violations
+//! - **E-invoices**: Read embedded ZUGFeRD / Factur-X / XRechnung invoice data (`einvoice` feature)
 //! - **Error handling**: Comprehensive PDF-specific error types
 //!
 //! # Example
@@ -39,6 +42,8 @@
 pub(crate) mod bindings;
 #[cfg(all(feature = "pdf", feature = "bundled-pdfium"))]
 pub mod bundled;
+#[cfg(feature = "einvoice")]
+pub mod einvoice;
 #[cfg(feature = "pdf")]
 pub mod error;
 #[cfg(feature = "pdf")]
@@ -47,9 +52,23 @@ pub mod fonts;
 pub mod hierarchy;
 #[cfg(feature = "pdf")]
 pub mod images;
+#[cfg(feature = "math-ocr")]
+pub(crate) mod math;
 #[cfg(feature = "pdf")]
 pub mod metadata;
 #[cfg(feature = "pdf")]
+pub(crate) mod outline;
+#[cfg(feature = "pdf")]
+pub(crate) mod page_range;
+#[cfg(feature = "pdf")]
+pub(crate) mod password;
+#[cfg(feature = "pdf")]
+pub mod pdfa;
+#[cfg(feature = "pdf")]
+pub(crate) mod positions;
+#[cfg(feature = "pdf")]
+pub(crate) mod reading_order;
+#[cfg(feature = "pdf")]
 pub mod rendering;
 #[cfg(feature = "pdf")]
 pub mod table;
@@ -60,6 +79,10 @@ pub mod text;
 pub use crate::core::config::HierarchyConfig;
 #[cfg(all(feature = "pdf", feature = "bundled-pdfium"))]
 pub use bundled::extract_bundled_pdfium;
+#[cfg(feature = "einvoice")]
+pub use einvoice::{
+    EInvoice, EInvoiceLineItem, EInvoiceParty, EInvoiceSyntax, extract_einvoice, extract_einvoice_with_config,
+};
 #[cfg(feature = "pdf")]
 pub use error::PdfError;
 #[cfg(feature = "pdf")]
@@ -74,6 +97,8 @@ pub use images::{PdfImage, PdfImageExtractor, extract_images_from_pdf};
 #[cfg(feature = "pdf")]
 pub use metadata::extract_metadata;
 #[cfg(feature = "pdf")]
+pub use pdfa::{PdfaReport, PdfaViolation, check_pdfa_conformance};
+#[cfg(feature = "pdf")]
 pub use rendering::{PageRenderOptions, render_page_to_image};
 #[cfg(feature = "pdf")]
 pub use table::extract_words_from_page;
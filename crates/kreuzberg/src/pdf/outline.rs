@@ -0,0 +1,78 @@
+//! PDF outline (bookmark) extraction.
+//!
+//! Walks the document's outline dictionary and converts it into [`OutlineNode`]s.
+//! Malformed PDFs can contain cyclic or absurdly deep outlines, so the walk is bounded
+//! by depth and by total entry count.
+
+use super::error::{PdfError, Result};
+use crate::core::config::PdfConfig;
+use crate::types::OutlineNode;
+use pdfium_render::prelude::*;
+
+/// Maximum nesting depth followed when walking the outline.
+const MAX_OUTLINE_DEPTH: usize = 32;
+
+/// Maximum number of outline entries collected from one document.
+const MAX_OUTLINE_ENTRIES: usize = 10_000;
+
+/// Extract the outline of a PDF from its bookmarks.
+///
+/// Page numbers are 1-indexed and refer to `pdf_bytes`. Bookmarks that do not point
+/// to a page in the document have no `page_number`.
+pub(crate) fn extract_outline(pdf_bytes: &[u8], pdf_config: Option<&PdfConfig>) -> Result<Vec<OutlineNode>> {
+    let pdfium = super::bindings::bind_pdfium(PdfError::InvalidPdf, "initialize Pdfium")?;
+
+    let passwords = pdf_config.and_then(|pdf| pdf.passwords.as_deref()).unwrap_or_default();
+    let document = super::password::load_document(&pdfium, pdf_bytes, passwords)?;
+
+    let mut remaining = MAX_OUTLINE_ENTRIES;
+    Ok(document
+        .bookmarks()
+        .root()
+        .map(|root| collect_siblings(root, 1, &mut remaining))
+        .unwrap_or_default())
+}
+
+/// Convert `first` and its following siblings, recursing into their children.
+fn collect_siblings(first: PdfBookmark<'_>, level: usize, remaining: &mut usize) -> Vec<OutlineNode> {
+    let mut nodes = Vec::new();
+    let mut current = Some(first);
+
+    while let Some(bookmark) = current {
+        if *remaining == 0 {
+            break;
+        }
+        *remaining -= 1;
+
+        let children = match bookmark.first_child() {
+            Some(child) if level < MAX_OUTLINE_DEPTH => collect_siblings(child, level + 1, remaining),
+            _ => Vec::new(),
+        };
+
+        nodes.push(OutlineNode {
+            title: bookmark.title().map(|t| t.trim().to_string()).unwrap_or_default(),
+            level,
+            page_number: bookmark_page_number(&bookmark),
+            children,
+        });
+
+        current = bookmark.next_sibling();
+    }
+
+    nodes
+}
+
+/// Resolve the 1-indexed target page of a bookmark, from its destination or GoTo action.
+fn bookmark_page_number(bookmark: &PdfBookmark<'_>) -> Option<usize> {
+    let page_index = match bookmark.destination() {
+        Some(destination) => destination.page_index().ok(),
+        None => bookmark.action().and_then(|action| {
+            action
+                .as_local_destination_action()
+                .and_then(|local| local.destination().ok())
+                .and_then(|destination| destination.page_index().ok())
+        }),
+    }?;
+
+    usize::try_from(page_index).ok().map(|index| index + 1)
+}
@@ -0,0 +1,100 @@
+//! Page range selection for PDF extraction.
+//!
+//! The requested pages are copied into a new in-memory document so that every later
+//! extraction phase (text, tables, images, OCR) only sees the selected pages. Page
+//! numbers are mapped back to the original document after extraction.
+
+use super::error::PdfError;
+use super::metadata::PdfExtractionMetadata;
+use crate::core::config::PdfConfig;
+use crate::{KreuzbergError, Result};
+
+/// Check that a 1-indexed, inclusive page range is well-formed and lies within the document.
+pub(crate) fn validate_page_range((start, end): (usize, usize), page_count: usize) -> Result<()> {
+    if start == 0 {
+        return Err(KreuzbergError::validation(
+            "Invalid page_range: page numbers are 1-indexed, start must be at least 1",
+        ));
+    }
+    if start > end {
+        return Err(KreuzbergError::validation(format!(
+            "Invalid page_range: start ({}) must not be greater than end ({})",
+            start, end
+        )));
+    }
+    if end > page_count {
+        return Err(KreuzbergError::validation(format!(
+            "Invalid page_range: end ({}) exceeds document page count ({})",
+            end, page_count
+        )));
+    }
+    Ok(())
+}
+
+/// Pages selected from a PDF by [`extract_page_range`].
+pub(crate) struct PageSelection {
+    /// New PDF holding only the selected pages
+    pub bytes: Vec<u8>,
+    /// Whether owner restrictions of the source were bypassed
+    pub owner_restrictions_bypassed: bool,
+    /// Document metadata of the source, which the new PDF lacks; `page_count` is the
+    /// source's page count and there is no page structure
+    pub metadata: PdfExtractionMetadata,
+}
+
+/// Build a new PDF containing only the pages in `range` (1-indexed, inclusive).
+///
+/// The source is opened with `pdf_config.passwords` and its owner restrictions are checked
+/// here, since the unencrypted subset no longer carries them. Its document metadata is
+/// read here as well, since the subset is a new document without it.
+pub(crate) fn extract_page_range(
+    content: &[u8],
+    range: (usize, usize),
+    pdf_config: &PdfConfig,
+) -> Result<PageSelection> {
+    let pdfium = super::bindings::bind_pdfium(PdfError::InvalidPdf, "initialize Pdfium")?;
+
+    let passwords = pdf_config.passwords.as_deref().unwrap_or_default();
+    let source = super::password::load_document(&pdfium, content, passwords)?;
+    let owner_restrictions_bypassed =
+        super::password::check_owner_restrictions(&source, pdf_config.allow_owner_locked)?;
+
+    validate_page_range(range, source.pages().len() as usize)?;
+    let metadata = super::metadata::extract_metadata_from_document_impl(&source, None, "")?;
+
+    let mut subset = pdfium
+        .create_new_pdf()
+        .map_err(|e| PdfError::ExtractionFailed(format!("Failed to create PDF for page range: {:?}", e)))?;
+    let (start, end) = (range.0 as i32 - 1, range.1 as i32 - 1);
+    subset
+        .pages_mut()
+        .copy_page_range_from_document(&source, start..=end, 0)
+        .map_err(|e| PdfError::ExtractionFailed(format!("Failed to copy pages {}-{}: {:?}", range.0, range.1, e)))?;
+
+    let bytes = subset
+        .save_to_bytes()
+        .map_err(|e| PdfError::ExtractionFailed(format!("Failed to save page range: {:?}", e)))?;
+    Ok(PageSelection {
+        bytes,
+        owner_restrictions_bypassed,
+        metadata,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_page_range_accepts_in_bounds() {
+        assert!(validate_page_range((1, 1), 1).is_ok());
+        assert!(validate_page_range((5, 10), 10).is_ok());
+    }
+
+    #[test]
+    fn test_validate_page_range_rejects_invalid() {
+        assert!(validate_page_range((0, 2), 10).is_err());
+        assert!(validate_page_range((4, 3), 10).is_err());
+        assert!(validate_page_range((5, 11), 10).is_err());
+    }
+}
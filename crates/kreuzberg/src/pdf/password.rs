@@ -0,0 +1,95 @@
+//! Opening encrypted PDFs.
+//!
+//! PDFs can carry two passwords: a *user* password required to open the document and an
+//! *owner* password guarding permissions such as printing or copying text. Many PDFs are
+//! "owner-locked": encrypted with an empty user password so that anyone can open them,
+//! while the permission flags forbid copying. This module opens documents with the empty
+//! user password before trying configured passwords, and checks owner restrictions.
+
+use super::error::{PdfError, Result};
+use pdfium_render::prelude::*;
+
+/// Open a PDF, trying the empty user password before each of `passwords` in order.
+///
+/// # Errors
+///
+/// Returns [`PdfError::PasswordRequired`] when the document needs a password and none
+/// was configured, and [`PdfError::InvalidPassword`] when none of `passwords` opened it.
+pub(crate) fn load_document<'a>(
+    pdfium: &'a Pdfium,
+    pdf_bytes: &'a [u8],
+    passwords: &[String],
+) -> Result<PdfDocument<'a>> {
+    let err_msg = match pdfium.load_pdf_from_byte_slice(pdf_bytes, None) {
+        Ok(document) => return Ok(document),
+        Err(e) => super::error::format_pdfium_error(e),
+    };
+
+    if !is_password_error(&err_msg) {
+        return Err(PdfError::InvalidPdf(err_msg));
+    }
+    if passwords.is_empty() {
+        return Err(PdfError::PasswordRequired);
+    }
+
+    for password in passwords {
+        match pdfium.load_pdf_from_byte_slice(pdf_bytes, Some(password)) {
+            Ok(document) => return Ok(document),
+            Err(e) => {
+                let err_msg = super::error::format_pdfium_error(e);
+                if !is_password_error(&err_msg) {
+                    return Err(PdfError::InvalidPdf(err_msg));
+                }
+            }
+        }
+    }
+
+    Err(PdfError::InvalidPassword)
+}
+
+/// Check the owner permissions of an opened document.
+///
+/// When the owner password forbids text extraction, the restriction is bypassed with a
+/// warning if `allow_owner_locked` is set, and reported as an error otherwise.
+///
+/// # Returns
+///
+/// `true` if an owner restriction was bypassed.
+pub(crate) fn check_owner_restrictions(document: &PdfDocument<'_>, allow_owner_locked: bool) -> Result<bool> {
+    let permissions = document.permissions();
+    let encrypted = permissions
+        .security_handler_revision()
+        .is_ok_and(|revision| revision != PdfSecurityHandlerRevision::Unprotected);
+    if !encrypted || permissions.can_extract_text_and_graphics().unwrap_or(true) {
+        return Ok(false);
+    }
+
+    if !allow_owner_locked {
+        return Err(PdfError::EncryptionNotSupported(
+            "the document's owner permissions forbid text extraction; set PdfConfig.allow_owner_locked to extract it"
+                .to_string(),
+        ));
+    }
+
+    tracing::warn!(
+        "Bypassing PDF owner restrictions that forbid text extraction (PdfConfig.allow_owner_locked is set); \
+         make sure you are permitted to extract this document"
+    );
+    Ok(true)
+}
+
+fn is_password_error(err_msg: &str) -> bool {
+    err_msg.contains("password") || err_msg.contains("Password")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_password_error() {
+        assert!(is_password_error("PdfiumLibraryInternalError(PasswordError)"));
+        assert!(is_password_error("incorrect password"));
+        assert!(!is_password_error("PdfiumLibraryInternalError(FormatError)"));
+    }
+}
@@ -0,0 +1,399 @@
+//! Basic PDF/A conformance checks.
+//!
+//! This is not a full PDF/A validator. It reads the conformance level a document
+//! claims in its XMP metadata and checks the rules that most often break archival
+//! workflows: encryption, non-embedded fonts, and transparency.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::pdf::check_pdfa_conformance;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let pdf_bytes = std::fs::read("archive.pdf")?;
+//! let report = check_pdfa_conformance(&pdf_bytes)?;
+//!
+//! println!("Claimed: {:?}", report.claimed_level());
+//! for violation in &report.violations {
+//!     println!("Violation: {}", violation);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::error::{PdfError, Result};
+use lopdf::{Dictionary, Document, Object};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Result of a PDF/A conformance check.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PdfaReport {
+    /// PDF/A part claimed in the XMP metadata (`pdfaid:part`, e.g. 1, 2, 3, 4)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part: Option<u8>,
+
+    /// Conformance level claimed in the XMP metadata (`pdfaid:conformance`, e.g. "A", "B", "U")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conformance: Option<String>,
+
+    /// Rule violations found in the document
+    pub violations: Vec<PdfaViolation>,
+}
+
+impl PdfaReport {
+    /// The claimed conformance level formatted as e.g. `PDF/A-2b`, if any is claimed.
+    pub fn claimed_level(&self) -> Option<String> {
+        let part = self.part?;
+        Some(match &self.conformance {
+            Some(conformance) => format!("PDF/A-{}{}", part, conformance.to_ascii_lowercase()),
+            None => format!("PDF/A-{}", part),
+        })
+    }
+
+    /// Whether the document claims PDF/A conformance and no violations were found.
+    pub fn is_conformant(&self) -> bool {
+        self.part.is_some() && self.violations.is_empty()
+    }
+}
+
+/// A PDF/A rule violation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum PdfaViolation {
+    /// The document is encrypted.
+    Encrypted,
+    /// A font is used without its font program embedded.
+    FontNotEmbedded {
+        /// The font's `BaseFont` name
+        font: String,
+    },
+    /// The document uses transparency.
+    Transparency {
+        /// The transparency feature that was found (soft mask, constant alpha, blend mode, group)
+        feature: String,
+    },
+}
+
+impl fmt::Display for PdfaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PdfaViolation::Encrypted => write!(f, "document is encrypted"),
+            PdfaViolation::FontNotEmbedded { font } => write!(f, "font '{}' is not embedded", font),
+            PdfaViolation::Transparency { feature } => write!(f, "transparency is used ({})", feature),
+        }
+    }
+}
+
+/// Check a PDF for PDF/A conformance.
+///
+/// Returns the level claimed in the document's XMP metadata along with any
+/// violations of the checked rules. A document with no claim can still be
+/// checked, e.g. to see whether it is a candidate for conversion.
+pub fn check_pdfa_conformance(pdf_bytes: &[u8]) -> Result<PdfaReport> {
+    let document =
+        Document::load_mem(pdf_bytes).map_err(|e| PdfError::InvalidPdf(format!("Failed to load PDF: {}", e)))?;
+
+    let mut report = PdfaReport::default();
+
+    if let Some(xmp) = xmp_metadata(&document) {
+        report.part = xmp_property(&xmp, "pdfaid:part").and_then(|part| part.parse().ok());
+        report.conformance = xmp_property(&xmp, "pdfaid:conformance").map(|c| c.to_ascii_uppercase());
+    }
+
+    if document.is_encrypted() || document.trailer.has(b"Encrypt") {
+        report.violations.push(PdfaViolation::Encrypted);
+    }
+
+    let mut scan = RuleScan::default();
+    for object in document.objects.values() {
+        scan.visit(&document, object);
+    }
+
+    report.violations.extend(
+        scan.unembedded_fonts
+            .into_iter()
+            .map(|font| PdfaViolation::FontNotEmbedded { font }),
+    );
+    report.violations.extend(
+        scan.transparency
+            .into_iter()
+            .map(|feature| PdfaViolation::Transparency {
+                feature: feature.to_string(),
+            }),
+    );
+
+    Ok(report)
+}
+
+/// Read the document-level XMP metadata stream referenced from the catalog.
+fn xmp_metadata(document: &Document) -> Option<String> {
+    let catalog = document.catalog().ok()?;
+    let (_, metadata) = document.dereference(catalog.get(b"Metadata").ok()?).ok()?;
+    let stream = metadata.as_stream().ok()?;
+    let content = stream.decompressed_content().unwrap_or_else(|_| stream.content.clone());
+    Some(String::from_utf8_lossy(&content).into_owned())
+}
+
+/// Find an XMP property written either as an attribute (`name="value"`) or an element (`<name>value</name>`).
+fn xmp_property(xmp: &str, name: &str) -> Option<String> {
+    let attribute = format!("{}=", name);
+    if let Some(start) = xmp.find(&attribute) {
+        let rest = &xmp[start + attribute.len()..];
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &rest[1..];
+        let end = value.find(quote)?;
+        return Some(value[..end].trim().to_string()).filter(|v| !v.is_empty());
+    }
+
+    let open = format!("<{}>", name);
+    let start = xmp.find(&open)? + open.len();
+    let end = xmp[start..].find('<')?;
+    Some(xmp[start..start + end].trim().to_string()).filter(|v| !v.is_empty())
+}
+
+#[derive(Default)]
+struct RuleScan {
+    unembedded_fonts: BTreeSet<String>,
+    transparency: BTreeSet<&'static str>,
+}
+
+impl RuleScan {
+    fn visit(&mut self, document: &Document, object: &Object) {
+        match object {
+            Object::Dictionary(dict) => self.visit_dictionary(document, dict),
+            Object::Stream(stream) => self.visit_dictionary(document, &stream.dict),
+            Object::Array(items) => {
+                for item in items {
+                    self.visit(document, item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_dictionary(&mut self, document: &Document, dict: &Dictionary) {
+        self.check_font(document, dict);
+        self.check_transparency(dict);
+
+        for (_, value) in dict.iter() {
+            self.visit(document, value);
+        }
+    }
+
+    fn check_font(&mut self, document: &Document, dict: &Dictionary) {
+        if name_of(dict, b"Type") != Some(b"Font".as_slice()) {
+            return;
+        }
+        // Type0 fonts embed through their descendant CIDFont; Type3 glyphs are content streams.
+        if matches!(name_of(dict, b"Subtype"), Some(b"Type0") | Some(b"Type3")) {
+            return;
+        }
+
+        let embedded = dict
+            .get(b"FontDescriptor")
+            .ok()
+            .and_then(|descriptor| document.dereference(descriptor).ok())
+            .and_then(|(_, descriptor)| descriptor.as_dict().ok())
+            .is_some_and(|descriptor| {
+                descriptor.has(b"FontFile") || descriptor.has(b"FontFile2") || descriptor.has(b"FontFile3")
+            });
+
+        if !embedded {
+            let font = name_of(dict, b"BaseFont")
+                .map(|name| String::from_utf8_lossy(name).into_owned())
+                .unwrap_or_else(|| "unnamed".to_string());
+            self.unembedded_fonts.insert(font);
+        }
+    }
+
+    fn check_transparency(&mut self, dict: &Dictionary) {
+        if let Ok(smask) = dict.get(b"SMask")
+            && smask.as_name().ok() != Some(b"None".as_slice())
+        {
+            self.transparency.insert("soft mask");
+        }
+
+        for key in [b"CA".as_slice(), b"ca".as_slice()] {
+            if let Ok(alpha) = dict.get(key)
+                && alpha.as_float().is_ok_and(|alpha| alpha < 1.0)
+            {
+                self.transparency.insert("constant alpha");
+            }
+        }
+
+        if let Some(mode) = name_of(dict, b"BM")
+            && mode != b"Normal"
+            && mode != b"Compatible"
+        {
+            self.transparency.insert("blend mode");
+        }
+
+        if name_of(dict, b"S") == Some(b"Transparency".as_slice()) {
+            self.transparency.insert("transparency group");
+        }
+    }
+}
+
+fn name_of<'a>(dict: &'a Dictionary, key: &[u8]) -> Option<&'a [u8]> {
+    dict.get(key).ok()?.as_name().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::{Stream, dictionary};
+
+    const XMP_PDFA_2B: &str = r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description rdf:about="" xmlns:pdfaid="http://www.aiim.org/pdfa/ns/id/">
+      <pdfaid:part>2</pdfaid:part>
+      <pdfaid:conformance>B</pdfaid:conformance>
+    </rdf:Description>
+  </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>"#;
+
+    fn build_pdf(xmp: Option<&str>, font_embedded: bool, page_extras: Dictionary) -> Vec<u8> {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+
+        let mut descriptor = dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => "Helvetica",
+        };
+        if font_embedded {
+            let font_file = doc.add_object(Stream::new(dictionary! {}, vec![0u8; 16]));
+            descriptor.set("FontFile2", font_file);
+        }
+        let descriptor_id = doc.add_object(descriptor);
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "TrueType",
+            "BaseFont" => "Helvetica",
+            "FontDescriptor" => descriptor_id,
+        });
+
+        let content_id = doc.add_object(Stream::new(dictionary! {}, b"BT /F1 12 Tf (Hi) Tj ET".to_vec()));
+        let mut page = dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "Contents" => content_id,
+            "Resources" => dictionary! { "Font" => dictionary! { "F1" => font_id } },
+        };
+        for (key, value) in page_extras.iter() {
+            page.set(key.clone(), value.clone());
+        }
+        let page_id = doc.add_object(page);
+
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![Object::Reference(page_id)],
+                "Count" => 1,
+            }),
+        );
+
+        let mut catalog = dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        };
+        if let Some(xmp) = xmp {
+            let metadata_id = doc.add_object(Stream::new(
+                dictionary! { "Type" => "Metadata", "Subtype" => "XML" },
+                xmp.as_bytes().to_vec(),
+            ));
+            catalog.set("Metadata", metadata_id);
+        }
+        let catalog_id = doc.add_object(catalog);
+        doc.trailer.set("Root", catalog_id);
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_conformant_pdfa_document() {
+        let bytes = build_pdf(Some(XMP_PDFA_2B), true, Dictionary::new());
+        let report = check_pdfa_conformance(&bytes).unwrap();
+
+        assert_eq!(report.part, Some(2));
+        assert_eq!(report.conformance.as_deref(), Some("B"));
+        assert_eq!(report.claimed_level().as_deref(), Some("PDF/A-2b"));
+        assert!(report.violations.is_empty());
+        assert!(report.is_conformant());
+    }
+
+    #[test]
+    fn test_reports_unembedded_font() {
+        let bytes = build_pdf(Some(XMP_PDFA_2B), false, Dictionary::new());
+        let report = check_pdfa_conformance(&bytes).unwrap();
+
+        assert_eq!(
+            report.violations,
+            vec![PdfaViolation::FontNotEmbedded {
+                font: "Helvetica".to_string()
+            }]
+        );
+        assert!(!report.is_conformant());
+    }
+
+    #[test]
+    fn test_reports_transparency_group() {
+        let extras = dictionary! {
+            "Group" => dictionary! { "S" => "Transparency", "CS" => "DeviceRGB" },
+        };
+        let bytes = build_pdf(None, true, extras);
+        let report = check_pdfa_conformance(&bytes).unwrap();
+
+        assert_eq!(report.part, None);
+        assert!(report.claimed_level().is_none());
+        assert!(report.violations.contains(&PdfaViolation::Transparency {
+            feature: "transparency group".to_string()
+        }));
+        assert!(!report.is_conformant());
+    }
+
+    #[test]
+    fn test_xmp_property_attribute_and_element_forms() {
+        assert_eq!(
+            xmp_property(
+                r#"<rdf:Description pdfaid:part="1" pdfaid:conformance='a'/>"#,
+                "pdfaid:part"
+            )
+            .as_deref(),
+            Some("1")
+        );
+        assert_eq!(
+            xmp_property(
+                r#"<rdf:Description pdfaid:part="1" pdfaid:conformance='a'/>"#,
+                "pdfaid:conformance"
+            )
+            .as_deref(),
+            Some("a")
+        );
+        assert_eq!(xmp_property(XMP_PDFA_2B, "pdfaid:part").as_deref(), Some("2"));
+        assert_eq!(xmp_property(XMP_PDFA_2B, "pdfaid:amd"), None);
+    }
+
+    #[test]
+    fn test_invalid_pdf() {
+        assert!(check_pdfa_conformance(b"not a pdf").is_err());
+    }
+
+    #[test]
+    fn test_violation_display() {
+        assert_eq!(PdfaViolation::Encrypted.to_string(), "document is encrypted");
+        assert_eq!(
+            PdfaViolation::FontNotEmbedded {
+                font: "Arial".to_string()
+            }
+            .to_string(),
+            "font 'Arial' is not embedded"
+        );
+    }
+}
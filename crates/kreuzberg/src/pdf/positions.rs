@@ -0,0 +1,92 @@
+//! Positioned text blocks from the PDF text layer.
+//!
+//! Pdfium groups the characters of a page into segments: runs of text that share a
+//! baseline and font. Each non-blank segment becomes a [`TextBlock`] with its bounds in
+//! PDF user space (points, y grows upwards).
+//!
+//! For source maps, the page text is split into lines instead, each carrying the range
+//! of character indices it occupies in the page's text layer.
+
+use super::error::{PdfError, Result};
+use crate::core::config::PdfConfig;
+use crate::types::source_map::{SourceText, SourceUnit};
+use crate::types::{BoundingBox, TextBlock};
+
+/// Extract the text blocks of every page of `pdf_bytes`.
+///
+/// Page numbers are 1-indexed and refer to `pdf_bytes`. Pages whose text layer cannot
+/// be read are skipped.
+pub(crate) fn extract_text_blocks(pdf_bytes: &[u8], pdf_config: Option<&PdfConfig>) -> Result<Vec<TextBlock>> {
+    let pdfium = super::bindings::bind_pdfium(PdfError::TextExtractionFailed, "initialize Pdfium")?;
+
+    let passwords = pdf_config.and_then(|pdf| pdf.passwords.as_deref()).unwrap_or_default();
+    let document = super::password::load_document(&pdfium, pdf_bytes, passwords)?;
+
+    let mut blocks = Vec::new();
+    for (index, page) in document.pages().iter().enumerate() {
+        let Ok(text) = page.text() else {
+            tracing::debug!("Skipping text blocks of page {}: text layer unavailable", index + 1);
+            continue;
+        };
+
+        for segment in text.segments().iter() {
+            let segment_text = segment.text();
+            let trimmed = segment_text.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            let bounds = segment.bounds();
+            blocks.push(TextBlock {
+                text: trimmed.to_string(),
+                page: index + 1,
+                bbox: BoundingBox {
+                    x0: f64::from(bounds.left().value),
+                    y0: f64::from(bounds.bottom().value),
+                    x1: f64::from(bounds.right().value),
+                    y1: f64::from(bounds.top().value),
+                },
+            });
+        }
+    }
+
+    Ok(blocks)
+}
+
+/// Extract the lines of every page of `pdf_bytes` with their character indices.
+///
+/// Page numbers are 1-indexed and refer to `pdf_bytes`. Indices count the characters of
+/// the page text as Pdfium returns it and exclude surrounding whitespace. Pages whose
+/// text layer cannot be read are skipped.
+pub(crate) fn extract_source_lines(pdf_bytes: &[u8], pdf_config: Option<&PdfConfig>) -> Result<Vec<SourceText>> {
+    let pdfium = super::bindings::bind_pdfium(PdfError::TextExtractionFailed, "initialize Pdfium")?;
+
+    let passwords = pdf_config.and_then(|pdf| pdf.passwords.as_deref()).unwrap_or_default();
+    let document = super::password::load_document(&pdfium, pdf_bytes, passwords)?;
+
+    let mut lines = Vec::new();
+    for (index, page) in document.pages().iter().enumerate() {
+        let Ok(text) = page.text() else {
+            tracing::debug!("Skipping source lines of page {}: text layer unavailable", index + 1);
+            continue;
+        };
+
+        let mut char_index = 0;
+        for line in text.all().split_inclusive('\n') {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() {
+                let start = char_index + line.chars().take_while(|c| c.is_whitespace()).count();
+                lines.push(SourceText {
+                    text: trimmed.to_string(),
+                    page: Some(index + 1),
+                    source_start: start,
+                    source_end: start + trimmed.chars().count(),
+                    unit: SourceUnit::PageChar,
+                });
+            }
+            char_index += line.chars().count();
+        }
+    }
+
+    Ok(lines)
+}
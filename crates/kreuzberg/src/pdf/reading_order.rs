@@ -0,0 +1,289 @@
+//! Column-aware reading order for PDF page text.
+//!
+//! Pdfium reports page text in content stream order, which interleaves the columns of
+//! many multi-column layouts. With [`ReadingOrder::ColumnAware`] the page text is rebuilt
+//! from positioned text segments instead: vertical gutters that no segment crosses split
+//! the page into columns, and each column is read top-to-bottom before moving right.
+//! Segments that straddle a gutter (titles, abstracts, full-width captions) are emitted
+//! in place and separate the page into bands that are ordered independently.
+
+use crate::core::config::ReadingOrder;
+use pdfium_render::prelude::*;
+
+/// Minimum width of a column gutter, as a fraction of the page width.
+const MIN_GUTTER_RATIO: f32 = 0.02;
+
+/// Minimum width of a column gutter in points.
+const MIN_GUTTER_POINTS: f32 = 6.0;
+
+/// Fraction of segments allowed to cross a gutter before it stops counting as one
+/// (at least one segment is always allowed).
+///
+/// Keeps a few spanning headings from hiding the gutter of the page below them.
+const GUTTER_CROSSING_TOLERANCE: f32 = 0.05;
+
+/// A positioned run of text in PDF user space (y grows upwards).
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TextSegment {
+    pub text: String,
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl TextSegment {
+    fn center_x(&self) -> f32 {
+        (self.left + self.right) / 2.0
+    }
+
+    fn center_y(&self) -> f32 {
+        (self.top + self.bottom) / 2.0
+    }
+
+    fn height(&self) -> f32 {
+        (self.top - self.bottom).abs()
+    }
+}
+
+/// Text of a page in the requested reading order.
+pub(crate) fn page_text(page: &PdfPage<'_>, text: &PdfPageText<'_>, order: ReadingOrder) -> String {
+    match order {
+        ReadingOrder::Raw => text.all(),
+        ReadingOrder::ColumnAware => {
+            let segments = text
+                .segments()
+                .iter()
+                .map(|segment| {
+                    let bounds = segment.bounds();
+                    TextSegment {
+                        text: segment.text(),
+                        left: bounds.left().value,
+                        right: bounds.right().value,
+                        top: bounds.top().value,
+                        bottom: bounds.bottom().value,
+                    }
+                })
+                .collect();
+            order_segments(segments, page.width().value)
+        }
+    }
+}
+
+/// Join segments column by column, top-to-bottom within each column.
+pub(crate) fn order_segments(segments: Vec<TextSegment>, page_width: f32) -> String {
+    let mut segments: Vec<TextSegment> = segments
+        .into_iter()
+        .filter(|segment| !segment.text.trim().is_empty())
+        .collect();
+    if segments.is_empty() {
+        return String::new();
+    }
+
+    let gutters = find_gutters(&segments, page_width);
+
+    segments.sort_by(|a, b| b.top.total_cmp(&a.top).then(a.left.total_cmp(&b.left)));
+
+    let mut lines = Vec::new();
+    let mut band: Vec<Vec<TextSegment>> = vec![Vec::new(); gutters.len() + 1];
+    for segment in segments {
+        let spans_gutter = gutters
+            .iter()
+            .any(|&gutter| segment.left < gutter && segment.right > gutter);
+        if spans_gutter {
+            flush_band(&mut band, &mut lines);
+            lines.push(segment.text.trim().to_string());
+        } else {
+            let column = gutters.iter().filter(|&&gutter| gutter < segment.center_x()).count();
+            band[column].push(segment);
+        }
+    }
+    flush_band(&mut band, &mut lines);
+
+    lines.join("\n")
+}
+
+/// Find x-positions of column gutters: interior vertical strips that (almost) no segment covers.
+fn find_gutters(segments: &[TextSegment], page_width: f32) -> Vec<f32> {
+    // The histogram spans the text only, one bin per point, so pages whose text sits far
+    // from the origin (or left of it) do not allocate bins for the empty space.
+    let text_left = segments.iter().map(|s| s.left).fold(f32::INFINITY, f32::min);
+    let text_right = segments.iter().map(|s| s.right).fold(f32::NEG_INFINITY, f32::max);
+    if !text_left.is_finite() || !text_right.is_finite() || text_right <= text_left || page_width <= 0.0 {
+        return Vec::new();
+    }
+
+    let bins = (text_right - text_left).ceil() as usize + 1;
+    let mut coverage = vec![0usize; bins];
+    for segment in segments {
+        let start = (segment.left - text_left).max(0.0).floor() as usize;
+        let end = ((segment.right - text_left).max(0.0).ceil() as usize).min(bins);
+        for bin in coverage.iter_mut().take(end).skip(start) {
+            *bin += 1;
+        }
+    }
+
+    let tolerance = ((segments.len() as f32 * GUTTER_CROSSING_TOLERANCE) as usize).max(1);
+    let min_width = (page_width * MIN_GUTTER_RATIO).max(MIN_GUTTER_POINTS);
+
+    // Only gaps with well-covered text on both sides count; margins and indents do not.
+    let (Some(first_bin), Some(last_bin)) = (
+        coverage.iter().position(|&count| count > tolerance),
+        coverage.iter().rposition(|&count| count > tolerance),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut gutters = Vec::new();
+    let mut run_start = None;
+    for (bin, &count) in coverage.iter().enumerate().take(last_bin + 1).skip(first_bin) {
+        match (count <= tolerance, run_start) {
+            (true, None) => run_start = Some(bin),
+            (false, Some(start)) => {
+                if (bin - start) as f32 >= min_width {
+                    gutters.push(text_left + (start + bin) as f32 / 2.0);
+                }
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    gutters
+}
+
+/// Emit the buffered columns of a band in reading order and reset them.
+fn flush_band(band: &mut [Vec<TextSegment>], lines: &mut Vec<String>) {
+    for column in band.iter_mut() {
+        lines.extend(merge_lines(std::mem::take(column)));
+    }
+}
+
+/// Merge segments that sit on the same baseline into single lines.
+///
+/// Expects segments sorted top-to-bottom.
+fn merge_lines(segments: Vec<TextSegment>) -> Vec<String> {
+    let mut rows: Vec<Vec<TextSegment>> = Vec::new();
+    for segment in segments {
+        match rows.last_mut() {
+            Some(row)
+                if (row[0].center_y() - segment.center_y()).abs() < row[0].height().max(segment.height()) / 2.0 =>
+            {
+                row.push(segment)
+            }
+            _ => rows.push(vec![segment]),
+        }
+    }
+
+    rows.into_iter()
+        .map(|mut row| {
+            row.sort_by(|a, b| a.left.total_cmp(&b.left));
+            row.iter()
+                .map(|segment| segment.text.trim())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, left: f32, right: f32, top: f32) -> TextSegment {
+        TextSegment {
+            text: text.to_string(),
+            left,
+            right,
+            top,
+            bottom: top - 10.0,
+        }
+    }
+
+    #[test]
+    fn test_two_columns_read_left_then_right() {
+        // Content stream order interleaves the columns line by line.
+        let segments = vec![
+            segment("L1", 50.0, 280.0, 700.0),
+            segment("R1", 320.0, 550.0, 700.0),
+            segment("L2", 50.0, 280.0, 685.0),
+            segment("R2", 320.0, 550.0, 685.0),
+            segment("L3", 50.0, 280.0, 670.0),
+            segment("R3", 320.0, 550.0, 670.0),
+        ];
+
+        assert_eq!(order_segments(segments, 612.0), "L1\nL2\nL3\nR1\nR2\nR3");
+    }
+
+    #[test]
+    fn test_columns_offset_from_origin() {
+        // Pages whose media box does not start at the origin place text at negative or
+        // large coordinates; the gutters are found all the same.
+        for offset in [-306.0, 20_000.0] {
+            let segments = vec![
+                segment("L1", offset + 50.0, offset + 280.0, 700.0),
+                segment("R1", offset + 320.0, offset + 550.0, 700.0),
+                segment("L2", offset + 50.0, offset + 280.0, 685.0),
+                segment("R2", offset + 320.0, offset + 550.0, 685.0),
+            ];
+
+            assert_eq!(order_segments(segments, 612.0), "L1\nL2\nR1\nR2");
+        }
+    }
+
+    #[test]
+    fn test_spanning_title_precedes_columns() {
+        let segments = vec![
+            segment("L1", 50.0, 280.0, 700.0),
+            segment("Title", 150.0, 450.0, 750.0),
+            segment("R1", 320.0, 550.0, 700.0),
+            segment("L2", 50.0, 280.0, 685.0),
+            segment("R2", 320.0, 550.0, 685.0),
+        ];
+
+        assert_eq!(order_segments(segments, 612.0), "Title\nL1\nL2\nR1\nR2");
+    }
+
+    #[test]
+    fn test_spanning_block_separates_bands() {
+        let segments = vec![
+            segment("A1", 50.0, 280.0, 700.0),
+            segment("B1", 320.0, 550.0, 700.0),
+            segment("Figure 1: spanning caption", 50.0, 550.0, 600.0),
+            segment("A2", 50.0, 280.0, 500.0),
+            segment("B2", 320.0, 550.0, 500.0),
+        ];
+
+        assert_eq!(
+            order_segments(segments, 612.0),
+            "A1\nB1\nFigure 1: spanning caption\nA2\nB2"
+        );
+    }
+
+    #[test]
+    fn test_single_column_reads_top_to_bottom() {
+        let segments = vec![
+            segment("second", 50.0, 550.0, 680.0),
+            segment("first", 50.0, 550.0, 700.0),
+        ];
+
+        assert_eq!(order_segments(segments, 612.0), "first\nsecond");
+    }
+
+    #[test]
+    fn test_segments_on_same_line_are_joined() {
+        let segments = vec![
+            segment("world", 100.0, 140.0, 700.0),
+            segment("Hello", 50.0, 95.0, 700.0),
+            segment("next", 50.0, 90.0, 685.0),
+        ];
+
+        assert_eq!(order_segments(segments, 612.0), "Hello world\nnext");
+    }
+
+    #[test]
+    fn test_empty_segments() {
+        assert_eq!(order_segments(Vec::new(), 612.0), "");
+        assert_eq!(order_segments(vec![segment("  ", 0.0, 10.0, 10.0)], 612.0), "");
+    }
+}
@@ -103,6 +103,38 @@ impl PdfRenderer<'_> {
         Ok(DynamicImage::ImageRgb8(image))
     }
 
+    /// Render a page at a fixed pixel width, scaling its height to keep the aspect ratio.
+    ///
+    /// Pages that would come out taller than `max_height` are scaled down to fit it
+    /// instead, which makes them narrower than `width`. The document is opened with the
+    /// empty user password before each of `passwords`.
+    pub fn render_page_to_width(
+        &self,
+        pdf_bytes: &[u8],
+        page_index: usize,
+        width: u32,
+        max_height: u32,
+        passwords: &[String],
+    ) -> Result<DynamicImage> {
+        let document = super::password::load_document(&self.pdfium, pdf_bytes, passwords)?;
+
+        let page = document
+            .pages()
+            .get(page_index as i32)
+            .map_err(|_| PdfError::PageNotFound(page_index))?;
+
+        let config = PdfRenderConfig::new()
+            .set_target_width(width.clamp(1, i32::MAX as u32) as i32)
+            .set_maximum_height(max_height.clamp(1, i32::MAX as u32) as i32)
+            .rotate_if_landscape(PdfPageRenderRotation::None, false);
+
+        let bitmap = page
+            .render_with_config(&config)
+            .map_err(|e| PdfError::RenderingFailed(format!("Failed to render page: {}", e)))?;
+
+        Ok(DynamicImage::ImageRgb8(bitmap.as_image().into_rgb8()))
+    }
+
     pub fn render_all_pages(&self, pdf_bytes: &[u8], options: &PageRenderOptions) -> Result<Vec<DynamicImage>> {
         self.render_all_pages_with_password(pdf_bytes, options, None)
     }
@@ -4,7 +4,8 @@
 
 use super::bindings::{PdfiumHandle, bind_pdfium};
 use super::error::{PdfError, Result};
-use crate::core::config::PageConfig;
+use super::reading_order::page_text;
+use crate::core::config::{PageConfig, PdfConfig, ReadingOrder};
 use crate::pdf::metadata::PdfExtractionMetadata;
 use crate::types::{PageBoundary, PageContent};
 use pdfium_render::prelude::*;
@@ -176,13 +177,16 @@ pub fn extract_text_from_pdf_document(
     page_config: Option<&PageConfig>,
     extraction_config: Option<&crate::core::config::ExtractionConfig>,
 ) -> Result<PdfTextExtractionResult> {
+    let pdf_config = extraction_config.and_then(|cfg| cfg.pdf_options.as_ref());
+    let reading_order = pdf_config.map(|pdf_cfg| pdf_cfg.reading_order).unwrap_or_default();
+
     if page_config.is_none() {
-        return extract_text_lazy_fast_path(document);
+        return extract_text_lazy_fast_path(document, pdf_config, reading_order);
     }
 
     let config = page_config.unwrap();
 
-    extract_text_lazy_with_tracking(document, config, extraction_config)
+    extract_text_lazy_with_tracking(document, config, extraction_config, reading_order)
 }
 
 /// Strip `/Rotate` entries from PDF bytes to work around a pdfium bug where
@@ -261,6 +265,24 @@ fn find_rotate_offset(bytes: &[u8], start: usize) -> Option<usize> {
         .map(|p| start + p)
 }
 
+/// Number of the document's first page in the original PDF.
+///
+/// With a page range the document only holds the selected pages; markers and
+/// separators keep the original page numbers.
+pub(crate) fn first_page_number(pdf_config: Option<&PdfConfig>) -> usize {
+    pdf_config
+        .and_then(|pdf_cfg| pdf_cfg.page_range)
+        .map_or(1, |(start, _)| start)
+}
+
+/// Separator inserted before page `page_number`.
+pub(crate) fn page_separator(pdf_config: Option<&PdfConfig>, page_number: usize) -> Cow<'_, str> {
+    pdf_config.map_or(
+        Cow::Borrowed(crate::core::config::pdf::DEFAULT_PAGE_SEPARATOR),
+        |pdf_cfg| pdf_cfg.separator_before_page(page_number),
+    )
+}
+
 /// Fast path for text extraction without page tracking.
 ///
 /// Processes pages one-by-one lazily, building content incrementally with
@@ -274,22 +296,33 @@ fn find_rotate_offset(bytes: &[u8], start: usize) -> Option<usize> {
 /// and extrapolating for the full document. This reduces String reallocation
 /// calls from O(n) to O(log n) while maintaining low peak memory usage.
 /// For large documents, this can reduce allocation overhead by 40-50%.
-fn extract_text_lazy_fast_path(document: &PdfDocument<'_>) -> Result<PdfTextExtractionResult> {
+fn extract_text_lazy_fast_path(
+    document: &PdfDocument<'_>,
+    pdf_config: Option<&PdfConfig>,
+    reading_order: ReadingOrder,
+) -> Result<PdfTextExtractionResult> {
     let page_count = document.pages().len() as usize;
     let mut content = String::new();
+    let first_page_number = first_page_number(pdf_config);
+
     let mut total_sample_size = 0usize;
     let mut sample_count = 0;
 
     for (page_idx, page) in document.pages().iter().enumerate() {
+        crate::core::progress::emit(crate::core::progress::ProgressEvent::PageStarted {
+            page: page_idx + 1,
+            total: page_count,
+        });
+
         let text = page
             .text()
             .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
 
-        let page_text = text.all();
+        let page_text = page_text(&page, &text, reading_order);
         let page_size = page_text.len();
 
         if page_idx > 0 {
-            content.push_str("\n\n");
+            content.push_str(&page_separator(pdf_config, page_idx + first_page_number));
         }
 
         content.push_str(&page_text);
@@ -328,6 +361,7 @@ fn extract_text_lazy_with_tracking(
     document: &PdfDocument<'_>,
     config: &PageConfig,
     extraction_config: Option<&crate::core::config::ExtractionConfig>,
+    reading_order: ReadingOrder,
 ) -> Result<PdfTextExtractionResult> {
     let mut content = String::new();
     let page_count = document.pages().len() as usize;
@@ -338,6 +372,9 @@ fn extract_text_lazy_with_tracking(
         None
     };
 
+    let pdf_config = extraction_config.and_then(|cfg| cfg.pdf_options.as_ref());
+    let first_page_number = first_page_number(pdf_config);
+
     // Check if hierarchy extraction is enabled
     let should_extract_hierarchy = extraction_config
         .and_then(|cfg| cfg.pdf_options.as_ref())
@@ -355,12 +392,16 @@ fn extract_text_lazy_with_tracking(
 
     for (page_idx, page) in document.pages().iter().enumerate() {
         let page_number = page_idx + 1;
+        crate::core::progress::emit(crate::core::progress::ProgressEvent::PageStarted {
+            page: page_number,
+            total: page_count,
+        });
 
         let text = page
             .text()
             .map_err(|e| PdfError::TextExtractionFailed(format!("Page text extraction failed: {}", e)))?;
 
-        let page_text_ref = text.all();
+        let page_text_ref = page_text(&page, &text, reading_order);
         let page_size = page_text_ref.len();
 
         if page_idx < 5 {
@@ -370,11 +411,13 @@ fn extract_text_lazy_with_tracking(
 
         // Insert page marker before the page content (for ALL pages including page 1)
         if config.insert_page_markers {
-            let marker = config.marker_format.replace("{page_num}", &page_number.to_string());
+            let marker = config
+                .marker_format
+                .replace("{page_num}", &(page_idx + first_page_number).to_string());
             content.push_str(&marker);
         } else if page_idx > 0 {
             // Only add separator between pages when markers are disabled
-            content.push_str("\n\n");
+            content.push_str(&page_separator(pdf_config, page_idx + first_page_number));
         }
 
         let byte_start = content.len();
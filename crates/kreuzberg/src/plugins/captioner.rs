@@ -0,0 +1,122 @@
+//! Image captioner plugin trait.
+//!
+//! This module defines the trait for attaching captions to extracted images.
+
+use crate::Result;
+use crate::plugins::Plugin;
+use crate::types::ExtractedImage;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Trait for image captioner plugins.
+///
+/// Implement this trait to caption extracted images, for example with a vision model
+/// for retrieval over figures. When a captioner is registered, the extraction pipeline
+/// calls it for every image in `ExtractionResult.images` that has no caption yet and
+/// stores the returned text in `ExtractedImage.caption`. Images are only present when
+/// image extraction is enabled in the config.
+///
+/// If several captioners are registered, the most recently registered one is used.
+///
+/// # Errors
+///
+/// I/O, lock and plugin errors abort the extraction. Any other error leaves the image
+/// without a caption and is recorded in `metadata.additional["image_caption_errors"]`.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::{ImageCaptioner, Plugin};
+/// use kreuzberg::types::ExtractedImage;
+/// use kreuzberg::Result;
+/// use async_trait::async_trait;
+///
+/// struct FormatCaptioner;
+///
+/// impl Plugin for FormatCaptioner {
+///     fn name(&self) -> &str { "format-captioner" }
+///     fn version(&self) -> String { "1.0.0".to_string() }
+///     fn initialize(&self) -> Result<()> { Ok(()) }
+///     fn shutdown(&self) -> Result<()> { Ok(()) }
+/// }
+///
+/// #[async_trait]
+/// impl ImageCaptioner for FormatCaptioner {
+///     async fn caption(&self, image: &ExtractedImage) -> Result<String> {
+///         Ok(format!("{} image, {} bytes", image.format, image.data.len()))
+///     }
+/// }
+/// ```
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait ImageCaptioner: Plugin {
+    /// Generate a caption for an extracted image.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The extracted image, including its encoded bytes and format
+    ///
+    /// # Returns
+    ///
+    /// The caption text.
+    async fn caption(&self, image: &ExtractedImage) -> Result<String>;
+}
+
+/// Register an image captioner with the global registry.
+///
+/// # Errors
+///
+/// - `KreuzbergError::Validation` - Invalid captioner name (empty or contains whitespace)
+/// - Any error from the captioner's `initialize()` method
+pub fn register_image_captioner(captioner: Arc<dyn ImageCaptioner>) -> crate::Result<()> {
+    use crate::plugins::registry::get_image_captioner_registry;
+
+    let registry = get_image_captioner_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let mut registry = registry
+        .write()
+        .expect("Image captioner registry lock poisoned - critical runtime error");
+
+    registry.register(captioner)
+}
+
+/// Unregister an image captioner by name.
+///
+/// Calls `shutdown()` on the captioner. Unknown names are ignored.
+pub fn unregister_image_captioner(name: &str) -> crate::Result<()> {
+    use crate::plugins::registry::get_image_captioner_registry;
+
+    let registry = get_image_captioner_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let mut registry = registry
+        .write()
+        .expect("Image captioner registry lock poisoned - critical runtime error");
+
+    registry.remove(name)
+}
+
+/// List the names of all registered image captioners, in registration order.
+pub fn list_image_captioners() -> crate::Result<Vec<String>> {
+    use crate::plugins::registry::get_image_captioner_registry;
+
+    let registry = get_image_captioner_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let registry = registry
+        .read()
+        .expect("Image captioner registry lock poisoned - critical runtime error");
+
+    Ok(registry.list())
+}
+
+/// Shut down and remove all registered image captioners.
+pub fn clear_image_captioners() -> crate::Result<()> {
+    use crate::plugins::registry::get_image_captioner_registry;
+
+    let registry = get_image_captioner_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let mut registry = registry
+        .write()
+        .expect("Image captioner registry lock poisoned - critical runtime error");
+
+    registry.shutdown_all()
+}
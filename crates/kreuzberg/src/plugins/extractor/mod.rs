@@ -9,7 +9,10 @@ mod r#trait;
 pub use r#trait::DocumentExtractor;
 
 // Re-export registry functions for backward compatibility
-pub use registry::{clear_extractors, list_extractors, register_extractor, unregister_extractor};
+pub use registry::{
+    ExtractorInfo, clear_extractors, list_document_extractors, list_extractors, register_extractor,
+    unregister_extractor,
+};
 
 #[cfg(test)]
 mod tests {
@@ -65,6 +68,11 @@ mod tests {
                 elements: None,
                 ocr_elements: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             })
         }
 
@@ -242,6 +250,11 @@ mod tests {
                     elements: None,
                     ocr_elements: None,
                     document: None,
+                    outline: None,
+                    formulas: None,
+                    text_blocks: None,
+                    source_map: None,
+                    references: None,
                 })
             }
 
@@ -61,6 +61,10 @@ use std::sync::Arc;
 ///             elements: None,
 ///             ocr_elements: None,
 ///             document: None,
+///             outline: None,
+///             formulas: None,
+///             text_blocks: None,
+///             source_map: None,
 ///         })
 ///     }
 ///
@@ -152,6 +156,74 @@ pub fn list_extractors() -> crate::Result<Vec<String>> {
     Ok(registry.list())
 }
 
+/// Description of a registered document extractor and the formats it handles.
+///
+/// Returned by [`list_document_extractors`] to allow building format-support
+/// matrices programmatically.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ExtractorInfo {
+    /// Extractor name as reported by `Plugin::name()`.
+    pub name: String,
+    /// MIME types the extractor is registered for (sorted, may include wildcards like `image/*`).
+    pub mime_types: Vec<String>,
+    /// File extensions associated with the extractor's MIME types (sorted, without leading dot).
+    pub extensions: Vec<String>,
+}
+
+/// List all registered document extractors with their supported formats.
+///
+/// Built-in extractors are registered first if needed, so the result includes both
+/// built-in and plugin-registered extractors. Entries are sorted by extractor name.
+///
+/// # Returns
+///
+/// A vector of [`ExtractorInfo`] describing each extractor.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::list_document_extractors;
+///
+/// # tokio_test::block_on(async {
+/// for info in list_document_extractors()? {
+///     println!("{}: {:?} ({:?})", info.name, info.mime_types, info.extensions);
+/// }
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// # });
+/// ```
+pub fn list_document_extractors() -> crate::Result<Vec<ExtractorInfo>> {
+    use crate::core::mime::get_extensions_for_mime;
+    use crate::plugins::registry::get_document_extractor_registry;
+
+    crate::extractors::ensure_initialized()?;
+
+    let registry = get_document_extractor_registry();
+    let registry = registry
+        .read()
+        .expect("~keep Failed to acquire read lock on extractor registry"); // ~keep
+
+    let infos = registry
+        .list_with_mime_types()
+        .into_iter()
+        .map(|(name, mime_types)| {
+            let mut extensions: Vec<String> = mime_types
+                .iter()
+                .flat_map(|mime| get_extensions_for_mime(mime).unwrap_or_default())
+                .collect();
+            extensions.sort();
+            extensions.dedup();
+
+            ExtractorInfo {
+                name,
+                mime_types,
+                extensions,
+            }
+        })
+        .collect();
+
+    Ok(infos)
+}
+
 /// Clear all extractors from the global registry.
 ///
 /// Removes all extractors and calls their `shutdown()` methods.
@@ -237,6 +309,11 @@ mod tests {
                 elements: None,
                 ocr_elements: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             })
         }
 
@@ -315,6 +392,36 @@ mod tests {
         super::unregister_extractor("mock-extractor").unwrap();
     }
 
+    #[test]
+    #[serial]
+    fn test_list_document_extractors_includes_formats() {
+        use std::sync::Arc;
+
+        crate::extractors::ensure_initialized().unwrap();
+        let extractor = Arc::new(MockExtractor {
+            mime_types: vec!["text/test-info", "application/pdf"],
+            priority: 50,
+        });
+        super::register_extractor(extractor).unwrap();
+
+        let infos = super::list_document_extractors().unwrap();
+        let info = infos
+            .iter()
+            .find(|info| info.name == "mock-extractor")
+            .expect("mock extractor should be listed");
+        assert_eq!(info.mime_types, vec!["application/pdf", "text/test-info"]);
+        assert!(info.extensions.contains(&"pdf".to_string()));
+
+        assert!(infos.iter().any(|info| info.name == "plain-text-extractor"));
+
+        let names: Vec<&str> = infos.iter().map(|info| info.name.as_str()).collect();
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+
+        super::unregister_extractor("mock-extractor").unwrap();
+    }
+
     #[test]
     #[serial]
     fn test_clear_extractors() {
@@ -378,6 +485,11 @@ mod tests {
                     elements: None,
                     ocr_elements: None,
                     document: None,
+                    outline: None,
+                    formulas: None,
+                    text_blocks: None,
+                    source_map: None,
+                    references: None,
                 })
             }
 
@@ -428,6 +540,11 @@ mod tests {
                     elements: None,
                     ocr_elements: None,
                     document: None,
+                    outline: None,
+                    formulas: None,
+                    text_blocks: None,
+                    source_map: None,
+                    references: None,
                 })
             }
 
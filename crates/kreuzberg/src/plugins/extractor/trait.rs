@@ -68,6 +68,10 @@ use crate::KreuzbergError;
 ///             elements: None,
 ///             ocr_elements: None,
 ///             document: None,
+///             outline: None,
+///             formulas: None,
+///             text_blocks: None,
+///             source_map: None,
 ///         })
 ///     }
 ///
@@ -151,6 +155,10 @@ pub trait DocumentExtractor: Plugin {
     ///         elements: None,
     ///         ocr_elements: None,
     ///         document: None,
+    ///         outline: None,
+    ///         formulas: None,
+    ///         text_blocks: None,
+    ///         source_map: None,
     ///     })
     /// }
     /// # }
@@ -226,6 +234,10 @@ pub trait DocumentExtractor: Plugin {
     ///         elements: None,
     ///         ocr_elements: None,
     ///         document: None,
+    ///         outline: None,
+    ///         formulas: None,
+    ///         text_blocks: None,
+    ///         source_map: None,
     ///     })
     /// }
     /// # }
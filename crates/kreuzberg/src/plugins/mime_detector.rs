@@ -0,0 +1,119 @@
+//! MIME detector plugin trait.
+//!
+//! This module defines the trait for recognizing formats the built-in MIME detection
+//! does not know about.
+
+use crate::plugins::Plugin;
+use std::sync::Arc;
+
+/// Trait for MIME detector plugins.
+///
+/// Implement this trait to teach Kreuzberg to recognize proprietary or internal
+/// formats, typically by their magic number. Registered detectors are consulted before
+/// the built-in detection by [`detect_mime_type`](crate::core::mime::detect_mime_type),
+/// [`detect_mime_type_from_bytes`](crate::core::mime::detect_mime_type_from_bytes) and
+/// [`detect_mime_type_from_reader`](crate::core::mime::detect_mime_type_from_reader),
+/// so a file is recognized by its content even when its extension says otherwise.
+///
+/// Detectors are asked in reverse registration order, and the first type returned
+/// wins. To extract the detected format, register a
+/// [`DocumentExtractor`](crate::plugins::DocumentExtractor) for the returned MIME type;
+/// types handled by a registered extractor pass
+/// [`validate_mime_type`](crate::core::mime::validate_mime_type).
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::{MimeDetector, Plugin};
+/// use kreuzberg::Result;
+///
+/// struct LedgerDetector;
+///
+/// impl Plugin for LedgerDetector {
+///     fn name(&self) -> &str { "ledger-detector" }
+///     fn version(&self) -> String { "1.0.0".to_string() }
+///     fn initialize(&self) -> Result<()> { Ok(()) }
+///     fn shutdown(&self) -> Result<()> { Ok(()) }
+/// }
+///
+/// impl MimeDetector for LedgerDetector {
+///     fn detect(&self, header: &[u8]) -> Option<String> {
+///         header
+///             .starts_with(b"LDGR\x01")
+///             .then(|| "application/x-ledger".to_string())
+///     }
+/// }
+/// ```
+pub trait MimeDetector: Plugin {
+    /// Detect the MIME type of a document from its first bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - The start of the document, at most
+    ///   [`MIME_SNIFF_BYTES`](crate::core::mime::MIME_SNIFF_BYTES) long for files and
+    ///   streams, the whole content for byte slices
+    ///
+    /// # Returns
+    ///
+    /// The MIME type, or `None` to leave the document to the other detectors.
+    fn detect(&self, header: &[u8]) -> Option<String>;
+}
+
+/// Register a MIME detector with the global registry.
+///
+/// # Errors
+///
+/// - `KreuzbergError::Validation` - Invalid detector name (empty or contains whitespace)
+/// - Any error from the detector's `initialize()` method
+pub fn register_mime_detector(detector: Arc<dyn MimeDetector>) -> crate::Result<()> {
+    use crate::plugins::registry::get_mime_detector_registry;
+
+    let registry = get_mime_detector_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let mut registry = registry
+        .write()
+        .expect("MIME detector registry lock poisoned - critical runtime error");
+
+    registry.register(detector)
+}
+
+/// Unregister a MIME detector by name.
+///
+/// Calls `shutdown()` on the detector. Unknown names are ignored.
+pub fn unregister_mime_detector(name: &str) -> crate::Result<()> {
+    use crate::plugins::registry::get_mime_detector_registry;
+
+    let registry = get_mime_detector_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let mut registry = registry
+        .write()
+        .expect("MIME detector registry lock poisoned - critical runtime error");
+
+    registry.remove(name)
+}
+
+/// List the names of all registered MIME detectors, in registration order.
+pub fn list_mime_detectors() -> crate::Result<Vec<String>> {
+    use crate::plugins::registry::get_mime_detector_registry;
+
+    let registry = get_mime_detector_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let registry = registry
+        .read()
+        .expect("MIME detector registry lock poisoned - critical runtime error");
+
+    Ok(registry.list())
+}
+
+/// Shut down and remove all registered MIME detectors.
+pub fn clear_mime_detectors() -> crate::Result<()> {
+    use crate::plugins::registry::get_mime_detector_registry;
+
+    let registry = get_mime_detector_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let mut registry = registry
+        .write()
+        .expect("MIME detector registry lock poisoned - critical runtime error");
+
+    registry.shutdown_all()
+}
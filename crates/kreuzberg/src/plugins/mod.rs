@@ -10,6 +10,8 @@
 //! - [`DocumentExtractor`] - Document format extraction plugins
 //! - [`PostProcessor`] - Content post-processing plugins
 //! - [`Validator`] - Validation plugins
+//! - [`ImageCaptioner`] - Image captioning plugins
+//! - [`MimeDetector`] - MIME type detection plugins
 //!
 //! # Language Support
 //!
@@ -52,6 +54,10 @@
 //! #             elements: None,
 //! #             ocr_elements: None,
 //! #             document: None,
+//! #             outline: None,
+//! #             formulas: None,
+//! #             text_blocks: None,
+//! #             source_map: None,
 //! #         })
 //! #     }
 //! #     async fn extract_file(&self, _: &std::path::Path, _: &str, _: &kreuzberg::ExtractionConfig)
@@ -69,6 +75,10 @@
 //! #             elements: None,
 //! #             ocr_elements: None,
 //! #             document: None,
+//! #             outline: None,
+//! #             formulas: None,
+//! #             text_blocks: None,
+//! #             source_map: None,
 //! #         })
 //! #     }
 //! #     fn supported_mime_types(&self) -> &[&str] { &[] }
@@ -135,6 +145,10 @@
 //!             elements: None,
 //!             ocr_elements: None,
 //!             document: None,
+//!             outline: None,
+//!             formulas: None,
+//!             text_blocks: None,
+//!             source_map: None,
 //!         })
 //!     }
 //!
@@ -208,17 +222,31 @@
 //! }
 //! ```
 
+mod captioner;
 mod extractor;
+mod mime_detector;
 mod ocr;
 mod processor;
 pub mod registry;
 pub mod startup_validation;
+#[cfg(feature = "test-utils")]
+pub mod testing;
 mod traits;
 mod validator;
 
-pub use extractor::{DocumentExtractor, clear_extractors, list_extractors, register_extractor, unregister_extractor};
+pub use captioner::{
+    ImageCaptioner, clear_image_captioners, list_image_captioners, register_image_captioner, unregister_image_captioner,
+};
+pub use extractor::{
+    DocumentExtractor, ExtractorInfo, clear_extractors, list_document_extractors, list_extractors, register_extractor,
+    unregister_extractor,
+};
+pub use mime_detector::{
+    MimeDetector, clear_mime_detectors, list_mime_detectors, register_mime_detector, unregister_mime_detector,
+};
 pub use ocr::{
-    OcrBackend, OcrBackendType, clear_ocr_backends, list_ocr_backends, register_ocr_backend, unregister_ocr_backend,
+    OcrBackend, OcrBackendType, clear_ocr_backends, list_ocr_backends, ocr_backend_languages, register_ocr_backend,
+    unregister_ocr_backend,
 };
 pub use processor::{PostProcessor, ProcessingStage, list_post_processors};
 pub use traits::Plugin;
@@ -226,7 +254,9 @@ pub use validator::{Validator, clear_validators, list_validators, register_valid
 
 // Re-export registry items for backward compatibility
 pub use registry::{
-    DOCUMENT_EXTRACTOR_REGISTRY, DocumentExtractorRegistry, OCR_BACKEND_REGISTRY, OcrBackendRegistry,
-    POST_PROCESSOR_REGISTRY, PostProcessorRegistry, VALIDATOR_REGISTRY, ValidatorRegistry,
-    get_document_extractor_registry, get_ocr_backend_registry, get_post_processor_registry, get_validator_registry,
+    DOCUMENT_EXTRACTOR_REGISTRY, DocumentExtractorRegistry, IMAGE_CAPTIONER_REGISTRY, ImageCaptionerRegistry,
+    MIME_DETECTOR_REGISTRY, MimeDetectorRegistry, OCR_BACKEND_REGISTRY, OcrBackendRegistry, POST_PROCESSOR_REGISTRY,
+    PostProcessorRegistry, VALIDATOR_REGISTRY, ValidatorRegistry, get_document_extractor_registry,
+    get_image_captioner_registry, get_mime_detector_registry, get_ocr_backend_registry, get_post_processor_registry,
+    get_validator_registry, reset_all_registries,
 };
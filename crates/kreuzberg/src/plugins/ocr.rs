@@ -73,6 +73,10 @@ pub enum OcrBackendType {
 ///             elements: None,
 ///             ocr_elements: None,
 ///             document: None,
+///             outline: None,
+///             formulas: None,
+///             text_blocks: None,
+///             source_map: None,
 ///         })
 ///     }
 ///
@@ -157,6 +161,10 @@ pub trait OcrBackend: Plugin {
     ///         elements: None,
     ///         ocr_elements: None,
     ///         document: None,
+    ///         outline: None,
+    ///         formulas: None,
+    ///         text_blocks: None,
+    ///         source_map: None,
     ///     })
     /// }
     /// # }
@@ -333,6 +341,10 @@ pub trait OcrBackend: Plugin {
 ///             elements: None,
 ///             ocr_elements: None,
 ///             document: None,
+///             outline: None,
+///             formulas: None,
+///             text_blocks: None,
+///             source_map: None,
 ///         })
 ///     }
 ///     fn supports_language(&self, _: &str) -> bool { true }
@@ -425,6 +437,50 @@ pub fn list_ocr_backends() -> crate::Result<Vec<String>> {
     Ok(registry.list())
 }
 
+/// List the languages supported by a registered OCR backend.
+///
+/// Asks the backend itself, so the answer reflects the local installation: Tesseract
+/// reports the installed traineddata files and PaddleOCR its bundled models.
+///
+/// # Arguments
+///
+/// * `backend` - Name of the OCR backend (e.g. "tesseract", "paddle-ocr")
+///
+/// # Returns
+///
+/// The backend's language codes, sorted and deduplicated.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Plugin` if no backend with that name is registered.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::plugins::ocr_backend_languages;
+///
+/// # tokio_test::block_on(async {
+/// let languages = ocr_backend_languages("tesseract")?;
+/// println!("Tesseract languages: {}", languages.join(", "));
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// # });
+/// ```
+pub fn ocr_backend_languages(backend: &str) -> crate::Result<Vec<String>> {
+    use crate::plugins::registry::get_ocr_backend_registry;
+
+    let registry = get_ocr_backend_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let backend = registry
+        .read()
+        .expect("OCR backend registry lock poisoned - critical runtime error")
+        .get(backend)?;
+
+    let mut languages = backend.supported_languages();
+    languages.sort();
+    languages.dedup();
+    Ok(languages)
+}
+
 /// Clear all OCR backends from the global registry.
 ///
 /// Removes all OCR backends and calls their `shutdown()` methods.
@@ -499,6 +555,11 @@ mod tests {
                 elements: None,
                 ocr_elements: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             })
         }
 
@@ -654,4 +715,24 @@ mod tests {
         let result = backend.process_image(b"", &config).await;
         assert!(result.is_ok());
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_ocr_backend_languages_from_registry() {
+        register_ocr_backend(Arc::new(MockOcrBackend {
+            languages: vec!["eng".to_string(), "deu".to_string(), "eng".to_string()],
+        }))
+        .unwrap();
+
+        let languages = ocr_backend_languages("mock-ocr");
+        unregister_ocr_backend("mock-ocr").unwrap();
+
+        assert_eq!(languages.unwrap(), vec!["deu".to_string(), "eng".to_string()]);
+    }
+
+    #[test]
+    fn test_ocr_backend_languages_unknown_backend() {
+        let err = ocr_backend_languages("no-such-backend").unwrap_err();
+        assert!(matches!(err, crate::KreuzbergError::Plugin { .. }));
+    }
 }
@@ -0,0 +1,140 @@
+//! Image captioner registry implementation.
+
+use crate::Result;
+use crate::plugins::ImageCaptioner;
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+/// Registry for image captioner plugins.
+///
+/// Keeps captioners in registration order; the most recently registered captioner
+/// is the one the extraction pipeline uses.
+pub struct ImageCaptionerRegistry {
+    captioners: IndexMap<String, Arc<dyn ImageCaptioner>>,
+}
+
+impl ImageCaptionerRegistry {
+    /// Create a new empty image captioner registry.
+    pub fn new() -> Self {
+        Self {
+            captioners: IndexMap::new(),
+        }
+    }
+
+    /// Register an image captioner.
+    ///
+    /// Registering a captioner under an existing name replaces the previous one and
+    /// makes it the active captioner.
+    ///
+    /// # Arguments
+    ///
+    /// * `captioner` - The captioner to register
+    pub fn register(&mut self, captioner: Arc<dyn ImageCaptioner>) -> Result<()> {
+        let name = captioner.name().to_string();
+
+        super::validate_plugin_name(&name)?;
+        captioner.initialize()?;
+
+        self.captioners.shift_remove(&name);
+        self.captioners.insert(name.clone(), captioner);
+        tracing::debug!("Registered image captioner '{}'", name);
+
+        Ok(())
+    }
+
+    /// The captioner used by the extraction pipeline, if any is registered.
+    pub fn active(&self) -> Option<Arc<dyn ImageCaptioner>> {
+        self.captioners.values().last().cloned()
+    }
+
+    /// List all registered captioner names in registration order.
+    pub fn list(&self) -> Vec<String> {
+        self.captioners.keys().cloned().collect()
+    }
+
+    /// Remove a captioner from the registry.
+    ///
+    /// Calls `shutdown()` on the captioner before removing.
+    pub fn remove(&mut self, name: &str) -> Result<()> {
+        if let Some(captioner) = self.captioners.shift_remove(name) {
+            captioner.shutdown()?;
+        }
+        Ok(())
+    }
+
+    /// Shutdown all captioners and clear the registry.
+    pub fn shutdown_all(&mut self) -> Result<()> {
+        for name in self.list() {
+            self.remove(&name)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for ImageCaptionerRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::Plugin;
+    use crate::types::ExtractedImage;
+    use async_trait::async_trait;
+
+    struct NamedCaptioner(&'static str);
+
+    impl Plugin for NamedCaptioner {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ImageCaptioner for NamedCaptioner {
+        async fn caption(&self, _image: &ExtractedImage) -> Result<String> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_most_recent_captioner_is_active() {
+        let mut registry = ImageCaptionerRegistry::new();
+        assert!(registry.active().is_none());
+
+        registry.register(Arc::new(NamedCaptioner("first"))).unwrap();
+        registry.register(Arc::new(NamedCaptioner("second"))).unwrap();
+        assert_eq!(registry.active().unwrap().name(), "second");
+
+        registry.register(Arc::new(NamedCaptioner("first"))).unwrap();
+        assert_eq!(registry.list(), vec!["second", "first"]);
+        assert_eq!(registry.active().unwrap().name(), "first");
+
+        registry.remove("first").unwrap();
+        assert_eq!(registry.active().unwrap().name(), "second");
+
+        registry.shutdown_all().unwrap();
+        assert!(registry.list().is_empty());
+    }
+
+    #[test]
+    fn test_register_rejects_invalid_name() {
+        let mut registry = ImageCaptionerRegistry::new();
+        assert!(registry.register(Arc::new(NamedCaptioner("bad name"))).is_err());
+        assert!(registry.list().is_empty());
+    }
+}
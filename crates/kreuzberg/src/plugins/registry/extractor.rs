@@ -139,7 +139,7 @@ impl DocumentExtractorRegistry {
 
         #[cfg(feature = "otel")]
         tracing::Span::current().record("registry.found", false);
-        Err(KreuzbergError::UnsupportedFormat(mime_type.to_string()))
+        Err(KreuzbergError::unsupported_format(mime_type))
     }
 
     /// List all registered extractors.
@@ -147,6 +147,26 @@ impl DocumentExtractorRegistry {
         self.name_index.keys().cloned().collect()
     }
 
+    /// List all registered extractors together with the MIME types they were registered for.
+    ///
+    /// Entries are sorted by extractor name and each MIME type list is sorted,
+    /// so the output is stable across calls.
+    pub fn list_with_mime_types(&self) -> Vec<(String, Vec<String>)> {
+        let mut entries: Vec<(String, Vec<String>)> = self
+            .name_index
+            .iter()
+            .map(|(name, index_entries)| {
+                let mut mime_types: Vec<String> = index_entries.iter().map(|(mime, _)| mime.clone()).collect();
+                mime_types.sort();
+                mime_types.dedup();
+                (name.clone(), mime_types)
+            })
+            .collect();
+
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
+    }
+
     /// Remove an extractor from the registry.
     pub fn remove(&mut self, name: &str) -> Result<()> {
         let index_entries = match self.name_index.remove(name) {
@@ -264,6 +284,11 @@ mod tests {
                 elements: None,
                 ocr_elements: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             })
         }
 
@@ -296,6 +321,38 @@ mod tests {
         assert!(names.contains(&"pdf-extractor".to_string()));
     }
 
+    #[test]
+    fn test_document_extractor_registry_list_with_mime_types() {
+        let mut registry = DocumentExtractorRegistry::new();
+
+        registry
+            .register(Arc::new(MockExtractor {
+                name: "zeta-extractor".to_string(),
+                mime_types: &["text/x-zeta", "application/x-zeta"],
+                priority: 50,
+            }))
+            .unwrap();
+        registry
+            .register(Arc::new(MockExtractor {
+                name: "alpha-extractor".to_string(),
+                mime_types: &["text/x-alpha"],
+                priority: 50,
+            }))
+            .unwrap();
+
+        let entries = registry.list_with_mime_types();
+        assert_eq!(
+            entries,
+            vec![
+                ("alpha-extractor".to_string(), vec!["text/x-alpha".to_string()]),
+                (
+                    "zeta-extractor".to_string(),
+                    vec!["application/x-zeta".to_string(), "text/x-zeta".to_string()]
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_document_extractor_registry_prefix_match() {
         let mut registry = DocumentExtractorRegistry::new();
@@ -343,7 +400,7 @@ mod tests {
         let registry = DocumentExtractorRegistry::new();
 
         let result = registry.get("application/unknown");
-        assert!(matches!(result, Err(KreuzbergError::UnsupportedFormat(_))));
+        assert!(matches!(result, Err(KreuzbergError::UnsupportedFormat { .. })));
     }
 
     #[test]
@@ -508,6 +565,11 @@ mod tests {
                 elements: None,
                 ocr_elements: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             })
         }
 
@@ -0,0 +1,169 @@
+//! MIME detector registry implementation.
+
+use crate::Result;
+use crate::plugins::MimeDetector;
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+/// Registry for MIME detector plugins.
+///
+/// Keeps detectors in registration order; detection asks the most recently registered
+/// detector first.
+pub struct MimeDetectorRegistry {
+    detectors: IndexMap<String, Arc<dyn MimeDetector>>,
+}
+
+impl MimeDetectorRegistry {
+    /// Create a new empty MIME detector registry.
+    pub fn new() -> Self {
+        Self {
+            detectors: IndexMap::new(),
+        }
+    }
+
+    /// Register a MIME detector.
+    ///
+    /// Registering a detector under an existing name replaces the previous one and
+    /// makes it the first detector asked.
+    ///
+    /// # Arguments
+    ///
+    /// * `detector` - The detector to register
+    pub fn register(&mut self, detector: Arc<dyn MimeDetector>) -> Result<()> {
+        let name = detector.name().to_string();
+
+        super::validate_plugin_name(&name)?;
+        detector.initialize()?;
+
+        self.detectors.shift_remove(&name);
+        self.detectors.insert(name.clone(), detector);
+        tracing::debug!("Registered MIME detector '{}'", name);
+
+        Ok(())
+    }
+
+    /// Detect the MIME type of `header` with the registered detectors.
+    ///
+    /// Returns the type reported by the most recently registered detector that
+    /// recognizes the header, or `None` if none does.
+    pub fn detect(&self, header: &[u8]) -> Option<String> {
+        self.detectors
+            .values()
+            .rev()
+            .find_map(|detector| detector.detect(header))
+    }
+
+    /// Whether no detector is registered.
+    pub fn is_empty(&self) -> bool {
+        self.detectors.is_empty()
+    }
+
+    /// List all registered detector names in registration order.
+    pub fn list(&self) -> Vec<String> {
+        self.detectors.keys().cloned().collect()
+    }
+
+    /// Remove a detector from the registry.
+    ///
+    /// Calls `shutdown()` on the detector before removing.
+    pub fn remove(&mut self, name: &str) -> Result<()> {
+        if let Some(detector) = self.detectors.shift_remove(name) {
+            detector.shutdown()?;
+        }
+        Ok(())
+    }
+
+    /// Shutdown all detectors and clear the registry.
+    pub fn shutdown_all(&mut self) -> Result<()> {
+        for name in self.list() {
+            self.remove(&name)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for MimeDetectorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::Plugin;
+
+    struct MagicDetector {
+        name: &'static str,
+        magic: &'static [u8],
+        mime_type: &'static str,
+    }
+
+    impl Plugin for MagicDetector {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    impl MimeDetector for MagicDetector {
+        fn detect(&self, header: &[u8]) -> Option<String> {
+            header.starts_with(self.magic).then(|| self.mime_type.to_string())
+        }
+    }
+
+    fn detector(name: &'static str, magic: &'static [u8], mime_type: &'static str) -> Arc<dyn MimeDetector> {
+        Arc::new(MagicDetector { name, magic, mime_type })
+    }
+
+    #[test]
+    fn test_most_recent_detector_is_asked_first() {
+        let mut registry = MimeDetectorRegistry::new();
+        assert!(registry.is_empty());
+        assert!(registry.detect(b"KRZB").is_none());
+
+        registry
+            .register(detector("generic", b"KR", "application/x-generic"))
+            .unwrap();
+        registry
+            .register(detector("specific", b"KRZB", "application/x-specific"))
+            .unwrap();
+        assert_eq!(registry.detect(b"KRZB\x01").as_deref(), Some("application/x-specific"));
+        assert_eq!(registry.detect(b"KRAB").as_deref(), Some("application/x-generic"));
+        assert!(registry.detect(b"%PDF-1.7").is_none());
+
+        registry
+            .register(detector("generic", b"KR", "application/x-generic"))
+            .unwrap();
+        assert_eq!(registry.list(), vec!["specific", "generic"]);
+        assert_eq!(registry.detect(b"KRZB\x01").as_deref(), Some("application/x-generic"));
+
+        registry.remove("generic").unwrap();
+        assert_eq!(registry.detect(b"KRZB\x01").as_deref(), Some("application/x-specific"));
+
+        registry.shutdown_all().unwrap();
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn test_register_rejects_invalid_name() {
+        let mut registry = MimeDetectorRegistry::new();
+        assert!(
+            registry
+                .register(detector("bad name", b"KRZB", "application/x-specific"))
+                .is_err()
+        );
+        assert!(registry.list().is_empty());
+    }
+}
@@ -4,12 +4,16 @@
 //! Each plugin type (OcrBackend, DocumentExtractor, etc.) has its own registry
 //! with type-safe registration and lookup.
 
+mod captioner;
 mod extractor;
+mod mime_detector;
 mod ocr;
 mod processor;
 mod validator;
 
+pub use captioner::ImageCaptionerRegistry;
 pub use extractor::DocumentExtractorRegistry;
+pub use mime_detector::MimeDetectorRegistry;
 pub use ocr::OcrBackendRegistry;
 pub use processor::PostProcessorRegistry;
 pub use validator::ValidatorRegistry;
@@ -63,6 +67,14 @@ pub static POST_PROCESSOR_REGISTRY: Lazy<Arc<RwLock<PostProcessorRegistry>>> =
 pub static VALIDATOR_REGISTRY: Lazy<Arc<RwLock<ValidatorRegistry>>> =
     Lazy::new(|| Arc::new(RwLock::new(ValidatorRegistry::new())));
 
+/// Global image captioner registry singleton.
+pub static IMAGE_CAPTIONER_REGISTRY: Lazy<Arc<RwLock<ImageCaptionerRegistry>>> =
+    Lazy::new(|| Arc::new(RwLock::new(ImageCaptionerRegistry::new())));
+
+/// Global MIME detector registry singleton.
+pub static MIME_DETECTOR_REGISTRY: Lazy<Arc<RwLock<MimeDetectorRegistry>>> =
+    Lazy::new(|| Arc::new(RwLock::new(MimeDetectorRegistry::new())));
+
 /// Get the global OCR backend registry.
 pub fn get_ocr_backend_registry() -> Arc<RwLock<OcrBackendRegistry>> {
     OCR_BACKEND_REGISTRY.clone()
@@ -83,9 +95,111 @@ pub fn get_validator_registry() -> Arc<RwLock<ValidatorRegistry>> {
     VALIDATOR_REGISTRY.clone()
 }
 
+/// Get the global image captioner registry.
+pub fn get_image_captioner_registry() -> Arc<RwLock<ImageCaptionerRegistry>> {
+    IMAGE_CAPTIONER_REGISTRY.clone()
+}
+
+/// Get the global MIME detector registry.
+pub fn get_mime_detector_registry() -> Arc<RwLock<MimeDetectorRegistry>> {
+    MIME_DETECTOR_REGISTRY.clone()
+}
+
+/// Clear every global plugin registry in one call.
+///
+/// Removes all OCR backends, document extractors, post-processors, validators, image
+/// captioners and MIME detectors, calling each plugin's `shutdown()` method. All registry write locks are held
+/// for the duration of the reset, so no extraction observes a partially cleared
+/// plugin set.
+///
+/// The default OCR backends (Tesseract and PaddleOCR, when their features are enabled)
+/// are registered again as part of the reset, as [`OcrBackendRegistry::new`] does.
+/// Built-in document extractors are re-registered automatically on the next
+/// extraction (see [`crate::extractors::ensure_initialized`]); other built-in plugins
+/// must be registered again explicitly.
+///
+/// # Errors
+///
+/// Returns `LockPoisoned` if a registry lock is poisoned. If a plugin's `shutdown()`
+/// fails, the remaining registries are still cleared and the first error is returned.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::reset_all_registries;
+///
+/// # fn main() -> kreuzberg::Result<()> {
+/// reset_all_registries()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn reset_all_registries() -> Result<()> {
+    fn poisoned(registry: &str) -> KreuzbergError {
+        KreuzbergError::LockPoisoned(format!("{} registry lock poisoned", registry))
+    }
+
+    // Locks are always acquired in this order to avoid deadlocking with concurrent resets.
+    let mut ocr_backends = OCR_BACKEND_REGISTRY.write().map_err(|_| poisoned("OCR backend"))?;
+    let mut extractors = DOCUMENT_EXTRACTOR_REGISTRY
+        .write()
+        .map_err(|_| poisoned("Document extractor"))?;
+    let mut processors = POST_PROCESSOR_REGISTRY
+        .write()
+        .map_err(|_| poisoned("Post-processor"))?;
+    let mut validators = VALIDATOR_REGISTRY.write().map_err(|_| poisoned("Validator"))?;
+    let mut captioners = IMAGE_CAPTIONER_REGISTRY
+        .write()
+        .map_err(|_| poisoned("Image captioner"))?;
+    let mut mime_detectors = MIME_DETECTOR_REGISTRY.write().map_err(|_| poisoned("MIME detector"))?;
+
+    let results = [
+        ocr_backends.shutdown_all(),
+        extractors.shutdown_all(),
+        processors.shutdown_all(),
+        validators.shutdown_all(),
+        captioners.shutdown_all(),
+        mime_detectors.shutdown_all(),
+    ];
+    *ocr_backends = OcrBackendRegistry::new();
+
+    results.into_iter().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::config::ExtractionConfig;
+    use crate::plugins::{Plugin, Validator};
+    use crate::types::ExtractionResult;
+    use async_trait::async_trait;
+    use serial_test::serial;
+
+    struct NoopValidator;
+
+    impl Plugin for NoopValidator {
+        fn name(&self) -> &str {
+            "noop-validator"
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Validator for NoopValidator {
+        async fn validate(&self, _result: &ExtractionResult, _config: &ExtractionConfig) -> Result<()> {
+            Ok(())
+        }
+    }
 
     #[test]
     fn test_global_registry_access() {
@@ -113,4 +227,31 @@ mod tests {
             .expect("Failed to acquire read lock on validator registry in test")
             .list();
     }
+
+    #[test]
+    #[serial]
+    fn test_reset_all_registries() {
+        crate::extractors::ensure_initialized().expect("Failed to register built-in extractors");
+        get_validator_registry()
+            .write()
+            .expect("Failed to acquire write lock on validator registry in test")
+            .register(Arc::new(NoopValidator))
+            .expect("Failed to register validator");
+
+        reset_all_registries().expect("Failed to reset registries");
+
+        let mut ocr_backends = get_ocr_backend_registry().read().unwrap().list();
+        let mut default_backends = OcrBackendRegistry::new().list();
+        ocr_backends.sort();
+        default_backends.sort();
+        assert_eq!(ocr_backends, default_backends);
+        assert!(get_document_extractor_registry().read().unwrap().list().is_empty());
+        assert!(get_post_processor_registry().read().unwrap().list().is_empty());
+        assert!(get_validator_registry().read().unwrap().list().is_empty());
+        assert!(get_image_captioner_registry().read().unwrap().list().is_empty());
+        assert!(get_mime_detector_registry().read().unwrap().is_empty());
+
+        crate::extractors::ensure_initialized().expect("Failed to re-register built-in extractors");
+        assert!(!get_document_extractor_registry().read().unwrap().list().is_empty());
+    }
 }
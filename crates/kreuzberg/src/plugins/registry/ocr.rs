@@ -253,6 +253,11 @@ mod tests {
                 elements: None,
                 ocr_elements: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             })
         }
 
@@ -382,6 +387,11 @@ mod tests {
                 elements: None,
                 ocr_elements: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             })
         }
 
@@ -0,0 +1,157 @@
+//! Test-only OCR backends.
+//!
+//! These backends implement [`OcrBackend`] without any OCR engine so that plugin
+//! registration and OCR dispatch can be exercised without Tesseract installed.
+//! They are only compiled with the `test-utils` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::plugins::register_ocr_backend;
+//! use kreuzberg::plugins::testing::NullOcrBackend;
+//! use std::sync::Arc;
+//!
+//! register_ocr_backend(Arc::new(NullOcrBackend))?;
+//! # Ok::<(), kreuzberg::KreuzbergError>(())
+//! ```
+
+use crate::Result;
+use crate::core::config::OcrConfig;
+use crate::plugins::{OcrBackend, OcrBackendType, Plugin};
+use crate::types::{ExtractionResult, Metadata};
+use async_trait::async_trait;
+use std::borrow::Cow;
+
+/// Content returned by [`EchoOcrBackend`] for every image.
+pub const ECHO_OCR_MARKER: &str = "[kreuzberg-echo-ocr]";
+
+/// OCR backend that recognizes nothing.
+///
+/// Registered as `"null"`. Returns empty content for every image and claims support
+/// for every language.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullOcrBackend;
+
+/// OCR backend that returns [`ECHO_OCR_MARKER`] for every image.
+///
+/// Registered as `"echo"`. Useful for asserting that OCR output actually reached the
+/// extraction result. Claims support for every language.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EchoOcrBackend;
+
+fn text_result(content: &str) -> ExtractionResult {
+    ExtractionResult {
+        content: content.to_string(),
+        mime_type: Cow::Borrowed("text/plain"),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        pages: None,
+        djot_content: None,
+        elements: None,
+        ocr_elements: None,
+        document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
+    }
+}
+
+impl Plugin for NullOcrBackend {
+    fn name(&self) -> &str {
+        "null"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl OcrBackend for NullOcrBackend {
+    async fn process_image(&self, _image_bytes: &[u8], _config: &OcrConfig) -> Result<ExtractionResult> {
+        Ok(text_result(""))
+    }
+
+    fn supports_language(&self, _lang: &str) -> bool {
+        true
+    }
+
+    fn backend_type(&self) -> OcrBackendType {
+        OcrBackendType::Custom
+    }
+}
+
+impl Plugin for EchoOcrBackend {
+    fn name(&self) -> &str {
+        "echo"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl OcrBackend for EchoOcrBackend {
+    async fn process_image(&self, _image_bytes: &[u8], _config: &OcrConfig) -> Result<ExtractionResult> {
+        Ok(text_result(ECHO_OCR_MARKER))
+    }
+
+    fn supports_language(&self, _lang: &str) -> bool {
+        true
+    }
+
+    fn backend_type(&self) -> OcrBackendType {
+        OcrBackendType::Custom
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_null_backend_returns_empty_content() {
+        let result = NullOcrBackend
+            .process_image(b"not an image", &OcrConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(NullOcrBackend.name(), "null");
+        assert!(result.content.is_empty());
+        assert!(NullOcrBackend.supports_language("eng"));
+        assert!(NullOcrBackend.supports_language("xyz"));
+    }
+
+    #[tokio::test]
+    async fn test_echo_backend_returns_marker() {
+        let result = EchoOcrBackend.process_image(&[], &OcrConfig::default()).await.unwrap();
+
+        assert_eq!(EchoOcrBackend.name(), "echo");
+        assert_eq!(result.content, ECHO_OCR_MARKER);
+        assert_eq!(EchoOcrBackend.backend_type(), OcrBackendType::Custom);
+    }
+}
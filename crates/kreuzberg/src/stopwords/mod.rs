@@ -299,6 +299,125 @@ pub fn get_stopwords_with_fallback(language: &str, fallback: &str) -> Option<&'s
     get_stopwords(language).or_else(|| get_stopwords(fallback))
 }
 
+/// ISO 639-3 (and ISO 639-2/B) codes of the languages with a stopword list.
+const ISO_639_3_CODES: &[(&str, &str)] = &[
+    ("afr", "af"),
+    ("ara", "ar"),
+    ("bul", "bg"),
+    ("ben", "bn"),
+    ("bre", "br"),
+    ("cat", "ca"),
+    ("ces", "cs"),
+    ("cze", "cs"),
+    ("dan", "da"),
+    ("deu", "de"),
+    ("ger", "de"),
+    ("ell", "el"),
+    ("gre", "el"),
+    ("eng", "en"),
+    ("epo", "eo"),
+    ("spa", "es"),
+    ("est", "et"),
+    ("eus", "eu"),
+    ("baq", "eu"),
+    ("fas", "fa"),
+    ("per", "fa"),
+    ("pes", "fa"),
+    ("fin", "fi"),
+    ("fra", "fr"),
+    ("fre", "fr"),
+    ("gle", "ga"),
+    ("glg", "gl"),
+    ("guj", "gu"),
+    ("hau", "ha"),
+    ("heb", "he"),
+    ("hin", "hi"),
+    ("hrv", "hr"),
+    ("hun", "hu"),
+    ("hye", "hy"),
+    ("arm", "hy"),
+    ("ind", "id"),
+    ("ita", "it"),
+    ("jpn", "ja"),
+    ("kan", "kn"),
+    ("kor", "ko"),
+    ("kur", "ku"),
+    ("lat", "la"),
+    ("lit", "lt"),
+    ("lav", "lv"),
+    ("mal", "ml"),
+    ("mar", "mr"),
+    ("msa", "ms"),
+    ("may", "ms"),
+    ("zsm", "ms"),
+    ("nep", "ne"),
+    ("nld", "nl"),
+    ("dut", "nl"),
+    ("nor", "no"),
+    ("nob", "no"),
+    ("nno", "no"),
+    ("pol", "pl"),
+    ("por", "pt"),
+    ("ron", "ro"),
+    ("rum", "ro"),
+    ("rus", "ru"),
+    ("sin", "si"),
+    ("slk", "sk"),
+    ("slo", "sk"),
+    ("slv", "sl"),
+    ("som", "so"),
+    ("sot", "st"),
+    ("swe", "sv"),
+    ("swa", "sw"),
+    ("swh", "sw"),
+    ("tam", "ta"),
+    ("tel", "te"),
+    ("tha", "th"),
+    ("tgl", "tl"),
+    ("tur", "tr"),
+    ("ukr", "uk"),
+    ("urd", "ur"),
+    ("vie", "vi"),
+    ("yor", "yo"),
+    ("zho", "zh"),
+    ("chi", "zh"),
+    ("cmn", "zh"),
+    ("zul", "zu"),
+];
+
+/// Resolve a language code to the key of its stopword list in [`STOPWORDS`].
+///
+/// Unlike [`get_stopwords()`], three-letter codes are looked up as ISO 639-3 rather
+/// than truncated, so the codes reported in `detected_languages` ("spa", "nld",
+/// "cmn") resolve correctly. Locales ("de-AT", "pt_BR") use their language part.
+///
+/// Returns `None` if no stopword list exists for the language.
+///
+/// # Examples
+///
+/// ```rust
+/// use kreuzberg::stopwords::resolve_stopwords_language;
+///
+/// assert_eq!(resolve_stopwords_language("de"), Some("de"));
+/// assert_eq!(resolve_stopwords_language("deu"), Some("de"));
+/// assert_eq!(resolve_stopwords_language("spa"), Some("es"));
+/// assert_eq!(resolve_stopwords_language("pt-BR"), Some("pt"));
+/// assert_eq!(resolve_stopwords_language("jav"), None);
+/// ```
+pub fn resolve_stopwords_language(lang: &str) -> Option<&'static str> {
+    let normalized = lang.trim().to_lowercase();
+    let base = normalized.split(['-', '_']).next().unwrap_or_default();
+
+    let code = match base.len() {
+        3 => ISO_639_3_CODES
+            .iter()
+            .find_map(|&(iso3, iso1)| (iso3 == base).then_some(iso1))?,
+        _ => base,
+    };
+
+    STOPWORDS.get_key_value(code).map(|(key, _)| key.as_str())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1441,4 +1560,20 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_resolve_stopwords_language() {
+        assert_eq!(resolve_stopwords_language("de"), Some("de"));
+        assert_eq!(resolve_stopwords_language("DE-at"), Some("de"));
+        assert_eq!(resolve_stopwords_language("deu"), Some("de"));
+        assert_eq!(resolve_stopwords_language("spa"), Some("es"));
+        assert_eq!(resolve_stopwords_language("cmn"), Some("zh"));
+        assert_eq!(resolve_stopwords_language("jav"), None);
+        assert_eq!(resolve_stopwords_language("xx"), None);
+        assert_eq!(resolve_stopwords_language(""), None);
+
+        for &(iso3, iso1) in ISO_639_3_CODES {
+            assert!(STOPWORDS.contains_key(iso1), "{iso3} maps to missing list {iso1}");
+        }
+    }
 }
@@ -0,0 +1,95 @@
+//! Right-to-left text reordering.
+//!
+//! PDFs and some other sources store Arabic and Hebrew text in visual (display) order,
+//! so extracting it character by character yields reversed words. [`normalize_bidi`]
+//! puts such text back into logical order with the Unicode Bidirectional Algorithm.
+
+use std::borrow::Cow;
+use unicode_bidi::{BidiClass, BidiInfo, bidi_class};
+
+/// Whether `text` contains right-to-left characters (Hebrew, Arabic, Syriac, Thaana, ...).
+pub fn contains_rtl(text: &str) -> bool {
+    text.chars()
+        .any(|ch| matches!(bidi_class(ch), BidiClass::R | BidiClass::AL))
+}
+
+/// Reorder visually ordered right-to-left text into logical order.
+///
+/// Each line containing right-to-left characters is reordered on its own, so embedded
+/// numbers and Latin words keep their reading order while the right-to-left runs around
+/// them are reversed. Other lines, line endings and the byte length of every line are
+/// preserved. Text without right-to-left characters is returned borrowed.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::text::bidi::normalize_bidi;
+///
+/// assert_eq!(normalize_bidi("Title\n\u{05DD}\u{05D5}\u{05DC}\u{05E9}"), "Title\n\u{05E9}\u{05DC}\u{05D5}\u{05DD}");
+/// ```
+pub fn normalize_bidi(text: &str) -> Cow<'_, str> {
+    if !contains_rtl(text) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut normalized = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let (body, ending) = line.split_at(line.trim_end_matches(['\r', '\n']).len());
+        normalized.push_str(&reorder_line(body));
+        normalized.push_str(ending);
+    }
+    Cow::Owned(normalized)
+}
+
+fn reorder_line(line: &str) -> Cow<'_, str> {
+    if !contains_rtl(line) {
+        return Cow::Borrowed(line);
+    }
+
+    let info = BidiInfo::new(line, None);
+    let mut reordered = String::with_capacity(line.len());
+    for paragraph in &info.paragraphs {
+        reordered.push_str(&info.reorder_line(paragraph, paragraph.range.clone()));
+    }
+    Cow::Owned(reordered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // "shalom" and "marhaba" as stored by visual-order sources: last letter first.
+    const SHALOM_VISUAL: &str = "\u{05DD}\u{05D5}\u{05DC}\u{05E9}";
+    const SHALOM: &str = "\u{05E9}\u{05DC}\u{05D5}\u{05DD}";
+    const MARHABA_VISUAL: &str = "\u{0627}\u{0628}\u{062D}\u{0631}\u{0645}";
+    const MARHABA: &str = "\u{0645}\u{0631}\u{062D}\u{0628}\u{0627}";
+
+    #[test]
+    fn test_reverses_hebrew_and_arabic_words() {
+        assert_eq!(normalize_bidi(SHALOM_VISUAL), SHALOM);
+        assert_eq!(
+            normalize_bidi(&format!("{} {}", MARHABA_VISUAL, SHALOM_VISUAL)),
+            format!("{} {}", SHALOM, MARHABA)
+        );
+    }
+
+    #[test]
+    fn test_keeps_numbers_in_reading_order() {
+        assert_eq!(
+            normalize_bidi(&format!("2024 {}", SHALOM_VISUAL)),
+            format!("{} 2024", SHALOM)
+        );
+    }
+
+    #[test]
+    fn test_only_touches_lines_with_rtl_text() {
+        let text = format!("Report 7\r\n{}\nEnd", SHALOM_VISUAL);
+        let normalized = normalize_bidi(&text);
+        assert_eq!(normalized, format!("Report 7\r\n{}\nEnd", SHALOM));
+        assert_eq!(normalized.len(), text.len());
+
+        assert!(matches!(normalize_bidi("Plain Latin text"), Cow::Borrowed(_)));
+        assert!(!contains_rtl("Plain Latin text"));
+        assert!(contains_rtl(MARHABA));
+    }
+}
@@ -0,0 +1,63 @@
+//! Stable content hashes for deduplication.
+//!
+//! The hash is meant to be reproduced outside Kreuzberg, so it only uses standard
+//! primitives:
+//!
+//! 1. Normalize the text to Unicode NFC.
+//! 2. Split it on Unicode whitespace (the `White_Space` property) and join the
+//!    non-empty pieces with a single ASCII space. This trims the text and collapses
+//!    every run of whitespace, including line breaks.
+//! 3. Hash the UTF-8 bytes with SHA-256 and format the digest as 64 lowercase hex
+//!    characters.
+//!
+//! In Python: `hashlib.sha256(" ".join(unicodedata.normalize("NFC", text).split()).encode()).hexdigest()`
+//! (Python additionally treats the ASCII separators `\x1c`-`\x1f` as whitespace).
+
+use sha2::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
+
+/// Text as it is hashed by [`content_hash`]: NFC with whitespace runs collapsed.
+pub fn normalize_for_hash(text: &str) -> String {
+    let nfc: String = text.nfc().collect();
+    nfc.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// SHA-256 of the normalized text, as lowercase hex.
+///
+/// Texts that only differ in whitespace or Unicode composition hash the same.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::text::content_hash;
+///
+/// assert_eq!(content_hash("Hello,\n  world"), content_hash("Hello, world"));
+/// assert_eq!(content_hash("").len(), 64);
+/// ```
+pub fn content_hash(text: &str) -> String {
+    Sha256::digest(normalize_for_hash(text).as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_for_hash() {
+        assert_eq!(normalize_for_hash("  a\t b\n\nc  "), "a b c");
+        assert_eq!(normalize_for_hash("Cafe\u{301}"), "Caf\u{e9}");
+    }
+
+    #[test]
+    fn test_content_hash_is_sha256_of_normalized_text() {
+        assert_eq!(
+            content_hash(" abc\n"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(content_hash("Caf\u{e9}"), content_hash("Cafe\u{301}"));
+        assert_ne!(content_hash("abc"), content_hash("abd"));
+    }
+}
@@ -0,0 +1,31 @@
+//! HTML escaping.
+//!
+//! [`html_escape`] is shared by every place that writes document text into HTML: table
+//! rendering, the HTML output format and PPTX table markup.
+
+/// Escape the HTML special characters `&`, `<`, `>`, `"` and `'` in `text`.
+pub(crate) fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_escape() {
+        assert_eq!(html_escape("plain text"), "plain text");
+        assert_eq!(html_escape("a & b"), "a &amp; b");
+        assert_eq!(html_escape("<tag>"), "&lt;tag&gt;");
+        assert_eq!(html_escape("\"quoted\""), "&quot;quoted&quot;");
+        assert_eq!(html_escape("'apostrophe'"), "&#39;apostrophe&#39;");
+        assert_eq!(
+            html_escape("<a href=\"url\" title='test'>text & more</a>"),
+            "&lt;a href=&quot;url&quot; title=&#39;test&#39;&gt;text &amp; more&lt;/a&gt;"
+        );
+    }
+}
@@ -0,0 +1,342 @@
+//! Repeated header/footer removal post-processor.
+//!
+//! Multi-page documents (reports, scans) often repeat the same running header or footer
+//! on every page. This processor finds lines at the top or bottom of pages that recur on
+//! a configurable fraction of pages and removes them from `content`, using the page
+//! boundaries to scope detection.
+//!
+//! The processor is opt-in: it only runs when `header-footer-stripper` is listed in
+//! `postprocessor.enabled_processors`.
+
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::types::PageBoundary;
+use crate::{ExtractionConfig, ExtractionResult, Result};
+use ahash::{AHashMap, AHashSet};
+use async_trait::async_trait;
+use std::borrow::Cow;
+
+/// Number of non-empty lines at the top and bottom of each page considered as
+/// header/footer candidates.
+const EDGE_LINES: usize = 3;
+
+/// Post-processor that strips headers and footers repeated across pages.
+///
+/// Lines are compared after trimming and replacing digit runs with `#`, so running
+/// footers like "Page 3 of 12" match across pages. The number of removed lines is
+/// stored in `metadata.additional["header_footer_lines_removed"]`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::plugins::{Plugin, PostProcessor};
+/// use kreuzberg::text::HeaderFooterStripper;
+///
+/// let processor = HeaderFooterStripper;
+/// assert_eq!(processor.name(), "header-footer-stripper");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct HeaderFooterStripper;
+
+impl Plugin for HeaderFooterStripper {
+    fn name(&self) -> &str {
+        "header-footer-stripper"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for HeaderFooterStripper {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let threshold = config
+            .postprocessor
+            .as_ref()
+            .map_or(0.6, |pp| pp.header_footer_threshold);
+
+        let Some(boundaries) = result
+            .metadata
+            .pages
+            .as_mut()
+            .and_then(|pages| pages.boundaries.as_mut())
+        else {
+            return Ok(());
+        };
+
+        let Some(stripped) = strip_repeated_lines(&result.content, boundaries, threshold) else {
+            return Ok(());
+        };
+
+        if let Some(ref mut pages) = result.pages {
+            for page in pages.iter_mut() {
+                if let Some(boundary) = boundaries.iter().find(|b| b.page_number == page.page_number) {
+                    page.content = stripped.content[boundary.byte_start..boundary.byte_end]
+                        .trim()
+                        .to_string();
+                }
+            }
+        }
+
+        result.content = stripped.content;
+        result.metadata.additional.insert(
+            Cow::Borrowed("header_footer_lines_removed"),
+            serde_json::Value::Number(stripped.lines_removed.into()),
+        );
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Early
+    }
+
+    fn should_process(&self, result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        let opted_in = config
+            .postprocessor
+            .as_ref()
+            .and_then(|pp| pp.enabled_processors.as_ref())
+            .is_some_and(|names| names.iter().any(|name| name == self.name()));
+        let page_count = result
+            .metadata
+            .pages
+            .as_ref()
+            .and_then(|pages| pages.boundaries.as_ref())
+            .map_or(0, |boundaries| boundaries.len());
+
+        opted_in && page_count >= 2
+    }
+}
+
+struct StrippedContent {
+    content: String,
+    lines_removed: usize,
+}
+
+/// Remove header/footer lines repeated on at least `threshold` of the pages.
+///
+/// `boundaries` are rewritten to match the returned content. Returns `None` when the
+/// boundaries don't describe `content` or nothing repeats.
+fn strip_repeated_lines(content: &str, boundaries: &mut [PageBoundary], threshold: f64) -> Option<StrippedContent> {
+    if !PageBoundary::all_valid(content, boundaries) {
+        return None;
+    }
+
+    let mut page_counts: AHashMap<String, usize> = AHashMap::new();
+    for boundary in boundaries.iter() {
+        let page = &content[boundary.byte_start..boundary.byte_end];
+        let candidates: AHashSet<String> = edge_lines(page).map(|(_, line)| normalize_line(line)).collect();
+        for candidate in candidates {
+            *page_counts.entry(candidate).or_default() += 1;
+        }
+    }
+
+    let min_pages = ((boundaries.len() as f64 * threshold.clamp(0.0, 1.0)).ceil() as usize).max(2);
+    let repeated: AHashSet<String> = page_counts
+        .into_iter()
+        .filter(|(_, count)| *count >= min_pages)
+        .map(|(line, _)| line)
+        .collect();
+    if repeated.is_empty() {
+        return None;
+    }
+
+    let mut lines_removed = 0;
+    let strip_page = |page: &str| {
+        let remove: AHashSet<usize> = edge_lines(page)
+            .filter(|(_, line)| repeated.contains(&normalize_line(line)))
+            .map(|(index, _)| index)
+            .collect();
+        lines_removed += remove.len();
+
+        let kept: Vec<&str> = page
+            .split('\n')
+            .enumerate()
+            .filter(|(index, _)| !remove.contains(index))
+            .map(|(_, line)| line)
+            .collect();
+        kept.join("\n").trim_matches('\n').to_string()
+    };
+    let stripped = PageBoundary::rewrite_pages(content, boundaries, strip_page, str::to_string)?;
+
+    Some(StrippedContent {
+        content: stripped,
+        lines_removed,
+    })
+}
+
+/// The first and last [`EDGE_LINES`] non-empty lines of a page, with their line index.
+fn edge_lines(page: &str) -> impl Iterator<Item = (usize, &str)> {
+    let lines: Vec<(usize, &str)> = page
+        .split('\n')
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+    let tail_start = lines.len().saturating_sub(EDGE_LINES).max(EDGE_LINES.min(lines.len()));
+    let mut edges = lines[..EDGE_LINES.min(lines.len())].to_vec();
+    edges.extend_from_slice(&lines[tail_start..]);
+    edges.into_iter()
+}
+
+/// Trim and replace digit runs with `#` so page numbers don't defeat matching.
+fn normalize_line(line: &str) -> String {
+    let mut normalized = String::with_capacity(line.len());
+    let mut in_digits = false;
+    for ch in line.trim().chars() {
+        if ch.is_ascii_digit() {
+            if !in_digits {
+                normalized.push('#');
+            }
+            in_digits = true;
+        } else {
+            normalized.push(ch);
+            in_digits = false;
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::PostProcessorConfig;
+    use crate::types::{Metadata, PageStructure, PageUnitType};
+
+    fn paged_result(pages: &[String]) -> ExtractionResult {
+        let mut content = String::new();
+        let mut boundaries = Vec::new();
+        for (index, page) in pages.iter().enumerate() {
+            if index > 0 {
+                content.push_str("\n\n");
+            }
+            let byte_start = content.len();
+            content.push_str(page);
+            boundaries.push(PageBoundary {
+                byte_start,
+                byte_end: content.len(),
+                page_number: index + 1,
+            });
+        }
+
+        ExtractionResult {
+            content,
+            mime_type: Cow::Borrowed("application/pdf"),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: pages.len(),
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(boundaries),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            djot_content: None,
+            elements: None,
+            ocr_elements: None,
+            document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
+        }
+    }
+
+    fn opt_in_config() -> ExtractionConfig {
+        ExtractionConfig {
+            postprocessor: Some(PostProcessorConfig {
+                enabled_processors: Some(vec!["header-footer-stripper".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn report_pages() -> Vec<String> {
+        let bodies = [
+            "Revenue grew in every region.\nMargins held steady.",
+            "Operating costs fell slightly.\nHeadcount was flat.",
+            "Section three covers risk.\nNo new exposures were found.",
+            "Capital spending rose.\nMost went to new plants.",
+            "The outlook remains positive.\nGuidance is unchanged.",
+        ];
+        bodies
+            .iter()
+            .enumerate()
+            .map(|(index, body)| {
+                format!(
+                    "Annual Report 2024\n{}\nACME Corp Confidential - Page {} of 5",
+                    body,
+                    index + 1
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_strips_footer_shared_by_five_pages() {
+        let mut result = paged_result(&report_pages());
+        let config = opt_in_config();
+
+        assert!(HeaderFooterStripper.should_process(&result, &config));
+        HeaderFooterStripper.process(&mut result, &config).await.unwrap();
+
+        assert!(!result.content.contains("Confidential"));
+        assert!(!result.content.contains("Annual Report 2024"));
+        assert!(result.content.contains("Revenue grew in every region."));
+        assert!(result.content.contains("Guidance is unchanged."));
+        assert_eq!(
+            result.metadata.additional.get("header_footer_lines_removed"),
+            Some(&serde_json::json!(10))
+        );
+
+        let boundaries = result.metadata.pages.unwrap().boundaries.unwrap();
+        assert_eq!(
+            &result.content[boundaries[2].byte_start..boundaries[2].byte_end],
+            "Section three covers risk.\nNo new exposures were found."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_threshold_keeps_lines_on_too_few_pages() {
+        let mut pages = report_pages();
+        for page in pages.iter_mut().skip(2) {
+            *page = page.replace("ACME Corp Confidential", "Draft");
+        }
+        let mut result = paged_result(&pages);
+        let mut config = opt_in_config();
+        config.postprocessor.as_mut().unwrap().header_footer_threshold = 0.8;
+
+        HeaderFooterStripper.process(&mut result, &config).await.unwrap();
+
+        assert!(!result.content.contains("Annual Report 2024"));
+        assert!(result.content.contains("ACME Corp Confidential - Page 1 of 5"));
+        assert!(result.content.contains("Draft - Page 5 of 5"));
+    }
+
+    #[test]
+    fn test_requires_opt_in_by_name() {
+        let result = paged_result(&report_pages());
+
+        assert!(!HeaderFooterStripper.should_process(&result, &ExtractionConfig::default()));
+    }
+
+    #[test]
+    fn test_normalize_line_masks_numbers() {
+        assert_eq!(normalize_line("  Page 12 of 340 "), "Page # of #");
+    }
+}
@@ -1,5 +1,15 @@
+pub mod bidi;
+pub(crate) mod escape;
+pub mod header_footer;
+pub mod plain_text;
 pub mod utf8_validation;
 
+#[cfg(feature = "quality")]
+pub mod content_hash;
+
+#[cfg(feature = "quality")]
+pub mod normalizer;
+
 #[cfg(feature = "quality")]
 pub mod quality;
 
@@ -12,6 +22,20 @@ pub mod token_reduction;
 #[cfg(feature = "quality")]
 pub mod quality_processor;
 
+#[cfg(feature = "references")]
+pub mod references;
+
+pub use bidi::normalize_bidi;
+pub(crate) use escape::html_escape;
+pub use header_footer::HeaderFooterStripper;
+pub use plain_text::markdown_to_plain_text;
+
+#[cfg(feature = "quality")]
+pub use content_hash::content_hash;
+
+#[cfg(feature = "quality")]
+pub use normalizer::{TextNormalizer, normalize_text};
+
 #[cfg(feature = "quality")]
 pub use quality::{calculate_quality_score, clean_extracted_text, normalize_spaces};
 
@@ -0,0 +1,289 @@
+//! Ligature and typographic punctuation normalization post-processor.
+//!
+//! PDF text layers frequently contain ligature code points (ﬁ, ﬂ) and curly quotes that
+//! break downstream tokenization and exact-match search. This processor rewrites them
+//! to plain ASCII, optionally after Unicode NFC normalization.
+//!
+//! The processor is opt-in: it only runs when `text-normalizer` is listed in
+//! `postprocessor.enabled_processors`. Behavior is configured through
+//! [`TextNormalizerConfig`](crate::core::config::TextNormalizerConfig).
+
+use crate::core::config::TextNormalizerConfig;
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::types::PageBoundary;
+use crate::{ExtractionConfig, ExtractionResult, Result};
+use async_trait::async_trait;
+use unicode_normalization::UnicodeNormalization;
+
+/// Post-processor that expands ligatures and straightens quotes and dashes.
+///
+/// `content`, per-page content and page boundaries are kept consistent.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::plugins::{Plugin, PostProcessor};
+/// use kreuzberg::text::TextNormalizer;
+///
+/// let processor = TextNormalizer;
+/// assert_eq!(processor.name(), "text-normalizer");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TextNormalizer;
+
+impl Plugin for TextNormalizer {
+    fn name(&self) -> &str {
+        "text-normalizer"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for TextNormalizer {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let options = config
+            .postprocessor
+            .as_ref()
+            .map(|pp| pp.text_normalizer)
+            .unwrap_or_default();
+
+        let boundaries = result
+            .metadata
+            .pages
+            .as_mut()
+            .and_then(|pages| pages.boundaries.as_mut());
+
+        // Normalize each page separately so the boundaries can be remapped.
+        let normalize = |text: &str| normalize_text(text, &options);
+        result.content = boundaries
+            .and_then(|boundaries| PageBoundary::rewrite_pages(&result.content, boundaries, normalize, normalize))
+            .unwrap_or_else(|| normalize_text(&result.content, &options));
+
+        if let Some(ref mut pages) = result.pages {
+            for page in pages.iter_mut() {
+                page.content = normalize_text(&page.content, &options);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Early
+    }
+
+    fn should_process(&self, _result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        config
+            .postprocessor
+            .as_ref()
+            .and_then(|pp| pp.enabled_processors.as_ref())
+            .is_some_and(|names| names.iter().any(|name| name == self.name()))
+    }
+}
+
+/// Apply the configured normalizations to `text`.
+pub fn normalize_text(text: &str, options: &TextNormalizerConfig) -> String {
+    let composed;
+    let text = if options.unicode_nfc {
+        composed = text.nfc().collect::<String>();
+        composed.as_str()
+    } else {
+        text
+    };
+
+    let mut normalized = String::with_capacity(text.len());
+    for ch in text.chars() {
+        let replacement = if options.expand_ligatures {
+            expand_ligature(ch)
+        } else {
+            None
+        }
+        .or_else(|| {
+            if options.normalize_punctuation {
+                ascii_punctuation(ch)
+            } else {
+                None
+            }
+        });
+
+        match replacement {
+            Some(replacement) => normalized.push_str(replacement),
+            None => normalized.push(ch),
+        }
+    }
+    normalized
+}
+
+fn expand_ligature(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        '\u{FB00}' => "ff",
+        '\u{FB01}' => "fi",
+        '\u{FB02}' => "fl",
+        '\u{FB03}' => "ffi",
+        '\u{FB04}' => "ffl",
+        '\u{FB05}' | '\u{FB06}' => "st",
+        '\u{0132}' => "IJ",
+        '\u{0133}' => "ij",
+        _ => return None,
+    })
+}
+
+fn ascii_punctuation(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => "'",
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' => "\"",
+        '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2212}' => "-",
+        '\u{2014}' | '\u{2015}' => "--",
+        '\u{2026}' => "...",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::PostProcessorConfig;
+    use crate::types::{Metadata, PageBoundary, PageStructure, PageUnitType};
+    use std::borrow::Cow;
+
+    fn opt_in_config(options: TextNormalizerConfig) -> ExtractionConfig {
+        ExtractionConfig {
+            postprocessor: Some(PostProcessorConfig {
+                enabled_processors: Some(vec!["text-normalizer".to_string()]),
+                text_normalizer: options,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn result_with_content(content: &str, boundaries: Option<Vec<PageBoundary>>) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: Cow::Borrowed("application/pdf"),
+            metadata: Metadata {
+                pages: boundaries.map(|boundaries| PageStructure {
+                    total_count: boundaries.len(),
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(boundaries),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            djot_content: None,
+            elements: None,
+            ocr_elements: None,
+            document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
+        }
+    }
+
+    #[test]
+    fn test_expands_fi_fl_ligatures() {
+        let options = TextNormalizerConfig::default();
+        assert_eq!(
+            normalize_text("\u{FB01}nancial \u{FB02}ow, e\u{FB03}cient", &options),
+            "financial flow, efficient"
+        );
+    }
+
+    #[test]
+    fn test_normalizes_en_em_dashes_and_quotes() {
+        let options = TextNormalizerConfig::default();
+        assert_eq!(
+            normalize_text(
+                "1990\u{2013}2000 \u{2014} \u{201C}it\u{2019}s fine\u{201D}\u{2026}",
+                &options
+            ),
+            "1990-2000 -- \"it's fine\"..."
+        );
+    }
+
+    #[test]
+    fn test_options_disable_individual_rewrites() {
+        let options = TextNormalizerConfig {
+            expand_ligatures: false,
+            normalize_punctuation: true,
+            unicode_nfc: false,
+        };
+        assert_eq!(normalize_text("\u{FB01} \u{2013}", &options), "\u{FB01} -");
+
+        let options = TextNormalizerConfig {
+            expand_ligatures: true,
+            normalize_punctuation: false,
+            unicode_nfc: false,
+        };
+        assert_eq!(normalize_text("\u{FB01} \u{2013}", &options), "fi \u{2013}");
+    }
+
+    #[test]
+    fn test_unicode_nfc_composes_combining_marks() {
+        let options = TextNormalizerConfig {
+            unicode_nfc: true,
+            ..Default::default()
+        };
+        assert_eq!(normalize_text("Cafe\u{0301}", &options), "Caf\u{00E9}");
+        assert_eq!(
+            normalize_text("Cafe\u{0301}", &TextNormalizerConfig::default()),
+            "Cafe\u{0301}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_process_remaps_page_boundaries() {
+        let first = "Fi\u{FB01}h \u{2014} one";
+        let second = "\u{FB02}ag two";
+        let content = format!("{}\n\n{}", first, second);
+        let boundaries = vec![
+            PageBoundary {
+                byte_start: 0,
+                byte_end: first.len(),
+                page_number: 1,
+            },
+            PageBoundary {
+                byte_start: first.len() + 2,
+                byte_end: content.len(),
+                page_number: 2,
+            },
+        ];
+        let mut result = result_with_content(&content, Some(boundaries));
+        let config = opt_in_config(TextNormalizerConfig::default());
+
+        assert!(TextNormalizer.should_process(&result, &config));
+        TextNormalizer.process(&mut result, &config).await.unwrap();
+
+        assert_eq!(result.content, "Fifih -- one\n\nflag two");
+        let boundaries = result.metadata.pages.unwrap().boundaries.unwrap();
+        assert_eq!(
+            &result.content[boundaries[1].byte_start..boundaries[1].byte_end],
+            "flag two"
+        );
+    }
+
+    #[test]
+    fn test_requires_opt_in_by_name() {
+        let result = result_with_content("\u{FB01}", None);
+        assert!(!TextNormalizer.should_process(&result, &ExtractionConfig::default()));
+    }
+}
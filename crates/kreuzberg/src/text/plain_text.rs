@@ -0,0 +1,362 @@
+//! Markdown to plain text conversion.
+//!
+//! Extractors that render Markdown (HTML, DOCX) use this in
+//! [`OutputMode::PlainText`](crate::core::config::OutputMode::PlainText) to drop the
+//! decoration while keeping the prose: heading markers, emphasis, link and image
+//! syntax, list markers, block quotes and table pipes. Fenced code is kept verbatim.
+
+/// Strip Markdown decoration from `markdown`, keeping its text.
+///
+/// Paragraph breaks are preserved as single blank lines. Table rows become
+/// tab-separated cells and table separator rows are dropped.
+pub fn markdown_to_plain_text(markdown: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut fence: Option<&str> = None;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            } else {
+                lines.push(line.to_string());
+            }
+            continue;
+        }
+        if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+            fence = Some(marker);
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            if lines.last().is_some_and(|last| !last.is_empty()) {
+                lines.push(String::new());
+            }
+            continue;
+        }
+
+        if is_thematic_break(trimmed) || is_setext_underline(trimmed) {
+            continue;
+        }
+
+        if trimmed.starts_with('|') {
+            if !is_table_separator(trimmed) {
+                lines.push(table_row_to_text(trimmed));
+            }
+            continue;
+        }
+
+        let text = strip_block_prefixes(trimmed);
+        let text = strip_inline(text);
+        let text = text.trim();
+        if !text.is_empty() {
+            lines.push(text.to_string());
+        }
+    }
+
+    while lines.last().is_some_and(|last| last.is_empty()) {
+        lines.pop();
+    }
+
+    lines.join("\n")
+}
+
+/// `---`, `***` or `___` (optionally spaced) on a line of its own.
+fn is_thematic_break(line: &str) -> bool {
+    let mut chars = line.chars().filter(|c| !c.is_whitespace());
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    matches!(first, '-' | '*' | '_') && line.chars().filter(|&c| c == first).count() >= 3 && chars.all(|c| c == first)
+}
+
+/// `===` underlining a setext heading.
+fn is_setext_underline(line: &str) -> bool {
+    line.len() >= 2 && line.chars().all(|c| c == '=')
+}
+
+/// `| --- | :---: |` row between a table header and its body.
+fn is_table_separator(line: &str) -> bool {
+    line.contains('-') && line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t'))
+}
+
+/// Join the cells of a table row with tabs.
+fn table_row_to_text(line: &str) -> String {
+    let inner = line.strip_prefix('|').unwrap_or(line);
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '|' => cells.push(std::mem::take(&mut cell)),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell);
+
+    cells
+        .iter()
+        .map(|cell| strip_inline(cell.trim()).trim().to_string())
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+/// Remove heading markers, block quote markers and list markers from the start of a line.
+fn strip_block_prefixes(mut line: &str) -> &str {
+    loop {
+        let before = line;
+
+        if let Some(rest) = line.strip_prefix('>') {
+            line = rest.trim_start();
+        }
+
+        let hashes = line.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&hashes) {
+            let rest = &line[hashes..];
+            if rest.is_empty() || rest.starts_with(' ') {
+                let rest = rest.trim();
+                // A closing `#` sequence only counts when separated by a space (`# C#` keeps its `#`).
+                let unclosed = rest.trim_end_matches('#');
+                line = if unclosed.is_empty() || unclosed.ends_with(' ') {
+                    unclosed.trim_end()
+                } else {
+                    rest
+                };
+            }
+        }
+
+        if let Some(rest) = strip_list_marker(line) {
+            line = rest;
+            for task in ["[ ] ", "[x] ", "[X] "] {
+                if let Some(rest) = line.strip_prefix(task) {
+                    line = rest;
+                }
+            }
+        }
+
+        if line == before {
+            return line;
+        }
+    }
+}
+
+/// Strip a `- `, `* `, `+ `, `1. ` or `1) ` list marker.
+fn strip_list_marker(line: &str) -> Option<&str> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(rest) = line.strip_prefix(bullet) {
+            return Some(rest.trim_start());
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if (1..=9).contains(&digits) {
+        let rest = &line[digits..];
+        return rest
+            .strip_prefix(". ")
+            .or_else(|| rest.strip_prefix(") "))
+            .map(str::trim_start);
+    }
+
+    None
+}
+
+/// Remove inline Markdown: emphasis, code spans, links, images, autolinks and escapes.
+fn strip_inline(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if chars.get(i + 1).is_some_and(|n| n.is_ascii_punctuation()) => {
+                out.push(chars[i + 1]);
+                i += 2;
+            }
+            '`' => {
+                let run = count_run(&chars, i, '`');
+                match find_run(&chars, i + run, '`', run) {
+                    Some(end) => {
+                        let code: String = chars[i + run..end].iter().collect();
+                        out.push_str(code.trim());
+                        i = end + run;
+                    }
+                    None => {
+                        out.extend(&chars[i..i + run]);
+                        i += run;
+                    }
+                }
+            }
+            '!' if chars.get(i + 1) == Some(&'[') => match parse_link(&chars, i + 1) {
+                Some((label, end)) => {
+                    out.push_str(&strip_inline(&label));
+                    i = end;
+                }
+                None => {
+                    out.push(c);
+                    i += 1;
+                }
+            },
+            '[' => match parse_link(&chars, i) {
+                Some((label, end)) => {
+                    out.push_str(&strip_inline(&label));
+                    i = end;
+                }
+                None => {
+                    out.push(c);
+                    i += 1;
+                }
+            },
+            '<' => match parse_autolink(&chars, i) {
+                Some((target, end)) => {
+                    out.push_str(&target);
+                    i = end;
+                }
+                None => {
+                    out.push(c);
+                    i += 1;
+                }
+            },
+            '*' | '_' | '~' => {
+                let run = count_run(&chars, i, c);
+                let prev = i.checked_sub(1).map(|p| chars[p]);
+                let next = chars.get(i + run).copied();
+                let spaced = |ch: Option<char>| ch.is_none_or(char::is_whitespace);
+                let word = |ch: Option<char>| ch.is_some_and(char::is_alphanumeric);
+
+                let literal =
+                    (spaced(prev) && spaced(next)) || (c == '_' && word(prev) && word(next)) || (c == '~' && run != 2);
+                if literal {
+                    out.extend(&chars[i..i + run]);
+                }
+                i += run;
+            }
+            _ => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
+
+fn count_run(chars: &[char], start: usize, c: char) -> usize {
+    chars[start..].iter().take_while(|&&x| x == c).count()
+}
+
+/// Find the next run of exactly `len` `c` characters at or after `start`.
+fn find_run(chars: &[char], start: usize, c: char, len: usize) -> Option<usize> {
+    let mut i = start;
+    while i < chars.len() {
+        if chars[i] == c {
+            let run = count_run(chars, i, c);
+            if run == len {
+                return Some(i);
+            }
+            i += run;
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+/// Parse `[label](target)` or `[label][ref]` starting at `[`, returning the label
+/// and the index just past the link.
+fn parse_link(chars: &[char], open: usize) -> Option<(String, usize)> {
+    let close = matching(chars, open, '[', ']')?;
+    let label: String = chars[open + 1..close].iter().collect();
+
+    let end = match chars.get(close + 1) {
+        Some('(') => matching(chars, close + 1, '(', ')')? + 1,
+        Some('[') => matching(chars, close + 1, '[', ']')? + 1,
+        _ => return None,
+    };
+
+    Some((label, end))
+}
+
+/// Parse `<scheme:target>` starting at `<`, returning the target and the index past `>`.
+fn parse_autolink(chars: &[char], open: usize) -> Option<(String, usize)> {
+    let close = open + chars[open..].iter().position(|&c| c == '>')?;
+    let target: String = chars[open + 1..close].iter().collect();
+    let is_link = ["http://", "https://", "mailto:"]
+        .iter()
+        .any(|scheme| target.starts_with(scheme))
+        && !target.contains(char::is_whitespace);
+
+    is_link.then(|| (target.trim_start_matches("mailto:").to_string(), close + 1))
+}
+
+/// Index of the bracket closing the one at `open`, honouring nesting and escapes.
+fn matching(chars: &[char], open: usize, left: char, right: char) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            c if c == left => depth += 1,
+            c if c == right => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strips_block_decoration() {
+        let markdown = "# Title\n\nSome *emphasis* and **strong** text.\n\n- first\n- second\n  1. nested\n\n> quoted line\n\n---\n\nSetext\n======";
+        assert_eq!(
+            markdown_to_plain_text(markdown),
+            "Title\n\nSome emphasis and strong text.\n\nfirst\nsecond\nnested\n\nquoted line\n\nSetext"
+        );
+    }
+
+    #[test]
+    fn test_strips_inline_syntax() {
+        assert_eq!(
+            markdown_to_plain_text("See [the docs](https://example.com/a_(b)) and ![logo](logo.png)."),
+            "See the docs and logo."
+        );
+        assert_eq!(markdown_to_plain_text("Mail <mailto:a@b.org> now"), "Mail a@b.org now");
+        assert_eq!(
+            markdown_to_plain_text("Use `snake_case` ~~not~~ names"),
+            "Use snake_case not names"
+        );
+        assert_eq!(
+            markdown_to_plain_text("keep snake_case and 2 * 3"),
+            "keep snake_case and 2 * 3"
+        );
+        assert_eq!(markdown_to_plain_text(r"literal \*star\*"), "literal *star*");
+    }
+
+    #[test]
+    fn test_tables_become_tab_separated() {
+        let markdown = "| Name | Age |\n| --- | :-: |\n| **Alice** | 30 |\n| a \\| b | 1 |";
+        assert_eq!(markdown_to_plain_text(markdown), "Name\tAge\nAlice\t30\na | b\t1");
+    }
+
+    #[test]
+    fn test_code_fences_are_kept_verbatim() {
+        let markdown = "Intro\n\n```rust\nlet *x* = 1;\n```\n\nOutro";
+        assert_eq!(markdown_to_plain_text(markdown), "Intro\n\nlet *x* = 1;\n\nOutro");
+    }
+}
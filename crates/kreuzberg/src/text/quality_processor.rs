@@ -23,6 +23,8 @@ use std::borrow::Cow;
 /// - Runs in the Early processing stage
 /// - Calculates quality score when `config.enable_quality_processing` is true
 /// - Stores quality score in `metadata.additional["quality_score"]`
+/// - Stores a SHA-256 hash of the normalized content in `metadata.additional["content_hash"]`
+///   (see [`content_hash`](crate::text::content_hash) for the exact algorithm)
 /// - Cleans and normalizes extracted text
 ///
 /// # Example
@@ -71,6 +73,10 @@ impl PostProcessor for QualityProcessor {
                 serde_json::Number::from_f64(quality_score).unwrap_or(serde_json::Number::from(0)),
             ),
         );
+        result.metadata.additional.insert(
+            Cow::Borrowed("content_hash"),
+            serde_json::Value::String(crate::text::content_hash(&result.content)),
+        );
 
         Ok(())
     }
@@ -128,6 +134,11 @@ mod tests {
 	            ocr_elements: None,
 	            djot_content: None,
 	            document: None,
+	            outline: None,
+	            formulas: None,
+	            text_blocks: None,
+	            source_map: None,
+	            references: None,
 	        };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -135,6 +146,10 @@ mod tests {
         assert!(result.metadata.additional.contains_key("quality_score"));
         let score = result.metadata.additional.get("quality_score").unwrap();
         assert!(score.is_number());
+        assert_eq!(
+            result.metadata.additional.get("content_hash"),
+            Some(&serde_json::json!(crate::text::content_hash(&result.content)))
+        );
     }
 
     #[tokio::test]
@@ -158,6 +173,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -195,6 +215,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let config_with_quality = ExtractionConfig {
@@ -227,6 +252,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let long_result = ExtractionResult {
@@ -242,6 +272,11 @@ mod tests {
             elements: None,
             ocr_elements: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -0,0 +1,407 @@
+//! Heuristic parsing of a document's references section.
+//!
+//! Academic papers end with a list of cited works under a heading such as
+//! "References" or "Bibliography". This module locates the last such heading in the
+//! extracted text, splits the section into entries and pulls the authors, title, year
+//! and DOI out of each entry. Parsing is best-effort: the common numbered (`[1]`, `1.`)
+//! and author-year layouts (IEEE, APA, ACM) are recognized, and any field that cannot
+//! be identified is left empty while the full entry is kept in [`Reference::raw`].
+
+use crate::types::Reference;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Heading that opens a references section, optionally numbered or Markdown-prefixed.
+static SECTION_HEADING: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^\s*(?:#{1,6}\s*)?(?:[0-9ivx]+\.?\s+)?(?:references|bibliography|works cited|literature cited|reference list)\s*:?\s*$",
+    )
+    .expect("valid references heading regex")
+});
+
+/// Heading that closes a references section.
+static END_HEADING: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^\s*(?:#{1,6}\s*|[0-9ivx]+\.?\s+)?(?:appendix|appendices|acknowledge?ments?|supplementary)\b.{0,40}$",
+    )
+    .expect("valid end heading regex")
+});
+
+static MARKDOWN_HEADING: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*#{1,6}\s").expect("valid markdown heading regex"));
+
+static BRACKET_MARKER: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*\[(\d{1,3})\]\s*").expect("valid marker regex"));
+
+static NUMBER_MARKER: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*(\d{1,3})\.\s+").expect("valid marker regex"));
+
+static BULLET_MARKER: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*[-*•]\s+").expect("valid bullet regex"));
+
+/// Start of an author-year entry: a capitalized surname followed by a comma.
+static SURNAME_START: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\p{Lu}[\p{L}'’-]+,\s").expect("valid surname regex"));
+
+static DOI: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b10\.\d{4,9}/\S+").expect("valid DOI regex"));
+
+static PAREN_YEAR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\((1[5-9]\d{2}|20\d{2})[a-z]?\)").expect("valid year regex"));
+
+static YEAR: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(1[5-9]\d{2}|20\d{2})[a-z]?\b").expect("valid year regex"));
+
+static QUOTED_TITLE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"[“"]([^”"]{3,})[”"]"#).expect("valid title regex"));
+
+static AUTHOR_SEPARATOR: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+(?:and|&)\s+|;").expect("valid separator regex"));
+
+/// Entries shorter than this (in characters) are treated as noise.
+const MIN_ENTRY_CHARS: usize = 10;
+
+/// Name particles that may appear lowercase inside an author name.
+const NAME_PARTICLES: &[&str] = &[
+    "van", "von", "de", "der", "den", "da", "di", "du", "la", "le", "del", "dos",
+];
+
+/// Parse the references section of `content` into structured entries.
+///
+/// Returns an empty vector when no references heading is found.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::text::references::extract_references;
+///
+/// let content = "Introduction\n\nReferences\n\n[1] A. Smith, \"A study of things,\" 2020.\n";
+/// let references = extract_references(content);
+/// assert_eq!(references[0].title.as_deref(), Some("A study of things"));
+/// assert_eq!(references[0].year, Some(2020));
+/// ```
+pub fn extract_references(content: &str) -> Vec<Reference> {
+    let lines: Vec<&str> = content.lines().collect();
+    let Some(start) = lines.iter().rposition(|line| SECTION_HEADING.is_match(line)) else {
+        return Vec::new();
+    };
+
+    let section = &lines[start + 1..];
+    let end = section
+        .iter()
+        .position(|line| END_HEADING.is_match(line) || MARKDOWN_HEADING.is_match(line))
+        .unwrap_or(section.len());
+    let section = &section[..end];
+
+    let entries = split_numbered(section, &BRACKET_MARKER)
+        .or_else(|| split_numbered(section, &NUMBER_MARKER))
+        .unwrap_or_else(|| split_unnumbered(section));
+
+    entries.into_iter().filter_map(parse_entry).collect()
+}
+
+/// Split on sequential list markers (`[1]`, `[2]`, ... or `1.`, `2.`, ...).
+///
+/// Only markers continuing the sequence start a new entry, so numbers inside an entry
+/// (volumes, page ranges) are not mistaken for markers. Returns `None` if no marker
+/// starting the sequence is found.
+fn split_numbered(lines: &[&str], marker: &Regex) -> Option<Vec<String>> {
+    let mut entries: Vec<Vec<&str>> = Vec::new();
+    let mut expected = 1;
+
+    for &line in lines {
+        let next = marker
+            .captures(line)
+            .filter(|caps| caps[1].parse::<u32>().ok() == Some(expected));
+        match next {
+            Some(caps) => {
+                entries.push(vec![&line[caps[0].len()..]]);
+                expected += 1;
+            }
+            None => {
+                if let Some(entry) = entries.last_mut() {
+                    entry.push(line);
+                }
+            }
+        }
+    }
+
+    (!entries.is_empty()).then(|| entries.iter().map(|entry| join_lines(entry)).collect())
+}
+
+/// Split an unnumbered list on blank lines, bullets, and author-year entry starts.
+///
+/// A line starting with `Surname,` opens a new entry when the previous line ended a
+/// sentence, which separates entries that were laid out without blank lines between them.
+fn split_unnumbered(lines: &[&str]) -> Vec<String> {
+    let mut entries = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+
+    for &line in lines {
+        let trimmed = line.trim();
+        let bullet = BULLET_MARKER.find(line).map(|m| m.end());
+        let author_start =
+            current.last().is_some_and(|prev| prev.trim_end().ends_with('.')) && SURNAME_START.is_match(trimmed);
+
+        if (trimmed.is_empty() || bullet.is_some() || author_start) && !current.is_empty() {
+            entries.push(join_lines(&current));
+            current.clear();
+        }
+        if !trimmed.is_empty() {
+            current.push(bullet.map_or(line, |end| &line[end..]));
+        }
+    }
+    if !current.is_empty() {
+        entries.push(join_lines(&current));
+    }
+
+    entries
+}
+
+/// Join the lines of an entry, undoing end-of-line hyphenation and dropping bare page numbers.
+fn join_lines(lines: &[&str]) -> String {
+    let mut joined = String::new();
+
+    for line in lines {
+        let line = line.trim();
+        if line.is_empty() || line.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        let hyphenated = joined.ends_with('-')
+            && joined.chars().rev().nth(1).is_some_and(char::is_alphabetic)
+            && line.starts_with(|c: char| c.is_lowercase());
+        if hyphenated {
+            joined.pop();
+        } else if !joined.is_empty() {
+            joined.push(' ');
+        }
+        joined.push_str(line);
+    }
+
+    joined.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn parse_entry(raw: String) -> Option<Reference> {
+    if raw.chars().count() < MIN_ENTRY_CHARS {
+        return None;
+    }
+
+    let doi_match = DOI.find(&raw);
+    let doi = doi_match.map(|m| m.as_str().trim_end_matches(['.', ',', ';', ')', ']']).to_string());
+    let year = find_year(&raw, doi_match.map(|m| m.range()));
+
+    let (author_part, title) = if let Some(caps) = QUOTED_TITLE.captures(&raw) {
+        let quote_start = caps.get(0).map_or(0, |m| m.start());
+        (Some(&raw[..quote_start]), Some(clean_title(&caps[1])))
+    } else if let Some(paren) = PAREN_YEAR.find(&raw) {
+        let rest = raw[paren.end()..].trim_start_matches(['.', ',', ':', ' ']);
+        (Some(&raw[..paren.start()]), first_sentence(rest))
+    } else {
+        match author_prefix_end(&raw) {
+            Some(end) => (Some(&raw[..end]), first_sentence(&raw[end..])),
+            None => (None, None),
+        }
+    };
+
+    let authors = author_part.map(split_authors).unwrap_or_default();
+
+    Some(Reference {
+        raw,
+        authors,
+        title,
+        year,
+        doi,
+    })
+}
+
+/// Prefer a parenthesized year (author-year styles), otherwise the last standalone year
+/// outside the DOI and not part of a page range.
+fn find_year(raw: &str, doi_range: Option<std::ops::Range<usize>>) -> Option<u16> {
+    if let Some(caps) = PAREN_YEAR.captures(raw) {
+        return caps[1].parse().ok();
+    }
+
+    YEAR.captures_iter(raw)
+        .filter_map(|caps| caps.get(1))
+        .filter(|m| {
+            let in_doi = doi_range
+                .as_ref()
+                .is_some_and(|range| range.start <= m.start() && m.end() <= range.end);
+            let in_range = raw[..m.start()].ends_with(['-', '–']) || raw[m.end()..].starts_with(['-', '–']);
+            !in_doi && !in_range
+        })
+        .last()
+        .and_then(|m| m.as_str().parse().ok())
+}
+
+/// Byte offset just past the longest `. `-terminated prefix made up of author names.
+fn author_prefix_end(raw: &str) -> Option<usize> {
+    let mut end = None;
+
+    for (idx, _) in raw.match_indices(". ") {
+        let candidate = &raw[..=idx];
+        let names_only = candidate
+            .trim_end_matches('.')
+            .split(',')
+            .flat_map(|piece| AUTHOR_SEPARATOR.split(piece))
+            .map(str::trim)
+            .filter(|piece| !piece.is_empty() && *piece != "and" && *piece != "&")
+            .all(is_name_like);
+        if !names_only {
+            break;
+        }
+        end = Some(idx + 1);
+    }
+
+    end
+}
+
+fn is_name_like(piece: &str) -> bool {
+    if piece.eq_ignore_ascii_case("et al") {
+        return true;
+    }
+    let tokens: Vec<&str> = piece.split_whitespace().collect();
+    if tokens.is_empty() || tokens.len() > 4 {
+        return false;
+    }
+    let full_names = tokens.iter().filter(|token| !is_initials(token)).count();
+    full_names <= 3
+        && tokens
+            .iter()
+            .all(|token| NAME_PARTICLES.contains(token) || token.starts_with(|c: char| c.is_uppercase()))
+}
+
+/// Whether `token` is one or more initials, e.g. `J.`, `J`, `J.-P.` or `JR`.
+fn is_initials(token: &str) -> bool {
+    let letters: Vec<char> = token.chars().filter(|c| !matches!(c, '.' | '-')).collect();
+    !letters.is_empty() && letters.len() <= 3 && letters.iter().all(|c| c.is_uppercase())
+}
+
+/// Split an author list, rejoining `Surname, I.` pairs that a comma split apart.
+fn split_authors(part: &str) -> Vec<String> {
+    let part = part.trim().trim_end_matches([',', ':']).trim();
+    let mut authors: Vec<String> = Vec::new();
+    let mut last_is_bare_surname = false;
+
+    for piece in AUTHOR_SEPARATOR.split(part).flat_map(|piece| piece.split(',')) {
+        let piece = piece.trim();
+        if piece.is_empty() || piece.eq_ignore_ascii_case("et al.") || piece.eq_ignore_ascii_case("et al") {
+            continue;
+        }
+        let initials_only = piece.split_whitespace().all(is_initials);
+        if initials_only && last_is_bare_surname {
+            if let Some(last) = authors.last_mut() {
+                last.push_str(", ");
+                last.push_str(piece);
+            }
+            last_is_bare_surname = false;
+            continue;
+        }
+        last_is_bare_surname = !initials_only && piece.split_whitespace().all(|token| !is_initials(token));
+        authors.push(trim_name(piece).to_string());
+    }
+
+    authors
+}
+
+/// Drop a sentence-ending period unless it belongs to a trailing initial.
+fn trim_name(name: &str) -> &str {
+    match name.rsplit(' ').next() {
+        Some(last) if is_initials(last) => name,
+        _ => name.trim_end_matches('.'),
+    }
+}
+
+/// The first sentence of `text`, used as the title of author-year entries.
+fn first_sentence(text: &str) -> Option<String> {
+    let text = text.trim();
+    let end = text
+        .char_indices()
+        .find(|&(idx, c)| matches!(c, '.' | '?' | '!') && text[idx + c.len_utf8()..].starts_with(' '))
+        .map_or(text.len(), |(idx, c)| if c == '.' { idx } else { idx + c.len_utf8() });
+    let title = clean_title(&text[..end]);
+    (!title.is_empty()).then_some(title)
+}
+
+fn clean_title(title: &str) -> String {
+    title.trim().trim_end_matches([',', '.', ';', ':']).trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ieee_numbered_entries() {
+        let content = "Conclusion\nWe are done.\n\nREFERENCES\n\
+[1] A. Vaswani, N. Shazeer, and N. Parmar, \"Attention is all you need,\" in Proc. NeurIPS,\n\
+2017, pp. 5998–6008.\n\
+[2] K. He, X. Zhang, S. Ren, and J. Sun, \"Deep residual learning for image recog-\n\
+nition,\" in Proc. CVPR, 2016. doi: 10.1109/CVPR.2016.90.\n";
+
+        let references = extract_references(content);
+        assert_eq!(references.len(), 2);
+
+        assert_eq!(references[0].authors, vec!["A. Vaswani", "N. Shazeer", "N. Parmar"]);
+        assert_eq!(references[0].title.as_deref(), Some("Attention is all you need"));
+        assert_eq!(references[0].year, Some(2017));
+        assert_eq!(references[0].doi, None);
+
+        assert_eq!(
+            references[1].title.as_deref(),
+            Some("Deep residual learning for image recognition")
+        );
+        assert_eq!(references[1].authors.len(), 4);
+        assert_eq!(references[1].year, Some(2016));
+        assert_eq!(references[1].doi.as_deref(), Some("10.1109/CVPR.2016.90"));
+        assert!(references[1].raw.starts_with("K. He, X. Zhang"));
+    }
+
+    #[test]
+    fn test_apa_entries_without_markers() {
+        let content = "## References\n\n\
+Smith, J., & Doe, A. B. (2019). Learning to parse references. Journal of Documents, 12(3), 45–67.\n\
+https://doi.org/10.1000/jd.2019.003\n\n\
+Brown, T. (2020a). Are citations useful? Proceedings of Things.\n\n\
+## Appendix A\n\nNot a reference.\n";
+
+        let references = extract_references(content);
+        assert_eq!(references.len(), 2);
+
+        assert_eq!(references[0].authors, vec!["Smith, J.", "Doe, A. B."]);
+        assert_eq!(references[0].title.as_deref(), Some("Learning to parse references"));
+        assert_eq!(references[0].year, Some(2019));
+        assert_eq!(references[0].doi.as_deref(), Some("10.1000/jd.2019.003"));
+
+        assert_eq!(references[1].authors, vec!["Brown, T."]);
+        assert_eq!(references[1].title.as_deref(), Some("Are citations useful?"));
+        assert_eq!(references[1].year, Some(2020));
+    }
+
+    #[test]
+    fn test_consecutive_author_year_lines_are_split() {
+        let content = "Bibliography\n\
+Knuth, D. E. The Art of Computer Programming. Addison-Wesley, 1968.\n\
+Lamport, L. LaTeX: A Document Preparation System. Addison-Wesley,\n\
+1994.\n";
+
+        let references = extract_references(content);
+        assert_eq!(references.len(), 2);
+        assert_eq!(references[0].authors, vec!["Knuth, D. E."]);
+        assert_eq!(references[0].title.as_deref(), Some("The Art of Computer Programming"));
+        assert_eq!(references[0].year, Some(1968));
+        assert_eq!(references[1].year, Some(1994));
+    }
+
+    #[test]
+    fn test_numbered_list_and_section_end() {
+        let content = "7. References\n\
+1. Lovelace, A. Notes on the analytical engine. Scientific Memoirs, 1843.\n\
+2. Turing, A. M. On computable numbers. Proc. London Math. Soc., 1936.\n\
+Acknowledgements\n\
+3. This line is not an entry of the list at all.\n";
+
+        let references = extract_references(content);
+        assert_eq!(references.len(), 2);
+        assert_eq!(references[0].year, Some(1843));
+        assert_eq!(references[1].authors, vec!["Turing, A. M."]);
+        assert_eq!(references[1].title.as_deref(), Some("On computable numbers"));
+    }
+
+    #[test]
+    fn test_without_references_section() {
+        assert!(extract_references("Just some text.\nWith references to nothing.\n").is_empty());
+        assert!(extract_references("References\n\n[1]\n").is_empty());
+    }
+}
@@ -98,3 +98,62 @@ impl TokenReductionConfig {
         }
     }
 }
+
+/// Words kept by `preserve_important_words`: capitalized words, and technical terms
+/// such as `snake_case`, `camelCase`, `file.rs` or `std::fs`.
+const IMPORTANT_WORD_PATTERNS: &[&str] = &[r"^\p{Lu}", r"\p{Ll}\p{Lu}", r"\w[_./:]+\w"];
+
+impl From<&crate::core::config::TokenReductionConfig> for TokenReductionConfig {
+    fn from(config: &crate::core::config::TokenReductionConfig) -> Self {
+        let preserve_patterns = if config.preserve_important_words {
+            IMPORTANT_WORD_PATTERNS
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            level: ReductionLevel::from(config.mode.as_str()),
+            language_hint: config.language.clone(),
+            preserve_patterns,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+
+    #[test]
+    fn test_from_extraction_config() {
+        let extraction_config = crate::core::config::TokenReductionConfig {
+            mode: "aggressive".to_string(),
+            preserve_important_words: true,
+            language: Some("deu".to_string()),
+        };
+
+        let config = TokenReductionConfig::from(&extraction_config);
+        assert_eq!(config.level, ReductionLevel::Aggressive);
+        assert_eq!(config.language_hint.as_deref(), Some("deu"));
+
+        let patterns: Vec<Regex> = config
+            .preserve_patterns
+            .iter()
+            .map(|pattern| Regex::new(pattern).unwrap())
+            .collect();
+        for word in ["Berlin", "parse_config", "camelCase", "std::fs", "main.rs"] {
+            assert!(patterns.iter().any(|pattern| pattern.is_match(word)), "{word}");
+        }
+        assert!(!patterns.iter().any(|pattern| pattern.is_match("the")));
+
+        let config = TokenReductionConfig::from(&crate::core::config::TokenReductionConfig {
+            preserve_important_words: false,
+            ..extraction_config
+        });
+        assert!(config.preserve_patterns.is_empty());
+    }
+}
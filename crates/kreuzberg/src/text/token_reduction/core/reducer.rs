@@ -25,9 +25,10 @@ pub struct TokenReducer {
 impl TokenReducer {
     pub fn new(config: &TokenReductionConfig, language_hint: Option<&str>) -> Result<Self> {
         let config = Arc::new(config.clone());
-        let language = language_hint
-            .or(config.language_hint.as_deref())
-            .unwrap_or("en")
+        let language = language_hint.or(config.language_hint.as_deref()).unwrap_or("en");
+        // Map ISO 639-3 codes and locales ("deu", "de-AT") to the stopword list key.
+        let language = crate::stopwords::resolve_stopwords_language(language)
+            .unwrap_or(language)
             .to_string();
 
         let text_processor = SimdTextProcessor::new();
@@ -0,0 +1,140 @@
+//! Token counting with HuggingFace tokenizers.
+//!
+//! Counting tokens is a cheap pre-flight check before sending extracted text to an
+//! LLM, e.g. to decide whether chunking is needed at all.
+//!
+//! A model is either a HuggingFace Hub repository id (e.g. `"bert-base-uncased"`),
+//! whose `tokenizer.json` is downloaded on first use, or a path to a local
+//! `tokenizer.json` file. Loaded tokenizers are cached for the lifetime of the process.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use kreuzberg::tokenize::count_tokens;
+//!
+//! let tokens = count_tokens("Hello, world!", "bert-base-uncased")?;
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+use tokenizers::Tokenizer;
+
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+
+/// Loaded tokenizers keyed by model id or file path.
+static TOKENIZER_CACHE: Lazy<RwLock<HashMap<String, Arc<Tokenizer>>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Count the tokens `text` encodes to with the tokenizer of `model`.
+///
+/// Special tokens (e.g. `[CLS]`, `[SEP]`) are not included in the count.
+pub fn count_tokens(text: &str, model: &str) -> Result<usize> {
+    let tokenizer = get_or_load_tokenizer(model)?;
+    let encoding = tokenizer
+        .encode(text, false)
+        .map_err(|e| KreuzbergError::Other(format!("Failed to tokenize text with '{}': {}", model, e)))?;
+    Ok(encoding.len())
+}
+
+impl ExtractionResult {
+    /// Count the tokens in `content` with the tokenizer of `model`.
+    ///
+    /// See [`count_tokens`] for how `model` is resolved.
+    pub fn token_count(&self, model: &str) -> Result<usize> {
+        count_tokens(&self.content, model)
+    }
+}
+
+fn get_or_load_tokenizer(model: &str) -> Result<Arc<Tokenizer>> {
+    if let Some(tokenizer) = TOKENIZER_CACHE
+        .read()
+        .map_err(|e| KreuzbergError::LockPoisoned(e.to_string()))?
+        .get(model)
+    {
+        return Ok(Arc::clone(tokenizer));
+    }
+
+    let tokenizer = Arc::new(load_tokenizer(model)?);
+    let mut cache = TOKENIZER_CACHE
+        .write()
+        .map_err(|e| KreuzbergError::LockPoisoned(e.to_string()))?;
+    Ok(Arc::clone(cache.entry(model.to_string()).or_insert(tokenizer)))
+}
+
+fn load_tokenizer(model: &str) -> Result<Tokenizer> {
+    let local_path = Path::new(model);
+    let tokenizer_path = if local_path.is_file() {
+        local_path.to_path_buf()
+    } else {
+        let api = hf_hub::api::sync::ApiBuilder::new()
+            .with_progress(false)
+            .build()
+            .map_err(|e| KreuzbergError::Other(format!("Failed to initialize HuggingFace Hub API: {}", e)))?;
+        api.model(model.to_string())
+            .get("tokenizer.json")
+            .map_err(|e| KreuzbergError::validation(format!("Failed to load tokenizer for '{}': {}", model, e)))?
+    };
+
+    Tokenizer::from_file(&tokenizer_path).map_err(|e| {
+        KreuzbergError::validation(format!("Invalid tokenizer file '{}': {}", tokenizer_path.display(), e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// Minimal word-level tokenizer that splits on whitespace and punctuation.
+    const WORD_LEVEL_TOKENIZER: &str = r#"{
+        "version": "1.0",
+        "truncation": null,
+        "padding": null,
+        "added_tokens": [],
+        "normalizer": null,
+        "pre_tokenizer": {"type": "Whitespace"},
+        "post_processor": null,
+        "decoder": null,
+        "model": {"type": "WordLevel", "vocab": {"[UNK]": 0, "hello": 1, "world": 2}, "unk_token": "[UNK]"}
+    }"#;
+
+    fn write_tokenizer() -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(WORD_LEVEL_TOKENIZER.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_count_tokens_with_local_tokenizer() {
+        let file = write_tokenizer();
+        let model = file.path().to_str().unwrap();
+
+        assert_eq!(count_tokens("hello world", model).unwrap(), 2);
+        assert_eq!(count_tokens("hello world, unknown words!", model).unwrap(), 6);
+        assert_eq!(count_tokens("", model).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_tokenizer_is_cached() {
+        let file = write_tokenizer();
+        let model = file.path().to_str().unwrap();
+
+        let first = get_or_load_tokenizer(model).unwrap();
+        let second = get_or_load_tokenizer(model).unwrap();
+
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn test_invalid_tokenizer_file_is_rejected() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"not a tokenizer").unwrap();
+
+        let result = count_tokens("hello", file.path().to_str().unwrap());
+
+        assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+    }
+}
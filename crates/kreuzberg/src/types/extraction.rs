@@ -7,10 +7,15 @@ use std::collections::HashMap;
 
 use super::djot::DjotContent;
 use super::document_structure::DocumentStructure;
+use super::formula::Formula;
 use super::metadata::Metadata;
 use super::ocr_elements::OcrElement;
+use super::outline::OutlineNode;
 use super::page::PageContent;
+use super::reference::Reference;
+use super::source_map::SourceSpan;
 use super::tables::Table;
+use super::text_block::TextBlock;
 
 /// General extraction result used by the core extraction API.
 ///
@@ -106,6 +111,81 @@ pub struct ExtractionResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub document: Option<DocumentStructure>,
+
+    /// Document outline (when `extract_outline` is enabled).
+    ///
+    /// Built from PDF bookmarks or DOCX heading styles. `None` when outline
+    /// extraction is disabled or the format has no outline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub outline: Option<Vec<OutlineNode>>,
+
+    /// Mathematical formulas recognized as LaTeX (when `PdfConfig.extract_math` is enabled).
+    ///
+    /// Requires the `math-ocr` feature. The garbled text of a formula stays in `content`;
+    /// this list carries the recognized LaTeX with its page and location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub formulas: Option<Vec<Formula>>,
+
+    /// Text blocks with page numbers and bounding boxes (when `emit_positions` is enabled).
+    ///
+    /// PDFs provide blocks from their text layer; OCR results provide them from the
+    /// recognized OCR elements. Other formats leave this unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub text_blocks: Option<Vec<TextBlock>>,
+
+    /// Ranges of `content` mapped to positions in the source (when `emit_source_offsets`
+    /// is enabled).
+    ///
+    /// PDFs map lines of their text layer to page character indices; HTML maps text nodes
+    /// to byte offsets. Text that cannot be located in `content` has no span. Other formats
+    /// leave this unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub source_map: Option<Vec<SourceSpan>>,
+
+    /// Entries of the references section (when `extract_references` is enabled).
+    ///
+    /// Requires the `references` feature. Parsed heuristically from the content of
+    /// academic papers, before `max_content_chars` truncation; `None` when no references
+    /// section was found.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub references: Option<Vec<Reference>>,
+}
+
+impl ExtractionResult {
+    /// Move the result out, leaving an empty result with the same MIME type in its place.
+    ///
+    /// Lets plugin bridges hand a result to a conversion that consumes it without
+    /// cloning it first (see `ExtractionConfig::low_memory`).
+    pub fn take(&mut self) -> ExtractionResult {
+        let mime_type = self.mime_type.clone();
+        std::mem::replace(
+            self,
+            ExtractionResult {
+                content: String::new(),
+                mime_type,
+                metadata: Metadata::default(),
+                tables: vec![],
+                detected_languages: None,
+                chunks: None,
+                images: None,
+                pages: None,
+                djot_content: None,
+                elements: None,
+                ocr_elements: None,
+                document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
+            },
+        )
+    }
 }
 
 /// A text chunk with optional embedding and metadata.
@@ -163,6 +243,13 @@ pub struct ChunkMetadata {
     /// Only populated when page tracking is enabled in extraction configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_page: Option<usize>,
+
+    /// Titles of the Markdown headings this chunk falls under, outermost first
+    /// (e.g. `["Chapter 2", "Installation"]`).
+    ///
+    /// Only populated by the Markdown chunker, for chunks that follow a heading.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heading_path: Option<Vec<String>>,
 }
 
 /// Extracted image from a document.
@@ -214,6 +301,22 @@ pub struct ExtractedImage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// Alternative text authored for the image (e.g. the PPTX `descr` attribute)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_text: Option<String>,
+
+    /// Caption generated for the image by a registered `ImageCaptioner` plugin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Top-left corner of the image on its page as `(x, y)`, normalized to 0..1 with
+    /// the origin at the top-left of the page
+    ///
+    /// Set for PDF images drawn directly by the page content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "api", schema(value_type = Option<Vec<f32>>))]
+    pub page_position: Option<(f32, f32)>,
+
     /// Nested OCR extraction result (if image was OCRed)
     ///
     /// When OCR is performed on this image, the result is embedded here
@@ -18,6 +18,9 @@ pub struct ExcelWorkbook {
     pub sheets: Vec<ExcelSheet>,
     /// Workbook-level metadata (author, creation date, etc.)
     pub metadata: HashMap<String, String>,
+    /// Names of the hidden and very hidden sheets, whether or not they were extracted
+    #[serde(default)]
+    pub hidden_sheets: Vec<String>,
 }
 
 /// Single Excel worksheet.
@@ -99,6 +102,9 @@ pub struct PptxExtractionResult {
     pub table_count: usize,
     /// Extracted images from the presentation
     pub images: Vec<ExtractedImage>,
+    /// Whether image extraction stopped early because an image limit was reached
+    #[serde(default)]
+    pub images_truncated: bool,
     /// Slide structure with boundaries (when page tracking is enabled)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_structure: Option<PageStructure>,
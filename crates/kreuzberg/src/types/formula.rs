@@ -0,0 +1,68 @@
+//! Mathematical formulas recognized in documents.
+
+use serde::{Deserialize, Serialize};
+
+use super::extraction::BoundingBox;
+
+/// Whether a formula sits inside a line of text or on lines of its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FormulaKind {
+    /// Part of a line of running text (`$...$`).
+    Inline,
+    /// Set apart from the surrounding text (`$$...$$`).
+    Display,
+}
+
+/// A formula recognized as LaTeX.
+///
+/// Produced by PDF extraction when `PdfConfig.extract_math` is enabled and the
+/// `math-ocr` feature is compiled in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct Formula {
+    /// LaTeX source of the formula, without surrounding `$` delimiters.
+    pub latex: String,
+    /// Inline or display formula.
+    pub kind: FormulaKind,
+    /// 1-indexed page the formula is on.
+    pub page_number: usize,
+    /// Region of the page the formula was recognized from, in PDF points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounding_box: Option<BoundingBox>,
+}
+
+impl Formula {
+    /// The formula wrapped in Markdown math delimiters: `$...$` for inline
+    /// formulas and `$$...$$` for display formulas.
+    pub fn to_markdown(&self) -> String {
+        match self.kind {
+            FormulaKind::Inline => format!("${}$", self.latex),
+            FormulaKind::Display => format!("$${}$$", self.latex),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formula_to_markdown_uses_kind_delimiters() {
+        let mut formula = Formula {
+            latex: r"\frac{a}{b}".to_string(),
+            kind: FormulaKind::Inline,
+            page_number: 1,
+            bounding_box: None,
+        };
+        assert_eq!(formula.to_markdown(), r"$\frac{a}{b}$");
+
+        formula.kind = FormulaKind::Display;
+        assert_eq!(formula.to_markdown(), r"$$\frac{a}{b}$$");
+        assert_eq!(
+            serde_json::to_value(&formula).unwrap(),
+            serde_json::json!({"latex": r"\frac{a}{b}", "kind": "display", "page_number": 1})
+        );
+    }
+}
@@ -0,0 +1,561 @@
+//! Merging of extraction results produced from parts of the same document.
+//!
+//! Used to stitch back results that were extracted in parallel from page ranges
+//! (map-reduce extraction). Page numbers and byte offsets of each part are shifted
+//! past the parts that precede it.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use super::extraction::ExtractionResult;
+use super::metadata::Metadata;
+use super::outline::OutlineNode;
+use super::page::PageStructure;
+
+/// Separator inserted between the content of consecutive parts.
+const PART_SEPARATOR: &str = "\n\n";
+
+impl ExtractionResult {
+    /// Merge results extracted from consecutive parts of one document into a single result.
+    ///
+    /// Parts are concatenated in order, separated by a blank line. For every part after the
+    /// first, byte offsets (page boundaries, chunk offsets, source map ranges) are shifted
+    /// by the length of the preceding content. Parts extracted with
+    /// `PdfConfig.page_range` already carry the page numbers of the original document
+    /// and keep them; page numbers of a part that starts over at an earlier page are
+    /// shifted to follow the last page of the preceding parts.
+    ///
+    /// - Tables, images, pages, chunks, elements and OCR elements are concatenated and
+    ///   renumbered (`image_index`, `chunk_index`/`total_chunks`, `element_index`).
+    ///   References are concatenated.
+    /// - Detected languages are unioned in order of first appearance.
+    /// - For conflicting metadata the first non-empty value wins; page structure is combined.
+    /// - `djot_content` and `document` cannot be concatenated and are taken from the first
+    ///   part that has them.
+    ///
+    /// The MIME type is taken from the first part. Merging an empty vector yields an empty
+    /// `text/plain` result.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kreuzberg::ExtractionResult;
+    ///
+    /// # fn example(parts: Vec<ExtractionResult>) {
+    /// // `parts` were extracted concurrently from consecutive page ranges.
+    /// let merged = ExtractionResult::merge(parts);
+    /// println!("{}", merged.content);
+    /// # }
+    /// ```
+    pub fn merge(results: Vec<ExtractionResult>) -> ExtractionResult {
+        let mut results = results.into_iter();
+        let Some(mut merged) = results.next() else {
+            return ExtractionResult {
+                content: String::new(),
+                mime_type: Cow::Borrowed("text/plain"),
+                metadata: Metadata::default(),
+                tables: vec![],
+                detected_languages: None,
+                chunks: None,
+                images: None,
+                pages: None,
+                djot_content: None,
+                elements: None,
+                ocr_elements: None,
+                document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
+            };
+        };
+
+        let (first_page, mut last_page) = part_page_span(&merged);
+        let mut page_count = last_page - first_page + 1;
+        for part in results {
+            let (first_page, part_last_page) = part_page_span(&part);
+            let page_offset = (last_page + 1).saturating_sub(first_page);
+            page_count += part_last_page - first_page + 1;
+            last_page = part_last_page + page_offset;
+            merged.append_part(part, page_offset);
+        }
+
+        if let Some(structure) = merged.metadata.pages.as_mut() {
+            structure.total_count = page_count;
+        }
+        if let Some(images) = merged.images.as_mut() {
+            for (index, image) in images.iter_mut().enumerate() {
+                image.image_index = index;
+            }
+        }
+        if let Some(chunks) = merged.chunks.as_mut() {
+            let total = chunks.len();
+            for (index, chunk) in chunks.iter_mut().enumerate() {
+                chunk.metadata.chunk_index = index;
+                chunk.metadata.total_chunks = total;
+            }
+        }
+        if let Some(elements) = merged.elements.as_mut() {
+            for (index, element) in elements.iter_mut().enumerate() {
+                if element.metadata.element_index.is_some() {
+                    element.metadata.element_index = Some(index);
+                }
+            }
+        }
+
+        merged
+    }
+
+    /// Add `offset` to every page number in the result.
+    ///
+    /// Covers tables, images, pages, chunks, elements, OCR elements, the outline,
+    /// formulas, text blocks, the source map and the page structure in metadata. Byte
+    /// offsets are left untouched.
+    pub(crate) fn shift_page_numbers(&mut self, offset: usize) {
+        if offset == 0 {
+            return;
+        }
+
+        for table in &mut self.tables {
+            table.page_number += offset;
+        }
+
+        if let Some(images) = self.images.as_mut() {
+            for image in images.iter_mut() {
+                shift_page(&mut image.page_number, offset);
+            }
+        }
+
+        if let Some(pages) = self.pages.as_mut() {
+            for page in pages.iter_mut() {
+                page.page_number += offset;
+                for table in page.tables.iter_mut() {
+                    Arc::make_mut(table).page_number += offset;
+                }
+                for image in page.images.iter_mut() {
+                    shift_page(&mut Arc::make_mut(image).page_number, offset);
+                }
+            }
+        }
+
+        if let Some(chunks) = self.chunks.as_mut() {
+            for chunk in chunks.iter_mut() {
+                shift_page(&mut chunk.metadata.first_page, offset);
+                shift_page(&mut chunk.metadata.last_page, offset);
+            }
+        }
+
+        if let Some(elements) = self.elements.as_mut() {
+            for element in elements.iter_mut() {
+                shift_page(&mut element.metadata.page_number, offset);
+            }
+        }
+
+        if let Some(ocr_elements) = self.ocr_elements.as_mut() {
+            for element in ocr_elements.iter_mut() {
+                element.page_number += offset;
+            }
+        }
+
+        if let Some(outline) = self.outline.as_mut() {
+            shift_outline_pages(outline, offset);
+        }
+
+        if let Some(formulas) = self.formulas.as_mut() {
+            for formula in formulas.iter_mut() {
+                formula.page_number += offset;
+            }
+        }
+
+        if let Some(text_blocks) = self.text_blocks.as_mut() {
+            for block in text_blocks.iter_mut() {
+                block.page += offset;
+            }
+        }
+
+        if let Some(source_map) = self.source_map.as_mut() {
+            for span in source_map.iter_mut() {
+                shift_page(&mut span.page, offset);
+            }
+        }
+
+        if let Some(structure) = self.metadata.pages.as_mut() {
+            if let Some(boundaries) = structure.boundaries.as_mut() {
+                for boundary in boundaries.iter_mut() {
+                    boundary.page_number += offset;
+                }
+            }
+            if let Some(pages) = structure.pages.as_mut() {
+                for page in pages.iter_mut() {
+                    page.number += offset;
+                }
+            }
+        }
+    }
+
+    /// Append one part, shifting its pages by `page_offset`.
+    fn append_part(&mut self, mut part: ExtractionResult, page_offset: usize) {
+        part.shift_page_numbers(page_offset);
+
+        let byte_offset = if self.content.is_empty() || part.content.is_empty() {
+            self.content.len()
+        } else {
+            self.content.push_str(PART_SEPARATOR);
+            self.content.len()
+        };
+        self.content.push_str(&part.content);
+
+        self.tables.append(&mut part.tables);
+        extend_option(&mut self.images, part.images);
+        extend_option(&mut self.pages, part.pages);
+
+        if let Some(chunks) = part.chunks.as_mut() {
+            for chunk in chunks.iter_mut() {
+                chunk.metadata.byte_start += byte_offset;
+                chunk.metadata.byte_end += byte_offset;
+            }
+        }
+        extend_option(&mut self.chunks, part.chunks);
+        extend_option(&mut self.elements, part.elements);
+        extend_option(&mut self.ocr_elements, part.ocr_elements);
+        extend_option(&mut self.outline, part.outline);
+        extend_option(&mut self.formulas, part.formulas);
+        extend_option(&mut self.text_blocks, part.text_blocks);
+
+        if let Some(source_map) = part.source_map.as_mut() {
+            for span in source_map.iter_mut() {
+                span.content_start += byte_offset;
+                span.content_end += byte_offset;
+            }
+        }
+        extend_option(&mut self.source_map, part.source_map);
+        extend_option(&mut self.references, part.references);
+
+        if let Some(languages) = part.detected_languages {
+            let merged = self.detected_languages.get_or_insert_with(Vec::new);
+            for language in languages {
+                if !merged.contains(&language) {
+                    merged.push(language);
+                }
+            }
+        }
+
+        self.djot_content = self.djot_content.take().or(part.djot_content);
+        self.document = self.document.take().or(part.document);
+
+        let part_pages = part.metadata.pages.take();
+        merge_page_structure(&mut self.metadata.pages, part_pages, byte_offset);
+        merge_metadata(&mut self.metadata, part.metadata);
+    }
+}
+
+/// First and last page a part covers, from the best pagination information available.
+///
+/// Parts without any page numbers cover page 1, or as many pages from page 1 as their
+/// page structure declares.
+fn part_page_span(result: &ExtractionResult) -> (usize, usize) {
+    let structure = result.metadata.pages.as_ref();
+    let page_numbers = structure
+        .and_then(|pages| pages.boundaries.as_ref())
+        .into_iter()
+        .flatten()
+        .map(|boundary| boundary.page_number)
+        .chain(result.pages.iter().flatten().map(|page| page.page_number))
+        .chain(result.tables.iter().map(|table| table.page_number))
+        .chain(result.images.iter().flatten().filter_map(|image| image.page_number))
+        .filter(|&page| page > 0);
+
+    let (first, last) = page_numbers.fold((usize::MAX, 0), |(first, last), page| (first.min(page), last.max(page)));
+    let first = if first == usize::MAX { 1 } else { first };
+    let declared = structure.map_or(0, |pages| pages.total_count).max(1);
+    (first, last.max(first + declared - 1))
+}
+
+fn shift_page(page: &mut Option<usize>, offset: usize) {
+    if let Some(page) = page.as_mut() {
+        *page += offset;
+    }
+}
+
+fn shift_outline_pages(nodes: &mut [OutlineNode], offset: usize) {
+    for node in nodes {
+        shift_page(&mut node.page_number, offset);
+        shift_outline_pages(&mut node.children, offset);
+    }
+}
+
+fn extend_option<T>(target: &mut Option<Vec<T>>, items: Option<Vec<T>>) {
+    if let Some(items) = items {
+        target.get_or_insert_with(Vec::new).extend(items);
+    }
+}
+
+fn merge_page_structure(target: &mut Option<PageStructure>, part: Option<PageStructure>, byte_offset: usize) {
+    let Some(mut part) = part else {
+        return;
+    };
+
+    if let Some(boundaries) = part.boundaries.as_mut() {
+        for boundary in boundaries.iter_mut() {
+            boundary.byte_start += byte_offset;
+            boundary.byte_end += byte_offset;
+        }
+    }
+
+    match target {
+        Some(target) => {
+            extend_option(&mut target.boundaries, part.boundaries);
+            extend_option(&mut target.pages, part.pages);
+        }
+        None => *target = Some(part),
+    }
+}
+
+/// Fill metadata fields that are still empty from a later part.
+fn merge_metadata(target: &mut Metadata, part: Metadata) {
+    fill_string(&mut target.title, part.title);
+    fill_string(&mut target.subject, part.subject);
+    fill_vec(&mut target.authors, part.authors);
+    fill_vec(&mut target.keywords, part.keywords);
+    fill_string(&mut target.language, part.language);
+    fill_string(&mut target.created_at, part.created_at);
+    fill_string(&mut target.modified_at, part.modified_at);
+    fill_string(&mut target.created_by, part.created_by);
+    fill_string(&mut target.modified_by, part.modified_by);
+    target.format = target.format.take().or(part.format);
+    target.image_preprocessing = target.image_preprocessing.take().or(part.image_preprocessing);
+    target.json_schema = target.json_schema.take().or(part.json_schema);
+    target.error = target.error.take().or(part.error);
+    target.extraction_duration_ms = target.extraction_duration_ms.or(part.extraction_duration_ms);
+    target.truncated = target.truncated.or(part.truncated);
+    target.ocr_used |= part.ocr_used;
+    target.ocr_page_count = match (target.ocr_page_count, part.ocr_page_count) {
+        (Some(a), Some(b)) => Some(a + b),
+        (a, b) => a.or(b),
+    };
+    target.ocr_language = target.ocr_language.take().or(part.ocr_language);
+    target.ocr_backend = target.ocr_backend.take().or(part.ocr_backend);
+    target.extractor_name = target.extractor_name.take().or(part.extractor_name);
+
+    for (key, value) in part.additional {
+        let is_empty = target.additional.get(&key).is_none_or(is_empty_value);
+        if is_empty && !is_empty_value(&value) {
+            target.additional.insert(key, value);
+        }
+    }
+}
+
+fn fill_string(target: &mut Option<String>, value: Option<String>) {
+    if target.as_deref().is_none_or(str::is_empty)
+        && let Some(value) = value.filter(|value| !value.is_empty())
+    {
+        *target = Some(value);
+    }
+}
+
+fn fill_vec<T>(target: &mut Option<Vec<T>>, value: Option<Vec<T>>) {
+    if target.as_ref().is_none_or(Vec::is_empty)
+        && let Some(value) = value.filter(|value| !value.is_empty())
+    {
+        *target = Some(value);
+    }
+}
+
+fn is_empty_value(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Null => true,
+        serde_json::Value::String(s) => s.is_empty(),
+        serde_json::Value::Array(items) => items.is_empty(),
+        serde_json::Value::Object(map) => map.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Chunk, ChunkMetadata, PageBoundary, PageUnitType, Table};
+
+    fn part(content: &str, pages: usize) -> ExtractionResult {
+        part_from_page(content, 1, pages)
+    }
+
+    /// A part whose pages are numbered from `first_page`, like a page range extraction.
+    fn part_from_page(content: &str, first_page: usize, pages: usize) -> ExtractionResult {
+        let mut boundaries = Vec::new();
+        let mut start = 0;
+        for (index, line) in content.split_inclusive('\n').enumerate().take(pages) {
+            boundaries.push(PageBoundary {
+                byte_start: start,
+                byte_end: start + line.len(),
+                page_number: first_page + index,
+            });
+            start += line.len();
+        }
+
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: Cow::Borrowed("application/pdf"),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: pages,
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(boundaries),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            djot_content: None,
+            elements: None,
+            ocr_elements: None,
+            document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
+        }
+    }
+
+    fn table(page_number: usize) -> Table {
+        Table {
+            cells: vec![vec!["a".to_string()]],
+            markdown: "| a |".to_string(),
+            page_number,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_merge_empty() {
+        let merged = ExtractionResult::merge(vec![]);
+        assert!(merged.content.is_empty());
+        assert_eq!(merged.mime_type, "text/plain");
+    }
+
+    #[test]
+    fn test_merge_recomputes_page_boundaries() {
+        let merged = ExtractionResult::merge(vec![part("one\ntwo", 2), part("three", 1)]);
+
+        assert_eq!(merged.content, "one\ntwo\n\nthree");
+        let pages = merged.metadata.pages.expect("page structure");
+        assert_eq!(pages.total_count, 3);
+        let boundaries = pages.boundaries.expect("boundaries");
+        assert_eq!(boundaries.len(), 3);
+        assert_eq!(boundaries[2].page_number, 3);
+        assert_eq!(
+            &merged.content[boundaries[2].byte_start..boundaries[2].byte_end],
+            "three"
+        );
+    }
+
+    #[test]
+    fn test_merge_shifts_each_part_once() {
+        let merged = ExtractionResult::merge(vec![part("a", 1), part("b\nc", 2), part("d", 1), part("e", 1)]);
+
+        let pages = merged.metadata.pages.expect("page structure");
+        assert_eq!(pages.total_count, 5);
+        let page_numbers: Vec<usize> = pages
+            .boundaries
+            .expect("boundaries")
+            .iter()
+            .map(|boundary| boundary.page_number)
+            .collect();
+        assert_eq!(page_numbers, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_merge_keeps_page_range_numbers() {
+        let mut second = part_from_page("c\nd", 3, 2);
+        second.tables.push(table(4));
+        let merged = ExtractionResult::merge(vec![part_from_page("a\nb", 1, 2), second, part_from_page("e\nf", 5, 2)]);
+
+        let pages = merged.metadata.pages.expect("page structure");
+        assert_eq!(pages.total_count, 6);
+        let boundaries = pages.boundaries.expect("boundaries");
+        let page_numbers: Vec<usize> = boundaries.iter().map(|boundary| boundary.page_number).collect();
+        assert_eq!(page_numbers, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(&merged.content[boundaries[4].byte_start..boundaries[4].byte_end], "e\n");
+        assert_eq!(merged.tables[0].page_number, 4);
+    }
+
+    #[test]
+    fn test_merge_shifts_tables_and_chunks() {
+        let mut first = part("one\ntwo", 2);
+        first.tables.push(table(2));
+        let mut second = part("three", 1);
+        second.tables.push(table(1));
+        second.chunks = Some(vec![Chunk {
+            content: "three".to_string(),
+            embedding: None,
+            metadata: ChunkMetadata {
+                byte_start: 0,
+                byte_end: 5,
+                token_count: None,
+                chunk_index: 0,
+                total_chunks: 1,
+                first_page: Some(1),
+                last_page: Some(1),
+                heading_path: None,
+            },
+        }]);
+
+        let merged = ExtractionResult::merge(vec![first, second]);
+
+        assert_eq!(
+            merged.tables.iter().map(|t| t.page_number).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        let chunk = &merged.chunks.expect("chunks")[0];
+        assert_eq!(
+            &merged.content[chunk.metadata.byte_start..chunk.metadata.byte_end],
+            "three"
+        );
+        assert_eq!(chunk.metadata.first_page, Some(3));
+        assert_eq!(chunk.metadata.total_chunks, 1);
+    }
+
+    #[test]
+    fn test_merge_metadata_prefers_first_non_empty() {
+        let mut first = part("a", 1);
+        first.metadata.title = Some(String::new());
+        first.detected_languages = Some(vec!["eng".to_string()]);
+        let mut second = part("b", 1);
+        second.metadata.title = Some("Second".to_string());
+        second.metadata.subject = Some("Subject".to_string());
+        second.detected_languages = Some(vec!["deu".to_string(), "eng".to_string()]);
+
+        let merged = ExtractionResult::merge(vec![first, second]);
+
+        assert_eq!(merged.metadata.title.as_deref(), Some("Second"));
+        assert_eq!(merged.metadata.subject.as_deref(), Some("Subject"));
+        assert_eq!(
+            merged.detected_languages,
+            Some(vec!["eng".to_string(), "deu".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_merge_metadata_combines_ocr_usage() {
+        let first = part("a", 1);
+        let mut second = part("b", 1);
+        second.metadata.ocr_used = true;
+        second.metadata.ocr_page_count = Some(1);
+        let mut third = part("c", 1);
+        third.metadata.ocr_used = true;
+        third.metadata.ocr_page_count = Some(2);
+
+        let merged = ExtractionResult::merge(vec![first, second, third]);
+
+        assert!(merged.metadata.ocr_used);
+        assert_eq!(merged.metadata.ocr_page_count, Some(3));
+    }
+}
@@ -93,10 +93,15 @@ pub struct Metadata {
     pub language: Option<String>,
 
     /// Creation timestamp (ISO 8601 format)
+    ///
+    /// Normalized to RFC 3339 by the extraction pipeline when the date is recognized;
+    /// the original value is then kept in `additional["created_at_raw"]`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
 
     /// Last modification timestamp (ISO 8601 format)
+    ///
+    /// Normalized like `created_at`; the original value is kept in `additional["modified_at_raw"]`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_at: Option<String>,
 
@@ -139,6 +144,35 @@ pub struct Metadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extraction_duration_ms: Option<u64>,
 
+    /// Whether the content was truncated to `ExtractionConfig.max_content_chars`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncated: Option<bool>,
+
+    /// Whether any of `content` was produced by OCR rather than read from a text layer.
+    ///
+    /// OCR-derived text is lower confidence; downstream systems can use this flag to
+    /// apply stricter validation.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub ocr_used: bool,
+
+    /// Number of pages that were run through OCR (PDF only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr_page_count: Option<usize>,
+
+    /// OCR language selected by `OcrConfig.auto_language`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr_language: Option<String>,
+
+    /// OCR backend that produced the text, e.g. after falling back via `OcrConfig.fallback_backends`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr_backend: Option<String>,
+
+    /// Extractor that handled the document, e.g. "pdf", "docx", "html".
+    ///
+    /// Set by extraction dispatch from the extractor plugin name without its `-extractor` suffix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extractor_name: Option<String>,
+
     /// Additional custom fields from postprocessors.
     ///
     /// This flattened map allows Python/TypeScript postprocessors to add
@@ -165,6 +199,12 @@ pub struct ExcelMetadata {
     pub sheet_count: usize,
     /// Names of all sheets in order
     pub sheet_names: Vec<String>,
+    /// Names of the workbook's hidden and very hidden sheets, in order
+    ///
+    /// Hidden sheets are only extracted (and counted in `sheet_count` and `sheet_names`)
+    /// when `XlsxConfig.include_hidden_sheets` is set or they are selected by name.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_sheets: Vec<String>,
 }
 
 /// Email metadata extracted from .eml and .msg files.
@@ -626,3 +666,361 @@ pub struct PptxMetadata {
     /// Names of slides (if available)
     pub slide_names: Vec<String>,
 }
+
+/// Keys the extraction pipeline may add to the metadata of any format, such as
+/// truncation, quality processing, chunking and token reduction results.
+const COMMON_FIELDS: &[&str] = &[
+    "extractor_name",
+    "truncated",
+    "content_hash",
+    "quality_score",
+    "ocr_render_dpi",
+    "owner_restrictions_bypassed",
+    "chunks_truncated",
+    "token_reduction_stats",
+    "ocr_empty_pages",
+];
+
+const PDF_FIELDS: &[&str] = &[
+    "title",
+    "subject",
+    "authors",
+    "keywords",
+    "created_at",
+    "modified_at",
+    "created_by",
+    "pages",
+    "format_type",
+    "pdf_version",
+    "producer",
+    "is_encrypted",
+    "width",
+    "height",
+    "page_count",
+    "ocr_used",
+    "ocr_page_count",
+    "ocr_language",
+    "ocr_backend",
+    "ocr_confidence",
+    "ocr_confidence_normalized",
+    "ocr_empty_pages",
+    "ocr_failed_pages",
+    "created_at_raw",
+    "modified_at_raw",
+];
+
+const DOCX_FIELDS: &[&str] = &[
+    "title",
+    "authors",
+    "created_by",
+    "subject",
+    "keywords",
+    "description",
+    "modified_by",
+    "created_at",
+    "modified_at",
+    "revision",
+    "category",
+    "content_status",
+    "language",
+    "page_count",
+    "word_count",
+    "character_count",
+    "line_count",
+    "paragraph_count",
+    "template",
+    "company",
+    "total_editing_time_minutes",
+    "application",
+    "page_boundaries_estimated",
+];
+
+const ODT_FIELDS: &[&str] = &[
+    "title",
+    "authors",
+    "created_by",
+    "initial_creator",
+    "subject",
+    "keywords",
+    "description",
+    "created_at",
+    "modified_at",
+    "language",
+    "generator",
+    "editing_duration",
+    "editing_cycles",
+    "page_count",
+    "word_count",
+    "character_count",
+    "paragraph_count",
+    "table_count",
+    "image_count",
+];
+
+const LEGACY_OFFICE_FIELDS: &[&str] = &[
+    "title",
+    "subject",
+    "authors",
+    "created_by",
+    "modified_by",
+    "revision",
+    "slide_count",
+    "extraction_method",
+];
+
+const RTF_FIELDS: &[&str] = &[
+    "title",
+    "subject",
+    "authors",
+    "created_by",
+    "modified_by",
+    "created_at",
+    "modified_at",
+    "revision",
+    "generator",
+    "page_count",
+    "word_count",
+    "character_count",
+    "line_count",
+    "paragraph_count",
+];
+
+const EXCEL_FIELDS: &[&str] = &[
+    "format_type",
+    "sheet_count",
+    "sheet_names",
+    "hidden_sheets",
+    "title",
+    "creator",
+    "created_by",
+    "subject",
+    "keywords",
+    "description",
+    "modified_by",
+    "created_at",
+    "modified_at",
+    "revision",
+    "category",
+    "content_status",
+    "language",
+    "worksheet_names",
+    "organization",
+    "application",
+    "application_version",
+];
+
+const PPTX_FIELDS: &[&str] = &[
+    "format_type",
+    "slide_count",
+    "slide_names",
+    "image_count",
+    "table_count",
+    "images_truncated",
+    "pages",
+];
+
+const EMAIL_FIELDS: &[&str] = &[
+    "format_type",
+    "subject",
+    "created_at",
+    "from_email",
+    "from_name",
+    "to_emails",
+    "cc_emails",
+    "bcc_emails",
+    "message_id",
+    "attachments",
+    "email_from",
+    "email_to",
+    "email_cc",
+    "email_bcc",
+];
+
+const HTML_FIELDS: &[&str] = &[
+    "format_type",
+    "title",
+    "description",
+    "keywords",
+    "author",
+    "canonical_url",
+    "base_href",
+    "language",
+    "text_direction",
+    "open_graph",
+    "twitter_card",
+    "meta_tags",
+    "headers",
+    "links",
+    "images",
+    "structured_data",
+    "images_truncated",
+];
+
+const IMAGE_FIELDS: &[&str] = &[
+    "format_type",
+    "width",
+    "height",
+    "format",
+    "exif",
+    "ocr_used",
+    "ocr_language",
+    "ocr_backend",
+    "ocr_confidence",
+    "ocr_confidence_normalized",
+    "ocr_empty_pages",
+];
+
+const TEXT_FIELDS: &[&str] = &[
+    "format_type",
+    "line_count",
+    "word_count",
+    "character_count",
+    "headers",
+    "links",
+    "code_blocks",
+];
+
+const MARKDOWN_FIELDS: &[&str] = &[
+    "title",
+    "subject",
+    "created_at",
+    "author",
+    "keywords",
+    "language",
+    "category",
+    "tags",
+    "version",
+    "abstract",
+];
+
+const XML_FIELDS: &[&str] = &["format_type", "element_count", "unique_elements"];
+
+const CSV_FIELDS: &[&str] = &["row_count", "column_count", "extraction_method"];
+
+const STRUCTURED_FIELDS: &[&str] = &["data_format", "field_count"];
+
+const ARCHIVE_FIELDS: &[&str] = &[
+    "format_type",
+    "format",
+    "file_count",
+    "file_list",
+    "total_size",
+    "compressed_size",
+    "files",
+];
+
+const ZIP_FIELDS: &[&str] = &[
+    "format_type",
+    "format",
+    "file_count",
+    "file_list",
+    "total_size",
+    "compressed_size",
+    "files",
+    "archive_members",
+    "archive_errors",
+    "documents",
+];
+
+const EPUB_FIELDS: &[&str] = &[
+    "title",
+    "authors",
+    "language",
+    "created_at",
+    "pages",
+    "identifier",
+    "publisher",
+    "subject",
+    "description",
+    "rights",
+];
+
+const BIBTEX_FIELDS: &[&str] = &["authors", "entry_count", "entry_types", "citation_keys", "year_range"];
+
+const CITATION_FIELDS: &[&str] = &["authors", "keywords", "citation_count", "dois", "format", "year_range"];
+
+const JUPYTER_FIELDS: &[&str] = &["nbformat", "kernelspec", "language_info"];
+
+const DXF_FIELDS: &[&str] = &["layers", "text_entity_count"];
+
+/// Metadata keys the extractor for `mime_type` may populate.
+///
+/// Keys are the names used in the serialized `Metadata` (typed fields, flattened
+/// format-specific fields and `additional` entries alike), so a UI can render
+/// placeholders for fields a document happens not to have. This is a static mapping
+/// kept in sync with the extractors by hand; MIME types without an entry return an
+/// empty list. The keys the pipeline may add to every format (`extractor_name`,
+/// `truncated`, `content_hash`, ...) are included for each supported MIME type. Use
+/// `is_valid_format_field` to check a key against the full registry.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::types::metadata::known_fields_for;
+///
+/// assert!(known_fields_for("application/pdf").contains(&"page_count"));
+/// assert!(known_fields_for("application/x-unknown").is_empty());
+/// ```
+pub fn known_fields_for(mime_type: &str) -> Vec<&'static str> {
+    let mime_type = mime_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    let fields = match mime_type.as_str() {
+        "application/pdf" => PDF_FIELDS,
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => DOCX_FIELDS,
+        "application/vnd.oasis.opendocument.text" => ODT_FIELDS,
+        "application/msword" | "application/vnd.ms-powerpoint" => LEGACY_OFFICE_FIELDS,
+        "application/rtf" | "text/rtf" => RTF_FIELDS,
+        "application/vnd.oasis.opendocument.spreadsheet" => EXCEL_FIELDS,
+        m if m.starts_with("application/vnd.openxmlformats-officedocument.spreadsheetml")
+            || m.starts_with("application/vnd.ms-excel") =>
+        {
+            EXCEL_FIELDS
+        }
+        m if m.starts_with("application/vnd.openxmlformats-officedocument.presentationml")
+            || m == "application/vnd.ms-powerpoint.presentation.macroenabled.12" =>
+        {
+            PPTX_FIELDS
+        }
+        "message/rfc822" | "application/vnd.ms-outlook" => EMAIL_FIELDS,
+        "text/html" | "application/xhtml+xml" => HTML_FIELDS,
+        "image/svg+xml" | "application/xml" | "text/xml" => XML_FIELDS,
+        "image/vnd.dxf" | "application/dxf" => DXF_FIELDS,
+        m if m.starts_with("image/") => IMAGE_FIELDS,
+        "text/markdown"
+        | "text/x-markdown"
+        | "text/x-gfm"
+        | "text/x-commonmark"
+        | "text/x-markdown-extra"
+        | "text/x-multimarkdown" => MARKDOWN_FIELDS,
+        "text/plain" | "text/troff" | "text/x-mdoc" | "text/x-pod" | "text/x-dokuwiki" => TEXT_FIELDS,
+        "text/csv" | "text/tab-separated-values" => CSV_FIELDS,
+        "application/json" | "text/json" | "application/yaml" | "application/x-yaml" | "text/yaml" | "text/x-yaml"
+        | "application/toml" | "text/toml" => STRUCTURED_FIELDS,
+        "application/zip" | "application/x-zip-compressed" => ZIP_FIELDS,
+        "application/x-tar"
+        | "application/tar"
+        | "application/x-gtar"
+        | "application/x-ustar"
+        | "application/x-7z-compressed"
+        | "application/gzip"
+        | "application/x-gzip" => ARCHIVE_FIELDS,
+        "application/epub+zip" | "application/x-epub+zip" | "application/vnd.epub+zip" => EPUB_FIELDS,
+        "application/x-bibtex" | "text/x-bibtex" | "application/x-biblatex" => BIBTEX_FIELDS,
+        "application/x-research-info-systems" | "application/x-pubmed" | "application/x-endnote+xml" => CITATION_FIELDS,
+        "application/x-ipynb+json" => JUPYTER_FIELDS,
+        _ => return Vec::new(),
+    };
+
+    let mut known = fields.to_vec();
+    for field in COMMON_FIELDS {
+        if !known.contains(field) {
+            known.push(field);
+        }
+    }
+    known
+}
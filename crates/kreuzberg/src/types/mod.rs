@@ -5,11 +5,19 @@ pub mod djot;
 pub mod document_structure;
 pub mod extraction;
 pub mod formats;
+pub mod formula;
+mod merge;
 pub mod metadata;
 pub mod ocr_elements;
+pub mod outline;
 pub mod page;
+pub mod reference;
+mod render;
 pub mod serde_helpers;
+pub mod source_map;
+mod stable_json;
 pub mod tables;
+pub mod text_block;
 
 // Re-export all types for backward compatibility
 pub use djot::*;
@@ -19,10 +27,15 @@ pub use document_structure::{
 };
 pub use extraction::*;
 pub use formats::*;
+pub use formula::{Formula, FormulaKind};
 pub use metadata::*;
 pub use ocr_elements::*;
+pub use outline::OutlineNode;
 pub use page::*;
+pub use reference::Reference;
+pub use source_map::{SourceSpan, SourceUnit};
 pub use tables::*;
+pub use text_block::TextBlock;
 
 #[cfg(test)]
 mod tests {
@@ -71,6 +84,9 @@ mod tests {
             cells: vec![vec!["A".to_string(), "B".to_string()]],
             markdown: "| A | B |\n|---|---|\n".to_string(),
             page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         };
 
         let json = serde_json::to_value(&table).unwrap();
@@ -89,6 +105,9 @@ mod tests {
             ],
             markdown: "| X | Y |\n|---|---|\n| 1 | 2 |\n".to_string(),
             page_number: 5,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -105,6 +124,9 @@ mod tests {
             cells: vec![vec!["shared".to_string()]],
             markdown: "| shared |".to_string(),
             page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         });
 
         let tables_before = [Arc::clone(&shared_table), Arc::clone(&shared_table)].to_vec();
@@ -120,11 +142,17 @@ mod tests {
                 cells: vec![vec!["A".to_string()]],
                 markdown: "| A |".to_string(),
                 page_number: 1,
+                name: None,
+                has_header: false,
+                cell_spans: Vec::new(),
             },
             Table {
                 cells: vec![vec!["B".to_string()]],
                 markdown: "| B |".to_string(),
                 page_number: 2,
+                name: None,
+                has_header: false,
+                cell_spans: Vec::new(),
             },
         ];
 
@@ -147,11 +175,17 @@ mod tests {
                     cells: vec![vec!["Table1".to_string()]],
                     markdown: "| Table1 |".to_string(),
                     page_number: 3,
+                    name: None,
+                    has_header: false,
+                    cell_spans: Vec::new(),
                 }),
                 Arc::new(Table {
                     cells: vec![vec!["Table2".to_string()]],
                     markdown: "| Table2 |".to_string(),
                     page_number: 3,
+                    name: None,
+                    has_header: false,
+                    cell_spans: Vec::new(),
                 }),
             ],
             images: Vec::new(),
@@ -182,6 +216,9 @@ mod tests {
             bits_per_component: Some(8),
             is_mask: false,
             description: Some("Image 1".to_string()),
+            alt_text: None,
+            page_position: None,
+            caption: None,
             ocr_result: None,
         });
 
@@ -196,6 +233,9 @@ mod tests {
             bits_per_component: Some(8),
             is_mask: false,
             description: Some("Image 2".to_string()),
+            alt_text: None,
+            page_position: None,
+            caption: None,
             ocr_result: None,
         });
 
@@ -224,6 +264,9 @@ mod tests {
             cells: vec![vec!["shared across pages".to_string()]],
             markdown: "| shared across pages |".to_string(),
             page_number: 0,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         });
 
         let page1 = PageContent {
@@ -280,16 +323,181 @@ mod tests {
             cells: vec![vec!["A".to_string()]],
             markdown: "| A |".to_string(),
             page_number: 1,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         };
 
         let table2 = Table {
             cells: vec![vec!["B".to_string()]],
             markdown: "| B |".to_string(),
             page_number: 2,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
         };
 
         let json = serde_json::to_string(&vec![table1, table2]).unwrap();
         assert!(json.contains("\"A\""));
         assert!(json.contains("\"B\""));
     }
+
+    #[test]
+    fn test_table_name_serialization() {
+        let named = Table {
+            cells: vec![vec!["A".to_string()]],
+            markdown: "| A |".to_string(),
+            page_number: 2,
+            name: Some("Q4".to_string()),
+            has_header: false,
+            cell_spans: Vec::new(),
+        };
+
+        let json = serde_json::to_value(&named).unwrap();
+        assert_eq!(json["name"], "Q4");
+
+        let unnamed: Table = serde_json::from_str(r#"{"cells": [], "markdown": "", "page_number": 1}"#).unwrap();
+        assert_eq!(unnamed.name, None);
+        assert!(serde_json::to_value(&unnamed).unwrap().get("name").is_none());
+    }
+
+    #[test]
+    fn test_known_fields_for_mime_types() {
+        let pdf = known_fields_for("application/pdf");
+        assert!(pdf.contains(&"title"));
+        assert!(pdf.contains(&"page_count"));
+
+        let email = known_fields_for("message/rfc822");
+        assert!(email.contains(&"from_email"));
+        assert!(!email.contains(&"page_count"));
+
+        assert_eq!(
+            known_fields_for("Text/HTML; charset=utf-8"),
+            known_fields_for("text/html")
+        );
+        assert!(known_fields_for("image/png").contains(&"width"));
+        assert!(known_fields_for("image/svg+xml").contains(&"element_count"));
+        assert!(known_fields_for("application/x-unknown").is_empty());
+    }
+
+    #[test]
+    fn test_known_fields_include_pipeline_keys() {
+        for mime_type in ["application/pdf", "text/csv", "application/zip", "image/png"] {
+            let fields = known_fields_for(mime_type);
+            for key in [
+                "extractor_name",
+                "truncated",
+                "content_hash",
+                "quality_score",
+                "token_reduction_stats",
+            ] {
+                assert!(fields.contains(&key), "{mime_type} is missing {key}");
+            }
+            let unique: std::collections::HashSet<_> = fields.iter().collect();
+            assert_eq!(unique.len(), fields.len(), "{mime_type} lists a key twice");
+        }
+    }
+
+    #[test]
+    fn test_known_fields_match_serialized_text_metadata() {
+        let metadata = Metadata {
+            format: Some(FormatMetadata::Text(TextMetadata {
+                line_count: 1,
+                word_count: 2,
+                character_count: 9,
+                headers: Some(vec![]),
+                links: Some(vec![]),
+                code_blocks: Some(vec![]),
+            })),
+            ..Default::default()
+        };
+
+        assert_fields_known("text/plain", &metadata);
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn test_known_fields_match_serialized_ocr_metadata() {
+        use crate::ocr::confidence::{OCR_CONFIDENCE_KEY, OCR_CONFIDENCE_NORMALIZED_KEY, OCR_EMPTY_PAGES_KEY};
+
+        let ocr_metadata = Metadata {
+            truncated: Some(true),
+            ocr_used: true,
+            ocr_language: Some("eng".to_string()),
+            ocr_backend: Some("tesseract".to_string()),
+            additional: [
+                (Cow::Borrowed(OCR_CONFIDENCE_KEY), serde_json::json!(91.0)),
+                (Cow::Borrowed(OCR_CONFIDENCE_NORMALIZED_KEY), serde_json::json!(0.91)),
+                (Cow::Borrowed(OCR_EMPTY_PAGES_KEY), serde_json::json!([1])),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let image = Metadata {
+            format: Some(FormatMetadata::Image(ImageMetadata {
+                width: 640,
+                height: 480,
+                format: "PNG".to_string(),
+                exif: std::collections::HashMap::new(),
+            })),
+            ..ocr_metadata.clone()
+        };
+        assert_fields_known("image/png", &image);
+
+        #[cfg(feature = "pdf")]
+        {
+            let mut pdf = Metadata {
+                title: Some("Report".to_string()),
+                created_at: Some("2023-01-15T12:30:45+01:00".to_string()),
+                modified_at: Some("2023-01-16T08:00:00+01:00".to_string()),
+                ocr_page_count: Some(2),
+                format: Some(FormatMetadata::Pdf(crate::pdf::metadata::PdfMetadata {
+                    pdf_version: Some("1.7".to_string()),
+                    producer: Some("Writer".to_string()),
+                    is_encrypted: Some(false),
+                    width: Some(612),
+                    height: Some(792),
+                    page_count: Some(2),
+                })),
+                ..ocr_metadata
+            };
+            for (key, value) in [
+                ("created_at_raw", serde_json::json!("D:20230115123045+01'00'")),
+                ("modified_at_raw", serde_json::json!("D:20230116080000+01'00'")),
+                ("ocr_failed_pages", serde_json::json!([2])),
+            ] {
+                pdf.additional.insert(Cow::Borrowed(key), value);
+            }
+            assert_fields_known("application/pdf", &pdf);
+        }
+    }
+
+    #[test]
+    fn test_known_fields_match_serialized_excel_metadata() {
+        let metadata = Metadata {
+            title: Some("Budget".to_string()),
+            created_by: Some("Alice".to_string()),
+            format: Some(FormatMetadata::Excel(ExcelMetadata {
+                sheet_count: 1,
+                sheet_names: vec!["Summary".to_string()],
+                hidden_sheets: vec!["Lookup".to_string()],
+            })),
+            ..Default::default()
+        };
+
+        assert_fields_known(
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            &metadata,
+        );
+    }
+
+    fn assert_fields_known(mime_type: &str, metadata: &Metadata) {
+        let json = serde_json::to_value(metadata).unwrap();
+        let known = known_fields_for(mime_type);
+        for key in json.as_object().unwrap().keys() {
+            assert!(known.contains(&key.as_str()), "{}: missing key: {}", mime_type, key);
+        }
+    }
 }
@@ -0,0 +1,102 @@
+//! Document outline (bookmarks / heading tree).
+
+use serde::{Deserialize, Serialize};
+
+/// An entry in a document's outline.
+///
+/// PDFs provide the outline from their bookmarks; DOCX documents from their heading
+/// styles. Children are nested entries one level deeper.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "api", schema(no_recursion))]
+pub struct OutlineNode {
+    /// Entry title as shown in the document's navigation pane.
+    pub title: String,
+    /// Nesting level, starting at 1 for top-level entries.
+    pub level: usize,
+    /// 1-indexed page the entry points to, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<usize>,
+    /// Nested entries.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<OutlineNode>,
+}
+
+impl OutlineNode {
+    /// Build an outline tree from entries in document order.
+    ///
+    /// Each entry is `(level, title, page_number)`. An entry becomes a child of the
+    /// closest preceding entry with a lower level. Levels are renumbered so that nested
+    /// entries are exactly one level below their parent, e.g. a heading-2 directly under
+    /// a heading-0 ends up at level 2.
+    pub fn build_tree(entries: impl IntoIterator<Item = (usize, String, Option<usize>)>) -> Vec<OutlineNode> {
+        let mut roots: Vec<OutlineNode> = Vec::new();
+        // Source levels of the open path from the root to the last entry.
+        let mut open_levels: Vec<usize> = Vec::new();
+
+        for (source_level, title, page_number) in entries {
+            while open_levels.last().is_some_and(|&open| open >= source_level) {
+                open_levels.pop();
+            }
+
+            let node = OutlineNode {
+                title,
+                level: open_levels.len() + 1,
+                page_number,
+                children: Vec::new(),
+            };
+
+            let mut siblings = &mut roots;
+            for _ in 0..open_levels.len() {
+                siblings = &mut siblings
+                    .last_mut()
+                    .expect("open path always has a node per level")
+                    .children;
+            }
+            siblings.push(node);
+            open_levels.push(source_level);
+        }
+
+        roots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(level: usize, title: &str) -> (usize, String, Option<usize>) {
+        (level, title.to_string(), None)
+    }
+
+    #[test]
+    fn test_build_tree_nests_by_level() {
+        let tree = OutlineNode::build_tree([
+            entry(1, "Intro"),
+            entry(2, "Background"),
+            entry(3, "History"),
+            entry(2, "Scope"),
+            entry(1, "Methods"),
+        ]);
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].title, "Intro");
+        assert_eq!(tree[0].children.len(), 2);
+        assert_eq!(tree[0].children[0].children[0].title, "History");
+        assert_eq!(tree[0].children[0].children[0].level, 3);
+        assert_eq!(tree[0].children[1].title, "Scope");
+        assert_eq!(tree[1].title, "Methods");
+        assert!(tree[1].children.is_empty());
+    }
+
+    #[test]
+    fn test_build_tree_renumbers_skipped_levels() {
+        let tree = OutlineNode::build_tree([entry(2, "Chapter"), entry(4, "Detail"), entry(1, "Appendix")]);
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].level, 1);
+        assert_eq!(tree[0].children[0].title, "Detail");
+        assert_eq!(tree[0].children[0].level, 2);
+        assert_eq!(tree[1].level, 1);
+    }
+}
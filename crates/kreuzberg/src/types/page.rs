@@ -48,6 +48,8 @@ pub enum PageUnitType {
     Slide,
     /// Spreadsheet sheets (XLSX, ODS)
     Sheet,
+    /// Book chapters (EPUB spine items)
+    Chapter,
 }
 
 /// Byte offset boundary for a page.
@@ -66,6 +68,53 @@ pub struct PageBoundary {
     pub page_number: usize,
 }
 
+impl PageBoundary {
+    /// Whether `boundaries` describe `content`: each range lies within it on character
+    /// boundaries, and the ranges are in order without overlapping.
+    pub fn all_valid(content: &str, boundaries: &[PageBoundary]) -> bool {
+        let mut cursor = 0;
+        boundaries.iter().all(|boundary| {
+            let valid = boundary.byte_start >= cursor
+                && boundary.byte_start <= boundary.byte_end
+                && boundary.byte_end <= content.len()
+                && content.is_char_boundary(boundary.byte_start)
+                && content.is_char_boundary(boundary.byte_end);
+            cursor = boundary.byte_end;
+            valid
+        })
+    }
+
+    /// Rebuild `content` page by page and move `boundaries` onto the result.
+    ///
+    /// The text of each page is replaced by `rewrite_page`, and any text before, between
+    /// or after the pages by `rewrite_between`. Returns `None`, leaving `boundaries`
+    /// untouched, when they don't describe `content`.
+    pub fn rewrite_pages(
+        content: &str,
+        boundaries: &mut [PageBoundary],
+        mut rewrite_page: impl FnMut(&str) -> String,
+        mut rewrite_between: impl FnMut(&str) -> String,
+    ) -> Option<String> {
+        if !Self::all_valid(content, boundaries) {
+            return None;
+        }
+
+        let mut rewritten = String::with_capacity(content.len());
+        let mut cursor = 0;
+        for boundary in boundaries.iter_mut() {
+            rewritten.push_str(&rewrite_between(&content[cursor..boundary.byte_start]));
+            let page_start = rewritten.len();
+            rewritten.push_str(&rewrite_page(&content[boundary.byte_start..boundary.byte_end]));
+            cursor = boundary.byte_end;
+            boundary.byte_start = page_start;
+            boundary.byte_end = rewritten.len();
+        }
+        rewritten.push_str(&rewrite_between(&content[cursor..]));
+
+        Some(rewritten)
+    }
+}
+
 /// Metadata for individual page/slide/sheet.
 ///
 /// Captures per-page information including dimensions, content counts,
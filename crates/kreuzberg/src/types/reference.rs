@@ -0,0 +1,27 @@
+//! Bibliography entries parsed from a document's references section.
+
+use serde::{Deserialize, Serialize};
+
+/// An entry of a document's references section.
+///
+/// Produced when `extract_references` is enabled and the `references` feature is
+/// compiled in. Fields are parsed heuristically from the entry text and are `None`
+/// (or empty) when they could not be recognized; `raw` always holds the full entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct Reference {
+    /// The entry text with line breaks joined and its list marker (`[1]`, `1.`) removed.
+    pub raw: String,
+    /// Author names in the order listed, as written (e.g. `"Smith, J."` or `"J. Smith"`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+    /// Title of the cited work.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Publication year.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<u16>,
+    /// DOI without a resolver prefix, e.g. `"10.1000/xyz123"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doi: Option<String>,
+}
@@ -0,0 +1,337 @@
+//! Rendering of an extraction result back into a single Markdown document, or into
+//! the plain text of each page.
+//!
+//! Used for previews and for handing a clean document to an LLM. The output only
+//! depends on the result, so it is stable enough for snapshot tests.
+
+use super::extraction::{ExtractedImage, ExtractionResult};
+use super::tables::Table;
+
+/// Separator between the blocks (page text, tables, images) of the document.
+const BLOCK_SEPARATOR: &str = "\n\n";
+
+impl ExtractionResult {
+    /// Reassemble the result into one Markdown document.
+    ///
+    /// When the result has page boundaries, the text of each page is followed by the
+    /// tables and image placeholders of that page. Without page boundaries, all
+    /// tables and images follow the content. Tables and images whose page is unknown
+    /// or outside the page boundaries are appended at the end.
+    ///
+    /// - Tables are rendered from `Table::markdown`. Tables whose Markdown already
+    ///   appears verbatim in `content` (e.g. spreadsheets) are not repeated.
+    /// - Images become `![alt](image_<index>.<format>)` placeholders, using the alt
+    ///   text, or the description when there is none.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kreuzberg::{ExtractionConfig, extract_file_sync};
+    ///
+    /// # fn example() -> kreuzberg::Result<()> {
+    /// let result = extract_file_sync("report.pdf", None, &ExtractionConfig::default())?;
+    /// std::fs::write("report.md", result.to_markdown())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let tables: Vec<&Table> = self
+            .tables
+            .iter()
+            .filter(|table| {
+                let markdown = table.markdown.trim();
+                !markdown.is_empty() && !self.content.contains(markdown)
+            })
+            .collect();
+        let images: &[ExtractedImage] = self.images.as_deref().unwrap_or_default();
+
+        let mut blocks: Vec<String> = Vec::new();
+        let mut placed_pages = Vec::new();
+
+        let boundaries = self
+            .metadata
+            .pages
+            .as_ref()
+            .and_then(|pages| pages.boundaries.as_deref())
+            .filter(|boundaries| !boundaries.is_empty());
+
+        match boundaries {
+            Some(boundaries) => {
+                let mut cursor = 0;
+                for boundary in boundaries {
+                    if boundary.byte_start > cursor {
+                        push_text(
+                            &mut blocks,
+                            slice_at_char_boundaries(&self.content, cursor, boundary.byte_start),
+                        );
+                    }
+                    push_text(
+                        &mut blocks,
+                        slice_at_char_boundaries(&self.content, boundary.byte_start, boundary.byte_end),
+                    );
+                    cursor = cursor.max(boundary.byte_end);
+
+                    push_page_objects(&mut blocks, &tables, images, boundary.page_number);
+                    placed_pages.push(boundary.page_number);
+                }
+                push_text(
+                    &mut blocks,
+                    slice_at_char_boundaries(&self.content, cursor, self.content.len()),
+                );
+            }
+            None => push_text(&mut blocks, &self.content),
+        }
+
+        for table in tables.iter().filter(|table| !placed_pages.contains(&table.page_number)) {
+            blocks.push(table.markdown.trim().to_string());
+        }
+        for image in images
+            .iter()
+            .filter(|image| image.page_number.is_none_or(|page| !placed_pages.contains(&page)))
+        {
+            blocks.push(image_placeholder(image));
+        }
+
+        blocks.join(BLOCK_SEPARATOR)
+    }
+
+    /// The text of each page, in page order.
+    ///
+    /// Uses `pages` when page extraction (`PageConfig::extract_pages`) populated it, and
+    /// otherwise slices `content` along the page boundaries in `metadata.pages`, so the
+    /// merged content and the per-page breakdown never need to be split by hand. Returns
+    /// `None` for results without page information.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kreuzberg::{ExtractionConfig, extract_file_sync};
+    ///
+    /// # fn example() -> kreuzberg::Result<()> {
+    /// let result = extract_file_sync("report.pdf", None, &ExtractionConfig::default())?;
+    /// for (index, text) in result.page_texts().unwrap_or_default().iter().enumerate() {
+    ///     println!("page {}: {} chars", index + 1, text.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn page_texts(&self) -> Option<Vec<&str>> {
+        if let Some(pages) = self.pages.as_ref() {
+            return Some(pages.iter().map(|page| page.content.as_str()).collect());
+        }
+
+        let boundaries = self.metadata.pages.as_ref()?.boundaries.as_ref()?;
+        Some(
+            boundaries
+                .iter()
+                .map(|boundary| slice_at_char_boundaries(&self.content, boundary.byte_start, boundary.byte_end))
+                .collect(),
+        )
+    }
+}
+
+fn push_text(blocks: &mut Vec<String>, text: &str) {
+    let text = text.trim();
+    if !text.is_empty() {
+        blocks.push(text.to_string());
+    }
+}
+
+/// Slice `text` between two byte offsets, moving each down to a char boundary.
+///
+/// Offsets in the middle of a character still select the text around them, and
+/// adjacent ranges stay adjacent, so no text is dropped or repeated.
+fn slice_at_char_boundaries(text: &str, start: usize, end: usize) -> &str {
+    let start = text.floor_char_boundary(start);
+    let end = text.floor_char_boundary(end).max(start);
+    &text[start..end]
+}
+
+fn push_page_objects(blocks: &mut Vec<String>, tables: &[&Table], images: &[ExtractedImage], page_number: usize) {
+    for table in tables.iter().filter(|table| table.page_number == page_number) {
+        blocks.push(table.markdown.trim().to_string());
+    }
+    for image in images.iter().filter(|image| image.page_number == Some(page_number)) {
+        blocks.push(image_placeholder(image));
+    }
+}
+
+fn image_placeholder(image: &ExtractedImage) -> String {
+    let alt = image
+        .alt_text
+        .as_deref()
+        .or(image.description.as_deref())
+        .unwrap_or_default();
+    let alt = alt.split_whitespace().collect::<Vec<_>>().join(" ").replace(']', "\\]");
+    format!("![{}](image_{}.{})", alt, image.image_index, image.format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, PageBoundary, PageContent, PageStructure, PageUnitType};
+    use bytes::Bytes;
+    use std::borrow::Cow;
+
+    fn result(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: Cow::Borrowed("application/pdf"),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            djot_content: None,
+            elements: None,
+            ocr_elements: None,
+            document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
+        }
+    }
+
+    fn table(page_number: usize, header: &str) -> Table {
+        Table {
+            cells: vec![vec![header.to_string()]],
+            markdown: format!("| {} |\n|------|\n", header),
+            page_number,
+            name: None,
+            has_header: false,
+            cell_spans: Vec::new(),
+        }
+    }
+
+    fn image(index: usize, page_number: Option<usize>, alt_text: Option<&str>) -> ExtractedImage {
+        ExtractedImage {
+            data: Bytes::new(),
+            format: Cow::Borrowed("png"),
+            image_index: index,
+            page_number,
+            width: None,
+            height: None,
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: None,
+            alt_text: alt_text.map(str::to_string),
+            page_position: None,
+            caption: None,
+            ocr_result: None,
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_without_pages_appends_tables_and_images() {
+        let mut result = result("# Title\n\nBody text.\n");
+        result.tables.push(table(1, "A"));
+        result.images = Some(vec![image(0, None, Some("A [chart]"))]);
+
+        assert_eq!(
+            result.to_markdown(),
+            "# Title\n\nBody text.\n\n| A |\n|------|\n\n![A [chart\\]](image_0.png)"
+        );
+    }
+
+    fn two_page_structure() -> PageStructure {
+        PageStructure {
+            total_count: 2,
+            unit_type: PageUnitType::Page,
+            boundaries: Some(vec![
+                PageBoundary {
+                    byte_start: 0,
+                    byte_end: 10,
+                    page_number: 1,
+                },
+                PageBoundary {
+                    byte_start: 10,
+                    byte_end: 20,
+                    page_number: 2,
+                },
+            ]),
+            pages: None,
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_interleaves_by_page() {
+        let mut result = result("Page one.\nPage two.\n");
+        result.metadata.pages = Some(two_page_structure());
+        result.tables = vec![table(2, "B"), table(1, "A"), table(7, "Z")];
+        result.images = Some(vec![image(0, Some(1), None)]);
+
+        assert_eq!(
+            result.to_markdown(),
+            "Page one.\n\n| A |\n|------|\n\n![](image_0.png)\n\nPage two.\n\n| B |\n|------|\n\n| Z |\n|------|"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_skips_tables_already_in_content() {
+        let mut result = result("| A |\n|------|\n");
+        result.tables.push(table(1, "A"));
+
+        assert_eq!(result.to_markdown(), "| A |\n|------|");
+    }
+
+    #[test]
+    fn test_page_texts_follow_page_boundaries() {
+        let mut result = result("Page one.\nPage two.\n");
+        assert_eq!(result.page_texts(), None);
+
+        result.metadata.pages = Some(two_page_structure());
+        assert_eq!(result.page_texts(), Some(vec!["Page one.\n", "Page two.\n"]));
+    }
+
+    #[test]
+    fn test_boundaries_inside_a_character_keep_the_text() {
+        let mut result = result("Café\nPage two.");
+        // Byte 4 falls inside the two-byte 'é'.
+        result.metadata.pages = Some(PageStructure {
+            total_count: 2,
+            unit_type: PageUnitType::Page,
+            boundaries: Some(vec![
+                PageBoundary {
+                    byte_start: 0,
+                    byte_end: 4,
+                    page_number: 1,
+                },
+                PageBoundary {
+                    byte_start: 4,
+                    byte_end: 15,
+                    page_number: 2,
+                },
+            ]),
+            pages: None,
+        });
+
+        assert_eq!(result.page_texts(), Some(vec!["Caf", "é\nPage two."]));
+        assert_eq!(result.to_markdown(), "Caf\n\né\nPage two.");
+    }
+
+    #[test]
+    fn test_page_texts_prefer_extracted_pages() {
+        let mut result = result("Page one.\nPage two.\n");
+        result.metadata.pages = Some(two_page_structure());
+        result.pages = Some(
+            ["Page one.", "Page two."]
+                .into_iter()
+                .enumerate()
+                .map(|(index, content)| PageContent {
+                    page_number: index + 1,
+                    content: content.to_string(),
+                    tables: vec![],
+                    images: vec![],
+                    hierarchy: None,
+                    is_blank: None,
+                })
+                .collect(),
+        );
+
+        assert_eq!(result.page_texts(), Some(vec!["Page one.", "Page two."]));
+    }
+}
@@ -0,0 +1,193 @@
+//! Mapping of extracted content back to positions in the source document.
+//!
+//! Extractors collect the text of the source with its position ([`SourceText`]) and
+//! locate each piece in the extracted content. Content is rarely a verbatim copy of the
+//! source (markup is rewritten, whitespace collapsed, entities decoded), so pieces are
+//! matched in document order with whitespace differences ignored, and pieces that cannot
+//! be found are left out of the map.
+
+use serde::{Deserialize, Serialize};
+
+/// How far past the previous match the next piece of source text is searched for.
+///
+/// Bounds the damage of a piece that is missing from the content but matches text much
+/// further down: at most this much content can be skipped.
+const ALIGN_WINDOW_BYTES: usize = 4096;
+
+/// A range of `content` mapped to where its text came from in the source document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct SourceSpan {
+    /// Byte offset in `content` where the range starts
+    pub content_start: usize,
+    /// Byte offset in `content` where the range ends (exclusive)
+    pub content_end: usize,
+    /// 1-indexed page of the source, for paged formats
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+    /// Start of the text in the source, counted in `unit`
+    pub source_start: usize,
+    /// End of the text in the source (exclusive), counted in `unit`
+    pub source_end: usize,
+    /// What `source_start` and `source_end` count
+    pub unit: SourceUnit,
+}
+
+/// Unit of the source positions of a [`SourceSpan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SourceUnit {
+    /// Byte offsets into the source document (HTML, decoded as UTF-8)
+    Byte,
+    /// Character indices into the text layer of `page` (PDF), as used by Pdfium's
+    /// `FPDFText_*` functions
+    PageChar,
+}
+
+/// A piece of source text with its position, before it is located in the content.
+#[derive(Debug, Clone)]
+pub(crate) struct SourceText {
+    pub text: String,
+    pub page: Option<usize>,
+    pub source_start: usize,
+    pub source_end: usize,
+    pub unit: SourceUnit,
+}
+
+/// Locate each piece of source text in `content`, in order.
+///
+/// Pieces are searched for after the previous match, ignoring differences in
+/// whitespace. Pieces without any text or not found within [`ALIGN_WINDOW_BYTES`] of
+/// the previous match are skipped.
+pub(crate) fn align_source_text(content: &str, sources: impl IntoIterator<Item = SourceText>) -> Vec<SourceSpan> {
+    let mut spans = Vec::new();
+    let mut cursor: usize = 0;
+
+    for source in sources {
+        let window_end =
+            content.floor_char_boundary(cursor.saturating_add(ALIGN_WINDOW_BYTES.max(source.text.len() * 4)));
+        let Some((start, end)) = find_ignoring_whitespace(&content[cursor..window_end], &source.text) else {
+            continue;
+        };
+
+        spans.push(SourceSpan {
+            content_start: cursor + start,
+            content_end: cursor + end,
+            page: source.page,
+            source_start: source.source_start,
+            source_end: source.source_end,
+            unit: source.unit,
+        });
+        cursor += end;
+    }
+
+    spans
+}
+
+/// Move spans of `old_content` to where their text is in `new_content`.
+///
+/// Used when post-processing rewrites the content after the extractor built the map.
+/// Spans whose text is no longer found are dropped.
+pub(crate) fn realign_source_map(spans: &[SourceSpan], old_content: &str, new_content: &str) -> Vec<SourceSpan> {
+    let sources = spans.iter().filter_map(|span| {
+        Some(SourceText {
+            text: old_content.get(span.content_start..span.content_end)?.to_string(),
+            page: span.page,
+            source_start: span.source_start,
+            source_end: span.source_end,
+            unit: span.unit,
+        })
+    });
+    align_source_text(new_content, sources)
+}
+
+/// Find `needle` in `haystack`, where each whitespace run in `needle` matches any run
+/// of whitespace (including none) in `haystack`. Returns the byte range of the match.
+fn find_ignoring_whitespace(haystack: &str, needle: &str) -> Option<(usize, usize)> {
+    let mut words = needle.split_whitespace();
+    let first = words.next()?;
+    let rest: Vec<&str> = words.collect();
+
+    let mut from = 0;
+    while let Some(offset) = haystack[from..].find(first) {
+        let start = from + offset;
+        let mut end = start + first.len();
+        let mut matched = true;
+        for word in &rest {
+            let remaining = &haystack[end..];
+            let trimmed = remaining.trim_start();
+            if !trimmed.starts_with(word) {
+                matched = false;
+                break;
+            }
+            end += remaining.len() - trimmed.len() + word.len();
+        }
+        if matched {
+            return Some((start, end));
+        }
+        from = start + first.chars().next().map_or(1, char::len_utf8);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(text: &str, source_start: usize) -> SourceText {
+        SourceText {
+            text: text.to_string(),
+            page: Some(1),
+            source_start,
+            source_end: source_start + text.chars().count(),
+            unit: SourceUnit::PageChar,
+        }
+    }
+
+    #[test]
+    fn test_align_source_text_ignores_whitespace_and_skips_missing_text() {
+        let content = "# Title\n\nFirst  line of\ntext. Second line.";
+        let spans = align_source_text(
+            content,
+            [
+                source("Title", 0),
+                source("First line of text.", 6),
+                source("not in the content", 26),
+                source("Second line.", 45),
+            ],
+        );
+
+        let texts: Vec<&str> = spans
+            .iter()
+            .map(|span| &content[span.content_start..span.content_end])
+            .collect();
+        assert_eq!(texts, vec!["Title", "First  line of\ntext.", "Second line."]);
+        assert_eq!(spans[2].source_start, 45);
+        assert_eq!(spans[1].page, Some(1));
+    }
+
+    #[test]
+    fn test_align_source_text_matches_in_order() {
+        let content = "a b a";
+        let spans = align_source_text(content, [source("a", 0), source("a", 4)]);
+
+        assert_eq!(spans[0].content_start, 0);
+        assert_eq!(spans[1].content_start, 4);
+    }
+
+    #[test]
+    fn test_realign_source_map_follows_rewritten_content() {
+        let old_content = "Hello   world. Bye.";
+        let spans = align_source_text(old_content, [source("Hello world.", 0), source("Bye.", 13)]);
+
+        let new_content = "Intro. Hello world. Bye.";
+        let spans = realign_source_map(&spans, old_content, new_content);
+        let texts: Vec<&str> = spans
+            .iter()
+            .map(|span| &new_content[span.content_start..span.content_end])
+            .collect();
+        assert_eq!(texts, vec!["Hello world.", "Bye."]);
+    }
+}
@@ -0,0 +1,123 @@
+//! JSON serialization of an extraction result with a stable key order.
+//!
+//! The default serialization follows struct field order for typed fields but the
+//! iteration order of hash maps for open-ended ones such as `metadata.additional`, so
+//! two serializations of equal results can differ. The stable form sorts every object's
+//! keys, which makes it suitable for snapshot comparisons.
+
+use super::extraction::ExtractionResult;
+
+impl ExtractionResult {
+    /// Serialize the result as pretty-printed JSON with a stable key order.
+    ///
+    /// The fields are the same as in the default serialization, but the keys of every
+    /// object, at every level, are sorted by their UTF-8 bytes (so `content` comes before
+    /// `metadata`, `mime_type` and `tables`). Arrays keep their order. Equal results
+    /// always produce identical output.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Serialization` if the result cannot be serialized.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kreuzberg::{ExtractionConfig, extract_file_sync};
+    ///
+    /// # fn example() -> kreuzberg::Result<()> {
+    /// let result = extract_file_sync("report.pdf", None, &ExtractionConfig::default())?;
+    /// std::fs::write("report.snapshot.json", result.to_stable_json()?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_stable_json(&self) -> crate::Result<String> {
+        let value = sort_keys(serde_json::to_value(self)?);
+        Ok(serde_json::to_string_pretty(&value)?)
+    }
+}
+
+/// Rebuild `value` with the keys of every object in sorted order.
+///
+/// `serde_json::Map` already keeps keys sorted unless the `preserve_order` feature is
+/// enabled somewhere in the dependency graph; sorting explicitly keeps the output
+/// independent of that.
+fn sort_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<(String, serde_json::Value)> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(values) => serde_json::Value::Array(values.into_iter().map(sort_keys).collect()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Metadata;
+    use std::borrow::Cow;
+
+    fn result(additional_keys: impl Iterator<Item = usize>) -> ExtractionResult {
+        let mut metadata = Metadata {
+            title: Some("Report".to_string()),
+            ..Default::default()
+        };
+        for index in additional_keys {
+            metadata.additional.insert(
+                Cow::Owned(format!("key_{:02}", index)),
+                serde_json::json!({ "z": index, "a": [index, 0] }),
+            );
+        }
+
+        ExtractionResult {
+            content: "Body text.".to_string(),
+            mime_type: Cow::Borrowed("text/plain"),
+            metadata,
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            djot_content: None,
+            elements: None,
+            ocr_elements: None,
+            document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
+        }
+    }
+
+    #[test]
+    fn test_to_stable_json_is_identical_across_serializations() {
+        let first = result(0..40).to_stable_json().unwrap();
+        let second = result((0..40).rev()).to_stable_json().unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first, result(0..40).to_stable_json().unwrap());
+    }
+
+    #[test]
+    fn test_to_stable_json_sorts_keys_at_every_level() {
+        let json = result(0..3).to_stable_json().unwrap();
+
+        let position = |needle: &str| json.find(needle).unwrap_or_else(|| panic!("{} missing", needle));
+        assert!(position("\"content\"") < position("\"metadata\""));
+        assert!(position("\"metadata\"") < position("\"mime_type\""));
+        assert!(position("\"key_00\"") < position("\"key_01\""));
+        assert!(position("\"key_01\"") < position("\"key_02\""));
+        assert!(position("\"key_02\"") < position("\"title\""));
+
+        let nested = &json[position("\"key_00\"")..position("\"key_01\"")];
+        assert!(nested.find("\"a\"").unwrap() < nested.find("\"z\"").unwrap());
+    }
+}
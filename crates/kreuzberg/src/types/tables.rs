@@ -14,7 +14,339 @@ pub struct Table {
     /// Markdown representation of the table
     pub markdown: String,
     /// Page number where the table was found (1-indexed)
+    ///
+    /// For spreadsheets this is the 1-indexed sheet position.
     pub page_number: usize,
+    /// Table name, if the format has one (e.g. the sheet name for spreadsheets)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether the first row of `cells` is a header row
+    ///
+    /// Set by extractors that can tell (`<th>` cells in HTML, header rows or bold first
+    /// rows in DOCX, the header row of Markdown tables, a first row of text labels in
+    /// CSV and spreadsheets) or forced with
+    /// [`TableExtractionConfig::assume_first_row_header`](crate::TableExtractionConfig::assume_first_row_header).
+    #[serde(default)]
+    pub has_header: bool,
+    /// Merged cell regions, for formats that have them (DOCX)
+    ///
+    /// `cells` stays a full grid: a merged region's text is in its top-left cell and the
+    /// other cells it covers are empty. Empty when the table has no merged cells.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cell_spans: Vec<CellSpan>,
+}
+
+/// A merged region of a [`Table`], anchored at its top-left cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct CellSpan {
+    /// Row of the top-left cell in `Table::cells` (0-indexed)
+    pub row: usize,
+    /// Column of the top-left cell in `Table::cells` (0-indexed)
+    pub col: usize,
+    /// Number of rows the region covers
+    pub row_span: usize,
+    /// Number of columns the region covers
+    pub col_span: usize,
+}
+
+impl Table {
+    /// Render the table cells as a GitHub-Flavored Markdown table.
+    ///
+    /// For tables with a header row, the `markdown` field produced by extractors
+    /// corresponds to [`TableMarkdownOptions::default()`]; use this method to re-render
+    /// with padded columns or inferred alignment, e.g. for diff-stable snapshots.
+    ///
+    /// Markdown tables always start with a header row, so tables without one
+    /// (`has_header == false`) are rendered under an empty header row and every row of
+    /// `cells` stays in the body.
+    pub fn to_markdown(&self, options: TableMarkdownOptions) -> String {
+        match self.cells.first() {
+            Some(first) if !self.has_header => {
+                let mut cells = Vec::with_capacity(self.cells.len() + 1);
+                cells.push(vec![String::new(); first.len()]);
+                cells.extend(self.cells.iter().cloned());
+                cells_to_markdown_with_options(&cells, &options)
+            }
+            _ => cells_to_markdown_with_options(&self.cells, &options),
+        }
+    }
+
+    /// Render the table as an HTML `<table>`.
+    ///
+    /// Unlike Markdown, HTML keeps merged cells: regions from `cell_spans` get `rowspan`
+    /// and `colspan` attributes and the cells they cover are left out. With a header row,
+    /// the first row is rendered in `<thead>` with `<th>` cells (together with the rows
+    /// a header cell spans into). Cell content is HTML-escaped and line breaks become `<br>`.
+    pub fn to_html(&self) -> String {
+        let num_rows = self.cells.len();
+        let num_cols = self.cells.iter().map(Vec::len).max().unwrap_or(0);
+        if num_cols == 0 {
+            return String::new();
+        }
+
+        let mut covered = vec![vec![false; num_cols]; num_rows];
+        let mut anchors = ahash::AHashMap::with_capacity(self.cell_spans.len());
+        for span in &self.cell_spans {
+            if span.row >= num_rows || span.col >= num_cols {
+                continue;
+            }
+            let row_span = span.row_span.clamp(1, num_rows - span.row);
+            let col_span = span.col_span.clamp(1, num_cols - span.col);
+            for row in &mut covered[span.row..span.row + row_span] {
+                row[span.col..span.col + col_span].fill(true);
+            }
+            anchors.insert((span.row, span.col), (row_span, col_span));
+        }
+
+        let header_rows = if self.has_header {
+            anchors
+                .iter()
+                .filter(|((row, _), _)| *row == 0)
+                .map(|(_, (row_span, _))| *row_span)
+                .max()
+                .unwrap_or(1)
+        } else {
+            0
+        };
+
+        let mut html = String::from("<table>\n");
+        for (row_idx, row) in self.cells.iter().enumerate() {
+            if row_idx == 0 && header_rows > 0 {
+                html.push_str("<thead>\n");
+            }
+            if row_idx == header_rows {
+                html.push_str("<tbody>\n");
+            }
+
+            let tag = if row_idx < header_rows { "th" } else { "td" };
+            html.push_str("<tr>");
+            for (col_idx, cell) in row.iter().enumerate() {
+                let span = anchors.get(&(row_idx, col_idx));
+                if covered[row_idx][col_idx] && span.is_none() {
+                    continue;
+                }
+                html.push('<');
+                html.push_str(tag);
+                if let Some(&(row_span, col_span)) = span {
+                    if row_span > 1 {
+                        html.push_str(&format!(" rowspan=\"{row_span}\""));
+                    }
+                    if col_span > 1 {
+                        html.push_str(&format!(" colspan=\"{col_span}\""));
+                    }
+                }
+                html.push('>');
+                html.push_str(&crate::text::html_escape(cell).replace('\n', "<br>"));
+                html.push_str("</");
+                html.push_str(tag);
+                html.push('>');
+            }
+            html.push_str("</tr>\n");
+
+            if row_idx + 1 == header_rows {
+                html.push_str("</thead>\n");
+            }
+        }
+        if num_rows > header_rows {
+            html.push_str("</tbody>\n");
+        }
+        html.push_str("</table>\n");
+
+        html
+    }
+}
+
+/// Options controlling Markdown rendering of a [`Table`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct TableMarkdownOptions {
+    /// Pad every cell to its column width so all rows line up
+    #[serde(default)]
+    pub pad_columns: bool,
+    /// Right-align columns whose body cells are all numeric
+    #[serde(default)]
+    pub infer_alignment: bool,
+    /// Escape `|` characters in cell content
+    #[serde(default = "default_true")]
+    pub escape_pipes: bool,
+}
+
+impl Default for TableMarkdownOptions {
+    fn default() -> Self {
+        Self {
+            pad_columns: false,
+            infer_alignment: false,
+            escape_pipes: true,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Render cells as a GitHub-Flavored Markdown table.
+///
+/// The first row is the header. Rows shorter than the header are padded with
+/// empty cells (a bare ` |` unless columns are padded) and longer rows are
+/// truncated. Newlines inside cells are collapsed to spaces so each row stays on
+/// a single line.
+pub(crate) fn cells_to_markdown_with_options(cells: &[Vec<String>], options: &TableMarkdownOptions) -> String {
+    let Some(header) = cells.first() else {
+        return String::new();
+    };
+    let num_cols = header.len();
+    if num_cols == 0 {
+        return String::new();
+    }
+
+    let rendered: Vec<Vec<String>> = cells
+        .iter()
+        .map(|row| {
+            row.iter()
+                .take(num_cols)
+                .map(|cell| render_cell(cell, options))
+                .collect()
+        })
+        .collect();
+
+    let right_aligned: Vec<bool> = (0..num_cols)
+        .map(|col| options.infer_alignment && is_numeric_column(&rendered[1..], col))
+        .collect();
+
+    let widths: Vec<usize> = (0..num_cols)
+        .map(|col| {
+            if options.pad_columns {
+                rendered
+                    .iter()
+                    .map(|row| row.get(col).map_or(0, |cell| cell.chars().count()))
+                    .max()
+                    .unwrap_or(0)
+                    .max(3)
+            } else {
+                0
+            }
+        })
+        .collect();
+
+    let capacity = crate::extraction::capacity::estimate_table_markdown_capacity(cells.len(), num_cols);
+    let mut markdown = String::with_capacity(capacity);
+
+    push_row(&mut markdown, &rendered[0], &widths, &right_aligned);
+
+    markdown.push('|');
+    for col in 0..num_cols {
+        if options.pad_columns {
+            markdown.push(' ');
+            let dashes = if right_aligned[col] {
+                widths[col] - 1
+            } else {
+                widths[col]
+            };
+            markdown.push_str(&"-".repeat(dashes));
+            if right_aligned[col] {
+                markdown.push(':');
+            }
+            markdown.push_str(" |");
+        } else if right_aligned[col] {
+            markdown.push_str("-----:|");
+        } else {
+            markdown.push_str("------|");
+        }
+    }
+    markdown.push('\n');
+
+    for row in &rendered[1..] {
+        push_row(&mut markdown, row, &widths, &right_aligned);
+    }
+
+    markdown
+}
+
+fn render_cell(cell: &str, options: &TableMarkdownOptions) -> String {
+    let single_line = if cell.contains(['\n', '\r']) {
+        cell.split(['\n', '\r'])
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    } else {
+        cell.to_string()
+    };
+
+    if options.escape_pipes {
+        single_line.replace('|', "\\|")
+    } else {
+        single_line
+    }
+}
+
+fn is_numeric_column(rows: &[Vec<String>], col: usize) -> bool {
+    let mut saw_number = false;
+    for row in rows {
+        let cell = row.get(col).map_or("", |cell| cell.trim());
+        if cell.is_empty() {
+            continue;
+        }
+        if !is_numeric_cell(cell) {
+            return false;
+        }
+        saw_number = true;
+    }
+    saw_number
+}
+
+/// Whether a trimmed, non-empty cell holds a number, allowing currency symbols,
+/// thousands separators and a trailing percent sign. Words that parse as non-finite
+/// floats ("inf", "NaN", "infinity") are not numbers.
+fn is_numeric_cell(cell: &str) -> bool {
+    let normalized: String = cell
+        .trim_start_matches(['$', '€', '£'])
+        .trim_end_matches('%')
+        .chars()
+        .filter(|c| *c != ',')
+        .collect();
+    normalized.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+/// Guess whether the first row of plain cell data (CSV, spreadsheets) is a header row.
+///
+/// It is when the table has at least one more row and every first-row cell is a
+/// distinct, non-numeric label.
+pub(crate) fn first_row_looks_like_header(cells: &[Vec<String>]) -> bool {
+    let [first, _, ..] = cells else {
+        return false;
+    };
+    let mut seen = ahash::AHashSet::with_capacity(first.len());
+    !first.is_empty()
+        && first.iter().all(|cell| {
+            let cell = cell.trim();
+            !cell.is_empty() && !is_numeric_cell(cell) && seen.insert(cell)
+        })
+}
+
+fn push_row(markdown: &mut String, row: &[String], widths: &[usize], right_aligned: &[bool]) {
+    markdown.push('|');
+    for (col, &width) in widths.iter().enumerate() {
+        let Some(cell) = row.get(col) else {
+            markdown.push(' ');
+            markdown.push_str(&" ".repeat(width));
+            markdown.push_str(if width == 0 { "|" } else { " |" });
+            continue;
+        };
+        markdown.push(' ');
+        let padding = width.saturating_sub(cell.chars().count());
+        if right_aligned[col] {
+            markdown.push_str(&" ".repeat(padding));
+            markdown.push_str(cell);
+        } else {
+            markdown.push_str(cell);
+            markdown.push_str(&" ".repeat(padding));
+        }
+        markdown.push_str(" |");
+    }
+    markdown.push('\n');
 }
 
 /// Individual table cell with content and optional styling.
@@ -39,3 +371,192 @@ pub struct TableCell {
 fn default_span() -> usize {
     1
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(cells: &[&[&str]]) -> Table {
+        Table {
+            cells: cells
+                .iter()
+                .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+                .collect(),
+            markdown: String::new(),
+            page_number: 1,
+            name: None,
+            has_header: true,
+            cell_spans: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_default_matches_legacy_format() {
+        let table = table(&[&["Name", "Age"], &["Alice", "30"]]);
+
+        assert_eq!(
+            table.to_markdown(TableMarkdownOptions::default()),
+            "| Name | Age |\n|------|------|\n| Alice | 30 |\n"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_pads_columns() {
+        let table = table(&[&["Name", "Age"], &["Alice", "30"], &["Bob", "4"]]);
+        let options = TableMarkdownOptions {
+            pad_columns: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            table.to_markdown(options),
+            "| Name  | Age |\n| ----- | --- |\n| Alice | 30  |\n| Bob   | 4   |\n"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_infers_numeric_alignment() {
+        let table = table(&[&["Item", "Price"], &["Tea", "1,200.50"], &["Cake", "$3"]]);
+        let options = TableMarkdownOptions {
+            pad_columns: true,
+            infer_alignment: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            table.to_markdown(options),
+            "| Item |    Price |\n| ---- | -------: |\n| Tea  | 1,200.50 |\n| Cake |       $3 |\n"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_does_not_align_non_finite_words() {
+        let table = table(&[&["Name", "Value"], &["a", "inf"], &["b", "NaN"], &["c", "Infinity"]]);
+        let options = TableMarkdownOptions {
+            infer_alignment: true,
+            ..Default::default()
+        };
+
+        assert!(!table.to_markdown(options).contains(":|"));
+    }
+
+    #[test]
+    fn test_to_markdown_escapes_pipes_and_newlines() {
+        let table = table(&[&["Header"], &["a | b\nsecond line"]]);
+
+        let escaped = table.to_markdown(TableMarkdownOptions::default());
+        assert_eq!(escaped, "| Header |\n|------|\n| a \\| b second line |\n");
+
+        let raw = table.to_markdown(TableMarkdownOptions {
+            escape_pipes: false,
+            ..Default::default()
+        });
+        assert!(raw.contains("| a | b second line |"));
+        assert_eq!(raw.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_to_markdown_is_deterministic_for_irregular_rows() {
+        let table = table(&[&["A", "B", "C"], &["1"], &["1", "2", "3", "4"]]);
+        let options = TableMarkdownOptions {
+            pad_columns: true,
+            ..Default::default()
+        };
+
+        let first = table.to_markdown(options);
+        assert_eq!(first, table.to_markdown(options));
+        let widths: Vec<usize> = first.lines().map(|line| line.chars().count()).collect();
+        assert!(widths.iter().all(|&w| w == widths[0]));
+    }
+
+    #[test]
+    fn test_to_markdown_without_header_keeps_first_row_in_body() {
+        let table = Table {
+            has_header: false,
+            ..table(&[&["Alice", "30"], &["Bob", "4"]])
+        };
+
+        assert_eq!(
+            table.to_markdown(TableMarkdownOptions::default()),
+            "|  |  |\n|------|------|\n| Alice | 30 |\n| Bob | 4 |\n"
+        );
+    }
+
+    #[test]
+    fn test_to_html_escapes_cells() {
+        let table = table(&[&["Name", "Note"], &["<b>Alice</b>", "Tom & \"Jerry\"\nline 2"]]);
+
+        assert_eq!(
+            table.to_html(),
+            "<table>\n<thead>\n<tr><th>Name</th><th>Note</th></tr>\n</thead>\n<tbody>\n\
+             <tr><td>&lt;b&gt;Alice&lt;/b&gt;</td><td>Tom &amp; &quot;Jerry&quot;<br>line 2</td></tr>\n\
+             </tbody>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn test_to_html_renders_cell_spans() {
+        let table = Table {
+            has_header: false,
+            cell_spans: vec![
+                CellSpan {
+                    row: 0,
+                    col: 0,
+                    row_span: 2,
+                    col_span: 1,
+                },
+                CellSpan {
+                    row: 0,
+                    col: 1,
+                    row_span: 1,
+                    col_span: 2,
+                },
+            ],
+            ..table(&[&["a", "b", ""], &["", "c", "d"]])
+        };
+
+        assert_eq!(
+            table.to_html(),
+            "<table>\n<tbody>\n<tr><td rowspan=\"2\">a</td><td colspan=\"2\">b</td></tr>\n\
+             <tr><td>c</td><td>d</td></tr>\n</tbody>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn test_to_html_header_includes_rows_spanned_by_header_cells() {
+        let table = Table {
+            cell_spans: vec![CellSpan {
+                row: 0,
+                col: 0,
+                row_span: 2,
+                col_span: 1,
+            }],
+            ..table(&[&["Region", "Sales"], &["", "Q1"], &["North", "10"]])
+        };
+
+        let html = table.to_html();
+        assert!(
+            html.contains(
+                "<thead>\n<tr><th rowspan=\"2\">Region</th><th>Sales</th></tr>\n<tr><th>Q1</th></tr>\n</thead>"
+            )
+        );
+        assert!(html.contains("<tbody>\n<tr><td>North</td><td>10</td></tr>\n</tbody>"));
+    }
+
+    #[test]
+    fn test_first_row_looks_like_header() {
+        let looks_like_header = |rows: &[&[&str]]| first_row_looks_like_header(&table(rows).cells);
+
+        assert!(looks_like_header(&[&["Name", "Age"], &["Alice", "30"]]));
+        assert!(!looks_like_header(&[&["Alice", "30"], &["Bob", "4"]]));
+        assert!(!looks_like_header(&[&["Name", ""], &["Alice", "x"]]));
+        assert!(!looks_like_header(&[&["x", "x"], &["a", "b"]]));
+        assert!(!looks_like_header(&[&["Name", "Age"]]));
+    }
+
+    #[test]
+    fn test_to_markdown_empty_table() {
+        assert_eq!(table(&[]).to_markdown(TableMarkdownOptions::default()), "");
+        assert_eq!(table(&[]).to_html(), "");
+    }
+}
@@ -0,0 +1,92 @@
+//! Positioned text blocks for click-to-source and highlight overlays.
+
+use serde::{Deserialize, Serialize};
+
+use super::extraction::BoundingBox;
+use super::ocr_elements::OcrElement;
+
+/// A run of text with the page it appears on and its bounding box.
+///
+/// Blocks from the PDF text layer use PDF user space (points, origin at the bottom-left
+/// of the page, `y1` above `y0`). Blocks derived from OCR use image pixels with the
+/// origin at the top-left, so `y0` is the top edge and `y1` the bottom edge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+pub struct TextBlock {
+    /// Text content of the block.
+    pub text: String,
+    /// 1-indexed page the block appears on.
+    pub page: usize,
+    /// Bounding box of the block on its page.
+    pub bbox: BoundingBox,
+}
+
+impl TextBlock {
+    /// Convert OCR elements into text blocks, skipping elements without text.
+    ///
+    /// Elements without a page number (`0`) are placed on page 1.
+    pub fn from_ocr_elements(elements: &[OcrElement]) -> Vec<TextBlock> {
+        elements
+            .iter()
+            .filter(|element| !element.text.trim().is_empty())
+            .map(|element| {
+                let (left, top, width, height) = element.geometry.to_aabb();
+                TextBlock {
+                    text: element.text.trim().to_string(),
+                    page: element.page_number.max(1),
+                    bbox: BoundingBox {
+                        x0: f64::from(left),
+                        y0: f64::from(top),
+                        x1: f64::from(left + width),
+                        y1: f64::from(top + height),
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OcrBoundingGeometry, OcrConfidence};
+
+    #[test]
+    fn test_from_ocr_elements_uses_enclosing_rectangle() {
+        let elements = vec![
+            OcrElement::new(
+                " Hello ",
+                OcrBoundingGeometry::Quadrilateral {
+                    points: [(10, 20), (50, 18), (52, 40), (12, 42)],
+                },
+                OcrConfidence::from_tesseract(90.0),
+            )
+            .with_page_number(2),
+            OcrElement::new(
+                "  ",
+                OcrBoundingGeometry::Rectangle {
+                    left: 0,
+                    top: 0,
+                    width: 5,
+                    height: 5,
+                },
+                OcrConfidence::from_tesseract(90.0),
+            ),
+        ];
+
+        let blocks = TextBlock::from_ocr_elements(&elements);
+
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].text, "Hello");
+        assert_eq!(blocks[0].page, 2);
+        assert_eq!(
+            blocks[0].bbox,
+            BoundingBox {
+                x0: 10.0,
+                y0: 18.0,
+                x1: 52.0,
+                y1: 42.0
+            }
+        );
+    }
+}
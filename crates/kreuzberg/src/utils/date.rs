@@ -0,0 +1,467 @@
+//! Date normalization for metadata fields.
+//!
+//! Document formats store dates in many shapes: PDF date strings (`D:20230101120000+01'00'`),
+//! ISO 8601, RFC 2822 email headers, or plain "January 15, 2024". [`normalize_date`] turns all
+//! of these into an RFC 3339 timestamp so results can be sorted without format-specific parsing.
+//!
+//! Values without a time default to midnight, and values without a timezone are assumed to be UTC.
+
+/// Normalize a date string to RFC 3339 (`YYYY-MM-DDTHH:MM:SS[.fff](Z|±HH:MM)`).
+///
+/// Supported inputs:
+/// - PDF date strings: `D:YYYYMMDDHHmmSSOHH'mm'` (all parts after the year optional)
+/// - ISO 8601 dates and date-times: `2024-01-15`, `2024-01-15T10:30:00+02:00`, `2024-01-15 10:30`
+/// - RFC 2822: `Mon, 15 Jan 2024 10:30:00 +0100`
+/// - Written dates: `January 15, 2024`, `15 Jan 2024`, `2024/01/15`
+///
+/// Returns `None` if the value is not recognized or is not a valid calendar date.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::utils::normalize_date;
+///
+/// assert_eq!(
+///     normalize_date("D:20230101120000+01'00'").as_deref(),
+///     Some("2023-01-01T12:00:00+01:00")
+/// );
+/// assert_eq!(normalize_date("2024-01-15").as_deref(), Some("2024-01-15T00:00:00Z"));
+/// assert_eq!(normalize_date("not a date"), None);
+/// ```
+pub fn normalize_date(raw: &str) -> Option<String> {
+    let value = raw.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    parse_pdf_date(value)
+        .or_else(|| parse_iso_date(value))
+        .or_else(|| parse_rfc2822_date(value))
+        .or_else(|| parse_written_date(value))
+        .and_then(|date| date.validate())
+        .map(|date| date.to_rfc3339())
+}
+
+/// A parsed date-time before validation and formatting.
+#[derive(Debug, Default, PartialEq)]
+struct DateParts {
+    year: u32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    fraction: Option<String>,
+    /// Offset from UTC in minutes; `None` means UTC (`Z`).
+    offset_minutes: Option<i32>,
+}
+
+impl DateParts {
+    fn date(year: u32, month: u32, day: u32) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            ..Default::default()
+        }
+    }
+
+    fn validate(self) -> Option<Self> {
+        let valid = (1..=12).contains(&self.month)
+            && self.day >= 1
+            && self.day <= days_in_month(self.year, self.month)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second <= 60
+            && self.offset_minutes.is_none_or(|offset| offset.abs() < 24 * 60);
+        valid.then_some(self)
+    }
+
+    fn to_rfc3339(&self) -> String {
+        let mut out = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        );
+        if let Some(fraction) = &self.fraction {
+            out.push('.');
+            out.push_str(fraction);
+        }
+        match self.offset_minutes {
+            None | Some(0) => out.push('Z'),
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.abs();
+                out.push_str(&format!("{}{:02}:{:02}", sign, offset / 60, offset % 60));
+            }
+        }
+        out
+    }
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parse a run of exactly `len` ASCII digits at the start of `s`.
+fn take_digits(s: &str, len: usize) -> Option<(u32, &str)> {
+    let digits = s.get(..len)?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((digits.parse().ok()?, &s[len..]))
+}
+
+/// Parse a PDF date string (ISO 32000-1, section 7.9.4).
+///
+/// Without the `D:` prefix at least a full `YYYYMMDD` date is required, so plain
+/// numbers are not mistaken for dates.
+fn parse_pdf_date(value: &str) -> Option<DateParts> {
+    let (body, prefixed) = match value.strip_prefix("D:") {
+        Some(body) => (body, true),
+        None => (value, false),
+    };
+
+    let digit_count = body.bytes().take_while(u8::is_ascii_digit).count();
+    if digit_count < if prefixed { 4 } else { 8 } || digit_count > 14 || !digit_count.is_multiple_of(2) {
+        return None;
+    }
+
+    let (year, mut rest) = take_digits(body, 4)?;
+    let mut fields = [1u32, 1, 0, 0, 0];
+    for field in fields.iter_mut().take((digit_count - 4) / 2) {
+        let (parsed, remaining) = take_digits(rest, 2)?;
+        *field = parsed;
+        rest = remaining;
+    }
+    let [month, day, hour, minute, second] = fields;
+
+    let offset_minutes = match rest.chars().next() {
+        None | Some('Z') if rest.len() <= 1 || rest[1..].chars().all(|c| c == '0' || c == '\'') => None,
+        Some(sign @ ('+' | '-')) => {
+            let (hours, after_hours) = take_digits(&rest[1..], 2)?;
+            let after_hours = after_hours.trim_start_matches('\'');
+            let minutes = match take_digits(after_hours, 2) {
+                Some((minutes, tail)) if tail.is_empty() || tail == "'" => minutes,
+                Some(_) => return None,
+                None if after_hours.is_empty() => 0,
+                None => return None,
+            };
+            let offset = (hours * 60 + minutes) as i32;
+            Some(if sign == '-' { -offset } else { offset })
+        }
+        _ => return None,
+    };
+
+    Some(DateParts {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        fraction: None,
+        offset_minutes,
+    })
+}
+
+/// Parse an ISO 8601 date or date-time (`YYYY-MM-DD[(T| )HH:MM[:SS[.f+]]][Z|±HH[:]MM]`).
+fn parse_iso_date(value: &str) -> Option<DateParts> {
+    let (year, rest) = take_digits(value, 4)?;
+    let rest = rest.strip_prefix('-')?;
+    let (month, rest) = take_digits(rest, 2)?;
+    let rest = rest.strip_prefix('-')?;
+    let (day, rest) = take_digits(rest, 2)?;
+
+    let mut date = DateParts::date(year, month, day);
+    if rest.is_empty() {
+        return Some(date);
+    }
+
+    let rest = rest.strip_prefix(['T', 't', ' '])?;
+    let (time, zone) = parse_time(rest)?;
+    date.hour = time.0;
+    date.minute = time.1;
+    date.second = time.2;
+    date.fraction = time.3;
+    date.offset_minutes = parse_iso_offset(zone)?;
+    Some(date)
+}
+
+type TimeParts = (u32, u32, u32, Option<String>);
+
+/// Parse `HH:MM[:SS[.f+]]`, returning the remainder.
+fn parse_time(value: &str) -> Option<(TimeParts, &str)> {
+    let (hour, rest) = take_digits(value, 2)?;
+    let rest = rest.strip_prefix(':')?;
+    let (minute, mut rest) = take_digits(rest, 2)?;
+
+    let mut second = 0;
+    let mut fraction = None;
+    if let Some(after_colon) = rest.strip_prefix(':') {
+        let (parsed, after_seconds) = take_digits(after_colon, 2)?;
+        second = parsed;
+        rest = after_seconds;
+
+        if let Some(after_dot) = rest.strip_prefix(['.', ',']) {
+            let len = after_dot.bytes().take_while(u8::is_ascii_digit).count();
+            if len == 0 {
+                return None;
+            }
+            fraction = Some(after_dot[..len].to_string());
+            rest = &after_dot[len..];
+        }
+    }
+
+    Some(((hour, minute, second, fraction), rest))
+}
+
+/// Parse an ISO 8601 zone designator. The outer `None` means the zone is malformed.
+fn parse_iso_offset(zone: &str) -> Option<Option<i32>> {
+    let zone = zone.trim();
+    if zone.is_empty() || zone.eq_ignore_ascii_case("z") {
+        return Some(None);
+    }
+
+    let sign = match zone.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let (hours, rest) = take_digits(&zone[1..], 2)?;
+    let rest = rest.strip_prefix(':').unwrap_or(rest);
+    let minutes = if rest.is_empty() {
+        0
+    } else {
+        let (minutes, tail) = take_digits(rest, 2)?;
+        if !tail.is_empty() {
+            return None;
+        }
+        minutes
+    };
+    Some(Some(sign * (hours * 60 + minutes) as i32))
+}
+
+/// Parse an RFC 2822 date (`[Mon, ]15 Jan 2024 10:30[:00] +0100`).
+fn parse_rfc2822_date(value: &str) -> Option<DateParts> {
+    let value = match value.split_once(',') {
+        Some((weekday, rest)) if month_from_name(weekday.trim()).is_none() && is_weekday(weekday.trim()) => rest.trim(),
+        _ => value,
+    };
+
+    let mut tokens = value.split_whitespace();
+    let day: u32 = tokens.next()?.parse().ok()?;
+    let month = month_from_name(tokens.next()?)?;
+    let year: u32 = tokens.next()?.parse().ok()?;
+    let (time, tail) = parse_time(tokens.next()?)?;
+    if !tail.is_empty() {
+        return None;
+    }
+
+    let offset_minutes = match tokens.next() {
+        None => None,
+        Some(zone) if ["GMT", "UT", "UTC", "Z"].iter().any(|z| zone.eq_ignore_ascii_case(z)) => None,
+        Some(zone) => parse_iso_offset(zone)?,
+    };
+    // Trailing comments such as "(CET)" are ignored.
+    if tokens.any(|token| !token.starts_with('(')) {
+        return None;
+    }
+
+    Some(DateParts {
+        hour: time.0,
+        minute: time.1,
+        second: time.2,
+        fraction: time.3,
+        offset_minutes,
+        ..DateParts::date(year, month, day)
+    })
+}
+
+/// Parse date-only written forms: `January 15, 2024`, `15 January 2024`, and `2024/01/15`.
+fn parse_written_date(value: &str) -> Option<DateParts> {
+    let parts: Vec<&str> = value.split('/').collect();
+    if let [year, month, day] = parts.as_slice()
+        && year.len() == 4
+    {
+        return Some(DateParts::date(
+            year.parse().ok()?,
+            month.parse().ok()?,
+            day.parse().ok()?,
+        ));
+    }
+
+    let tokens: Vec<&str> = value
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .collect();
+    let [first, second, year] = tokens.as_slice() else {
+        return None;
+    };
+    let year: u32 = year.parse().ok()?;
+    if year < 1000 {
+        return None;
+    }
+
+    if let Some(month) = month_from_name(first) {
+        let day = second
+            .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+            .parse()
+            .ok()?;
+        Some(DateParts::date(year, month, day))
+    } else {
+        let month = month_from_name(second)?;
+        let day = first.trim_end_matches(|c: char| c.is_ascii_alphabetic()).parse().ok()?;
+        Some(DateParts::date(year, month, day))
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Map an English month name or three-letter abbreviation to its number.
+fn month_from_name(name: &str) -> Option<u32> {
+    let name = name.trim_end_matches('.').to_ascii_lowercase();
+    if name.len() < 3 {
+        return None;
+    }
+    MONTHS
+        .iter()
+        .position(|month| month.starts_with(&name) && (name.len() == 3 || name.len() == month.len() || name == "sept"))
+        .map(|index| index as u32 + 1)
+}
+
+fn is_weekday(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    ["mon", "tue", "wed", "thu", "fri", "sat", "sun"]
+        .iter()
+        .any(|day| name.starts_with(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pdf_date_with_positive_offset() {
+        assert_eq!(
+            normalize_date("D:20230101120000+01'00'").as_deref(),
+            Some("2023-01-01T12:00:00+01:00")
+        );
+    }
+
+    #[test]
+    fn test_pdf_date_offset_variants() {
+        assert_eq!(
+            normalize_date("D:20230101120000-05'30").as_deref(),
+            Some("2023-01-01T12:00:00-05:30")
+        );
+        assert_eq!(
+            normalize_date("D:20230101120000+02").as_deref(),
+            Some("2023-01-01T12:00:00+02:00")
+        );
+        assert_eq!(
+            normalize_date("D:20230101120000Z00'00'").as_deref(),
+            Some("2023-01-01T12:00:00Z")
+        );
+        assert_eq!(
+            normalize_date("D:20230101120000Z").as_deref(),
+            Some("2023-01-01T12:00:00Z")
+        );
+    }
+
+    #[test]
+    fn test_pdf_date_partial() {
+        assert_eq!(normalize_date("D:2023").as_deref(), Some("2023-01-01T00:00:00Z"));
+        assert_eq!(normalize_date("D:20230115").as_deref(), Some("2023-01-15T00:00:00Z"));
+        assert_eq!(
+            normalize_date("D:202301151230").as_deref(),
+            Some("2023-01-15T12:30:00Z")
+        );
+        assert_eq!(normalize_date("20230115").as_deref(), Some("2023-01-15T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_pdf_date_invalid() {
+        assert_eq!(normalize_date("D:20231301"), None);
+        assert_eq!(normalize_date("D:20230230"), None);
+        assert_eq!(normalize_date("D:20230101120000+01'00'junk"), None);
+        assert_eq!(normalize_date("12345"), None);
+    }
+
+    #[test]
+    fn test_iso_dates() {
+        assert_eq!(normalize_date("2024-01-15").as_deref(), Some("2024-01-15T00:00:00Z"));
+        assert_eq!(
+            normalize_date("2024-01-15T10:30:00Z").as_deref(),
+            Some("2024-01-15T10:30:00Z")
+        );
+        assert_eq!(
+            normalize_date("2024-01-15 10:30").as_deref(),
+            Some("2024-01-15T10:30:00Z")
+        );
+        assert_eq!(
+            normalize_date("2024-01-15T10:30:00.123+0200").as_deref(),
+            Some("2024-01-15T10:30:00.123+02:00")
+        );
+        assert_eq!(normalize_date("2024-02-30"), None);
+    }
+
+    #[test]
+    fn test_rfc2822_dates() {
+        assert_eq!(
+            normalize_date("Mon, 15 Jan 2024 10:30:00 +0100").as_deref(),
+            Some("2024-01-15T10:30:00+01:00")
+        );
+        assert_eq!(
+            normalize_date("15 Jan 2024 10:30:00 GMT").as_deref(),
+            Some("2024-01-15T10:30:00Z")
+        );
+        assert_eq!(
+            normalize_date("Tue, 1 Oct 2024 08:00:00 -0700 (PDT)").as_deref(),
+            Some("2024-10-01T08:00:00-07:00")
+        );
+    }
+
+    #[test]
+    fn test_written_dates() {
+        assert_eq!(
+            normalize_date("January 15, 2024").as_deref(),
+            Some("2024-01-15T00:00:00Z")
+        );
+        assert_eq!(normalize_date("15 Sept 2024").as_deref(), Some("2024-09-15T00:00:00Z"));
+        assert_eq!(normalize_date("Mar 3rd, 2024").as_deref(), Some("2024-03-03T00:00:00Z"));
+        assert_eq!(normalize_date("2024/01/15").as_deref(), Some("2024-01-15T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_unrecognized_dates() {
+        assert_eq!(normalize_date(""), None);
+        assert_eq!(normalize_date("yesterday"), None);
+        assert_eq!(normalize_date("01/02/2024"), None);
+    }
+
+    #[test]
+    fn test_leap_years() {
+        assert!(normalize_date("2024-02-29").is_some());
+        assert!(normalize_date("2023-02-29").is_none());
+        assert!(normalize_date("2000-02-29").is_some());
+        assert!(normalize_date("1900-02-29").is_none());
+    }
+}
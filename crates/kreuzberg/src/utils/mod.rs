@@ -4,6 +4,7 @@
 //! - Quality processing: clean OCR artifacts, calculate quality scores
 //! - String utilities: safe decoding, mojibake fixing, encoding detection
 //! - Object pooling: reusable pools for batch processing to reduce allocations
+//! - Date normalization: convert metadata dates to RFC 3339
 
 #[cfg(feature = "quality")]
 pub mod quality;
@@ -11,6 +12,7 @@ pub mod quality;
 #[cfg(feature = "quality")]
 pub mod string_utils;
 
+pub mod date;
 pub mod pool;
 pub mod pool_sizing;
 pub mod string_pool;
@@ -21,6 +23,8 @@ pub use quality::{calculate_quality_score, clean_extracted_text, normalize_space
 #[cfg(feature = "quality")]
 pub use string_utils::{calculate_text_confidence, fix_mojibake, safe_decode};
 
+pub use date::normalize_date;
+
 pub use pool::{
     ByteBufferPool, Pool, PoolError, PoolGuard, Recyclable, StringBufferPool, create_byte_buffer_pool,
     create_string_buffer_pool,
@@ -99,6 +99,8 @@ impl MimeStringPool {
             "application/x-research-info-systems",
             "application/x-typst",
             "text/csv",
+            "image/vnd.dxf",
+            "application/dxf",
             "text/tab-separated-values",
             "text/troff",
             "text/x-commonmark",
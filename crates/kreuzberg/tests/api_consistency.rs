@@ -242,6 +242,11 @@ fn test_extraction_config_no_unknown_fields_in_default() {
         "chunking",
         "images",
         "pdf_options",
+        "xlsx_options",
+        "docx_options",
+        "jupyter_options",
+        "archive_options",
+        "table_options",
         "token_reduction",
         "language_detection",
         "pages",
@@ -251,8 +256,19 @@ fn test_extraction_config_no_unknown_fields_in_default() {
         "max_concurrent_extractions",
         "result_format",
         "output_format",
+        "output_mode",
         "include_document_structure",
         "security_limits",
+        "max_content_chars",
+        "preserve_list_markers",
+        "extract_outline",
+        "extract_references",
+        "normalize_bidi",
+        "html_include_alt_text",
+        "emit_positions",
+        "emit_source_offsets",
+        "low_memory",
+        "normalize_newlines",
     ];
 
     for key in obj.keys() {
@@ -292,9 +308,15 @@ fn test_extraction_config_needs_image_processing() {
         extract_images: true,
         target_dpi: 150,
         max_image_dimension: 2000,
+        max_decode_dimension: 32768,
         auto_adjust_dpi: true,
         min_dpi: 72,
         max_dpi: 600,
+        max_images: None,
+        max_total_image_bytes: None,
+        min_width: 16,
+        min_height: 16,
+        output_format: kreuzberg::ImageFormat::Original,
     });
     assert!(
         config.needs_image_processing(),
@@ -50,3 +50,93 @@ Hello world\r\n\
 
     assert_eq!(content.trim_end_matches('\n'), "Hello world");
 }
+
+/// Multipart request uploading `files` as `(filename, content type, body)` to `uri`.
+fn multipart_request(uri: &str, files: &[(&str, &str, &str)]) -> Request<Body> {
+    let boundary = "X-BOUNDARY";
+    let mut body = String::new();
+    for (file_name, content_type, content) in files {
+        body.push_str(&format!(
+            "--{boundary}\r\n\
+Content-Disposition: form-data; name=\"files\"; filename=\"{file_name}\"\r\n\
+Content-Type: {content_type}\r\n\
+\r\n\
+{content}\r\n"
+        ));
+    }
+    body.push_str(&format!("--{boundary}--\r\n"));
+    let body_bytes = body.into_bytes();
+
+    Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", format!("multipart/form-data; boundary={boundary}"))
+        .header("content-length", body_bytes.len())
+        .body(Body::from(body_bytes))
+        .expect("Failed to build request")
+}
+
+const PARTIAL_BATCH: &[(&str, &str, &str)] = &[
+    ("good.txt", "text/plain", "Hello world"),
+    ("broken.pdf", "application/pdf", "this is not a pdf"),
+];
+
+#[tokio::test]
+async fn test_extract_returns_partial_results_by_default() {
+    let router = create_router_with_limits(ExtractionConfig::default(), ApiSizeLimits::from_mb(5, 5));
+
+    let response = router
+        .oneshot(multipart_request("/extract", PARTIAL_BATCH))
+        .await
+        .expect("Request failed");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let bytes = to_bytes(response.into_body(), 1_000_000)
+        .await
+        .expect("Failed to read body");
+    let value: Value = serde_json::from_slice(&bytes).expect("Response JSON parse failed");
+    assert_eq!(
+        value[0]["content"].as_str().map(|c| c.trim_end_matches('\n')),
+        Some("Hello world")
+    );
+    assert!(value[0]["metadata"].get("warnings").is_none());
+    assert!(value[1]["metadata"]["error"].is_object());
+}
+
+#[tokio::test]
+async fn test_extract_rejects_partial_results_when_not_ok() {
+    let router = create_router_with_limits(ExtractionConfig::default(), ApiSizeLimits::from_mb(5, 5));
+
+    let response = router
+        .oneshot(multipart_request("/extract?partial_ok=false", PARTIAL_BATCH))
+        .await
+        .expect("Request failed");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let bytes = to_bytes(response.into_body(), 1_000_000)
+        .await
+        .expect("Failed to read body");
+    let value: Value = serde_json::from_slice(&bytes).expect("Response JSON parse failed");
+    let message = value["message"]
+        .as_str()
+        .expect("Error response should include a message");
+    assert!(message.contains("partially succeeded"), "unexpected message: {message}");
+    assert!(message.contains("file 1"), "unexpected message: {message}");
+}
+
+#[tokio::test]
+async fn test_extract_fails_when_every_file_fails() {
+    let router = create_router_with_limits(ExtractionConfig::default(), ApiSizeLimits::from_mb(5, 5));
+
+    let response = router
+        .oneshot(multipart_request(
+            "/extract",
+            &[
+                ("a.pdf", "application/pdf", "not a pdf"),
+                ("b.pdf", "application/pdf", "not a pdf either"),
+            ],
+        ))
+        .await
+        .expect("Request failed");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
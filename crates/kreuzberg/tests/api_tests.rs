@@ -10,8 +10,8 @@ use serde_json::json;
 use tower::ServiceExt;
 
 use kreuzberg::{
-    ExtractionConfig,
-    api::{HealthResponse, InfoResponse, create_router},
+    ExtractionConfig, OcrConfig,
+    api::{HealthResponse, InfoResponse, ReadinessResponse, create_router},
 };
 
 /// Test the health check endpoint.
@@ -40,6 +40,77 @@ async fn test_health_endpoint() {
     assert!(!health.version.is_empty());
 }
 
+/// Test the readiness endpoint with the default configuration.
+#[tokio::test]
+async fn test_ready_endpoint() {
+    let app = create_router(ExtractionConfig::default());
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/ready")
+                .body(Body::empty())
+                .expect("Failed to create HTTP request body"),
+        )
+        .await
+        .expect("Failed to send HTTP request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read HTTP response body");
+    let ready: ReadinessResponse = serde_json::from_slice(&body).expect("Failed to deserialize JSON response");
+
+    assert_eq!(ready.status, "ready");
+    assert!(
+        ready
+            .checks
+            .iter()
+            .any(|check| check.name == "cache_directory" && check.ok)
+    );
+    assert!(!ready.checks.iter().any(|check| check.name == "ocr_backend"));
+}
+
+/// Test that the readiness endpoint reports a missing OCR backend.
+#[tokio::test]
+async fn test_ready_endpoint_missing_ocr_backend() {
+    let config = ExtractionConfig {
+        ocr: Some(OcrConfig {
+            backend: "nonexistent-backend".to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let app = create_router(config);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/ready")
+                .body(Body::empty())
+                .expect("Failed to create HTTP request body"),
+        )
+        .await
+        .expect("Failed to send HTTP request");
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("Failed to read HTTP response body");
+    let ready: ReadinessResponse = serde_json::from_slice(&body).expect("Failed to deserialize JSON response");
+
+    assert_eq!(ready.status, "not_ready");
+    let ocr_check = ready
+        .checks
+        .iter()
+        .find(|check| check.name == "ocr_backend")
+        .expect("OCR backend check should be present");
+    assert!(!ocr_check.ok);
+    assert!(ocr_check.message.is_some());
+}
+
 /// Test the info endpoint.
 #[tokio::test]
 async fn test_info_endpoint() {
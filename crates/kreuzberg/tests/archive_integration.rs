@@ -5,8 +5,9 @@
 
 #![cfg(feature = "archives")]
 
-use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::core::config::{ArchiveConfig, ArchiveMode, ExtractionConfig};
 use kreuzberg::core::extractor::{extract_bytes, extract_bytes_sync};
+use kreuzberg::extractors::security::SecurityLimits;
 use std::io::{Cursor, Write};
 use tar::Builder as TarBuilder;
 use zip::write::{FileOptions, ZipWriter};
@@ -343,6 +344,203 @@ async fn test_password_protected_archive() {
     assert!(result.is_err(), "Should fail on invalid/encrypted ZIP");
 }
 
+/// Test extracting every member of a ZIP of mixed formats into one result.
+#[cfg(all(feature = "office", feature = "html"))]
+#[tokio::test]
+async fn test_zip_members_combined() {
+    if helpers::skip_if_missing("archives/mixed_documents.zip") {
+        return;
+    }
+    let zip_bytes =
+        std::fs::read(helpers::get_test_file_path("archives/mixed_documents.zip")).expect("Failed to read fixture");
+    let config = ExtractionConfig {
+        archive_options: Some(ArchiveConfig::default()),
+        ..Default::default()
+    };
+
+    let result = extract_bytes(&zip_bytes, "application/zip", &config)
+        .await
+        .expect("Should extract ZIP members");
+
+    assert_eq!(result.mime_type, "application/zip");
+    assert!(!result.content.contains("ZIP Archive"), "Members replace the listing");
+    assert!(result.content.contains("Quarterly notes"));
+    assert!(result.content.contains("This archive bundles the quarterly documents"));
+    assert!(result.content.contains("All regions reported on time"));
+    assert!(
+        !result.tables.is_empty(),
+        "Tables of the CSV and DOCX members should be kept"
+    );
+
+    let members: Vec<&str> = result
+        .metadata
+        .additional
+        .get("archive_members")
+        .and_then(|members| members.as_array())
+        .expect("archive_members should be a list")
+        .iter()
+        .map(|member| member["path"].as_str().expect("member path"))
+        .collect();
+    assert_eq!(
+        members,
+        vec![
+            "notes.txt",
+            "readme.md",
+            "data/regions.csv",
+            "data/summary.html",
+            "report.docx"
+        ]
+    );
+    assert!(!result.metadata.additional.contains_key("archive_errors"));
+    assert!(matches!(
+        result.metadata.format,
+        Some(kreuzberg::FormatMetadata::Archive(_))
+    ));
+}
+
+/// Test returning each ZIP member as its own result.
+#[tokio::test]
+async fn test_zip_members_separate() {
+    let config = ExtractionConfig {
+        archive_options: Some(ArchiveConfig {
+            mode: ArchiveMode::Separate,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let result = extract_bytes(&create_simple_zip(), "application/zip", &config)
+        .await
+        .expect("Should extract ZIP members");
+
+    assert!(
+        result.content.contains("ZIP Archive"),
+        "Separate mode keeps the listing"
+    );
+    let documents = result
+        .metadata
+        .additional
+        .get("documents")
+        .and_then(|documents| documents.as_array())
+        .expect("documents should be a list");
+    assert_eq!(documents.len(), 1);
+    assert_eq!(documents[0]["mime_type"], "text/plain");
+    assert_eq!(
+        documents[0]["content"].as_str().map(|content| content.trim_end()),
+        Some("Hello from ZIP!")
+    );
+}
+
+/// Test decrypting a password-protected ZIP.
+#[tokio::test]
+async fn test_zip_members_with_password() {
+    let mut cursor = Cursor::new(Vec::new());
+    {
+        let mut zip = ZipWriter::new(&mut cursor);
+        let options = FileOptions::<'_, ()>::default().with_aes_encryption(zip::AesMode::Aes256, "s3cret");
+        zip.start_file("secret.txt", options).expect("Operation failed");
+        zip.write_all(b"Confidential figures").expect("Operation failed");
+        zip.finish().expect("Operation failed");
+    }
+    let zip_bytes = cursor.into_inner();
+
+    let config_with = |password: Option<&str>| ExtractionConfig {
+        archive_options: Some(ArchiveConfig {
+            password: password.map(str::to_string),
+            ..Default::default()
+        }),
+        use_cache: false,
+        ..Default::default()
+    };
+
+    let result = extract_bytes(&zip_bytes, "application/zip", &config_with(Some("s3cret")))
+        .await
+        .expect("Should decrypt ZIP with the right password");
+    assert!(result.content.contains("Confidential figures"));
+
+    let missing = extract_bytes(&zip_bytes, "application/zip", &config_with(None)).await;
+    assert!(missing.is_err(), "Encrypted ZIP without password should fail");
+
+    let wrong = extract_bytes(&zip_bytes, "application/zip", &config_with(Some("guess"))).await;
+    assert!(wrong.is_err(), "Encrypted ZIP with the wrong password should fail");
+}
+
+/// Test extracting ZIPs nested in ZIPs, up to `security_limits.max_nesting_depth`.
+#[tokio::test]
+async fn test_zip_members_nested_depth_limit() {
+    let mut nested = create_simple_zip();
+    for level in 0..3 {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+            zip.start_file(format!("level{}.zip", level), options)
+                .expect("Operation failed");
+            zip.write_all(&nested).expect("Operation failed");
+            zip.finish().expect("Operation failed");
+        }
+        nested = cursor.into_inner();
+    }
+
+    let config_with = |max_nesting_depth: usize| ExtractionConfig {
+        archive_options: Some(ArchiveConfig::default()),
+        security_limits: Some(SecurityLimits {
+            max_nesting_depth,
+            ..Default::default()
+        }),
+        use_cache: false,
+        ..Default::default()
+    };
+
+    let result = extract_bytes(&nested, "application/zip", &config_with(4))
+        .await
+        .expect("Four levels of archives should be within the limit");
+    assert!(result.content.contains("Hello from ZIP!"));
+
+    let err = extract_bytes(&nested, "application/zip", &config_with(3))
+        .await
+        .expect_err("Four levels of archives should exceed the limit");
+    assert!(matches!(err, kreuzberg::KreuzbergError::Validation { .. }), "{}", err);
+}
+
+/// Test that ZIPs nested in ZIPs share one file count budget.
+#[tokio::test]
+async fn test_zip_members_nested_share_file_limit() {
+    let mut nested = create_simple_zip();
+    for level in 0..3 {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+            zip.start_file(format!("level{}.zip", level), options)
+                .expect("Operation failed");
+            zip.write_all(&nested).expect("Operation failed");
+            zip.finish().expect("Operation failed");
+        }
+        nested = cursor.into_inner();
+    }
+
+    let config_with = |max_files_in_archive: usize| ExtractionConfig {
+        archive_options: Some(ArchiveConfig::default()),
+        security_limits: Some(SecurityLimits {
+            max_files_in_archive,
+            ..Default::default()
+        }),
+        use_cache: false,
+        ..Default::default()
+    };
+
+    let result = extract_bytes(&nested, "application/zip", &config_with(4))
+        .await
+        .expect("Four files across all archives should be within the limit");
+    assert!(result.content.contains("Hello from ZIP!"));
+
+    let err = extract_bytes(&nested, "application/zip", &config_with(3))
+        .await
+        .expect_err("Four files across all archives should exceed the limit");
+    assert!(matches!(err, kreuzberg::KreuzbergError::Validation { .. }), "{}", err);
+}
+
 /// Test corrupted archive.
 #[tokio::test]
 async fn test_corrupted_archive() {
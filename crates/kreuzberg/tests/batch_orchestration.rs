@@ -106,7 +106,7 @@ async fn test_batch_documents_concurrency_limiting() {
     assert_eq!(results.len(), 4);
 }
 
-/// Test batch extraction with CPU-bound limit (default: num_cpus * 2).
+/// Test batch extraction with CPU-bound limit (default: number of CPU cores).
 #[tokio::test]
 async fn test_batch_documents_default_concurrency() {
     use helpers::get_test_file_path;
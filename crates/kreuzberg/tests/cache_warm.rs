@@ -0,0 +1,69 @@
+//! Tests that extraction serves results stored by `cache::warm`.
+//!
+//! The extraction cache lives in the working directory, so this file holds a single test
+//! that switches to a temporary directory before touching it.
+
+#![cfg(feature = "tokio-runtime")]
+
+use kreuzberg::cache;
+use kreuzberg::{ExtractionConfig, extract_bytes, extract_file};
+use tempfile::tempdir;
+
+const CONTENT: &[u8] = b"Original content";
+
+#[tokio::test]
+async fn test_extraction_reads_warmed_cache() {
+    let dir = tempdir().expect("Operation failed");
+    let original_dir = std::env::current_dir().expect("Operation failed");
+    std::env::set_current_dir(dir.path()).expect("Operation failed");
+
+    let path = dir.path().join("notes.txt");
+    std::fs::write(&path, CONTENT).expect("Operation failed");
+    let config = ExtractionConfig::default();
+
+    let report = cache::warm(&[&path], &config).await.expect("Operation failed");
+    assert_eq!((report.hits, report.misses), (0, 1));
+
+    // Rewrite the warmed entry so a cache hit is distinguishable from a fresh extraction.
+    let extraction_cache = cache::extraction_cache().expect("Operation failed");
+    let mut warmed = cache::get_cached_extraction(&extraction_cache, &path, &config)
+        .expect("Operation failed")
+        .expect("file should be warmed");
+    warmed.content = "From the cache".to_string();
+    let key = cache::extraction_cache_key(CONTENT, "text/plain", &config).expect("Operation failed");
+    extraction_cache
+        .set(&key, rmp_serde::to_vec_named(&warmed).expect("Operation failed"), None)
+        .expect("Operation failed");
+
+    let from_file = extract_file(&path, None, &config).await.expect("Operation failed");
+    assert_eq!(from_file.content, "From the cache");
+    let from_bytes = extract_bytes(CONTENT, "text/plain", &config)
+        .await
+        .expect("Operation failed");
+    assert_eq!(from_bytes.content, "From the cache");
+
+    // Fields that only control caching, concurrency or memory use share the entry; fields
+    // that change the output must not be served the entry warmed with another config.
+    let low_memory = ExtractionConfig {
+        low_memory: true,
+        ..Default::default()
+    };
+    let from_low_memory = extract_file(&path, None, &low_memory).await.expect("Operation failed");
+    assert_eq!(from_low_memory.content, "From the cache");
+    let truncated = ExtractionConfig {
+        max_content_chars: Some(8),
+        ..Default::default()
+    };
+    let from_truncated = extract_file(&path, None, &truncated).await.expect("Operation failed");
+    assert_ne!(from_truncated.content, "From the cache");
+    assert!(from_truncated.content.starts_with("Original"));
+
+    let uncached = ExtractionConfig {
+        use_cache: false,
+        ..Default::default()
+    };
+    let fresh = extract_file(&path, None, &uncached).await.expect("Operation failed");
+    assert!(fresh.content.contains("Original content"));
+
+    std::env::set_current_dir(original_dir).expect("Operation failed");
+}
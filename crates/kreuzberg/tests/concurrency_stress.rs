@@ -142,6 +142,8 @@ async fn test_concurrent_extractions_with_cache() {
             disabled_processors: None,
             enabled_set: None,
             disabled_set: None,
+            header_footer_threshold: 0.6,
+            text_normalizer: Default::default(),
         }),
         ..Default::default()
     };
@@ -366,6 +368,8 @@ async fn test_concurrent_pipeline_processing() {
             disabled_processors: None,
             enabled_set: None,
             disabled_set: None,
+            header_footer_threshold: 0.6,
+            text_normalizer: Default::default(),
         }),
         ..Default::default()
     };
@@ -388,6 +392,11 @@ async fn test_concurrent_pipeline_processing() {
                 ocr_elements: None,
                 djot_content: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             };
 
             run_pipeline(result, &config).await
@@ -11,6 +11,8 @@ use kreuzberg::core::config::ChunkingConfig;
 use kreuzberg::core::config::ExtractionConfig;
 use kreuzberg::core::config::OutputFormat;
 use kreuzberg::core::extractor::extract_bytes;
+#[cfg(feature = "office")]
+use kreuzberg::core::extractor::extract_file;
 use kreuzberg::types::OutputFormat as ResultFormat;
 
 mod helpers;
@@ -271,6 +273,66 @@ async fn test_cache_disabled_does_not_crash() {
     assert!(!result.content.is_empty(), "Should still extract content");
 }
 
+/// Test extracting the same file twice produces byte-identical output
+#[tokio::test]
+#[cfg(feature = "office")]
+async fn test_extraction_is_deterministic() {
+    let relative_path = "docx/extraction_test.docx";
+    if helpers::skip_if_missing(relative_path) {
+        return;
+    }
+    let path = helpers::get_test_file_path(relative_path);
+
+    let config = ExtractionConfig {
+        use_cache: false,
+        #[cfg(feature = "keywords-rake")]
+        keywords: Some(kreuzberg::keywords::KeywordConfig::rake()),
+        ..Default::default()
+    };
+
+    let first = extract_file(&path, None, &config)
+        .await
+        .expect("First extraction should succeed");
+    let second = extract_file(&path, None, &config)
+        .await
+        .expect("Second extraction should succeed");
+
+    assert!(!first.content.is_empty(), "Should extract content");
+    assert_eq!(
+        first.content.as_bytes(),
+        second.content.as_bytes(),
+        "Repeated extraction should produce identical content"
+    );
+    let cells = |result: &kreuzberg::ExtractionResult| -> Vec<Vec<Vec<String>>> {
+        result.tables.iter().map(|table| table.cells.clone()).collect()
+    };
+    assert_eq!(
+        cells(&first),
+        cells(&second),
+        "Repeated extraction should produce identical tables"
+    );
+
+    // RAKE collects its candidates in a hash map, so this also checks that keywords with
+    // equal scores come out in the same order.
+    #[cfg(feature = "keywords-rake")]
+    {
+        let keywords = first
+            .metadata
+            .additional
+            .get("keywords")
+            .expect("Should extract keywords");
+        assert!(
+            keywords.as_array().is_some_and(|keywords| keywords.len() > 1),
+            "Should extract several keywords"
+        );
+        assert_eq!(
+            Some(keywords),
+            second.metadata.additional.get("keywords"),
+            "Repeated extraction should produce keywords in the same order"
+        );
+    }
+}
+
 /// Test quality_processing enabled produces quality score
 #[tokio::test]
 #[cfg(feature = "quality")]
@@ -25,6 +25,9 @@ async fn test_chunking_enabled() {
             preset: None,
             trim: true,
             chunker_type: kreuzberg::chunking::ChunkerType::Text,
+            overlap_unit: kreuzberg::chunking::OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         }),
         ..Default::default()
     };
@@ -70,6 +73,9 @@ async fn test_chunking_with_overlap() {
             preset: None,
             trim: true,
             chunker_type: kreuzberg::chunking::ChunkerType::Text,
+            overlap_unit: kreuzberg::chunking::OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         }),
         ..Default::default()
     };
@@ -112,6 +118,9 @@ async fn test_chunking_custom_sizes() {
             preset: None,
             trim: true,
             chunker_type: kreuzberg::chunking::ChunkerType::Text,
+            overlap_unit: kreuzberg::chunking::OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         }),
         ..Default::default()
     };
@@ -357,6 +366,7 @@ async fn test_token_reduction_aggressive() {
         token_reduction: Some(TokenReductionConfig {
             mode: "aggressive".to_string(),
             preserve_important_words: true,
+            language: None,
         }),
         ..Default::default()
     };
@@ -378,6 +388,7 @@ async fn test_token_reduction_conservative() {
         token_reduction: Some(TokenReductionConfig {
             mode: "light".to_string(),
             preserve_important_words: true,
+            language: None,
         }),
         ..Default::default()
     };
@@ -399,6 +410,7 @@ async fn test_token_reduction_disabled() {
         token_reduction: Some(TokenReductionConfig {
             mode: "off".to_string(),
             preserve_important_words: false,
+            language: None,
         }),
         ..Default::default()
     };
@@ -413,6 +425,50 @@ async fn test_token_reduction_disabled() {
     assert!(result.content.contains("without token reduction"));
 }
 
+/// Test token reduction statistics - aggressive mode reduces more than light mode.
+#[tokio::test]
+#[cfg(feature = "quality")]
+async fn test_token_reduction_stats_aggressive_exceeds_light() {
+    let text = "The quick brown fox jumps over the lazy dog and then it runs into the forest. \
+                This is a very long sentence with many unnecessary words that could be reduced. "
+        .repeat(5);
+
+    let mut ratios = Vec::new();
+    for mode in ["light", "aggressive"] {
+        let config = ExtractionConfig {
+            use_cache: false,
+            token_reduction: Some(TokenReductionConfig {
+                mode: mode.to_string(),
+                preserve_important_words: true,
+                language: None,
+            }),
+            ..Default::default()
+        };
+
+        let result = extract_bytes(text.as_bytes(), "text/plain", &config)
+            .await
+            .expect("Should extract successfully");
+
+        let stats = result
+            .metadata
+            .additional
+            .get("token_reduction_stats")
+            .expect("Token reduction stats should be recorded");
+        ratios.push(
+            stats["reduction_ratio"]
+                .as_f64()
+                .expect("reduction_ratio should be a number"),
+        );
+    }
+
+    assert!(
+        ratios[1] > ratios[0],
+        "aggressive ratio {} should exceed light ratio {}",
+        ratios[1],
+        ratios[0]
+    );
+}
+
 /// Test quality processing enabled - quality scoring applied.
 #[tokio::test]
 #[cfg(feature = "quality")]
@@ -524,6 +580,9 @@ async fn test_chunking_with_embeddings() {
             preset: None,
             trim: true,
             chunker_type: kreuzberg::chunking::ChunkerType::Text,
+            overlap_unit: kreuzberg::chunking::OverlapUnit::Characters,
+            max_chunks: None,
+            balance: false,
         }),
         ..Default::default()
     };
@@ -3,6 +3,7 @@
 //! Tests the config loading APIs:
 //! - from_file() with TOML/YAML/JSON
 //! - discover() for searching parent directories
+//! - discover_with_source() for reporting which file was loaded
 //! - Error handling for invalid configs
 
 use kreuzberg::KreuzbergError;
@@ -281,6 +282,34 @@ enabled = true
     );
 }
 
+/// Test discover_with_source() reports the parent-directory file it loaded.
+#[test]
+#[serial_test::serial]
+fn test_discover_with_source_reports_config_path() {
+    let temp_dir = TempDir::new().expect("Operation failed");
+    let config_path = temp_dir.path().join("kreuzberg.toml");
+    fs::write(&config_path, "use_cache = false\n").expect("Operation failed");
+
+    let sub_dir = temp_dir.path().join("subdir");
+    fs::create_dir(&sub_dir).expect("Operation failed");
+
+    let original_dir = std::env::current_dir().expect("Operation failed");
+    std::env::set_current_dir(&sub_dir).expect("Operation failed");
+
+    let result = ExtractionConfig::discover_with_source();
+
+    std::env::set_current_dir(original_dir).expect("Operation failed");
+
+    let (config, source) = result
+        .expect("Discover should succeed")
+        .expect("Should find config in parent directory");
+    assert!(!config.use_cache);
+    assert_eq!(
+        source.canonicalize().expect("Operation failed"),
+        config_path.canonicalize().expect("Operation failed")
+    );
+}
+
 /// Test discover() returns None when no config found.
 #[test]
 #[serial_test::serial]
@@ -421,7 +421,7 @@ async fn test_unsupported_mime_type_error() {
     assert!(result.is_err());
     assert!(matches!(
         result.unwrap_err(),
-        kreuzberg::KreuzbergError::UnsupportedFormat(_)
+        kreuzberg::KreuzbergError::UnsupportedFormat { .. }
     ));
 }
 
@@ -179,6 +179,47 @@ async fn test_docx_paragraphs_separated_by_blank_lines() {
     );
 }
 
+#[tokio::test]
+async fn test_docx_outline_from_heading_styles() {
+    let path = get_test_file_path("docx/unit_test_headers.docx");
+    if !path.exists() {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        extract_outline: true,
+        ..Default::default()
+    };
+    let result = extract_file(&path, None, &config).await.expect("Should extract DOCX");
+
+    let outline = result
+        .outline
+        .expect("Outline should be populated when extract_outline is set");
+    assert_eq!(outline.len(), 1, "Title should be the single root. Got: {:?}", outline);
+    assert_eq!(outline[0].title, "Test Document");
+    assert_eq!(
+        outline[0].children.len(),
+        2,
+        "Both Heading1 sections nest under the title"
+    );
+    assert!(outline[0].children.iter().all(|section| section.level == 2));
+    assert!(outline[0].children[0].title.starts_with("Section 1"));
+}
+
+#[tokio::test]
+async fn test_docx_outline_absent_by_default() {
+    let path = get_test_file_path("docx/unit_test_headers.docx");
+    if !path.exists() {
+        return;
+    }
+
+    let result = extract_file(&path, None, &ExtractionConfig::default())
+        .await
+        .expect("Should extract DOCX");
+
+    assert!(result.outline.is_none());
+}
+
 // ---------------------------------------------------------------------------
 // List tests
 // ---------------------------------------------------------------------------
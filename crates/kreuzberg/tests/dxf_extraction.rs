@@ -0,0 +1,33 @@
+//! DXF extraction integration tests.
+//!
+//! Verifies that TEXT and MTEXT annotations are extracted from an ASCII DXF drawing
+//! while geometry is skipped, and that their layers are reported in metadata.
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+
+const DXF_DRAWING: &str = "cad/mounting_bracket.dxf";
+
+#[test]
+fn test_dxf_text_entities_extracted_with_layers() {
+    if skip_if_missing(DXF_DRAWING) {
+        return;
+    }
+
+    let file_path = get_test_file_path(DXF_DRAWING);
+    let result = extract_file_sync(&file_path, None, &ExtractionConfig::default()).expect("Failed to extract DXF");
+
+    assert_mime_type(&result, "image/vnd.dxf");
+    assert_eq!(
+        result.content,
+        "⌀12 H7 THROUGH\nALL EDGES DEBURRED\nMOUNTING BRACKET\nMaterial: 6061-T6 Aluminium\nTolerance: ±0.1"
+    );
+    assert_eq!(
+        result.metadata.additional.get("layers"),
+        Some(&serde_json::json!(["ANNOTATIONS", "TITLE_BLOCK"]))
+    );
+    assert!(result.tables.is_empty());
+}
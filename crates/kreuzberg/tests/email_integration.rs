@@ -136,6 +136,27 @@ Content-Type: text/html; charset=utf-8\r\n\
     assert_eq!(result.metadata.subject, Some("HTML Email".to_string()));
 }
 
+/// Test that an HTML-only EML body is converted to Markdown rather than stripped.
+#[cfg(feature = "html")]
+#[tokio::test]
+async fn test_eml_html_only_body_converted_to_markdown() {
+    if helpers::skip_if_missing("email/html_only.eml") {
+        return;
+    }
+
+    let config = ExtractionConfig::default();
+    let data = std::fs::read(helpers::get_test_file_path("email/html_only.eml")).unwrap();
+    let result = extract_bytes(&data, "message/rfc822", &config)
+        .await
+        .expect("Should extract HTML-only email");
+
+    assert!(result.content.contains("# Welcome to Our Service"));
+    assert!(result.content.contains("**only HTML**"));
+    assert!(!result.content.contains("Should not appear"));
+    assert!(result.content.contains("From: html-sender@example.com"));
+    assert_eq!(result.metadata.subject, Some("HTML Only Email".to_string()));
+}
+
 /// Test EML with plain text body.
 #[tokio::test]
 async fn test_eml_plain_text_body() {
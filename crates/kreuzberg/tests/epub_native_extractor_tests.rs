@@ -264,3 +264,58 @@ async fn test_native_epub_no_content_loss() {
 
     println!("✅ All EPUBs extracted successfully - no content loss!");
 }
+
+/// Test 7: Chapter boundaries follow the spine order
+///
+/// Validates:
+/// - Each chapter maps to a page boundary with `chapter` unit type
+/// - Boundaries are contiguous, ordered and within the content
+/// - Per-chapter content is produced when page extraction is enabled
+#[tokio::test]
+async fn test_native_epub_chapter_boundaries() {
+    use kreuzberg::core::config::PageConfig;
+    use kreuzberg::types::PageUnitType;
+
+    let test_file = get_test_epub_path("features.epub");
+    if !test_file.exists() {
+        println!("Skipping test: Test file not found at {:?}", test_file);
+        return;
+    }
+
+    let bytes = std::fs::read(&test_file).expect("Failed to read features.epub");
+    let extractor = EpubExtractor::new();
+    let config = ExtractionConfig {
+        pages: Some(PageConfig {
+            extract_pages: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let result = extractor
+        .extract_bytes(&bytes, "application/epub+zip", &config)
+        .await
+        .expect("Should extract features.epub successfully");
+
+    let structure = result.metadata.pages.as_ref().expect("Should have chapter structure");
+    assert_eq!(structure.unit_type, PageUnitType::Chapter);
+    assert!(structure.total_count > 1, "features.epub has multiple chapters");
+
+    let boundaries = structure.boundaries.as_ref().expect("Should have chapter boundaries");
+    assert_eq!(boundaries.len(), structure.total_count);
+    for (index, boundary) in boundaries.iter().enumerate() {
+        assert_eq!(boundary.page_number, index + 1);
+        assert!(boundary.byte_start < boundary.byte_end);
+        assert!(boundary.byte_end <= result.content.len());
+        if index > 0 {
+            assert!(boundary.byte_start >= boundaries[index - 1].byte_end);
+        }
+    }
+
+    let pages = result.pages.as_ref().expect("Should have per-chapter content");
+    assert_eq!(pages.len(), boundaries.len());
+    assert_eq!(
+        pages[0].content,
+        result.content[boundaries[0].byte_start..boundaries[0].byte_end]
+    );
+}
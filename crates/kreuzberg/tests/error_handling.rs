@@ -305,12 +305,35 @@ async fn test_unsupported_format() {
 
     let error = result.unwrap_err();
     assert!(
-        matches!(error, kreuzberg::KreuzbergError::UnsupportedFormat(_)),
+        matches!(error, kreuzberg::KreuzbergError::UnsupportedFormat { .. }),
         "Should be UnsupportedFormat error, got: {:?}",
         error
     );
 }
 
+/// Test recognized but unsupported format - Photoshop document detected from its extension.
+#[tokio::test]
+async fn test_unsupported_detected_format_psd() {
+    let config = ExtractionConfig::default();
+
+    let mut psd_file = tempfile::Builder::new().suffix(".psd").tempfile().unwrap();
+    psd_file.write_all(b"8BPS\x00\x01\x00\x00\x00\x00\x00\x00").unwrap();
+
+    let result = extract_file(psd_file.path(), None, &config).await;
+
+    match result {
+        Err(kreuzberg::KreuzbergError::UnsupportedFormat {
+            mime_type,
+            detected_extension,
+            ..
+        }) => {
+            assert_eq!(mime_type, "image/vnd.adobe.photoshop");
+            assert_eq!(detected_extension.as_deref(), Some("psd"));
+        }
+        other => panic!("Expected UnsupportedFormat error for .psd, got: {:?}", other),
+    }
+}
+
 /// Test permission denied - no read access (platform-specific).
 #[tokio::test]
 #[cfg(unix)]
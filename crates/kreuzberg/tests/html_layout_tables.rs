@@ -0,0 +1,56 @@
+//! Integration tests for skipping HTML layout tables.
+
+#![cfg(feature = "html")]
+
+mod helpers;
+
+use helpers::{get_test_file_path, skip_if_missing};
+use kreuzberg::core::config::{ExtractionConfig, TableDetection, TableExtractionConfig};
+use kreuzberg::extract_file;
+
+#[tokio::test]
+async fn test_html_data_tables_skips_layout_tables() {
+    if skip_if_missing("html/layout_tables.html") {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        table_options: Some(TableExtractionConfig {
+            html_table_detection: TableDetection::DataTables,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = extract_file(get_test_file_path("html/layout_tables.html"), None, &config)
+        .await
+        .expect("HTML extraction should succeed");
+
+    assert_eq!(result.tables.len(), 1, "only the sales table should be extracted");
+    let table = &result.tables[0];
+    assert_eq!(table.cells[0], vec!["Region", "Q1", "Q2"]);
+    assert_eq!(table.cells[1], vec!["North", "120", "135"]);
+    assert_eq!(table.cells[2], vec!["South", "98", "104"]);
+
+    assert!(result.content.contains("Quarterly revenue by region"));
+    assert!(result.content.contains("Copyright 2024 Example Corp"));
+}
+
+#[tokio::test]
+async fn test_html_all_tables_is_default() {
+    if skip_if_missing("html/layout_tables.html") {
+        return;
+    }
+
+    let result = extract_file(
+        get_test_file_path("html/layout_tables.html"),
+        None,
+        &ExtractionConfig::default(),
+    )
+    .await
+    .expect("HTML extraction should succeed");
+
+    assert!(
+        result.tables.iter().any(|table| table.cells[0][0] == "Example Corp"),
+        "the presentation table is extracted unless data_tables is selected"
+    );
+}
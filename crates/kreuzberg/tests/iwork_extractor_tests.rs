@@ -0,0 +1,78 @@
+//! Apple iWork (Pages, Numbers) extraction integration tests.
+//!
+//! Verifies that text comes from the embedded `QuickLook/Preview.pdf` and that bundle
+//! metadata is read from `Metadata/*.plist`.
+
+#![cfg(all(feature = "office", feature = "pdf"))]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::core::mime::detect_mime_type_from_bytes;
+use kreuzberg::extract_file_sync;
+
+const PAGES_DOCUMENT: &str = "iwork/sample.pages";
+const NUMBERS_DOCUMENT: &str = "iwork/sample.numbers";
+
+#[test]
+fn test_pages_preview_text_and_metadata() {
+    if skip_if_missing(PAGES_DOCUMENT) {
+        return;
+    }
+
+    let file_path = get_test_file_path(PAGES_DOCUMENT);
+    let result = extract_file_sync(&file_path, None, &ExtractionConfig::default()).expect("Failed to extract Pages");
+
+    assert_mime_type(&result, "application/vnd.apple.pages");
+    assert!(result.content.contains("Quarterly Report"));
+    assert!(result.content.contains("Revenue grew in every region"));
+
+    let metadata = &result.metadata.additional;
+    assert_eq!(
+        metadata.get("document_uuid"),
+        Some(&serde_json::json!("5B9E7D1A-2C4F-4E8B-9A61-0F3D2C8B7E11"))
+    );
+    assert_eq!(metadata.get("file_format_version"), Some(&serde_json::json!("14.0.1")));
+    assert_eq!(
+        metadata.get("preview_source"),
+        Some(&serde_json::json!("QuickLook/Preview.pdf"))
+    );
+    assert_eq!(
+        metadata.get("build_versions"),
+        Some(&serde_json::json!(["Template: Blank (14.0)", "M14.0-7040.0.73-4"]))
+    );
+}
+
+#[test]
+fn test_numbers_preview_text() {
+    if skip_if_missing(NUMBERS_DOCUMENT) {
+        return;
+    }
+
+    let file_path = get_test_file_path(NUMBERS_DOCUMENT);
+    let result = extract_file_sync(&file_path, None, &ExtractionConfig::default()).expect("Failed to extract Numbers");
+
+    assert_mime_type(&result, "application/vnd.apple.numbers");
+    assert!(result.content.contains("Household Budget"));
+    assert!(result.content.contains("Groceries 450"));
+}
+
+#[test]
+fn test_iwork_mime_detection_from_bytes() {
+    if skip_if_missing(PAGES_DOCUMENT) || skip_if_missing(NUMBERS_DOCUMENT) {
+        return;
+    }
+
+    let pages = std::fs::read(get_test_file_path(PAGES_DOCUMENT)).unwrap();
+    let numbers = std::fs::read(get_test_file_path(NUMBERS_DOCUMENT)).unwrap();
+
+    assert_eq!(
+        detect_mime_type_from_bytes(&pages).unwrap(),
+        "application/vnd.apple.pages"
+    );
+    assert_eq!(
+        detect_mime_type_from_bytes(&numbers).unwrap(),
+        "application/vnd.apple.numbers"
+    );
+}
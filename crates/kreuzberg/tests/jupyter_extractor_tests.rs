@@ -702,3 +702,60 @@ async fn test_jupyter_pandoc_baseline_alignment() {
         );
     }
 }
+
+/// Test r_outputs.ipynb - Validates the kernel language and output filtering.
+///
+/// Notebook contains:
+/// - Markdown cell with a heading (intro)
+/// - R code cell with stream output and an execute_result in text/html and text/plain (totals)
+/// - R code cell with an error output (missing)
+#[tokio::test]
+#[cfg(feature = "office")]
+async fn test_jupyter_kernel_language_and_output_filtering() {
+    use kreuzberg::core::config::{JupyterConfig, NotebookOutputs};
+
+    let notebook_content = fs::read(jupyter_fixture("r_outputs.ipynb")).expect("Failed to read r_outputs.ipynb");
+    let extract = |outputs: NotebookOutputs| {
+        let config = ExtractionConfig {
+            jupyter_options: Some(JupyterConfig { outputs }),
+            ..Default::default()
+        };
+        let notebook_content = notebook_content.clone();
+        async move {
+            extract_bytes(&notebook_content, "application/x-ipynb+json", &config)
+                .await
+                .expect("Failed to extract r_outputs.ipynb")
+        }
+    };
+
+    let all = extract(NotebookOutputs::All).await;
+    assert!(all.content.contains("# Sales summary"));
+    assert!(
+        all.content.contains("```R\nsales <- read.csv(\"sales.csv\")"),
+        "Code should be fenced with the kernel language"
+    );
+    assert!(all.content.contains("Loaded 4 regions"));
+    assert!(all.content.contains("<td>North</td>"));
+    assert!(all.content.contains("1  North   120"));
+    assert!(all.content.contains("object 'profit' not found"));
+    assert_eq!(
+        all.metadata.additional.get("kernel_language").and_then(|v| v.as_str()),
+        Some("R")
+    );
+
+    let text = extract(NotebookOutputs::Text).await;
+    assert!(text.content.contains("Loaded 4 regions"));
+    assert!(text.content.contains("1  North   120"));
+    assert!(text.content.contains("object 'profit' not found"));
+    assert!(
+        !text.content.contains("<td>North</td>"),
+        "Text outputs should drop HTML representations"
+    );
+
+    let none = extract(NotebookOutputs::None).await;
+    assert!(none.content.contains("Quarterly totals by region."));
+    assert!(none.content.contains("summary(profit)"));
+    assert!(!none.content.contains("Loaded 4 regions"));
+    assert!(!none.content.contains("1  North   120"));
+    assert!(!none.content.contains(".output"));
+}
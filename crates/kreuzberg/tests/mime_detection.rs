@@ -220,7 +220,7 @@ async fn test_unknown_mime_type() {
 
         let error = result.unwrap_err();
         assert!(
-            matches!(error, kreuzberg::KreuzbergError::UnsupportedFormat(_)),
+            matches!(error, kreuzberg::KreuzbergError::UnsupportedFormat { .. }),
             "Should return UnsupportedFormat error for: {}",
             mime_type
         );
@@ -300,7 +300,7 @@ async fn test_no_extension() {
             assert!(
                 matches!(
                     error,
-                    kreuzberg::KreuzbergError::Validation { .. } | kreuzberg::KreuzbergError::UnsupportedFormat(_)
+                    kreuzberg::KreuzbergError::Validation { .. } | kreuzberg::KreuzbergError::UnsupportedFormat { .. }
                 ),
                 "Should return appropriate error for file without extension"
             );
@@ -459,3 +459,136 @@ async fn test_pandoc_mime_validation() {
         assert_eq!(result.expect("Operation failed"), mime_type);
     }
 }
+
+/// Test that a registered MIME detector recognizes a magic-number format and routes it
+/// to a custom extractor.
+#[tokio::test]
+async fn test_registered_mime_detector_recognizes_magic_number() {
+    use async_trait::async_trait;
+    use kreuzberg::core::config::ExtractionConfig;
+    use kreuzberg::core::mime::{detect_mime_type_from_bytes, detect_mime_type_from_reader};
+    use kreuzberg::plugins::{
+        DocumentExtractor, MimeDetector, Plugin, register_extractor, register_mime_detector, unregister_extractor,
+        unregister_mime_detector,
+    };
+    use kreuzberg::types::{ExtractionResult, Metadata};
+    use std::sync::Arc;
+
+    const LEDGER_MIME_TYPE: &str = "application/x-kreuzberg-ledger";
+
+    struct LedgerDetector;
+
+    impl Plugin for LedgerDetector {
+        fn name(&self) -> &str {
+            "ledger-detector"
+        }
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+        fn initialize(&self) -> kreuzberg::Result<()> {
+            Ok(())
+        }
+        fn shutdown(&self) -> kreuzberg::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl MimeDetector for LedgerDetector {
+        fn detect(&self, header: &[u8]) -> Option<String> {
+            header.starts_with(b"LDGR\x01").then(|| LEDGER_MIME_TYPE.to_string())
+        }
+    }
+
+    struct LedgerExtractor;
+
+    impl Plugin for LedgerExtractor {
+        fn name(&self) -> &str {
+            "ledger-extractor"
+        }
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+        fn initialize(&self) -> kreuzberg::Result<()> {
+            Ok(())
+        }
+        fn shutdown(&self) -> kreuzberg::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl DocumentExtractor for LedgerExtractor {
+        async fn extract_bytes(
+            &self,
+            content: &[u8],
+            mime_type: &str,
+            _config: &ExtractionConfig,
+        ) -> kreuzberg::Result<ExtractionResult> {
+            Ok(ExtractionResult {
+                content: String::from_utf8_lossy(&content[5..]).into_owned(),
+                mime_type: mime_type.to_string().into(),
+                metadata: Metadata::default(),
+                tables: vec![],
+                detected_languages: None,
+                chunks: None,
+                images: None,
+                pages: None,
+                djot_content: None,
+                elements: None,
+                ocr_elements: None,
+                document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
+            })
+        }
+
+        fn supported_mime_types(&self) -> &[&str] {
+            &[LEDGER_MIME_TYPE]
+        }
+    }
+
+    let ledger = b"LDGR\x01opening balance 42".to_vec();
+
+    assert!(detect_mime_type_from_bytes(&ledger).is_ok_and(|mime| mime != LEDGER_MIME_TYPE));
+    assert!(validate_mime_type(LEDGER_MIME_TYPE).is_err());
+
+    register_mime_detector(Arc::new(LedgerDetector)).expect("Failed to register detector");
+    register_extractor(Arc::new(LedgerExtractor)).expect("Failed to register extractor");
+
+    assert_eq!(detect_mime_type_from_bytes(&ledger).unwrap(), LEDGER_MIME_TYPE);
+    let (mime_type, _) = detect_mime_type_from_reader(&mut std::io::Cursor::new(&ledger)).unwrap();
+    assert_eq!(mime_type, LEDGER_MIME_TYPE);
+    assert_eq!(
+        detect_mime_type_from_bytes(b"%PDF-1.7\n").unwrap(),
+        "application/pdf",
+        "Unrecognized content should fall through to the built-in detection"
+    );
+
+    // The detector wins over a misleading extension.
+    let mut file = tempfile::Builder::new().suffix(".txt").tempfile().unwrap();
+    file.write_all(&ledger).unwrap();
+    file.flush().unwrap();
+    assert_eq!(detect_mime_type(file.path(), true).unwrap(), LEDGER_MIME_TYPE);
+    assert_eq!(
+        detect_mime_type(file.path(), false).unwrap(),
+        "text/plain",
+        "Without check_exists the path is a name only and the file is not read"
+    );
+
+    let config = ExtractionConfig {
+        use_cache: false,
+        ..Default::default()
+    };
+    let result = kreuzberg::extract_file(file.path(), None, &config).await;
+
+    unregister_extractor("ledger-extractor").expect("Failed to unregister extractor");
+    unregister_mime_detector("ledger-detector").expect("Failed to unregister detector");
+
+    let result = result.expect("Custom format should be extracted");
+    assert_eq!(result.content, "opening balance 42");
+    assert_eq!(result.mime_type, LEDGER_MIME_TYPE);
+    assert_eq!(detect_mime_type(file.path(), true).unwrap(), "text/plain");
+}
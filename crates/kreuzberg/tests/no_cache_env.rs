@@ -0,0 +1,33 @@
+//! Tests for the `KREUZBERG_NO_CACHE` global cache kill switch.
+//!
+//! The variable is read once per process, so this file holds a single test that sets it
+//! before any config is built.
+
+use kreuzberg::ExtractionConfig;
+use kreuzberg::cache::{NO_CACHE_ENV_VAR, cache_disabled_by_env};
+
+#[test]
+#[allow(unsafe_code)]
+fn test_no_cache_env_overrides_config() {
+    unsafe {
+        std::env::set_var(NO_CACHE_ENV_VAR, "1");
+        std::env::set_var("KREUZBERG_CACHE_ENABLED", "true");
+    }
+
+    assert!(cache_disabled_by_env());
+    assert!(!ExtractionConfig::default().use_cache);
+
+    let config: ExtractionConfig = serde_json::from_str(r#"{"use_cache": true}"#).expect("valid config");
+    assert!(!config.use_cache, "env override must beat an explicit config value");
+
+    let mut config = ExtractionConfig::default();
+    config.apply_env_overrides().expect("valid env overrides");
+    assert!(
+        !config.use_cache,
+        "KREUZBERG_NO_CACHE must beat KREUZBERG_CACHE_ENABLED"
+    );
+
+    unsafe {
+        std::env::remove_var("KREUZBERG_CACHE_ENABLED");
+    }
+}
@@ -16,7 +16,7 @@
 mod helpers;
 
 use helpers::*;
-use kreuzberg::core::config::{ExtractionConfig, OcrConfig};
+use kreuzberg::core::config::{ExtractionConfig, OcrConfig, Rect};
 use kreuzberg::extract_file_sync;
 use kreuzberg::types::TesseractConfig;
 
@@ -268,6 +268,205 @@ fn test_force_ocr_disabled() {
     assert!(result.metadata.format.is_some(), "PDF should have metadata");
 }
 
+#[test]
+fn test_ocr_empty_pages_reported_for_blank_image() {
+    if skip_if_missing("images/blank_page.png") {
+        return;
+    }
+
+    let file_path = get_test_file_path("images/blank_page.png");
+    let config = ExtractionConfig {
+        ocr: Some(OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "eng".to_string(),
+            empty_page_confidence: Some(0.0),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let result = extract_file_sync(&file_path, None, &config).expect("Should extract blank image");
+
+    assert!(result.content.trim().is_empty(), "Blank image should have no text");
+    assert_eq!(
+        result.metadata.additional.get("ocr_empty_pages"),
+        Some(&serde_json::json!([1]))
+    );
+
+    let config = ExtractionConfig {
+        ocr: Some(OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "eng".to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = extract_file_sync(&file_path, None, &config).expect("Should extract blank image");
+    assert!(
+        !result.metadata.additional.contains_key("ocr_empty_pages"),
+        "Empty pages are only reported when empty_page_confidence is set"
+    );
+}
+
+#[test]
+#[cfg(feature = "pdf")]
+fn test_ocr_empty_pages_reported_for_blank_pdf() {
+    if skip_if_missing("pdf/blank_page.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/blank_page.pdf");
+    let config = ExtractionConfig {
+        ocr: Some(OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "eng".to_string(),
+            empty_page_confidence: Some(0.5),
+            ..Default::default()
+        }),
+        force_ocr: true,
+        ..Default::default()
+    };
+
+    let result = extract_file_sync(&file_path, None, &config).expect("Should extract blank PDF with OCR");
+
+    assert_mime_type(&result, "application/pdf");
+    assert_eq!(
+        result.metadata.additional.get("ocr_empty_pages"),
+        Some(&serde_json::json!([1]))
+    );
+    assert!(
+        !result.metadata.additional.contains_key("ocr_failed_pages"),
+        "A blank page is not an OCR failure"
+    );
+}
+
+#[test]
+#[cfg(feature = "pdf")]
+fn test_ocr_page_filter_keeps_native_text_of_other_pages() {
+    if skip_if_missing("pdf/text_and_blank_page.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/text_and_blank_page.pdf");
+    let config = ExtractionConfig {
+        ocr: Some(OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "eng".to_string(),
+            page_filter: Some(vec![2]),
+            empty_page_confidence: Some(0.0),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let result = extract_file_sync(&file_path, None, &config).expect("Should extract with an OCR page filter");
+
+    assert!(
+        result.content.contains("Native text layer on page one"),
+        "Pages outside the filter should keep their native text, got: {}",
+        result.content
+    );
+    assert_eq!(
+        result.metadata.ocr_page_count,
+        Some(1),
+        "Only the filtered page is OCRed"
+    );
+    assert_eq!(
+        result.metadata.additional.get("ocr_empty_pages"),
+        Some(&serde_json::json!([2]))
+    );
+
+    let config = ExtractionConfig {
+        ocr: Some(OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "eng".to_string(),
+            page_filter: Some(vec![5]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let err = extract_file_sync(&file_path, None, &config).expect_err("Page 5 does not exist");
+    assert!(
+        matches!(err, kreuzberg::KreuzbergError::Validation { .. }),
+        "Expected a validation error, got: {:?}",
+        err
+    );
+}
+
+#[test]
+fn test_ocr_region_only_recognizes_text_inside_region() {
+    if skip_if_missing("images/english_and_korean.png") {
+        return;
+    }
+
+    let file_path = get_test_file_path("images/english_and_korean.png");
+    let ocr_config = |region| OcrConfig {
+        backend: "tesseract".to_string(),
+        language: "eng".to_string(),
+        region,
+        ..Default::default()
+    };
+
+    let full = extract_file_sync(
+        &file_path,
+        None,
+        &ExtractionConfig {
+            ocr: Some(ocr_config(None)),
+            ..Default::default()
+        },
+    )
+    .expect("Should OCR the whole image");
+    assert!(
+        full.content.contains("hashtag"),
+        "The last line should be recognized without a region, got: {}",
+        full.content
+    );
+
+    // The heading occupies the top fifth of the image.
+    let top = Rect {
+        left: 0.0,
+        top: 0.0,
+        width: 1.0,
+        height: 0.2,
+    };
+    let cropped = extract_file_sync(
+        &file_path,
+        None,
+        &ExtractionConfig {
+            ocr: Some(ocr_config(Some(top))),
+            ..Default::default()
+        },
+    )
+    .expect("Should OCR the region");
+
+    assert!(
+        cropped.content.contains("RULES"),
+        "Text inside the region should be recognized, got: {}",
+        cropped.content
+    );
+    assert!(
+        !cropped.content.contains("hashtag"),
+        "Text outside the region should be skipped, got: {}",
+        cropped.content
+    );
+
+    let outside = Rect { left: 0.5, ..top };
+    let err = extract_file_sync(
+        &file_path,
+        None,
+        &ExtractionConfig {
+            ocr: Some(ocr_config(Some(outside))),
+            ..Default::default()
+        },
+    )
+    .expect_err("A region extending past the page is invalid");
+    assert!(
+        matches!(err, kreuzberg::KreuzbergError::Validation { .. }),
+        "Expected a validation error, got: {:?}",
+        err
+    );
+}
+
 #[test]
 fn test_table_detection_enabled() {
     if skip_if_missing("images/simple_table.png") {
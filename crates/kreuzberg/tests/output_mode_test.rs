@@ -0,0 +1,75 @@
+//! Integration tests for `ExtractionConfig.output_mode`.
+
+#![cfg(feature = "html")]
+
+mod helpers;
+
+use helpers::{get_test_file_path, skip_if_missing};
+use kreuzberg::core::config::{ExtractionConfig, OutputMode};
+use kreuzberg::extract_file;
+
+async fn extract_simple_table(output_mode: OutputMode) -> String {
+    let config = ExtractionConfig {
+        output_mode,
+        ..Default::default()
+    };
+    extract_file(get_test_file_path("html/simple_table.html"), None, &config)
+        .await
+        .expect("HTML extraction should succeed")
+        .content
+}
+
+#[tokio::test]
+async fn test_html_plain_text_mode_strips_markdown() {
+    if skip_if_missing("html/simple_table.html") {
+        return;
+    }
+
+    let markdown = extract_simple_table(OutputMode::Markdown).await;
+    let plain = extract_simple_table(OutputMode::PlainText).await;
+
+    assert!(
+        markdown
+            .lines()
+            .any(|line| line.starts_with('#') && line.contains("Sample Data Table")),
+        "markdown: {markdown}"
+    );
+    assert!(markdown.contains('|'), "markdown should render tables with pipes");
+    assert!(markdown.contains("**Total Products:**"), "markdown: {markdown}");
+
+    for line in plain.lines() {
+        assert!(!line.starts_with('#'), "heading marker left in plain text: {line:?}");
+    }
+    assert!(!plain.contains('|'), "table pipes left in plain text: {plain}");
+    assert!(!plain.contains("**"), "emphasis left in plain text: {plain}");
+
+    for text in [
+        "Sample Data Table",
+        "Summary Statistics",
+        "Desk Chair",
+        "$199.99",
+        "Total Products:",
+    ] {
+        assert!(markdown.contains(text), "markdown missing {text:?}");
+        assert!(plain.contains(text), "plain text missing {text:?}");
+    }
+    assert!(plain.contains("Laptop\tElectronics\t$999.99\t25"), "plain: {plain}");
+}
+
+#[tokio::test]
+async fn test_plain_text_mode_keeps_markdown_tables() {
+    if skip_if_missing("html/simple_table.html") {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        output_mode: OutputMode::PlainText,
+        ..Default::default()
+    };
+    let result = extract_file(get_test_file_path("html/simple_table.html"), None, &config)
+        .await
+        .expect("HTML extraction should succeed");
+
+    assert!(!result.tables.is_empty());
+    assert!(result.tables[0].markdown.contains('|'));
+}
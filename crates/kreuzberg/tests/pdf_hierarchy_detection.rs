@@ -42,7 +42,13 @@ async fn test_full_hierarchy_extraction() {
         pdf_options: Some(PdfConfig {
             extract_images: false,
             passwords: None,
+            allow_owner_locked: true,
             extract_metadata: true,
+            reading_order: Default::default(),
+            page_range: None,
+            page_separator: None,
+            extract_math: false,
+            insert_image_markers: false,
             hierarchy: Some(HierarchyConfig {
                 enabled: true,
                 k_clusters: 6,
@@ -154,7 +160,13 @@ async fn test_hierarchy_disabled() {
         pdf_options: Some(PdfConfig {
             extract_images: false,
             passwords: None,
+            allow_owner_locked: true,
             extract_metadata: true,
+            reading_order: Default::default(),
+            page_range: None,
+            page_separator: None,
+            extract_math: false,
+            insert_image_markers: false,
             hierarchy: Some(HierarchyConfig {
                 enabled: false,
                 k_clusters: 6,
@@ -209,7 +221,13 @@ async fn test_hierarchy_with_explicit_disabled() {
         pdf_options: Some(PdfConfig {
             extract_images: false,
             passwords: None,
+            allow_owner_locked: true,
             extract_metadata: true,
+            reading_order: Default::default(),
+            page_range: None,
+            page_separator: None,
+            extract_math: false,
+            insert_image_markers: false,
             hierarchy: Some(HierarchyConfig {
                 enabled: false,
                 k_clusters: 6,
@@ -265,7 +283,13 @@ async fn test_hierarchy_different_k_clusters() {
             pdf_options: Some(PdfConfig {
                 extract_images: false,
                 passwords: None,
+                allow_owner_locked: true,
                 extract_metadata: true,
+                reading_order: Default::default(),
+                page_range: None,
+                page_separator: None,
+                extract_math: false,
+                insert_image_markers: false,
                 hierarchy: Some(HierarchyConfig {
                     enabled: true,
                     k_clusters: *k,
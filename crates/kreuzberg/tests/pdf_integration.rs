@@ -9,7 +9,7 @@
 mod helpers;
 
 use helpers::*;
-use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::core::config::{ExtractionConfig, PdfConfig};
 use kreuzberg::extract_file_sync;
 
 #[test]
@@ -43,3 +43,189 @@ fn test_pdf_password_protected_fails_gracefully() {
         }
     }
 }
+
+#[test]
+fn test_pdf_owner_locked_opens_with_empty_password() {
+    if skip_if_missing("pdf/owner_locked.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/owner_locked.pdf");
+    let result = extract_file_sync(&file_path, None, &ExtractionConfig::default())
+        .expect("Owner-locked PDF should open with the empty user password");
+
+    assert!(result.content.contains("Owner Locked Sample"));
+    assert_eq!(
+        result.metadata.additional.get("owner_restrictions_bypassed"),
+        Some(&serde_json::json!(true))
+    );
+}
+
+#[test]
+fn test_pdf_owner_locked_respects_restrictions_when_disallowed() {
+    if skip_if_missing("pdf/owner_locked.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/owner_locked.pdf");
+    let config = ExtractionConfig {
+        pdf_options: Some(PdfConfig {
+            allow_owner_locked: false,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let err = extract_file_sync(&file_path, None, &config).expect_err("Owner restrictions should be enforced");
+
+    assert!(err.to_string().to_lowercase().contains("encryption"), "got: {}", err);
+}
+
+#[test]
+fn test_pdf_owner_locked_ignores_wrong_configured_passwords() {
+    if skip_if_missing("pdf/owner_locked.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/owner_locked.pdf");
+    let config = ExtractionConfig {
+        pdf_options: Some(PdfConfig {
+            passwords: Some(vec!["not-the-password".to_string()]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = extract_file_sync(&file_path, None, &config).expect("Empty user password should be tried first");
+
+    assert!(result.content.contains("Owner Locked Sample"));
+}
+
+#[test]
+fn test_pdf_outline_from_bookmarks() {
+    if skip_if_missing("pdf/test_article.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/test_article.pdf");
+    let config = ExtractionConfig {
+        extract_outline: true,
+        ..Default::default()
+    };
+    let result = extract_file_sync(&file_path, None, &config).expect("PDF with bookmarks should extract");
+
+    let outline = result
+        .outline
+        .expect("Outline should be populated when extract_outline is set");
+    assert_eq!(outline.len(), 1, "Got: {:?}", outline);
+    assert_eq!(outline[0].title, "Bibliography");
+    assert_eq!(outline[0].level, 1);
+    assert_eq!(outline[0].page_number, Some(27));
+    assert!(outline[0].children.is_empty());
+}
+
+#[test]
+fn test_pdf_outline_keeps_original_pages_with_page_range() {
+    if skip_if_missing("pdf/test_article.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/test_article.pdf");
+    let config = ExtractionConfig {
+        extract_outline: true,
+        pdf_options: Some(PdfConfig {
+            page_range: Some((1, 2)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = extract_file_sync(&file_path, None, &config).expect("Page range extraction should succeed");
+
+    let outline = result.outline.expect("Outline should cover the whole document");
+    assert_eq!(outline[0].page_number, Some(27));
+}
+
+#[test]
+fn test_pdf_text_blocks_with_positions() {
+    if skip_if_missing("pdf/test_article.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/test_article.pdf");
+    let config = ExtractionConfig {
+        emit_positions: true,
+        pdf_options: Some(PdfConfig {
+            page_range: Some((2, 3)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = extract_file_sync(&file_path, None, &config).expect("PDF extraction should succeed");
+
+    let blocks = result
+        .text_blocks
+        .expect("Text blocks should be populated when emit_positions is set");
+    assert!(!blocks.is_empty());
+    assert!(
+        blocks.iter().all(|block| (2..=3).contains(&block.page)),
+        "Got: {:?}",
+        blocks
+    );
+    assert!(blocks.iter().all(|block| !block.text.trim().is_empty()
+        && block.bbox.x1 >= block.bbox.x0
+        && block.bbox.y1 >= block.bbox.y0));
+}
+
+#[cfg(feature = "references")]
+#[test]
+fn test_pdf_references_section_is_parsed() {
+    if skip_if_missing("pdf/paper_with_references.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/paper_with_references.pdf");
+    let config = ExtractionConfig {
+        extract_references: true,
+        ..Default::default()
+    };
+    let result = extract_file_sync(&file_path, None, &config).expect("PDF extraction should succeed");
+
+    let references = result
+        .references
+        .expect("References should be populated when extract_references is set");
+    assert_eq!(references.len(), 4, "Got: {:?}", references);
+
+    assert_eq!(references[0].title.as_deref(), Some("Attention is all you need"));
+    assert_eq!(references[0].authors, vec!["A. Vaswani", "N. Shazeer", "N. Parmar"]);
+    assert_eq!(references[0].year, Some(2017));
+
+    assert_eq!(references[1].authors.first().map(String::as_str), Some("Devlin, J."));
+    assert_eq!(references[1].year, Some(2019));
+    assert_eq!(references[1].doi.as_deref(), Some("10.18653/v1/N19-1423"));
+
+    assert_eq!(references[2].doi.as_deref(), Some("10.1109/CVPR.2016.90"));
+    assert_eq!(references[3].title.as_deref(), Some("The Art of Computer Programming"));
+    assert_eq!(references[3].year, Some(1968));
+}
+
+#[test]
+fn test_pdf_arabic_text_is_reordered_to_logical_order() {
+    if skip_if_missing("pdf/arabic_rtl.pdf") {
+        return;
+    }
+
+    // "marhaba bil-alam" (hello world) in logical order
+    let logical = "\u{0645}\u{0631}\u{062D}\u{0628}\u{0627} \u{0628}\u{0627}\u{0644}\u{0639}\u{0627}\u{0644}\u{0645}";
+
+    let file_path = get_test_file_path("pdf/arabic_rtl.pdf");
+    let config = ExtractionConfig {
+        normalize_bidi: true,
+        ..Default::default()
+    };
+    let result = extract_file_sync(&file_path, None, &config).expect("PDF extraction should succeed");
+
+    assert!(
+        result.content.contains(logical),
+        "Arabic text should be in logical order, got: {:?}",
+        result.content
+    );
+    assert!(result.content.contains("Greeting"), "Latin text should be unchanged");
+}
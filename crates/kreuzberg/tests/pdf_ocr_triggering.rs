@@ -281,7 +281,13 @@ fn test_ocr_custom_threshold() {
         pdf_options: Some(PdfConfig {
             extract_images: false,
             passwords: None,
+            allow_owner_locked: true,
             extract_metadata: true,
+            reading_order: Default::default(),
+            page_range: None,
+            page_separator: None,
+            extract_math: false,
+            insert_image_markers: false,
             hierarchy: Some(HierarchyConfig {
                 enabled: true,
                 k_clusters: 6,
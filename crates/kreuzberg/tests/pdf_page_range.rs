@@ -0,0 +1,108 @@
+//! PDF page range extraction tests.
+//!
+//! Verifies that `PdfConfig.page_range` restricts extraction to the selected pages and
+//! that page numbers refer to the original document.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::{ExtractionConfig, PageConfig, PdfConfig};
+use kreuzberg::types::FormatMetadata;
+use kreuzberg::{KreuzbergError, extract_file_sync};
+
+const MULTI_PAGE_PDF: &str = "pdf/program_design_in_the_unix_environment.pdf";
+
+fn config_with_page_range(page_range: (usize, usize)) -> ExtractionConfig {
+    ExtractionConfig {
+        pdf_options: Some(PdfConfig {
+            page_range: Some(page_range),
+            ..Default::default()
+        }),
+        pages: Some(PageConfig {
+            extract_pages: true,
+            insert_page_markers: true,
+            marker_format: "[PAGE {page_num}]".to_string(),
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_page_range_keeps_original_page_numbers() {
+    if skip_if_missing(MULTI_PAGE_PDF) {
+        return;
+    }
+
+    let file_path = get_test_file_path(MULTI_PAGE_PDF);
+    let result =
+        extract_file_sync(&file_path, None, &config_with_page_range((2, 4))).expect("Failed to extract PDF page range");
+
+    let pages = result.pages.expect("Pages should be extracted");
+    let page_numbers: Vec<usize> = pages.iter().map(|page| page.page_number).collect();
+    assert_eq!(page_numbers, vec![2, 3, 4]);
+
+    let boundaries = result
+        .metadata
+        .pages
+        .and_then(|structure| structure.boundaries)
+        .expect("Page boundaries should be tracked");
+    assert!(
+        boundaries
+            .iter()
+            .all(|boundary| (2..=4).contains(&boundary.page_number))
+    );
+
+    assert!(result.content.contains("[PAGE 2]"));
+    assert!(result.content.contains("[PAGE 4]"));
+    assert!(!result.content.contains("[PAGE 1]"));
+    assert!(!result.content.contains("[PAGE 5]"));
+}
+
+#[test]
+fn test_page_range_keeps_document_metadata() {
+    if skip_if_missing(MULTI_PAGE_PDF) {
+        return;
+    }
+
+    let file_path = get_test_file_path(MULTI_PAGE_PDF);
+    let full = extract_file_sync(&file_path, None, &ExtractionConfig::default()).expect("Failed to extract PDF");
+    let ranged =
+        extract_file_sync(&file_path, None, &config_with_page_range((2, 4))).expect("Failed to extract PDF page range");
+
+    assert_eq!(ranged.metadata.title, full.metadata.title);
+    assert_eq!(ranged.metadata.authors, full.metadata.authors);
+    assert_eq!(ranged.metadata.created_at, full.metadata.created_at);
+
+    let page_count = |result: &kreuzberg::ExtractionResult| match &result.metadata.format {
+        Some(FormatMetadata::Pdf(pdf)) => pdf.page_count,
+        other => panic!("Expected PDF metadata, got {:?}", other),
+    };
+    assert_eq!(page_count(&ranged), page_count(&full));
+    assert!(page_count(&ranged).is_some_and(|count| count > 4));
+}
+
+#[test]
+fn test_page_range_beyond_document_is_rejected() {
+    if skip_if_missing(MULTI_PAGE_PDF) {
+        return;
+    }
+
+    let file_path = get_test_file_path(MULTI_PAGE_PDF);
+    let result = extract_file_sync(&file_path, None, &config_with_page_range((1, 10_000)));
+
+    assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+}
+
+#[test]
+fn test_page_range_start_after_end_is_rejected() {
+    if skip_if_missing(MULTI_PAGE_PDF) {
+        return;
+    }
+
+    let file_path = get_test_file_path(MULTI_PAGE_PDF);
+    let result = extract_file_sync(&file_path, None, &config_with_page_range((4, 2)));
+
+    assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+}
@@ -0,0 +1,90 @@
+//! Integration tests for column-aware PDF reading order.
+//!
+//! The `two_column.pdf` fixture interleaves the two columns line by line in its
+//! content stream, so raw extraction does not read either column contiguously.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::{ExtractionConfig, PdfConfig, ReadingOrder};
+use kreuzberg::extract_file_sync;
+
+fn config_with_reading_order(reading_order: ReadingOrder) -> ExtractionConfig {
+    ExtractionConfig {
+        pdf_options: Some(PdfConfig {
+            extract_images: false,
+            passwords: None,
+            allow_owner_locked: true,
+            extract_metadata: true,
+            hierarchy: None,
+            reading_order,
+            page_range: None,
+            page_separator: None,
+            extract_math: false,
+            insert_image_markers: false,
+        }),
+        ..Default::default()
+    }
+}
+
+fn position(content: &str, needle: &str) -> usize {
+    content
+        .find(needle)
+        .unwrap_or_else(|| panic!("Expected {:?} in content: {:?}", needle, content))
+}
+
+#[test]
+fn test_column_aware_reads_left_column_first() {
+    if skip_if_missing("pdf/two_column.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/two_column.pdf");
+    let result = extract_file_sync(&file_path, None, &config_with_reading_order(ReadingOrder::ColumnAware))
+        .expect("Should extract two-column PDF");
+
+    let content = &result.content;
+    let title = position(content, "Two Column Reading Order");
+    let left_first = position(content, "Left column line one");
+    let left_last = position(content, "Left column line four");
+    let right_first = position(content, "Right column line one");
+    let right_last = position(content, "Right column line four");
+
+    assert!(title < left_first, "Spanning title should come first: {:?}", content);
+    assert!(
+        left_first < left_last,
+        "Left column should read top-to-bottom: {:?}",
+        content
+    );
+    assert!(
+        left_last < right_first,
+        "Left column should be read before the right column: {:?}",
+        content
+    );
+    assert!(
+        right_first < right_last,
+        "Right column should read top-to-bottom: {:?}",
+        content
+    );
+}
+
+#[test]
+fn test_raw_reading_order_keeps_all_text() {
+    if skip_if_missing("pdf/two_column.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/two_column.pdf");
+    let result = extract_file_sync(&file_path, None, &config_with_reading_order(ReadingOrder::Raw))
+        .expect("Should extract two-column PDF");
+
+    for line in [
+        "Left column line one",
+        "Right column line four",
+        "Two Column Reading Order",
+    ] {
+        position(&result.content, line);
+    }
+}
@@ -146,6 +146,11 @@ async fn test_pipeline_empty_no_processors() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -195,6 +200,11 @@ async fn test_pipeline_single_processor_per_stage() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -244,6 +254,11 @@ async fn test_pipeline_multiple_processors_per_stage() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -284,6 +299,11 @@ async fn test_pipeline_all_stages_enabled() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -322,6 +342,11 @@ async fn test_pipeline_postprocessing_disabled() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
@@ -330,6 +355,8 @@ async fn test_pipeline_postprocessing_disabled() {
             disabled_processors: None,
             enabled_set: None,
             disabled_set: None,
+            header_footer_threshold: 0.6,
+            text_normalizer: Default::default(),
         }),
         ..Default::default()
     };
@@ -375,6 +402,11 @@ async fn test_pipeline_early_stage_runs_first() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -419,6 +451,11 @@ async fn test_pipeline_middle_stage_runs_second() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -459,6 +496,11 @@ async fn test_pipeline_late_stage_runs_last() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -499,6 +541,11 @@ async fn test_pipeline_within_stage_priority_order() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -571,6 +618,11 @@ async fn test_pipeline_cross_stage_data_flow() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -633,6 +685,11 @@ async fn test_pipeline_early_stage_error_recorded() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -679,6 +736,11 @@ async fn test_pipeline_middle_stage_error_propagation() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -755,6 +817,11 @@ async fn test_pipeline_late_stage_error_doesnt_affect_earlier_stages() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -847,6 +914,11 @@ async fn test_pipeline_processor_error_doesnt_stop_other_processors() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -929,6 +1001,11 @@ async fn test_pipeline_multiple_processor_errors() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -975,6 +1052,11 @@ async fn test_pipeline_error_context_preservation() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -1055,6 +1137,11 @@ async fn test_pipeline_metadata_added_in_early_visible_in_middle() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -1131,6 +1218,11 @@ async fn test_pipeline_content_modified_in_middle_visible_in_late() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -1204,6 +1296,11 @@ async fn test_pipeline_multiple_processors_modifying_same_metadata() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -1296,6 +1393,11 @@ async fn test_pipeline_processors_reading_previous_output() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -1365,6 +1467,11 @@ async fn test_pipeline_large_content_modification() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -1405,6 +1512,11 @@ async fn test_pipeline_enabled_processors_whitelist() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
@@ -1413,6 +1525,8 @@ async fn test_pipeline_enabled_processors_whitelist() {
             disabled_processors: None,
             enabled_set: None,
             disabled_set: None,
+            header_footer_threshold: 0.6,
+            text_normalizer: Default::default(),
         }),
         ..Default::default()
     };
@@ -1456,6 +1570,11 @@ async fn test_pipeline_disabled_processors_blacklist() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
@@ -1464,6 +1583,8 @@ async fn test_pipeline_disabled_processors_blacklist() {
             disabled_processors: Some(vec!["proc2".to_string()]),
             enabled_set: None,
             disabled_set: None,
+            header_footer_threshold: 0.6,
+            text_normalizer: Default::default(),
         }),
         ..Default::default()
     };
@@ -1507,6 +1628,11 @@ async fn test_pipeline_no_filtering_runs_all() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig::default();
 
@@ -1549,6 +1675,11 @@ async fn test_pipeline_empty_whitelist_runs_none() {
         elements: None,
         ocr_elements: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
@@ -1557,6 +1688,8 @@ async fn test_pipeline_empty_whitelist_runs_none() {
             disabled_processors: None,
             enabled_set: None,
             disabled_set: None,
+            header_footer_threshold: 0.6,
+            text_normalizer: Default::default(),
         }),
         ..Default::default()
     };
@@ -64,6 +64,11 @@ impl DocumentExtractor for FailingExtractor {
                 ocr_elements: None,
                 djot_content: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             })
         }
     }
@@ -313,6 +318,11 @@ fn test_extractor_priority_ordering_complex() {
                 ocr_elements: None,
                 djot_content: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             })
         }
         fn supported_mime_types(&self) -> &[&str] {
@@ -398,14 +408,14 @@ fn test_extractor_wildcard_vs_exact_priority() {
 fn test_extractor_empty_mime_type() {
     let registry = DocumentExtractorRegistry::new();
     let result = registry.get("");
-    assert!(matches!(result, Err(KreuzbergError::UnsupportedFormat(_))));
+    assert!(matches!(result, Err(KreuzbergError::UnsupportedFormat { .. })));
 }
 
 #[test]
 fn test_extractor_special_characters_mime() {
     let registry = DocumentExtractorRegistry::new();
     let result = registry.get("application/vnd.openxmlformats-officedocument.wordprocessingml.document");
-    assert!(matches!(result, Err(KreuzbergError::UnsupportedFormat(_))));
+    assert!(matches!(result, Err(KreuzbergError::UnsupportedFormat { .. })));
 }
 
 #[test]
@@ -477,6 +487,11 @@ async fn test_processor_execution_order_within_stage() {
         ocr_elements: None,
         djot_content: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     let config = ExtractionConfig::default();
@@ -516,6 +531,11 @@ async fn test_processor_error_propagation() {
         ocr_elements: None,
         djot_content: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     let config = ExtractionConfig::default();
@@ -692,6 +712,11 @@ async fn test_validator_content_validation() {
         ocr_elements: None,
         djot_content: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     let validation = validators[0].validate(&short_result, &config).await;
@@ -710,6 +735,11 @@ async fn test_validator_content_validation() {
         ocr_elements: None,
         djot_content: None,
         document: None,
+        outline: None,
+        formulas: None,
+        text_blocks: None,
+        source_map: None,
+        references: None,
     };
 
     let validation = validators[0].validate(&long_result, &config).await;
@@ -0,0 +1,74 @@
+//! Tests for the `test-utils` OCR backends.
+//!
+//! Exercises OCR backend registration and dispatch without Tesseract installed.
+
+#![cfg(feature = "test-utils")]
+
+use kreuzberg::core::config::OcrConfig;
+use kreuzberg::plugins::registry::get_ocr_backend_registry;
+use kreuzberg::plugins::testing::{ECHO_OCR_MARKER, EchoOcrBackend, NullOcrBackend};
+use kreuzberg::plugins::{list_ocr_backends, register_ocr_backend, unregister_ocr_backend};
+use serial_test::serial;
+use std::sync::Arc;
+
+#[tokio::test]
+#[serial]
+async fn test_null_and_echo_backends_register_and_dispatch() {
+    register_ocr_backend(Arc::new(NullOcrBackend)).expect("register null backend");
+    register_ocr_backend(Arc::new(EchoOcrBackend)).expect("register echo backend");
+
+    let names = list_ocr_backends().expect("list backends");
+    assert!(names.contains(&"null".to_string()));
+    assert!(names.contains(&"echo".to_string()));
+
+    let (null, echo) = {
+        let registry = get_ocr_backend_registry();
+        let registry = registry.read().expect("registry lock");
+        (
+            registry.get("null").expect("null backend"),
+            registry.get("echo").expect("echo backend"),
+        )
+    };
+
+    let config = OcrConfig::default();
+    let null_result = null.process_image(b"image", &config).await.expect("null OCR");
+    let echo_result = echo.process_image(b"image", &config).await.expect("echo OCR");
+    assert!(null_result.content.is_empty());
+    assert_eq!(echo_result.content, ECHO_OCR_MARKER);
+    assert!(null.supports_language("jpn"));
+
+    unregister_ocr_backend("null").expect("unregister null backend");
+    unregister_ocr_backend("echo").expect("unregister echo backend");
+    assert!(
+        !list_ocr_backends()
+            .expect("list backends")
+            .contains(&"null".to_string())
+    );
+}
+
+#[cfg(feature = "ocr")]
+#[tokio::test]
+#[serial]
+async fn test_echo_backend_used_for_image_extraction() {
+    use kreuzberg::core::config::ExtractionConfig;
+    use kreuzberg::extract_bytes;
+
+    register_ocr_backend(Arc::new(EchoOcrBackend)).expect("register echo backend");
+
+    let mut png = Vec::new();
+    image::RgbImage::new(4, 4)
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .expect("encode png");
+
+    let config = ExtractionConfig {
+        ocr: Some(OcrConfig {
+            backend: "echo".to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = extract_bytes(&png, "image/png", &config).await;
+
+    unregister_ocr_backend("echo").expect("unregister echo backend");
+    assert!(result.expect("extraction").content.contains(ECHO_OCR_MARKER));
+}
@@ -690,9 +690,15 @@ async fn test_pptx_image_page_numbers_not_reversed() {
             extract_images: true,
             target_dpi: 300,
             max_image_dimension: 4096,
+            max_decode_dimension: 32768,
             auto_adjust_dpi: true,
             min_dpi: 72,
             max_dpi: 600,
+            max_images: None,
+            max_total_image_bytes: None,
+            min_width: 16,
+            min_height: 16,
+            output_format: kreuzberg::ImageFormat::Original,
         }),
         ..Default::default()
     };
@@ -757,9 +763,15 @@ async fn test_pptx_image_page_numbers_issue329_user_file() {
             extract_images: true,
             target_dpi: 300,
             max_image_dimension: 4096,
+            max_decode_dimension: 32768,
             auto_adjust_dpi: true,
             min_dpi: 72,
             max_dpi: 600,
+            max_images: None,
+            max_total_image_bytes: None,
+            min_width: 16,
+            min_height: 16,
+            output_format: kreuzberg::ImageFormat::Original,
         }),
         ..Default::default()
     };
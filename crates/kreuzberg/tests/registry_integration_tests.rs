@@ -131,6 +131,11 @@ impl DocumentExtractor for MockExtractor {
             ocr_elements: None,
             djot_content: None,
             document: None,
+            outline: None,
+            formulas: None,
+            text_blocks: None,
+            source_map: None,
+            references: None,
         })
     }
 
@@ -416,8 +421,8 @@ fn test_get_extractor_for_unsupported_mime_fails() {
     assert!(result.is_err(), "Should not find extractor for unsupported MIME type");
 
     match result {
-        Err(KreuzbergError::UnsupportedFormat(mime)) => {
-            assert_eq!(mime, "application/nonexistent");
+        Err(KreuzbergError::UnsupportedFormat { mime_type, .. }) => {
+            assert_eq!(mime_type, "application/nonexistent");
         }
         _ => panic!("Expected UnsupportedFormat error"),
     }
@@ -0,0 +1,53 @@
+//! SVG extraction integration tests.
+//!
+//! Verifies that `<text>` content is read without rasterization and that `force_ocr`
+//! renders the image so outlined text can be recognized.
+
+#![cfg(feature = "xml")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_file_sync;
+
+const SVG_WITH_OUTLINES: &str = "xml/svg_text_and_outlines.svg";
+
+#[test]
+fn test_svg_text_elements_extracted_in_order() {
+    if skip_if_missing(SVG_WITH_OUTLINES) {
+        return;
+    }
+
+    let file_path = get_test_file_path(SVG_WITH_OUTLINES);
+    let result = extract_file_sync(&file_path, None, &ExtractionConfig::default()).expect("Failed to extract SVG");
+
+    assert_mime_type(&result, "image/svg+xml");
+    assert_eq!(result.content, "Revenue by Quarter\nQ1 2024\nQ2 2024");
+    assert_eq!(result.metadata.title.as_deref(), Some("Quarterly Revenue Chart"));
+}
+
+#[cfg(feature = "ocr")]
+#[test]
+fn test_svg_force_ocr_renders_outlined_text() {
+    if skip_if_missing(SVG_WITH_OUTLINES) {
+        return;
+    }
+
+    let config = ExtractionConfig {
+        force_ocr: true,
+        ..Default::default()
+    };
+    let file_path = get_test_file_path(SVG_WITH_OUTLINES);
+    let result = match extract_file_sync(&file_path, None, &config) {
+        Ok(result) => result,
+        Err(kreuzberg::KreuzbergError::MissingDependency(_)) => {
+            tracing::debug!("Skipping test: OCR backend not available");
+            return;
+        }
+        Err(e) => panic!("Failed to OCR SVG: {}", e),
+    };
+
+    assert_mime_type(&result, "image/svg+xml");
+    assert_non_empty_content(&result);
+}
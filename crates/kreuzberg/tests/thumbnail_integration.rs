@@ -0,0 +1,54 @@
+//! Thumbnail rendering tests against real documents.
+
+#![cfg(feature = "pdf")]
+
+mod helpers;
+
+use helpers::*;
+use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::extract_thumbnail;
+
+#[test]
+fn test_pdf_thumbnail_renders_first_page_at_width() {
+    if skip_if_missing("pdf/blank_page.pdf") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pdf/blank_page.pdf");
+    let png = extract_thumbnail(&file_path, &ExtractionConfig::default(), 306).expect("PDF thumbnail should render");
+
+    let thumbnail = image::load_from_memory(&png).expect("thumbnail should be a valid PNG");
+    assert_eq!(thumbnail.width(), 306);
+    assert!(
+        thumbnail.height().abs_diff(396) <= 1,
+        "US Letter page should keep its aspect ratio, got height {}",
+        thumbnail.height()
+    );
+}
+
+#[cfg(feature = "office")]
+#[test]
+fn test_pptx_thumbnail_uses_embedded_preview() {
+    if skip_if_missing("pptx/simple.pptx") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pptx/simple.pptx");
+    let png = extract_thumbnail(&file_path, &ExtractionConfig::default(), 120).expect("PPTX thumbnail should render");
+
+    let thumbnail = image::load_from_memory(&png).expect("thumbnail should be a valid PNG");
+    assert_eq!(thumbnail.width(), 120);
+    assert!(thumbnail.height() > 0);
+}
+
+#[cfg(feature = "office")]
+#[test]
+fn test_pptx_without_preview_is_unsupported() {
+    if skip_if_missing("pptx/pitch_deck_presentation.pptx") {
+        return;
+    }
+
+    let file_path = get_test_file_path("pptx/pitch_deck_presentation.pptx");
+    let err = extract_thumbnail(&file_path, &ExtractionConfig::default(), 120).unwrap_err();
+    assert!(matches!(err, kreuzberg::KreuzbergError::UnsupportedFormat { .. }));
+}
@@ -1,7 +1,8 @@
 //! End-to-end integration test for XLSX metadata extraction
 #![cfg(feature = "excel")]
 
-use kreuzberg::extraction::excel::read_excel_file;
+use kreuzberg::XlsxConfig;
+use kreuzberg::extraction::excel::{read_excel_file, read_excel_file_with_config};
 
 #[test]
 fn test_xlsx_full_metadata_extraction() {
@@ -144,3 +145,36 @@ fn test_xlsx_excel_solver_extreme_dimensions_no_oom() {
     );
     println!("   Successfully handled dimension A1:XFD1048575 without OOM");
 }
+
+#[test]
+fn test_xlsx_hidden_sheets_skipped_by_default() {
+    let workspace_root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Operation failed")
+        .parent()
+        .expect("Operation failed");
+    let test_file = workspace_root.join("test_documents/xlsx/hidden_sheets.xlsx");
+
+    if !test_file.exists() {
+        println!("Skipping test: Test file not found at {:?}", test_file);
+        return;
+    }
+
+    let file_path = test_file.to_str().expect("File path should be valid UTF-8");
+    let hidden = vec!["Lookup".to_string(), "Internal".to_string()];
+
+    let result = read_excel_file(file_path).expect("Should extract XLSX with hidden sheets");
+    let names: Vec<&str> = result.sheets.iter().map(|sheet| sheet.name.as_str()).collect();
+    assert_eq!(names, vec!["Summary"], "Hidden sheets should be skipped by default");
+    assert_eq!(result.hidden_sheets, hidden);
+
+    let config = XlsxConfig {
+        include_hidden_sheets: true,
+        ..Default::default()
+    };
+    let result = read_excel_file_with_config(file_path, &config).expect("Should extract hidden sheets");
+    let names: Vec<&str> = result.sheets.iter().map(|sheet| sheet.name.as_str()).collect();
+    assert_eq!(names, vec!["Summary", "Lookup", "Internal"]);
+    assert!(result.sheets[2].markdown.contains("Draft figures, do not publish"));
+    assert_eq!(result.hidden_sheets, hidden);
+}
@@ -180,6 +180,9 @@ namespace Kreuzberg.E2E.Ocr {
             TestHelpers.AssertExpectedMime(result, new[] { "application/pdf" });
             TestHelpers.AssertMinContentLength(result, 20);
             TestHelpers.AssertContentContainsAny(result, new[] { "Docling", "Markdown", "JSON" });
+            var metadataNode = TestHelpers.MetadataToJson(result.Metadata);
+            TestHelpers.AssertMetadata(metadataNode, "ocr_page_count", @"{""gte"": 1}");
+            TestHelpers.AssertMetadata(metadataNode, "ocr_used", @"{""eq"": true}");
         }
 
     }
@@ -159,6 +159,8 @@ namespace Kreuzberg.E2E.Pdf {
             TestHelpers.AssertExpectedMime(result, new[] { "application/pdf" });
             TestHelpers.AssertMinContentLength(result, 50);
             TestHelpers.AssertContentContainsAny(result, new[] { "May 5, 2023", "To Whom it May Concern", "Mallori" });
+            var metadataNode = TestHelpers.MetadataToJson(result.Metadata);
+            TestHelpers.AssertMetadata(metadataNode, "extractor_name", @"{""eq"": ""pdf""}");
         }
 
         [SkippableFact]
@@ -272,6 +272,8 @@ defmodule E2E.OcrTest do
           |> E2E.Helpers.assert_expected_mime(["application/pdf"])
           |> E2E.Helpers.assert_min_content_length(20)
           |> E2E.Helpers.assert_content_contains_any(["Docling", "Markdown", "JSON"])
+          |> E2E.Helpers.assert_metadata_expectation("ocr_page_count", %{gte: 1})
+          |> E2E.Helpers.assert_metadata_expectation("ocr_used", %{eq: true})
 
         {:skipped, reason} ->
           IO.puts("SKIPPED: #{reason}")
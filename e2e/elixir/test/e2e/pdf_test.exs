@@ -234,6 +234,7 @@ defmodule E2E.PdfTest do
           |> E2E.Helpers.assert_expected_mime(["application/pdf"])
           |> E2E.Helpers.assert_min_content_length(50)
           |> E2E.Helpers.assert_content_contains_any(["May 5, 2023", "To Whom it May Concern", "Mallori"])
+          |> E2E.Helpers.assert_metadata_expectation("extractor_name", %{eq: "pdf"})
 
         {:skipped, reason} ->
           IO.puts("SKIPPED: #{reason}")
@@ -243,6 +243,8 @@ public class OcrTest {
                 E2EHelpers.Assertions.assertExpectedMime(result, Arrays.asList("application/pdf"));
                 E2EHelpers.Assertions.assertMinContentLength(result, 20);
                 E2EHelpers.Assertions.assertContentContainsAny(result, Arrays.asList("Docling", "Markdown", "JSON"));
+                E2EHelpers.Assertions.assertMetadataExpectation(result, "ocr_page_count", Map.of("gte", 1));
+                E2EHelpers.Assertions.assertMetadataExpectation(result, "ocr_used", Map.of("eq", true));
             }
         );
     }
@@ -208,6 +208,7 @@ public class PdfTest {
                 E2EHelpers.Assertions.assertExpectedMime(result, Arrays.asList("application/pdf"));
                 E2EHelpers.Assertions.assertMinContentLength(result, 50);
                 E2EHelpers.Assertions.assertContentContainsAny(result, Arrays.asList("May 5, 2023", "To Whom it May Concern", "Mallori"));
+                E2EHelpers.Assertions.assertMetadataExpectation(result, "extractor_name", Map.of("eq", "pdf"));
             }
         );
     }
@@ -249,6 +249,8 @@ class OcrTest extends TestCase
         Helpers::assertExpectedMime($result, ['application/pdf']);
         Helpers::assertMinContentLength($result, 20);
         Helpers::assertContentContainsAny($result, ['Docling', 'Markdown', 'JSON']);
+        Helpers::assertMetadataExpectation($result, 'ocr_page_count', ['gte' => 1]);
+        Helpers::assertMetadataExpectation($result, 'ocr_used', ['eq' => true]);
     }
 
 }
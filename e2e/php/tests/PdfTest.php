@@ -217,6 +217,7 @@ class PdfTest extends TestCase
         Helpers::assertExpectedMime($result, ['application/pdf']);
         Helpers::assertMinContentLength($result, 50);
         Helpers::assertContentContainsAny($result, ['May 5, 2023', 'To Whom it May Concern', 'Mallori']);
+        Helpers::assertMetadataExpectation($result, 'extractor_name', ['eq' => 'pdf']);
     }
 
     /**
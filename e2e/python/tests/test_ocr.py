@@ -219,3 +219,5 @@ def test_ocr_pdf_tesseract() -> None:
     helpers.assert_expected_mime(result, ["application/pdf"])
     helpers.assert_min_content_length(result, 20)
     helpers.assert_content_contains_any(result, ["Docling", "Markdown", "JSON"])
+    helpers.assert_metadata_expectation(result, "ocr_page_count", {"gte": 1})
+    helpers.assert_metadata_expectation(result, "ocr_used", {"eq": True})
@@ -175,6 +175,7 @@ def test_pdf_simple_text() -> None:
     helpers.assert_expected_mime(result, ["application/pdf"])
     helpers.assert_min_content_length(result, 50)
     helpers.assert_content_contains_any(result, ["May 5, 2023", "To Whom it May Concern", "Mallori"])
+    helpers.assert_metadata_expectation(result, "extractor_name", {"eq": "pdf"})
 
 
 def test_pdf_tables_large() -> None:
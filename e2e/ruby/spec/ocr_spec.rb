@@ -218,6 +218,8 @@ RSpec.describe 'ocr fixtures' do
       )
       E2ERuby::Assertions.assert_min_content_length(result, 20)
       E2ERuby::Assertions.assert_content_contains_any(result, %w[Docling Markdown JSON])
+      E2ERuby::Assertions.assert_metadata_expectation(result, 'ocr_page_count', { gte: 1 })
+      E2ERuby::Assertions.assert_metadata_expectation(result, 'ocr_used', { eq: true })
     end
   end
 end
@@ -190,6 +190,7 @@ RSpec.describe 'pdf fixtures' do
       )
       E2ERuby::Assertions.assert_min_content_length(result, 50)
       E2ERuby::Assertions.assert_content_contains_any(result, ['May 5, 2023', 'To Whom it May Concern', 'Mallori'])
+      E2ERuby::Assertions.assert_metadata_expectation(result, 'extractor_name', { eq: 'pdf' })
     end
   end
 
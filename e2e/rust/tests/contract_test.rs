@@ -349,7 +349,7 @@ fn test_config_force_ocr() {
             println!("Skipping config_force_ocr: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping config_force_ocr: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -421,7 +421,7 @@ fn test_config_keywords() {
             println!("Skipping config_keywords: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping config_keywords: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
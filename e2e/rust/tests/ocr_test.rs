@@ -34,7 +34,7 @@ fn test_ocr_image_hello_world() {
             println!("Skipping ocr_image_hello_world: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_image_hello_world: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -78,7 +78,7 @@ fn test_ocr_image_no_text() {
             println!("Skipping ocr_image_no_text: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_image_no_text: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -127,7 +127,7 @@ fn test_ocr_paddle_confidence_filter() {
             );
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_paddle_confidence_filter: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -170,7 +170,7 @@ fn test_ocr_paddle_image_chinese() {
             println!("Skipping ocr_paddle_image_chinese: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_paddle_image_chinese: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -213,7 +213,7 @@ fn test_ocr_paddle_image_english() {
             println!("Skipping ocr_paddle_image_english: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_paddle_image_english: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -260,7 +260,7 @@ fn test_ocr_paddle_markdown() {
             println!("Skipping ocr_paddle_markdown: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_paddle_markdown: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -304,7 +304,7 @@ fn test_ocr_paddle_pdf_scanned() {
             println!("Skipping ocr_paddle_pdf_scanned: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_paddle_pdf_scanned: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -351,7 +351,7 @@ fn test_ocr_paddle_structured() {
             println!("Skipping ocr_paddle_structured: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_paddle_structured: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -401,7 +401,7 @@ fn test_ocr_paddle_table_detection() {
             );
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_paddle_table_detection: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -448,7 +448,7 @@ fn test_ocr_pdf_image_only_german() {
             );
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_pdf_image_only_german: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -492,7 +492,7 @@ fn test_ocr_pdf_rotated_90() {
             println!("Skipping ocr_pdf_rotated_90: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_pdf_rotated_90: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -535,7 +535,7 @@ fn test_ocr_pdf_tesseract() {
             println!("Skipping ocr_pdf_tesseract: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping ocr_pdf_tesseract: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -549,4 +549,6 @@ fn test_ocr_pdf_tesseract() {
     assertions::assert_expected_mime(&result, &["application/pdf"]);
     assertions::assert_min_content_length(&result, 20);
     assertions::assert_content_contains_any(&result, &["Docling", "Markdown", "JSON"]);
+    assertions::assert_metadata_expectation(&result, "ocr_page_count", &serde_json::json!({"gte":1}));
+    assertions::assert_metadata_expectation(&result, "ocr_used", &serde_json::json!({"eq":true}));
 }
@@ -71,7 +71,7 @@ fn test_office_doc_legacy() {
             println!("Skipping office_doc_legacy: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping office_doc_legacy: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
@@ -669,7 +669,7 @@ fn test_office_ppt_legacy() {
             println!("Skipping office_ppt_legacy: missing dependency {dep}", dep = dep);
             return;
         }
-        Err(KreuzbergError::UnsupportedFormat(fmt)) => {
+        Err(KreuzbergError::UnsupportedFormat { mime_type: fmt, .. }) => {
             println!(
                 "Skipping office_ppt_legacy: unsupported format {fmt} (requires optional tool)",
                 fmt = fmt
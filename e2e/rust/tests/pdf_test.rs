@@ -248,6 +248,7 @@ fn test_pdf_simple_text() {
     assertions::assert_expected_mime(&result, &["application/pdf"]);
     assertions::assert_min_content_length(&result, 50);
     assertions::assert_content_contains_any(&result, &["May 5, 2023", "To Whom it May Concern", "Mallori"]);
+    assertions::assert_metadata_expectation(&result, "extractor_name", &serde_json::json!({"eq":"pdf"}));
 }
 
 #[test]
@@ -434,6 +434,8 @@ describe("ocr fixtures", () => {
 			assertions.assertExpectedMime(result, ["application/pdf"]);
 			assertions.assertMinContentLength(result, 20);
 			assertions.assertContentContainsAny(result, ["Docling", "Markdown", "JSON"]);
+			assertions.assertMetadataExpectation(result, "ocr_page_count", { gte: 1 });
+			assertions.assertMetadataExpectation(result, "ocr_used", { eq: true });
 		},
 		TEST_TIMEOUT_MS,
 	);
@@ -292,6 +292,7 @@ describe("pdf fixtures", () => {
 			assertions.assertExpectedMime(result, ["application/pdf"]);
 			assertions.assertMinContentLength(result, 50);
 			assertions.assertContentContainsAny(result, ["May 5, 2023", "To Whom it May Concern", "Mallori"]);
+			assertions.assertMetadataExpectation(result, "extractor_name", { eq: "pdf" });
 		},
 		TEST_TIMEOUT_MS,
 	);
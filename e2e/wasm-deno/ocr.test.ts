@@ -121,4 +121,6 @@ Deno.test("ocr_pdf_tesseract", { permissions: { read: true } }, async () => {
 	assertions.assertExpectedMime(result, ["application/pdf"]);
 	assertions.assertMinContentLength(result, 20);
 	assertions.assertContentContainsAny(result, ["Docling", "Markdown", "JSON"]);
+	assertions.assertMetadataExpectation(result, "ocr_page_count", { gte: 1 });
+	assertions.assertMetadataExpectation(result, "ocr_used", { eq: true });
 });
@@ -221,6 +221,7 @@ Deno.test("pdf_simple_text", { permissions: { read: true } }, async () => {
 	assertions.assertExpectedMime(result, ["application/pdf"]);
 	assertions.assertMinContentLength(result, 50);
 	assertions.assertContentContainsAny(result, ["May 5, 2023", "To Whom it May Concern", "Mallori"]);
+	assertions.assertMetadataExpectation(result, "extractor_name", { eq: "pdf" });
 });
 
 Deno.test("pdf_tables_large", { permissions: { read: true } }, async () => {
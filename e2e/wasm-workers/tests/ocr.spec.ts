@@ -146,5 +146,7 @@ describe("ocr", () => {
 		assertions.assertExpectedMime(result, ["application/pdf"]);
 		assertions.assertMinContentLength(result, 20);
 		assertions.assertContentContainsAny(result, ["Docling", "Markdown", "JSON"]);
+		assertions.assertMetadataExpectation(result, "ocr_page_count", { gte: 1 });
+		assertions.assertMetadataExpectation(result, "ocr_used", { eq: true });
 	});
 });
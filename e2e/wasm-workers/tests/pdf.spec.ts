@@ -261,6 +261,7 @@ describe("pdf", () => {
 		assertions.assertExpectedMime(result, ["application/pdf"]);
 		assertions.assertMinContentLength(result, 50);
 		assertions.assertContentContainsAny(result, ["May 5, 2023", "To Whom it May Concern", "Mallori"]);
+		assertions.assertMetadataExpectation(result, "extractor_name", { eq: "pdf" });
 	});
 
 	it("pdf_tables_large", async () => {
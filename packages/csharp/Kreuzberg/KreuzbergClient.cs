@@ -1220,6 +1220,8 @@ public static class KreuzbergClient
             result.Elements = DeserializeField<List<Element>>(cRes.ElementsJson);
             result.OcrElements = DeserializeField<List<OcrElement>>(cRes.OcrElementsJson);
             result.Document = DeserializeField<DocumentStructure>(cRes.DocumentJson);
+            result.Outline = DeserializeField<List<OutlineNode>>(cRes.OutlineJson);
+            result.TextBlocks = DeserializeField<List<TextBlock>>(cRes.TextBlocksJson);
 
             if (result.Metadata.Pages == null && cRes.PageStructureJson != IntPtr.Zero)
             {
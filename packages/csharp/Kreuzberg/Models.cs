@@ -513,6 +513,75 @@ public sealed class ExtractionResult
     /// </summary>
     [JsonPropertyName("document")]
     public DocumentStructure? Document { get; set; }
+
+    /// <summary>
+    /// Document outline from PDF bookmarks or DOCX heading styles.
+    /// Available when outline extraction is enabled.
+    /// </summary>
+    [JsonPropertyName("outline")]
+    public List<OutlineNode>? Outline { get; set; }
+
+    /// <summary>
+    /// Text blocks with their page and bounding box.
+    /// Available when position output is enabled.
+    /// </summary>
+    [JsonPropertyName("text_blocks")]
+    public List<TextBlock>? TextBlocks { get; set; }
+}
+
+/// <summary>
+/// A run of text with the page it appears on and its bounding box.
+/// PDF blocks use PDF points (origin bottom-left); OCR blocks use image pixels (origin top-left).
+/// </summary>
+public sealed class TextBlock
+{
+    /// <summary>
+    /// Text content of the block.
+    /// </summary>
+    [JsonPropertyName("text")]
+    public string Text { get; set; } = string.Empty;
+
+    /// <summary>
+    /// 1-indexed page the block appears on.
+    /// </summary>
+    [JsonPropertyName("page")]
+    public int Page { get; set; }
+
+    /// <summary>
+    /// Bounding box of the block on its page.
+    /// </summary>
+    [JsonPropertyName("bbox")]
+    public BoundingBox BBox { get; set; } = new();
+}
+
+/// <summary>
+/// An entry in a document's outline.
+/// </summary>
+public sealed class OutlineNode
+{
+    /// <summary>
+    /// Entry title as shown in the document's navigation pane.
+    /// </summary>
+    [JsonPropertyName("title")]
+    public string Title { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Nesting level, starting at 1 for top-level entries.
+    /// </summary>
+    [JsonPropertyName("level")]
+    public int Level { get; set; }
+
+    /// <summary>
+    /// 1-indexed page the entry points to, when known.
+    /// </summary>
+    [JsonPropertyName("page_number")]
+    public int? PageNumber { get; set; }
+
+    /// <summary>
+    /// Nested entries.
+    /// </summary>
+    [JsonPropertyName("children")]
+    public List<OutlineNode> Children { get; set; } = new();
 }
 
 /// <summary>
@@ -627,6 +696,18 @@ public sealed class Table
     /// </summary>
     [JsonPropertyName("page_number")]
     public int PageNumber { get; set; }
+
+    /// <summary>
+    /// Table name, e.g. the sheet name for spreadsheets.
+    /// </summary>
+    [JsonPropertyName("name")]
+    public string? Name { get; set; }
+
+    /// <summary>
+    /// Whether the first row of <see cref="Cells"/> is a header row.
+    /// </summary>
+    [JsonPropertyName("has_header")]
+    public bool HasHeader { get; set; }
 }
 
 /// <summary>
@@ -699,6 +780,12 @@ public sealed class ChunkMetadata
     /// </summary>
     [JsonPropertyName("last_page")]
     public int? LastPage { get; set; }
+
+    /// <summary>
+    /// Titles of the Markdown headings this chunk falls under, outermost first. Only set by the Markdown chunker.
+    /// </summary>
+    [JsonPropertyName("heading_path")]
+    public List<string>? HeadingPath { get; set; }
 }
 
 /// <summary>
@@ -1202,6 +1289,12 @@ public sealed class ExcelMetadata
     /// </summary>
     [JsonPropertyName("sheet_names")]
     public List<string> SheetNames { get; set; } = new();
+
+    /// <summary>
+    /// Names of the hidden and very hidden sheets in the workbook.
+    /// </summary>
+    [JsonPropertyName("hidden_sheets")]
+    public List<string> HiddenSheets { get; set; } = new();
 }
 
 /// <summary>
@@ -1886,6 +1979,13 @@ public sealed class ExtractionConfig
     [JsonPropertyName("output_format")]
     public string? OutputFormat { get; init; }
 
+    /// <summary>
+    /// Markdown decoration of HTML and DOCX content (markdown, plain_text).
+    /// Default: markdown
+    /// </summary>
+    [JsonPropertyName("output_mode")]
+    public string? OutputMode { get; init; }
+
     /// <summary>
     /// Result structure format (unified, element_based).
     /// Default: unified
@@ -1901,6 +2001,34 @@ public sealed class ExtractionConfig
     [JsonPropertyName("include_document_structure")]
     public bool IncludeDocumentStructure { get; init; }
 
+    /// <summary>
+    /// Whether to populate the result outline from PDF bookmarks or DOCX heading styles.
+    /// Default: false
+    /// </summary>
+    [JsonPropertyName("extract_outline")]
+    public bool ExtractOutline { get; init; }
+
+    /// <summary>
+    /// Whether to populate the result text blocks with page numbers and bounding boxes.
+    /// Default: false
+    /// </summary>
+    [JsonPropertyName("emit_positions")]
+    public bool EmitPositions { get; init; }
+
+    /// <summary>
+    /// Whether post-processor plugins update the result in place instead of working on a full copy, reducing peak memory for large documents.
+    /// Default: false
+    /// </summary>
+    [JsonPropertyName("low_memory")]
+    public bool LowMemory { get; init; }
+
+    /// <summary>
+    /// Line ending of the extracted text, applied as the final processing step (lf, crlf, keep).
+    /// Default: null (line endings are left unchanged)
+    /// </summary>
+    [JsonPropertyName("normalize_newlines")]
+    public string? NormalizeNewlines { get; init; }
+
 }
 
 /// <summary>
@@ -2349,6 +2477,13 @@ public sealed class TokenReductionConfig
     /// </summary>
     [JsonPropertyName("preserve_important_words")]
     public bool? PreserveImportantWords { get; init; }
+
+    /// <summary>
+    /// Stopword language as an ISO 639-1 or 639-3 code (e.g., "de", "deu").
+    /// Defaults to the detected language, then English.
+    /// </summary>
+    [JsonPropertyName("language")]
+    public string? Language { get; init; }
 }
 
 /// <summary>
@@ -65,6 +65,10 @@ internal static partial class NativeMethods
         public IntPtr OcrElementsJson;
         /// <summary>JSON object of document structure pointer.</summary>
         public IntPtr DocumentJson;
+        /// <summary>JSON array of document outline entries pointer.</summary>
+        public IntPtr OutlineJson;
+        /// <summary>JSON array of positioned text blocks pointer.</summary>
+        public IntPtr TextBlocksJson;
 
         /// <summary>Whether extraction succeeded.</summary>
         [MarshalAs(UnmanagedType.I1)]
@@ -1454,7 +1454,7 @@ internal static class Serialization
     private static readonly FrozenDictionary<FormatType, string[]> FormatFields = new Dictionary<FormatType, string[]>
     {
         { FormatType.Pdf, new[] { "title", "subject", "author", "keywords", "creator", "producer", "creation_date", "modification_date", "page_count" } },
-        { FormatType.Excel, new[] { "sheet_count", "sheet_names" } },
+        { FormatType.Excel, new[] { "sheet_count", "sheet_names", "hidden_sheets" } },
         { FormatType.Email, new[] { "from_email", "from_name", "to_emails", "cc_emails", "bcc_emails", "message_id", "attachments" } },
         { FormatType.Pptx, new[] { "slide_count", "slide_names" } },
         { FormatType.Archive, new[] { "format", "file_count", "file_list", "total_size", "compressed_size" } },
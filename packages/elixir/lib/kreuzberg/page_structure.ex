@@ -125,7 +125,7 @@ defmodule Kreuzberg.PageStructure do
   ## Fields
 
     * `:total_count` - Total number of pages/slides/sheets
-    * `:unit_type` - Type of paginated unit ("page", "slide", "sheet")
+    * `:unit_type` - Type of paginated unit ("page", "slide", "sheet", "chapter")
     * `:boundaries` - Optional list of byte offset boundaries per page
     * `:pages` - Optional list of per-page metadata
   """
@@ -203,9 +203,20 @@ pub fn parse_extraction_config(_env: Env, options: Term) -> Result<kreuzberg::co
             // Forward remaining fields from deserialized config
             config.result_format = deserialized.result_format;
             config.output_format = deserialized.output_format;
+            config.output_mode = deserialized.output_mode;
             config.html_options = deserialized.html_options;
             config.max_concurrent_extractions = deserialized.max_concurrent_extractions;
             config.security_limits = deserialized.security_limits;
+            config.max_content_chars = deserialized.max_content_chars;
+            config.preserve_list_markers = deserialized.preserve_list_markers;
+            config.extract_outline = deserialized.extract_outline;
+            config.extract_references = deserialized.extract_references;
+            config.normalize_bidi = deserialized.normalize_bidi;
+            config.html_include_alt_text = deserialized.html_include_alt_text;
+            config.emit_positions = deserialized.emit_positions;
+            config.emit_source_offsets = deserialized.emit_source_offsets;
+            config.low_memory = deserialized.low_memory;
+            config.normalize_newlines = deserialized.normalize_newlines;
         }
         Err(e) => {
             // Nested structure deserialization failed
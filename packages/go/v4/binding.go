@@ -415,6 +415,14 @@ func convertCResult(cRes *C.CExtractionResult) (*ExtractionResult, error) {
 		return nil, newSerializationErrorWithContext("failed to decode document structure", err, ErrorCodeValidation, nil)
 	}
 
+	if err := decodeJSONCString(cRes.outline_json, &result.Outline); err != nil {
+		return nil, newSerializationErrorWithContext("failed to decode outline", err, ErrorCodeValidation, nil)
+	}
+
+	if err := decodeJSONCString(cRes.text_blocks_json, &result.TextBlocks); err != nil {
+		return nil, newSerializationErrorWithContext("failed to decode text blocks", err, ErrorCodeValidation, nil)
+	}
+
 	return result, nil
 }
 
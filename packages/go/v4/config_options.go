@@ -134,6 +134,27 @@ func WithIncludeDocumentStructure(include bool) ExtractionOption {
 	}
 }
 
+// WithExtractOutline sets whether to populate the result outline from PDF bookmarks or DOCX headings.
+func WithExtractOutline(extract bool) ExtractionOption {
+	return func(c *ExtractionConfig) {
+		c.ExtractOutline = &extract
+	}
+}
+
+// WithEmitPositions sets whether to populate the result text blocks with page numbers and bounding boxes.
+func WithEmitPositions(emit bool) ExtractionOption {
+	return func(c *ExtractionConfig) {
+		c.EmitPositions = &emit
+	}
+}
+
+// WithLowMemory sets whether post-processor plugins update the result in place instead of a full copy, reducing peak memory.
+func WithLowMemory(lowMemory bool) ExtractionOption {
+	return func(c *ExtractionConfig) {
+		c.LowMemory = &lowMemory
+	}
+}
+
 // WithOutputFormat sets the content output format.
 // Options: "plain", "markdown", "djot", "html"
 func WithOutputFormat(format string) ExtractionOption {
@@ -142,6 +163,22 @@ func WithOutputFormat(format string) ExtractionOption {
 	}
 }
 
+// WithOutputMode sets whether HTML and DOCX content keeps its Markdown decoration.
+// Options: "markdown", "plain_text"
+func WithOutputMode(mode string) ExtractionOption {
+	return func(c *ExtractionConfig) {
+		c.OutputMode = mode
+	}
+}
+
+// WithNormalizeNewlines sets the line ending of the extracted text.
+// Options: "lf", "crlf", "keep"
+func WithNormalizeNewlines(ending string) ExtractionOption {
+	return func(c *ExtractionConfig) {
+		c.NormalizeNewlines = ending
+	}
+}
+
 // WithResultFormat sets the result structure format.
 // Options: "unified", "element_based"
 func WithResultFormat(format string) ExtractionOption {
@@ -652,6 +689,13 @@ func WithPreserveImportantWords(enabled bool) TokenReductionOption {
 	}
 }
 
+// WithTokenReductionLanguage sets the stopword language ("de", "deu"); defaults to the detected language.
+func WithTokenReductionLanguage(lang string) TokenReductionOption {
+	return func(c *TokenReductionConfig) {
+		c.Language = &lang
+	}
+}
+
 // ============================================================================
 // LanguageDetectionConfig Options
 // ============================================================================
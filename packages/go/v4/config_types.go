@@ -87,8 +87,14 @@ type ExtractionConfig struct {
 	SecurityLimits           *SecurityLimitsConfig    `json:"security_limits,omitempty"`
 	MaxConcurrentExtractions *int                     `json:"max_concurrent_extractions,omitempty"`
 	IncludeDocumentStructure *bool                    `json:"include_document_structure,omitempty"`
+	ExtractOutline           *bool                    `json:"extract_outline,omitempty"`
+	HTMLIncludeAltText       *bool                    `json:"html_include_alt_text,omitempty"`
+	EmitPositions            *bool                    `json:"emit_positions,omitempty"`
+	LowMemory                *bool                    `json:"low_memory,omitempty"`
 	OutputFormat             string                   `json:"output_format,omitempty"`
+	OutputMode               string                   `json:"output_mode,omitempty"`
 	ResultFormat             string                   `json:"result_format,omitempty"`
+	NormalizeNewlines        string                   `json:"normalize_newlines,omitempty"`
 }
 
 // SecurityLimitsConfig controls security thresholds for archive extraction.
@@ -127,11 +133,31 @@ type PaddleOcrConfig struct {
 
 // OCRConfig selects and configures OCR backends.
 type OCRConfig struct {
-	Backend       string            `json:"backend,omitempty"`
-	Language      *string           `json:"language,omitempty"`
-	Tesseract     *TesseractConfig  `json:"tesseract_config,omitempty"`
-	PaddleOcr     *PaddleOcrConfig  `json:"paddle_ocr_config,omitempty"`
-	ElementConfig *OcrElementConfig `json:"element_config,omitempty"`
+	Backend             string            `json:"backend,omitempty"`
+	Language            *string           `json:"language,omitempty"`
+	Tesseract           *TesseractConfig  `json:"tesseract_config,omitempty"`
+	PaddleOcr           *PaddleOcrConfig  `json:"paddle_ocr_config,omitempty"`
+	ElementConfig       *OcrElementConfig `json:"element_config,omitempty"`
+	// Backends tried in order when Backend is unavailable or fails.
+	FallbackBackends    []string          `json:"fallback_backends,omitempty"`
+	// Domain-specific words to bias recognition towards (Tesseract user-words, PaddleOCR post-correction).
+	UserWords           []string          `json:"user_words,omitempty"`
+	// DPI at which PDF pages are rendered before OCR (default 300).
+	RenderDpi           *int              `json:"render_dpi,omitempty"`
+	// Report pages where OCR found no text (blank, or normalized confidence below this floor) in metadata["ocr_empty_pages"].
+	EmptyPageConfidence *float64          `json:"empty_page_confidence,omitempty"`
+	// Only OCR these 1-indexed PDF pages, keeping the native text of the rest.
+	PageFilter          []int             `json:"page_filter,omitempty"`
+	// Only OCR this region of each page, in coordinates normalized to the page size.
+	Region              *Rect             `json:"region,omitempty"`
+}
+
+// Rect is a rectangle in coordinates normalized to the page size (0-1, origin top-left).
+type Rect struct {
+	Left   float64 `json:"left"`
+	Top    float64 `json:"top"`
+	Width  float64 `json:"width"`
+	Height float64 `json:"height"`
 }
 
 // TesseractConfig exposes fine-grained controls for the Tesseract backend.
@@ -198,10 +224,18 @@ type FontConfig struct {
 
 // PdfConfig exposes PDF-specific options.
 type PdfConfig struct {
-	ExtractImages   *bool       `json:"extract_images,omitempty"`
-	Passwords       []string    `json:"passwords,omitempty"`
-	ExtractMetadata *bool       `json:"extract_metadata,omitempty"`
-	FontConfig      *FontConfig `json:"font_config,omitempty"`
+	ExtractImages      *bool       `json:"extract_images,omitempty"`
+	Passwords          []string    `json:"passwords,omitempty"`
+	ExtractMetadata    *bool       `json:"extract_metadata,omitempty"`
+	FontConfig         *FontConfig `json:"font_config,omitempty"`
+	// Reading order: "raw" (default) or "column_aware".
+	ReadingOrder       string      `json:"reading_order,omitempty"`
+	// Text inserted between pages; "{page}" becomes the following page number. Default: "\n\n".
+	PageSeparator      *string     `json:"page_separator,omitempty"`
+	// Recognize formulas as LaTeX (requires the math-ocr feature).
+	ExtractMath        *bool       `json:"extract_math,omitempty"`
+	// Insert "[image N]" markers into the content where extracted images appear.
+	InsertImageMarkers *bool       `json:"insert_image_markers,omitempty"`
 }
 
 // HierarchyConfig controls PDF hierarchy extraction based on font sizes.
@@ -221,8 +255,9 @@ type HierarchyConfig struct {
 
 // TokenReductionConfig governs token pruning before embeddings.
 type TokenReductionConfig struct {
-	Mode                   string `json:"mode,omitempty"`
-	PreserveImportantWords *bool  `json:"preserve_important_words,omitempty"`
+	Mode                   string  `json:"mode,omitempty"`
+	PreserveImportantWords *bool   `json:"preserve_important_words,omitempty"`
+	Language               *string `json:"language,omitempty"`
 }
 
 // LanguageDetectionConfig enables automatic language detection.
@@ -223,7 +223,7 @@ typedef struct CErrorDetails {
  * # Memory Layout
  *
  * Must be kept in sync with the Java side's MemoryLayout definition in KreuzbergFFI.java
- * Field order: 15 pointers (8 bytes each) + 1 bool + 7 bytes padding = 128 bytes total
+ * Field order: 17 pointers (8 bytes each) + 1 bool + 7 bytes padding = 144 bytes total
  *
  * The `#[repr(C)]` attribute ensures the struct follows C's memory layout rules:
  * - Fields are laid out in order
@@ -296,6 +296,14 @@ typedef struct CExtractionResult {
    * Document structure as JSON object (null-terminated string, or NULL if not available, must be freed with kreuzberg_free_string)
    */
   char *document_json;
+  /**
+   * Document outline as JSON array (null-terminated string, or NULL if not requested, must be freed with kreuzberg_free_string)
+   */
+  char *outline_json;
+  /**
+   * Positioned text blocks as JSON array (null-terminated string, or NULL if not requested, must be freed with kreuzberg_free_string)
+   */
+  char *text_blocks_json;
   /**
    * Whether extraction was successful
    */
@@ -1673,7 +1681,7 @@ char *kreuzberg_clone_string(const char *s);
  *
  * # Memory Layout
  *
- * This function frees all 13 string fields in CExtractionResult:
+ * This function frees all 17 string fields in CExtractionResult:
  * 1. content
  * 2. mime_type
  * 3. language
@@ -1688,6 +1696,9 @@ char *kreuzberg_clone_string(const char *s);
  * 12. pages_json (FIXED: was missing before PR #3)
  * 13. elements_json (ADDED: for element-based extraction support)
  * 14. ocr_elements_json (ADDED: for OCR element output)
+ * 15. document_json
+ * 16. outline_json
+ * 17. text_blocks_json
  *
  * # Example (C)
  *
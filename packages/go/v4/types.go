@@ -96,6 +96,24 @@ type ExtractionResult struct {
 	OcrElements       []OcrElement       `json:"ocr_elements,omitempty"`
 	DjotContent       *DjotContent       `json:"djot_content,omitempty"`
 	Document          *DocumentStructure `json:"document,omitempty"`
+	Outline           []OutlineNode      `json:"outline,omitempty"`
+	TextBlocks        []TextBlock        `json:"text_blocks,omitempty"`
+}
+
+// TextBlock is a run of text with the page it appears on and its bounding box.
+// PDF blocks use PDF points (origin bottom-left); OCR blocks use image pixels (origin top-left).
+type TextBlock struct {
+	Text string      `json:"text"`
+	Page int         `json:"page"`
+	BBox BoundingBox `json:"bbox"`
+}
+
+// OutlineNode is an entry in a document outline (PDF bookmarks or DOCX headings).
+type OutlineNode struct {
+	Title      string        `json:"title"`
+	Level      int           `json:"level"`
+	PageNumber *int          `json:"page_number,omitempty"`
+	Children   []OutlineNode `json:"children,omitempty"`
 }
 
 // Table represents a detected table in the source document.
@@ -103,6 +121,8 @@ type Table struct {
 	Cells      [][]string `json:"cells"`
 	Markdown   string     `json:"markdown"`
 	PageNumber int        `json:"page_number"`
+	Name       *string    `json:"name,omitempty"`
+	HasHeader  bool       `json:"has_header"`
 }
 
 // Chunk contains chunked content plus optional embeddings and metadata.
@@ -114,13 +134,14 @@ type Chunk struct {
 
 // ChunkMetadata provides positional information for a chunk.
 type ChunkMetadata struct {
-	ByteStart   uint64  `json:"byte_start"`
-	ByteEnd     uint64  `json:"byte_end"`
-	TokenCount  *uint64 `json:"token_count,omitempty"`
-	ChunkIndex  uint64  `json:"chunk_index"`
-	TotalChunks uint64  `json:"total_chunks"`
-	FirstPage   *uint64 `json:"first_page,omitempty"`
-	LastPage    *uint64 `json:"last_page,omitempty"`
+	ByteStart   uint64   `json:"byte_start"`
+	ByteEnd     uint64   `json:"byte_end"`
+	TokenCount  *uint64  `json:"token_count,omitempty"`
+	ChunkIndex  uint64   `json:"chunk_index"`
+	TotalChunks uint64   `json:"total_chunks"`
+	FirstPage   *uint64  `json:"first_page,omitempty"`
+	LastPage    *uint64  `json:"last_page,omitempty"`
+	HeadingPath []string `json:"heading_path,omitempty"`
 }
 
 // ExtractedImage represents an extracted image, optionally with nested OCR results.
@@ -135,6 +156,10 @@ type ExtractedImage struct {
 	BitsPerComponent *uint32           `json:"bits_per_component,omitempty"`
 	IsMask           bool              `json:"is_mask"`
 	Description      *string           `json:"description,omitempty"`
+	AltText          *string           `json:"alt_text,omitempty"`
+	Caption          *string           `json:"caption,omitempty"`
+	// PagePosition is the top-left corner as [x, y] fractions of the page (PDF only).
+	PagePosition     *[2]float32       `json:"page_position,omitempty"`
 	OCRResult        *ExtractionResult `json:"ocr_result,omitempty"`
 }
 
@@ -264,8 +289,9 @@ type PdfMetadata struct {
 
 // ExcelMetadata lists sheets inside spreadsheet documents.
 type ExcelMetadata struct {
-	SheetCount uint64   `json:"sheet_count"`
-	SheetNames []string `json:"sheet_names"`
+	SheetCount   uint64   `json:"sheet_count"`
+	SheetNames   []string `json:"sheet_names"`
+	HiddenSheets []string `json:"hidden_sheets,omitempty"`
 }
 
 // EmailMetadata captures envelope data for EML/MSG messages.
@@ -449,9 +475,10 @@ type ErrorMetadata struct {
 type PageUnitType string
 
 const (
-	PageUnitTypePage  PageUnitType = "page"
-	PageUnitTypeSlide PageUnitType = "slide"
-	PageUnitTypeSheet PageUnitType = "sheet"
+	PageUnitTypePage    PageUnitType = "page"
+	PageUnitTypeSlide   PageUnitType = "slide"
+	PageUnitTypeSheet   PageUnitType = "sheet"
+	PageUnitTypeChapter PageUnitType = "chapter"
 )
 
 // PageBoundary marks byte offset boundaries for a page in the extracted content.
@@ -2,6 +2,7 @@ package dev.kreuzberg;
 
 import com.fasterxml.jackson.annotation.JsonCreator;
 import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.List;
 import java.util.Objects;
 import java.util.Optional;
 
@@ -20,12 +21,13 @@ public final class ChunkMetadata {
 	private final Integer tokenCount;
 	private final int chunkIndex;
 	private final int totalChunks;
+	private final List<String> headingPath;
 
 	@JsonCreator
 	public ChunkMetadata(@JsonProperty("byte_start") long byteStart, @JsonProperty("byte_end") long byteEnd,
 			@JsonProperty("first_page") Long firstPage, @JsonProperty("last_page") Long lastPage,
 			@JsonProperty("token_count") Integer tokenCount, @JsonProperty("chunk_index") int chunkIndex,
-			@JsonProperty("total_chunks") int totalChunks) {
+			@JsonProperty("total_chunks") int totalChunks, @JsonProperty("heading_path") List<String> headingPath) {
 		if (byteStart < 0 || byteEnd < byteStart) {
 			throw new IllegalArgumentException("Invalid chunk byte range: " + byteStart + "-" + byteEnd);
 		}
@@ -51,6 +53,7 @@ public final class ChunkMetadata {
 		this.tokenCount = tokenCount;
 		this.chunkIndex = chunkIndex;
 		this.totalChunks = totalChunks;
+		this.headingPath = headingPath != null ? List.copyOf(headingPath) : null;
 	}
 
 	/**
@@ -121,6 +124,19 @@ public final class ChunkMetadata {
 		return totalChunks;
 	}
 
+	/**
+	 * Get the titles of the Markdown headings this chunk falls under, outermost
+	 * first (optional).
+	 *
+	 * <p>
+	 * Only set by the Markdown chunker.
+	 *
+	 * @return heading path, or empty if not available
+	 */
+	public Optional<List<String>> getHeadingPath() {
+		return Optional.ofNullable(headingPath);
+	}
+
 	@Override
 	public boolean equals(Object obj) {
 		if (this == obj) {
@@ -132,18 +148,20 @@ public final class ChunkMetadata {
 		ChunkMetadata other = (ChunkMetadata) obj;
 		return byteStart == other.byteStart && byteEnd == other.byteEnd && Objects.equals(firstPage, other.firstPage)
 				&& Objects.equals(lastPage, other.lastPage) && Objects.equals(tokenCount, other.tokenCount)
-				&& chunkIndex == other.chunkIndex && totalChunks == other.totalChunks;
+				&& chunkIndex == other.chunkIndex && totalChunks == other.totalChunks
+				&& Objects.equals(headingPath, other.headingPath);
 	}
 
 	@Override
 	public int hashCode() {
-		return Objects.hash(byteStart, byteEnd, firstPage, lastPage, tokenCount, chunkIndex, totalChunks);
+		return Objects.hash(byteStart, byteEnd, firstPage, lastPage, tokenCount, chunkIndex, totalChunks,
+				headingPath);
 	}
 
 	@Override
 	public String toString() {
 		return "ChunkMetadata{" + "byteStart=" + byteStart + ", byteEnd=" + byteEnd + ", firstPage=" + firstPage
 				+ ", lastPage=" + lastPage + ", tokenCount=" + tokenCount + ", chunkIndex=" + chunkIndex
-				+ ", totalChunks=" + totalChunks + '}';
+				+ ", totalChunks=" + totalChunks + ", headingPath=" + headingPath + '}';
 	}
 }
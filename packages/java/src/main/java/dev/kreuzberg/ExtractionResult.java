@@ -30,11 +30,30 @@ public final class ExtractionResult {
 	@JsonProperty("djot_content")
 	private final DjotContent djotContent;
 	private final DocumentStructure document;
+	private final List<OutlineNode> outline;
+	@JsonProperty("text_blocks")
+	private final List<TextBlock> textBlocks;
 
 	ExtractionResult(String content, String mimeType, Metadata metadata, List<Table> tables,
 			List<String> detectedLanguages, List<Chunk> chunks, List<ExtractedImage> images, List<PageContent> pages,
 			PageStructure pageStructure, List<Element> elements, List<OcrElement> ocrElements, DjotContent djotContent,
 			DocumentStructure document) {
+		this(content, mimeType, metadata, tables, detectedLanguages, chunks, images, pages, pageStructure, elements,
+				ocrElements, djotContent, document, null);
+	}
+
+	ExtractionResult(String content, String mimeType, Metadata metadata, List<Table> tables,
+			List<String> detectedLanguages, List<Chunk> chunks, List<ExtractedImage> images, List<PageContent> pages,
+			PageStructure pageStructure, List<Element> elements, List<OcrElement> ocrElements, DjotContent djotContent,
+			DocumentStructure document, List<OutlineNode> outline) {
+		this(content, mimeType, metadata, tables, detectedLanguages, chunks, images, pages, pageStructure, elements,
+				ocrElements, djotContent, document, outline, null);
+	}
+
+	ExtractionResult(String content, String mimeType, Metadata metadata, List<Table> tables,
+			List<String> detectedLanguages, List<Chunk> chunks, List<ExtractedImage> images, List<PageContent> pages,
+			PageStructure pageStructure, List<Element> elements, List<OcrElement> ocrElements, DjotContent djotContent,
+			DocumentStructure document, List<OutlineNode> outline, List<TextBlock> textBlocks) {
 		this.content = Objects.requireNonNull(content, "content must not be null");
 		this.mimeType = Objects.requireNonNull(mimeType, "mimeType must not be null");
 		this.metadata = metadata != null ? metadata : Metadata.empty();
@@ -52,6 +71,8 @@ public final class ExtractionResult {
 		this.ocrElements = Collections.unmodifiableList(ocrElements != null ? ocrElements : List.of());
 		this.djotContent = djotContent;
 		this.document = document;
+		this.outline = outline != null ? Collections.unmodifiableList(outline) : null;
+		this.textBlocks = textBlocks != null ? Collections.unmodifiableList(textBlocks) : null;
 	}
 
 	public String getContent() {
@@ -178,6 +199,34 @@ public final class ExtractionResult {
 		return Optional.ofNullable(document);
 	}
 
+	/**
+	 * Get the document outline (optional).
+	 *
+	 * <p>
+	 * Available when outline extraction is enabled in the extraction
+	 * configuration via {@code extract_outline=true}. PDFs provide the outline
+	 * from their bookmarks, DOCX documents from their heading styles.
+	 *
+	 * @return top-level outline entries, or empty if not available
+	 */
+	public Optional<List<OutlineNode>> getOutline() {
+		return Optional.ofNullable(outline);
+	}
+
+	/**
+	 * Get the positioned text blocks (optional).
+	 *
+	 * <p>
+	 * Available when the extraction configuration sets
+	 * {@code emit_positions=true}. PDFs provide blocks from their text layer, OCR
+	 * results from the recognized OCR elements.
+	 *
+	 * @return text blocks with page and bounding box, or empty if not available
+	 */
+	public Optional<List<TextBlock>> getTextBlocks() {
+		return Optional.ofNullable(textBlocks);
+	}
+
 	/**
 	 * Check if the extraction was successful.
 	 *
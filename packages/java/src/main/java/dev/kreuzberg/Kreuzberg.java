@@ -1233,10 +1233,13 @@ public final class Kreuzberg {
 				.readCString(result.get(ValueLayout.ADDRESS, KreuzbergFFI.OCR_ELEMENTS_OFFSET));
 		String documentJson = KreuzbergFFI
 				.readCString(result.get(ValueLayout.ADDRESS, KreuzbergFFI.DOCUMENT_JSON_OFFSET));
+		String outlineJson = KreuzbergFFI.readCString(result.get(ValueLayout.ADDRESS, KreuzbergFFI.OUTLINE_JSON_OFFSET));
+		String textBlocksJson = KreuzbergFFI
+				.readCString(result.get(ValueLayout.ADDRESS, KreuzbergFFI.TEXT_BLOCKS_JSON_OFFSET));
 
 		return ResultParser.parse(content, mimeType, tablesJson, detectedLanguagesJson, metadataJson, chunksJson,
 				imagesJson, pagesJson, pageStructureJson, elementsJson, ocrElementsJson, null, language, date, subject,
-				documentJson);
+				documentJson, outlineJson, textBlocksJson);
 	}
 
 	/**
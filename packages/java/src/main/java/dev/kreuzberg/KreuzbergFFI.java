@@ -119,7 +119,8 @@ public final class KreuzbergFFI {
 			ValueLayout.ADDRESS.withName("chunks_json"), ValueLayout.ADDRESS.withName("images_json"),
 			ValueLayout.ADDRESS.withName("page_structure_json"), ValueLayout.ADDRESS.withName("pages_json"),
 			ValueLayout.ADDRESS.withName("elements_json"), ValueLayout.ADDRESS.withName("ocr_elements_json"),
-			ValueLayout.ADDRESS.withName("document_json"), ValueLayout.JAVA_BOOLEAN.withName("success"),
+			ValueLayout.ADDRESS.withName("document_json"), ValueLayout.ADDRESS.withName("outline_json"),
+			ValueLayout.ADDRESS.withName("text_blocks_json"), ValueLayout.JAVA_BOOLEAN.withName("success"),
 			MemoryLayout.paddingLayout(7));
 
 	public static final long CONTENT_OFFSET = C_EXTRACTION_RESULT_LAYOUT
@@ -152,6 +153,10 @@ public final class KreuzbergFFI {
 			.byteOffset(MemoryLayout.PathElement.groupElement("ocr_elements_json"));
 	public static final long DOCUMENT_JSON_OFFSET = C_EXTRACTION_RESULT_LAYOUT
 			.byteOffset(MemoryLayout.PathElement.groupElement("document_json"));
+	public static final long OUTLINE_JSON_OFFSET = C_EXTRACTION_RESULT_LAYOUT
+			.byteOffset(MemoryLayout.PathElement.groupElement("outline_json"));
+	public static final long TEXT_BLOCKS_JSON_OFFSET = C_EXTRACTION_RESULT_LAYOUT
+			.byteOffset(MemoryLayout.PathElement.groupElement("text_blocks_json"));
 	public static final long SUCCESS_OFFSET = C_EXTRACTION_RESULT_LAYOUT
 			.byteOffset(MemoryLayout.PathElement.groupElement("success"));
 
@@ -0,0 +1,94 @@
+package dev.kreuzberg;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonIgnore;
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Collections;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * An entry in a document's outline.
+ *
+ * <p>
+ * PDFs provide the outline from their bookmarks; DOCX documents from their
+ * heading styles. Available when extraction is configured with
+ * {@code extract_outline=true}.
+ */
+@JsonIgnoreProperties(ignoreUnknown = true)
+@JsonInclude(JsonInclude.Include.NON_EMPTY)
+public final class OutlineNode {
+	private final String title;
+	private final int level;
+	@JsonProperty("page_number")
+	private final Integer pageNumber;
+	private final List<OutlineNode> children;
+
+	/**
+	 * Create a new OutlineNode.
+	 *
+	 * @param title
+	 *            the entry title
+	 * @param level
+	 *            the nesting level, starting at 1
+	 * @param pageNumber
+	 *            the 1-indexed target page, or null if unknown
+	 * @param children
+	 *            the nested entries (may be null)
+	 */
+	@JsonCreator
+	public OutlineNode(@JsonProperty("title") String title, @JsonProperty("level") int level,
+			@JsonProperty("page_number") Integer pageNumber, @JsonProperty("children") List<OutlineNode> children) {
+		this.title = title != null ? title : "";
+		this.level = level;
+		this.pageNumber = pageNumber;
+		this.children = Collections.unmodifiableList(children != null ? children : Collections.emptyList());
+	}
+
+	@JsonProperty("title")
+	public String getTitle() {
+		return title;
+	}
+
+	@JsonProperty("level")
+	public int getLevel() {
+		return level;
+	}
+
+	@JsonIgnore
+	public Optional<Integer> getPageNumber() {
+		return Optional.ofNullable(pageNumber);
+	}
+
+	@JsonProperty("children")
+	public List<OutlineNode> getChildren() {
+		return children;
+	}
+
+	@Override
+	public boolean equals(Object obj) {
+		if (this == obj) {
+			return true;
+		}
+		if (!(obj instanceof OutlineNode)) {
+			return false;
+		}
+		OutlineNode other = (OutlineNode) obj;
+		return level == other.level && Objects.equals(title, other.title)
+				&& Objects.equals(pageNumber, other.pageNumber) && Objects.equals(children, other.children);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(title, level, pageNumber, children);
+	}
+
+	@Override
+	public String toString() {
+		return "OutlineNode{title='" + title + "', level=" + level + ", pageNumber=" + pageNumber + ", children="
+				+ children.size() + "}";
+	}
+}
@@ -15,7 +15,10 @@ public enum PageUnitType {
 	SLIDE("slide"),
 
 	/** Spreadsheet sheets (XLSX, ODS). */
-	SHEET("sheet");
+	SHEET("sheet"),
+
+	/** Book chapters (EPUB). */
+	CHAPTER("chapter");
 
 	private final String wireValue;
 
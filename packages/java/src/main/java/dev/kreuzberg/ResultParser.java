@@ -39,6 +39,10 @@ final class ResultParser {
 	};
 	private static final TypeReference<DocumentStructure> DOCUMENT_STRUCTURE = new TypeReference<>() {
 	};
+	private static final TypeReference<List<OutlineNode>> OUTLINE_LIST = new TypeReference<>() {
+	};
+	private static final TypeReference<List<TextBlock>> TEXT_BLOCK_LIST = new TypeReference<>() {
+	};
 
 	private ResultParser() {
 	}
@@ -63,6 +67,25 @@ final class ResultParser {
 			String metadataJson, String chunksJson, String imagesJson, String pagesJson, String pageStructureJson,
 			String elementsJson, String ocrElementsJson, String djotContentJson, String language, String date,
 			String subject, String documentStructureJson) throws KreuzbergException {
+		return parse(content, mimeType, tablesJson, detectedLanguagesJson, metadataJson, chunksJson, imagesJson,
+				pagesJson, pageStructureJson, elementsJson, ocrElementsJson, djotContentJson, language, date, subject,
+				documentStructureJson, null);
+	}
+
+	static ExtractionResult parse(String content, String mimeType, String tablesJson, String detectedLanguagesJson,
+			String metadataJson, String chunksJson, String imagesJson, String pagesJson, String pageStructureJson,
+			String elementsJson, String ocrElementsJson, String djotContentJson, String language, String date,
+			String subject, String documentStructureJson, String outlineJson) throws KreuzbergException {
+		return parse(content, mimeType, tablesJson, detectedLanguagesJson, metadataJson, chunksJson, imagesJson,
+				pagesJson, pageStructureJson, elementsJson, ocrElementsJson, djotContentJson, language, date, subject,
+				documentStructureJson, outlineJson, null);
+	}
+
+	static ExtractionResult parse(String content, String mimeType, String tablesJson, String detectedLanguagesJson,
+			String metadataJson, String chunksJson, String imagesJson, String pagesJson, String pageStructureJson,
+			String elementsJson, String ocrElementsJson, String djotContentJson, String language, String date,
+			String subject, String documentStructureJson, String outlineJson, String textBlocksJson)
+			throws KreuzbergException {
 		try {
 			Map<String, Object> metadata = decode(metadataJson, METADATA_MAP, Collections.emptyMap());
 			List<Table> tables = decode(tablesJson, TABLE_LIST, List.of());
@@ -75,13 +98,15 @@ final class ResultParser {
 			List<OcrElement> ocrElements = decode(ocrElementsJson, OCR_ELEMENT_LIST, List.of());
 			DjotContent djotContent = decode(djotContentJson, DJOT_CONTENT, null);
 			DocumentStructure documentStructure = decode(documentStructureJson, DOCUMENT_STRUCTURE, null);
+			List<OutlineNode> outline = decode(outlineJson, OUTLINE_LIST, null);
+			List<TextBlock> textBlocks = decode(textBlocksJson, TEXT_BLOCK_LIST, null);
 
 			// Build Metadata with FFI-provided language, date, and subject if available
 			Metadata metadataObj = buildMetadata(metadata, language, date, subject);
 
 			return new ExtractionResult(content != null ? content : "", mimeType != null ? mimeType : "", metadataObj,
 					tables, detectedLanguages, chunks, images, pages, pageStructure, elements, ocrElements, djotContent,
-					documentStructure);
+					documentStructure, outline, textBlocks);
 		} catch (Exception e) {
 			throw new KreuzbergException("Failed to parse extraction result", e);
 		}
@@ -190,7 +215,8 @@ final class ResultParser {
 					wire.chunks != null ? wire.chunks : List.of(), wire.images != null ? wire.images : List.of(),
 					wire.pages != null ? wire.pages : List.of(), wire.pageStructure,
 					wire.elements != null ? wire.elements : List.of(),
-					wire.ocrElements != null ? wire.ocrElements : List.of(), wire.djotContent, wire.document);
+					wire.ocrElements != null ? wire.ocrElements : List.of(), wire.djotContent, wire.document,
+					wire.outline, wire.textBlocks);
 		} catch (Exception e) {
 			throw new KreuzbergException("Failed to parse result JSON", e);
 		}
@@ -201,7 +227,8 @@ final class ResultParser {
 				result.getMetadata(), result.getTables(), result.getDetectedLanguages(), result.getChunks(),
 				result.getImages(), result.getPages(), result.getPageStructure().orElse(null), result.getElements(),
 				result.getOcrElements(), result.getDjotContent().orElse(null),
-				result.getDocumentStructure().orElse(null));
+				result.getDocumentStructure().orElse(null), result.getOutline().orElse(null),
+				result.getTextBlocks().orElse(null));
 		return MAPPER.writeValueAsString(wire);
 	}
 
@@ -227,6 +254,8 @@ final class ResultParser {
 		private final List<OcrElement> ocrElements;
 		private final DjotContent djotContent;
 		private final DocumentStructure document;
+		private final List<OutlineNode> outline;
+		private final List<TextBlock> textBlocks;
 
 		WireExtractionResult(@JsonProperty("content") String content, @JsonProperty("mime_type") String mimeType,
 				@JsonProperty("metadata") Metadata metadata, @JsonProperty("tables") List<Table> tables,
@@ -237,7 +266,9 @@ final class ResultParser {
 				@JsonProperty("elements") List<Element> elements,
 				@JsonProperty("ocr_elements") List<OcrElement> ocrElements,
 				@JsonProperty("djot_content") DjotContent djotContent,
-				@JsonProperty("document") DocumentStructure document) {
+				@JsonProperty("document") DocumentStructure document,
+				@JsonProperty("outline") List<OutlineNode> outline,
+				@JsonProperty("text_blocks") List<TextBlock> textBlocks) {
 			this.content = content;
 			this.mimeType = mimeType;
 			this.metadata = metadata;
@@ -251,6 +282,8 @@ final class ResultParser {
 			this.ocrElements = ocrElements;
 			this.djotContent = djotContent;
 			this.document = document;
+			this.outline = outline;
+			this.textBlocks = textBlocks;
 		}
 	}
 }
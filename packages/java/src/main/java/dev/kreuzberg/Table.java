@@ -20,9 +20,14 @@ import java.util.Objects;
  *            the Markdown representation of the table
  * @param pageNumber
  *            the page number where the table was found (1-indexed)
+ * @param name
+ *            the table name, e.g. the sheet name for spreadsheets (may be null)
+ * @param hasHeader
+ *            whether the first row of cells is a header row
  */
 public record Table(@JsonProperty("cells") List<List<String>> cells, @JsonProperty("markdown") String markdown,
-		@JsonProperty("page_number") int pageNumber) {
+		@JsonProperty("page_number") int pageNumber, @JsonProperty("name") String name,
+		@JsonProperty("has_header") boolean hasHeader) {
 	/**
 	 * Creates a new Table.
 	 *
@@ -33,6 +38,10 @@ public record Table(@JsonProperty("cells") List<List<String>> cells, @JsonProper
 	 * @param pageNumber
 	 *            the page number (0 for non-paginated documents, >= 1 for paginated
 	 *            documents)
+	 * @param name
+	 *            the table name (may be null)
+	 * @param hasHeader
+	 *            whether the first row of cells is a header row
 	 * @throws NullPointerException
 	 *             if cells or markdown is null
 	 * @throws IllegalArgumentException
@@ -40,7 +49,8 @@ public record Table(@JsonProperty("cells") List<List<String>> cells, @JsonProper
 	 */
 	@JsonCreator
 	public Table(@JsonProperty("cells") List<List<String>> cells, @JsonProperty("markdown") String markdown,
-			@JsonProperty("page_number") int pageNumber) {
+			@JsonProperty("page_number") int pageNumber, @JsonProperty("name") String name,
+			@JsonProperty("has_header") boolean hasHeader) {
 		Objects.requireNonNull(cells, "cells must not be null");
 		Objects.requireNonNull(markdown, "markdown must not be null");
 		if (pageNumber < 0) {
@@ -49,6 +59,38 @@ public record Table(@JsonProperty("cells") List<List<String>> cells, @JsonProper
 		this.cells = deepCopyTable(cells);
 		this.markdown = markdown;
 		this.pageNumber = pageNumber;
+		this.name = name;
+		this.hasHeader = hasHeader;
+	}
+
+	/**
+	 * Creates a new Table whose first row is not marked as a header.
+	 *
+	 * @param cells
+	 *            the table cells (must not be null)
+	 * @param markdown
+	 *            the Markdown representation (must not be null)
+	 * @param pageNumber
+	 *            the page number
+	 * @param name
+	 *            the table name (may be null)
+	 */
+	public Table(List<List<String>> cells, String markdown, int pageNumber, String name) {
+		this(cells, markdown, pageNumber, name, false);
+	}
+
+	/**
+	 * Creates a new unnamed Table.
+	 *
+	 * @param cells
+	 *            the table cells (must not be null)
+	 * @param markdown
+	 *            the Markdown representation (must not be null)
+	 * @param pageNumber
+	 *            the page number
+	 */
+	public Table(List<List<String>> cells, String markdown, int pageNumber) {
+		this(cells, markdown, pageNumber, null);
 	}
 
 	/**
@@ -0,0 +1,77 @@
+package dev.kreuzberg;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+
+/**
+ * A run of text with the page it appears on and its bounding box.
+ *
+ * <p>
+ * Available when extraction is configured with {@code emit_positions=true}.
+ * Blocks from the PDF text layer use PDF points with the origin at the
+ * bottom-left of the page; blocks derived from OCR use image pixels with the
+ * origin at the top-left.
+ */
+@JsonIgnoreProperties(ignoreUnknown = true)
+public final class TextBlock {
+	private final String text;
+	private final int page;
+	private final BoundingBox bbox;
+
+	/**
+	 * Create a new TextBlock.
+	 *
+	 * @param text
+	 *            the text content of the block
+	 * @param page
+	 *            the 1-indexed page the block appears on
+	 * @param bbox
+	 *            the bounding box of the block on its page
+	 */
+	@JsonCreator
+	public TextBlock(@JsonProperty("text") String text, @JsonProperty("page") int page,
+			@JsonProperty("bbox") BoundingBox bbox) {
+		this.text = text != null ? text : "";
+		this.page = page;
+		this.bbox = Objects.requireNonNull(bbox, "bbox must not be null");
+	}
+
+	@JsonProperty("text")
+	public String getText() {
+		return text;
+	}
+
+	@JsonProperty("page")
+	public int getPage() {
+		return page;
+	}
+
+	@JsonProperty("bbox")
+	public BoundingBox getBbox() {
+		return bbox;
+	}
+
+	@Override
+	public boolean equals(Object obj) {
+		if (this == obj) {
+			return true;
+		}
+		if (!(obj instanceof TextBlock)) {
+			return false;
+		}
+		TextBlock other = (TextBlock) obj;
+		return page == other.page && Objects.equals(text, other.text) && Objects.equals(bbox, other.bbox);
+	}
+
+	@Override
+	public int hashCode() {
+		return Objects.hash(text, page, bbox);
+	}
+
+	@Override
+	public String toString() {
+		return "TextBlock{text='" + text + "', page=" + page + ", bbox=" + bbox + "}";
+	}
+}
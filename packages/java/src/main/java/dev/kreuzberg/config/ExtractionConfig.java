@@ -31,6 +31,8 @@ public final class ExtractionConfig {
 	private final boolean enableQualityProcessingSet;
 	private final boolean forceOcrSet;
 	private final String outputFormat;
+	private final String outputMode;
+	private final String normalizeNewlines;
 	private final String resultFormat;
 	private final OcrConfig ocr;
 	private final ChunkingConfig chunking;
@@ -46,6 +48,12 @@ public final class ExtractionConfig {
 	private final Map<String, Object> securityLimits;
 	private final boolean includeDocumentStructure;
 	private final boolean includeDocumentStructureSet;
+	private final boolean extractOutline;
+	private final boolean extractOutlineSet;
+	private final boolean emitPositions;
+	private final boolean emitPositionsSet;
+	private final boolean lowMemory;
+	private final boolean lowMemorySet;
 
 	private ExtractionConfig(Builder builder) {
 		this.useCache = builder.useCache;
@@ -55,6 +63,8 @@ public final class ExtractionConfig {
 		this.enableQualityProcessingSet = builder.enableQualityProcessingSet;
 		this.forceOcrSet = builder.forceOcrSet;
 		this.outputFormat = builder.outputFormat;
+		this.outputMode = builder.outputMode;
+		this.normalizeNewlines = builder.normalizeNewlines;
 		this.resultFormat = builder.resultFormat;
 		this.ocr = builder.ocr;
 		this.chunking = builder.chunking;
@@ -70,6 +80,12 @@ public final class ExtractionConfig {
 		this.securityLimits = builder.securityLimits;
 		this.includeDocumentStructure = builder.includeDocumentStructure;
 		this.includeDocumentStructureSet = builder.includeDocumentStructureSet;
+		this.extractOutline = builder.extractOutline;
+		this.extractOutlineSet = builder.extractOutlineSet;
+		this.emitPositions = builder.emitPositions;
+		this.emitPositionsSet = builder.emitPositionsSet;
+		this.lowMemory = builder.lowMemory;
+		this.lowMemorySet = builder.lowMemorySet;
 	}
 
 	public static Builder builder() {
@@ -98,6 +114,24 @@ public final class ExtractionConfig {
 		return outputFormat;
 	}
 
+	/**
+	 * Get the Markdown decoration mode of HTML and DOCX content.
+	 *
+	 * @return output mode (markdown, plain_text), or null if not set
+	 */
+	public String getOutputMode() {
+		return outputMode;
+	}
+
+	/**
+	 * Get the line ending applied to the extracted text.
+	 *
+	 * @return line ending (lf, crlf, keep), or null if line endings are left unchanged
+	 */
+	public String getNormalizeNewlines() {
+		return normalizeNewlines;
+	}
+
 	/**
 	 * Get the result structure format.
 	 *
@@ -172,6 +206,33 @@ public final class ExtractionConfig {
 		return includeDocumentStructure;
 	}
 
+	/**
+	 * Check if outline extraction is enabled.
+	 *
+	 * @return true if the result outline is populated
+	 */
+	public boolean isExtractOutline() {
+		return extractOutline;
+	}
+
+	/**
+	 * Check if positioned text blocks are emitted.
+	 *
+	 * @return true if the result text blocks are populated
+	 */
+	public boolean isEmitPositions() {
+		return emitPositions;
+	}
+
+	/**
+	 * Check if low-memory post-processing is enabled.
+	 *
+	 * @return true if post-processors update the result in place
+	 */
+	public boolean isLowMemory() {
+		return lowMemory;
+	}
+
 	/**
 	 * Parse configuration from JSON produced by the Rust core.
 	 *
@@ -422,9 +483,24 @@ public final class ExtractionConfig {
 		if (includeDefaults || includeDocumentStructureSet) {
 			map.put("include_document_structure", includeDocumentStructure);
 		}
+		if (includeDefaults || extractOutlineSet) {
+			map.put("extract_outline", extractOutline);
+		}
+		if (includeDefaults || emitPositionsSet) {
+			map.put("emit_positions", emitPositions);
+		}
+		if (includeDefaults || lowMemorySet) {
+			map.put("low_memory", lowMemory);
+		}
 		if (outputFormat != null) {
 			map.put("output_format", outputFormat);
 		}
+		if (outputMode != null) {
+			map.put("output_mode", outputMode);
+		}
+		if (normalizeNewlines != null) {
+			map.put("normalize_newlines", normalizeNewlines);
+		}
 		if (resultFormat != null) {
 			map.put("result_format", resultFormat);
 		}
@@ -485,9 +561,24 @@ public final class ExtractionConfig {
 		if (raw.containsKey("include_document_structure")) {
 			builder.includeDocumentStructure(asBoolean(raw.get("include_document_structure"), false));
 		}
+		if (raw.containsKey("extract_outline")) {
+			builder.extractOutline(asBoolean(raw.get("extract_outline"), false));
+		}
+		if (raw.containsKey("emit_positions")) {
+			builder.emitPositions(asBoolean(raw.get("emit_positions"), false));
+		}
+		if (raw.containsKey("low_memory")) {
+			builder.lowMemory(asBoolean(raw.get("low_memory"), false));
+		}
 		if (raw.containsKey("output_format")) {
 			builder.outputFormat(asString(raw.get("output_format")));
 		}
+		if (raw.containsKey("output_mode")) {
+			builder.outputMode(asString(raw.get("output_mode")));
+		}
+		if (raw.containsKey("normalize_newlines")) {
+			builder.normalizeNewlines(asString(raw.get("normalize_newlines")));
+		}
 		if (raw.containsKey("result_format")) {
 			builder.resultFormat(asString(raw.get("result_format")));
 		}
@@ -587,11 +678,19 @@ public final class ExtractionConfig {
 		private boolean enableQualityProcessing = true;
 		private boolean forceOcr = false;
 		private boolean includeDocumentStructure = false;
+		private boolean extractOutline = false;
+		private boolean emitPositions = false;
+		private boolean lowMemory = false;
 		private boolean useCacheSet = false;
 		private boolean enableQualityProcessingSet = false;
 		private boolean forceOcrSet = false;
 		private boolean includeDocumentStructureSet = false;
+		private boolean extractOutlineSet = false;
+		private boolean emitPositionsSet = false;
+		private boolean lowMemorySet = false;
 		private String outputFormat;
+		private String outputMode;
+		private String normalizeNewlines;
 		private String resultFormat;
 		private OcrConfig ocr;
 		private ChunkingConfig chunking;
@@ -645,6 +744,59 @@ public final class ExtractionConfig {
 			return this;
 		}
 
+		/**
+		 * Enable or disable outline extraction.
+		 *
+		 * <p>
+		 * When enabled, the extraction result outline is populated from PDF bookmarks
+		 * or DOCX heading styles.
+		 *
+		 * @param extractOutline
+		 *            true to enable outline extraction
+		 * @return this builder for chaining
+		 */
+		public Builder extractOutline(boolean extractOutline) {
+			this.extractOutline = extractOutline;
+			this.extractOutlineSet = true;
+			return this;
+		}
+
+		/**
+		 * Enable or disable positioned text blocks.
+		 *
+		 * <p>
+		 * When enabled, the extraction result text blocks are populated with each
+		 * block's page and bounding box, from the PDF text layer or OCR elements.
+		 *
+		 * @param emitPositions
+		 *            true to emit text blocks
+		 * @return this builder for chaining
+		 */
+		public Builder emitPositions(boolean emitPositions) {
+			this.emitPositions = emitPositions;
+			this.emitPositionsSet = true;
+			return this;
+		}
+
+		/**
+		 * Enable or disable low-memory post-processing.
+		 *
+		 * <p>
+		 * When enabled, post-processor plugins update the extraction result in place
+		 * instead of a full copy, reducing peak memory for large documents. A
+		 * post-processor whose output cannot be applied may leave the result partially
+		 * updated.
+		 *
+		 * @param lowMemory
+		 *            true to avoid copying the result for post-processors
+		 * @return this builder for chaining
+		 */
+		public Builder lowMemory(boolean lowMemory) {
+			this.lowMemory = lowMemory;
+			this.lowMemorySet = true;
+			return this;
+		}
+
 		/**
 		 * Set the content output format.
 		 *
@@ -661,6 +813,37 @@ public final class ExtractionConfig {
 			return this;
 		}
 
+		/**
+		 * Set whether HTML and DOCX content keeps its Markdown decoration.
+		 *
+		 * <p>
+		 * Valid modes: markdown, plain_text
+		 *
+		 * @param mode
+		 *            the output mode
+		 * @return this builder for chaining
+		 */
+		public Builder outputMode(String mode) {
+			this.outputMode = mode;
+			return this;
+		}
+
+		/**
+		 * Set the line ending of the extracted text, applied as the final processing
+		 * step.
+		 *
+		 * <p>
+		 * Valid values: lf, crlf, keep. Unset leaves line endings unchanged.
+		 *
+		 * @param ending
+		 *            the line ending
+		 * @return this builder for chaining
+		 */
+		public Builder normalizeNewlines(String ending) {
+			this.normalizeNewlines = ending;
+			return this;
+		}
+
 		/**
 		 * Set the result structure format.
 		 *
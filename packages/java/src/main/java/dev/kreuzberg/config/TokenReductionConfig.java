@@ -13,10 +13,12 @@ import java.util.Map;
 public final class TokenReductionConfig {
 	private final String mode;
 	private final boolean preserveImportantWords;
+	private final String language;
 
 	private TokenReductionConfig(Builder builder) {
 		this.mode = builder.mode;
 		this.preserveImportantWords = builder.preserveImportantWords;
+		this.language = builder.language;
 	}
 
 	public static Builder builder() {
@@ -31,16 +33,30 @@ public final class TokenReductionConfig {
 		return preserveImportantWords;
 	}
 
+	/**
+	 * Get the stopword language.
+	 *
+	 * @return ISO 639-1 or 639-3 language code, or null to use the detected
+	 *         language
+	 */
+	public String getLanguage() {
+		return language;
+	}
+
 	public Map<String, Object> toMap() {
 		Map<String, Object> map = new HashMap<>();
 		map.put("mode", mode);
 		map.put("preserve_important_words", preserveImportantWords);
+		if (language != null) {
+			map.put("language", language);
+		}
 		return map;
 	}
 
 	public static final class Builder {
 		private String mode = "off";
 		private boolean preserveImportantWords = true;
+		private String language;
 
 		private Builder() {
 		}
@@ -60,6 +76,19 @@ public final class TokenReductionConfig {
 			return this;
 		}
 
+		/**
+		 * Set the stopword language, e.g. "de" or "deu". When unset, the detected
+		 * language is used, falling back to English.
+		 *
+		 * @param language
+		 *            ISO 639-1 or 639-3 language code
+		 * @return this builder for chaining
+		 */
+		public Builder language(String language) {
+			this.language = language;
+			return this;
+		}
+
 		public TokenReductionConfig build() {
 			return new TokenReductionConfig(this);
 		}
@@ -76,6 +105,9 @@ public final class TokenReductionConfig {
 		if (map.get("preserve_important_words") instanceof Boolean) {
 			builder.preserveImportantWords((Boolean) map.get("preserve_important_words"));
 		}
+		if (map.get("language") instanceof String) {
+			builder.language((String) map.get("language"));
+		}
 		return builder.build();
 	}
 }
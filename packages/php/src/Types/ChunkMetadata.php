@@ -14,6 +14,7 @@ namespace Kreuzberg\Types;
  * @property-read int $totalChunks Total number of chunks
  * @property-read int|null $firstPage First page number in chunk
  * @property-read int|null $lastPage Last page number in chunk
+ * @property-read array<string>|null $headingPath Titles of the enclosing Markdown headings, outermost first
  */
 readonly class ChunkMetadata
 {
@@ -25,6 +26,7 @@ readonly class ChunkMetadata
         public int $totalChunks,
         public ?int $firstPage = null,
         public ?int $lastPage = null,
+        public ?array $headingPath = null,
     ) {
     }
 
@@ -54,6 +56,9 @@ readonly class ChunkMetadata
         /** @var int|null $lastPage */
         $lastPage = $data['last_page'] ?? null;
 
+        /** @var array<string>|null $headingPath */
+        $headingPath = $data['heading_path'] ?? null;
+
         return new self(
             byteStart: $byteStart,
             byteEnd: $byteEnd,
@@ -62,6 +67,7 @@ readonly class ChunkMetadata
             totalChunks: $totalChunks,
             firstPage: $firstPage,
             lastPage: $lastPage,
+            headingPath: $headingPath,
         );
     }
 }
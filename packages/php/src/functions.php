@@ -24,10 +24,14 @@ function convertToKreuzbergException(\Exception $e): KreuzbergException
         return KreuzbergException::validation($message);
     }
 
+    // Check for unsupported format errors
+    if (str_contains($message, '[UnsupportedFormat]')) {
+        return KreuzbergException::unsupportedFormat($message);
+    }
+
     // Check for parsing errors
     if (str_contains($message, 'Failed to parse') ||
-        str_contains($message, 'parsing error') ||
-        str_contains($message, 'Could not determine MIME type')) {
+        str_contains($message, 'parsing error')) {
         return KreuzbergException::parsing($message);
     }
 
@@ -44,6 +44,7 @@ from kreuzberg._deprecation import deprecated
 from kreuzberg._internal_bindings import (
     Chunk,
     ChunkingConfig,
+    DocxConfig,
     EmbeddingConfig,
     EmbeddingModelType,
     EmbeddingPreset,
@@ -53,6 +54,7 @@ from kreuzberg._internal_bindings import (
     HierarchyConfig,
     ImageExtractionConfig,
     ImagePreprocessingConfig,
+    JupyterConfig,
     KeywordAlgorithm,
     KeywordConfig,
     LanguageDetectionConfig,
@@ -67,6 +69,7 @@ from kreuzberg._internal_bindings import (
     _discover_extraction_config_impl,
     _load_extraction_config_from_file_impl,
     clear_document_extractors,
+    clear_mime_detectors,
     clear_ocr_backends,
     clear_post_processors,
     clear_validators,
@@ -83,10 +86,12 @@ from kreuzberg._internal_bindings import (
     get_valid_token_reduction_levels,
     list_document_extractors,
     list_embedding_presets,
+    list_mime_detectors,
     list_ocr_backends,
     list_post_processors,
     list_validators,
     unregister_document_extractor,
+    unregister_mime_detector,
     unregister_ocr_backend,
     unregister_post_processor,
     unregister_validator,
@@ -141,6 +146,9 @@ from kreuzberg._internal_bindings import (
 from kreuzberg._internal_bindings import (
     get_last_error_code as _get_last_error_code_impl,
 )
+from kreuzberg._internal_bindings import (
+    register_mime_detector as _register_mime_detector_impl,
+)
 from kreuzberg._internal_bindings import (
     register_ocr_backend as _register_ocr_backend_impl,
 )
@@ -214,6 +222,7 @@ __all__ = [
     "CacheError",
     "Chunk",
     "ChunkingConfig",
+    "DocxConfig",
     "EmbeddingConfig",
     "EmbeddingModelType",
     "EmbeddingPreset",
@@ -226,6 +235,7 @@ __all__ = [
     "ImageExtractionConfig",
     "ImagePreprocessingConfig",
     "ImageProcessingError",
+    "JupyterConfig",
     "KeywordAlgorithm",
     "KeywordConfig",
     "KreuzbergError",
@@ -255,6 +265,7 @@ __all__ = [
     "batch_extract_files_sync",
     "classify_error",
     "clear_document_extractors",
+    "clear_mime_detectors",
     "clear_ocr_backends",
     "clear_post_processors",
     "clear_validators",
@@ -281,14 +292,17 @@ __all__ = [
     "get_valid_token_reduction_levels",
     "list_document_extractors",
     "list_embedding_presets",
+    "list_mime_detectors",
     "list_ocr_backends",
     "list_post_processors",
     "list_validators",
     "load_extraction_config_from_file",
+    "register_mime_detector",
     "register_ocr_backend",
     "register_post_processor",
     "register_validator",
     "unregister_document_extractor",
+    "unregister_mime_detector",
     "unregister_ocr_backend",
     "unregister_post_processor",
     "unregister_validator",
@@ -829,6 +843,43 @@ def register_validator(validator: Any) -> None:
     return _register_validator_impl(validator)
 
 
+def register_mime_detector(detector: Any) -> None:
+    """Register a Python MIME detector with the Rust core.
+
+    Registered detectors receive the start of each document and are asked for its MIME
+    type before the built-in detection, so proprietary formats are recognized by their
+    content. Pair a detector with a document extractor for the returned type to extract it.
+
+    Args:
+        detector: Python object implementing the MimeDetector protocol
+
+    Required methods on the detector object:
+        - name() -> str: Return detector name (must be non-empty)
+        - detect(header: bytes) -> str | None: Return the MIME type, or None if not recognized
+
+    Optional methods:
+        - initialize(): Called when detector is registered
+        - shutdown(): Called when detector is unregistered
+        - version() -> str: Detector version (defaults to "1.0.0")
+
+    Raises:
+        AttributeError: If detector is missing required methods (name, detect)
+        ValueError: If detector name is empty
+        RuntimeError: If registration with the Rust registry fails
+
+    Example:
+        >>> from kreuzberg import register_mime_detector
+        >>> class LedgerDetector:
+        ...     def name(self) -> str:
+        ...         return "ledger-detector"
+        ...
+        ...     def detect(self, header: bytes) -> str | None:
+        ...         return "application/x-ledger" if header.startswith(b"LDGR\x01") else None
+        >>> register_mime_detector(LedgerDetector())
+    """
+    return _register_mime_detector_impl(detector)
+
+
 def get_last_error_code() -> int | None:
     """Get the last error code from the FFI layer.
 
@@ -27,6 +27,7 @@ __all__ = [
     "DjotTable",
     "DocumentNode",
     "DocumentStructure",
+    "DocxConfig",
     "Element",
     "ElementMetadata",
     "ElementType",
@@ -52,11 +53,13 @@ __all__ = [
     "ImagePreprocessingConfig",
     "ImagePreprocessingMetadata",
     "InlineElement",
+    "JupyterConfig",
     "KeywordAlgorithm",
     "KeywordConfig",
     "LanguageDetectionConfig",
     "LinkMetadata",
     "Metadata",
+    "MimeDetectorProtocol",
     "MissingDependencyError",
     "NodeContent",
     "NodeContentType",
@@ -102,6 +105,7 @@ __all__ = [
     "batch_extract_files_sync",
     "classify_error",
     "clear_document_extractors",
+    "clear_mime_detectors",
     "clear_ocr_backends",
     "clear_post_processors",
     "clear_validators",
@@ -127,13 +131,16 @@ __all__ = [
     "init_async_runtime",
     "list_document_extractors",
     "list_embedding_presets",
+    "list_mime_detectors",
     "list_ocr_backends",
     "list_post_processors",
     "list_validators",
+    "register_mime_detector",
     "register_ocr_backend",
     "register_post_processor",
     "register_validator",
     "unregister_document_extractor",
+    "unregister_mime_detector",
     "unregister_ocr_backend",
     "unregister_post_processor",
     "unregister_validator",
@@ -309,6 +316,10 @@ class ValidatorProtocol(Protocol):
     def priority(self) -> int: ...
     def should_validate(self, result: ExtractionResult) -> bool: ...
 
+class MimeDetectorProtocol(Protocol):
+    def name(self) -> str: ...
+    def detect(self, header: bytes) -> str | None: ...
+
 class ExtractionConfig:
     """Main extraction configuration for document processing.
 
@@ -357,7 +368,7 @@ class ExtractionConfig:
             for custom text processing. None = use defaults. Default: None
 
         max_concurrent_extractions (int | None): Maximum concurrent extractions
-            in batch operations. None = num_cpus * 2. Default: None
+            in batch operations. None = number of CPU cores. Default: None
 
         html_options (HtmlConversionOptions | None): HTML conversion options for
             converting documents to markdown. Default: None
@@ -371,6 +382,58 @@ class ExtractionConfig:
             Controls the format of the extracted content.
             Values: "plain" (default), "markdown", "djot", "html". Default: "plain"
 
+        output_mode (str): Markdown decoration of HTML and DOCX content. "plain_text"
+            strips heading markers, emphasis, link syntax, list markers and table
+            pipes for clean prose. Values: "markdown" (default), "plain_text".
+            Default: "markdown"
+
+        max_content_chars (int | None): Maximum number of characters kept in the
+            extracted content. Longer content is truncated, preferring a paragraph
+            break, and metadata['truncated'] is set. None = unlimited. Default: None
+
+        preserve_list_markers (bool): Keep "- " / "1. " list item markers in Markdown
+            and DOCX content, indenting nested items two spaces per level. When False,
+            list items are emitted as bare lines. Default: True
+
+        extract_outline (bool): Populate ExtractionResult.outline with the document
+            outline, from PDF bookmarks or DOCX heading styles. Default: False
+
+        extract_references (bool): Populate ExtractionResult.references by parsing
+            the references section of the content into authors, title, year and DOI.
+            Requires the references feature. Default: False
+
+        normalize_bidi (bool): Reorder lines containing right-to-left text (Arabic,
+            Hebrew) from visual into logical order with the Unicode Bidirectional
+            Algorithm. Only enable for sources that store visual order, such as many
+            PDFs. Default: False
+
+        html_include_alt_text (bool): Keep accessibility text in HTML content. Images
+            with alt text are followed by "[alt: ...]" and links, buttons and form
+            controls get "[aria-label: ...]". Default: True
+
+        emit_positions (bool): Populate ExtractionResult.text_blocks with text blocks,
+            their page and bounding box, from the PDF text layer or OCR elements.
+            Default: False
+
+        emit_source_offsets (bool): Populate ExtractionResult.source_map with
+            content ranges mapped to source positions: page character indices for
+            PDFs, byte offsets for HTML. Default: False
+
+        low_memory (bool): Let post-processors update the result in place instead
+            of a full copy, reducing peak memory for large documents. A post-processor
+            whose output cannot be applied may leave the result partially updated.
+            Default: False
+
+        normalize_newlines (str | None): Line ending of the extracted text, applied
+            as the final processing step to content, pages and chunks. Values: "lf",
+            "crlf", "keep". Default: None (line endings are left unchanged)
+
+        docx_options (DocxConfig | None): DOCX-specific options, such as estimating
+            page boundaries. None = use defaults. Default: None
+
+        jupyter_options (JupyterConfig | None): Jupyter notebook options, such as which
+            cell outputs are included. None = use defaults. Default: None
+
     Example:
         Basic extraction with defaults:
             >>> from kreuzberg import ExtractionConfig, extract_file_sync
@@ -403,7 +466,20 @@ class ExtractionConfig:
     security_limits: dict[str, int] | None
     result_format: str
     output_format: str
+    output_mode: str
     include_document_structure: bool
+    max_content_chars: int | None
+    preserve_list_markers: bool
+    extract_outline: bool
+    extract_references: bool
+    normalize_bidi: bool
+    html_include_alt_text: bool
+    emit_positions: bool
+    emit_source_offsets: bool
+    low_memory: bool
+    normalize_newlines: str | None
+    docx_options: DocxConfig | None
+    jupyter_options: JupyterConfig | None
 
     def __init__(
         self,
@@ -425,7 +501,20 @@ class ExtractionConfig:
         security_limits: dict[str, int] | None = None,
         result_format: str | None = None,
         output_format: str | None = None,
+        output_mode: str | None = None,
         include_document_structure: bool | None = None,
+        max_content_chars: int | None = None,
+        preserve_list_markers: bool | None = None,
+        extract_outline: bool | None = None,
+        extract_references: bool | None = None,
+        normalize_bidi: bool | None = None,
+        html_include_alt_text: bool | None = None,
+        emit_positions: bool | None = None,
+        emit_source_offsets: bool | None = None,
+        low_memory: bool | None = None,
+        normalize_newlines: str | None = None,
+        docx_options: DocxConfig | None = None,
+        jupyter_options: JupyterConfig | None = None,
     ) -> None: ...
     @staticmethod
     def from_file(path: str | Path) -> ExtractionConfig: ...
@@ -455,6 +544,36 @@ class OcrConfig:
             extracting individual OCR elements (words, lines, etc.).
             Default: None
 
+        auto_language (bool): Pick the OCR language from the script detected on
+            the first page (Tesseract only, requires osd.traineddata). Falls back
+            to language when detection is uncertain. Default: False
+
+        fallback_backends (list[str]): Backends tried in order when backend is
+            not installed or fails, e.g. ["paddle-ocr"]. The backend that produced
+            the text is reported in metadata["ocr_backend"]. Default: []
+
+        user_words (list[str] | None): Domain-specific words to bias recognition
+            towards, e.g. drug names. Loaded as a Tesseract user-words file; PaddleOCR
+            output is corrected towards them. Default: None
+
+        render_dpi (int | None): DPI at which PDF pages are rendered before OCR.
+            Raise it for small, dense text; lower it for speed. The value used is
+            reported in metadata["ocr_render_dpi"]. Default: None (300)
+
+        empty_page_confidence (float | None): Report pages where OCR found no text,
+            i.e. blank text or a normalized confidence (0.0-1.0) below this floor.
+            They are listed, 1-indexed, in metadata["ocr_empty_pages"].
+            Default: None (disabled)
+
+        page_filter (list[int] | None): Only OCR these 1-indexed PDF pages and keep
+            the native text of the rest, instead of deciding automatically whether to
+            OCR. Pages outside the document raise a ValidationError. Default: None
+
+        region (tuple[float, float, float, float] | None): Only OCR this region of
+            each page, as (left, top, width, height) normalized to the page size with
+            the origin at the top-left, e.g. (0.5, 0.75, 0.5, 0.25) for a signature
+            box. The region must lie within the page. Default: None (whole page)
+
     Example:
         Using Tesseract with German language:
             >>> from kreuzberg import OcrConfig
@@ -472,6 +591,13 @@ class OcrConfig:
     tesseract_config: TesseractConfig | None
     paddle_ocr_config: PaddleOcrConfig | None
     element_config: OcrElementConfig | None
+    auto_language: bool
+    fallback_backends: list[str]
+    user_words: list[str] | None
+    render_dpi: int | None
+    empty_page_confidence: float | None
+    page_filter: list[int] | None
+    region: tuple[float, float, float, float] | None
 
     def __init__(
         self,
@@ -481,6 +607,13 @@ class OcrConfig:
         tesseract_config: TesseractConfig | None = None,
         paddle_ocr_config: PaddleOcrConfig | None = None,
         element_config: OcrElementConfig | None = None,
+        auto_language: bool = False,
+        fallback_backends: list[str] | None = None,
+        user_words: list[str] | None = None,
+        render_dpi: int | None = None,
+        empty_page_confidence: float | None = None,
+        page_filter: list[int] | None = None,
+        region: tuple[float, float, float, float] | None = None,
     ) -> None: ...
 
 class EmbeddingModelType:
@@ -645,6 +778,14 @@ class ChunkingConfig:
             settings if provided). Use list_embedding_presets() to see available presets.
             Default: None
 
+        overlap_unit (str): Unit of max_overlap. "characters" overlaps by characters;
+            "sentences" carries that many trailing sentences into the next chunk.
+            Default: "characters"
+
+        max_chunks (int | None): Stop after this many chunks. Text past the last chunk
+            is not chunked and the result metadata gets chunks_truncated=True.
+            Default: None (no limit)
+
     Example:
         Basic chunking with defaults:
             >>> from kreuzberg import ExtractionConfig, ChunkingConfig
@@ -667,6 +808,8 @@ class ChunkingConfig:
     max_overlap: int
     embedding: EmbeddingConfig | None
     preset: str | None
+    overlap_unit: Literal["characters", "sentences"]
+    max_chunks: int | None
 
     def __init__(
         self,
@@ -675,6 +818,8 @@ class ChunkingConfig:
         max_overlap: int | None = None,
         embedding: EmbeddingConfig | None = None,
         preset: str | None = None,
+        overlap_unit: Literal["characters", "sentences"] | None = None,
+        max_chunks: int | None = None,
     ) -> None: ...
 
 class ImageExtractionConfig:
@@ -694,6 +839,10 @@ class ImageExtractionConfig:
         max_image_dimension (int): Maximum width or height for extracted images.
             Larger images are downscaled to fit. Default: 4096
 
+        max_decode_dimension (int): Largest width or height an image may declare
+            before it is decoded. Larger image files are rejected and larger embedded
+            images are left undecoded. 0 disables the limit. Default: 32768
+
         auto_adjust_dpi (bool): Automatically adjust DPI based on image content
             quality. May override target_dpi for better results. Default: True
 
@@ -703,6 +852,21 @@ class ImageExtractionConfig:
         max_dpi (int): Maximum DPI threshold. Images with higher DPI are downscaled.
             Default: 600
 
+        max_images (int | None): Maximum number of images to extract per document.
+            Extraction stops once the limit is reached. Default: None (unlimited)
+
+        max_total_image_bytes (int | None): Maximum total bytes of image data to
+            extract per document. Default: None (unlimited)
+
+        min_width (int): Minimum image width in pixels. Narrower images (icons,
+            tracking pixels) are skipped before OCR. Default: 16
+
+        min_height (int): Minimum image height in pixels. Default: 16
+
+        output_format (str): Encoding for extracted images: "original" keeps the bytes
+            as found, "png", "webp", "jpeg" or "jpeg:<quality>" re-encode every image
+            and update its format. Default: "original"
+
     Example:
         Basic image extraction:
             >>> from kreuzberg import ExtractionConfig, ImageExtractionConfig
@@ -719,9 +883,15 @@ class ImageExtractionConfig:
     extract_images: bool
     target_dpi: int
     max_image_dimension: int
+    max_decode_dimension: int
     auto_adjust_dpi: bool
     min_dpi: int
     max_dpi: int
+    max_images: int | None
+    max_total_image_bytes: int | None
+    min_width: int
+    min_height: int
+    output_format: str
 
     def __init__(
         self,
@@ -729,9 +899,15 @@ class ImageExtractionConfig:
         extract_images: bool | None = None,
         target_dpi: int | None = None,
         max_image_dimension: int | None = None,
+        max_decode_dimension: int | None = None,
         auto_adjust_dpi: bool | None = None,
         min_dpi: int | None = None,
         max_dpi: int | None = None,
+        max_images: int | None = None,
+        max_total_image_bytes: int | None = None,
+        min_width: int | None = None,
+        min_height: int | None = None,
+        output_format: str | None = None,
     ) -> None: ...
 
 class PdfConfig:
@@ -742,9 +918,18 @@ class PdfConfig:
             Default: False
 
         passwords (list[str] | None): List of passwords to try when opening
-            encrypted PDFs. Try each password in order until one succeeds.
+            encrypted PDFs. The empty user password is tried first, then each
+            password in order until one succeeds.
             Default: None
 
+        allow_owner_locked (bool): Extract text from PDFs whose owner permissions forbid
+            copying. A warning is logged when restrictions are bypassed; when False such
+            PDFs raise an error. Default: True
+
+        extract_math (bool): Recognize mathematical formulas as LaTeX into
+            ExtractionResult.formulas. Requires the math-ocr feature and downloads
+            its ONNX model on first use. Default: False
+
         extract_metadata (bool): Extract PDF metadata (title, author, creation date,
             etc.). Default: True
 
@@ -752,6 +937,18 @@ class PdfConfig:
             for detecting document structure and organization. None = no hierarchy detection.
             Default: None
 
+        reading_order (str): Order in which page text is read. "raw" keeps content stream
+            order; "column_aware" reads multi-column pages column by column.
+            Default: "raw"
+
+        page_separator (str | None): Text inserted between pages. "{page}" is replaced
+            with the number of the page that follows. Not used when page markers are
+            enabled. None = "\\n\\n". Default: None
+
+        insert_image_markers (bool): Insert "[image N]" markers into the content where
+            extracted images appear, with N = image_index + 1. Requires image extraction.
+            Default: False
+
     Example:
         Basic PDF configuration:
             >>> from kreuzberg import ExtractionConfig, PdfConfig
@@ -765,12 +962,23 @@ class PdfConfig:
 
         Enable hierarchy detection:
             >>> config = ExtractionConfig(pdf_options=PdfConfig(hierarchy=HierarchyConfig(k_clusters=6)))
+
+        Read two-column papers column by column:
+            >>> config = ExtractionConfig(pdf_options=PdfConfig(reading_order="column_aware"))
+
+        Mark where each page starts:
+            >>> config = ExtractionConfig(pdf_options=PdfConfig(page_separator="\\n\\n<!-- page {page} -->\\n\\n"))
     """
 
     extract_images: bool
     passwords: list[str] | None
     extract_metadata: bool
     hierarchy: HierarchyConfig | None
+    reading_order: Literal["raw", "column_aware"]
+    allow_owner_locked: bool
+    page_separator: str | None
+    extract_math: bool
+    insert_image_markers: bool
 
     def __init__(
         self,
@@ -779,6 +987,11 @@ class PdfConfig:
         passwords: list[str] | None = None,
         extract_metadata: bool | None = None,
         hierarchy: HierarchyConfig | None = None,
+        reading_order: Literal["raw", "column_aware"] | None = None,
+        allow_owner_locked: bool | None = None,
+        page_separator: str | None = None,
+        extract_math: bool | None = None,
+        insert_image_markers: bool | None = None,
     ) -> None: ...
 
 class HierarchyConfig:
@@ -852,6 +1065,42 @@ class PageConfig:
         marker_format: str | None = None,
     ) -> None: ...
 
+class DocxConfig:
+    """DOCX extraction configuration.
+
+    Attributes:
+        estimate_pages (bool): Estimate page boundaries from content length and
+            explicit page breaks. Populates metadata["pages"] and sets
+            metadata["page_boundaries_estimated"]. DOCX files do not store Word's
+            pagination, so treat the result as approximate. Default: False
+
+    Example:
+        >>> from kreuzberg import DocxConfig, ExtractionConfig
+        >>> config = ExtractionConfig(docx_options=DocxConfig(estimate_pages=True))
+    """
+
+    estimate_pages: bool
+
+    def __init__(self, *, estimate_pages: bool | None = None) -> None: ...
+
+class JupyterConfig:
+    """Jupyter notebook extraction configuration.
+
+    Attributes:
+        outputs (str): Which code cell outputs are included. "all" includes every
+            output, labelling each representation of rich outputs by MIME type;
+            "text" keeps stream output, errors and the text/plain representation;
+            "none" drops outputs. Default: "all"
+
+    Example:
+        >>> from kreuzberg import ExtractionConfig, JupyterConfig
+        >>> config = ExtractionConfig(jupyter_options=JupyterConfig(outputs="text"))
+    """
+
+    outputs: Literal["all", "text", "none"]
+
+    def __init__(self, *, outputs: Literal["all", "text", "none"] | None = None) -> None: ...
+
 class KeywordAlgorithm:
     Yake: KeywordAlgorithm
     Rake: KeywordAlgorithm
@@ -936,6 +1185,10 @@ class TokenReductionConfig:
         preserve_important_words (bool): Preserve capitalized words, technical terms,
             and proper nouns even in aggressive reduction modes. Default: True
 
+        language (str | None): Language of the stopword list, as an ISO 639-1 or
+            639-3 code (e.g. "de" or "deu"). When None, the first detected language
+            is used if language detection is enabled, otherwise English. Default: None
+
     Example:
         Moderate token reduction:
             >>> from kreuzberg import ExtractionConfig, TokenReductionConfig
@@ -950,12 +1203,14 @@ class TokenReductionConfig:
 
     mode: str
     preserve_important_words: bool
+    language: str | None
 
     def __init__(
         self,
         *,
         mode: Literal["off", "light", "moderate", "aggressive", "maximum"] | None = None,
         preserve_important_words: bool | None = None,
+        language: str | None = None,
     ) -> None: ...
 
 class LanguageDetectionConfig:
@@ -1281,6 +1536,7 @@ class PdfMetadata(TypedDict, total=False):
 class ExcelMetadata(TypedDict, total=False):
     sheet_count: int
     sheet_names: list[str]
+    hidden_sheets: list[str]
 
 class EmailMetadata(TypedDict, total=False):
     from_email: str
@@ -1407,7 +1663,7 @@ class PageInfo(TypedDict, total=False):
 
 class PageStructure(TypedDict, total=False):
     total_count: int
-    unit_type: Literal["page", "slide", "sheet"]
+    unit_type: Literal["page", "slide", "sheet", "chapter"]
     boundaries: list[PageBoundary] | None
     pages: list[PageInfo] | None
 
@@ -1438,6 +1694,7 @@ class Metadata(TypedDict, total=False):
     # Excel-specific (flattened from ExcelMetadata)
     sheet_count: int
     sheet_names: list[str]
+    hidden_sheets: list[str]
 
     # Email-specific (flattened from EmailMetadata)
     from_email: str | None
@@ -1514,6 +1771,9 @@ class ExtractedImage(TypedDict, total=False):
     bits_per_component: int
     is_mask: bool
     description: str
+    alt_text: str
+    caption: str
+    page_position: tuple[float, float]
     ocr_result: ExtractionResult
 
 class ChunkMetadata(TypedDict, total=False):
@@ -1524,6 +1784,7 @@ class ChunkMetadata(TypedDict, total=False):
     token_count: int | None
     first_page: int
     last_page: int
+    heading_path: list[str]
 
 class Chunk:
     content: str
@@ -1756,6 +2017,103 @@ class DocumentStructure(TypedDict):
 
     nodes: list[DocumentNode]
 
+class OutlineNode(TypedDict, total=False):
+    """An entry in a document outline.
+
+    PDFs provide the outline from their bookmarks, DOCX documents from their heading
+    styles. Enable with ExtractionConfig(extract_outline=True).
+
+    Attributes:
+        title (str): Entry title as shown in the document's navigation pane.
+        level (int): Nesting level, starting at 1 for top-level entries.
+        page_number (int): 1-indexed target page, when known.
+        children (list[OutlineNode]): Nested entries.
+    """
+
+    title: str
+    level: int
+    page_number: int
+    children: list[OutlineNode]
+
+class Formula(TypedDict, total=False):
+    """A mathematical formula recognized as LaTeX.
+
+    Produced for PDFs when PdfConfig(extract_math=True) is set and the library was
+    built with the math-ocr feature.
+
+    Attributes:
+        latex (str): LaTeX source, without surrounding ``$`` delimiters.
+        kind (Literal["inline", "display"]): "inline" for formulas in running text,
+            "display" for formulas set apart from it.
+        page_number (int): 1-indexed page the formula is on.
+        bounding_box (BoundingBox): Region of the page the formula was recognized
+            from, in PDF points.
+    """
+
+    latex: str
+    kind: Literal["inline", "display"]
+    page_number: int
+    bounding_box: BoundingBox
+
+class TextBlock(TypedDict):
+    """A run of text with its page and bounding box.
+
+    Produced when ExtractionConfig(emit_positions=True) is set. PDF blocks use PDF
+    points with the origin at the bottom-left; OCR blocks use image pixels with the
+    origin at the top-left.
+
+    Attributes:
+        text (str): Text content of the block.
+        page (int): 1-indexed page the block appears on.
+        bbox (BoundingBox): Bounding box of the block on its page.
+    """
+
+    text: str
+    page: int
+    bbox: BoundingBox
+
+class SourceSpan(TypedDict, total=False):
+    """A range of content mapped to where its text came from in the source document.
+
+    Produced when ExtractionConfig(emit_source_offsets=True) is set.
+
+    Attributes:
+        content_start (int): Byte offset in content where the range starts.
+        content_end (int): Byte offset in content where the range ends (exclusive).
+        page (int): 1-indexed page of the source, present for paged formats (PDF).
+        source_start (int): Start of the text in the source, counted in unit.
+        source_end (int): End of the text in the source (exclusive), counted in unit.
+        unit (str): "byte" for byte offsets into the source (HTML), "page_char" for
+            character indices into the page text layer (PDF).
+    """
+
+    content_start: int
+    content_end: int
+    page: int
+    source_start: int
+    source_end: int
+    unit: Literal["byte", "page_char"]
+
+class Reference(TypedDict, total=False):
+    """An entry of a document's references section, parsed heuristically.
+
+    Produced when ExtractionConfig(extract_references=True) is set. Fields that
+    could not be recognized are absent.
+
+    Attributes:
+        raw (str): The entry text with line breaks joined and its list marker removed.
+        authors (list[str]): Author names in the order listed, as written.
+        title (str): Title of the cited work.
+        year (int): Publication year.
+        doi (str): DOI without a resolver prefix, e.g. "10.1000/xyz123".
+    """
+
+    raw: str
+    authors: list[str]
+    title: str
+    year: int
+    doi: str
+
 class ExtractionResult:
     content: str
     mime_type: str
@@ -1767,6 +2125,11 @@ class ExtractionResult:
     pages: list[PageContent] | None
     elements: list[Element] | None
     document: DocumentStructure | None
+    outline: list[OutlineNode] | None
+    formulas: list[Formula] | None
+    text_blocks: list[TextBlock] | None
+    source_map: list[SourceSpan] | None
+    references: list[Reference] | None
     ocr_elements: list[OcrElement] | None
     djot_content: DjotContent | None
     output_format: str | None
@@ -1787,6 +2150,8 @@ class ExtractedTable:
     cells: list[list[str]]
     markdown: str
     page_number: int
+    name: str | None
+    has_header: bool
 
 @overload
 def extract_file_sync(
@@ -1847,6 +2212,10 @@ def unregister_post_processor(name: str) -> None: ...
 def register_validator(validator: ValidatorProtocol) -> None: ...
 def clear_validators() -> None: ...
 def unregister_validator(name: str) -> None: ...
+def register_mime_detector(detector: MimeDetectorProtocol) -> None: ...
+def clear_mime_detectors() -> None: ...
+def unregister_mime_detector(name: str) -> None: ...
+def list_mime_detectors() -> list[str]: ...
 def list_embedding_presets() -> list[str]: ...
 def get_embedding_preset(name: str) -> EmbeddingPreset | None: ...
 def clear_document_extractors() -> None: ...
@@ -6,14 +6,19 @@ import contextlib
 from typing import Any, Literal
 
 from kreuzberg import (
+    clear_mime_detectors,
     clear_post_processors,
     clear_validators,
+    detect_mime_type,
+    list_mime_detectors,
     list_ocr_backends,
     list_post_processors,
     list_validators,
+    register_mime_detector,
     register_ocr_backend,
     register_post_processor,
     register_validator,
+    unregister_mime_detector,
     unregister_ocr_backend,
 )
 
@@ -268,3 +273,33 @@ def test_unregister_nonexistent_ocr_backend() -> None:
     """Test unregistering a nonexistent backend handles gracefully."""
     with contextlib.suppress(Exception):
         unregister_ocr_backend("nonexistent_backend_xyz")
+
+
+class MockMimeDetector:
+    """Mock MIME detector recognizing a magic number."""
+
+    def name(self) -> str:
+        return "mock_mime_detector"
+
+    def detect(self, header: bytes) -> str | None:
+        return "application/x-mock-ledger" if header.startswith(b"LDGR\x01") else None
+
+
+def test_register_mime_detector_detects_content() -> None:
+    """Test a registered MIME detector is asked before the built-in detection."""
+    register_mime_detector(MockMimeDetector())
+    try:
+        assert "mock_mime_detector" in list_mime_detectors()
+        assert detect_mime_type(b"LDGR\x01opening balance") == "application/x-mock-ledger"
+        assert detect_mime_type(b"%PDF-1.7\n") == "application/pdf"
+    finally:
+        unregister_mime_detector("mock_mime_detector")
+
+    assert "mock_mime_detector" not in list_mime_detectors()
+
+
+def test_clear_mime_detectors() -> None:
+    """Test clearing MIME detectors empties the list."""
+    register_mime_detector(MockMimeDetector())
+    clear_mime_detectors()
+    assert list_mime_detectors() == []
@@ -6,24 +6,24 @@
 use crate::error_handling::{runtime_error, validation_error};
 use crate::helpers::{get_kw, json_value_to_ruby, ruby_value_to_json, symbol_to_string};
 
+use html_to_markdown_rs::WhitespaceMode;
 use html_to_markdown_rs::options::{
-    CodeBlockStyle, ConversionOptions, HeadingStyle, HighlightStyle, ListIndentType, NewlineStyle,
-    PreprocessingPreset,
+    CodeBlockStyle, ConversionOptions, HeadingStyle, HighlightStyle, ListIndentType, NewlineStyle, PreprocessingPreset,
 };
-use html_to_markdown_rs::WhitespaceMode;
 use kreuzberg::core::config::PageConfig;
 use kreuzberg::keywords::{
     KeywordAlgorithm as RustKeywordAlgorithm, KeywordConfig as RustKeywordConfig, RakeParams as RustRakeParams,
     YakeParams as RustYakeParams,
 };
-use kreuzberg::types::TesseractConfig as RustTesseractConfig;
 use kreuzberg::pdf::HierarchyConfig;
+use kreuzberg::types::TesseractConfig as RustTesseractConfig;
 use kreuzberg::{
-    ChunkingConfig, EmbeddingConfig, ExtractionConfig, ImageExtractionConfig, ImagePreprocessingConfig,
-    LanguageDetectionConfig, OcrConfig, OutputFormat, PdfConfig, PostProcessorConfig, TokenReductionConfig,
+    ChunkingConfig, EmbeddingConfig, ExtractionConfig, ImageExtractionConfig, ImageFormat, ImagePreprocessingConfig,
+    LanguageDetectionConfig, OcrConfig, OutputFormat, PdfConfig, PostProcessorConfig, ReadingOrder,
+    TokenReductionConfig,
 };
-use magnus::{Error, RArray, RHash, Ruby, TryConvert, Value};
 use magnus::value::ReprValue;
+use magnus::{Error, RArray, RHash, Ruby, TryConvert, Value};
 use std::fs;
 
 /// Parse OcrConfig from Ruby Hash
@@ -47,8 +47,62 @@ pub fn parse_ocr_config(ruby: &Ruby, hash: RHash) -> Result<OcrConfig, Error> {
         element_config: None,
         tesseract_config: None,
         output_format: None,
+        max_retries: 0,
+        auto_language: false,
+        fallback_backends: Vec::new(),
+        user_words: None,
+        render_dpi: None,
+        empty_page_confidence: None,
+        page_filter: None,
+        region: None,
     };
 
+    if let Some(val) = get_kw(ruby, hash, "auto_language") {
+        config.auto_language = bool::try_convert(val)?;
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "fallback_backends")
+        && !val.is_nil()
+    {
+        let arr = RArray::try_convert(val)?;
+        config.fallback_backends = arr.to_vec::<String>()?;
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "user_words")
+        && !val.is_nil()
+    {
+        let arr = RArray::try_convert(val)?;
+        config.user_words = Some(arr.to_vec::<String>()?);
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "render_dpi")
+        && !val.is_nil()
+    {
+        config.render_dpi = Some(i32::try_convert(val)?);
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "empty_page_confidence")
+        && !val.is_nil()
+    {
+        config.empty_page_confidence = Some(f64::try_convert(val)?);
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "page_filter")
+        && !val.is_nil()
+    {
+        let arr = RArray::try_convert(val)?;
+        config.page_filter = Some(arr.to_vec::<usize>()?);
+    }
+
+    if let Some(val) = get_kw(ruby, hash, "region")
+        && !val.is_nil()
+    {
+        let region_json = ruby_value_to_json(val)?;
+        let parsed: kreuzberg::core::config::Rect =
+            serde_json::from_value(region_json).map_err(|e| runtime_error(format!("Invalid region: {}", e)))?;
+        config.region = Some(parsed);
+    }
+
     if let Some(val) = get_kw(ruby, hash, "tesseract_config")
         && !val.is_nil()
     {
@@ -112,6 +166,32 @@ pub fn parse_chunking_config(ruby: &Ruby, hash: RHash) -> Result<ChunkingConfig,
         None
     };
 
+    let overlap_unit = if let Some(val) = get_kw(ruby, hash, "overlap_unit")
+        && !val.is_nil()
+    {
+        let value = symbol_to_string(val)?;
+        match value.as_str() {
+            "characters" => kreuzberg::OverlapUnit::Characters,
+            "sentences" => kreuzberg::OverlapUnit::Sentences,
+            other => {
+                return Err(runtime_error(format!(
+                    "Invalid chunking.overlap_unit: {}. Expected 'characters' or 'sentences'",
+                    other
+                )));
+            }
+        }
+    } else {
+        kreuzberg::OverlapUnit::Characters
+    };
+
+    let max_chunks = if let Some(val) = get_kw(ruby, hash, "max_chunks")
+        && !val.is_nil()
+    {
+        Some(usize::try_convert(val)?)
+    } else {
+        None
+    };
+
     let embedding = if let Some(val) = get_kw(ruby, hash, "embedding")
         && !val.is_nil()
     {
@@ -130,6 +210,9 @@ pub fn parse_chunking_config(ruby: &Ruby, hash: RHash) -> Result<ChunkingConfig,
         chunker_type: kreuzberg::ChunkerType::Text,
         embedding,
         preset,
+        overlap_unit,
+        max_chunks,
+        balance: false,
     };
 
     Ok(config)
@@ -240,11 +323,59 @@ pub fn parse_pdf_config(ruby: &Ruby, hash: RHash) -> Result<PdfConfig, Error> {
         None
     };
 
+    let reading_order = if let Some(val) = get_kw(ruby, hash, "reading_order")
+        && !val.is_nil()
+    {
+        let value = symbol_to_string(val)?;
+        match value.as_str() {
+            "raw" => ReadingOrder::Raw,
+            "column_aware" => ReadingOrder::ColumnAware,
+            other => {
+                return Err(runtime_error(format!(
+                    "Invalid reading_order: {}. Expected 'raw' or 'column_aware'",
+                    other
+                )));
+            }
+        }
+    } else {
+        ReadingOrder::Raw
+    };
+
+    let allow_owner_locked = if let Some(val) = get_kw(ruby, hash, "allow_owner_locked") {
+        bool::try_convert(val)?
+    } else {
+        true
+    };
+
+    let page_separator = if let Some(val) = get_kw(ruby, hash, "page_separator") {
+        Some(String::try_convert(val)?)
+    } else {
+        None
+    };
+
+    let extract_math = if let Some(val) = get_kw(ruby, hash, "extract_math") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let insert_image_markers = if let Some(val) = get_kw(ruby, hash, "insert_image_markers") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
     let config = PdfConfig {
         extract_images,
         passwords,
+        allow_owner_locked,
+        extract_math,
         extract_metadata,
         hierarchy,
+        reading_order,
+        page_range: None,
+        page_separator,
+        insert_image_markers,
     };
 
     Ok(config)
@@ -270,6 +401,12 @@ pub fn parse_image_extraction_config(ruby: &Ruby, hash: RHash) -> Result<ImageEx
         4096
     };
 
+    let max_decode_dimension = if let Some(val) = get_kw(ruby, hash, "max_decode_dimension") {
+        u32::try_convert(val)?
+    } else {
+        32768
+    };
+
     let auto_adjust_dpi = if let Some(val) = get_kw(ruby, hash, "auto_adjust_dpi") {
         bool::try_convert(val)?
     } else {
@@ -288,13 +425,52 @@ pub fn parse_image_extraction_config(ruby: &Ruby, hash: RHash) -> Result<ImageEx
         600
     };
 
+    let max_images = if let Some(val) = get_kw(ruby, hash, "max_images") {
+        Some(usize::try_convert(val)?)
+    } else {
+        None
+    };
+
+    let max_total_image_bytes = if let Some(val) = get_kw(ruby, hash, "max_total_image_bytes") {
+        Some(usize::try_convert(val)?)
+    } else {
+        None
+    };
+
+    let min_width = if let Some(val) = get_kw(ruby, hash, "min_width") {
+        u32::try_convert(val)?
+    } else {
+        16
+    };
+
+    let min_height = if let Some(val) = get_kw(ruby, hash, "min_height") {
+        u32::try_convert(val)?
+    } else {
+        16
+    };
+
+    let output_format = if let Some(val) = get_kw(ruby, hash, "output_format") {
+        let format_str = String::try_convert(val)?;
+        format_str
+            .parse()
+            .map_err(|e| runtime_error(format!("Invalid images output_format: {}", e)))?
+    } else {
+        ImageFormat::default()
+    };
+
     let config = ImageExtractionConfig {
         extract_images,
         target_dpi,
         max_image_dimension,
+        max_decode_dimension,
         auto_adjust_dpi,
         min_dpi,
         max_dpi,
+        max_images,
+        max_total_image_bytes,
+        min_width,
+        min_height,
+        output_format,
     };
 
     Ok(config)
@@ -393,6 +569,7 @@ pub fn parse_postprocessor_config(ruby: &Ruby, hash: RHash) -> Result<PostProces
         disabled_processors,
         enabled_set: None,
         disabled_set: None,
+        ..Default::default()
     };
 
     Ok(config)
@@ -412,9 +589,15 @@ pub fn parse_token_reduction_config(ruby: &Ruby, hash: RHash) -> Result<TokenRed
         true
     };
 
+    let language = match get_kw(ruby, hash, "language") {
+        Some(val) if !val.is_nil() => Some(String::try_convert(val)?),
+        _ => None,
+    };
+
     let config = TokenReductionConfig {
         mode,
         preserve_important_words,
+        language,
     };
 
     Ok(config)
@@ -889,7 +1072,7 @@ pub fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extra
 
     if let Some(hash) = opts {
         if let Some(val) = get_kw(ruby, hash, "use_cache") {
-            config.use_cache = bool::try_convert(val)?;
+            config.use_cache = bool::try_convert(val)? && !kreuzberg::cache::cache_disabled_by_env();
         }
 
         if let Some(val) = get_kw(ruby, hash, "enable_quality_processing") {
@@ -904,6 +1087,60 @@ pub fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extra
             config.include_document_structure = bool::try_convert(val)?;
         }
 
+        if let Some(val) = get_kw(ruby, hash, "max_content_chars")
+            && !val.is_nil()
+        {
+            config.max_content_chars = Some(usize::try_convert(val)?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "preserve_list_markers")
+            && !val.is_nil()
+        {
+            config.preserve_list_markers = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "extract_outline")
+            && !val.is_nil()
+        {
+            config.extract_outline = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "extract_references")
+            && !val.is_nil()
+        {
+            config.extract_references = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "normalize_bidi")
+            && !val.is_nil()
+        {
+            config.normalize_bidi = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "html_include_alt_text")
+            && !val.is_nil()
+        {
+            config.html_include_alt_text = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "emit_positions")
+            && !val.is_nil()
+        {
+            config.emit_positions = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "emit_source_offsets")
+            && !val.is_nil()
+        {
+            config.emit_source_offsets = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "low_memory")
+            && !val.is_nil()
+        {
+            config.low_memory = bool::try_convert(val)?;
+        }
+
         if let Some(val) = get_kw(ruby, hash, "ocr")
             && !val.is_nil()
         {
@@ -988,7 +1225,7 @@ pub fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extra
                     return Err(runtime_error(format!(
                         "Invalid result_format: '{}'. Expected 'unified' or 'element_based'",
                         format_str
-                    )))
+                    )));
                 }
             };
         }
@@ -1004,10 +1241,44 @@ pub fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extra
                     return Err(runtime_error(format!(
                         "Invalid output_format: '{}'. Expected 'plain', 'markdown', 'djot', or 'html'",
                         format_str
-                    )))
+                    )));
                 }
             };
         }
+
+        if let Some(val) = get_kw(ruby, hash, "output_mode")
+            && !val.is_nil()
+        {
+            let mode_str = String::try_convert(val)?;
+            config.output_mode = mode_str.parse().map_err(runtime_error)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "normalize_newlines")
+            && !val.is_nil()
+        {
+            let ending_str = String::try_convert(val)?;
+            config.normalize_newlines = Some(ending_str.parse().map_err(runtime_error)?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "docx_options")
+            && !val.is_nil()
+        {
+            let json_value = ruby_value_to_json(val)?;
+            config.docx_options = Some(
+                serde_json::from_value(json_value)
+                    .map_err(|e| runtime_error(format!("Invalid docx_options: {}", e)))?,
+            );
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "jupyter_options")
+            && !val.is_nil()
+        {
+            let json_value = ruby_value_to_json(val)?;
+            config.jupyter_options = Some(
+                serde_json::from_value(json_value)
+                    .map_err(|e| runtime_error(format!("Invalid jupyter_options: {}", e)))?,
+            );
+        }
     }
 
     Ok(config)
@@ -1032,18 +1303,12 @@ pub fn config_from_file(path: String) -> Result<RHash, Error> {
         .map(|s| s.to_lowercase());
 
     let json_value: serde_json::Value = match extension.as_deref() {
-        Some("toml") => {
-            toml::from_str(&content)
-                .map_err(|e| validation_error(format!("Invalid TOML in config file '{}': {}", path, e)))?
-        }
-        Some("yaml") | Some("yml") => {
-            serde_yaml_ng::from_str(&content)
-                .map_err(|e| validation_error(format!("Invalid YAML in config file '{}': {}", path, e)))?
-        }
-        Some("json") => {
-            serde_json::from_str(&content)
-                .map_err(|e| validation_error(format!("Invalid JSON in config file '{}': {}", path, e)))?
-        }
+        Some("toml") => toml::from_str(&content)
+            .map_err(|e| validation_error(format!("Invalid TOML in config file '{}': {}", path, e)))?,
+        Some("yaml") | Some("yml") => serde_yaml_ng::from_str(&content)
+            .map_err(|e| validation_error(format!("Invalid YAML in config file '{}': {}", path, e)))?,
+        Some("json") => serde_json::from_str(&content)
+            .map_err(|e| validation_error(format!("Invalid JSON in config file '{}': {}", path, e)))?,
         Some(ext) => {
             return Err(validation_error(format!(
                 "Unsupported config file format: .{}. Supported formats: .toml, .yaml, .yml, .json",
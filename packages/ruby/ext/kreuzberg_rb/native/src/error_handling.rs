@@ -91,7 +91,8 @@ pub fn kreuzberg_error(err: KreuzbergError) -> Error {
                 Error::new(ruby.exception_runtime_error(), format!("IO error: {}", err))
             }
         }
-        KreuzbergError::UnsupportedFormat(message) => {
+        error @ KreuzbergError::UnsupportedFormat { .. } => {
+            let message = error.to_string();
             if let Some(class) = fetch_error_class("UnsupportedFormatError") {
                 Error::new(class, message)
             } else {
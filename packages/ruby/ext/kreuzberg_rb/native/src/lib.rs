@@ -457,6 +457,10 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
     module.define_module_function("unregister_ocr_backend", function!(plugins::unregister_ocr_backend, 1))?;
     module.define_module_function("list_ocr_backends", function!(plugins::list_ocr_backends, 0))?;
     module.define_module_function("clear_ocr_backends", function!(plugins::clear_ocr_backends, 0))?;
+    module.define_module_function("register_image_captioner", function!(plugins::register_image_captioner, 2))?;
+    module.define_module_function("unregister_image_captioner", function!(plugins::unregister_image_captioner, 1))?;
+    module.define_module_function("list_image_captioners", function!(plugins::list_image_captioners, 0))?;
+    module.define_module_function("clear_image_captioners", function!(plugins::clear_image_captioners, 0))?;
     module.define_module_function("list_document_extractors", function!(plugins::list_document_extractors, 0))?;
     module.define_module_function("unregister_document_extractor", function!(plugins::unregister_document_extractor, 1))?;
     module.define_module_function("clear_document_extractors", function!(plugins::clear_document_extractors, 0))?;
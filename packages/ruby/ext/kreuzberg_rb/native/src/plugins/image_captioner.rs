@@ -0,0 +1,111 @@
+//! Image captioner plugin registration and management
+
+use crate::error_handling::{kreuzberg_error, runtime_error};
+use crate::gc_guarded_value::GcGuardedValue;
+use magnus::{Error, Ruby, TryConvert, Value};
+use magnus::value::ReprValue;
+use kreuzberg::plugins::{
+    register_image_captioner as kz_register_image_captioner,
+    unregister_image_captioner as kz_unregister_image_captioner,
+    list_image_captioners as kz_list_image_captioners,
+    clear_image_captioners as kz_clear_image_captioners,
+    ImageCaptioner, Plugin,
+};
+use kreuzberg::types::ExtractedImage;
+use kreuzberg::KreuzbergError;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Ruby image captioner wrapper that implements the ImageCaptioner trait
+struct RubyImageCaptioner {
+    name: String,
+    captioner: GcGuardedValue,
+}
+
+// SAFETY: Ruby's GC is handled by GcGuardedValue, and we ensure all Ruby
+// calls happen through proper Magnus/Ruby FFI boundaries
+unsafe impl Send for RubyImageCaptioner {}
+unsafe impl Sync for RubyImageCaptioner {}
+
+impl Plugin for RubyImageCaptioner {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> String {
+        "1.0.0".to_string()
+    }
+
+    fn initialize(&self) -> kreuzberg::Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> kreuzberg::Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ImageCaptioner for RubyImageCaptioner {
+    async fn caption(&self, image: &ExtractedImage) -> kreuzberg::Result<String> {
+        let captioner_name = self.name.clone();
+        let captioner = self.captioner.value();
+
+        tokio::task::block_in_place(|| {
+            let ruby = Ruby::get().expect("Ruby not initialized");
+
+            // Convert image bytes to Ruby string (binary)
+            let ruby_bytes = ruby.str_from_slice(&image.data);
+
+            // Call Ruby captioner's caption method
+            let result: magnus::Value = captioner
+                .funcall("caption", (ruby_bytes, image.format.as_ref()))
+                .map_err(|e| KreuzbergError::Plugin {
+                    message: format!("Ruby image captioner failed: {}", e),
+                    plugin_name: captioner_name.clone(),
+                })?;
+
+            String::try_convert(result)
+                .map_err(|e| KreuzbergError::Plugin {
+                    message: format!("Image captioner must return a String: {}", e),
+                    plugin_name: captioner_name.clone(),
+                })
+        })
+    }
+}
+
+/// Register an image captioner plugin
+pub fn register_image_captioner(name: String, captioner: Value) -> Result<(), Error> {
+    let _ruby = Ruby::get().expect("Ruby not initialized");
+
+    // Validate that the captioner has the required method
+    if !captioner.respond_to("caption", true)? {
+        return Err(runtime_error("Image captioner must implement #caption(image_bytes, format) method"));
+    }
+
+    let captioner_impl = Arc::new(RubyImageCaptioner {
+        name,
+        captioner: GcGuardedValue::new(captioner),
+    });
+
+    kz_register_image_captioner(captioner_impl)
+        .map_err(kreuzberg_error)
+}
+
+/// Unregister an image captioner
+pub fn unregister_image_captioner(name: String) -> Result<(), Error> {
+    kz_unregister_image_captioner(name.as_str())
+        .map_err(kreuzberg_error)
+}
+
+/// List registered image captioners
+pub fn list_image_captioners() -> Result<Vec<String>, Error> {
+    kz_list_image_captioners()
+        .map_err(kreuzberg_error)
+}
+
+/// Clear all image captioners
+pub fn clear_image_captioners() -> Result<(), Error> {
+    kz_clear_image_captioners()
+        .map_err(kreuzberg_error)
+}
@@ -1,15 +1,19 @@
 //! Plugin management for Kreuzberg
 //!
 //! Handles registration and management of custom plugins including post-processors,
-//! validators, and OCR backends.
+//! validators, OCR backends, and image captioners.
 
 pub mod post_processor;
 pub mod validator;
 pub mod ocr_backend;
+pub mod image_captioner;
 
 pub use post_processor::register_post_processor;
 pub use validator::register_validator;
 pub use ocr_backend::{register_ocr_backend, unregister_ocr_backend, list_ocr_backends, clear_ocr_backends};
+pub use image_captioner::{
+    register_image_captioner, unregister_image_captioner, list_image_captioners, clear_image_captioners,
+};
 
 // Plugin registry functions
 pub use kreuzberg::get_post_processor_registry;
@@ -101,6 +101,11 @@ impl OcrBackend for RubyOcrBackend {
                 elements: None,
                 ocr_elements: None,
                 document: None,
+                outline: None,
+                formulas: None,
+                text_blocks: None,
+                source_map: None,
+                references: None,
             })
         })
     }
@@ -51,19 +51,24 @@ pub fn register_post_processor(args: &[Value]) -> Result<(), Error> {
         async fn process(
             &self,
             result: &mut kreuzberg::ExtractionResult,
-            _config: &kreuzberg::ExtractionConfig,
+            config: &kreuzberg::ExtractionConfig,
         ) -> kreuzberg::Result<()> {
             let processor_name = self.name.clone();
             let processor = self.processor.value();
-            let result_clone = result.clone();
+            let low_memory = config.low_memory;
 
-            let updated_result = tokio::task::block_in_place(|| {
+            let (new_content, new_mime) = tokio::task::block_in_place(|| {
                 let ruby = Ruby::get().expect("Ruby not initialized");
-                let result_hash = crate::result::extraction_result_to_ruby(&ruby, result_clone.clone()).map_err(|e| {
-                    kreuzberg::KreuzbergError::Plugin {
-                        message: format!("Failed to convert result to Ruby: {}", e),
-                        plugin_name: processor_name.clone(),
-                    }
+                // Only content and MIME type are read back, so low-memory mode hands the plugin
+                // those and the parsed metadata instead of converting a clone of the whole result.
+                let result_hash = if low_memory {
+                    crate::result::extraction_result_summary_to_ruby(&ruby, result)
+                } else {
+                    crate::result::extraction_result_to_ruby(&ruby, result.clone())
+                }
+                .map_err(|e| kreuzberg::KreuzbergError::Plugin {
+                    message: format!("Failed to convert result to Ruby: {}", e),
+                    plugin_name: processor_name.clone(),
                 })?;
 
                 let modified = processor
@@ -79,29 +84,34 @@ pub fn register_post_processor(args: &[Value]) -> Result<(), Error> {
                         plugin_name: processor_name.clone(),
                     })?;
 
-                let mut updated_result = result_clone;
-
-                if let Some(content_val) = get_kw(&ruby, modified_hash, "content") {
-                    let new_content =
+                let new_content = get_kw(&ruby, modified_hash, "content")
+                    .map(|content_val| {
                         String::try_convert(content_val).map_err(|e| kreuzberg::KreuzbergError::Plugin {
                             message: format!("Failed to convert content: {}", e),
                             plugin_name: processor_name.clone(),
-                        })?;
-                    updated_result.content = new_content;
-                }
-
-                if let Some(mime_val) = get_kw(&ruby, modified_hash, "mime_type") {
-                    let new_mime = String::try_convert(mime_val).map_err(|e| kreuzberg::KreuzbergError::Plugin {
-                        message: format!("Failed to convert mime_type: {}", e),
-                        plugin_name: processor_name.clone(),
-                    })?;
-                    updated_result.mime_type = std::borrow::Cow::Owned(new_mime);
-                }
+                        })
+                    })
+                    .transpose()?;
+
+                let new_mime = get_kw(&ruby, modified_hash, "mime_type")
+                    .map(|mime_val| {
+                        String::try_convert(mime_val).map_err(|e| kreuzberg::KreuzbergError::Plugin {
+                            message: format!("Failed to convert mime_type: {}", e),
+                            plugin_name: processor_name.clone(),
+                        })
+                    })
+                    .transpose()?;
 
-                Ok::<kreuzberg::ExtractionResult, kreuzberg::KreuzbergError>(updated_result)
+                Ok::<_, kreuzberg::KreuzbergError>((new_content, new_mime))
             })?;
 
-            *result = updated_result;
+            // Only the converted copy above is made; the plugin's changes are applied in place.
+            if let Some(new_content) = new_content {
+                result.content = new_content;
+            }
+            if let Some(new_mime) = new_mime {
+                result.mime_type = std::borrow::Cow::Owned(new_mime);
+            }
             Ok(())
         }
 
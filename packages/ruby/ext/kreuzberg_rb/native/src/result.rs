@@ -7,8 +7,8 @@ use crate::error_handling::runtime_error;
 use crate::helpers::{json_value_to_ruby, set_hash_entry};
 
 use kreuzberg::ExtractionResult as RustExtractionResult;
-use magnus::{Error, RHash, Ruby, IntoValue};
 use magnus::value::ReprValue;
+use magnus::{Error, IntoValue, RHash, Ruby};
 
 /// Convert Kreuzberg ExtractionResult to Ruby Hash
 ///
@@ -53,6 +53,8 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
         table_hash.aset("cells", cells_array)?;
         table_hash.aset("markdown", table.markdown)?;
         table_hash.aset("page_number", table.page_number)?;
+        table_hash.aset("name", table.name)?;
+        table_hash.aset("has_header", table.has_header)?;
 
         tables_array.push(table_hash)?;
     }
@@ -93,6 +95,11 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
             } else {
                 chunk_hash.aset("last_page", ruby.qnil().as_value())?;
             }
+            if let Some(heading_path) = chunk.metadata.heading_path {
+                chunk_hash.aset("heading_path", heading_path)?;
+            } else {
+                chunk_hash.aset("heading_path", ruby.qnil().as_value())?;
+            }
             if let Some(embedding) = chunk.embedding {
                 let embedding_array = ruby.ary_new();
                 for value in embedding {
@@ -158,6 +165,21 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
             } else {
                 image_hash.aset("description", ruby.qnil().as_value())?;
             }
+            if let Some(alt_text) = image.alt_text {
+                image_hash.aset("alt_text", alt_text)?;
+            } else {
+                image_hash.aset("alt_text", ruby.qnil().as_value())?;
+            }
+            if let Some(caption) = image.caption {
+                image_hash.aset("caption", caption)?;
+            } else {
+                image_hash.aset("caption", ruby.qnil().as_value())?;
+            }
+            if let Some((x, y)) = image.page_position {
+                image_hash.aset("page_position", vec![x, y])?;
+            } else {
+                image_hash.aset("page_position", ruby.qnil().as_value())?;
+            }
             if let Some(ocr_result) = image.ocr_result {
                 let nested = extraction_result_to_ruby(ruby, *ocr_result)?;
                 image_hash.aset("ocr_result", nested.into_value_with(ruby))?;
@@ -191,6 +213,8 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
                 table_hash.aset("cells", cells_array)?;
                 table_hash.aset("markdown", table.markdown.clone())?;
                 table_hash.aset("page_number", table.page_number as i64)?;
+                table_hash.aset("name", table.name.clone())?;
+                table_hash.aset("has_header", table.has_header)?;
 
                 tables_array.push(table_hash)?;
             }
@@ -242,6 +266,21 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
                 } else {
                     image_hash.aset("description", ruby.qnil().as_value())?;
                 }
+                if let Some(alt_text) = &image.alt_text {
+                    image_hash.aset("alt_text", alt_text.clone())?;
+                } else {
+                    image_hash.aset("alt_text", ruby.qnil().as_value())?;
+                }
+                if let Some(caption) = &image.caption {
+                    image_hash.aset("caption", caption.clone())?;
+                } else {
+                    image_hash.aset("caption", ruby.qnil().as_value())?;
+                }
+                if let Some((x, y)) = image.page_position {
+                    image_hash.aset("page_position", vec![x, y])?;
+                } else {
+                    image_hash.aset("page_position", ruby.qnil().as_value())?;
+                }
                 if let Some(ocr_result) = &image.ocr_result {
                     let nested = extraction_result_to_ruby(ruby, (**ocr_result).clone())?;
                     image_hash.aset("ocr_result", nested.into_value_with(ruby))?;
@@ -366,7 +405,14 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
                 }
                 NodeContent::List { ordered } => {
                     content_hash.aset("node_type", "list")?;
-                    content_hash.aset("ordered", if ordered { ruby.qtrue().as_value() } else { ruby.qfalse().as_value() })?;
+                    content_hash.aset(
+                        "ordered",
+                        if ordered {
+                            ruby.qtrue().as_value()
+                        } else {
+                            ruby.qfalse().as_value()
+                        },
+                    )?;
                 }
                 NodeContent::ListItem { text } => {
                     content_hash.aset("node_type", "list_item")?;
@@ -385,7 +431,14 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
                         cell_hash.aset("col", cell.col as i64)?;
                         cell_hash.aset("row_span", cell.row_span as i64)?;
                         cell_hash.aset("col_span", cell.col_span as i64)?;
-                        cell_hash.aset("is_header", if cell.is_header { ruby.qtrue().as_value() } else { ruby.qfalse().as_value() })?;
+                        cell_hash.aset(
+                            "is_header",
+                            if cell.is_header {
+                                ruby.qtrue().as_value()
+                            } else {
+                                ruby.qfalse().as_value()
+                            },
+                        )?;
                         if let Some(bbox) = cell.bbox {
                             let bbox_hash = ruby.hash_new();
                             bbox_hash.aset("x0", bbox.x0)?;
@@ -401,7 +454,10 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
                     grid_hash.aset("cells", cells_array)?;
                     content_hash.aset("grid", grid_hash)?;
                 }
-                NodeContent::Image { description, image_index } => {
+                NodeContent::Image {
+                    description,
+                    image_index,
+                } => {
                     content_hash.aset("node_type", "image")?;
                     if let Some(desc) = description {
                         content_hash.aset("description", desc)?;
@@ -434,7 +490,11 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
                     content_hash.aset("node_type", "footnote")?;
                     content_hash.aset("text", text)?;
                 }
-                NodeContent::Group { label, heading_level, heading_text } => {
+                NodeContent::Group {
+                    label,
+                    heading_level,
+                    heading_text,
+                } => {
                     content_hash.aset("node_type", "group")?;
                     if let Some(lbl) = label {
                         content_hash.aset("label", lbl)?;
@@ -557,3 +617,24 @@ pub fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> R
 
     Ok(hash)
 }
+
+/// Convert the content, MIME type and metadata of an ExtractionResult to a Ruby Hash.
+///
+/// Borrows the result, so tables, images, pages and chunks are neither copied nor converted.
+/// Used for post-processors in low-memory mode, which can only change content and MIME type.
+pub fn extraction_result_summary_to_ruby(ruby: &Ruby, result: &RustExtractionResult) -> Result<RHash, Error> {
+    let hash = ruby.hash_new();
+
+    let content_value = ruby.str_new(result.content.as_str()).into_value_with(ruby);
+    set_hash_entry(ruby, &hash, "content", content_value)?;
+
+    let mime_value = ruby.str_new(result.mime_type.as_ref()).into_value_with(ruby);
+    set_hash_entry(ruby, &hash, "mime_type", mime_value)?;
+
+    let metadata_value = serde_json::to_value(&result.metadata)
+        .map_err(|e| runtime_error(format!("Failed to serialize metadata: {}", e)))?;
+    let metadata_hash = json_value_to_ruby(ruby, &metadata_value)?;
+    set_hash_entry(ruby, &hash, "metadata", metadata_hash)?;
+
+    Ok(hash)
+}
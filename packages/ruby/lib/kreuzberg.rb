@@ -20,6 +20,7 @@ module Kreuzberg
   autoload :PostProcessorProtocol, 'kreuzberg/post_processor_protocol'
   autoload :ValidatorProtocol, 'kreuzberg/validator_protocol'
   autoload :OcrBackendProtocol, 'kreuzberg/ocr_backend_protocol'
+  autoload :ImageCaptionerProtocol, 'kreuzberg/image_captioner_protocol'
 
   autoload :HtmlMetadata, 'kreuzberg/types'
   autoload :HeaderMetadata, 'kreuzberg/types'
@@ -76,6 +77,14 @@ module Kreuzberg
 
   module_function :list_ocr_backends
 
+  module_function :register_image_captioner
+
+  module_function :unregister_image_captioner
+
+  module_function :list_image_captioners
+
+  module_function :clear_image_captioners
+
   module_function :detect_mime_type
 
   module_function :detect_mime_type_from_path
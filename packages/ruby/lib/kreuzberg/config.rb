@@ -6,16 +6,29 @@ module Kreuzberg
   module Config
     # @example
     class OCR
-      attr_reader :backend, :language, :tesseract_config, :paddle_ocr_config, :element_config
+      attr_reader :backend, :language, :tesseract_config, :paddle_ocr_config, :element_config, :fallback_backends,
+                  :user_words, :render_dpi, :empty_page_confidence, :page_filter, :region
 
       def initialize(
         backend: 'tesseract',
         language: 'eng',
         tesseract_config: nil,
         paddle_ocr_config: nil,
-        element_config: nil
+        element_config: nil,
+        fallback_backends: nil,
+        user_words: nil,
+        render_dpi: nil,
+        empty_page_confidence: nil,
+        page_filter: nil,
+        region: nil
       )
         @backend = backend.to_s
+        @fallback_backends = fallback_backends&.map(&:to_s)
+        @user_words = user_words&.map(&:to_s)
+        @render_dpi = render_dpi&.to_i
+        @empty_page_confidence = empty_page_confidence&.to_f
+        @page_filter = page_filter&.map(&:to_i)
+        @region = region&.to_h&.transform_keys(&:to_sym)&.transform_values(&:to_f)
         @language = language.to_s
         @tesseract_config = normalize_tesseract_config(tesseract_config)
         @paddle_ocr_config = normalize_paddle_ocr_config(paddle_ocr_config)
@@ -28,7 +41,13 @@ module Kreuzberg
           language: @language,
           tesseract_config: @tesseract_config&.to_h,
           paddle_ocr_config: @paddle_ocr_config&.to_h,
-          element_config: @element_config&.to_h
+          element_config: @element_config&.to_h,
+          fallback_backends: @fallback_backends,
+          user_words: @user_words,
+          render_dpi: @render_dpi,
+          empty_page_confidence: @empty_page_confidence,
+          page_filter: @page_filter,
+          region: @region
         }.compact
       end
 
@@ -193,11 +212,13 @@ module Kreuzberg
     #   chunking = Chunking.new(max_chars: 1000, max_overlap: 200)
     #
     class Chunking
-      attr_reader :max_chars, :max_overlap, :preset, :embedding, :enabled
+      attr_reader :max_chars, :max_overlap, :overlap_unit, :max_chunks, :preset, :embedding, :enabled
 
       def initialize(
         max_chars: nil,
         max_overlap: nil,
+        overlap_unit: nil,
+        max_chunks: nil,
         preset: nil,
         embedding: nil,
         chunk_size: nil,
@@ -214,6 +235,8 @@ module Kreuzberg
         raise ArgumentError, "max_chars must be a positive integer, got #{@max_chars}" if @max_chars.negative?
         raise ArgumentError, "max_overlap must be a positive integer, got #{@max_overlap}" if @max_overlap.negative?
 
+        @overlap_unit = overlap_unit&.to_s
+        @max_chunks = max_chunks&.to_i
         @preset = preset&.to_s
         @embedding = normalize_embedding(embedding)
         @enabled = boolean_or_nil(enabled)
@@ -223,6 +246,8 @@ module Kreuzberg
         config = {
           max_chars: @max_chars,
           max_overlap: @max_overlap,
+          overlap_unit: @overlap_unit,
+          max_chunks: @max_chunks,
           preset: @preset,
           embedding: @embedding&.to_h
         }.compact
@@ -390,15 +415,32 @@ module Kreuzberg
     #   hierarchy = Hierarchy.new(enabled: true, k_clusters: 6)
     #   pdf = PDF.new(extract_images: true, hierarchy: hierarchy)
     #
+    # @example Reading multi-column pages column by column
+    #   pdf = PDF.new(reading_order: :column_aware)
+    #
+    # @example Marking where each page starts
+    #   pdf = PDF.new(page_separator: "\n\n<!-- page {page} -->\n\n")
+    #
+    # @example Recognizing formulas as LaTeX (requires the math-ocr feature)
+    #   pdf = PDF.new(extract_math: true)
+    #
+    # @example Marking where extracted images appear in the text
+    #   pdf = PDF.new(extract_images: true, insert_image_markers: true)
+    #
     class PDF
-      attr_reader :extract_images, :passwords, :extract_metadata, :font_config, :hierarchy
+      attr_reader :extract_images, :passwords, :extract_metadata, :font_config, :hierarchy, :reading_order,
+                  :page_separator, :extract_math, :insert_image_markers
 
       def initialize(
         extract_images: false,
         passwords: nil,
         extract_metadata: true,
         font_config: nil,
-        hierarchy: nil
+        hierarchy: nil,
+        reading_order: nil,
+        page_separator: nil,
+        extract_math: false,
+        insert_image_markers: false
       )
         @extract_images = extract_images ? true : false
         @passwords = if passwords.is_a?(Array)
@@ -409,6 +451,10 @@ module Kreuzberg
         @extract_metadata = extract_metadata ? true : false
         @font_config = normalize_font_config(font_config)
         @hierarchy = normalize_hierarchy(hierarchy)
+        @reading_order = reading_order&.to_s
+        @page_separator = page_separator&.to_s
+        @extract_math = extract_math ? true : false
+        @insert_image_markers = insert_image_markers ? true : false
       end
 
       def to_h
@@ -417,7 +463,11 @@ module Kreuzberg
           passwords: @passwords,
           extract_metadata: @extract_metadata,
           font_config: @font_config&.to_h,
-          hierarchy: @hierarchy&.to_h
+          hierarchy: @hierarchy&.to_h,
+          reading_order: @reading_order,
+          page_separator: @page_separator,
+          extract_math: @extract_math,
+          insert_image_markers: @insert_image_markers
         }.compact
       end
 
@@ -567,14 +617,18 @@ module Kreuzberg
     # @example Aggressive reduction
     #   token = TokenReduction.new(mode: "aggressive", preserve_important_words: false)
     #
+    # @example German stopwords
+    #   token = TokenReduction.new(mode: "moderate", language: "de")
+    #
     class TokenReduction
-      attr_reader :mode, :preserve_important_words
+      attr_reader :mode, :preserve_important_words, :language
 
       VALID_MODES = %w[off light moderate aggressive maximum].freeze
 
-      def initialize(mode: 'off', preserve_important_words: true)
+      def initialize(mode: 'off', preserve_important_words: true, language: nil)
         @mode = mode.to_s
         @preserve_important_words = preserve_important_words ? true : false
+        @language = language&.to_s
 
         # Validate mode against known valid modes
         return if VALID_MODES.include?(@mode)
@@ -585,8 +639,9 @@ module Kreuzberg
       def to_h
         {
           mode: @mode,
-          preserve_important_words: @preserve_important_words
-        }
+          preserve_important_words: @preserve_important_words,
+          language: @language
+        }.compact
       end
     end
 
@@ -0,0 +1,34 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # Protocol for image captioners registered with Kreuzberg.register_image_captioner.
+  #
+  # When a captioner is registered, every extracted image without a caption is passed
+  # to #caption and the returned text is stored as the image's caption. Images are only
+  # extracted when image extraction is enabled. If several captioners are registered,
+  # the most recently registered one is used.
+  #
+  # @example Registering a captioner
+  #   class VisionCaptioner
+  #     include Kreuzberg::ImageCaptionerProtocol
+  #
+  #     def caption(image_bytes, format)
+  #       my_vision_model.describe(image_bytes, format: format)
+  #     end
+  #   end
+  #
+  #   Kreuzberg.register_image_captioner('vision-captioner', VisionCaptioner.new)
+  module ImageCaptionerProtocol
+    # Generate a caption for an extracted image.
+    #
+    # Raising an error aborts the extraction.
+    #
+    # @param image_bytes [String] Binary image data
+    # @param format [String] Image format (e.g., "png", "jpeg")
+    #
+    # @return [String] Caption text
+    def caption(image_bytes, format)
+      raise NotImplementedError, "#{self.class} must implement #caption(image_bytes, format)"
+    end
+  end
+end
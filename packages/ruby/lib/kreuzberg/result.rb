@@ -22,9 +22,13 @@ module Kreuzberg
     #   @return [String] Markdown representation
     # @!attribute [r] page_number
     #   @return [Integer] Page number where table was found
-    Table = Struct.new(:cells, :markdown, :page_number, keyword_init: true) do
+    # @!attribute [r] name
+    #   @return [String, nil] Table name (sheet name for spreadsheets)
+    # @!attribute [r] has_header
+    #   @return [Boolean] Whether the first row of cells is a header row
+    Table = Struct.new(:cells, :markdown, :page_number, :name, :has_header, keyword_init: true) do
       def to_h
-        { cells: cells, markdown: markdown, page_number: page_number }
+        { cells: cells, markdown: markdown, page_number: page_number, name: name, has_header: has_header }
       end
     end
 
@@ -78,6 +82,9 @@ module Kreuzberg
       :bits_per_component,
       :is_mask,
       :description,
+      :alt_text,
+      :caption,
+      :page_position,
       :ocr_result,
       keyword_init: true
     ) do
@@ -93,6 +100,9 @@ module Kreuzberg
           bits_per_component: bits_per_component,
           is_mask: is_mask,
           description: description,
+          alt_text: alt_text,
+          caption: caption,
+          page_position: page_position,
           ocr_result: ocr_result&.to_h
         }
       end
@@ -483,7 +493,9 @@ module Kreuzberg
         Table.new(
           cells: table_hash['cells'] || [],
           markdown: table_hash['markdown'] || '',
-          page_number: table_hash['page_number'] || 0
+          page_number: table_hash['page_number'] || 0,
+          name: table_hash['name'],
+          has_header: table_hash['has_header'] || false
         )
       end
     end
@@ -529,6 +541,9 @@ module Kreuzberg
           bits_per_component: image_hash['bits_per_component'],
           is_mask: image_hash['is_mask'],
           description: image_hash['description'],
+          alt_text: image_hash['alt_text'],
+          caption: image_hash['caption'],
+          page_position: image_hash['page_position'],
           ocr_result: image_hash['ocr_result'] ? Result.new(image_hash['ocr_result']) : nil
         )
       end
@@ -11,6 +11,7 @@
 export interface ExcelMetadata {
 	sheetCount?: number;
 	sheetNames?: string[];
+	hiddenSheets?: string[];
 }
 
 export interface EmailMetadata {
@@ -178,6 +179,7 @@ export interface Metadata {
 
 	sheet_count?: number;
 	sheet_names?: string[];
+	hidden_sheets?: string[];
 
 	from_email?: string | null;
 	from_name?: string | null;
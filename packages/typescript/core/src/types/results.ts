@@ -13,6 +13,8 @@ export interface Table {
 	cells: string[][];
 	markdown: string;
 	pageNumber: number;
+	name?: string | null;
+	hasHeader?: boolean;
 }
 
 export interface ChunkMetadata {
@@ -0,0 +1,107 @@
+#!/usr/bin/env tsx
+/**
+ * Peak RSS of an extraction with a registered post-processor, with and without
+ * `lowMemory`.
+ *
+ * Each mode runs in a fresh child process so the peak RSS of one run does not
+ * carry over into the other. Use a large PDF (hundreds of pages) for a
+ * meaningful comparison.
+ *
+ * Usage: tsx low_memory_postprocessor.ts <file.pdf> [iterations]
+ */
+
+import { execFileSync } from "child_process";
+import { clearPostProcessors, extractFile, registerPostProcessor } from "@kreuzberg/node";
+
+interface RunOutput {
+	low_memory: boolean;
+	peak_rss_mb: number;
+	extraction_time_ms: number;
+	content_length: number;
+}
+
+async function runChild(filePath: string, lowMemory: boolean): Promise<void> {
+	registerPostProcessor({
+		name: () => "benchmark_word_count",
+		processingStage: () => "late",
+		process: (result) => {
+			result.metadata = { ...result.metadata, word_count: result.content.split(/\s+/).length };
+			return result;
+		},
+	});
+
+	const start = performance.now();
+	const result = await extractFile(filePath, null, { useCache: false, lowMemory });
+	const durationMs = performance.now() - start;
+	clearPostProcessors();
+
+	const output: RunOutput = {
+		low_memory: lowMemory,
+		// maxRSS is reported in kilobytes.
+		peak_rss_mb: process.resourceUsage().maxRSS / 1024,
+		extraction_time_ms: durationMs,
+		content_length: result.content.length,
+	};
+	console.log(JSON.stringify(output));
+}
+
+function runMode(filePath: string, lowMemory: boolean): RunOutput {
+	const stdout = execFileSync(
+		process.execPath,
+		[...process.execArgv, process.argv[1], "--child", filePath, String(lowMemory)],
+		{ encoding: "utf8", maxBuffer: 16 * 1024 * 1024 },
+	);
+	const lines = stdout.trim().split("\n");
+	return JSON.parse(lines[lines.length - 1]) as RunOutput;
+}
+
+function median(values: number[]): number {
+	const sorted = [...values].sort((a, b) => a - b);
+	return sorted[Math.floor(sorted.length / 2)];
+}
+
+async function main(): Promise<void> {
+	const args = process.argv.slice(2);
+
+	if (args[0] === "--child") {
+		await runChild(args[1], args[2] === "true");
+		return;
+	}
+
+	if (args.length < 1) {
+		console.error("Usage: low_memory_postprocessor.ts <file.pdf> [iterations]");
+		process.exit(1);
+	}
+
+	const filePath = args[0];
+	const iterations = Number.parseInt(args[1] ?? "3", 10);
+	const summary: Record<string, { peak_rss_mb: number; extraction_time_ms: number }> = {};
+
+	for (const lowMemory of [false, true]) {
+		const runs = Array.from({ length: iterations }, () => runMode(filePath, lowMemory));
+		summary[lowMemory ? "low_memory" : "default"] = {
+			peak_rss_mb: median(runs.map((run) => run.peak_rss_mb)),
+			extraction_time_ms: median(runs.map((run) => run.extraction_time_ms)),
+		};
+	}
+
+	const saved = summary.default.peak_rss_mb - summary.low_memory.peak_rss_mb;
+	console.log(
+		JSON.stringify(
+			{
+				file: filePath,
+				iterations,
+				...summary,
+				peak_rss_saved_mb: saved,
+				peak_rss_saved_pct: (saved / summary.default.peak_rss_mb) * 100,
+			},
+			null,
+			2,
+		),
+	);
+}
+
+main().catch((err) => {
+	console.error(err);
+	process.exit(1);
+});
@@ -190,7 +190,7 @@ fn render_test(fixture: &Fixture) -> Result<String> {
         )?;
         writeln!(
             test_body,
-            "        Err(KreuzbergError::UnsupportedFormat(fmt)) => {{\n            println!(\"Skipping {id}: unsupported format {{fmt}} (requires optional tool)\", fmt=fmt);\n            return;\n        }},",
+            "        Err(KreuzbergError::UnsupportedFormat {{ mime_type: fmt, .. }}) => {{\n            println!(\"Skipping {id}: unsupported format {{fmt}} (requires optional tool)\", fmt=fmt);\n            return;\n        }},",
             id = fixture.id
         )?;
     }